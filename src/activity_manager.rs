@@ -262,7 +262,13 @@ impl ActivityManager {
             };
 
             let params = match host {
-                Host::Remote => HostParams::Remote(params),
+                Host::Remote => {
+                    self.context
+                        .as_mut()
+                        .unwrap()
+                        .set_bookmark_name(Some(bookmark_name.to_string()));
+                    HostParams::Remote(params)
+                }
                 Host::HostBridge => {
                     HostParams::HostBridge(HostBridgeParams::Remote(params.protocol, params.params))
                 }
@@ -413,6 +419,12 @@ impl ActivityManager {
                         result = Some(NextActivity::Authentication);
                         break;
                     }
+                    ExitReason::Connect => {
+                        // User went to a directory bookmark on another remote; connect to it
+                        info!("FileTransferActivity terminated due to 'Connect'");
+                        result = Some(NextActivity::FileTransfer);
+                        break;
+                    }
                     _ => { /* Nothing to do */ }
                 }
             }
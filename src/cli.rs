@@ -2,12 +2,14 @@
 //!
 //! defines the types for main.rs types
 
+mod batch;
 mod remote;
 
 use std::path::PathBuf;
 use std::time::Duration;
 
 use argh::FromArgs;
+pub use batch::{BatchTransfer, TransferDirection};
 pub use remote::{Remote, RemoteArgs};
 
 use crate::activity_manager::NextActivity;
@@ -15,11 +17,19 @@ use crate::system::logging::LogLevel;
 
 pub enum Task {
     Activity(NextActivity),
+    /// Print the active keybindings as JSON
+    DumpKeybindings,
+    /// Transfer files from or to a remote, without the terminal UI
+    Batch(Box<BatchTransfer>),
     /// Import ssh hosts from the specified ssh config file, or from the default location
     /// and save them as bookmarks.
     ImportSshHosts(Option<PathBuf>),
     ImportTheme(PathBuf),
     InstallUpdate,
+    /// Print the active keybindings, optionally filtered by action name or key
+    Keys(Option<String>),
+    /// Reset the keybindings file to the default keybindings
+    ResetKeybindings,
     Version,
 }
 
@@ -32,6 +42,11 @@ where positional can be:
 
     and any combination of the above
 
+To transfer files without the terminal UI:
+        - --download [address] [local-path]
+    OR
+        - --upload [local-path] [address]
+
 Address syntax can be:
 
     - `protocol://user@address:port:wrkdir` for protocols such as Sftp, Scp, Ftp
@@ -47,19 +62,49 @@ pub struct Args {
     /// resolve address argument as a bookmark name
     #[argh(option, short = 'b')]
     pub bookmark: Vec<String>,
-    /// use SSH config host alias (from ~/.ssh/config)
+    /// use SSH config host alias (from ~/.ssh/config), as [user@]alias[:path]
     #[argh(option, short = 'G')]
     pub ssh_host: Vec<String>,
+    /// SSH config file to resolve the -G host aliases with, instead of ~/.ssh/config
+    #[argh(option)]
+    pub ssh_config: Option<PathBuf>,
+    /// download the file at the remote address (e.g. `sftp://host:/path/to/file`) into the local path given as positional argument, then exit
+    #[argh(option)]
+    pub download: Option<String>,
+    /// upload the local file at the path to the remote address given as positional argument, then exit
+    #[argh(option)]
+    pub upload: Option<String>,
+    /// transfer directories recursively with --download and --upload
+    #[argh(switch, short = 'r')]
+    pub recursive: bool,
+    /// print the active keybindings in the format (only `json` is supported), then exit
+    #[argh(option)]
+    pub dump_keybindings: Option<String>,
     /// enable TRACE log level
     #[argh(switch, short = 'D')]
     pub debug: bool,
     /// provide password from CLI; if you need to provide multiple passwords, use multiple -P flags.
-    /// In case just respect the order of the addresses
+    /// In case just respect the order of the addresses. A password written as `env:VAR` is read from the VAR environment variable
     #[argh(option, short = 'P')]
     pub password: Vec<String>,
+    /// read the password from the first line of a file, instead of providing it with -P; use multiple flags in the order of the addresses
+    #[argh(option)]
+    pub password_file: Vec<PathBuf>,
+    /// provide the password of the host bridge from CLI; when set, `-P` provides the password of the remote only
+    #[argh(option)]
+    pub bridge_password: Option<String>,
     /// disable logging
     #[argh(switch, short = 'q')]
     pub quiet: bool,
+    /// reset the keybindings to the default ones, then exit
+    #[argh(switch)]
+    pub reset_keybindings: bool,
+    /// timeout in seconds to connect to the remote and host bridge addresses with; default 30s
+    #[argh(option)]
+    pub timeout: Option<u64>,
+    /// interval in seconds to send keepalives to the SFTP and SCP addresses with while idle; default 0 (disabled)
+    #[argh(option)]
+    pub keepalive: Option<u64>,
     /// set UI ticks; default 10ms
     #[argh(option, short = 'T', default = "10")]
     pub ticks: u64,
@@ -80,6 +125,7 @@ pub enum ArgsSubcommands {
     Config(ConfigArgs),
     ImportSshHosts(ImportSshHostsArgs),
     ImportTheme(ImportThemeArgs),
+    Keys(KeysArgs),
     Update(UpdateArgs),
 }
 
@@ -112,6 +158,15 @@ pub struct ImportThemeArgs {
     pub theme: PathBuf,
 }
 
+#[derive(FromArgs)]
+/// print the active keybindings
+#[argh(subcommand, name = "keys")]
+pub struct KeysArgs {
+    #[argh(positional)]
+    /// optional action name (or part of it) or key (e.g. `alt+b`) to look for
+    pub filter: Option<String>,
+}
+
 pub struct RunOpts {
     pub remote: RemoteArgs,
     pub keyring: bool,
@@ -149,6 +204,13 @@ impl RunOpts {
             ..Default::default()
         }
     }
+
+    pub fn keys(filter: Option<String>) -> Self {
+        Self {
+            task: Task::Keys(filter),
+            ..Default::default()
+        }
+    }
 }
 
 impl Default for RunOpts {
@@ -0,0 +1,159 @@
+//! ## Batch
+//!
+//! CLI args to transfer files without the terminal UI
+
+use std::path::PathBuf;
+
+use super::{Args, RemoteArgs};
+use crate::filetransfer::FileTransferParams;
+
+/// Direction of a batch transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferDirection {
+    Download,
+    Upload,
+}
+
+/// Transfer to run without the terminal UI, then exit
+#[derive(Debug)]
+pub struct BatchTransfer {
+    pub direction: TransferDirection,
+    /// Remote to transfer files from or to
+    pub remote: FileTransferParams,
+    /// Path of the file to transfer on the remote
+    pub remote_path: PathBuf,
+    /// Path of the file to transfer on the local host
+    pub local_path: PathBuf,
+    /// Whether directories are transferred with their content
+    pub recursive: bool,
+}
+
+impl BatchTransfer {
+    /// Returns whether the args ask for a batch transfer
+    pub fn is_requested(args: &Args) -> bool {
+        args.download.is_some() || args.upload.is_some()
+    }
+}
+
+impl TryFrom<&Args> for BatchTransfer {
+    type Error = String;
+
+    fn try_from(args: &Args) -> Result<Self, Self::Error> {
+        if !args.bookmark.is_empty() || !args.ssh_host.is_empty() || args.bridge_password.is_some()
+        {
+            return Err(
+                "Only a remote address can be used with --download and --upload".to_string(),
+            );
+        }
+        let (direction, source) = match (&args.download, &args.upload) {
+            (Some(source), None) => (TransferDirection::Download, source),
+            (None, Some(source)) => (TransferDirection::Upload, source),
+            (Some(_), Some(_)) => {
+                return Err("--download and --upload can't be used together".to_string());
+            }
+            (None, None) => return Err("Either --download or --upload is required".to_string()),
+        };
+        let [destination] = args.positional.as_slice() else {
+            return Err("A batch transfer requires exactly one destination".to_string());
+        };
+        let (address, local_path) = match direction {
+            TransferDirection::Download => (source, destination),
+            TransferDirection::Upload => (destination, source),
+        };
+
+        let mut remote = RemoteArgs::parse_remote_address(address)?;
+        let remote_path = remote.remote_path.take().ok_or_else(|| {
+            format!("The remote address must contain the path to transfer (e.g. `{address}:/path`)")
+        })?;
+        if let Some(password) = RemoteArgs::resolve_passwords(args)?.into_iter().next() {
+            remote.params.set_default_secret(password);
+        }
+
+        Ok(Self {
+            direction,
+            remote,
+            remote_path,
+            local_path: PathBuf::from(local_path),
+            recursive: args.recursive,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::filetransfer::FileTransferProtocol;
+
+    #[test]
+    fn should_parse_download() {
+        let args = Args {
+            download: Some("sftp://omar@localhost:2222:/tmp/file.txt".to_string()),
+            password: vec!["secret".to_string()],
+            recursive: true,
+            positional: vec!["./local/".to_string()],
+            ..Default::default()
+        };
+        let transfer = BatchTransfer::try_from(&args).unwrap();
+        assert_eq!(transfer.direction, TransferDirection::Download);
+        assert_eq!(transfer.remote.protocol, FileTransferProtocol::Sftp);
+        let params = transfer.remote.params.generic_params().unwrap();
+        assert_eq!(params.address.as_str(), "localhost");
+        assert_eq!(params.port, 2222);
+        assert_eq!(params.username.as_deref(), Some("omar"));
+        assert_eq!(params.password.as_deref(), Some("secret"));
+        assert_eq!(transfer.remote_path, PathBuf::from("/tmp/file.txt"));
+        assert_eq!(transfer.local_path, PathBuf::from("./local/"));
+        assert!(transfer.recursive);
+    }
+
+    #[test]
+    fn should_parse_upload() {
+        let args = Args {
+            upload: Some("./local/file.txt".to_string()),
+            positional: vec!["ftp://localhost:/tmp/".to_string()],
+            ..Default::default()
+        };
+        let transfer = BatchTransfer::try_from(&args).unwrap();
+        assert_eq!(transfer.direction, TransferDirection::Upload);
+        assert_eq!(transfer.remote.protocol, FileTransferProtocol::Ftp(false));
+        assert_eq!(transfer.remote_path, PathBuf::from("/tmp/"));
+        assert_eq!(transfer.local_path, PathBuf::from("./local/file.txt"));
+        assert!(!transfer.recursive);
+    }
+
+    #[test]
+    fn should_not_parse_bad_batch_transfer() {
+        // both directions
+        let args = Args {
+            download: Some("sftp://localhost:/tmp/file.txt".to_string()),
+            upload: Some("./file.txt".to_string()),
+            positional: vec!["./local/".to_string()],
+            ..Default::default()
+        };
+        assert!(BatchTransfer::try_from(&args).is_err());
+        // no destination
+        let args = Args {
+            download: Some("sftp://localhost:/tmp/file.txt".to_string()),
+            ..Default::default()
+        };
+        assert!(BatchTransfer::try_from(&args).is_err());
+        // no remote path
+        let args = Args {
+            download: Some("sftp://localhost".to_string()),
+            positional: vec!["./local/".to_string()],
+            ..Default::default()
+        };
+        assert!(BatchTransfer::try_from(&args).is_err());
+        // bookmark
+        let args = Args {
+            download: Some("sftp://localhost:/tmp/file.txt".to_string()),
+            bookmark: vec!["foo".to_string()],
+            positional: vec!["./local/".to_string()],
+            ..Default::default()
+        };
+        assert!(BatchTransfer::try_from(&args).is_err());
+    }
+}
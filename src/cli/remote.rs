@@ -1,11 +1,16 @@
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use ssh2_config::{ParseRule, SshConfig};
+use ssh2_config::{HostParams as SshHostParams, ParseRule, SshConfig};
 
 use super::Args;
 use crate::filetransfer::params::GenericProtocolParams;
 use crate::filetransfer::{FileTransferParams, FileTransferProtocol, ProtocolParams};
 use crate::utils;
+use crate::utils::ssh as ssh_utils;
+
+/// Prefix of the passwords read from an environment variable (e.g. `env:TERMSCP_PASSWORD`)
+const PASSWORD_ENV_PREFIX: &str = "env:";
 
 /// Address type
 enum AddrType {
@@ -42,11 +47,25 @@ impl TryFrom<&Args> for RemoteArgs {
         if total_hosts > 3 {
             return Err("Too many arguments".to_string());
         }
+        let passwords = Self::resolve_passwords(args)?;
+        let bridge_password = args
+            .bridge_password
+            .as_deref()
+            .map(Self::resolve_password)
+            .transpose()?;
+        if bridge_password.is_some() && passwords.len() > 1 {
+            return Err(
+                "Only the password of the remote can be provided with -P when using --bridge-password"
+                    .to_string(),
+            );
+        }
 
         // parse bookmark first
         let last_item_index = total_hosts.checked_sub(1).unwrap_or_default();
 
         let mut hosts = vec![];
+        // host to jump through to reach a SSH host (`ProxyJump`), which becomes the host bridge
+        let mut jump_host = None;
 
         for (i, (addr_type, arg)) in args
             .bookmark
@@ -56,8 +75,11 @@ impl TryFrom<&Args> for RemoteArgs {
             .chain(args.positional.iter().map(|x| (AddrType::Address, x)))
             .enumerate()
         {
-            // check if has password
-            let password = args.password.get(i).cloned();
+            // check if has password; with a bridge password, passwords are set once the legs are known
+            let password = match bridge_password {
+                Some(_) => None,
+                None => passwords.get(i).cloned(),
+            };
 
             // check if is last item and so a possible local dir
             if i == last_item_index && Path::new(arg).exists() {
@@ -69,8 +91,19 @@ impl TryFrom<&Args> for RemoteArgs {
                 AddrType::Address => Self::parse_remote_address(arg)
                     .map(|x| Remote::Host(HostParams::new(x, password)))?,
                 AddrType::Bookmark => Remote::Bookmark(BookmarkParams::new(arg, password.as_ref())),
-                AddrType::SshHost => Self::parse_ssh_host(arg)
-                    .map(|x| Remote::Host(HostParams::new(x, password)))?,
+                AddrType::SshHost => {
+                    let (params, proxy_jump) =
+                        Self::parse_ssh_host(arg, args.ssh_config.as_deref())?;
+                    if let Some(jump) = proxy_jump {
+                        if jump_host.is_some() {
+                            return Err(
+                                "Only one SSH host can jump through another host".to_string()
+                            );
+                        }
+                        jump_host = Some(Remote::Host(HostParams::new(jump, None::<String>)));
+                    }
+                    Remote::Host(HostParams::new(params, password))
+                }
             };
 
             // set remote
@@ -78,75 +111,282 @@ impl TryFrom<&Args> for RemoteArgs {
         }
 
         // set args based on hosts len
-        if hosts.len() == 1 {
+        if let Some(jump_host) = jump_host {
+            if hosts.len() > 1 {
+                return Err(
+                    "A SSH host with ProxyJump can't be used with another host bridge".to_string(),
+                );
+            }
+            remote_args.host_bridge = jump_host;
+            remote_args.remote = hosts.pop().unwrap();
+        } else if hosts.len() == 1 {
             remote_args.remote = hosts.pop().unwrap();
         } else if hosts.len() == 2 {
             remote_args.host_bridge = hosts.pop().unwrap();
             remote_args.remote = hosts.pop().unwrap();
         }
 
+        // set password for each leg
+        if let Some(bridge_password) = &bridge_password {
+            if remote_args.host_bridge.is_none() {
+                return Err("--bridge-password requires a host bridge".to_string());
+            }
+            remote_args
+                .host_bridge
+                .set_password(Some(bridge_password.clone()));
+            remote_args.remote.set_password(passwords.first().cloned());
+        }
+
+        // set connect timeout and keepalive; bookmarks keep their own
+        for remote in [&mut remote_args.host_bridge, &mut remote_args.remote] {
+            if let Some(params) = remote.generic_params_mut() {
+                if let Some(timeout) = args.timeout {
+                    params.connect_timeout = Some(Duration::from_secs(timeout));
+                }
+                if let Some(keepalive) = args.keepalive {
+                    params.keepalive_secs = keepalive;
+                }
+            }
+        }
+
         Ok(remote_args)
     }
 }
 
 impl RemoteArgs {
+    /// Get the passwords of the hosts, in the order of the arguments, from `-P` or from `--password-file`
+    pub(super) fn resolve_passwords(args: &Args) -> Result<Vec<String>, String> {
+        if args.password_file.is_empty() {
+            return args
+                .password
+                .iter()
+                .map(|x| Self::resolve_password(x))
+                .collect();
+        }
+        if !args.password.is_empty() {
+            return Err("Passwords can't be provided both with -P and --password-file".to_string());
+        }
+        args.password_file
+            .iter()
+            .map(|x| Self::read_password_file(x))
+            .collect()
+    }
+
+    /// Resolve a password provided from CLI; `env:VAR` is read from the VAR environment variable
+    fn resolve_password(password: &str) -> Result<String, String> {
+        match password.strip_prefix(PASSWORD_ENV_PREFIX) {
+            Some(var) => std::env::var(var).map_err(|e| {
+                format!("Could not read password from environment variable {var}: {e}")
+            }),
+            None => Ok(password.to_string()),
+        }
+    }
+
+    /// Read the password from the first line of the file at `path`
+    fn read_password_file(path: &Path) -> Result<String, String> {
+        // logging isn't set up yet, so the warning goes to stderr
+        #[cfg(unix)]
+        if Self::is_world_readable(path) {
+            eprintln!(
+                "Warning: password file {} is readable by anyone; consider `chmod 600` on it",
+                path.display()
+            );
+        }
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Could not read password file {}: {e}", path.display()))?;
+        Ok(content.lines().next().unwrap_or_default().to_string())
+    }
+
+    /// Returns whether the file at `path` can be read by any user
+    #[cfg(unix)]
+    fn is_world_readable(path: &Path) -> bool {
+        use std::os::unix::fs::PermissionsExt as _;
+
+        std::fs::metadata(path)
+            .map(|x| x.permissions().mode() & 0o004 != 0)
+            .unwrap_or(false)
+    }
+
     /// Parse remote address
-    fn parse_remote_address(remote: &str) -> Result<FileTransferParams, String> {
+    pub(super) fn parse_remote_address(remote: &str) -> Result<FileTransferParams, String> {
         utils::parser::parse_remote_opt(remote).map_err(|e| format!("Bad address option: {e}"))
     }
 
-    /// Parse SSH config host alias (e.g., "hostA:/path/to/dir" or just "hostA")
-    fn parse_ssh_host(host_arg: &str) -> Result<FileTransferParams, String> {
+    /// Parse SSH config host alias (e.g., "user@hostA:/path/to/dir", "hostA:/path/to/dir" or just "hostA")
+    fn parse_ssh_host(
+        host_arg: &str,
+        ssh_config_path: Option<&Path>,
+    ) -> Result<(FileTransferParams, Option<FileTransferParams>), String> {
+        let ssh_config = Self::load_ssh_config(ssh_config_path)?;
+
+        Self::ssh_host_params(&ssh_config, host_arg)
+    }
+
+    /// Load the SSH config at `path`, or from the default location if unset, following its includes
+    fn load_ssh_config(path: Option<&Path>) -> Result<SshConfig, String> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => dirs::home_dir()
+                .ok_or_else(|| "Could not find the home directory".to_string())?
+                .join(".ssh")
+                .join("config"),
+        };
+        let config = ssh_utils::read_ssh2_config_with_includes(&path)
+            .map_err(|e| format!("Could not read SSH config: {e}"))?;
+        // unsupported fields are kept to detect `ProxyCommand`
+        SshConfig::default()
+            .parse(
+                &mut config.as_bytes(),
+                ParseRule::ALLOW_UNKNOWN_FIELDS | ParseRule::ALLOW_UNSUPPORTED_FIELDS,
+            )
+            .map_err(|e| format!("Could not parse SSH config {}: {e}", path.display()))
+    }
+
+    /// Resolve the SSH config host alias in `host_arg` with `ssh_config`.
+    /// The params of all the hosts matching the alias are merged like ssh does: the first value of each param wins.
+    ///
+    /// The username before `@`, if any, takes precedence over the `User` of the host.
+    /// Returns the params of the host and of the host to jump through (`ProxyJump`), if any
+    fn ssh_host_params(
+        ssh_config: &SshConfig,
+        host_arg: &str,
+    ) -> Result<(FileTransferParams, Option<FileTransferParams>), String> {
         // Parse host:path format
-        let (host_alias, remote_path) = if let Some(colon_pos) = host_arg.find(':') {
-            let alias = &host_arg[..colon_pos];
+        let (host, remote_path) = if let Some(colon_pos) = host_arg.find(':') {
+            let host = &host_arg[..colon_pos];
             let path = &host_arg[colon_pos + 1..];
-            (alias, if path.is_empty() { None } else { Some(path) })
+            (host, if path.is_empty() { None } else { Some(path) })
         } else {
             (host_arg, None)
         };
-
-        // Load SSH config from default location
-        let ssh_config = SshConfig::parse_default_file(ParseRule::ALLOW_UNKNOWN_FIELDS)
-            .map_err(|e| format!("Could not parse SSH config: {e}"))?;
+        let (username, host_alias) = Self::split_ssh_username(host);
 
         // Find the host
-        let host = ssh_config
-            .get_hosts()
-            .iter()
-            .find(|h| {
-                h.pattern
-                    .iter()
-                    .any(|p| !p.negated && p.pattern == host_alias)
-            })
-            .ok_or_else(|| format!("SSH host '{}' not found in ~/.ssh/config", host_alias))?;
+        if !Self::is_ssh_host_defined(ssh_config, host_alias) {
+            return Err(format!("SSH host '{host_alias}' not found in SSH config"));
+        }
+        let host = ssh_config.query(host_alias);
+        Self::check_proxy_command(&host, host_alias)?;
+
+        let mut params = Self::ssh_host_sftp_params(Some(&host), host_alias, username, None);
+        // Set remote path if specified
+        if let Some(path) = remote_path {
+            params.remote_path = Some(PathBuf::from(path));
+        }
+
+        let proxy_jump = match host.proxy_jump.as_deref() {
+            None | Some([]) => None,
+            Some([jump]) if jump.eq_ignore_ascii_case("none") => None,
+            Some([jump]) => Some(Self::proxy_jump_params(ssh_config, jump)?),
+            Some(_) => {
+                return Err(format!(
+                    "SSH host '{host_alias}' jumps through several hosts with ProxyJump, which is not supported"
+                ));
+            }
+        };
+
+        Ok((params, proxy_jump))
+    }
+
+    /// Resolve the host to jump through, written as `[user@]host[:port]` in `ProxyJump`.
+    ///
+    /// The host may be an alias of `ssh_config`, but it can't jump through another host
+    fn proxy_jump_params(ssh_config: &SshConfig, jump: &str) -> Result<FileTransferParams, String> {
+        let (username, host) = Self::split_ssh_username(jump);
+        let (host_alias, port) = match host.rsplit_once(':') {
+            Some((host, port)) => {
+                let port = port
+                    .parse::<u16>()
+                    .map_err(|_| format!("Bad port in ProxyJump '{jump}'"))?;
+                (host, Some(port))
+            }
+            None => (host, None),
+        };
+        let host =
+            Self::is_ssh_host_defined(ssh_config, host_alias).then(|| ssh_config.query(host_alias));
+        if let Some(host) = host.as_ref() {
+            Self::check_proxy_command(host, host_alias)?;
+            if host.proxy_jump.is_some() {
+                return Err(format!(
+                    "Jump host '{host_alias}' jumps through another host with ProxyJump, which is not supported"
+                ));
+            }
+        }
+
+        Ok(Self::ssh_host_sftp_params(
+            host.as_ref(),
+            host_alias,
+            username,
+            port,
+        ))
+    }
 
+    /// Split the username before `@`, if any, from `host`; the username may contain `@` itself
+    fn split_ssh_username(host: &str) -> (Option<String>, &str) {
+        match host.rsplit_once('@') {
+            Some((user, alias)) if !user.is_empty() => (Some(user.to_string()), alias),
+            Some((_, alias)) => (None, alias),
+            None => (None, host),
+        }
+    }
+
+    /// Returns whether a `Host` of `ssh_config` matches `host_alias`, as ssh does:
+    /// patterns may have `*` and `?` wildcards, and a matching negated pattern excludes the host
+    fn is_ssh_host_defined(ssh_config: &SshConfig, host_alias: &str) -> bool {
+        // the params set before any `Host` are held by an implicit host, which matches any alias
+        ssh_config.intersecting_hosts(host_alias).count() > 1
+    }
+
+    /// `ProxyCommand` can't be run: report it instead of connecting directly
+    fn check_proxy_command(host: &SshHostParams, host_alias: &str) -> Result<(), String> {
+        if host.unsupported_fields.contains_key("proxycommand") {
+            Err(format!(
+                "ProxyCommand of SSH host '{host_alias}' is not supported; use ProxyJump instead"
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Make the SFTP params of `host`, which may be missing from the SSH config.
+    ///
+    /// `username` and `port`, if any, take precedence over the ones of the host
+    fn ssh_host_sftp_params(
+        params: Option<&SshHostParams>,
+        host_alias: &str,
+        username: Option<String>,
+        port: Option<u16>,
+    ) -> FileTransferParams {
         // Extract connection parameters
-        let address = host
-            .params
-            .host_name
-            .as_deref()
+        let address = params
+            .and_then(|x| x.host_name.as_deref())
             .unwrap_or(host_alias)
             .to_string();
-        let port = host.params.port.unwrap_or(22);
-        let username = host.params.user.clone();
+        let port = port.or(params.and_then(|x| x.port)).unwrap_or(22);
+        let username = username.or_else(|| params.and_then(|x| x.user.clone()));
+        let keepalive_secs = params
+            .and_then(|x| x.server_alive_interval)
+            .map(|x| x.as_secs())
+            .unwrap_or_default();
+        // Take the first identity file which exists, as ssh does
+        let identity_file = params
+            .and_then(|x| x.identity_file.as_ref())
+            .into_iter()
+            .flatten()
+            .map(|x| PathBuf::from(shellexpand::tilde(&x.to_string_lossy()).as_ref()))
+            .find(|x| x.exists());
 
-        let mut params = FileTransferParams::new(
+        FileTransferParams::new(
             FileTransferProtocol::Sftp,
             ProtocolParams::Generic(
                 GenericProtocolParams::default()
                     .address(address)
                     .port(port)
-                    .username(username),
+                    .username(username)
+                    .identity_file(identity_file)
+                    .keepalive_secs(keepalive_secs),
             ),
-        );
-
-        // Set remote path if specified
-        if let Some(path) = remote_path {
-            params.remote_path = Some(PathBuf::from(path));
-        }
-
-        Ok(params)
+        )
     }
 }
 
@@ -166,6 +406,30 @@ impl Remote {
     pub fn is_none(&self) -> bool {
         matches!(self, Self::None)
     }
+
+    /// Set the password specified in arguments
+    fn set_password(&mut self, password: Option<String>) {
+        match self {
+            Self::Bookmark(params) => params.password = password,
+            Self::Host(params) => params.password = password,
+            Self::None => {}
+        }
+    }
+
+    /// Get the generic params of a host argument, if any
+    fn generic_params_mut(&mut self) -> Option<&mut GenericProtocolParams> {
+        match self {
+            Self::Host(HostParams {
+                file_transfer_params:
+                    FileTransferParams {
+                        params: ProtocolParams::Generic(params),
+                        ..
+                    },
+                ..
+            }) => Some(params),
+            _ => None,
+        }
+    }
 }
 
 /// Bookmark parameters
@@ -210,6 +474,68 @@ mod test {
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::utils::test_helpers;
+
+    fn ssh_config() -> SshConfig {
+        let mut reader = std::io::BufReader::new(
+            r#"
+Host alias
+    HostName 10.0.0.1
+    Port 2222
+    User omar
+
+Host guarded
+    HostName 10.0.0.2
+    ProxyJump bastion
+
+Host bastion
+    HostName 10.0.0.254
+    User jumper
+
+Host gateway-guarded
+    ProxyJump me@gateway:2200
+
+Host chained
+    ProxyJump guarded
+
+Host multi
+    ProxyJump bastion,gateway
+
+Host command
+    ProxyCommand ssh -W %h:%p bastion
+
+Host dev-* !dev-secret
+    Port 2201
+    User developer
+"#
+            .as_bytes(),
+        );
+        SshConfig::default()
+            .parse(
+                &mut reader,
+                ParseRule::ALLOW_UNKNOWN_FIELDS | ParseRule::ALLOW_UNSUPPORTED_FIELDS,
+            )
+            .unwrap()
+    }
+
+    fn password(remote: &Remote) -> Option<&str> {
+        match remote {
+            Remote::Bookmark(params) => params.password.as_deref(),
+            Remote::Host(params) => params.password.as_deref(),
+            Remote::None => None,
+        }
+    }
+
+    fn connect_timeout(remote: &Remote) -> Option<Duration> {
+        match remote {
+            Remote::Host(params) => params
+                .file_transfer_params
+                .params
+                .generic_params()
+                .and_then(|x| x.connect_timeout),
+            _ => None,
+        }
+    }
 
     #[test]
     fn test_should_make_remote_args_from_args_one_remote() {
@@ -224,6 +550,26 @@ mod test {
         assert_eq!(remote_args.local_dir, None);
     }
 
+    #[test]
+    fn test_should_make_remote_args_from_ipv6_remote() {
+        let args = Args {
+            positional: vec!["sftp://[2001:db8::1]:22/path".to_string()],
+            ..Default::default()
+        };
+
+        let remote_args = RemoteArgs::try_from(&args).unwrap();
+        let Remote::Host(host) = remote_args.remote else {
+            panic!("expected a host");
+        };
+        let generic = host.file_transfer_params.params.generic_params().unwrap();
+        assert_eq!(generic.address.as_str(), "[2001:db8::1]");
+        assert_eq!(generic.port, 22);
+        assert_eq!(
+            host.file_transfer_params.remote_path,
+            Some(PathBuf::from("/path"))
+        );
+    }
+
     #[test]
     fn test_should_make_remote_args_from_args_two_remotes() {
         let args = Args {
@@ -255,6 +601,194 @@ mod test {
         assert_eq!(remote_args.local_dir, Some(PathBuf::from("/home")));
     }
 
+    #[test]
+    fn test_should_apply_passwords_in_order_of_arguments() {
+        let args = Args {
+            positional: vec!["scp://host1".to_string(), "scp://host2".to_string()],
+            password: vec!["secret1".to_string(), "secret2".to_string()],
+            ..Default::default()
+        };
+
+        let remote_args = RemoteArgs::try_from(&args).unwrap();
+        assert_eq!(password(&remote_args.remote), Some("secret1"));
+        assert_eq!(password(&remote_args.host_bridge), Some("secret2"));
+    }
+
+    #[test]
+    fn test_should_read_passwords_from_env() {
+        // SAFETY: the variables are only used by this test
+        unsafe {
+            std::env::set_var("TERMSCP_TEST_CLI_PASSWORD", "secret1");
+            std::env::set_var("TERMSCP_TEST_CLI_BRIDGE_PASSWORD", "secret2");
+        }
+        let args = Args {
+            positional: vec!["scp://host1".to_string(), "scp://host2".to_string()],
+            password: vec!["env:TERMSCP_TEST_CLI_PASSWORD".to_string()],
+            bridge_password: Some("env:TERMSCP_TEST_CLI_BRIDGE_PASSWORD".to_string()),
+            ..Default::default()
+        };
+
+        let remote_args = RemoteArgs::try_from(&args).unwrap();
+        assert_eq!(password(&remote_args.remote), Some("secret1"));
+        assert_eq!(password(&remote_args.host_bridge), Some("secret2"));
+        // Missing variable
+        let args = Args {
+            positional: vec!["scp://host1".to_string()],
+            password: vec!["env:TERMSCP_TEST_CLI_MISSING_PASSWORD".to_string()],
+            ..Default::default()
+        };
+        assert!(RemoteArgs::try_from(&args).is_err());
+    }
+
+    #[test]
+    fn test_should_read_passwords_from_files() {
+        let file1 = test_helpers::create_sample_file_with_content("secret1\nignored");
+        let file2 = test_helpers::create_sample_file_with_content("secret2");
+        let args = Args {
+            bookmark: vec!["foo".to_string()],
+            positional: vec!["scp://host1".to_string()],
+            password_file: vec![file1.path().to_path_buf(), file2.path().to_path_buf()],
+            ..Default::default()
+        };
+
+        let remote_args = RemoteArgs::try_from(&args).unwrap();
+        assert_eq!(password(&remote_args.remote), Some("secret1"));
+        assert_eq!(password(&remote_args.host_bridge), Some("secret2"));
+        // Not both -P and --password-file
+        let args = Args {
+            positional: vec!["scp://host1".to_string()],
+            password: vec!["secret".to_string()],
+            password_file: vec![file1.path().to_path_buf()],
+            ..Default::default()
+        };
+        assert!(RemoteArgs::try_from(&args).is_err());
+        // Missing file
+        let args = Args {
+            positional: vec!["scp://host1".to_string()],
+            password_file: vec![PathBuf::from("/this/file/does/not/exist")],
+            ..Default::default()
+        };
+        assert!(RemoteArgs::try_from(&args).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_should_tell_whether_password_file_is_world_readable() {
+        use std::os::unix::fs::PermissionsExt as _;
+
+        let file = test_helpers::create_sample_file_with_content("secret");
+        std::fs::set_permissions(file.path(), std::fs::Permissions::from_mode(0o600)).unwrap();
+        assert!(!RemoteArgs::is_world_readable(file.path()));
+        std::fs::set_permissions(file.path(), std::fs::Permissions::from_mode(0o644)).unwrap();
+        assert!(RemoteArgs::is_world_readable(file.path()));
+    }
+
+    #[test]
+    fn test_should_apply_bridge_password_to_host_bridge() {
+        let args = Args {
+            positional: vec!["scp://host1".to_string(), "scp://host2".to_string()],
+            password: vec!["remote-secret".to_string()],
+            bridge_password: Some("bridge-secret".to_string()),
+            ..Default::default()
+        };
+
+        let remote_args = RemoteArgs::try_from(&args).unwrap();
+        assert_eq!(password(&remote_args.remote), Some("remote-secret"));
+        assert_eq!(password(&remote_args.host_bridge), Some("bridge-secret"));
+    }
+
+    #[test]
+    fn test_should_apply_bridge_password_with_bookmark_and_remote() {
+        let args = Args {
+            bookmark: vec!["foo".to_string()],
+            positional: vec!["scp://host1".to_string()],
+            bridge_password: Some("bridge-secret".to_string()),
+            ..Default::default()
+        };
+
+        let remote_args = RemoteArgs::try_from(&args).unwrap();
+        assert!(matches!(remote_args.host_bridge, Remote::Host(_)));
+        assert_eq!(password(&remote_args.host_bridge), Some("bridge-secret"));
+        assert!(matches!(remote_args.remote, Remote::Bookmark(_)));
+        assert_eq!(password(&remote_args.remote), None);
+    }
+
+    #[test]
+    fn test_should_apply_connect_timeout_to_hosts() {
+        let args = Args {
+            positional: vec!["scp://host1".to_string(), "ftp://host2".to_string()],
+            timeout: Some(5),
+            ..Default::default()
+        };
+
+        let remote_args = RemoteArgs::try_from(&args).unwrap();
+        assert_eq!(
+            connect_timeout(&remote_args.remote),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            connect_timeout(&remote_args.host_bridge),
+            Some(Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn test_should_apply_keepalive_to_hosts() {
+        let args = Args {
+            bookmark: vec!["foo".to_string()],
+            positional: vec!["sftp://host1".to_string()],
+            keepalive: Some(30),
+            ..Default::default()
+        };
+
+        let remote_args = RemoteArgs::try_from(&args).unwrap();
+        let Remote::Host(host_bridge) = &remote_args.host_bridge else {
+            panic!("host bridge is not a host");
+        };
+        let generic = host_bridge
+            .file_transfer_params
+            .params
+            .generic_params()
+            .unwrap();
+        assert_eq!(generic.keepalive_secs, 30);
+        assert!(matches!(remote_args.remote, Remote::Bookmark(_)));
+    }
+
+    #[test]
+    fn test_should_not_set_connect_timeout_without_timeout_arg() {
+        let args = Args {
+            positional: vec!["sftp://host1".to_string()],
+            ..Default::default()
+        };
+
+        let remote_args = RemoteArgs::try_from(&args).unwrap();
+        assert!(matches!(remote_args.remote, Remote::Host(_)));
+        assert_eq!(connect_timeout(&remote_args.remote), None);
+    }
+
+    #[test]
+    fn test_should_not_make_remote_args_with_bridge_password_and_many_passwords() {
+        let args = Args {
+            positional: vec!["scp://host1".to_string(), "scp://host2".to_string()],
+            password: vec!["secret1".to_string(), "secret2".to_string()],
+            bridge_password: Some("bridge-secret".to_string()),
+            ..Default::default()
+        };
+
+        assert!(RemoteArgs::try_from(&args).is_err());
+    }
+
+    #[test]
+    fn test_should_not_make_remote_args_with_bridge_password_without_bridge() {
+        let args = Args {
+            positional: vec!["scp://host1".to_string()],
+            bridge_password: Some("bridge-secret".to_string()),
+            ..Default::default()
+        };
+
+        assert!(RemoteArgs::try_from(&args).is_err());
+    }
+
     #[test]
     fn test_should_make_remote_args_from_args_one_bookmarks() {
         let args = Args {
@@ -326,4 +860,249 @@ mod test {
         assert!(matches!(remote_args.remote, Remote::Bookmark(_)));
         assert_eq!(remote_args.local_dir, Some(PathBuf::from("/home")));
     }
+
+    #[test]
+    fn test_should_parse_ssh_host_with_user() {
+        let (params, _) = RemoteArgs::ssh_host_params(&ssh_config(), "me@alias").unwrap();
+        let generic = params.params.generic_params().unwrap();
+        assert_eq!(generic.address.as_str(), "10.0.0.1");
+        assert_eq!(generic.port, 2222);
+        assert_eq!(generic.username.as_deref(), Some("me"));
+        assert_eq!(params.remote_path, None);
+    }
+
+    #[test]
+    fn test_should_parse_ssh_host_with_user_and_path() {
+        let (params, _) = RemoteArgs::ssh_host_params(&ssh_config(), "me@alias:/tmp").unwrap();
+        let generic = params.params.generic_params().unwrap();
+        assert_eq!(generic.username.as_deref(), Some("me"));
+        assert_eq!(params.remote_path, Some(PathBuf::from("/tmp")));
+    }
+
+    #[test]
+    fn test_should_parse_ssh_host_with_path() {
+        let (params, _) = RemoteArgs::ssh_host_params(&ssh_config(), "alias:/tmp").unwrap();
+        let generic = params.params.generic_params().unwrap();
+        assert_eq!(generic.address.as_str(), "10.0.0.1");
+        assert_eq!(generic.username.as_deref(), Some("omar"));
+        assert_eq!(params.remote_path, Some(PathBuf::from("/tmp")));
+        // Plain host
+        let (params, _) = RemoteArgs::ssh_host_params(&ssh_config(), "alias").unwrap();
+        let generic = params.params.generic_params().unwrap();
+        assert_eq!(generic.username.as_deref(), Some("omar"));
+        assert_eq!(params.remote_path, None);
+    }
+
+    #[test]
+    fn test_should_parse_ssh_host_identity_file() {
+        let identity_file = test_helpers::create_sample_file();
+        let config = format!(
+            r#"
+Host alias
+    HostName 10.0.0.1
+    IdentityFile /this/key/does/not/exist
+    IdentityFile {}
+"#,
+            identity_file.path().display()
+        );
+        let mut reader = std::io::BufReader::new(config.as_bytes());
+        let config = SshConfig::default()
+            .parse(&mut reader, ParseRule::ALLOW_UNKNOWN_FIELDS)
+            .unwrap();
+        let (params, _) = RemoteArgs::ssh_host_params(&config, "alias").unwrap();
+        let generic = params.params.generic_params().unwrap();
+        assert_eq!(generic.identity_file.as_deref(), Some(identity_file.path()));
+        // No identity file
+        let (params, _) = RemoteArgs::ssh_host_params(&ssh_config(), "alias").unwrap();
+        let generic = params.params.generic_params().unwrap();
+        assert!(generic.identity_file.is_none());
+    }
+
+    #[test]
+    fn test_should_parse_ssh_host_keepalive() {
+        let mut reader = std::io::BufReader::new(
+            r#"
+Host alias
+    HostName 10.0.0.1
+    ServerAliveInterval 45
+
+Host bastion
+    ServerAliveInterval 20
+
+Host guarded
+    HostName 10.0.0.2
+    ProxyJump bastion
+"#
+            .as_bytes(),
+        );
+        let config = SshConfig::default()
+            .parse(&mut reader, ParseRule::ALLOW_UNKNOWN_FIELDS)
+            .unwrap();
+        let (params, _) = RemoteArgs::ssh_host_params(&config, "alias").unwrap();
+        assert_eq!(params.params.generic_params().unwrap().keepalive_secs, 45);
+        // The host to jump through has its own interval
+        let (params, jump) = RemoteArgs::ssh_host_params(&config, "guarded").unwrap();
+        assert_eq!(params.params.generic_params().unwrap().keepalive_secs, 0);
+        let jump = jump.unwrap();
+        assert_eq!(jump.params.generic_params().unwrap().keepalive_secs, 20);
+        // No interval
+        let (params, _) = RemoteArgs::ssh_host_params(&ssh_config(), "alias").unwrap();
+        assert_eq!(params.params.generic_params().unwrap().keepalive_secs, 0);
+    }
+
+    #[test]
+    fn test_should_parse_ssh_host_matching_pattern() {
+        let (params, _) = RemoteArgs::ssh_host_params(&ssh_config(), "dev-01:/srv").unwrap();
+        let generic = params.params.generic_params().unwrap();
+        // Without HostName, the alias is the address
+        assert_eq!(generic.address.as_str(), "dev-01");
+        assert_eq!(generic.port, 2201);
+        assert_eq!(generic.username.as_deref(), Some("developer"));
+        assert_eq!(params.remote_path, Some(PathBuf::from("/srv")));
+        // Excluded by the negated pattern
+        assert!(RemoteArgs::ssh_host_params(&ssh_config(), "dev-secret").is_err());
+        assert!(RemoteArgs::ssh_host_params(&ssh_config(), "prod-01").is_err());
+    }
+
+    #[test]
+    fn test_should_merge_params_of_matching_ssh_hosts() {
+        let mut reader = std::io::BufReader::new(
+            r#"
+Host *
+    User everyone
+    ServerAliveInterval 30
+
+Host alias
+    HostName 10.0.0.1
+    Port 2222
+    User omar
+    ProxyJump bastion
+
+Host bastion
+    HostName 10.0.0.254
+"#
+            .as_bytes(),
+        );
+        let ssh_config = SshConfig::default()
+            .parse(&mut reader, ParseRule::ALLOW_UNKNOWN_FIELDS)
+            .unwrap();
+        let (params, proxy_jump) = RemoteArgs::ssh_host_params(&ssh_config, "alias").unwrap();
+        let generic = params.params.generic_params().unwrap();
+        // the first value of each param wins, even if set by a wildcard host
+        assert_eq!(generic.address.as_str(), "10.0.0.1");
+        assert_eq!(generic.port, 2222);
+        assert_eq!(generic.username.as_deref(), Some("everyone"));
+        assert_eq!(generic.keepalive_secs, 30);
+        let proxy_jump = proxy_jump.unwrap();
+        let jump = proxy_jump.params.generic_params().unwrap();
+        assert_eq!(jump.address.as_str(), "10.0.0.254");
+        assert_eq!(jump.username.as_deref(), Some("everyone"));
+    }
+
+    #[test]
+    fn test_should_not_parse_unknown_ssh_host() {
+        assert!(RemoteArgs::ssh_host_params(&ssh_config(), "me@unknown:/tmp").is_err());
+    }
+
+    #[test]
+    fn test_should_resolve_ssh_host_with_custom_config() {
+        let ssh_config = test_helpers::create_sample_file_with_content(
+            r#"
+Host custom
+    HostName 10.0.0.10
+    Port 2022
+    User omar
+"#,
+        );
+        let args = Args {
+            ssh_host: vec!["custom:/tmp".to_string()],
+            ssh_config: Some(ssh_config.path().to_path_buf()),
+            ..Default::default()
+        };
+
+        let remote_args = RemoteArgs::try_from(&args).unwrap();
+        let Remote::Host(host) = remote_args.remote else {
+            panic!("expected a host");
+        };
+        let generic = host.file_transfer_params.params.generic_params().unwrap();
+        assert_eq!(generic.address.as_str(), "10.0.0.10");
+        assert_eq!(generic.port, 2022);
+        assert_eq!(generic.username.as_deref(), Some("omar"));
+        assert_eq!(
+            host.file_transfer_params.remote_path,
+            Some(PathBuf::from("/tmp"))
+        );
+        // Missing config
+        let args = Args {
+            ssh_host: vec!["custom".to_string()],
+            ssh_config: Some(PathBuf::from("/this/ssh/config/does/not/exist")),
+            ..Default::default()
+        };
+        assert!(RemoteArgs::try_from(&args).is_err());
+    }
+
+    #[test]
+    fn test_should_resolve_ssh_host_from_included_config() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(tmp_dir.path().join("config.d")).unwrap();
+        std::fs::write(
+            tmp_dir.path().join("config.d").join("lab"),
+            "Host included\n    HostName 10.0.0.20\n",
+        )
+        .unwrap();
+        let ssh_config = tmp_dir.path().join("config");
+        std::fs::write(
+            &ssh_config,
+            "Include config.d/*\n\nHost alias\n    HostName 10.0.0.1\n",
+        )
+        .unwrap();
+        let args = Args {
+            ssh_host: vec!["included".to_string()],
+            ssh_config: Some(ssh_config),
+            ..Default::default()
+        };
+
+        let remote_args = RemoteArgs::try_from(&args).unwrap();
+        let Remote::Host(host) = remote_args.remote else {
+            panic!("expected a host");
+        };
+        let generic = host.file_transfer_params.params.generic_params().unwrap();
+        assert_eq!(generic.address.as_str(), "10.0.0.20");
+    }
+
+    #[test]
+    fn test_should_parse_ssh_host_with_proxy_jump() {
+        let (params, jump) = RemoteArgs::ssh_host_params(&ssh_config(), "guarded:/srv").unwrap();
+        let generic = params.params.generic_params().unwrap();
+        assert_eq!(generic.address.as_str(), "10.0.0.2");
+        assert_eq!(params.remote_path, Some(PathBuf::from("/srv")));
+        // The jump host is resolved from the ssh config too
+        let jump = jump.unwrap();
+        let generic = jump.params.generic_params().unwrap();
+        assert_eq!(generic.address.as_str(), "10.0.0.254");
+        assert_eq!(generic.port, 22);
+        assert_eq!(generic.username.as_deref(), Some("jumper"));
+        assert_eq!(jump.remote_path, None);
+        // Without ProxyJump
+        let (_, jump) = RemoteArgs::ssh_host_params(&ssh_config(), "alias").unwrap();
+        assert!(jump.is_none());
+    }
+
+    #[test]
+    fn test_should_parse_proxy_jump_with_user_and_port() {
+        let (_, jump) = RemoteArgs::ssh_host_params(&ssh_config(), "gateway-guarded").unwrap();
+        let jump = jump.unwrap();
+        let generic = jump.params.generic_params().unwrap();
+        assert_eq!(generic.address.as_str(), "gateway");
+        assert_eq!(generic.port, 2200);
+        assert_eq!(generic.username.as_deref(), Some("me"));
+    }
+
+    #[test]
+    fn test_should_not_parse_unsupported_proxies() {
+        assert!(RemoteArgs::ssh_host_params(&ssh_config(), "chained").is_err());
+        assert!(RemoteArgs::ssh_host_params(&ssh_config(), "multi").is_err());
+        let err = RemoteArgs::ssh_host_params(&ssh_config(), "command").unwrap_err();
+        assert!(err.contains("ProxyCommand"));
+    }
 }
@@ -9,6 +9,7 @@ mod smb;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
 use serde::de::Error as DeError;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -28,6 +29,18 @@ use crate::filetransfer::{FileTransferParams, FileTransferProtocol};
 pub struct UserHosts {
     pub bookmarks: HashMap<String, Bookmark>,
     pub recents: HashMap<String, Bookmark>,
+    /// Remote directories bookmarked by the user; missing in bookmark files written by older versions
+    #[serde(default)]
+    pub directories: HashMap<String, DirectoryBookmark>,
+}
+
+/// DirectoryBookmark describes a remote directory saved by the user, along with the connection to reach it
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Eq)]
+pub struct DirectoryBookmark {
+    /// Remote directory to go to once connected
+    pub directory: PathBuf,
+    /// Connection to the remote the directory lives on
+    pub connection: Bookmark,
 }
 
 /// Bookmark describes a single bookmark entry in the user hosts storage
@@ -51,6 +64,20 @@ pub struct Bookmark {
     pub remote_path: Option<PathBuf>,
     /// local folder to open at startup
     pub local_path: Option<PathBuf>,
+    /// Timeout in seconds to establish the connection with, for generic parameters
+    pub connect_timeout: Option<u64>,
+    /// Interval in seconds to send keepalives with, for SSH protocols
+    pub keepalive_secs: Option<u64>,
+    /// Remote directory which was open when the last session of the bookmark was closed
+    pub last_remote_path: Option<PathBuf>,
+    /// Local directory which was open when the last session of the bookmark was closed
+    pub last_local_path: Option<PathBuf>,
+    /// Sorting and filter of the remote explorer when the last session of the bookmark was closed
+    pub last_remote_explorer: Option<ExplorerPrefs>,
+    /// Sorting and filter of the local explorer when the last session of the bookmark was closed
+    pub last_local_explorer: Option<ExplorerPrefs>,
+    /// Group to show the bookmark under in the bookmarks list
+    pub group: Option<String>,
     /// Kube params; optional. When used other fields are empty for sure
     pub kube: Option<KubeParams>,
     /// S3 params; optional. When used other fields are empty for sure
@@ -59,6 +86,15 @@ pub struct Bookmark {
     pub smb: Option<SmbParams>,
 }
 
+/// ExplorerPrefs describes how the files of an explorer are listed
+#[derive(Clone, Deserialize, Serialize, Debug, Default, PartialEq, Eq)]
+pub struct ExplorerPrefs {
+    /// File sorting (e.g. `by_name`)
+    pub sorting: Option<String>,
+    /// Expression to filter the file names by
+    pub filter: Option<String>,
+}
+
 // -- impls
 
 impl From<FileTransferParams> for Bookmark {
@@ -76,6 +112,13 @@ impl From<FileTransferParams> for Bookmark {
                 password: params.password,
                 remote_path,
                 local_path,
+                connect_timeout: params.connect_timeout.map(|x| x.as_secs()),
+                keepalive_secs: (params.keepalive_secs > 0).then_some(params.keepalive_secs),
+                last_remote_path: None,
+                last_local_path: None,
+                last_remote_explorer: None,
+                last_local_explorer: None,
+                group: None,
                 kube: None,
                 s3: None,
                 smb: None,
@@ -88,6 +131,13 @@ impl From<FileTransferParams> for Bookmark {
                 password: None,
                 remote_path,
                 local_path,
+                connect_timeout: None,
+                keepalive_secs: None,
+                last_remote_path: None,
+                last_local_path: None,
+                last_remote_explorer: None,
+                last_local_explorer: None,
+                group: None,
                 kube: None,
                 s3: Some(S3Params::from(params)),
                 smb: None,
@@ -100,6 +150,13 @@ impl From<FileTransferParams> for Bookmark {
                 password: None,
                 remote_path,
                 local_path,
+                connect_timeout: None,
+                keepalive_secs: None,
+                last_remote_path: None,
+                last_local_path: None,
+                last_remote_explorer: None,
+                last_local_explorer: None,
+                group: None,
                 kube: Some(KubeParams::from(params)),
                 s3: None,
                 smb: None,
@@ -116,6 +173,13 @@ impl From<FileTransferParams> for Bookmark {
                 password: params.password,
                 remote_path,
                 local_path,
+                connect_timeout: None,
+                keepalive_secs: None,
+                last_remote_path: None,
+                last_local_path: None,
+                last_remote_explorer: None,
+                last_local_explorer: None,
+                group: None,
                 kube: None,
                 s3: None,
             },
@@ -127,6 +191,13 @@ impl From<FileTransferParams> for Bookmark {
                 password: Some(parms.password),
                 remote_path,
                 local_path,
+                connect_timeout: None,
+                keepalive_secs: None,
+                last_remote_path: None,
+                last_local_path: None,
+                last_remote_explorer: None,
+                last_local_explorer: None,
+                group: None,
                 kube: None,
                 s3: None,
                 smb: None,
@@ -151,7 +222,9 @@ impl From<Bookmark> for FileTransferParams {
                     .address(bookmark.address.unwrap_or_default())
                     .port(bookmark.port.unwrap_or(22))
                     .username(bookmark.username)
-                    .password(bookmark.password);
+                    .password(bookmark.password)
+                    .connect_timeout(bookmark.connect_timeout.map(Duration::from_secs))
+                    .keepalive_secs(bookmark.keepalive_secs.unwrap_or_default());
                 Self::new(bookmark.protocol, ProtocolParams::Generic(params))
             }
             FileTransferProtocol::Kube => {
@@ -242,6 +315,13 @@ mod tests {
             password: Some(String::from("password")),
             remote_path: Some(PathBuf::from("/tmp")),
             local_path: Some(PathBuf::from("/usr")),
+            connect_timeout: None,
+            keepalive_secs: None,
+            last_remote_path: None,
+            last_local_path: None,
+            last_remote_explorer: None,
+            last_local_explorer: None,
+            group: None,
             kube: None,
             s3: None,
             smb: None,
@@ -254,6 +334,13 @@ mod tests {
             password: Some(String::from("password")),
             remote_path: Some(PathBuf::from("/home")),
             local_path: Some(PathBuf::from("/usr")),
+            connect_timeout: None,
+            keepalive_secs: None,
+            last_remote_path: None,
+            last_local_path: None,
+            last_remote_explorer: None,
+            last_local_explorer: None,
+            group: None,
             kube: None,
             s3: None,
             smb: None,
@@ -262,7 +349,11 @@ mod tests {
         bookmarks.insert(String::from("test"), bookmark);
         let mut recents: HashMap<String, Bookmark> = HashMap::with_capacity(1);
         recents.insert(String::from("ISO20201218T181432"), recent);
-        let hosts: UserHosts = UserHosts { bookmarks, recents };
+        let hosts: UserHosts = UserHosts {
+            bookmarks,
+            recents,
+            directories: HashMap::new(),
+        };
         // Verify
         let bookmark: &Bookmark = hosts.bookmarks.get(&String::from("test")).unwrap();
         assert_eq!(bookmark.address.as_deref().unwrap(), "192.168.1.1");
@@ -304,6 +395,9 @@ mod tests {
             port: 10222,
             username: Some(String::from("root")),
             password: Some(String::from("omar")),
+            identity_file: None,
+            connect_timeout: Some(Duration::from_secs(10)),
+            keepalive_secs: 30,
         });
         let params: FileTransferParams = FileTransferParams::new(FileTransferProtocol::Scp, params)
             .remote_path(Some(PathBuf::from("/home")))
@@ -314,6 +408,8 @@ mod tests {
         assert_eq!(bookmark.port.unwrap(), 10222);
         assert_eq!(bookmark.username.as_deref().unwrap(), "root");
         assert_eq!(bookmark.password.as_deref().unwrap(), "omar");
+        assert_eq!(bookmark.connect_timeout, Some(10));
+        assert_eq!(bookmark.keepalive_secs, Some(30));
         assert_eq!(
             bookmark.remote_path.as_deref().unwrap(),
             std::path::Path::new("/home")
@@ -386,6 +482,13 @@ mod tests {
             password: Some(String::from("password")),
             remote_path: Some(PathBuf::from("/tmp")),
             local_path: Some(PathBuf::from("/usr")),
+            connect_timeout: Some(15),
+            keepalive_secs: Some(45),
+            last_remote_path: None,
+            last_local_path: None,
+            last_remote_explorer: None,
+            last_local_explorer: None,
+            group: None,
             kube: None,
             s3: None,
             smb: None,
@@ -405,6 +508,8 @@ mod tests {
         assert_eq!(gparams.port, 22);
         assert_eq!(gparams.username.as_deref().unwrap(), "root");
         assert_eq!(gparams.password.as_deref().unwrap(), "password");
+        assert_eq!(gparams.connect_timeout, Some(Duration::from_secs(15)));
+        assert_eq!(gparams.keepalive_secs, 45);
     }
 
     #[test]
@@ -417,6 +522,13 @@ mod tests {
             password: Some(String::from("password")),
             remote_path: Some(PathBuf::from("/tmp")),
             local_path: Some(PathBuf::from("/usr")),
+            connect_timeout: None,
+            keepalive_secs: None,
+            last_remote_path: None,
+            last_local_path: None,
+            last_remote_explorer: None,
+            last_local_explorer: None,
+            group: None,
             kube: None,
             s3: None,
             smb: None,
@@ -447,6 +559,13 @@ mod tests {
             password: None,
             remote_path: Some(PathBuf::from("/tmp")),
             local_path: Some(PathBuf::from("/usr")),
+            connect_timeout: None,
+            keepalive_secs: None,
+            last_remote_path: None,
+            last_local_path: None,
+            last_remote_explorer: None,
+            last_local_explorer: None,
+            group: None,
             kube: None,
             s3: Some(S3Params {
                 bucket: String::from("veeso"),
@@ -489,6 +608,13 @@ mod tests {
             password: None,
             remote_path: Some(PathBuf::from("/tmp")),
             local_path: Some(PathBuf::from("/usr")),
+            connect_timeout: None,
+            keepalive_secs: None,
+            last_remote_path: None,
+            last_local_path: None,
+            last_remote_explorer: None,
+            last_local_explorer: None,
+            group: None,
             kube: Some(KubeParams {
                 namespace: Some(String::from("default")),
                 cluster_url: Some(String::from("https://localhost:6443")),
@@ -531,6 +657,13 @@ mod tests {
             password: Some("bar".to_string()),
             remote_path: Some(PathBuf::from("/tmp")),
             local_path: Some(PathBuf::from("/usr")),
+            connect_timeout: None,
+            keepalive_secs: None,
+            last_remote_path: None,
+            last_local_path: None,
+            last_remote_explorer: None,
+            last_local_explorer: None,
+            group: None,
             kube: None,
             s3: None,
             smb: Some(SmbParams {
@@ -569,6 +702,13 @@ mod tests {
             password: None,
             remote_path: Some(PathBuf::from("/tmp")),
             local_path: Some(PathBuf::from("/usr")),
+            connect_timeout: None,
+            keepalive_secs: None,
+            last_remote_path: None,
+            last_local_path: None,
+            last_remote_explorer: None,
+            last_local_explorer: None,
+            group: None,
             s3: None,
             kube: None,
             smb: Some(SmbParams {
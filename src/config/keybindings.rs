@@ -2,6 +2,8 @@
 //!
 //! `keybindings` is the module which provides the keybindings configurations and the serializers
 
+mod help;
+
 use std::fmt;
 use std::str::FromStr;
 
@@ -9,6 +11,9 @@ use serde::de::{self, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use tuirealm::event::{Key, KeyModifiers};
 
+pub use self::help::HelpRow;
+use super::serialization::{SerializerError, SerializerErrorKind};
+
 /// A single key binding that can be serialized/deserialized
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct KeyBinding {
@@ -74,36 +79,23 @@ impl FromStr for KeyBinding {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let s = s.trim().to_lowercase();
         let parts: Vec<&str> = s.split('+').collect();
+        // The last part is the key, the leading ones are modifiers (e.g. ctrl+shift+alt+a)
+        let Some((key_str, modifier_parts)) = parts.split_last() else {
+            return Err(format!("Invalid key binding format: {}", s));
+        };
 
         let mut modifiers = KeyModifiers::NONE;
-        let key_str: &str;
-
-        match parts.len() {
-            1 => {
-                key_str = parts[0];
-            }
-            2 => {
-                match parts[0] {
-                    "ctrl" | "control" | "c" => modifiers = KeyModifiers::CONTROL,
-                    "alt" | "a" | "meta" | "m" => modifiers = KeyModifiers::ALT,
-                    "shift" | "s" => modifiers = KeyModifiers::SHIFT,
-                    _ => return Err(format!("Unknown modifier: {}", parts[0])),
-                }
-                key_str = parts[1];
-            }
-            3 => {
-                // Handle combinations like ctrl+shift+a
-                for modifier in &parts[0..2] {
-                    match *modifier {
-                        "ctrl" | "control" | "c" => modifiers |= KeyModifiers::CONTROL,
-                        "alt" | "a" | "meta" | "m" => modifiers |= KeyModifiers::ALT,
-                        "shift" | "s" => modifiers |= KeyModifiers::SHIFT,
-                        _ => return Err(format!("Unknown modifier: {}", modifier)),
-                    }
+        for modifier in modifier_parts {
+            match *modifier {
+                "ctrl" | "control" | "c" => modifiers |= KeyModifiers::CONTROL,
+                "alt" | "a" | "meta" | "m" => modifiers |= KeyModifiers::ALT,
+                "shift" | "s" => modifiers |= KeyModifiers::SHIFT,
+                // Key events don't carry the super modifier, so such a binding would never match
+                "super" | "cmd" | "win" => {
+                    return Err(format!("Unsupported modifier: {}", modifier));
                 }
-                key_str = parts[2];
+                _ => return Err(format!("Unknown modifier: {}", modifier)),
             }
-            _ => return Err(format!("Invalid key binding format: {}", s)),
         }
 
         let key = parse_key(key_str)?;
@@ -145,6 +137,19 @@ fn parse_key(s: &str) -> Result<Key, String> {
         "f11" => Ok(Key::Function(11)),
         "f12" => Ok(Key::Function(12)),
 
+        // `+` separates the modifiers, so it must be named
+        "plus" => Ok(Key::Char('+')),
+
+        // Keypad keys; terminals report them as the keys they type
+        "kp_enter" | "kpenter" => Ok(Key::Enter),
+        "kp_plus" | "kpplus" => Ok(Key::Char('+')),
+        "kp_minus" | "kpminus" => Ok(Key::Char('-')),
+        "kp_multiply" | "kpmultiply" => Ok(Key::Char('*')),
+        "kp_divide" | "kpdivide" => Ok(Key::Char('/')),
+        "kp_decimal" | "kpdecimal" => Ok(Key::Char('.')),
+        "kp_begin" | "kpbegin" => Ok(Key::KeypadBegin),
+        s if parse_keypad_digit(s).is_some() => Ok(Key::Char(parse_keypad_digit(s).unwrap())),
+
         // Single character
         s if s.len() == 1 => Ok(Key::Char(s.chars().next().unwrap())),
 
@@ -152,11 +157,25 @@ fn parse_key(s: &str) -> Result<Key, String> {
     }
 }
 
+/// Parse a keypad digit key name, like `kp5`, `kp_5` or `numpad5`
+fn parse_keypad_digit(s: &str) -> Option<char> {
+    let digit = s
+        .strip_prefix("kp_")
+        .or_else(|| s.strip_prefix("kp"))
+        .or_else(|| s.strip_prefix("numpad"))?;
+    let mut chars = digit.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_digit() => Some(c),
+        _ => None,
+    }
+}
+
 fn key_to_string(key: &Key) -> String {
     match key {
         Key::Esc => "esc".to_string(),
         Key::Enter => "enter".to_string(),
         Key::Char(' ') => "space".to_string(),
+        Key::Char('+') => "plus".to_string(),
         Key::Char(c) => c.to_string(),
         Key::Tab => "tab".to_string(),
         Key::BackTab => "backtab".to_string(),
@@ -172,6 +191,7 @@ fn key_to_string(key: &Key) -> String {
         Key::Left => "left".to_string(),
         Key::Right => "right".to_string(),
         Key::Function(n) => format!("f{}", n),
+        Key::KeypadBegin => "kp_begin".to_string(),
         _ => "unknown".to_string(),
     }
 }
@@ -230,127 +250,478 @@ impl<'de> Deserialize<'de> for KeyBinding {
     }
 }
 
+/// Serialize and deserialize the bindings of an action, which are either a single key binding string
+/// (e.g. `"k"`) or an array of them (e.g. `["k", "up"]`)
+mod one_or_many {
+
+    use super::*;
+
+    pub fn serialize<S>(bindings: &[KeyBinding], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match bindings {
+            [binding] => binding.serialize(serializer),
+            bindings => bindings.serialize(serializer),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<KeyBinding>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct OneOrManyVisitor;
+
+        impl<'de> Visitor<'de> for OneOrManyVisitor {
+            type Value = Vec<KeyBinding>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a key binding string like 'ctrl+a' or an array of them")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Vec<KeyBinding>, E>
+            where
+                E: de::Error,
+            {
+                KeyBinding::from_str(value)
+                    .map(|binding| vec![binding])
+                    .map_err(de::Error::custom)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Vec<KeyBinding>, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                let mut bindings = Vec::new();
+                while let Some(binding) = seq.next_element()? {
+                    bindings.push(binding);
+                }
+                Ok(bindings)
+            }
+        }
+
+        deserializer.deserialize_any(OneOrManyVisitor)
+    }
+}
+
+/// Format the bindings of an action for display, separated by `|` (e.g. `k|up`)
+pub fn fmt_bindings(bindings: &[KeyBinding]) -> String {
+    bindings
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join("|")
+}
+
 /// Keybindings for file explorer actions
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
 pub struct ExplorerKeyBindings {
     // Navigation
-    pub move_up: KeyBinding,
-    pub move_down: KeyBinding,
-    pub move_up_page: KeyBinding,
-    pub move_down_page: KeyBinding,
-    pub move_to_top: KeyBinding,
-    pub move_to_bottom: KeyBinding,
-    pub go_to_parent: KeyBinding,
-    pub go_back: KeyBinding,
-    pub enter_dir: KeyBinding,
-    #[serde(default = "default_enter_dir_alt")]
-    pub enter_dir_alt: KeyBinding,
-    pub change_panel: KeyBinding,
+    #[serde(with = "one_or_many")]
+    pub move_up: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub move_down: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub move_up_page: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub move_down_page: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub move_to_top: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub move_to_bottom: Vec<KeyBinding>,
+    /// Key starting a sequence: pressed twice in a row, it moves to the top (e.g. `gg` in vim), while followed by a
+    /// `goto_path` key, it goes to a path. Unset by default
+    #[serde(with = "one_or_many")]
+    pub leader: Vec<KeyBinding>,
+    /// Key starting a jump: the next char moves to the next entry starting with it
+    #[serde(with = "one_or_many")]
+    pub jump_to_entry: Vec<KeyBinding>,
+    /// Key after which a char marks the working directory
+    #[serde(with = "one_or_many")]
+    pub set_dir_mark: Vec<KeyBinding>,
+    /// Key after which a char goes to the directory marked with it
+    #[serde(with = "one_or_many")]
+    pub goto_dir_mark: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub go_to_parent: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub go_back: Vec<KeyBinding>,
+    /// Go forward to the directory left with `go_back`
+    #[serde(with = "one_or_many")]
+    pub go_forward: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub go_home: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub next_sibling_dir: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub prev_sibling_dir: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub go_to_other_panel_dir: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub enter_dir: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub enter_dir_alt: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub change_panel: Vec<KeyBinding>,
 
     // File operations
-    pub transfer_file: KeyBinding,
-    pub copy_file: KeyBinding,
-    pub rename_file: KeyBinding,
-    pub delete_file: KeyBinding,
-    pub mkdir: KeyBinding,
-    pub new_file: KeyBinding,
-    pub edit_file: KeyBinding,
-    pub open_file: KeyBinding,
-    pub open_with: KeyBinding,
-    pub save_as: KeyBinding,
-    pub chmod: KeyBinding,
-    pub symlink: KeyBinding,
-    pub reload_dir: KeyBinding,
+    #[serde(with = "one_or_many")]
+    pub transfer_file: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub transfer_file_rsync: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub copy_file: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub rename_file: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub delete_file: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub mkdir: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub new_file: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub edit_file: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub open_file: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub open_with: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub save_as: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub chmod: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub symlink: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub reload_dir: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub mirror_dir: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub sync_dir: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub fanout: Vec<KeyBinding>,
+    /// Compress the marked files into an archive, on the side they live on
+    #[serde(with = "one_or_many")]
+    pub compress: Vec<KeyBinding>,
+    /// Extract the highlighted archive into the working directory
+    #[serde(with = "one_or_many")]
+    pub extract: Vec<KeyBinding>,
+    /// Copy the highlighted file in its own directory, with another name
+    #[serde(with = "one_or_many")]
+    pub duplicate: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub save_bookmark: Vec<KeyBinding>,
+    /// Bookmark the remote working directory, with the connection to reach it
+    #[serde(with = "one_or_many")]
+    pub save_dir_bookmark: Vec<KeyBinding>,
+    /// List the directory bookmarks, to connect to one and go to its directory
+    #[serde(with = "one_or_many")]
+    pub dir_bookmarks: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub transfer_and_open: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub cut_file: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub paste: Vec<KeyBinding>,
 
     // Selection
-    pub mark_file: KeyBinding,
-    pub mark_all: KeyBinding,
-    pub unmark_all: KeyBinding,
+    #[serde(with = "one_or_many")]
+    pub mark_file: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub mark_all: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub unmark_all: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub invert_selection: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub select_by_pattern: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub copy_marked: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub copy_path: Vec<KeyBinding>,
 
     // View
-    pub toggle_hidden: KeyBinding,
-    pub file_info: KeyBinding,
-    pub file_size: KeyBinding,
-    pub sorting: KeyBinding,
-    pub filter: KeyBinding,
+    #[serde(with = "one_or_many")]
+    pub toggle_hidden: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub toggle_hidden_patterns: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub toggle_raw_sizes: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub file_info: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub file_size: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub sorting: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub filter: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub grow_panel: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub shrink_panel: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub maximize_panel: Vec<KeyBinding>,
 
     // Search
-    pub fuzzy_search: KeyBinding,
-    pub goto_path: KeyBinding,
+    #[serde(with = "one_or_many")]
+    pub fuzzy_search: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub goto_path: Vec<KeyBinding>,
+    /// Switch between fuzzy and regex search, while typing in the fuzzy search input
+    #[serde(with = "one_or_many")]
+    pub toggle_search_regex: Vec<KeyBinding>,
+    /// Toggle the case sensitivity of the regex search, while typing in the fuzzy search input
+    #[serde(with = "one_or_many")]
+    pub toggle_search_case: Vec<KeyBinding>,
+    /// Close the find results; only checked in the finder, so it may share its key with `disconnect`
+    #[serde(with = "one_or_many")]
+    pub close_find: Vec<KeyBinding>,
 
     // Misc
-    pub terminal: KeyBinding,
-    pub sync_browsing: KeyBinding,
-    pub watcher: KeyBinding,
-    pub watched_paths: KeyBinding,
-    pub pending_queue: KeyBinding,
+    #[serde(with = "one_or_many")]
+    pub terminal: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub sync_browsing: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub watcher: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub watched_paths: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub pending_queue: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub toggle_log: Vec<KeyBinding>,
+    #[serde(with = "one_or_many")]
+    pub toggle_footer: Vec<KeyBinding>,
+    /// Show or hide the preview of the highlighted file
+    #[serde(with = "one_or_many")]
+    pub preview: Vec<KeyBinding>,
+    /// Show the next page of the footer hints, when they don't fit in the terminal width
+    #[serde(with = "one_or_many")]
+    pub next_footer_page: Vec<KeyBinding>,
 }
 
-/// Default value for enter_dir_alt (used when field is missing in config)
-fn default_enter_dir_alt() -> KeyBinding {
-    // Default to 'l' as vim-style alternative, but Enter is the primary
-    KeyBinding::simple(Key::Char('l'))
+impl ExplorerKeyBindings {
+    /// Returns the explorer actions with their binding, in the order the explorers check them.
+    ///
+    /// When several actions share a binding, only the first one in this order is triggered.
+    /// An action bound to several keys is listed once per binding
+    fn actions(&self) -> Vec<(&'static str, &KeyBinding)> {
+        self.action_bindings()
+            .into_iter()
+            .flat_map(|(action, bindings)| bindings.iter().map(move |binding| (action, binding)))
+            .collect()
+    }
+
+    /// Returns the explorer actions with all their bindings, in the order the explorers check them
+    fn action_bindings(&self) -> Vec<(&'static str, &Vec<KeyBinding>)> {
+        vec![
+            ("leader", &self.leader),
+            ("jump_to_entry", &self.jump_to_entry),
+            ("set_dir_mark", &self.set_dir_mark),
+            ("goto_dir_mark", &self.goto_dir_mark),
+            ("move_down", &self.move_down),
+            ("move_up", &self.move_up),
+            ("move_down_page", &self.move_down_page),
+            ("move_up_page", &self.move_up_page),
+            ("move_to_top", &self.move_to_top),
+            ("move_to_bottom", &self.move_to_bottom),
+            ("mark_all", &self.mark_all),
+            ("unmark_all", &self.unmark_all),
+            ("invert_selection", &self.invert_selection),
+            ("select_by_pattern", &self.select_by_pattern),
+            ("copy_marked", &self.copy_marked),
+            ("copy_path", &self.copy_path),
+            ("mark_file", &self.mark_file),
+            ("change_panel", &self.change_panel),
+            ("go_back", &self.go_back),
+            ("go_forward", &self.go_forward),
+            ("go_to_parent", &self.go_to_parent),
+            ("go_home", &self.go_home),
+            ("enter_dir", &self.enter_dir),
+            ("enter_dir_alt", &self.enter_dir_alt),
+            ("transfer_file", &self.transfer_file),
+            ("transfer_file_rsync", &self.transfer_file_rsync),
+            ("toggle_hidden", &self.toggle_hidden),
+            ("toggle_hidden_patterns", &self.toggle_hidden_patterns),
+            ("toggle_raw_sizes", &self.toggle_raw_sizes),
+            ("sorting", &self.sorting),
+            ("copy_file", &self.copy_file),
+            ("mkdir", &self.mkdir),
+            ("delete_file", &self.delete_file),
+            ("fuzzy_search", &self.fuzzy_search),
+            ("goto_path", &self.goto_path),
+            ("toggle_search_regex", &self.toggle_search_regex),
+            ("toggle_search_case", &self.toggle_search_case),
+            ("file_info", &self.file_info),
+            ("symlink", &self.symlink),
+            ("reload_dir", &self.reload_dir),
+            ("mirror_dir", &self.mirror_dir),
+            ("sync_dir", &self.sync_dir),
+            ("fanout", &self.fanout),
+            ("compress", &self.compress),
+            ("extract", &self.extract),
+            ("duplicate", &self.duplicate),
+            ("save_bookmark", &self.save_bookmark),
+            ("save_dir_bookmark", &self.save_dir_bookmark),
+            ("dir_bookmarks", &self.dir_bookmarks),
+            ("transfer_and_open", &self.transfer_and_open),
+            ("cut_file", &self.cut_file),
+            ("paste", &self.paste),
+            ("new_file", &self.new_file),
+            ("edit_file", &self.edit_file),
+            ("pending_queue", &self.pending_queue),
+            ("toggle_log", &self.toggle_log),
+            ("toggle_footer", &self.toggle_footer),
+            ("preview", &self.preview),
+            ("next_footer_page", &self.next_footer_page),
+            ("grow_panel", &self.grow_panel),
+            ("shrink_panel", &self.shrink_panel),
+            ("maximize_panel", &self.maximize_panel),
+            ("rename_file", &self.rename_file),
+            ("file_size", &self.file_size),
+            ("save_as", &self.save_as),
+            ("watcher", &self.watcher),
+            ("watched_paths", &self.watched_paths),
+            ("next_sibling_dir", &self.next_sibling_dir),
+            ("prev_sibling_dir", &self.prev_sibling_dir),
+            ("go_to_other_panel_dir", &self.go_to_other_panel_dir),
+            ("terminal", &self.terminal),
+            ("sync_browsing", &self.sync_browsing),
+            ("open_file", &self.open_file),
+            ("open_with", &self.open_with),
+            ("chmod", &self.chmod),
+            ("filter", &self.filter),
+        ]
+    }
 }
 
 impl Default for ExplorerKeyBindings {
     fn default() -> Self {
         Self {
             // Navigation
-            move_up: KeyBinding::simple(Key::Up),
-            move_down: KeyBinding::simple(Key::Down),
-            move_up_page: KeyBinding::simple(Key::PageUp),
-            move_down_page: KeyBinding::simple(Key::PageDown),
-            move_to_top: KeyBinding::simple(Key::Home),
-            move_to_bottom: KeyBinding::simple(Key::End),
-            go_to_parent: KeyBinding::simple(Key::Char('u')),
-            go_back: KeyBinding::simple(Key::Backspace),
-            enter_dir: KeyBinding::simple(Key::Enter),
-            enter_dir_alt: default_enter_dir_alt(),
-            change_panel: KeyBinding::simple(Key::Tab),
+            move_up: vec![KeyBinding::simple(Key::Up)],
+            move_down: vec![KeyBinding::simple(Key::Down)],
+            move_up_page: vec![KeyBinding::simple(Key::PageUp)],
+            move_down_page: vec![KeyBinding::simple(Key::PageDown)],
+            move_to_top: vec![KeyBinding::simple(Key::Home)],
+            move_to_bottom: vec![KeyBinding::simple(Key::End)],
+            leader: vec![],
+            jump_to_entry: vec![KeyBinding::simple(Key::Char('\''))],
+            set_dir_mark: vec![KeyBinding::simple(Key::Char('@'))],
+            goto_dir_mark: vec![KeyBinding::simple(Key::Char('`'))],
+            go_to_parent: vec![KeyBinding::simple(Key::Char('u'))],
+            go_back: vec![KeyBinding::simple(Key::Backspace)],
+            go_forward: vec![KeyBinding::shift(Key::Backspace)],
+            go_home: vec![KeyBinding::simple(Key::Char('~'))],
+            next_sibling_dir: vec![KeyBinding::alt(Key::Char('j'))],
+            prev_sibling_dir: vec![KeyBinding::alt(Key::Char('k'))],
+            go_to_other_panel_dir: vec![KeyBinding::alt(Key::Char('g'))],
+            enter_dir: vec![KeyBinding::simple(Key::Enter)],
+            // Same as `enter_dir`: `l` would shadow `reload_dir`
+            enter_dir_alt: vec![KeyBinding::simple(Key::Enter)],
+            change_panel: vec![
+                KeyBinding::simple(Key::Tab),
+                KeyBinding::shift(Key::BackTab),
+                KeyBinding::simple(Key::Left),
+                KeyBinding::simple(Key::Right),
+            ],
 
             // File operations
-            transfer_file: KeyBinding::simple(Key::Char(' ')),
-            copy_file: KeyBinding::simple(Key::Char('c')),
-            rename_file: KeyBinding::simple(Key::Char('r')),
-            delete_file: KeyBinding::simple(Key::Char('e')),
-            mkdir: KeyBinding::simple(Key::Char('d')),
-            new_file: KeyBinding::simple(Key::Char('n')),
-            edit_file: KeyBinding::simple(Key::Char('o')),
-            open_file: KeyBinding::simple(Key::Char('v')),
-            open_with: KeyBinding::simple(Key::Char('w')),
-            save_as: KeyBinding::simple(Key::Char('s')),
-            chmod: KeyBinding::simple(Key::Char('z')),
-            symlink: KeyBinding::simple(Key::Char('k')),
-            reload_dir: KeyBinding::simple(Key::Char('l')),
+            transfer_file: vec![KeyBinding::simple(Key::Char(' '))],
+            transfer_file_rsync: vec![KeyBinding::alt(Key::Char('r'))],
+            copy_file: vec![
+                KeyBinding::simple(Key::Char('c')),
+                KeyBinding::simple(Key::Function(5)),
+            ],
+            rename_file: vec![
+                KeyBinding::simple(Key::Char('r')),
+                KeyBinding::simple(Key::Function(6)),
+            ],
+            delete_file: vec![
+                KeyBinding::simple(Key::Char('e')),
+                KeyBinding::simple(Key::Delete),
+                KeyBinding::simple(Key::Function(8)),
+            ],
+            mkdir: vec![
+                KeyBinding::simple(Key::Char('d')),
+                KeyBinding::simple(Key::Function(7)),
+            ],
+            new_file: vec![KeyBinding::simple(Key::Char('n'))],
+            edit_file: vec![
+                KeyBinding::simple(Key::Char('o')),
+                KeyBinding::simple(Key::Function(4)),
+            ],
+            open_file: vec![
+                KeyBinding::simple(Key::Char('v')),
+                KeyBinding::simple(Key::Function(3)),
+            ],
+            open_with: vec![KeyBinding::simple(Key::Char('w'))],
+            save_as: vec![
+                KeyBinding::simple(Key::Char('s')),
+                KeyBinding::simple(Key::Function(2)),
+            ],
+            chmod: vec![KeyBinding::simple(Key::Char('z'))],
+            symlink: vec![KeyBinding::simple(Key::Char('k'))],
+            reload_dir: vec![KeyBinding::simple(Key::Char('l'))],
+            mirror_dir: vec![KeyBinding::alt(Key::Char('m'))],
+            sync_dir: vec![KeyBinding::alt(Key::Char('s'))],
+            fanout: vec![KeyBinding::alt(Key::Char('u'))],
+            compress: vec![KeyBinding::ctrl(Key::Char('z'))],
+            extract: vec![KeyBinding::ctrl(Key::Char('e'))],
+            duplicate: vec![KeyBinding::ctrl(Key::Char('d'))],
+            save_bookmark: vec![KeyBinding::alt(Key::Char('b'))],
+            save_dir_bookmark: vec![KeyBinding::alt(Key::Char('t'))],
+            dir_bookmarks: vec![KeyBinding::ctrl(Key::Char('o'))],
+            transfer_and_open: vec![KeyBinding::alt(Key::Char('v'))],
+            cut_file: vec![KeyBinding::ctrl(Key::Char('x'))],
+            paste: vec![KeyBinding::ctrl(Key::Char('v'))],
 
             // Selection
-            mark_file: KeyBinding::simple(Key::Char('m')),
-            mark_all: KeyBinding::ctrl(Key::Char('a')),
-            unmark_all: KeyBinding::alt(Key::Char('a')),
+            mark_file: vec![KeyBinding::simple(Key::Char('m'))],
+            mark_all: vec![KeyBinding::ctrl(Key::Char('a'))],
+            unmark_all: vec![KeyBinding::alt(Key::Char('a'))],
+            // `ctrl+i` is sent as `tab` by terminals
+            invert_selection: vec![KeyBinding::alt(Key::Char('i'))],
+            select_by_pattern: vec![KeyBinding::simple(Key::Char('\\'))],
+            copy_marked: vec![KeyBinding::alt(Key::Char('c'))],
+            copy_path: vec![KeyBinding::ctrl(Key::Char('y'))],
 
             // View
-            toggle_hidden: KeyBinding::simple(Key::Char('a')),
-            file_info: KeyBinding::simple(Key::Char('i')),
-            file_size: KeyBinding::ctrl(Key::Char('s')),
-            sorting: KeyBinding::simple(Key::Char('b')),
-            filter: KeyBinding::simple(Key::Char('/')),
+            toggle_hidden: vec![KeyBinding::simple(Key::Char('a'))],
+            toggle_hidden_patterns: vec![KeyBinding::alt(Key::Char('h'))],
+            toggle_raw_sizes: vec![KeyBinding::alt(Key::Char('e'))],
+            file_info: vec![KeyBinding::simple(Key::Char('i'))],
+            file_size: vec![KeyBinding::ctrl(Key::Char('s'))],
+            sorting: vec![KeyBinding::simple(Key::Char('b'))],
+            filter: vec![KeyBinding::simple(Key::Char('/'))],
+            grow_panel: vec![KeyBinding::alt(Key::Char('='))],
+            shrink_panel: vec![KeyBinding::alt(Key::Char('-'))],
+            maximize_panel: vec![KeyBinding::alt(Key::Char('z'))],
 
             // Search
-            fuzzy_search: KeyBinding::simple(Key::Char('f')),
-            goto_path: KeyBinding::simple(Key::Char('g')),
+            fuzzy_search: vec![KeyBinding::simple(Key::Char('f'))],
+            goto_path: vec![KeyBinding::simple(Key::Char('g'))],
+            toggle_search_regex: vec![KeyBinding::ctrl(Key::Char('r'))],
+            toggle_search_case: vec![KeyBinding::ctrl(Key::Char('u'))],
+            close_find: vec![KeyBinding::simple(Key::Esc)],
 
             // Misc
-            terminal: KeyBinding::simple(Key::Char('x')),
-            sync_browsing: KeyBinding::simple(Key::Char('y')),
-            watcher: KeyBinding::simple(Key::Char('t')),
-            watched_paths: KeyBinding::ctrl(Key::Char('t')),
-            pending_queue: KeyBinding::simple(Key::Char('p')),
+            terminal: vec![KeyBinding::simple(Key::Char('x'))],
+            sync_browsing: vec![KeyBinding::simple(Key::Char('y'))],
+            watcher: vec![KeyBinding::simple(Key::Char('t'))],
+            watched_paths: vec![KeyBinding::ctrl(Key::Char('t'))],
+            pending_queue: vec![KeyBinding::simple(Key::Char('p'))],
+            toggle_log: vec![KeyBinding::alt(Key::Char('l'))],
+            toggle_footer: vec![KeyBinding::alt(Key::Char('f'))],
+            preview: vec![KeyBinding::ctrl(Key::Char('p'))],
+            next_footer_page: vec![KeyBinding::alt(Key::Char('.'))],
         }
     }
 }
 
 /// Global keybindings that work across all activities
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
 pub struct GlobalKeyBindings {
     pub quit: KeyBinding,
     pub quit_alt: KeyBinding,
@@ -359,6 +730,19 @@ pub struct GlobalKeyBindings {
     pub help_alt: KeyBinding,
 }
 
+impl GlobalKeyBindings {
+    /// Returns the global actions with their binding
+    fn actions(&self) -> Vec<(&'static str, &KeyBinding)> {
+        vec![
+            ("quit", &self.quit),
+            ("quit_alt", &self.quit_alt),
+            ("help", &self.help),
+            ("help_alt", &self.help_alt),
+            ("disconnect", &self.disconnect),
+        ]
+    }
+}
+
 impl Default for GlobalKeyBindings {
     fn default() -> Self {
         Self {
@@ -373,6 +757,7 @@ impl Default for GlobalKeyBindings {
 
 /// Keybindings for the authentication activity
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
 pub struct AuthKeyBindings {
     pub quit: KeyBinding,
     pub quit_alt: KeyBinding,
@@ -381,6 +766,24 @@ pub struct AuthKeyBindings {
     pub help_alt: KeyBinding,
     pub release_notes: KeyBinding,
     pub save_bookmark: KeyBinding,
+    /// Connect again with the params of the last session
+    pub reconnect: KeyBinding,
+}
+
+impl AuthKeyBindings {
+    /// Returns the authentication actions with their binding
+    fn actions(&self) -> Vec<(&'static str, &KeyBinding)> {
+        vec![
+            ("quit", &self.quit),
+            ("quit_alt", &self.quit_alt),
+            ("setup", &self.setup),
+            ("help", &self.help),
+            ("help_alt", &self.help_alt),
+            ("release_notes", &self.release_notes),
+            ("save_bookmark", &self.save_bookmark),
+            ("reconnect", &self.reconnect),
+        ]
+    }
 }
 
 impl Default for AuthKeyBindings {
@@ -393,12 +796,14 @@ impl Default for AuthKeyBindings {
             help_alt: KeyBinding::simple(Key::Function(1)),
             release_notes: KeyBinding::ctrl(Key::Char('r')),
             save_bookmark: KeyBinding::ctrl(Key::Char('s')),
+            reconnect: KeyBinding::ctrl(Key::Char('l')),
         }
     }
 }
 
 /// Keybindings for the setup activity
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
 pub struct SetupKeyBindings {
     pub quit: KeyBinding,
     pub quit_alt: KeyBinding,
@@ -410,6 +815,22 @@ pub struct SetupKeyBindings {
     pub save_alt: KeyBinding,
 }
 
+impl SetupKeyBindings {
+    /// Returns the setup actions with their binding
+    fn actions(&self) -> Vec<(&'static str, &KeyBinding)> {
+        vec![
+            ("quit", &self.quit),
+            ("quit_alt", &self.quit_alt),
+            ("change_tab", &self.change_tab),
+            ("help", &self.help),
+            ("help_alt", &self.help_alt),
+            ("revert", &self.revert),
+            ("save", &self.save),
+            ("save_alt", &self.save_alt),
+        ]
+    }
+}
+
 impl Default for SetupKeyBindings {
     fn default() -> Self {
         Self {
@@ -425,9 +846,61 @@ impl Default for SetupKeyBindings {
     }
 }
 
-/// Complete keybindings configuration
+/// Several actions bound to the same key
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyBindingConflict {
+    pub binding: KeyBinding,
+    /// Conflicting actions, named after their section (e.g. `explorer.mkdir`), in the order they are checked;
+    /// only the first one is triggered
+    pub actions: Vec<String>,
+}
+
+impl fmt::Display for KeyBindingConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\"{}\" is bound to {}; only {} is triggered",
+            self.binding,
+            self.actions.join(", "),
+            self.actions[0]
+        )
+    }
+}
+
+/// Version of the keybindings file format; files written with an older version are migrated when loaded
+pub const KEYBINDINGS_VERSION: u32 = 11;
+
+/// Getter of the bindings of an explorer action, used by the migration steps
+type ExplorerActionBindings = fn(&mut ExplorerKeyBindings) -> &mut Vec<KeyBinding>;
+
+/// Built-in keybindings configurations, which can be used as a starting template
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyBindingsPreset {
+    Default,
+    Vim,
+    Emacs,
+}
+
+impl KeyBindingsPreset {
+    /// Returns the keybindings of the preset
+    pub fn keybindings(self) -> KeyBindings {
+        match self {
+            Self::Default => KeyBindings::default(),
+            Self::Vim => KeyBindings::vim_style(),
+            Self::Emacs => KeyBindings::emacs_style(),
+        }
+    }
+}
+
+/// Complete keybindings configuration.
+///
+/// Missing sections and bindings take their default value, so a file may only set the bindings to change
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
 pub struct KeyBindings {
+    /// Version of the file format; it's 0 for files written before the format was versioned
+    #[serde(default)]
+    pub version: u32,
     pub global: GlobalKeyBindings,
     pub explorer: ExplorerKeyBindings,
     pub auth: AuthKeyBindings,
@@ -437,6 +910,7 @@ pub struct KeyBindings {
 impl Default for KeyBindings {
     fn default() -> Self {
         Self {
+            version: KEYBINDINGS_VERSION,
             global: GlobalKeyBindings::default(),
             explorer: ExplorerKeyBindings::default(),
             auth: AuthKeyBindings::default(),
@@ -446,10 +920,237 @@ impl Default for KeyBindings {
 }
 
 impl KeyBindings {
+    /// Check that no actions of the same scope share the same key.
+    ///
+    /// The scopes are the file explorer, whose actions are checked after the global keys, the authentication form
+    /// and the setup. Actions are checked in the order the activities check them, so with conflicts the behaviour
+    /// is still deterministic: the first action wins. An action and its `_alt` binding may share the same key.
+    pub fn validate(&self) -> Result<(), Vec<KeyBindingConflict>> {
+        let actions = self.actions();
+        let scopes: [&[&str]; 3] = [&["global", "explorer"], &["auth"], &["setup"]];
+        let conflicts: Vec<KeyBindingConflict> = scopes
+            .into_iter()
+            .flat_map(|sections| {
+                Self::conflicts(actions.iter().filter(|(action, _)| {
+                    sections
+                        .iter()
+                        .any(|section| action.starts_with(&format!("{section}.")))
+                }))
+            })
+            .collect();
+        if conflicts.is_empty() {
+            Ok(())
+        } else {
+            Err(conflicts)
+        }
+    }
+
+    /// Collect the bindings shared by several of `actions`
+    fn conflicts<'a>(
+        actions: impl Iterator<Item = &'a (String, &'a KeyBinding)>,
+    ) -> Vec<KeyBindingConflict> {
+        let mut conflicts: Vec<KeyBindingConflict> = Vec::new();
+        for (action, binding) in actions {
+            match conflicts.iter_mut().find(|x| &x.binding == *binding) {
+                Some(conflict) => {
+                    let base = action.trim_end_matches("_alt");
+                    if !conflict
+                        .actions
+                        .iter()
+                        .any(|x| x.trim_end_matches("_alt") == base)
+                    {
+                        conflict.actions.push(action.clone());
+                    }
+                }
+                None => conflicts.push(KeyBindingConflict {
+                    binding: (*binding).clone(),
+                    actions: vec![action.clone()],
+                }),
+            }
+        }
+        conflicts.retain(|x| x.actions.len() > 1);
+        conflicts
+    }
+
+    /// Returns all the actions with their binding, named after their section (e.g. `explorer.mkdir`)
+    pub fn actions(&self) -> Vec<(String, &KeyBinding)> {
+        [
+            ("global", self.global.actions()),
+            ("explorer", self.explorer.actions()),
+            ("auth", self.auth.actions()),
+            ("setup", self.setup.actions()),
+        ]
+        .into_iter()
+        .flat_map(|(section, actions)| {
+            actions
+                .into_iter()
+                .map(move |(action, binding)| (format!("{section}.{action}"), binding))
+        })
+        .collect()
+    }
+
+    /// Migrate the keybindings read from a file written with the `version` of the file format, applying the steps
+    /// of the later versions in order.
+    ///
+    /// Returns the bindings added by the steps (e.g. `explorer.move_up: up`)
+    pub fn migrate(&mut self, version: u32) -> Vec<String> {
+        let mut added = Vec::new();
+        // Version 10: the explorer no longer moves with the arrow, page, home and end keys on top of the move bindings
+        if version < 10 {
+            let steps: [(&str, ExplorerActionBindings, KeyBinding); 6] = [
+                ("move_up", |x| &mut x.move_up, KeyBinding::simple(Key::Up)),
+                (
+                    "move_down",
+                    |x| &mut x.move_down,
+                    KeyBinding::simple(Key::Down),
+                ),
+                (
+                    "move_up_page",
+                    |x| &mut x.move_up_page,
+                    KeyBinding::simple(Key::PageUp),
+                ),
+                (
+                    "move_down_page",
+                    |x| &mut x.move_down_page,
+                    KeyBinding::simple(Key::PageDown),
+                ),
+                (
+                    "move_to_top",
+                    |x| &mut x.move_to_top,
+                    KeyBinding::simple(Key::Home),
+                ),
+                (
+                    "move_to_bottom",
+                    |x| &mut x.move_to_bottom,
+                    KeyBinding::simple(Key::End),
+                ),
+            ];
+            added.extend(self.add_explorer_bindings(steps));
+        }
+        // Version 11: the same goes for the backspace, tab, arrow, delete and function keys of the file operations
+        if version < 11 {
+            let steps: [(&str, ExplorerActionBindings, KeyBinding); 13] = [
+                (
+                    "go_back",
+                    |x| &mut x.go_back,
+                    KeyBinding::simple(Key::Backspace),
+                ),
+                (
+                    "change_panel",
+                    |x| &mut x.change_panel,
+                    KeyBinding::simple(Key::Tab),
+                ),
+                (
+                    "change_panel",
+                    |x| &mut x.change_panel,
+                    KeyBinding::shift(Key::BackTab),
+                ),
+                (
+                    "change_panel",
+                    |x| &mut x.change_panel,
+                    KeyBinding::simple(Key::Left),
+                ),
+                (
+                    "change_panel",
+                    |x| &mut x.change_panel,
+                    KeyBinding::simple(Key::Right),
+                ),
+                (
+                    "copy_file",
+                    |x| &mut x.copy_file,
+                    KeyBinding::simple(Key::Function(5)),
+                ),
+                (
+                    "rename_file",
+                    |x| &mut x.rename_file,
+                    KeyBinding::simple(Key::Function(6)),
+                ),
+                (
+                    "delete_file",
+                    |x| &mut x.delete_file,
+                    KeyBinding::simple(Key::Delete),
+                ),
+                (
+                    "delete_file",
+                    |x| &mut x.delete_file,
+                    KeyBinding::simple(Key::Function(8)),
+                ),
+                (
+                    "mkdir",
+                    |x| &mut x.mkdir,
+                    KeyBinding::simple(Key::Function(7)),
+                ),
+                (
+                    "edit_file",
+                    |x| &mut x.edit_file,
+                    KeyBinding::simple(Key::Function(4)),
+                ),
+                (
+                    "open_file",
+                    |x| &mut x.open_file,
+                    KeyBinding::simple(Key::Function(3)),
+                ),
+                (
+                    "save_as",
+                    |x| &mut x.save_as,
+                    KeyBinding::simple(Key::Function(2)),
+                ),
+            ];
+            added.extend(self.add_explorer_bindings(steps));
+        }
+        added
+    }
+
+    /// Add a binding to each explorer action, unless a global or explorer action is already bound to it.
+    ///
+    /// Returns the added bindings
+    fn add_explorer_bindings(
+        &mut self,
+        bindings: impl IntoIterator<Item = (&'static str, ExplorerActionBindings, KeyBinding)>,
+    ) -> Vec<String> {
+        let mut added = Vec::new();
+        for (action, action_bindings, binding) in bindings {
+            let is_bound = self
+                .global
+                .actions()
+                .into_iter()
+                .chain(self.explorer.actions())
+                .any(|(_, x)| *x == binding);
+            if !is_bound {
+                added.push(format!("explorer.{action}: {binding}"));
+                action_bindings(&mut self.explorer).push(binding);
+            }
+        }
+        added
+    }
+
+    /// Returns the bindings of a global or explorer action, given its name in the keybindings file (e.g. `mkdir`)
+    pub fn explorer_action(&self, action: &str) -> Option<Vec<KeyBinding>> {
+        if let Some((_, binding)) = self
+            .global
+            .actions()
+            .into_iter()
+            .find(|(name, _)| *name == action)
+        {
+            return Some(vec![binding.clone()]);
+        }
+        self.explorer
+            .action_bindings()
+            .into_iter()
+            .find(|(name, _)| *name == action)
+            .map(|(_, bindings)| bindings.clone())
+    }
+
+    /// Serialize the keybindings to JSON, with the same layout as the keybindings file
+    pub fn to_json(&self) -> Result<String, SerializerError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| SerializerError::new_ex(SerializerErrorKind::Serialization, e.to_string()))
+    }
+
     /// Create vim-style keybindings inspired by yazi, ranger, and other file managers
-    #[allow(dead_code)]
     pub fn vim_style() -> Self {
         Self {
+            version: KEYBINDINGS_VERSION,
             global: GlobalKeyBindings {
                 quit: KeyBinding::simple(Key::Char('q')),
                 quit_alt: KeyBinding::simple(Key::Char('Q')),
@@ -459,55 +1160,141 @@ impl KeyBindings {
             },
             explorer: ExplorerKeyBindings {
                 // Vim-style navigation (j/k like yazi/ranger)
-                move_up: KeyBinding::simple(Key::Char('k')),
-                move_down: KeyBinding::simple(Key::Char('j')),
-                move_up_page: KeyBinding::ctrl(Key::Char('u')),
-                move_down_page: KeyBinding::ctrl(Key::Char('d')),
-                move_to_top: KeyBinding::simple(Key::Char('g')),
-                move_to_bottom: KeyBinding::simple(Key::Char('G')),
-                go_to_parent: KeyBinding::simple(Key::Char('h')),
-                go_back: KeyBinding::simple(Key::Char('-')),
-                enter_dir: KeyBinding::simple(Key::Char('l')),
-                enter_dir_alt: KeyBinding::simple(Key::Enter),
-                change_panel: KeyBinding::simple(Key::Tab),
+                move_up: vec![
+                    KeyBinding::simple(Key::Char('k')),
+                    KeyBinding::simple(Key::Up),
+                ],
+                move_down: vec![
+                    KeyBinding::simple(Key::Char('j')),
+                    KeyBinding::simple(Key::Down),
+                ],
+                move_up_page: vec![
+                    KeyBinding::ctrl(Key::Char('u')),
+                    KeyBinding::simple(Key::PageUp),
+                ],
+                move_down_page: vec![
+                    KeyBinding::ctrl(Key::Char('d')),
+                    KeyBinding::simple(Key::PageDown),
+                ],
+                // `gg` goes to the top
+                move_to_top: vec![KeyBinding::simple(Key::Home)],
+                move_to_bottom: vec![
+                    KeyBinding::simple(Key::Char('G')),
+                    KeyBinding::simple(Key::End),
+                ],
+                leader: vec![KeyBinding::simple(Key::Char('g'))],
+                // `fa` moves to the next entry starting with `a`, `ma` and `'a` set and go to marks
+                jump_to_entry: vec![KeyBinding::simple(Key::Char('f'))],
+                set_dir_mark: vec![KeyBinding::simple(Key::Char('m'))],
+                goto_dir_mark: vec![KeyBinding::simple(Key::Char('\''))],
+                go_to_parent: vec![KeyBinding::simple(Key::Char('h'))],
+                go_back: vec![
+                    KeyBinding::simple(Key::Char('-')),
+                    KeyBinding::simple(Key::Backspace),
+                ],
+                go_forward: vec![KeyBinding::simple(Key::Char('+'))],
+                go_home: vec![KeyBinding::simple(Key::Char('~'))],
+                next_sibling_dir: vec![KeyBinding::alt(Key::Char('j'))],
+                prev_sibling_dir: vec![KeyBinding::alt(Key::Char('k'))],
+                go_to_other_panel_dir: vec![KeyBinding::alt(Key::Char('g'))],
+                enter_dir: vec![KeyBinding::simple(Key::Char('l'))],
+                enter_dir_alt: vec![KeyBinding::simple(Key::Enter)],
+                change_panel: vec![
+                    KeyBinding::simple(Key::Tab),
+                    KeyBinding::shift(Key::BackTab),
+                    KeyBinding::simple(Key::Left),
+                    KeyBinding::simple(Key::Right),
+                ],
 
                 // File operations (yazi/ranger style)
-                transfer_file: KeyBinding::simple(Key::Char('p')),
-                copy_file: KeyBinding::simple(Key::Char('c')),
-                rename_file: KeyBinding::simple(Key::Char('r')),
-                delete_file: KeyBinding::simple(Key::Char('d')),
-                mkdir: KeyBinding::simple(Key::Char('a')),
-                new_file: KeyBinding::simple(Key::Char('A')),
-                edit_file: KeyBinding::simple(Key::Char('e')),
-                open_file: KeyBinding::simple(Key::Char('o')),
-                open_with: KeyBinding::simple(Key::Char('O')),
-                save_as: KeyBinding::simple(Key::Char('S')),
-                chmod: KeyBinding::simple(Key::Char('z')),
-                symlink: KeyBinding::simple(Key::Char('K')),
-                reload_dir: KeyBinding::ctrl(Key::Char('r')),
+                transfer_file: vec![KeyBinding::simple(Key::Char('p'))],
+                transfer_file_rsync: vec![KeyBinding::alt(Key::Char('p'))],
+                copy_file: vec![
+                    KeyBinding::simple(Key::Char('c')),
+                    KeyBinding::simple(Key::Function(5)),
+                ],
+                rename_file: vec![
+                    KeyBinding::simple(Key::Char('r')),
+                    KeyBinding::simple(Key::Function(6)),
+                ],
+                delete_file: vec![
+                    KeyBinding::simple(Key::Char('d')),
+                    KeyBinding::simple(Key::Delete),
+                    KeyBinding::simple(Key::Function(8)),
+                ],
+                mkdir: vec![
+                    KeyBinding::simple(Key::Char('a')),
+                    KeyBinding::simple(Key::Function(7)),
+                ],
+                new_file: vec![KeyBinding::simple(Key::Char('A'))],
+                edit_file: vec![
+                    KeyBinding::simple(Key::Char('e')),
+                    KeyBinding::simple(Key::Function(4)),
+                ],
+                open_file: vec![
+                    KeyBinding::simple(Key::Char('o')),
+                    KeyBinding::simple(Key::Function(3)),
+                ],
+                open_with: vec![KeyBinding::simple(Key::Char('O'))],
+                save_as: vec![
+                    KeyBinding::simple(Key::Char('S')),
+                    KeyBinding::simple(Key::Function(2)),
+                ],
+                chmod: vec![KeyBinding::simple(Key::Char('z'))],
+                symlink: vec![KeyBinding::simple(Key::Char('K'))],
+                reload_dir: vec![KeyBinding::ctrl(Key::Char('r'))],
+                mirror_dir: vec![KeyBinding::alt(Key::Char('m'))],
+                sync_dir: vec![KeyBinding::alt(Key::Char('s'))],
+                fanout: vec![KeyBinding::alt(Key::Char('u'))],
+                compress: vec![KeyBinding::ctrl(Key::Char('z'))],
+                extract: vec![KeyBinding::ctrl(Key::Char('e'))],
+                duplicate: vec![KeyBinding::alt(Key::Char('d'))],
+                save_bookmark: vec![KeyBinding::alt(Key::Char('b'))],
+                save_dir_bookmark: vec![KeyBinding::alt(Key::Char('t'))],
+                dir_bookmarks: vec![KeyBinding::ctrl(Key::Char('o'))],
+                transfer_and_open: vec![KeyBinding::alt(Key::Char('v'))],
+                // `p` transfers files, so cut files are put back with `X`
+                cut_file: vec![KeyBinding::simple(Key::Char('x'))],
+                paste: vec![KeyBinding::simple(Key::Char('X'))],
 
                 // Selection (ranger style: space to mark)
-                mark_file: KeyBinding::simple(Key::Char(' ')),
-                mark_all: KeyBinding::simple(Key::Char('V')),
-                unmark_all: KeyBinding::simple(Key::Char('u')),
+                mark_file: vec![KeyBinding::simple(Key::Char(' '))],
+                mark_all: vec![KeyBinding::simple(Key::Char('V'))],
+                unmark_all: vec![KeyBinding::simple(Key::Char('u'))],
+                invert_selection: vec![KeyBinding::alt(Key::Char('i'))],
+                select_by_pattern: vec![KeyBinding::simple(Key::Char('\\'))],
+                copy_marked: vec![KeyBinding::alt(Key::Char('c'))],
+                copy_path: vec![KeyBinding::ctrl(Key::Char('y'))],
 
                 // View
-                toggle_hidden: KeyBinding::simple(Key::Char('.')),
-                file_info: KeyBinding::simple(Key::Char('i')),
-                file_size: KeyBinding::simple(Key::Char('I')),
-                sorting: KeyBinding::simple(Key::Char('s')),
-                filter: KeyBinding::simple(Key::Char('F')),
+                toggle_hidden: vec![KeyBinding::simple(Key::Char('.'))],
+                toggle_hidden_patterns: vec![KeyBinding::alt(Key::Char('h'))],
+                toggle_raw_sizes: vec![KeyBinding::alt(Key::Char('e'))],
+                file_info: vec![KeyBinding::simple(Key::Char('i'))],
+                file_size: vec![KeyBinding::simple(Key::Char('I'))],
+                sorting: vec![KeyBinding::simple(Key::Char('s'))],
+                filter: vec![KeyBinding::simple(Key::Char('F'))],
+                grow_panel: vec![KeyBinding::alt(Key::Char('='))],
+                shrink_panel: vec![KeyBinding::alt(Key::Char('-'))],
+                maximize_panel: vec![KeyBinding::alt(Key::Char('z'))],
 
                 // Search (vim style: / to search)
-                fuzzy_search: KeyBinding::simple(Key::Char('/')),
-                goto_path: KeyBinding::simple(Key::Char(':')),
+                fuzzy_search: vec![KeyBinding::simple(Key::Char('/'))],
+                goto_path: vec![KeyBinding::simple(Key::Char(':'))],
+                toggle_search_regex: vec![KeyBinding::alt(Key::Char('r'))],
+                toggle_search_case: vec![KeyBinding::ctrl(Key::Char('g'))],
+                close_find: vec![KeyBinding::simple(Key::Esc)],
 
                 // Misc
-                terminal: KeyBinding::simple(Key::Char('!')),
-                sync_browsing: KeyBinding::simple(Key::Char('y')),
-                watcher: KeyBinding::simple(Key::Char('w')),
-                watched_paths: KeyBinding::simple(Key::Char('W')),
-                pending_queue: KeyBinding::simple(Key::Char('p')),
+                terminal: vec![KeyBinding::simple(Key::Char('!'))],
+                sync_browsing: vec![KeyBinding::simple(Key::Char('y'))],
+                watcher: vec![KeyBinding::simple(Key::Char('w'))],
+                watched_paths: vec![KeyBinding::simple(Key::Char('W'))],
+                pending_queue: vec![KeyBinding::simple(Key::Char('P'))],
+                toggle_log: vec![KeyBinding::alt(Key::Char('l'))],
+                toggle_footer: vec![KeyBinding::alt(Key::Char('f'))],
+                preview: vec![KeyBinding::ctrl(Key::Char('p'))],
+                next_footer_page: vec![KeyBinding::alt(Key::Char('.'))],
             },
             auth: AuthKeyBindings {
                 quit: KeyBinding::simple(Key::Esc),
@@ -517,6 +1304,7 @@ impl KeyBindings {
                 help_alt: KeyBinding::simple(Key::Function(1)),
                 release_notes: KeyBinding::ctrl(Key::Char('n')),
                 save_bookmark: KeyBinding::ctrl(Key::Char('s')),
+                reconnect: KeyBinding::ctrl(Key::Char('l')),
             },
             setup: SetupKeyBindings {
                 quit: KeyBinding::simple(Key::Esc),
@@ -530,6 +1318,179 @@ impl KeyBindings {
             },
         }
     }
+
+    /// Create emacs-style keybindings inspired by emacs and dired
+    pub fn emacs_style() -> Self {
+        Self {
+            version: KEYBINDINGS_VERSION,
+            global: GlobalKeyBindings {
+                quit: KeyBinding::simple(Key::Char('q')),
+                quit_alt: KeyBinding::simple(Key::Function(10)),
+                disconnect: KeyBinding::simple(Key::Esc),
+                help: KeyBinding::ctrl(Key::Char('h')),
+                help_alt: KeyBinding::simple(Key::Function(1)),
+            },
+            explorer: ExplorerKeyBindings {
+                // Emacs-style navigation (C-n/C-p, C-f/C-b)
+                move_up: vec![
+                    KeyBinding::ctrl(Key::Char('p')),
+                    KeyBinding::simple(Key::Up),
+                ],
+                move_down: vec![
+                    KeyBinding::ctrl(Key::Char('n')),
+                    KeyBinding::simple(Key::Down),
+                ],
+                move_up_page: vec![
+                    KeyBinding::alt(Key::Char('v')),
+                    KeyBinding::simple(Key::PageUp),
+                ],
+                move_down_page: vec![
+                    KeyBinding::ctrl(Key::Char('v')),
+                    KeyBinding::simple(Key::PageDown),
+                ],
+                move_to_top: vec![
+                    KeyBinding::alt(Key::Char('<')),
+                    KeyBinding::simple(Key::Home),
+                ],
+                move_to_bottom: vec![
+                    KeyBinding::alt(Key::Char('>')),
+                    KeyBinding::simple(Key::End),
+                ],
+                leader: vec![],
+                jump_to_entry: vec![KeyBinding::simple(Key::Char('\''))],
+                // Like `C-x r m` to set a bookmark
+                set_dir_mark: vec![KeyBinding::ctrl(Key::Char('x'))],
+                goto_dir_mark: vec![KeyBinding::simple(Key::Char('`'))],
+                go_to_parent: vec![KeyBinding::ctrl(Key::Char('b'))],
+                go_back: vec![KeyBinding::simple(Key::Backspace)],
+                go_forward: vec![KeyBinding::shift(Key::Backspace)],
+                go_home: vec![KeyBinding::simple(Key::Char('~'))],
+                next_sibling_dir: vec![KeyBinding::alt(Key::Char('n'))],
+                prev_sibling_dir: vec![KeyBinding::alt(Key::Char('p'))],
+                go_to_other_panel_dir: vec![KeyBinding::alt(Key::Char('o'))],
+                enter_dir: vec![KeyBinding::ctrl(Key::Char('f'))],
+                enter_dir_alt: vec![KeyBinding::simple(Key::Enter)],
+                change_panel: vec![
+                    KeyBinding::simple(Key::Tab),
+                    KeyBinding::shift(Key::BackTab),
+                    KeyBinding::simple(Key::Left),
+                    KeyBinding::simple(Key::Right),
+                ],
+
+                // File operations (dired style)
+                transfer_file: vec![KeyBinding::simple(Key::Char(' '))],
+                transfer_file_rsync: vec![KeyBinding::alt(Key::Char('r'))],
+                copy_file: vec![
+                    KeyBinding::simple(Key::Char('C')),
+                    KeyBinding::simple(Key::Function(5)),
+                ],
+                rename_file: vec![
+                    KeyBinding::simple(Key::Char('R')),
+                    KeyBinding::simple(Key::Function(6)),
+                ],
+                delete_file: vec![
+                    KeyBinding::simple(Key::Char('D')),
+                    KeyBinding::simple(Key::Delete),
+                    KeyBinding::simple(Key::Function(8)),
+                ],
+                mkdir: vec![
+                    KeyBinding::simple(Key::Char('+')),
+                    KeyBinding::simple(Key::Function(7)),
+                ],
+                new_file: vec![KeyBinding::simple(Key::Char('N'))],
+                edit_file: vec![
+                    KeyBinding::simple(Key::Char('e')),
+                    KeyBinding::simple(Key::Function(4)),
+                ],
+                open_file: vec![
+                    KeyBinding::simple(Key::Char('o')),
+                    KeyBinding::simple(Key::Function(3)),
+                ],
+                open_with: vec![KeyBinding::simple(Key::Char('&'))],
+                save_as: vec![
+                    KeyBinding::ctrl(Key::Char('w')),
+                    KeyBinding::simple(Key::Function(2)),
+                ],
+                chmod: vec![KeyBinding::simple(Key::Char('M'))],
+                symlink: vec![KeyBinding::simple(Key::Char('S'))],
+                reload_dir: vec![KeyBinding::simple(Key::Char('g'))],
+                mirror_dir: vec![KeyBinding::alt(Key::Char('m'))],
+                sync_dir: vec![KeyBinding::alt(Key::Char('s'))],
+                fanout: vec![KeyBinding::alt(Key::Char('u'))],
+                compress: vec![KeyBinding::ctrl(Key::Char('z'))],
+                extract: vec![KeyBinding::ctrl(Key::Char('e'))],
+                duplicate: vec![KeyBinding::ctrl(Key::Char('d'))],
+                save_bookmark: vec![KeyBinding::alt(Key::Char('b'))],
+                save_dir_bookmark: vec![KeyBinding::alt(Key::Char('t'))],
+                dir_bookmarks: vec![KeyBinding::ctrl(Key::Char('o'))],
+                transfer_and_open: vec![KeyBinding::simple(Key::Char('v'))],
+                // Kill and yank
+                cut_file: vec![KeyBinding::ctrl(Key::Char('k'))],
+                paste: vec![KeyBinding::ctrl(Key::Char('y'))],
+
+                // Selection (dired style: m to mark, U to unmark all)
+                mark_file: vec![KeyBinding::simple(Key::Char('m'))],
+                mark_all: vec![KeyBinding::simple(Key::Char('*'))],
+                unmark_all: vec![KeyBinding::simple(Key::Char('U'))],
+                // Like dired's toggle marks
+                invert_selection: vec![KeyBinding::simple(Key::Char('t'))],
+                select_by_pattern: vec![KeyBinding::simple(Key::Char('\\'))],
+                copy_marked: vec![KeyBinding::alt(Key::Char('c'))],
+                // Like kill-ring-save
+                copy_path: vec![KeyBinding::alt(Key::Char('w'))],
+
+                // View
+                toggle_hidden: vec![KeyBinding::simple(Key::Char('.'))],
+                toggle_hidden_patterns: vec![KeyBinding::alt(Key::Char('h'))],
+                toggle_raw_sizes: vec![KeyBinding::alt(Key::Char('e'))],
+                file_info: vec![KeyBinding::simple(Key::Char('i'))],
+                file_size: vec![KeyBinding::simple(Key::Char('I'))],
+                sorting: vec![KeyBinding::simple(Key::Char('s'))],
+                filter: vec![KeyBinding::simple(Key::Char('%'))],
+                grow_panel: vec![KeyBinding::alt(Key::Char('='))],
+                shrink_panel: vec![KeyBinding::alt(Key::Char('-'))],
+                maximize_panel: vec![KeyBinding::alt(Key::Char('z'))],
+
+                // Search (emacs style: C-s to search, C-g to quit)
+                fuzzy_search: vec![KeyBinding::ctrl(Key::Char('s'))],
+                goto_path: vec![KeyBinding::simple(Key::Char('j'))],
+                toggle_search_regex: vec![KeyBinding::ctrl(Key::Char('r'))],
+                toggle_search_case: vec![KeyBinding::ctrl(Key::Char('u'))],
+                close_find: vec![KeyBinding::ctrl(Key::Char('g'))],
+
+                // Misc
+                terminal: vec![KeyBinding::simple(Key::Char('!'))],
+                sync_browsing: vec![KeyBinding::simple(Key::Char('y'))],
+                watcher: vec![KeyBinding::simple(Key::Char('w'))],
+                watched_paths: vec![KeyBinding::simple(Key::Char('W'))],
+                pending_queue: vec![KeyBinding::simple(Key::Char('P'))],
+                toggle_log: vec![KeyBinding::alt(Key::Char('l'))],
+                toggle_footer: vec![KeyBinding::alt(Key::Char('f'))],
+                preview: vec![KeyBinding::alt(Key::Char('q'))],
+                next_footer_page: vec![KeyBinding::alt(Key::Char('.'))],
+            },
+            auth: AuthKeyBindings {
+                quit: KeyBinding::simple(Key::Esc),
+                quit_alt: KeyBinding::ctrl(Key::Char('g')),
+                setup: KeyBinding::ctrl(Key::Char('c')),
+                help: KeyBinding::ctrl(Key::Char('h')),
+                help_alt: KeyBinding::simple(Key::Function(1)),
+                release_notes: KeyBinding::ctrl(Key::Char('r')),
+                save_bookmark: KeyBinding::ctrl(Key::Char('s')),
+                reconnect: KeyBinding::ctrl(Key::Char('l')),
+            },
+            setup: SetupKeyBindings {
+                quit: KeyBinding::simple(Key::Esc),
+                quit_alt: KeyBinding::ctrl(Key::Char('g')),
+                change_tab: KeyBinding::simple(Key::Tab),
+                help: KeyBinding::ctrl(Key::Char('h')),
+                help_alt: KeyBinding::simple(Key::Function(1)),
+                revert: KeyBinding::ctrl(Key::Char('r')),
+                save: KeyBinding::ctrl(Key::Char('s')),
+                save_alt: KeyBinding::ctrl(Key::Char('w')),
+            },
+        }
+    }
 }
 
 #[cfg(test)]
@@ -579,6 +1540,103 @@ mod tests {
         assert_eq!(KeyBinding::simple(Key::Function(1)).to_string(), "f1");
     }
 
+    #[test]
+    fn test_key_binding_with_three_modifiers() {
+        let all = KeyModifiers::CONTROL | KeyModifiers::SHIFT | KeyModifiers::ALT;
+        let kb = KeyBinding::from_str("ctrl+shift+alt+a").unwrap();
+        assert_eq!(kb, KeyBinding::new(Key::Char('a'), all));
+        // Modifiers are displayed in the ctrl, alt, shift order, whatever the order they were written in
+        assert_eq!(kb.to_string(), "ctrl+alt+shift+a");
+        assert_eq!(
+            KeyBinding::from_str("shift+alt+ctrl+a").unwrap(),
+            KeyBinding::new(Key::Char('a'), all)
+        );
+        assert!(KeyBinding::from_str("ctrl+shift+super+a").is_err());
+    }
+
+    #[test]
+    fn test_key_binding_display_round_trip() {
+        for s in [
+            "ctrl+shift+alt+a",
+            "shift+ctrl+a",
+            "alt+shift+f5",
+            "shift+alt+ctrl+pageup",
+            "meta+c+enter",
+        ] {
+            let kb = KeyBinding::from_str(s).unwrap();
+            assert_eq!(KeyBinding::from_str(&kb.to_string()).unwrap(), kb);
+        }
+    }
+
+    #[test]
+    fn test_key_binding_with_two_modifiers_round_trip() {
+        let kb = KeyBinding::from_str("alt+shift+f5").unwrap();
+        assert_eq!(
+            kb,
+            KeyBinding::new(Key::Function(5), KeyModifiers::ALT | KeyModifiers::SHIFT)
+        );
+        assert_eq!(kb.to_string(), "alt+shift+f5");
+        assert_eq!(KeyBinding::from_str(&kb.to_string()).unwrap(), kb);
+    }
+
+    #[test]
+    fn test_key_binding_super_modifier_is_unsupported() {
+        for (name, modifier) in [
+            ("super+t", "super"),
+            ("cmd+t", "cmd"),
+            ("win+t", "win"),
+            ("ctrl+super+t", "super"),
+        ] {
+            assert_eq!(
+                KeyBinding::from_str(name).unwrap_err(),
+                format!("Unsupported modifier: {modifier}")
+            );
+        }
+        // meta is still alt
+        assert_eq!(
+            KeyBinding::from_str("meta+t").unwrap(),
+            KeyBinding::alt(Key::Char('t'))
+        );
+    }
+
+    #[test]
+    fn test_key_binding_keypad_digits() {
+        for digit in '0'..='9' {
+            for name in [
+                format!("kp{digit}"),
+                format!("kp_{digit}"),
+                format!("numpad{digit}"),
+            ] {
+                let kb = KeyBinding::from_str(&name).unwrap();
+                assert_eq!(kb, KeyBinding::simple(Key::Char(digit)));
+                assert_eq!(KeyBinding::from_str(&kb.to_string()).unwrap(), kb);
+            }
+        }
+        assert!(KeyBinding::from_str("kp10").is_err());
+        assert!(KeyBinding::from_str("numpad").is_err());
+    }
+
+    #[test]
+    fn test_key_binding_keypad_keys() {
+        for (name, key) in [
+            ("kp_enter", Key::Enter),
+            ("kp_plus", Key::Char('+')),
+            ("kp_minus", Key::Char('-')),
+            ("kp_multiply", Key::Char('*')),
+            ("kp_divide", Key::Char('/')),
+            ("kp_decimal", Key::Char('.')),
+            ("kp_begin", Key::KeypadBegin),
+        ] {
+            let kb = KeyBinding::from_str(name).unwrap();
+            assert_eq!(kb, KeyBinding::simple(key));
+            assert_eq!(KeyBinding::from_str(&kb.to_string()).unwrap(), kb);
+        }
+        // `+` is written as `plus`, since it separates the modifiers
+        let kb = KeyBinding::alt(Key::Char('+'));
+        assert_eq!(kb.to_string(), "alt+plus");
+        assert_eq!(KeyBinding::from_str("alt+plus").unwrap(), kb);
+    }
+
     #[test]
     fn test_key_binding_matches() {
         let kb = KeyBinding::ctrl(Key::Char('a'));
@@ -590,17 +1648,200 @@ mod tests {
     #[test]
     fn test_default_keybindings() {
         let kb = KeyBindings::default();
-        assert_eq!(kb.explorer.move_up, KeyBinding::simple(Key::Up));
-        assert_eq!(kb.explorer.move_down, KeyBinding::simple(Key::Down));
+        assert_eq!(kb.explorer.move_up, vec![KeyBinding::simple(Key::Up)]);
+        assert_eq!(kb.explorer.move_down, vec![KeyBinding::simple(Key::Down)]);
+    }
+
+    #[test]
+    fn test_partial_keybindings_use_defaults() {
+        let toml = "[explorer]\nmove_up = \"w\"\n";
+        let kb: KeyBindings =
+            crate::config::serialization::deserialize(Box::new(std::io::Cursor::new(toml)))
+                .unwrap();
+        let mut expected = KeyBindings::default();
+        expected.explorer.move_up = vec![KeyBinding::simple(Key::Char('w'))];
+        // files without version predate versioning
+        expected.version = 0;
+        assert_eq!(kb, expected);
+    }
+
+    #[test]
+    fn test_keybindings_to_json() {
+        let mut kb = KeyBindings::default();
+        kb.explorer.move_up = vec![
+            KeyBinding::simple(Key::Char('k')),
+            KeyBinding::simple(Key::Up),
+        ];
+        let json = kb.to_json().unwrap();
+        assert!(json.contains(r#""quit": "q""#));
+        assert!(json.contains(r#""move_up": ["#));
+        let deserialized: KeyBindings = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, kb);
+    }
+
+    #[test]
+    fn test_explorer_action_bindings() {
+        let mut kb = KeyBindings::default();
+        kb.explorer.mkdir = vec![
+            KeyBinding::simple(Key::Char('d')),
+            KeyBinding::simple(Key::Function(7)),
+        ];
+        assert_eq!(
+            kb.explorer_action("mkdir"),
+            Some(vec![
+                KeyBinding::simple(Key::Char('d')),
+                KeyBinding::simple(Key::Function(7))
+            ])
+        );
+        assert_eq!(
+            kb.explorer_action("help"),
+            Some(vec![KeyBinding::simple(Key::Char('h'))])
+        );
+        // unbound actions exist all the same
+        assert_eq!(kb.explorer_action("leader"), Some(vec![]));
+        assert_eq!(kb.explorer_action("release_notes"), None);
+        assert_eq!(kb.explorer_action("mkdirr"), None);
+    }
+
+    #[test]
+    fn test_default_keybindings_have_no_conflicts() {
+        assert_eq!(KeyBindings::default().validate(), Ok(()));
+        assert_eq!(KeyBindings::vim_style().validate(), Ok(()));
+        assert_eq!(KeyBindings::emacs_style().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_reports_enter_dir_alt_reload_dir_clash() {
+        let mut kb = KeyBindings::default();
+        kb.explorer.enter_dir_alt = vec![KeyBinding::simple(Key::Char('l'))];
+        let conflicts = kb.validate().unwrap_err();
+        assert_eq!(
+            conflicts,
+            vec![KeyBindingConflict {
+                binding: KeyBinding::simple(Key::Char('l')),
+                actions: vec![
+                    String::from("explorer.enter_dir_alt"),
+                    String::from("explorer.reload_dir")
+                ],
+            }]
+        );
+        assert_eq!(
+            conflicts[0].to_string(),
+            "\"l\" is bound to explorer.enter_dir_alt, explorer.reload_dir; only explorer.enter_dir_alt is triggered"
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_copy_file_mkdir_clash() {
+        let mut kb = KeyBindings::default();
+        kb.explorer.copy_file = vec![KeyBinding::simple(Key::Char('c'))];
+        kb.explorer.mkdir = vec![KeyBinding::simple(Key::Char('c'))];
+        let conflicts = kb.validate().unwrap_err();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].binding, KeyBinding::simple(Key::Char('c')));
+        assert_eq!(
+            conflicts[0].actions,
+            vec![
+                String::from("explorer.copy_file"),
+                String::from("explorer.mkdir")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_checks_scopes_separately() {
+        let mut kb = KeyBindings::default();
+        // same key in different scopes is fine
+        kb.auth.setup = KeyBinding::simple(Key::Char('d'));
+        assert_eq!(kb.validate(), Ok(()));
+        // same key in the same scope is reported
+        kb.setup.revert = kb.setup.save.clone();
+        assert_eq!(
+            kb.validate().unwrap_err(),
+            vec![KeyBindingConflict {
+                binding: KeyBinding::ctrl(Key::Char('s')),
+                actions: vec![String::from("setup.revert"), String::from("setup.save")],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate_allows_alt_binding_of_same_action() {
+        let mut kb = KeyBindings::default();
+        kb.explorer.enter_dir_alt = kb.explorer.enter_dir.clone();
+        kb.global.quit_alt = kb.global.quit.clone();
+        assert_eq!(kb.validate(), Ok(()));
     }
 
     #[test]
     fn test_vim_style_keybindings() {
         let kb = KeyBindings::vim_style();
-        assert_eq!(kb.explorer.move_up, KeyBinding::simple(Key::Char('k')));
-        assert_eq!(kb.explorer.move_down, KeyBinding::simple(Key::Char('j')));
-        assert_eq!(kb.explorer.enter_dir, KeyBinding::simple(Key::Char('l')));
-        assert_eq!(kb.explorer.go_to_parent, KeyBinding::simple(Key::Char('h')));
+        assert_eq!(
+            kb.explorer.move_up,
+            vec![
+                KeyBinding::simple(Key::Char('k')),
+                KeyBinding::simple(Key::Up)
+            ]
+        );
+        assert_eq!(
+            kb.explorer.move_down,
+            vec![
+                KeyBinding::simple(Key::Char('j')),
+                KeyBinding::simple(Key::Down)
+            ]
+        );
+        assert_eq!(
+            kb.explorer.enter_dir,
+            vec![KeyBinding::simple(Key::Char('l'))]
+        );
+        assert_eq!(
+            kb.explorer.go_to_parent,
+            vec![KeyBinding::simple(Key::Char('h'))]
+        );
+        assert_eq!(kb.explorer.leader, vec![KeyBinding::simple(Key::Char('g'))]);
+        assert!(KeyBindings::default().explorer.leader.is_empty());
     }
-}
 
+    #[test]
+    fn test_keybindings_presets() {
+        assert_eq!(
+            KeyBindingsPreset::Default.keybindings(),
+            KeyBindings::default()
+        );
+        assert_eq!(
+            KeyBindingsPreset::Vim.keybindings(),
+            KeyBindings::vim_style()
+        );
+        assert_eq!(
+            KeyBindingsPreset::Emacs.keybindings(),
+            KeyBindings::emacs_style()
+        );
+    }
+
+    #[test]
+    fn test_emacs_style_keybindings() {
+        let kb = KeyBindings::emacs_style();
+        assert_eq!(
+            kb.explorer.move_up,
+            vec![
+                KeyBinding::ctrl(Key::Char('p')),
+                KeyBinding::simple(Key::Up)
+            ]
+        );
+        assert_eq!(
+            kb.explorer.move_down,
+            vec![
+                KeyBinding::ctrl(Key::Char('n')),
+                KeyBinding::simple(Key::Down)
+            ]
+        );
+        assert_eq!(
+            kb.explorer.enter_dir,
+            vec![KeyBinding::ctrl(Key::Char('f'))]
+        );
+        assert_eq!(
+            kb.explorer.go_to_parent,
+            vec![KeyBinding::ctrl(Key::Char('b'))]
+        );
+    }
+}
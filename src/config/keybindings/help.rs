@@ -0,0 +1,432 @@
+//! ## Help
+//!
+//! rows of the keybindings help popups, built from the active keybindings
+
+use std::fmt;
+
+use tuirealm::event::Key;
+
+use super::{KeyBinding, KeyBindings};
+
+/// Category of the actions in the keybindings help
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HelpCategory {
+    General,
+    Navigation,
+    Selection,
+    FileOperations,
+    Transfers,
+    View,
+    Bookmarks,
+    Configuration,
+}
+
+impl fmt::Display for HelpCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::General => "General",
+            Self::Navigation => "Navigation",
+            Self::Selection => "Selection",
+            Self::FileOperations => "File operations",
+            Self::Transfers => "Transfers",
+            Self::View => "View",
+            Self::Bookmarks => "Bookmarks",
+            Self::Configuration => "Configuration",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Row of the keybindings help: an action with its keys
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HelpRow {
+    pub category: HelpCategory,
+    pub label: &'static str,
+    pub bindings: Vec<KeyBinding>,
+}
+
+type HelpSection = (HelpCategory, Vec<(&'static str, Vec<KeyBinding>)>);
+
+impl KeyBindings {
+    /// Get the rows of the file explorer help, grouped by category
+    pub fn explorer_help(&self) -> Vec<HelpRow> {
+        let global = &self.global;
+        let explorer = &self.explorer;
+        help_rows(vec![
+            (
+                HelpCategory::General,
+                vec![
+                    (
+                        "Show help",
+                        vec![global.help.clone(), global.help_alt.clone()],
+                    ),
+                    ("Disconnect", vec![global.disconnect.clone()]),
+                    (
+                        "Quit termscp",
+                        vec![global.quit.clone(), global.quit_alt.clone()],
+                    ),
+                    (
+                        "Save current session as bookmark",
+                        explorer.save_bookmark.clone(),
+                    ),
+                    (
+                        "Bookmark remote directory",
+                        explorer.save_dir_bookmark.clone(),
+                    ),
+                    ("Go to directory bookmark", explorer.dir_bookmarks.clone()),
+                    ("Execute shell command", explorer.terminal.clone()),
+                ],
+            ),
+            (
+                HelpCategory::Navigation,
+                vec![
+                    ("Move up in list", explorer.move_up.clone()),
+                    ("Move down in list", explorer.move_down.clone()),
+                    ("Change explorer tab", explorer.change_panel.clone()),
+                    ("Enter directory", explorer.enter_dir.clone()),
+                    ("Go to parent directory", explorer.go_to_parent.clone()),
+                    ("Go to previous directory", explorer.go_back.clone()),
+                    ("Go forward to next directory", explorer.go_forward.clone()),
+                    ("Go to home directory", explorer.go_home.clone()),
+                    (
+                        "Go to previous sibling directory",
+                        explorer.prev_sibling_dir.clone(),
+                    ),
+                    (
+                        "Go to next sibling directory",
+                        explorer.next_sibling_dir.clone(),
+                    ),
+                    (
+                        "Go to the other panel's directory",
+                        explorer.go_to_other_panel_dir.clone(),
+                    ),
+                    ("Go to path", explorer.goto_path.clone()),
+                    (
+                        "Jump to next entry starting with char",
+                        explorer.jump_to_entry.clone(),
+                    ),
+                    ("Mark directory with char", explorer.set_dir_mark.clone()),
+                    (
+                        "Go to directory marked with char",
+                        explorer.goto_dir_mark.clone(),
+                    ),
+                    ("Search files", explorer.fuzzy_search.clone()),
+                    (
+                        "Switch fuzzy/regex search while searching",
+                        explorer.toggle_search_regex.clone(),
+                    ),
+                    (
+                        "Toggle regex search case sensitivity",
+                        explorer.toggle_search_case.clone(),
+                    ),
+                    ("Close search results", explorer.close_find.clone()),
+                    ("Reload directory content", explorer.reload_dir.clone()),
+                ],
+            ),
+            (
+                HelpCategory::Selection,
+                vec![
+                    ("Select/mark file", explorer.mark_file.clone()),
+                    ("Select all files", explorer.mark_all.clone()),
+                    ("Deselect all files", explorer.unmark_all.clone()),
+                    ("Invert selection", explorer.invert_selection.clone()),
+                    (
+                        "Select files matching pattern",
+                        explorer.select_by_pattern.clone(),
+                    ),
+                    ("Copy paths of selected files", explorer.copy_marked.clone()),
+                    ("Copy path of highlighted file", explorer.copy_path.clone()),
+                ],
+            ),
+            (
+                HelpCategory::FileOperations,
+                vec![
+                    ("Copy", explorer.copy_file.clone()),
+                    ("Duplicate file", explorer.duplicate.clone()),
+                    ("Rename file", explorer.rename_file.clone()),
+                    ("Cut file to move it", explorer.cut_file.clone()),
+                    ("Move cut files here", explorer.paste.clone()),
+                    ("Delete selected file", explorer.delete_file.clone()),
+                    ("Make directory", explorer.mkdir.clone()),
+                    ("Create new file", explorer.new_file.clone()),
+                    ("Create symlink", explorer.symlink.clone()),
+                    ("Open text file with editor", explorer.edit_file.clone()),
+                    ("Open file with default app", explorer.open_file.clone()),
+                    ("Open file with specified app", explorer.open_with.clone()),
+                    ("Save file as", explorer.save_as.clone()),
+                    ("Change file permissions", explorer.chmod.clone()),
+                    ("Show info about selected file", explorer.file_info.clone()),
+                    ("Get total path size", explorer.file_size.clone()),
+                    ("Watch/unwatch file changes", explorer.watcher.clone()),
+                    ("Show watched paths", explorer.watched_paths.clone()),
+                ],
+            ),
+            (
+                HelpCategory::Transfers,
+                vec![
+                    ("Upload/Download file", explorer.transfer_file.clone()),
+                    (
+                        "Upload/Download file with rsync",
+                        explorer.transfer_file_rsync.clone(),
+                    ),
+                    (
+                        "Download file and open it",
+                        explorer.transfer_and_open.clone(),
+                    ),
+                    (
+                        "Mirror directory to the other panel",
+                        explorer.mirror_dir.clone(),
+                    ),
+                    (
+                        "Sync directories in both directions",
+                        explorer.sync_dir.clone(),
+                    ),
+                    ("Upload file to several bookmarks", explorer.fanout.clone()),
+                    (
+                        "Compress selected files into an archive",
+                        explorer.compress.clone(),
+                    ),
+                    ("Extract selected archive", explorer.extract.clone()),
+                    (
+                        "Toggle synchronized browsing",
+                        explorer.sync_browsing.clone(),
+                    ),
+                    (
+                        "Interrupt file transfer",
+                        vec![KeyBinding::ctrl(Key::Char('c'))],
+                    ),
+                ],
+            ),
+            (
+                HelpCategory::View,
+                vec![
+                    ("Toggle hidden files", explorer.toggle_hidden.clone()),
+                    (
+                        "Toggle files hidden by patterns",
+                        explorer.toggle_hidden_patterns.clone(),
+                    ),
+                    (
+                        "Toggle human readable/exact file sizes",
+                        explorer.toggle_raw_sizes.clone(),
+                    ),
+                    ("Change file sorting mode", explorer.sorting.clone()),
+                    ("Filter files", explorer.filter.clone()),
+                    ("Toggle bottom panel", explorer.pending_queue.clone()),
+                    ("Show/hide log panel", explorer.toggle_log.clone()),
+                    ("Show/hide footer", explorer.toggle_footer.clone()),
+                    ("Show/hide file preview", explorer.preview.clone()),
+                    ("Show next footer hints", explorer.next_footer_page.clone()),
+                    ("Grow focused panel", explorer.grow_panel.clone()),
+                    ("Shrink focused panel", explorer.shrink_panel.clone()),
+                    (
+                        "Maximize/restore focused panel",
+                        explorer.maximize_panel.clone(),
+                    ),
+                ],
+            ),
+        ])
+    }
+
+    /// Get the rows of the authentication form help, grouped by category
+    pub fn auth_help(&self) -> Vec<HelpRow> {
+        let auth = &self.auth;
+        help_rows(vec![
+            (
+                HelpCategory::General,
+                vec![
+                    ("Show help", vec![auth.help.clone(), auth.help_alt.clone()]),
+                    ("Show release notes", vec![auth.release_notes.clone()]),
+                    ("Enter setup", vec![auth.setup.clone()]),
+                    (
+                        "Quit termscp",
+                        vec![auth.quit.clone(), auth.quit_alt.clone()],
+                    ),
+                ],
+            ),
+            (
+                HelpCategory::Navigation,
+                vec![
+                    (
+                        "Switch from form and bookmarks",
+                        vec![KeyBinding::simple(Key::Tab)],
+                    ),
+                    (
+                        "Switch bookmark tab",
+                        vec![
+                            KeyBinding::simple(Key::Right),
+                            KeyBinding::simple(Key::Left),
+                        ],
+                    ),
+                    (
+                        "Move up/down in current tab",
+                        vec![KeyBinding::simple(Key::Up), KeyBinding::simple(Key::Down)],
+                    ),
+                ],
+            ),
+            (
+                HelpCategory::Bookmarks,
+                vec![
+                    (
+                        "Connect/Load bookmark",
+                        vec![KeyBinding::simple(Key::Enter)],
+                    ),
+                    ("Save bookmark", vec![auth.save_bookmark.clone()]),
+                    (
+                        "Reconnect to the last session",
+                        vec![auth.reconnect.clone()],
+                    ),
+                    (
+                        "Delete selected bookmark",
+                        vec![
+                            KeyBinding::simple(Key::Delete),
+                            KeyBinding::simple(Key::Char('e')),
+                        ],
+                    ),
+                ],
+            ),
+        ])
+    }
+
+    /// Get the rows of the setup help, grouped by category
+    pub fn setup_help(&self) -> Vec<HelpRow> {
+        let setup = &self.setup;
+        help_rows(vec![
+            (
+                HelpCategory::General,
+                vec![
+                    (
+                        "Show help",
+                        vec![setup.help.clone(), setup.help_alt.clone()],
+                    ),
+                    (
+                        "Exit setup",
+                        vec![setup.quit.clone(), setup.quit_alt.clone()],
+                    ),
+                ],
+            ),
+            (
+                HelpCategory::Navigation,
+                vec![
+                    ("Change setup page", vec![setup.change_tab.clone()]),
+                    (
+                        "Change cursor",
+                        vec![
+                            KeyBinding::simple(Key::Right),
+                            KeyBinding::simple(Key::Left),
+                        ],
+                    ),
+                    (
+                        "Change input field",
+                        vec![KeyBinding::simple(Key::Up), KeyBinding::simple(Key::Down)],
+                    ),
+                    (
+                        "Select / Dismiss popup",
+                        vec![KeyBinding::simple(Key::Enter)],
+                    ),
+                ],
+            ),
+            (
+                HelpCategory::Configuration,
+                vec![
+                    (
+                        "Save configuration",
+                        vec![setup.save.clone(), setup.save_alt.clone()],
+                    ),
+                    ("Revert changes", vec![setup.revert.clone()]),
+                    ("New SSH key", vec![KeyBinding::ctrl(Key::Char('n'))]),
+                    (
+                        "Delete SSH key",
+                        vec![
+                            KeyBinding::simple(Key::Delete),
+                            KeyBinding::simple(Key::Char('e')),
+                        ],
+                    ),
+                ],
+            ),
+        ])
+    }
+}
+
+/// Flatten the sections of a help into its rows
+fn help_rows(sections: Vec<HelpSection>) -> Vec<HelpRow> {
+    sections
+        .into_iter()
+        .flat_map(|(category, rows)| {
+            rows.into_iter().map(move |(label, bindings)| HelpRow {
+                category,
+                label,
+                bindings,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_build_help_from_config() {
+        let mut keybindings = KeyBindings::default();
+        keybindings.explorer.copy_file = vec![KeyBinding::simple(Key::Char('c'))];
+        keybindings.global.quit = KeyBinding::ctrl(Key::Char('q'));
+        let rows = keybindings.explorer_help();
+        assert_eq!(
+            rows.iter().find(|x| x.label == "Copy").unwrap(),
+            &HelpRow {
+                category: HelpCategory::FileOperations,
+                label: "Copy",
+                bindings: vec![KeyBinding::simple(Key::Char('c'))],
+            }
+        );
+        assert_eq!(
+            rows.iter()
+                .find(|x| x.label == "Quit termscp")
+                .unwrap()
+                .bindings,
+            vec![
+                KeyBinding::ctrl(Key::Char('q')),
+                KeyBinding::simple(Key::Function(10))
+            ]
+        );
+        // The setup and authentication helps use their own keybindings
+        keybindings.setup.revert = KeyBinding::ctrl(Key::Char('z'));
+        assert_eq!(
+            keybindings
+                .setup_help()
+                .iter()
+                .find(|x| x.label == "Revert changes")
+                .unwrap()
+                .bindings,
+            vec![KeyBinding::ctrl(Key::Char('z'))]
+        );
+        assert!(
+            keybindings
+                .auth_help()
+                .iter()
+                .any(|x| x.label == "Save bookmark")
+        );
+    }
+
+    #[test]
+    fn should_group_help_by_category() {
+        let keybindings = KeyBindings::default();
+        for rows in [
+            keybindings.explorer_help(),
+            keybindings.auth_help(),
+            keybindings.setup_help(),
+        ] {
+            // each category is listed once, with all its rows
+            let mut categories: Vec<HelpCategory> = rows.iter().map(|x| x.category).collect();
+            categories.dedup();
+            let mut unique = categories.clone();
+            unique.sort_by_key(|x| *x as usize);
+            unique.dedup();
+            assert_eq!(categories.len(), unique.len());
+            assert!(rows.iter().all(|x| !x.bindings.is_empty()));
+        }
+    }
+}
@@ -8,4 +8,5 @@ pub mod bookmarks;
 pub mod keybindings;
 pub mod params;
 pub mod serialization;
+pub mod sync;
 pub mod themes;
@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 use crate::filetransfer::FileTransferProtocol;
 
 pub const DEFAULT_NOTIFICATION_TRANSFER_THRESHOLD: u64 = 536870912; // 512MB
+pub const DEFAULT_PANEL_SPLIT_RATIO: u16 = 50;
 
 #[derive(Deserialize, Serialize, Debug, Default)]
 /// UserConfig contains all the configurations for the user,
@@ -35,6 +36,51 @@ pub struct UserInterfaceConfig {
     pub remote_file_fmt: Option<String>,     // @! Since 0.5.0
     pub notifications: Option<bool>,         // @! Since 0.7.0; Default true
     pub notification_threshold: Option<u64>, // @! Since 0.7.0; Default 512MB
+    /// Date format for file listings: either a strftime pattern or `relative`
+    pub date_fmt: Option<String>, // @! Since 0.19.2
+    /// Locale for number grouping and dates in listings (e.g. `de_DE` or `system`)
+    pub locale: Option<String>, // @! Since 0.19.2
+    /// Width of the host bridge panel as a percentage of the explorers area
+    pub panel_split_ratio: Option<u16>, // @! Since 0.19.2; Default 50
+    /// Seconds after which confirmation popups select their safe option; if unset, they wait indefinitely
+    pub confirmation_timeout: Option<u64>, // @! Since 0.19.2
+    /// Show a summary of the connection parameters before connecting
+    pub connection_summary: Option<bool>, // @! Since 0.19.2; Default false
+    /// Quit without the confirmation popup, unless a transfer is in progress
+    pub quit_immediately: Option<bool>, // @! Since 0.19.2; Default false
+    /// Hide the footer bar in the file transfer activity
+    pub hide_footer: Option<bool>, // @! Since 0.19.2; Default false
+    /// Shorten long paths in explorer titles keeping their tail visible, instead of eliding their middle
+    pub title_path_tail: Option<bool>, // @! Since 0.19.2; Default false
+    /// Prompt before replacing an existing file with "save as", even if `prompt_on_file_replace` is disabled
+    pub prompt_on_save_as_replace: Option<bool>, // @! Since 0.19.2; Default true
+    /// Wildmatch patterns of files hidden from listings (e.g. build artifacts); patterns ending with `/` match directories only
+    pub hidden_patterns: Option<Vec<String>>, // @! Since 0.19.2; Default empty
+    /// Action performed when `<ENTER>` is pressed on a file: nothing, open, transfer or edit
+    pub file_submit_action: Option<String>, // @! Since 0.19.2; Default "nothing"
+    /// Give focus to the panel which was focused at the last disconnection, instead of the host bridge
+    pub restore_focused_panel: Option<bool>, // @! Since 0.19.2; Default false
+    /// Panel focused at the last disconnection (`local` or `remote`); written by termscp
+    pub last_focused_panel: Option<String>, // @! Since 0.19.2
+    /// Style of the cursor row in the explorers: color, reverse, bold or underline
+    pub highlight_style: Option<String>, // @! Since 0.19.2; Default "color"
+    /// Sort file names case-sensitively, so uppercase names come before lowercase ones
+    pub case_sensitive_sorting: Option<bool>, // @! Since 0.19.2; Default false
+    /// Ask for confirmation before disconnecting from the host bridge panel
+    pub confirm_disconnect: Option<bool>, // @! Since 0.19.2; Default true
+    /// Move the cursor to the next file after marking a file
+    pub advance_on_mark: Option<bool>, // @! Since 0.19.2; Default true
+    /// Actions whose keys are shown in the footer bar, in order (e.g. `help`, `transfer_file`)
+    pub footer_hints: Option<Vec<String>>, // @! Since 0.19.2; Default help, change_panel, transfer_file...
+    /// Display file sizes as the exact count of bytes, instead of human readable (e.g. `1.5 KiB`)
+    pub raw_file_sizes: Option<bool>, // @! Since 0.19.2; Default false
+    /// Display human readable sizes with SI units (1000-based, e.g. `MB`), instead of binary units (1024-based, e.g. `MiB`)
+    pub si_size_units: Option<bool>, // @! Since 0.19.2; Default false
+    /// Save the sorting and the filter of the explorers in the bookmark of the session, to restore them on the next connection
+    pub bookmark_sort_and_filter: Option<bool>, // @! Since 0.19.2; Default false
+    /// Association between file extension and command to open the files with (e.g. `md = "glow {}"`)
+    /// NOTE: this parameter must stay as last: <https://github.com/alexcrichton/toml-rs/issues/142>
+    pub open_with: Option<HashMap<String, String>>, // @! Since 0.19.2; Default empty
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -42,6 +88,8 @@ pub struct UserInterfaceConfig {
 pub struct RemoteConfig {
     /// Ssh configuration path. If NONE, won't be read
     pub ssh_config: Option<String>,
+    /// Use rsync over ssh to transfer files when available
+    pub rsync: Option<bool>, // @! Since 0.19.2; Default false
     /// Association between host name and path to private key
     /// NOTE: this parameter must stay as last: <https://github.com/alexcrichton/toml-rs/issues/142>
     pub ssh_keys: HashMap<String, PathBuf>,
@@ -62,6 +110,7 @@ impl Default for RemoteConfig {
 
         Self {
             ssh_config: ssh_config_path,
+            rsync: Some(false),
             ssh_keys: HashMap::default(),
         }
     }
@@ -83,6 +132,28 @@ impl Default for UserInterfaceConfig {
             remote_file_fmt: None,
             notifications: Some(true),
             notification_threshold: Some(DEFAULT_NOTIFICATION_TRANSFER_THRESHOLD),
+            date_fmt: None,
+            locale: None,
+            panel_split_ratio: Some(DEFAULT_PANEL_SPLIT_RATIO),
+            confirmation_timeout: None,
+            connection_summary: Some(false),
+            quit_immediately: Some(false),
+            hide_footer: Some(false),
+            title_path_tail: Some(false),
+            prompt_on_save_as_replace: Some(true),
+            hidden_patterns: None,
+            file_submit_action: None,
+            restore_focused_panel: Some(false),
+            last_focused_panel: None,
+            highlight_style: None,
+            case_sensitive_sorting: Some(false),
+            confirm_disconnect: Some(true),
+            advance_on_mark: Some(true),
+            footer_hints: None,
+            raw_file_sizes: Some(false),
+            si_size_units: Some(false),
+            bookmark_sort_and_filter: Some(false),
+            open_with: None,
         }
     }
 }
@@ -106,6 +177,7 @@ mod tests {
         let remote: RemoteConfig = RemoteConfig {
             ssh_keys: keys,
             ssh_config: Some(String::from("~/.ssh/config")),
+            rsync: Some(true),
         };
         let ui: UserInterfaceConfig = UserInterfaceConfig {
             default_protocol: String::from("SFTP"),
@@ -118,6 +190,31 @@ mod tests {
             remote_file_fmt: Some(String::from("{USER}")),
             notifications: Some(true),
             notification_threshold: Some(DEFAULT_NOTIFICATION_TRANSFER_THRESHOLD),
+            date_fmt: Some(String::from("%d/%m/%Y %H:%M")),
+            locale: Some(String::from("de_DE")),
+            panel_split_ratio: Some(60),
+            confirmation_timeout: Some(30),
+            connection_summary: Some(true),
+            quit_immediately: Some(true),
+            hide_footer: Some(true),
+            title_path_tail: Some(true),
+            prompt_on_save_as_replace: Some(false),
+            hidden_patterns: Some(vec![String::from("target/")]),
+            file_submit_action: Some(String::from("transfer")),
+            restore_focused_panel: Some(true),
+            last_focused_panel: Some(String::from("remote")),
+            highlight_style: Some(String::from("bold")),
+            case_sensitive_sorting: Some(true),
+            confirm_disconnect: Some(false),
+            advance_on_mark: Some(false),
+            footer_hints: Some(vec![String::from("help"), String::from("quit")]),
+            raw_file_sizes: Some(true),
+            si_size_units: Some(true),
+            bookmark_sort_and_filter: Some(true),
+            open_with: Some(HashMap::from([(
+                String::from("md"),
+                String::from("glow {}"),
+            )])),
         };
         assert_eq!(ui.default_protocol, String::from("SFTP"));
         assert_eq!(ui.text_editor, PathBuf::from("nano"));
@@ -126,6 +223,37 @@ mod tests {
         assert_eq!(ui.prompt_on_file_replace, Some(true));
         assert_eq!(ui.group_dirs, Some(String::from("first")));
         assert_eq!(ui.file_fmt, Some(String::from("{NAME}")));
+        assert_eq!(ui.date_fmt, Some(String::from("%d/%m/%Y %H:%M")));
+        assert_eq!(ui.locale, Some(String::from("de_DE")));
+        assert_eq!(ui.panel_split_ratio, Some(60));
+        assert_eq!(ui.confirmation_timeout, Some(30));
+        assert_eq!(ui.connection_summary, Some(true));
+        assert_eq!(ui.quit_immediately, Some(true));
+        assert_eq!(ui.hide_footer, Some(true));
+        assert_eq!(ui.title_path_tail, Some(true));
+        assert_eq!(ui.prompt_on_save_as_replace, Some(false));
+        assert_eq!(ui.hidden_patterns, Some(vec![String::from("target/")]));
+        assert_eq!(ui.file_submit_action, Some(String::from("transfer")));
+        assert_eq!(ui.restore_focused_panel, Some(true));
+        assert_eq!(ui.last_focused_panel, Some(String::from("remote")));
+        assert_eq!(ui.highlight_style, Some(String::from("bold")));
+        assert_eq!(ui.case_sensitive_sorting, Some(true));
+        assert_eq!(ui.confirm_disconnect, Some(false));
+        assert_eq!(ui.advance_on_mark, Some(false));
+        assert_eq!(
+            ui.footer_hints,
+            Some(vec![String::from("help"), String::from("quit")])
+        );
+        assert_eq!(ui.raw_file_sizes, Some(true));
+        assert_eq!(ui.si_size_units, Some(true));
+        assert_eq!(ui.bookmark_sort_and_filter, Some(true));
+        assert_eq!(
+            ui.open_with,
+            Some(HashMap::from([(
+                String::from("md"),
+                String::from("glow {}")
+            )]))
+        );
         let cfg: UserConfig = UserConfig {
             user_interface: ui,
             remote,
@@ -141,6 +269,7 @@ mod tests {
             cfg.remote.ssh_config.as_deref().unwrap(),
             String::from("~/.ssh/config")
         );
+        assert_eq!(cfg.remote.rsync, Some(true));
         assert_eq!(cfg.user_interface.default_protocol, String::from("SFTP"));
         assert_eq!(cfg.user_interface.text_editor, PathBuf::from("nano"));
         assert_eq!(cfg.user_interface.show_hidden_files, true);
@@ -115,7 +115,9 @@ mod tests {
     use tuirealm::ratatui::style::Color;
 
     use super::*;
-    use crate::config::bookmarks::{Bookmark, KubeParams, S3Params, SmbParams, UserHosts};
+    use crate::config::bookmarks::{
+        Bookmark, DirectoryBookmark, KubeParams, S3Params, SmbParams, UserHosts,
+    };
     use crate::config::params::UserConfig;
     use crate::config::themes::Theme;
     use crate::filetransfer::FileTransferProtocol;
@@ -373,6 +375,7 @@ mod tests {
         assert_eq!(host.protocol, FileTransferProtocol::Sftp);
         assert_eq!(host.username.as_deref().unwrap(), "root");
         assert_eq!(host.password.as_deref().unwrap(), "mypassword");
+        assert_eq!(host.group, None);
         let host: &Bookmark = hosts.bookmarks.get("msi-estrem").unwrap();
         assert_eq!(host.address.as_deref().unwrap(), "192.168.1.30");
         assert_eq!(host.port.unwrap(), 22);
@@ -389,6 +392,7 @@ mod tests {
         assert_eq!(host.protocol, FileTransferProtocol::Ftp(true));
         assert_eq!(host.username.as_deref().unwrap(), "aws001");
         assert_eq!(host.password, None);
+        assert_eq!(host.group.as_deref().unwrap(), "aws");
         // Aws s3 bucket
         let host: &Bookmark = hosts.bookmarks.get("my-bucket").unwrap();
         assert_eq!(host.address, None);
@@ -456,6 +460,13 @@ mod tests {
                 password: None,
                 remote_path: None,
                 local_path: None,
+                connect_timeout: None,
+                keepalive_secs: None,
+                last_remote_path: None,
+                last_local_path: None,
+                last_remote_explorer: None,
+                last_local_explorer: None,
+                group: None,
                 kube: None,
                 s3: None,
                 smb: None,
@@ -471,6 +482,13 @@ mod tests {
                 password: Some(String::from("password")),
                 remote_path: Some(PathBuf::from("/tmp")),
                 local_path: Some(PathBuf::from("/usr")),
+                connect_timeout: None,
+                keepalive_secs: None,
+                last_remote_path: None,
+                last_local_path: None,
+                last_remote_explorer: None,
+                last_local_explorer: None,
+                group: None,
                 kube: None,
                 s3: None,
                 smb: None,
@@ -486,6 +504,13 @@ mod tests {
                 password: None,
                 remote_path: None,
                 local_path: None,
+                connect_timeout: None,
+                keepalive_secs: None,
+                last_remote_path: None,
+                last_local_path: None,
+                last_remote_explorer: None,
+                last_local_explorer: None,
+                group: None,
                 s3: Some(S3Params {
                     bucket: "veeso".to_string(),
                     region: Some("eu-west-1".to_string()),
@@ -510,6 +535,13 @@ mod tests {
                 password: None,
                 remote_path: None,
                 local_path: None,
+                connect_timeout: None,
+                keepalive_secs: None,
+                last_remote_path: None,
+                last_local_path: None,
+                last_remote_explorer: None,
+                last_local_explorer: None,
+                group: None,
                 s3: None,
                 smb: None,
                 kube: Some(KubeParams {
@@ -536,6 +568,13 @@ mod tests {
                 password: None,
                 remote_path: None,
                 local_path: None,
+                connect_timeout: None,
+                keepalive_secs: None,
+                last_remote_path: None,
+                last_local_path: None,
+                last_remote_explorer: None,
+                last_local_explorer: None,
+                group: None,
                 s3: None,
                 kube: None,
                 smb: smb_params,
@@ -552,6 +591,13 @@ mod tests {
                 password: Some(String::from("aaa")),
                 remote_path: Some(PathBuf::from("/tmp")),
                 local_path: Some(PathBuf::from("/usr")),
+                connect_timeout: None,
+                keepalive_secs: None,
+                last_remote_path: None,
+                last_local_path: None,
+                last_remote_explorer: None,
+                last_local_explorer: None,
+                group: None,
                 s3: None,
                 kube: None,
                 smb: None,
@@ -559,10 +605,74 @@ mod tests {
         );
         let tmpfile: tempfile::NamedTempFile = tempfile::NamedTempFile::new().unwrap();
         // Serialize
-        let hosts: UserHosts = UserHosts { bookmarks, recents };
+        let hosts: UserHosts = UserHosts {
+            bookmarks,
+            recents,
+            directories: HashMap::new(),
+        };
         assert!(serialize(&hosts, Box::new(tmpfile)).is_ok());
     }
 
+    #[test]
+    fn should_serialize_directory_bookmarks() {
+        let mut directories: HashMap<String, DirectoryBookmark> = HashMap::with_capacity(1);
+        directories.insert(
+            String::from("www"),
+            DirectoryBookmark {
+                directory: PathBuf::from("/var/www/html"),
+                connection: Bookmark {
+                    address: Some(String::from("192.168.1.31")),
+                    port: Some(22),
+                    protocol: FileTransferProtocol::Sftp,
+                    username: Some(String::from("root")),
+                    password: None,
+                    remote_path: None,
+                    local_path: None,
+                    connect_timeout: None,
+                    keepalive_secs: None,
+                    last_remote_path: None,
+                    last_local_path: None,
+                    last_remote_explorer: None,
+                    last_local_explorer: None,
+                    group: None,
+                    kube: None,
+                    s3: None,
+                    smb: None,
+                },
+            },
+        );
+        let hosts = UserHosts {
+            bookmarks: HashMap::new(),
+            recents: HashMap::new(),
+            directories,
+        };
+        let tmpfile: tempfile::NamedTempFile = tempfile::NamedTempFile::new().unwrap();
+        let (reader, writer) = create_file_ioers(tmpfile.path());
+        assert!(serialize(&hosts, Box::new(writer)).is_ok());
+        let deserialized: UserHosts = deserialize(Box::new(reader)).unwrap();
+        assert_eq!(deserialized.directories, hosts.directories);
+    }
+
+    #[test]
+    fn should_deserialize_directory_bookmarks() {
+        let toml_file: tempfile::NamedTempFile = create_good_toml_bookmarks();
+        toml_file.as_file().sync_all().unwrap();
+        toml_file.as_file().rewind().unwrap();
+        let hosts: UserHosts = deserialize(Box::new(toml_file)).unwrap();
+        assert_eq!(hosts.directories.len(), 1);
+        let www = hosts.directories.get("www").unwrap();
+        assert_eq!(www.directory, PathBuf::from("/var/www/html"));
+        assert_eq!(www.connection.protocol, FileTransferProtocol::Sftp);
+        assert_eq!(www.connection.address.as_deref(), Some("192.168.1.31"));
+        assert_eq!(www.connection.username.as_deref(), Some("root"));
+        // Bookmark files without directories
+        let toml = create_v14_pod_bookmark();
+        toml.as_file().sync_all().unwrap();
+        toml.as_file().rewind().unwrap();
+        let hosts: UserHosts = deserialize(Box::new(toml)).unwrap();
+        assert!(hosts.directories.is_empty());
+    }
+
     #[test]
     fn test_config_serialization_theme_serialize() {
         let theme: Theme = Theme {
@@ -582,7 +692,10 @@ mod tests {
         let toml_file = create_good_toml_theme();
         toml_file.as_file().sync_all().unwrap();
         toml_file.as_file().rewind().unwrap();
-        assert!(deserialize::<Theme>(Box::new(toml_file)).is_ok());
+        let theme = deserialize::<Theme>(Box::new(toml_file)).unwrap();
+        // Keys missing from older themes get their default
+        assert_eq!(theme.transfer_explorer_directory, Color::LightCyan);
+        assert_eq!(theme.transfer_explorer_archive, Color::LightRed);
         let toml_file = create_bad_toml_theme();
         toml_file.as_file().sync_all().unwrap();
         toml_file.as_file().rewind().unwrap();
@@ -612,7 +725,7 @@ mod tests {
         [bookmarks]
         raspberrypi2 = { address = "192.168.1.31", port = 22, protocol = "SFTP", username = "root", password = "mypassword" }
         msi-estrem = { address = "192.168.1.30", port = 22, protocol = "SFTP", username = "cvisintin", password = "mysecret", directory = "/tmp", local_path = "/usr" }
-        aws-server-prod1 = { address = "51.23.67.12", port = 21, protocol = "FTPS", username = "aws001" }
+        aws-server-prod1 = { address = "51.23.67.12", port = 21, protocol = "FTPS", username = "aws001", group = "aws" }
         
         [bookmarks.my-bucket]
         protocol = "S3"
@@ -648,6 +761,10 @@ mod tests {
 
         [recents]
         ISO20201215T094000Z = { address = "172.16.104.10", port = 22, protocol = "SCP", username = "root" }
+
+        [directories.www]
+        directory = "/var/www/html"
+        connection = { address = "192.168.1.31", port = 22, protocol = "SFTP", username = "root" }
         "#;
         tmpfile.write_all(file_content.as_bytes()).unwrap();
         //write!(tmpfile, "[bookmarks]\nraspberrypi2 = {{ address = \"192.168.1.31\", port = 22, protocol = \"SFTP\", username = \"root\" }}\nmsi-estrem = {{ address = \"192.168.1.30\", port = 22, protocol = \"SFTP\", username = \"cvisintin\" }}\naws-server-prod1 = {{ address = \"51.23.67.12\", port = 21, protocol = \"FTPS\", username = \"aws001\" }}\n\n[recents]\nISO20201215T094000Z = {{ address = \"172.16.104.10\", port = 22, protocol = \"SCP\", username = \"root\" }}\n");
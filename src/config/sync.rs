@@ -0,0 +1,29 @@
+//! ## Sync
+//!
+//! `sync` is the module which provides the baselines stored for the bidirectional sync
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Sync baselines for all the sync pairs, identified by a key made of both endpoints
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct SyncBaselines {
+    pub pairs: HashMap<String, SyncBaseline>,
+}
+
+/// The state of a sync pair after the last successful sync
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct SyncBaseline {
+    /// Entries associated to their path relative to the sync root
+    pub entries: HashMap<String, SyncBaselineEntry>,
+}
+
+/// Describes an entry in the sync baseline
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct SyncBaselineEntry {
+    pub is_dir: bool,
+    pub size: u64,
+    /// Modification time as seconds since UNIX epoch
+    pub mtime: Option<u64>,
+}
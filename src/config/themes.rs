@@ -152,6 +152,30 @@ pub struct Theme {
         serialize_with = "serialize_color"
     )]
     pub transfer_status_sync_browsing: Color,
+    #[serde(
+        default = "default_transfer_explorer_directory",
+        deserialize_with = "deserialize_color",
+        serialize_with = "serialize_color"
+    )]
+    pub transfer_explorer_directory: Color,
+    #[serde(
+        default = "default_transfer_explorer_symlink",
+        deserialize_with = "deserialize_color",
+        serialize_with = "serialize_color"
+    )]
+    pub transfer_explorer_symlink: Color,
+    #[serde(
+        default = "default_transfer_explorer_executable",
+        deserialize_with = "deserialize_color",
+        serialize_with = "serialize_color"
+    )]
+    pub transfer_explorer_executable: Color,
+    #[serde(
+        default = "default_transfer_explorer_archive",
+        deserialize_with = "deserialize_color",
+        serialize_with = "serialize_color"
+    )]
+    pub transfer_explorer_archive: Color,
 }
 
 impl Default for Theme {
@@ -184,10 +208,32 @@ impl Default for Theme {
             transfer_status_hidden: Color::LightBlue,
             transfer_status_sorting: Color::LightYellow,
             transfer_status_sync_browsing: Color::LightGreen,
+            transfer_explorer_directory: default_transfer_explorer_directory(),
+            transfer_explorer_symlink: default_transfer_explorer_symlink(),
+            transfer_explorer_executable: default_transfer_explorer_executable(),
+            transfer_explorer_archive: default_transfer_explorer_archive(),
         }
     }
 }
 
+// -- defaults of the keys added after the first themes, so existing themes keep loading
+
+fn default_transfer_explorer_directory() -> Color {
+    Color::LightCyan
+}
+
+fn default_transfer_explorer_symlink() -> Color {
+    Color::LightMagenta
+}
+
+fn default_transfer_explorer_executable() -> Color {
+    Color::LightGreen
+}
+
+fn default_transfer_explorer_archive() -> Color {
+    Color::LightRed
+}
+
 // -- deserializer
 
 fn deserialize_color<'de, D>(deserializer: D) -> Result<Color, D::Error>
@@ -247,5 +293,9 @@ mod test {
         assert_eq!(theme.transfer_status_hidden, Color::LightBlue);
         assert_eq!(theme.transfer_status_sorting, Color::LightYellow);
         assert_eq!(theme.transfer_status_sync_browsing, Color::LightGreen);
+        assert_eq!(theme.transfer_explorer_directory, Color::LightCyan);
+        assert_eq!(theme.transfer_explorer_symlink, Color::LightMagenta);
+        assert_eq!(theme.transfer_explorer_executable, Color::LightGreen);
+        assert_eq!(theme.transfer_explorer_archive, Color::LightRed);
     }
 }
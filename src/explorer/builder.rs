@@ -3,11 +3,11 @@
 //! `builder` is the module which provides a builder for FileExplorer
 
 // Locals
-// Ext
-use std::collections::VecDeque;
-
 use super::formatter::Formatter;
-use super::{ExplorerOpts, FileExplorer, FileSorting, GroupDirs};
+use super::history::DirHistory;
+use super::{ExplorerOpts, FileExplorer, FileSorting, GroupDirs, HiddenPattern};
+use crate::utils::fmt::SizeUnits;
+use crate::utils::locale::Locale;
 
 /// Struct used to create a `FileExplorer`
 pub struct FileExplorerBuilder {
@@ -38,6 +38,25 @@ impl FileExplorerBuilder {
         self
     }
 
+    /// Set patterns of files hidden from listings
+    pub fn with_hidden_patterns(&mut self, patterns: &[String]) -> &mut FileExplorerBuilder {
+        if let Some(e) = self.explorer.as_mut() {
+            e.hidden_patterns = patterns.iter().map(|p| HiddenPattern::new(p)).collect();
+        }
+        self
+    }
+
+    /// Enable CASE_SENSITIVE_SORTING option
+    pub fn with_case_sensitive_sorting(&mut self, val: bool) -> &mut FileExplorerBuilder {
+        if let Some(e) = self.explorer.as_mut() {
+            match val {
+                true => e.opts.insert(ExplorerOpts::CASE_SENSITIVE_SORTING),
+                false => e.opts.remove(ExplorerOpts::CASE_SENSITIVE_SORTING),
+            }
+        }
+        self
+    }
+
     /// Set sorting method
     pub fn with_file_sorting(&mut self, sorting: FileSorting) -> &mut FileExplorerBuilder {
         if let Some(e) = self.explorer.as_mut() {
@@ -57,8 +76,7 @@ impl FileExplorerBuilder {
     /// Set stack size for FileExplorer
     pub fn with_stack_size(&mut self, sz: usize) -> &mut FileExplorerBuilder {
         if let Some(e) = self.explorer.as_mut() {
-            e.stack_size = sz;
-            e.dirstack = VecDeque::with_capacity(sz);
+            e.history = DirHistory::new(sz);
         }
         self
     }
@@ -72,6 +90,44 @@ impl FileExplorerBuilder {
         }
         self
     }
+
+    /// Set locale for FileExplorer; must be called after `with_formatter` and before `with_date_fmt`,
+    /// since the date format of the locale applies when no date format is configured
+    pub fn with_locale(&mut self, locale: Option<Locale>) -> &mut FileExplorerBuilder {
+        if let Some(e) = self.explorer.as_mut()
+            && let Some(locale) = locale
+        {
+            e.fmt.set_locale(locale);
+            e.fmt.set_date_fmt(locale.date_fmt());
+        }
+        self
+    }
+
+    /// Set date format for FileExplorer; must be called after `with_formatter`
+    pub fn with_date_fmt(&mut self, date_fmt: Option<&str>) -> &mut FileExplorerBuilder {
+        if let Some(e) = self.explorer.as_mut()
+            && let Some(date_fmt) = date_fmt
+        {
+            e.fmt.set_date_fmt(date_fmt);
+        }
+        self
+    }
+
+    /// Display sizes as the exact count of bytes; must be called after `with_formatter`
+    pub fn with_raw_sizes(&mut self, raw: bool) -> &mut FileExplorerBuilder {
+        if let Some(e) = self.explorer.as_mut() {
+            e.fmt.set_raw_sizes(raw);
+        }
+        self
+    }
+
+    /// Set the units of human readable sizes; must be called after `with_formatter`
+    pub fn with_size_units(&mut self, units: SizeUnits) -> &mut FileExplorerBuilder {
+        if let Some(e) = self.explorer.as_mut() {
+            e.fmt.set_size_units(units);
+        }
+        self
+    }
 }
 
 #[cfg(test)]
@@ -86,9 +142,15 @@ mod tests {
         let explorer: FileExplorer = FileExplorerBuilder::new().build();
         // Verify
         assert!(!explorer.opts.intersects(ExplorerOpts::SHOW_HIDDEN_FILES));
+        assert!(
+            !explorer
+                .opts
+                .intersects(ExplorerOpts::CASE_SENSITIVE_SORTING)
+        );
         assert_eq!(explorer.file_sorting, FileSorting::Name); // Default
         assert_eq!(explorer.group_dirs, None);
-        assert_eq!(explorer.stack_size, 16);
+        assert_eq!(explorer.history.capacity, 16);
+        assert!(!explorer.raw_sizes());
     }
 
     #[test]
@@ -97,13 +159,25 @@ mod tests {
             .with_file_sorting(FileSorting::ModifyTime)
             .with_group_dirs(Some(GroupDirs::First))
             .with_hidden_files(true)
+            .with_case_sensitive_sorting(true)
             .with_stack_size(24)
+            .with_hidden_patterns(&[String::from("target/"), String::from("*.o")])
             .with_formatter(Some("{NAME}"))
+            .with_locale(Locale::from_name("de_DE"))
+            .with_date_fmt(Some("relative"))
+            .with_raw_sizes(true)
             .build();
         // Verify
         assert!(explorer.opts.intersects(ExplorerOpts::SHOW_HIDDEN_FILES));
+        assert!(
+            explorer
+                .opts
+                .intersects(ExplorerOpts::CASE_SENSITIVE_SORTING)
+        );
         assert_eq!(explorer.file_sorting, FileSorting::ModifyTime); // Default
         assert_eq!(explorer.group_dirs, Some(GroupDirs::First));
-        assert_eq!(explorer.stack_size, 24);
+        assert_eq!(explorer.history.capacity, 24);
+        assert_eq!(explorer.hidden_patterns.len(), 2);
+        assert!(explorer.raw_sizes());
     }
 }
@@ -0,0 +1,133 @@
+//! ## Filter
+//!
+//! `filter` is the module which provides the filter on the file names of the `FileExplorer`
+
+use std::str::FromStr;
+
+use regex::Regex;
+use wildmatch::WildMatch;
+
+/// Characters which make an expression with `*` or `?` a regex rather than a glob
+const REGEX_ONLY_CHARS: &[char] = &['^', '$', '(', ')', '[', ']', '{', '}', '|', '+', '\\'];
+
+/// Filter on the file names, parsed from an expression
+#[derive(Clone, Debug)]
+pub struct FileFilter {
+    /// Expression the filter was parsed from
+    expression: String,
+    matcher: Matcher,
+}
+
+#[derive(Clone, Debug)]
+enum Matcher {
+    Regex(Regex),
+    Wildcard(WildMatch),
+    /// Matches the names which are not matched by the inner matcher
+    Not(Box<Matcher>),
+}
+
+impl FromStr for FileFilter {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            expression: s.to_string(),
+            matcher: Matcher::from(s),
+        })
+    }
+}
+
+impl From<&str> for Matcher {
+    fn from(s: &str) -> Self {
+        // a leading `!` excludes the matching files
+        if let Some(s) = s.strip_prefix('!') {
+            return Self::Not(Box::new(Self::from(s)));
+        }
+        // globs (e.g. `*.tmp`) would be valid regexes too, so they're told apart first
+        let is_glob = s.contains(['*', '?']) && !s.contains(REGEX_ONLY_CHARS);
+        if !is_glob && let Ok(regex) = Regex::new(s) {
+            Self::Regex(regex)
+        } else {
+            Self::Wildcard(WildMatch::new(s))
+        }
+    }
+}
+
+impl Matcher {
+    fn matches(&self, s: &str) -> bool {
+        match self {
+            Self::Regex(re) => re.is_match(s),
+            Self::Wildcard(wm) => wm.matches(s),
+            Self::Not(matcher) => !matcher.matches(s),
+        }
+    }
+}
+
+impl FileFilter {
+    /// Returns whether the file `name` passes the filter
+    pub fn matches(&self, name: &str) -> bool {
+        self.matcher.matches(name)
+    }
+
+    /// Get the expression the filter was parsed from
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn parse(s: &str) -> FileFilter {
+        FileFilter::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn should_filter_by_substring() {
+        let filter = parse("report");
+        assert_eq!(filter.expression(), "report");
+        assert!(filter.matches("report.pdf"));
+        assert!(filter.matches("old_report"));
+        assert!(!filter.matches("notes.md"));
+    }
+
+    #[test]
+    fn should_filter_by_regex() {
+        let filter = parse(r"^file\d+\.txt$");
+        assert!(matches!(filter.matcher, Matcher::Regex(_)));
+        assert!(filter.matches("file12.txt"));
+        assert!(!filter.matches("file.txt"));
+    }
+
+    #[test]
+    fn should_include_by_glob() {
+        let filter = parse("*.tmp");
+        assert!(matches!(filter.matcher, Matcher::Wildcard(_)));
+        assert!(filter.matches("build.tmp"));
+        assert!(!filter.matches("build.tmp.bak"));
+        assert!(!filter.matches("main.rs"));
+        let filter = parse("file?.txt");
+        assert!(filter.matches("file1.txt"));
+        assert!(!filter.matches("file10.txt"));
+    }
+
+    #[test]
+    fn should_exclude_by_glob() {
+        let filter = parse("!*.tmp");
+        assert_eq!(filter.expression(), "!*.tmp");
+        assert!(!filter.matches("build.tmp"));
+        assert!(filter.matches("main.rs"));
+        assert!(filter.matches("tmp"));
+    }
+
+    #[test]
+    fn should_exclude_by_substring() {
+        let filter = parse("!log");
+        assert!(!filter.matches("server.log"));
+        assert!(!filter.matches("logs"));
+        assert!(filter.matches("main.rs"));
+    }
+}
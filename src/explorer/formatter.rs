@@ -7,16 +7,15 @@ use std::path::PathBuf;
 use std::time::UNIX_EPOCH;
 
 // Ext
-use bytesize::ByteSize;
 use lazy_regex::{Lazy, Regex};
 use remotefs::File;
 use unicode_width::UnicodeWidthStr;
 #[cfg(posix)]
 use uzers::{get_group_by_gid, get_user_by_uid};
 
-use crate::utils::fmt::{fmt_path_elide, fmt_pex, fmt_time};
+use crate::utils::fmt::{SizeUnits, fmt_date, fmt_name_elide, fmt_path_elide, fmt_pex, fmt_size};
+use crate::utils::locale::Locale;
 use crate::utils::path::diff_paths;
-use crate::utils::string::secure_substring;
 // Types
 // FmtCallback: Formatter, fsentry: &File, cur_str, prefix, length, extra
 type FmtCallback = fn(&Formatter, &File, &str, &str, Option<&usize>, Option<&String>) -> String;
@@ -34,6 +33,7 @@ const FMT_KEY_SYMLINK: &str = "SYMLINK";
 const FMT_KEY_USER: &str = "USER";
 // Default
 const FMT_DEFAULT_STX: &str = "{NAME} {PEX} {USER} {SIZE} {MTIME}";
+const FMT_DEFAULT_DATE: &str = "%b %d %Y %H:%M";
 /**
  * Regex matches:
  *  - group 0: KEY NAME
@@ -120,6 +120,14 @@ impl CallChainBlock {
 /// at each fmt call.
 pub struct Formatter {
     call_chain: CallChainBlock,
+    /// Date format used when the time keys don't specify one
+    date_fmt: String,
+    /// Locale used to format sizes
+    locale: Option<Locale>,
+    /// Whether sizes are formatted as the exact count of bytes, instead of human readable
+    raw_sizes: bool,
+    /// Units of human readable sizes
+    size_units: SizeUnits,
 }
 
 impl Default for Formatter {
@@ -127,6 +135,10 @@ impl Default for Formatter {
     fn default() -> Self {
         Formatter {
             call_chain: Self::make_callchain(FMT_DEFAULT_STX),
+            date_fmt: FMT_DEFAULT_DATE.to_string(),
+            locale: None,
+            raw_sizes: false,
+            size_units: SizeUnits::default(),
         }
     }
 }
@@ -136,9 +148,39 @@ impl Formatter {
     pub fn new(fmt_str: &str) -> Self {
         Formatter {
             call_chain: Self::make_callchain(fmt_str),
+            date_fmt: FMT_DEFAULT_DATE.to_string(),
+            locale: None,
+            raw_sizes: false,
+            size_units: SizeUnits::default(),
         }
     }
 
+    /// Set the date format used when the time keys don't specify one.
+    /// The format is either a strftime pattern or `relative`
+    pub fn set_date_fmt(&mut self, fmt: &str) {
+        self.date_fmt = fmt.to_string();
+    }
+
+    /// Set the locale used to format sizes
+    pub fn set_locale(&mut self, locale: Locale) {
+        self.locale = Some(locale);
+    }
+
+    /// Set whether sizes are formatted as the exact count of bytes, instead of human readable
+    pub fn set_raw_sizes(&mut self, raw: bool) {
+        self.raw_sizes = raw;
+    }
+
+    /// Returns whether sizes are formatted as the exact count of bytes
+    pub fn raw_sizes(&self) -> bool {
+        self.raw_sizes
+    }
+
+    /// Set the units of human readable sizes
+    pub fn set_size_units(&mut self, units: SizeUnits) {
+        self.size_units = units;
+    }
+
     /// Format fsentry
     pub fn fmt(&self, fsentry: &File) -> String {
         // Execute callchain blocks
@@ -156,12 +198,12 @@ impl Formatter {
         fmt_len: Option<&usize>,
         fmt_extra: Option<&String>,
     ) -> String {
-        // Get date (use extra args as format or default "%b %d %Y %H:%M")
-        let datetime: String = fmt_time(
+        // Get date (use extra args as format or default configured date format)
+        let datetime: String = fmt_date(
             fsentry.metadata().accessed.unwrap_or(UNIX_EPOCH),
             match fmt_extra {
                 Some(fmt) => fmt.as_ref(),
-                None => "%b %d %Y %H:%M",
+                None => self.date_fmt.as_str(),
             },
         );
         // Add to cur str, prefix and the key value
@@ -184,11 +226,11 @@ impl Formatter {
         fmt_extra: Option<&String>,
     ) -> String {
         // Get date
-        let datetime: String = fmt_time(
+        let datetime: String = fmt_date(
             fsentry.metadata().created.unwrap_or(UNIX_EPOCH),
             match fmt_extra {
                 Some(fmt) => fmt.as_ref(),
-                None => "%b %d %Y %H:%M",
+                None => self.date_fmt.as_str(),
             },
         );
         // Add to cur str, prefix and the key value
@@ -244,11 +286,11 @@ impl Formatter {
         fmt_extra: Option<&String>,
     ) -> String {
         // Get date
-        let datetime: String = fmt_time(
+        let datetime: String = fmt_date(
             fsentry.metadata().modified.unwrap_or(UNIX_EPOCH),
             match fmt_extra {
                 Some(fmt) => fmt.as_ref(),
-                None => "%b %d %Y %H:%M",
+                None => self.date_fmt.as_str(),
             },
         );
         // Add to cur str, prefix and the key value
@@ -275,21 +317,19 @@ impl Formatter {
             Some(l) => *l,
             None => 24,
         };
-        let name = fsentry.name();
-        let last_idx: usize = match fsentry.is_dir() {
-            // NOTE: For directories is l - 2, since we push '/' to name
-            true => file_len - 2,
-            false => file_len - 1,
-        };
-        let mut name: String = match name.width() >= file_len {
-            false => name,
-            true => format!("{}…", secure_substring(&name, 0, last_idx)),
+        let max_width: usize = match fsentry.is_dir() {
+            // NOTE: For directories is l - 1, since we push '/' to name
+            true => file_len.saturating_sub(1),
+            false => file_len,
         };
+        let mut name: String = fmt_name_elide(&fsentry.name(), max_width);
         if fsentry.is_dir() {
             name.push('/');
         }
+        // Pad to the display width, since wide characters take more than a column
+        let padding = " ".repeat(file_len.saturating_sub(name.width()));
         // Add to cur str, prefix and the key value
-        format!("{cur_str}{prefix}{name:0file_len$}")
+        format!("{cur_str}{prefix}{name}{padding}")
     }
 
     /// Format path
@@ -362,9 +402,12 @@ impl Formatter {
         _fmt_extra: Option<&String>,
     ) -> String {
         if fsentry.is_file() {
-            // Get byte size
-            let size: ByteSize = ByteSize(fsentry.metadata().size);
-            let mut fmt = size.display().si().to_string();
+            let mut fmt = fmt_size(
+                fsentry.metadata().size,
+                self.raw_sizes,
+                self.size_units,
+                self.locale,
+            );
             // pad with up to len 10
             let pad = 10usize.saturating_sub(fmt.len());
             for _ in 0..pad {
@@ -375,16 +418,14 @@ impl Formatter {
             // Add to cur str, prefix and the key value
             //format!("{cur_str}{prefix}{size:10}", size = size.display().si())
         } else if fsentry.metadata().symlink.is_some() {
-            let size = ByteSize(
-                fsentry
-                    .metadata()
-                    .symlink
-                    .as_ref()
-                    .unwrap()
-                    .to_string_lossy()
-                    .len() as u64,
-            );
-            let mut fmt = size.display().si().to_string();
+            let size = fsentry
+                .metadata()
+                .symlink
+                .as_ref()
+                .unwrap()
+                .to_string_lossy()
+                .len() as u64;
+            let mut fmt = fmt_size(size, self.raw_sizes, self.size_units, self.locale);
             // pad with up to len 10
             let pad = 10usize.saturating_sub(fmt.len());
             for _ in 0..pad {
@@ -536,12 +577,13 @@ impl Formatter {
 mod tests {
 
     use std::path::PathBuf;
-    use std::time::SystemTime;
+    use std::time::{Duration, SystemTime};
 
     use pretty_assertions::assert_eq;
     use remotefs::fs::{File, FileType, Metadata, UnixPex};
 
     use super::*;
+    use crate::utils::fmt::fmt_time;
 
     #[test]
     fn test_fs_explorer_formatter_callchain() {
@@ -584,6 +626,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_format_mtime_with_configured_date_fmt() {
+        let mut formatter: Formatter = Formatter::new("{MTIME} {ATIME:10:%Y}");
+        formatter.set_date_fmt("%d/%m/%Y");
+        let t: SystemTime = SystemTime::now();
+        let entry = File {
+            path: PathBuf::from("/bar.txt"),
+            metadata: Metadata::default().modified(t).accessed(t),
+        };
+        assert_eq!(
+            formatter.fmt(&entry),
+            format!("{:17} {:10}", fmt_time(t, "%d/%m/%Y"), fmt_time(t, "%Y"))
+        );
+        formatter.set_date_fmt("relative");
+        assert_eq!(
+            formatter.fmt(&entry),
+            format!("{:17} {:10}", "just now", fmt_time(t, "%Y"))
+        );
+    }
+
+    #[test]
+    fn should_format_size_and_date_with_locale() {
+        let t: SystemTime = SystemTime::UNIX_EPOCH + Duration::from_secs(1700000000);
+        let entry = File {
+            path: PathBuf::from("/bar.txt"),
+            metadata: Metadata::default().modified(t).size(8192),
+        };
+        let mut formatter: Formatter = Formatter::new("{SIZE} {MTIME}");
+        let locale = Locale::from_name("de_DE").unwrap();
+        formatter.set_locale(locale);
+        formatter.set_date_fmt(locale.date_fmt());
+        assert_eq!(
+            formatter.fmt(&entry),
+            format!("8,0 KiB    {:17}", fmt_time(t, "%d.%m.%Y %H:%M"))
+        );
+        formatter.set_raw_sizes(true);
+        assert_eq!(
+            formatter.fmt(&entry),
+            format!("8.192 B    {:17}", fmt_time(t, "%d.%m.%Y %H:%M"))
+        );
+        formatter.set_raw_sizes(false);
+        formatter.set_size_units(SizeUnits::Si);
+        assert_eq!(
+            formatter.fmt(&entry),
+            format!("8,2 kB     {:17}", fmt_time(t, "%d.%m.%Y %H:%M"))
+        );
+        formatter.set_size_units(SizeUnits::Binary);
+        let locale = Locale::from_name("en_US").unwrap();
+        formatter.set_locale(locale);
+        formatter.set_date_fmt(locale.date_fmt());
+        assert_eq!(
+            formatter.fmt(&entry),
+            format!("8.0 KiB    {:17}", fmt_time(t, "%b %d %Y %H:%M"))
+        );
+    }
+
     #[test]
     fn test_fs_explorer_formatter_format_files() {
         // Make default
@@ -608,7 +706,7 @@ mod tests {
         assert_eq!(
             formatter.fmt(&entry),
             format!(
-                "bar.txt                  -rw-r--r-- root         8.2 kB     {}",
+                "bar.txt                  -rw-r--r-- root         8.0 KiB    {}",
                 fmt_time(t, "%b %d %Y %H:%M")
             )
         );
@@ -616,7 +714,7 @@ mod tests {
         assert_eq!(
             formatter.fmt(&entry),
             format!(
-                "bar.txt                  -rw-r--r-- 0            8.2 kB     {}",
+                "bar.txt                  -rw-r--r-- 0            8.0 KiB    {}",
                 fmt_time(t, "%b %d %Y %H:%M")
             )
         );
@@ -639,7 +737,7 @@ mod tests {
         assert_eq!(
             formatter.fmt(&entry),
             format!(
-                "piroparoporoperoperupup… -rw-r--r-- root         8.2 kB     {}",
+                "piroparopo…operupupu.txt -rw-r--r-- root         8.0 KiB    {}",
                 fmt_time(t, "%b %d %Y %H:%M")
             )
         );
@@ -647,7 +745,7 @@ mod tests {
         assert_eq!(
             formatter.fmt(&entry),
             format!(
-                "piroparoporoperoperupup… -rw-r--r-- 0            8.2 kB     {}",
+                "piroparopo…operupupu.txt -rw-r--r-- 0            8.0 KiB    {}",
                 fmt_time(t, "%b %d %Y %H:%M")
             )
         );
@@ -670,7 +768,7 @@ mod tests {
         assert_eq!(
             formatter.fmt(&entry),
             format!(
-                "bar.txt                  -????????? root         8.2 kB     {}",
+                "bar.txt                  -????????? root         8.0 KiB    {}",
                 fmt_time(t, "%b %d %Y %H:%M")
             )
         );
@@ -678,7 +776,7 @@ mod tests {
         assert_eq!(
             formatter.fmt(&entry),
             format!(
-                "bar.txt                  -????????? 0            8.2 kB     {}",
+                "bar.txt                  -????????? 0            8.0 KiB    {}",
                 fmt_time(t, "%b %d %Y %H:%M")
             )
         );
@@ -701,7 +799,7 @@ mod tests {
         assert_eq!(
             formatter.fmt(&entry),
             format!(
-                "bar.txt                  -????????? 0            8.2 kB     {}",
+                "bar.txt                  -????????? 0            8.0 KiB    {}",
                 fmt_time(t, "%b %d %Y %H:%M")
             )
         );
@@ -709,7 +807,7 @@ mod tests {
         assert_eq!(
             formatter.fmt(&entry),
             format!(
-                "bar.txt                  -????????? 0            8.2 kB     {}",
+                "bar.txt                  -????????? 0            8.0 KiB    {}",
                 fmt_time(t, "%b %d %Y %H:%M")
             )
         );
@@ -880,7 +978,7 @@ mod tests {
         assert_eq!(
             formatter.fmt(&entry),
             format!(
-                "bar.txt                                   0            0            -rw-r--r-- 8.2 kB     {} {} {}",
+                "bar.txt                                   0            0            -rw-r--r-- 8.0 KiB    {} {} {}",
                 fmt_time(t, "%a %b %d %Y %H:%M"),
                 fmt_time(t, "%a %b %d %Y %H:%M"),
                 fmt_time(t, "%a %b %d %Y %H:%M"),
@@ -984,7 +1082,7 @@ mod tests {
             },
         };
         let formatter: Formatter = Formatter::new("{NAME:8}");
-        assert_eq!(formatter.fmt(&entry).as_str(), "christi…");
+        assert_eq!(formatter.fmt(&entry).as_str(), "ch…n.txt");
     }
 
     #[test]
@@ -1024,7 +1122,7 @@ mod tests {
             },
         };
         let formatter: Formatter = Formatter::new("{NAME:8}");
-        assert_eq!(formatter.fmt(&entry).as_str(), "喵喵喵喵喵喵喵…");
+        assert_eq!(formatter.fmt(&entry).as_str(), "喵喵…喵 ");
     }
 
     /// Dummy formatter, just yelds an 'A' at the end of the current string
@@ -0,0 +1,140 @@
+//! ## History
+//!
+//! `history` keeps track of the directories visited in an explorer, to go back and forward like a browser
+
+use std::path::{Path, PathBuf};
+
+/// Directories visited in an explorer.
+///
+/// Entries before `cursor` are the directories to go back to, the most recent last;
+/// entries from `cursor` on are the directories to go forward to, the next one first
+#[derive(Debug)]
+pub(crate) struct DirHistory {
+    pub(crate) entries: Vec<PathBuf>,
+    pub(crate) cursor: usize,
+    /// Maximum amount of directories to go back to
+    pub(crate) capacity: usize,
+}
+
+impl DirHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Vec::with_capacity(capacity),
+            cursor: 0,
+            capacity,
+        }
+    }
+
+    /// Record `dir` as the directory left to enter a new one.
+    ///
+    /// The forward history is discarded, since it doesn't follow the new directory
+    pub fn visit(&mut self, dir: &Path) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.entries.truncate(self.cursor);
+        // Drop the oldest directories, which would overflow the history
+        if self.entries.len() >= self.capacity {
+            self.entries.drain(..=self.entries.len() - self.capacity);
+        }
+        self.entries.push(dir.to_path_buf());
+        self.cursor = self.entries.len();
+    }
+
+    /// Go back from `current`, which becomes the next directory to go forward to.
+    ///
+    /// Returns the directory to go back to, if any
+    pub fn back(&mut self, current: &Path) -> Option<PathBuf> {
+        if self.cursor == 0 {
+            return None;
+        }
+        self.cursor -= 1;
+        Some(std::mem::replace(
+            &mut self.entries[self.cursor],
+            current.to_path_buf(),
+        ))
+    }
+
+    /// Go forward from `current`, which becomes the previous directory to go back to.
+    ///
+    /// Returns the directory to go forward to, if any
+    pub fn forward(&mut self, current: &Path) -> Option<PathBuf> {
+        let next = self.entries.get_mut(self.cursor)?;
+        let next = std::mem::replace(next, current.to_path_buf());
+        self.cursor += 1;
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_go_back_and_forward() {
+        let mut history = DirHistory::new(16);
+        // "/" -> "/home" -> "/home/omar"
+        history.visit(Path::new("/"));
+        history.visit(Path::new("/home"));
+        assert_eq!(history.forward(Path::new("/home/omar")), None);
+        assert_eq!(
+            history.back(Path::new("/home/omar")),
+            Some(PathBuf::from("/home"))
+        );
+        assert_eq!(history.back(Path::new("/home")), Some(PathBuf::from("/")));
+        assert_eq!(history.back(Path::new("/")), None);
+        assert_eq!(
+            history.forward(Path::new("/")),
+            Some(PathBuf::from("/home"))
+        );
+        assert_eq!(
+            history.forward(Path::new("/home")),
+            Some(PathBuf::from("/home/omar"))
+        );
+        assert_eq!(history.forward(Path::new("/home/omar")), None);
+        assert_eq!(
+            history.back(Path::new("/home/omar")),
+            Some(PathBuf::from("/home"))
+        );
+    }
+
+    #[test]
+    fn should_truncate_forward_history_on_visit() {
+        let mut history = DirHistory::new(16);
+        history.visit(Path::new("/"));
+        history.visit(Path::new("/home"));
+        assert_eq!(
+            history.back(Path::new("/home/omar")),
+            Some(PathBuf::from("/home"))
+        );
+        // "/home" -> "/tmp"
+        history.visit(Path::new("/home"));
+        assert_eq!(history.forward(Path::new("/tmp")), None);
+        assert_eq!(history.entries.len(), 2);
+        assert_eq!(
+            history.back(Path::new("/tmp")),
+            Some(PathBuf::from("/home"))
+        );
+        assert_eq!(history.back(Path::new("/home")), Some(PathBuf::from("/")));
+    }
+
+    #[test]
+    fn should_drop_oldest_directories() {
+        let mut history = DirHistory::new(2);
+        history.visit(Path::new("/tmp"));
+        history.visit(Path::new("/home/omar"));
+        history.visit(Path::new("/dev"));
+        assert_eq!(
+            history.entries,
+            vec![PathBuf::from("/home/omar"), PathBuf::from("/dev")]
+        );
+        assert_eq!(history.back(Path::new("/")), Some(PathBuf::from("/dev")));
+        // Without capacity, nothing is recorded
+        let mut history = DirHistory::new(0);
+        history.visit(Path::new("/tmp"));
+        assert_eq!(history.back(Path::new("/")), None);
+    }
+}
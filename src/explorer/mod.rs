@@ -4,22 +4,29 @@
 
 // Mods
 pub(crate) mod builder;
+pub(crate) mod filter;
 mod formatter;
+mod history;
 // Locals
-use std::cmp::Reverse;
-use std::collections::{HashMap, VecDeque};
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
+use filter::FileFilter;
 use formatter::Formatter;
+use history::DirHistory;
 // Ext
 use remotefs::fs::File;
+use wildmatch::WildMatch;
 
 bitflags! {
     /// ExplorerOpts are bit options which provides different behaviours to `FileExplorer`
     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
     pub(crate) struct ExplorerOpts: u32 {
         const SHOW_HIDDEN_FILES = 0b00000001;
+        const SHOW_PATTERN_HIDDEN_FILES = 0b00000010;
+        const CASE_SENSITIVE_SORTING = 0b00000100;
     }
 }
 
@@ -30,6 +37,8 @@ pub enum FileSorting {
     ModifyTime,
     CreationTime,
     Size,
+    Extension,
+    Natural,
     None,
 }
 
@@ -40,14 +49,30 @@ pub enum GroupDirs {
     Last,
 }
 
+/// FileSubmitAction defines what happens when a file which is not a directory is submitted in the explorer
+#[derive(Copy, Clone, Default, PartialEq, Eq, std::fmt::Debug)]
+pub enum FileSubmitAction {
+    #[default]
+    Nothing,
+    Open,
+    Transfer,
+    Edit,
+}
+
+/// FocusedPanel defines which of the two explorers has the focus
+#[derive(Copy, Clone, Default, PartialEq, Eq, std::fmt::Debug)]
+pub enum FocusedPanel {
+    #[default]
+    Local,
+    Remote,
+}
+
 /// File explorer states
 pub struct FileExplorer {
     /// Current working directory
     pub wrkdir: PathBuf,
-    /// Stack of visited directories
-    pub(crate) dirstack: VecDeque<PathBuf>,
-    /// Stack size
-    pub(crate) stack_size: usize,
+    /// Visited directories, to go back and forward
+    pub(crate) history: DirHistory,
     /// Criteria to sort file
     pub(crate) file_sorting: FileSorting,
     /// defines how to group directories in the explorer
@@ -58,43 +83,83 @@ pub struct FileExplorer {
     pub(crate) fmt: Formatter,
     /// Is terminal open for this explorer?
     terminal: bool,
+    /// Patterns of files hidden from listings, unless `SHOW_PATTERN_HIDDEN_FILES` is set
+    pub(crate) hidden_patterns: Vec<HiddenPattern>,
+    /// Filter on the names of the listed files; kept when the working directory changes
+    filter: Option<FileFilter>,
     /// Files in directory
     files: Vec<File>,
     /// files enqueued for transfer. Map between source and destination
     transfer_queue: HashMap<PathBuf, PathBuf>, // transfer queue
+    /// Directories marked during the session, by char
+    dir_marks: HashMap<char, PathBuf>,
 }
 
 impl Default for FileExplorer {
     fn default() -> Self {
         FileExplorer {
             wrkdir: PathBuf::from("/"),
-            dirstack: VecDeque::with_capacity(16),
-            stack_size: 16,
+            history: DirHistory::new(16),
             file_sorting: FileSorting::Name,
             group_dirs: None,
             opts: ExplorerOpts::empty(),
             fmt: Formatter::default(),
             files: Vec::new(),
             terminal: false,
+            hidden_patterns: Vec::new(),
+            filter: None,
             transfer_queue: HashMap::new(),
+            dir_marks: HashMap::new(),
         }
     }
 }
 
+/// A pattern of file names to hide from listings
+pub(crate) struct HiddenPattern {
+    pattern: WildMatch,
+    /// If true, the pattern only matches directories
+    dirs_only: bool,
+}
+
+impl HiddenPattern {
+    /// Parse pattern; a trailing `/` restricts the pattern to directories
+    pub fn new(pattern: &str) -> Self {
+        match pattern.strip_suffix('/') {
+            Some(pattern) => Self {
+                pattern: WildMatch::new(pattern),
+                dirs_only: true,
+            },
+            None => Self {
+                pattern: WildMatch::new(pattern),
+                dirs_only: false,
+            },
+        }
+    }
+
+    /// Returns whether `file` matches the pattern
+    fn matches(&self, file: &File) -> bool {
+        (!self.dirs_only || file.is_dir()) && self.pattern.matches(file.name().as_str())
+    }
+}
+
 impl FileExplorer {
-    /// push directory to stack
+    /// Push the directory left to enter a new one to the history, discarding the forward history
     pub fn pushd(&mut self, dir: &Path) {
-        // Check if stack would overflow the size
-        while self.dirstack.len() >= self.stack_size {
-            self.dirstack.pop_front(); // Start cleaning events from back
-        }
-        // Eventually push front the new record
-        self.dirstack.push_back(PathBuf::from(dir));
+        self.history.visit(dir);
     }
 
-    /// Pop directory from the stack and return the directory
+    /// Go back in the history and return the previous directory;
+    /// the working directory becomes the next one to go forward to
     pub fn popd(&mut self) -> Option<PathBuf> {
-        self.dirstack.pop_back()
+        let wrkdir = self.wrkdir.clone();
+        self.history.back(wrkdir.as_path())
+    }
+
+    /// Go forward in the history and return the next directory;
+    /// the working directory becomes the previous one to go back to
+    pub fn forwardd(&mut self) -> Option<PathBuf> {
+        let wrkdir = self.wrkdir.clone();
+        self.history.forward(wrkdir.as_path())
     }
 
     /// Set Explorer files
@@ -116,17 +181,7 @@ impl FileExplorer {
     /// Iterate over files
     /// Filters are applied based on current options (e.g. hidden files not returned)
     pub fn iter_files(&self) -> impl Iterator<Item = &File> + '_ {
-        // Filter
-        let opts: ExplorerOpts = self.opts;
-        Box::new(self.files.iter().filter(move |x| {
-            // If true, element IS NOT filtered
-            let mut pass: bool = true;
-            // If hidden files SHOULDN'T be shown, AND pass with not hidden
-            if !opts.intersects(ExplorerOpts::SHOW_HIDDEN_FILES) {
-                pass &= !x.is_hidden();
-            }
-            pass
-        }))
+        Box::new(self.files.iter().filter(move |x| self.is_visible(x)))
     }
 
     /// Iterate all files; doesn't care about options
@@ -136,21 +191,40 @@ impl FileExplorer {
 
     /// Get file at relative index
     pub fn get(&self, idx: usize) -> Option<&File> {
-        let opts: ExplorerOpts = self.opts;
-        let filtered = self
-            .files
-            .iter()
-            .filter(move |x| {
-                // If true, element IS NOT filtered
-                let mut pass: bool = true;
-                // If hidden files SHOULDN'T be shown, AND pass with not hidden
-                if !opts.intersects(ExplorerOpts::SHOW_HIDDEN_FILES) {
-                    pass &= !x.is_hidden();
-                }
-                pass
+        self.iter_files().nth(idx)
+    }
+
+    /// Get the index of the next visible file after the one at `from`, whose name starts with `ch`, ignoring case.
+    /// The lookup wraps around; without `from`, it starts at the first file
+    pub fn next_index_starting_with(&self, from: Option<usize>, ch: char) -> Option<usize> {
+        let names: Vec<String> = self.iter_files().map(|x| x.name()).collect();
+        let start = from.map(|x| x + 1).unwrap_or(0);
+        (0..names.len())
+            .map(|i| (start + i) % names.len())
+            .find(|i| {
+                names[*i]
+                    .chars()
+                    .next()
+                    .is_some_and(|first| first.to_lowercase().eq(ch.to_lowercase()))
             })
-            .collect::<Vec<_>>();
-        filtered.get(idx).copied()
+    }
+
+    /// Returns whether `file` passes the filters of the current options
+    fn is_visible(&self, file: &File) -> bool {
+        // If hidden files SHOULDN'T be shown, AND pass with not hidden
+        if !self.opts.intersects(ExplorerOpts::SHOW_HIDDEN_FILES) && file.is_hidden() {
+            return false;
+        }
+        // Directories are never filtered, so that it's still possible to browse
+        if let Some(filter) = &self.filter
+            && !file.is_dir()
+            && !filter.matches(&file.name())
+        {
+            return false;
+        }
+        self.opts
+            .intersects(ExplorerOpts::SHOW_PATTERN_HIDDEN_FILES)
+            || !self.hidden_patterns.iter().any(|p| p.matches(file))
     }
 
     /// Enqueue a file for transfer
@@ -167,11 +241,57 @@ impl FileExplorer {
         }
     }
 
+    /// Invert the marks of the visible files: marked files are dequeued and the other ones are enqueued for `dst`
+    pub fn invert_queue(&mut self, dst: &Path) {
+        let files: Vec<_> = self.iter_files().map(|f| f.path.clone()).collect();
+        for file in files {
+            if self.transfer_queue.contains_key(&file) {
+                self.dequeue(&file);
+            } else {
+                self.enqueue(&file, dst);
+            }
+        }
+    }
+
+    /// Get the indexes of the visible files whose name matches the glob `pattern`
+    pub fn matching_indexes(&self, pattern: &str) -> Vec<usize> {
+        let pattern = WildMatch::new(pattern);
+        self.iter_files()
+            .enumerate()
+            .filter(|(_, x)| pattern.matches(x.name().as_str()))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Enqueue the visible files whose name matches the glob `pattern` for `dst`.
+    /// Returns the amount of enqueued files
+    pub fn enqueue_matching(&mut self, pattern: &str, dst: &Path) -> usize {
+        let indexes = self.matching_indexes(pattern);
+        let files: Vec<_> = self
+            .iter_files()
+            .enumerate()
+            .filter(|(i, _)| indexes.binary_search(i).is_ok())
+            .map(|(_, f)| f.path.clone())
+            .collect();
+        for file in files.iter() {
+            self.enqueue(file, dst);
+        }
+        files.len()
+    }
+
     /// Get enqueued files
     pub fn enqueued(&self) -> &HashMap<PathBuf, PathBuf> {
         &self.transfer_queue
     }
 
+    /// Get the paths of the enqueued files, in the order they are listed
+    pub fn enqueued_paths(&self) -> Vec<&Path> {
+        self.iter_files_all()
+            .map(|x| x.path())
+            .filter(|x| self.transfer_queue.contains_key(*x))
+            .collect()
+    }
+
     /// Dequeue a file
     pub fn dequeue(&mut self, src: &Path) {
         self.transfer_queue.remove(src);
@@ -182,6 +302,16 @@ impl FileExplorer {
         self.transfer_queue.clear();
     }
 
+    /// Mark `dir` with `mark`, replacing the directory previously marked with it
+    pub fn set_dir_mark(&mut self, mark: char, dir: &Path) {
+        self.dir_marks.insert(mark, dir.to_path_buf());
+    }
+
+    /// Get the directory marked with `mark`
+    pub fn dir_mark(&self, mark: char) -> Option<&Path> {
+        self.dir_marks.get(&mark).map(PathBuf::as_path)
+    }
+
     /// Toggle terminal state
     pub fn toggle_terminal(&mut self, terminal: bool) {
         self.terminal = terminal;
@@ -198,6 +328,29 @@ impl FileExplorer {
         self.fmt.fmt(entry)
     }
 
+    /// Switch sizes between human readable and the exact count of bytes
+    pub fn toggle_raw_sizes(&mut self) {
+        let raw = !self.fmt.raw_sizes();
+        self.fmt.set_raw_sizes(raw);
+    }
+
+    /// Returns whether sizes are displayed as the exact count of bytes
+    pub fn raw_sizes(&self) -> bool {
+        self.fmt.raw_sizes()
+    }
+
+    // Filter
+
+    /// Set the filter on the names of the listed files; `None` lists all the files
+    pub fn set_filter(&mut self, filter: Option<FileFilter>) {
+        self.filter = filter;
+    }
+
+    /// Get the filter on the names of the listed files
+    pub fn filter(&self) -> Option<&FileFilter> {
+        self.filter.as_ref()
+    }
+
     // Sorting
 
     /// Choose sorting method; then sort files
@@ -231,6 +384,8 @@ impl FileExplorer {
             FileSorting::CreationTime => self.sort_files_by_creation_time(),
             FileSorting::ModifyTime => self.sort_files_by_mtime(),
             FileSorting::Size => self.sort_files_by_size(),
+            FileSorting::Extension => self.sort_files_by_extension(),
+            FileSorting::Natural => self.sort_files_naturally(),
             FileSorting::None => {}
         }
         // Directories first (NOTE: MUST COME AFTER OTHER SORTING)
@@ -245,7 +400,11 @@ impl FileExplorer {
 
     /// Sort explorer files by their name. All names are converted to lowercase
     fn sort_files_by_name(&mut self) {
-        self.files.sort_by_key(|x: &File| x.name().to_lowercase());
+        if self.opts.intersects(ExplorerOpts::CASE_SENSITIVE_SORTING) {
+            self.files.sort_by_key(|x: &File| x.name());
+        } else {
+            self.files.sort_by_key(|x: &File| x.name().to_lowercase());
+        }
     }
 
     /// Sort files by mtime; the newest comes first
@@ -266,6 +425,23 @@ impl FileExplorer {
             .sort_by_key(|b: &File| Reverse(b.metadata().size));
     }
 
+    /// Sort files by extension, then by name; directories come first
+    fn sort_files_by_extension(&mut self) {
+        let case_sensitive = self.opts.intersects(ExplorerOpts::CASE_SENSITIVE_SORTING);
+        self.files
+            .sort_by(|a, b| cmp_by_extension(a, b, case_sensitive));
+    }
+
+    /// Sort files by name, comparing the numbers in names by their value (e.g. `file2` before `file10`)
+    fn sort_files_naturally(&mut self) {
+        if self.opts.intersects(ExplorerOpts::CASE_SENSITIVE_SORTING) {
+            self.files.sort_by(|a, b| cmp_natural(&a.name(), &b.name()));
+        } else {
+            self.files
+                .sort_by(|a, b| cmp_natural(&a.name().to_lowercase(), &b.name().to_lowercase()));
+        }
+    }
+
     /// Sort files; directories come first
     fn sort_files_directories_first(&mut self) {
         self.files.sort_by_key(|x: &File| !x.is_dir());
@@ -285,6 +461,82 @@ impl FileExplorer {
     pub fn hidden_files_visible(&self) -> bool {
         self.opts.intersects(ExplorerOpts::SHOW_HIDDEN_FILES)
     }
+
+    /// Returns whether the explorer has any hidden pattern
+    pub fn has_hidden_patterns(&self) -> bool {
+        !self.hidden_patterns.is_empty()
+    }
+
+    /// Show/hide files matching the hidden patterns
+    pub fn toggle_pattern_hidden_files(&mut self) {
+        self.opts.toggle(ExplorerOpts::SHOW_PATTERN_HIDDEN_FILES);
+    }
+
+    /// Returns whether files matching the hidden patterns are visible
+    pub fn pattern_hidden_files_visible(&self) -> bool {
+        self.opts
+            .intersects(ExplorerOpts::SHOW_PATTERN_HIDDEN_FILES)
+    }
+}
+
+/// Compare files by extension, then by name. Directories come first, sorted by name,
+/// and files without extension come before the others
+fn cmp_by_extension(a: &File, b: &File, case_sensitive: bool) -> Ordering {
+    let key = |x: &File| {
+        let ext = match x.is_dir() {
+            true => String::default(),
+            false => x.extension().unwrap_or_default(),
+        };
+        match case_sensitive {
+            true => (ext, x.name()),
+            false => (ext.to_lowercase(), x.name().to_lowercase()),
+        }
+    };
+    (!a.is_dir())
+        .cmp(&!b.is_dir())
+        .then_with(|| key(a).cmp(&key(b)))
+}
+
+/// Compare names naturally: they're split into runs of digits and non-digits,
+/// and runs of digits are compared by their numeric value, so `file2` comes before `file10`
+fn cmp_natural(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a, b);
+    loop {
+        let (run_a, rest_a) = split_run(a);
+        let (run_b, rest_b) = split_run(b);
+        if run_a.is_empty() || run_b.is_empty() {
+            return run_a.len().cmp(&run_b.len());
+        }
+        let is_number = |run: &str| run.starts_with(|c: char| c.is_ascii_digit());
+        let ordering = match (is_number(run_a), is_number(run_b)) {
+            (true, true) => cmp_numbers(run_a, run_b),
+            _ => run_a.cmp(run_b),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+        (a, b) = (rest_a, rest_b);
+    }
+}
+
+/// Split the leading run of digits or non-digits from `s`
+fn split_run(s: &str) -> (&str, &str) {
+    let digits = s.starts_with(|c: char| c.is_ascii_digit());
+    let end = s
+        .find(|c: char| c.is_ascii_digit() != digits)
+        .unwrap_or(s.len());
+    s.split_at(end)
+}
+
+/// Compare two runs of digits by value, without overflowing on long runs.
+/// Equal values are ordered by the count of leading zeros (e.g. `1` before `01`)
+fn cmp_numbers(a: &str, b: &str) -> Ordering {
+    let (trimmed_a, trimmed_b) = (a.trim_start_matches('0'), b.trim_start_matches('0'));
+    trimmed_a
+        .len()
+        .cmp(&trimmed_b.len())
+        .then_with(|| trimmed_a.cmp(trimmed_b))
+        .then_with(|| a.len().cmp(&b.len()))
 }
 
 // Traits
@@ -299,6 +551,8 @@ impl std::fmt::Display for FileSorting {
                 FileSorting::ModifyTime => "by_mtime",
                 FileSorting::Name => "by_name",
                 FileSorting::Size => "by_size",
+                FileSorting::Extension => "by_extension",
+                FileSorting::Natural => "by_natural",
                 FileSorting::None => "none",
             }
         )
@@ -313,6 +567,8 @@ impl FromStr for FileSorting {
             "by_mtime" => Ok(FileSorting::ModifyTime),
             "by_name" => Ok(FileSorting::Name),
             "by_size" => Ok(FileSorting::Size),
+            "by_extension" => Ok(FileSorting::Extension),
+            "by_natural" => Ok(FileSorting::Natural),
             _ => Err(()),
         }
     }
@@ -342,6 +598,58 @@ impl FromStr for GroupDirs {
     }
 }
 
+impl std::fmt::Display for FileSubmitAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                FileSubmitAction::Nothing => "nothing",
+                FileSubmitAction::Open => "open",
+                FileSubmitAction::Transfer => "transfer",
+                FileSubmitAction::Edit => "edit",
+            }
+        )
+    }
+}
+
+impl FromStr for FileSubmitAction {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "nothing" => Ok(FileSubmitAction::Nothing),
+            "open" => Ok(FileSubmitAction::Open),
+            "transfer" => Ok(FileSubmitAction::Transfer),
+            "edit" => Ok(FileSubmitAction::Edit),
+            _ => Err(()),
+        }
+    }
+}
+
+impl std::fmt::Display for FocusedPanel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                FocusedPanel::Local => "local",
+                FocusedPanel::Remote => "remote",
+            }
+        )
+    }
+}
+
+impl FromStr for FocusedPanel {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "local" => Ok(FocusedPanel::Local),
+            "remote" => Ok(FocusedPanel::Remote),
+            _ => Err(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -358,11 +666,11 @@ mod tests {
     fn test_fs_explorer_new() {
         let explorer: FileExplorer = FileExplorer::default();
         // Verify
-        assert_eq!(explorer.dirstack.len(), 0);
+        assert!(explorer.history.entries.is_empty());
         assert_eq!(explorer.files.len(), 0);
         assert_eq!(explorer.opts, ExplorerOpts::empty());
         assert_eq!(explorer.wrkdir, PathBuf::from("/"));
-        assert_eq!(explorer.stack_size, 16);
+        assert_eq!(explorer.history.capacity, 16);
         assert_eq!(explorer.group_dirs, None);
         assert_eq!(explorer.file_sorting, FileSorting::Name);
         assert_eq!(explorer.get_file_sorting(), FileSorting::Name);
@@ -371,31 +679,29 @@ mod tests {
     #[test]
     fn test_fs_explorer_stack() {
         let mut explorer = FileExplorer {
-            stack_size: 2,
-            dirstack: VecDeque::with_capacity(2),
+            history: DirHistory::new(2),
             ..Default::default()
         };
-        explorer.dirstack = VecDeque::with_capacity(2);
         // Push dir
         explorer.pushd(Path::new("/tmp"));
         explorer.pushd(Path::new("/home/omar"));
         // Pop
         assert_eq!(explorer.popd().unwrap(), PathBuf::from("/home/omar"));
-        assert_eq!(explorer.dirstack.len(), 1);
+        assert_eq!(explorer.history.cursor, 1);
         assert_eq!(explorer.popd().unwrap(), PathBuf::from("/tmp"));
-        assert_eq!(explorer.dirstack.len(), 0);
-        // Dirstack is empty now
+        assert_eq!(explorer.history.cursor, 0);
+        // There's no previous directory now
         assert!(explorer.popd().is_none());
+        // Go forward to the working directory
+        assert_eq!(explorer.forwardd().unwrap(), PathBuf::from("/"));
         // Exceed limit
         explorer.pushd(Path::new("/tmp"));
         explorer.pushd(Path::new("/home/omar"));
         explorer.pushd(Path::new("/dev"));
-        assert_eq!(explorer.dirstack.len(), 2);
-        assert_eq!(*explorer.dirstack.get(1).unwrap(), PathBuf::from("/dev"));
-        assert_eq!(
-            *explorer.dirstack.get(0).unwrap(),
-            PathBuf::from("/home/omar")
-        );
+        assert_eq!(explorer.history.entries.len(), 2);
+        assert_eq!(explorer.history.entries[1], PathBuf::from("/dev"));
+        assert_eq!(explorer.history.entries[0], PathBuf::from("/home/omar"));
+        assert!(explorer.forwardd().is_none());
     }
 
     #[test]
@@ -428,6 +734,124 @@ mod tests {
         assert_eq!(explorer.iter_files().count(), 6); // All files are returned now
     }
 
+    #[test]
+    fn test_fs_explorer_hidden_patterns() {
+        let mut explorer: FileExplorer = FileExplorer {
+            hidden_patterns: vec![
+                HiddenPattern::new("target/"),
+                HiddenPattern::new("node_modules/"),
+                HiddenPattern::new("*.o"),
+            ],
+            ..Default::default()
+        };
+        explorer.set_files(vec![
+            make_fs_entry("Cargo.toml", false),
+            make_fs_entry("main.o", false),
+            make_fs_entry("node_modules", true),
+            make_fs_entry("src", true),
+            make_fs_entry("target", true),
+            make_fs_entry("target", false),
+        ]);
+        // Patterns filter the listing
+        assert!(explorer.has_hidden_patterns());
+        assert_eq!(explorer.pattern_hidden_files_visible(), false);
+        assert_eq!(
+            explorer.iter_files().map(|x| x.name()).collect::<Vec<_>>(),
+            vec!["Cargo.toml", "src", "target"]
+        );
+        assert!(
+            !explorer
+                .iter_files()
+                .any(|x| x.is_dir() && x.name() == "target")
+        );
+        assert_eq!(explorer.get(2).unwrap().name(), "target");
+        assert!(explorer.get(3).is_none());
+        // Reveal
+        explorer.toggle_pattern_hidden_files();
+        assert_eq!(explorer.pattern_hidden_files_visible(), true);
+        assert_eq!(explorer.iter_files().count(), 6);
+        assert_eq!(explorer.get(1).unwrap().name(), "main.o");
+        // Hide again
+        explorer.toggle_pattern_hidden_files();
+        assert_eq!(explorer.iter_files().count(), 3);
+    }
+
+    #[test]
+    fn test_fs_explorer_hidden_patterns_are_separate_from_hidden_files() {
+        let mut explorer: FileExplorer = FileExplorer {
+            hidden_patterns: vec![HiddenPattern::new(".cache/")],
+            ..Default::default()
+        };
+        explorer.set_files(vec![
+            make_fs_entry(".cache", true),
+            make_fs_entry(".git", true),
+            make_fs_entry("README.md", false),
+        ]);
+        assert_eq!(explorer.iter_files().count(), 1);
+        // Showing hidden files doesn't reveal pattern-hidden files
+        explorer.toggle_hidden_files();
+        assert_eq!(
+            explorer.iter_files().map(|x| x.name()).collect::<Vec<_>>(),
+            vec![".git", "README.md"]
+        );
+        explorer.toggle_pattern_hidden_files();
+        assert_eq!(explorer.iter_files().count(), 3);
+    }
+
+    #[test]
+    fn test_fs_explorer_filter() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.set_files(vec![
+            make_fs_entry(".env.tmp", false),
+            make_fs_entry("build.tmp", false),
+            make_fs_entry("main.rs", false),
+            make_fs_entry("src", true),
+        ]);
+        assert!(explorer.filter().is_none());
+        // Directories are kept
+        explorer.set_filter(Some(FileFilter::from_str("*.tmp").unwrap()));
+        assert_eq!(explorer.filter().unwrap().expression(), "*.tmp");
+        assert_eq!(
+            explorer.iter_files().map(|x| x.name()).collect::<Vec<_>>(),
+            vec!["build.tmp", "src"]
+        );
+        // Hidden files are filtered too, once visible
+        explorer.toggle_hidden_files();
+        assert_eq!(explorer.iter_files().count(), 3);
+        explorer.set_filter(Some(FileFilter::from_str("!*.tmp").unwrap()));
+        assert_eq!(
+            explorer.iter_files().map(|x| x.name()).collect::<Vec<_>>(),
+            vec!["main.rs", "src"]
+        );
+        explorer.set_filter(None);
+        assert_eq!(explorer.iter_files().count(), 4);
+    }
+
+    #[test]
+    fn test_fs_explorer_filter_and_sorting_are_kept_on_changedir() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.sort_by(FileSorting::Size);
+        explorer.set_filter(Some(FileFilter::from_str("!*.log").unwrap()));
+        explorer.set_files(vec![
+            make_fs_entry_with_size("a.txt", false, 16),
+            make_fs_entry_with_size("b.log", false, 64),
+        ]);
+        // Enter another directory
+        explorer.pushd(Path::new("/home"));
+        explorer.wrkdir = PathBuf::from("/home/omar");
+        explorer.set_files(vec![
+            make_fs_entry_with_size("small.txt", false, 8),
+            make_fs_entry_with_size("server.log", false, 512),
+            make_fs_entry_with_size("big.txt", false, 256),
+        ]);
+        assert_eq!(explorer.get_file_sorting(), FileSorting::Size);
+        assert_eq!(explorer.filter().unwrap().expression(), "!*.log");
+        assert_eq!(
+            explorer.iter_files().map(|x| x.name()).collect::<Vec<_>>(),
+            vec!["big.txt", "small.txt"]
+        );
+    }
+
     #[test]
     fn test_fs_explorer_sort_by_name() {
         let mut explorer: FileExplorer = FileExplorer::default();
@@ -450,6 +874,77 @@ mod tests {
         assert_eq!(explorer.files.get(8).unwrap().name(), "src");
     }
 
+    #[test]
+    fn test_fs_explorer_sort_by_name_case_sensitive() {
+        let files = vec![
+            make_fs_entry("readme.md", false),
+            make_fs_entry("Makefile", false),
+            make_fs_entry("build.rs", false),
+            make_fs_entry("Cargo.toml", false),
+        ];
+        let names = |explorer: &FileExplorer| {
+            explorer
+                .files
+                .iter()
+                .map(|x| x.name())
+                .collect::<Vec<String>>()
+        };
+        // Case insensitive (default)
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.set_files(files.clone());
+        explorer.sort_by(FileSorting::Name);
+        assert_eq!(
+            names(&explorer),
+            vec!["build.rs", "Cargo.toml", "Makefile", "readme.md"]
+        );
+        // Case sensitive: uppercase names come first
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.opts.insert(ExplorerOpts::CASE_SENSITIVE_SORTING);
+        explorer.set_files(files);
+        explorer.sort_by(FileSorting::Name);
+        assert_eq!(
+            names(&explorer),
+            vec!["Cargo.toml", "Makefile", "build.rs", "readme.md"]
+        );
+    }
+
+    #[test]
+    fn test_fs_explorer_enqueued_paths_in_listing_order() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.set_files(vec![
+            make_fs_entry("README.md", false),
+            make_fs_entry("src", true),
+            make_fs_entry("Cargo.toml", false),
+            make_fs_entry("LICENSE", false),
+        ]);
+        explorer.sort_by(FileSorting::Name);
+        let dest = Path::new("/tmp");
+        explorer.enqueue(Path::new("src"), dest);
+        explorer.enqueue(Path::new("Cargo.toml"), dest);
+        explorer.enqueue(Path::new("README.md"), dest);
+        explorer.dequeue(Path::new("README.md"));
+        assert_eq!(
+            explorer.enqueued_paths(),
+            vec![Path::new("Cargo.toml"), Path::new("src")]
+        );
+        assert_eq!(
+            explorer
+                .enqueued_paths()
+                .iter()
+                .map(|x| x.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("\n"),
+            "Cargo.toml\nsrc"
+        );
+        // Order follows sorting
+        explorer.sort_by(FileSorting::Size);
+        explorer.group_dirs_by(Some(GroupDirs::First));
+        assert_eq!(
+            explorer.enqueued_paths(),
+            vec![Path::new("src"), Path::new("Cargo.toml")]
+        );
+    }
+
     #[test]
     fn test_fs_explorer_sort_by_mtime() {
         let mut explorer: FileExplorer = FileExplorer::default();
@@ -498,6 +993,120 @@ mod tests {
         assert_eq!(explorer.files.get(2).unwrap().name(), "CONTRIBUTING.md");
     }
 
+    #[test]
+    fn test_fs_explorer_sort_by_extension() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.set_files(vec![
+            make_fs_entry("report.pdf", false),
+            make_fs_entry("src", true),
+            make_fs_entry("Makefile", false),
+            make_fs_entry("archive.tar.gz", false),
+            make_fs_entry("notes.md", false),
+            make_fs_entry("backup.d", true),
+            make_fs_entry("README.md", false),
+            make_fs_entry(".gitignore", false),
+        ]);
+        explorer.sort_by(FileSorting::Extension);
+        assert_eq!(
+            explorer
+                .files
+                .iter()
+                .map(|x| x.name())
+                .collect::<Vec<String>>(),
+            vec![
+                // Directories first, by name
+                "backup.d",
+                "src",
+                // Files without extension
+                ".gitignore",
+                "Makefile",
+                // Then by extension and name
+                "archive.tar.gz",
+                "notes.md",
+                "README.md",
+                "report.pdf",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_compare_files_by_extension() {
+        let file = |name| make_fs_entry(name, false);
+        assert_eq!(
+            cmp_by_extension(&file("b.md"), &file("a.txt"), false),
+            Ordering::Less
+        );
+        assert_eq!(
+            cmp_by_extension(&file("a.md"), &file("b.md"), false),
+            Ordering::Less
+        );
+        // Files without extension come first
+        assert_eq!(
+            cmp_by_extension(&file("LICENSE"), &file("a.md"), false),
+            Ordering::Less
+        );
+        assert_eq!(
+            cmp_by_extension(&file("LICENSE"), &file("Makefile"), false),
+            Ordering::Less
+        );
+        // Directories come first, whatever their name
+        assert_eq!(
+            cmp_by_extension(&make_fs_entry("z.d", true), &file("a"), false),
+            Ordering::Less
+        );
+        // Case
+        assert_eq!(
+            cmp_by_extension(&file("a.MD"), &file("b.md"), false),
+            Ordering::Less
+        );
+        assert_eq!(
+            cmp_by_extension(&file("b.md"), &file("a.MD"), true),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_fs_explorer_sort_naturally() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.set_files(vec![
+            make_fs_entry("file2", false),
+            make_fs_entry("file10", false),
+            make_fs_entry("File1", false),
+            make_fs_entry("file1.txt", false),
+        ]);
+        explorer.sort_by(FileSorting::Natural);
+        assert_eq!(
+            explorer
+                .files
+                .iter()
+                .map(|x| x.name())
+                .collect::<Vec<String>>(),
+            vec!["File1", "file1.txt", "file2", "file10"]
+        );
+    }
+
+    #[test]
+    fn should_compare_names_naturally() {
+        assert_eq!(cmp_natural("file1", "file2"), Ordering::Less);
+        assert_eq!(cmp_natural("file2", "file10"), Ordering::Less);
+        assert_eq!(cmp_natural("file1", "file10"), Ordering::Less);
+        assert_eq!(cmp_natural("file10", "file2"), Ordering::Greater);
+        assert_eq!(cmp_natural("file2", "file2"), Ordering::Equal);
+        // Several numbers
+        assert_eq!(cmp_natural("v1.9.2", "v1.10.0"), Ordering::Less);
+        // Leading zeros
+        assert_eq!(cmp_natural("file01", "file2"), Ordering::Less);
+        assert_eq!(cmp_natural("file1", "file01"), Ordering::Less);
+        // Prefixes come first
+        assert_eq!(cmp_natural("file", "file1"), Ordering::Less);
+        assert_eq!(cmp_natural("", "a"), Ordering::Less);
+        // Numbers longer than u64
+        assert_eq!(
+            cmp_natural("file99999999999999999999", "file100000000000000000000"),
+            Ordering::Less
+        );
+    }
+
     #[test]
     fn test_fs_explorer_sort_by_name_and_dirs_first() {
         let mut explorer: FileExplorer = FileExplorer::default();
@@ -554,7 +1163,7 @@ mod tests {
 
     #[test]
     fn test_fs_explorer_fmt() {
-        let explorer: FileExplorer = FileExplorer::default();
+        let mut explorer: FileExplorer = FileExplorer::default();
         // Create fs entry
         let t: SystemTime = SystemTime::now();
         let entry = File {
@@ -575,7 +1184,7 @@ mod tests {
         assert_eq!(
             explorer.fmt_file(&entry),
             format!(
-                "bar.txt                  -rw-r--r-- root         8.2 kB     {}",
+                "bar.txt                  -rw-r--r-- root         8.0 KiB    {}",
                 fmt_time(t, "%b %d %Y %H:%M")
             )
         );
@@ -583,10 +1192,16 @@ mod tests {
         assert_eq!(
             explorer.fmt_file(&entry),
             format!(
-                "bar.txt                  -rw-r--r-- 0            8.2 kB     {}",
+                "bar.txt                  -rw-r--r-- 0            8.0 KiB    {}",
                 fmt_time(t, "%b %d %Y %H:%M")
             )
         );
+        // Exact sizes
+        explorer.toggle_raw_sizes();
+        assert!(explorer.raw_sizes());
+        assert!(explorer.fmt_file(&entry).contains(" 8192 B     "));
+        explorer.toggle_raw_sizes();
+        assert!(!explorer.raw_sizes());
     }
 
     #[test]
@@ -596,6 +1211,8 @@ mod tests {
         assert_eq!(FileSorting::ModifyTime.to_string(), "by_mtime");
         assert_eq!(FileSorting::Name.to_string(), "by_name");
         assert_eq!(FileSorting::Size.to_string(), "by_size");
+        assert_eq!(FileSorting::Extension.to_string(), "by_extension");
+        assert_eq!(FileSorting::Natural.to_string(), "by_natural");
         assert_eq!(
             FileSorting::from_str("by_creation_time").ok().unwrap(),
             FileSorting::CreationTime
@@ -612,6 +1229,14 @@ mod tests {
             FileSorting::from_str("by_size").ok().unwrap(),
             FileSorting::Size
         );
+        assert_eq!(
+            FileSorting::from_str("by_extension").ok().unwrap(),
+            FileSorting::Extension
+        );
+        assert_eq!(
+            FileSorting::from_str("by_natural").ok().unwrap(),
+            FileSorting::Natural
+        );
         assert!(FileSorting::from_str("omar").is_err());
         // Group dirs
         assert_eq!(GroupDirs::First.to_string(), "first");
@@ -619,6 +1244,33 @@ mod tests {
         assert_eq!(GroupDirs::from_str("first").ok().unwrap(), GroupDirs::First);
         assert_eq!(GroupDirs::from_str("last").ok().unwrap(), GroupDirs::Last);
         assert!(GroupDirs::from_str("omar").is_err());
+        // File submit action
+        for action in [
+            FileSubmitAction::Nothing,
+            FileSubmitAction::Open,
+            FileSubmitAction::Transfer,
+            FileSubmitAction::Edit,
+        ] {
+            assert_eq!(
+                FileSubmitAction::from_str(&action.to_string())
+                    .ok()
+                    .unwrap(),
+                action
+            );
+        }
+        assert_eq!(
+            FileSubmitAction::from_str("Transfer").ok().unwrap(),
+            FileSubmitAction::Transfer
+        );
+        assert!(FileSubmitAction::from_str("omar").is_err());
+        // Focused panel
+        assert_eq!(FocusedPanel::Local.to_string(), "local");
+        assert_eq!(FocusedPanel::Remote.to_string(), "remote");
+        assert_eq!(
+            FocusedPanel::from_str("Remote").ok().unwrap(),
+            FocusedPanel::Remote
+        );
+        assert!(FocusedPanel::from_str("omar").is_err());
     }
 
     #[test]
@@ -658,6 +1310,124 @@ mod tests {
         assert_eq!(explorer.enqueued().len(), 0);
     }
 
+    #[test]
+    fn test_should_invert_queue() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.set_files(vec![
+            make_fs_entry("CONTRIBUTING.md", false),
+            make_fs_entry("docs", true),
+            make_fs_entry("src", true),
+            make_fs_entry("README.md", false),
+        ]);
+        let dest = Path::new("/tmp");
+        explorer.enqueue(Path::new("docs"), dest);
+        explorer.enqueue(Path::new("README.md"), dest);
+        let mut marked: Vec<PathBuf> = explorer.enqueued().keys().cloned().collect();
+        marked.sort();
+        explorer.invert_queue(dest);
+        let mut inverted: Vec<&Path> = explorer.enqueued().keys().map(PathBuf::as_path).collect();
+        inverted.sort();
+        assert_eq!(
+            inverted,
+            vec![Path::new("CONTRIBUTING.md"), Path::new("src")]
+        );
+        // Inverting twice restores the marks
+        explorer.invert_queue(dest);
+        let mut restored: Vec<PathBuf> = explorer.enqueued().keys().cloned().collect();
+        restored.sort();
+        assert_eq!(restored, marked);
+    }
+
+    #[test]
+    fn test_should_get_matching_indexes() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.set_files(vec![
+            make_fs_entry("c.log", false),
+            make_fs_entry("b.txt", false),
+            make_fs_entry(".hidden.log", false),
+            make_fs_entry("a.log", false),
+            make_fs_entry("logs", true),
+        ]);
+        assert_eq!(explorer.matching_indexes("*.log"), vec![0, 2]);
+        assert_eq!(explorer.matching_indexes("?.*"), vec![0, 1, 2]);
+        assert!(explorer.matching_indexes("*.rs").is_empty());
+        // Hidden files match once they're shown
+        explorer.toggle_hidden_files();
+        assert_eq!(explorer.matching_indexes("*.log"), vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn test_should_enqueue_matching_files() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.set_files(vec![
+            make_fs_entry("c.log", false),
+            make_fs_entry("b.txt", false),
+            make_fs_entry(".hidden.log", false),
+            make_fs_entry("a.log", false),
+        ]);
+        assert_eq!(explorer.enqueue_matching("*.log", Path::new("/tmp")), 2);
+        assert_eq!(
+            explorer.enqueued_paths(),
+            vec![Path::new("a.log"), Path::new("c.log")]
+        );
+        assert_eq!(explorer.enqueue_matching("*.rs", Path::new("/tmp")), 0);
+        assert_eq!(explorer.enqueued().len(), 2);
+    }
+
+    #[test]
+    fn test_should_get_next_index_starting_with() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.set_files(vec![
+            make_fs_entry("apple.txt", false),
+            make_fs_entry("banana.txt", false),
+            make_fs_entry("Avocado.txt", false),
+            make_fs_entry(".abc", false),
+            make_fs_entry("cherry.txt", false),
+        ]);
+        // apple.txt, Avocado.txt, banana.txt, cherry.txt
+        assert_eq!(explorer.next_index_starting_with(None, 'a'), Some(0));
+        assert_eq!(explorer.next_index_starting_with(Some(0), 'a'), Some(1));
+        assert_eq!(explorer.next_index_starting_with(Some(1), 'A'), Some(0));
+        assert_eq!(explorer.next_index_starting_with(Some(3), 'a'), Some(0));
+        // A single match is selected again
+        assert_eq!(explorer.next_index_starting_with(Some(2), 'b'), Some(2));
+        assert_eq!(explorer.next_index_starting_with(Some(2), 'z'), None);
+        // Hidden files are skipped
+        assert_eq!(explorer.next_index_starting_with(None, '.'), None);
+        assert_eq!(
+            FileExplorer::default().next_index_starting_with(None, 'a'),
+            None
+        );
+    }
+
+    #[test]
+    fn test_should_set_and_get_dir_marks() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        assert_eq!(explorer.dir_mark('a'), None);
+        explorer.set_dir_mark('a', Path::new("/home/omar/docs"));
+        explorer.set_dir_mark('b', Path::new("/tmp"));
+        assert_eq!(explorer.dir_mark('a'), Some(Path::new("/home/omar/docs")));
+        assert_eq!(explorer.dir_mark('b'), Some(Path::new("/tmp")));
+        // Marks are case sensitive
+        assert_eq!(explorer.dir_mark('A'), None);
+        explorer.set_dir_mark('a', Path::new("/var/log"));
+        assert_eq!(explorer.dir_mark('a'), Some(Path::new("/var/log")));
+    }
+
+    #[test]
+    fn test_should_not_invert_queue_of_hidden_files() {
+        let mut explorer: FileExplorer = FileExplorer::default();
+        explorer.set_files(vec![
+            make_fs_entry(".git", true),
+            make_fs_entry("src", true),
+        ]);
+        let dest = Path::new("/tmp");
+        explorer.enqueue(Path::new(".git"), dest);
+        explorer.invert_queue(dest);
+        // `.git` is hidden, so it keeps its mark
+        assert_eq!(explorer.enqueued().len(), 2);
+    }
+
     fn make_fs_entry(name: &str, is_dir: bool) -> File {
         let t: SystemTime = SystemTime::now();
         let metadata = Metadata {
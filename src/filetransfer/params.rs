@@ -8,6 +8,7 @@ mod smb;
 mod webdav;
 
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 pub use self::aws_s3::AwsS3Params;
 pub use self::kube::KubeProtocolParams;
@@ -15,6 +16,12 @@ pub use self::smb::SmbParams;
 pub use self::webdav::WebDAVProtocolParams;
 use super::FileTransferProtocol;
 
+/// Text displayed in place of secrets in the params summary
+const SECRET_MASK: &str = "********";
+
+/// Timeout to establish the connection with, when none is set in the params
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// Host bridge params
 #[derive(Debug, Clone)]
 pub enum HostBridgeParams {
@@ -41,6 +48,21 @@ impl HostBridgeParams {
             }
         }
     }
+
+    /// Describe the params as a list of (key, value); secrets are masked
+    pub fn summary(&self) -> Vec<(&'static str, String)> {
+        match self {
+            HostBridgeParams::Localhost(path) => vec![
+                ("Protocol", String::from("Localhost")),
+                ("Local path", path.display().to_string()),
+            ],
+            HostBridgeParams::Remote(protocol, params) => {
+                let mut summary = vec![("Protocol", protocol.to_string())];
+                summary.extend(params.summary());
+                summary
+            }
+        }
+    }
 }
 
 /// Holds connection parameters for file transfers
@@ -59,6 +81,19 @@ impl FileTransferParams {
             .generic_params()
             .and_then(|p| p.username.clone())
     }
+
+    /// Describe the params as a list of (key, value); secrets are masked
+    pub fn summary(&self) -> Vec<(&'static str, String)> {
+        let mut summary = vec![("Protocol", self.protocol.to_string())];
+        summary.extend(self.params.summary());
+        if let Some(path) = self.remote_path.as_deref() {
+            summary.push(("Remote path", path.display().to_string()));
+        }
+        if let Some(path) = self.local_path.as_deref() {
+            summary.push(("Local path", path.display().to_string()));
+        }
+        summary
+    }
 }
 
 /// Container for protocol params
@@ -106,6 +141,73 @@ impl ProtocolParams {
             ProtocolParams::WebDAV(params) => params.uri.clone(),
         }
     }
+
+    /// Describe the params as a list of (key, value); secrets are masked and unset values are omitted
+    pub fn summary(&self) -> Vec<(&'static str, String)> {
+        let secret =
+            |x: Option<&String>| x.filter(|x| !x.is_empty()).map(|_| SECRET_MASK.to_string());
+        let rows: Vec<(&'static str, Option<String>)> = match self {
+            ProtocolParams::AwsS3(params) => vec![
+                ("Bucket", Some(params.bucket_name.clone())),
+                ("Region", params.region.clone()),
+                ("Endpoint", params.endpoint.clone()),
+                ("Profile", params.profile.clone()),
+                ("Access key", params.access_key.clone()),
+                (
+                    "Secret access key",
+                    secret(params.secret_access_key.as_ref()),
+                ),
+                ("Security token", secret(params.security_token.as_ref())),
+                ("Session token", secret(params.session_token.as_ref())),
+            ],
+            ProtocolParams::Generic(params) => vec![
+                ("Host", Some(params.address.clone())),
+                ("Port", Some(params.port.to_string())),
+                ("Username", params.username.clone()),
+                ("Password", secret(params.password.as_ref())),
+                (
+                    "Identity file",
+                    params
+                        .identity_file
+                        .as_ref()
+                        .map(|x| x.display().to_string()),
+                ),
+                (
+                    "Connect timeout",
+                    params.connect_timeout.map(|x| format!("{}s", x.as_secs())),
+                ),
+                (
+                    "Keepalive",
+                    (params.keepalive_secs > 0).then(|| format!("{}s", params.keepalive_secs)),
+                ),
+            ],
+            ProtocolParams::Kube(params) => vec![
+                ("Namespace", params.namespace.clone()),
+                ("Cluster URL", params.cluster_url.clone()),
+                ("Username", params.username.clone()),
+                ("Client certificate", params.client_cert.clone()),
+                ("Client key", secret(params.client_key.as_ref())),
+            ],
+            ProtocolParams::Smb(params) => vec![
+                ("Host", Some(params.address.clone())),
+                #[cfg(posix)]
+                ("Port", Some(params.port.to_string())),
+                ("Share", Some(params.share.clone())),
+                ("Username", params.username.clone()),
+                ("Password", secret(params.password.as_ref())),
+                #[cfg(posix)]
+                ("Workgroup", params.workgroup.clone()),
+            ],
+            ProtocolParams::WebDAV(params) => vec![
+                ("URI", Some(params.uri.clone())),
+                ("Username", Some(params.username.clone())),
+                ("Password", secret(Some(&params.password))),
+            ],
+        };
+        rows.into_iter()
+            .filter_map(|(key, value)| value.map(|value| (key, value)))
+            .collect()
+    }
 }
 
 /// Protocol params used by most common protocols
@@ -115,6 +217,13 @@ pub struct GenericProtocolParams {
     pub port: u16,
     pub username: Option<String>,
     pub password: Option<String>,
+    /// Private key to authenticate with, for SSH protocols (e.g. the `IdentityFile` of the ssh config)
+    pub identity_file: Option<PathBuf>,
+    /// Timeout to establish the connection with; if unset, [`DEFAULT_CONNECT_TIMEOUT`] is used,
+    /// unless the ssh config sets a `ConnectTimeout` for the host
+    pub connect_timeout: Option<Duration>,
+    /// Interval in seconds to send keepalives to SSH servers with while idle; 0 disables them
+    pub keepalive_secs: u64,
 }
 
 impl FileTransferParams {
@@ -230,6 +339,9 @@ impl Default for GenericProtocolParams {
             port: 22,
             username: None,
             password: None,
+            identity_file: None,
+            connect_timeout: None,
+            keepalive_secs: 0,
         }
     }
 }
@@ -259,6 +371,24 @@ impl GenericProtocolParams {
         self
     }
 
+    /// Set identity file for params
+    pub fn identity_file<P: AsRef<Path>>(mut self, identity_file: Option<P>) -> Self {
+        self.identity_file = identity_file.map(|x| x.as_ref().to_path_buf());
+        self
+    }
+
+    /// Set connect timeout for params
+    pub fn connect_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Set keepalive interval in seconds for params
+    pub fn keepalive_secs(mut self, secs: u64) -> Self {
+        self.keepalive_secs = secs;
+        self
+    }
+
     /// Returns whether a password is supposed to be required for this protocol params.
     /// The result true is returned ONLY if the supposed secret is MISSING!!!
     pub fn password_missing(&self) -> bool {
@@ -303,6 +433,8 @@ mod test {
         assert_eq!(params.port, 22);
         assert!(params.username.is_none());
         assert!(params.password.is_none());
+        assert!(params.connect_timeout.is_none());
+        assert_eq!(params.keepalive_secs, 0);
     }
 
     #[test]
@@ -416,6 +548,79 @@ mod test {
         assert_eq!(params.params.webdav_params().unwrap().password, "secret");
     }
 
+    #[test]
+    fn should_summarize_params_masking_password() {
+        let params = FileTransferParams::new(
+            FileTransferProtocol::Sftp,
+            ProtocolParams::Generic(
+                GenericProtocolParams::default()
+                    .address("192.168.1.31")
+                    .port(2022)
+                    .username(Some("omar"))
+                    .password(Some("mysecret"))
+                    .connect_timeout(Some(Duration::from_secs(10)))
+                    .keepalive_secs(60),
+            ),
+        )
+        .remote_path(Some("/home/omar"));
+        let summary = params.summary();
+        assert_eq!(
+            summary,
+            vec![
+                ("Protocol", String::from("SFTP")),
+                ("Host", String::from("192.168.1.31")),
+                ("Port", String::from("2022")),
+                ("Username", String::from("omar")),
+                ("Password", String::from(SECRET_MASK)),
+                ("Connect timeout", String::from("10s")),
+                ("Keepalive", String::from("60s")),
+                ("Remote path", String::from("/home/omar")),
+            ]
+        );
+        assert!(summary.iter().all(|(_, value)| !value.contains("mysecret")));
+    }
+
+    #[test]
+    fn should_summarize_params_masking_secrets() {
+        let mut s3 = AwsS3Params::new("omar", Some("eu-west-1"), None);
+        s3.access_key = Some(String::from("AKIA"));
+        s3.secret_access_key = Some(String::from("mysecret"));
+        let summary =
+            FileTransferParams::new(FileTransferProtocol::AwsS3, ProtocolParams::AwsS3(s3))
+                .summary();
+        assert_eq!(
+            summary,
+            vec![
+                ("Protocol", String::from("S3")),
+                ("Bucket", String::from("omar")),
+                ("Region", String::from("eu-west-1")),
+                ("Access key", String::from("AKIA")),
+                ("Secret access key", String::from(SECRET_MASK)),
+            ]
+        );
+        // Unset password is omitted
+        let summary = HostBridgeParams::Remote(
+            FileTransferProtocol::Ftp(true),
+            ProtocolParams::Generic(GenericProtocolParams::default().address("ftp.example.com")),
+        )
+        .summary();
+        assert_eq!(
+            summary,
+            vec![
+                ("Protocol", String::from("FTPS")),
+                ("Host", String::from("ftp.example.com")),
+                ("Port", String::from("22")),
+            ]
+        );
+        assert_eq!(
+            HostBridgeParams::Localhost(PathBuf::from("/tmp")).summary(),
+            vec![
+                ("Protocol", String::from("Localhost")),
+                ("Local path", String::from("/tmp")),
+            ]
+        );
+    }
+
     #[test]
     fn set_default_secret_generic() {
         let mut params =
@@ -2,10 +2,12 @@
 //!
 //! Remotefs client builder
 
+use std::io::ErrorKind;
+use std::net::{TcpStream, ToSocketAddrs};
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use remotefs::RemoteFs;
+use remotefs::{RemoteError, RemoteErrorType, RemoteFs, RemoteResult};
 use remotefs_aws_s3::AwsS3Fs;
 use remotefs_ftp::FtpFs;
 use remotefs_kube::KubeMultiPodFs as KubeFs;
@@ -24,7 +26,7 @@ use remotefs_webdav::WebDAVFs;
 use super::params::{AwsS3Params, GenericProtocolParams};
 #[cfg(smb)]
 use super::params::{AwsS3Params, GenericProtocolParams, SmbParams};
-use super::params::{KubeProtocolParams, WebDAVProtocolParams};
+use super::params::{DEFAULT_CONNECT_TIMEOUT, KubeProtocolParams, WebDAVProtocolParams};
 use super::{FileTransferProtocol, ProtocolParams};
 use crate::system::config_client::ConfigClient;
 use crate::system::sshkey_storage::SshKeyStorage;
@@ -74,6 +76,42 @@ impl RemoteFsBuilder {
         }
     }
 
+    /// Check that the host of the FTP params can be reached within the connect timeout, since the
+    /// FTP client waits for the connection with no timeout.
+    ///
+    /// Other protocols handle the timeout themselves, so they're always reported as reachable
+    pub fn probe(protocol: FileTransferProtocol, params: &ProtocolParams) -> RemoteResult<()> {
+        let (FileTransferProtocol::Ftp(_), ProtocolParams::Generic(params)) = (protocol, params)
+        else {
+            return Ok(());
+        };
+        let timeout = params.connect_timeout.unwrap_or(DEFAULT_CONNECT_TIMEOUT);
+        let addresses = (params.address.as_str(), params.port)
+            .to_socket_addrs()
+            .map_err(|e| RemoteError::new_ex(RemoteErrorType::BadAddress, e))?;
+        let mut error = None;
+        for address in addresses {
+            debug!("probing {address} (timeout: {}s)", timeout.as_secs());
+            match TcpStream::connect_timeout(&address, timeout) {
+                Ok(_) => return Ok(()),
+                Err(err) => error = Some(err),
+            }
+        }
+        Err(match error {
+            Some(err) if err.kind() == ErrorKind::TimedOut => RemoteError::new_ex(
+                RemoteErrorType::ConnectionError,
+                format!(
+                    "could not connect to {}:{} within {}s",
+                    params.address,
+                    params.port,
+                    timeout.as_secs()
+                ),
+            ),
+            Some(err) => RemoteError::new_ex(RemoteErrorType::ConnectionError, err),
+            None => RemoteError::new(RemoteErrorType::BadAddress),
+        })
+    }
+
     /// Build aws s3 client from parameters
     fn aws_s3_client(params: AwsS3Params) -> AwsS3Fs {
         let rt = Arc::new(
@@ -208,9 +246,15 @@ impl RemoteFsBuilder {
     /// Build ssh options from generic protocol params and client configuration
     fn build_ssh_opts(params: GenericProtocolParams, config_client: &ConfigClient) -> SshOpts {
         let mut opts = SshOpts::new(params.address.clone())
-            .key_storage(Box::new(Self::make_ssh_storage(config_client)))
+            .key_storage(Box::new(
+                Self::make_ssh_storage(config_client).with_identity_file(params.identity_file),
+            ))
             .ssh_agent_identity(Some(SshAgentIdentity::All))
             .port(params.port);
+        // the timeout set in the ssh config is used when unset
+        if let Some(timeout) = params.connect_timeout {
+            opts = opts.connection_timeout(timeout);
+        }
         // get ssh config
         let ssh_config = config_client
             .get_ssh_config()
@@ -353,6 +397,39 @@ mod test {
         assert!(RemoteFsBuilder::build(FileTransferProtocol::Smb, params, &config_client).is_ok());
     }
 
+    #[test]
+    fn should_probe_reachable_ftp_host() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let params = ProtocolParams::Generic(
+            GenericProtocolParams::default()
+                .address("127.0.0.1")
+                .port(listener.local_addr().unwrap().port())
+                .connect_timeout(Some(std::time::Duration::from_secs(5))),
+        );
+        assert!(RemoteFsBuilder::probe(FileTransferProtocol::Ftp(false), &params).is_ok());
+        // other protocols aren't probed
+        drop(listener);
+        assert!(RemoteFsBuilder::probe(FileTransferProtocol::Sftp, &params).is_ok());
+    }
+
+    #[test]
+    fn should_not_probe_unreachable_ftp_host() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+        let params = ProtocolParams::Generic(
+            GenericProtocolParams::default()
+                .address("127.0.0.1")
+                .port(port),
+        );
+        assert_eq!(
+            RemoteFsBuilder::probe(FileTransferProtocol::Ftp(true), &params)
+                .unwrap_err()
+                .kind,
+            RemoteErrorType::ConnectionError
+        );
+    }
+
     #[test]
     fn should_not_build_fs() {
         let params = ProtocolParams::Generic(
@@ -26,7 +26,7 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use self::activity_manager::{ActivityManager, NextActivity};
-use self::cli::{Args, ArgsSubcommands, RemoteArgs, RunOpts, Task};
+use self::cli::{Args, ArgsSubcommands, BatchTransfer, RemoteArgs, RunOpts, Task};
 use self::system::logging::{self, LogLevel};
 
 const APP_NAME: &str = env!("CARGO_PKG_NAME");
@@ -76,6 +76,7 @@ fn parse_args(args: Args) -> Result<RunOpts, String> {
             RunOpts::import_ssh_hosts(subargs.ssh_config, !args.wno_keyring)
         }
         Some(ArgsSubcommands::ImportTheme(args)) => RunOpts::import_theme(args.theme),
+        Some(ArgsSubcommands::Keys(args)) => RunOpts::keys(args.filter),
         Some(ArgsSubcommands::Config(_)) => RunOpts::config(),
         None => {
             let mut run_opts: RunOpts = RunOpts::default();
@@ -85,6 +86,21 @@ fn parse_args(args: Args) -> Result<RunOpts, String> {
                 run_opts.task = Task::Version;
                 return Ok(run_opts);
             }
+            // Dump keybindings
+            if let Some(format) = args.dump_keybindings.as_deref() {
+                if !format.eq_ignore_ascii_case("json") {
+                    return Err(format!(
+                        "Unsupported keybindings format \"{format}\"; only json is supported"
+                    ));
+                }
+                run_opts.task = Task::DumpKeybindings;
+                return Ok(run_opts);
+            }
+            // Reset keybindings
+            if args.reset_keybindings {
+                run_opts.task = Task::ResetKeybindings;
+                return Ok(run_opts);
+            }
             // Logging
             if args.debug {
                 run_opts.log_level = LogLevel::Trace;
@@ -97,6 +113,13 @@ fn parse_args(args: Args) -> Result<RunOpts, String> {
             }
             // Match ticks
             run_opts.ticks = Duration::from_millis(args.ticks);
+            // Batch transfer
+            if BatchTransfer::is_requested(&args) {
+                run_opts.task = Task::Batch(Box::new(BatchTransfer::try_from(&args)?));
+                return Ok(run_opts);
+            } else if args.recursive {
+                return Err("--recursive requires --download or --upload".to_string());
+            }
             // Remote argument
             match RemoteArgs::try_from(&args) {
                 Err(err) => return Err(err),
@@ -133,9 +156,13 @@ fn run(run_opts: RunOpts) -> MainResult<()> {
         Task::ImportSshHosts(ssh_config) => run_import_ssh_hosts(ssh_config, run_opts.keyring),
         Task::ImportTheme(theme) => run_import_theme(&theme),
         Task::InstallUpdate => run_install_update(),
+        Task::DumpKeybindings => run_dump_keybindings(),
+        Task::Keys(filter) => run_print_keybindings(filter.as_deref()),
+        Task::ResetKeybindings => run_reset_keybindings(),
         Task::Activity(activity) => {
             run_activity(activity, run_opts.ticks, run_opts.remote, run_opts.keyring)
         }
+        Task::Batch(transfer) => run_batch_transfer(*transfer),
         Task::Version => print_version(),
     }
 }
@@ -186,6 +213,40 @@ fn run_install_update() -> MainResult<()> {
     }
 }
 
+fn run_print_keybindings(filter: Option<&str>) -> MainResult<()> {
+    support::print_keybindings(filter).map_err(|err| {
+        eprintln!("{err}");
+        err.into()
+    })
+}
+
+fn run_dump_keybindings() -> MainResult<()> {
+    support::dump_keybindings().map_err(|err| {
+        eprintln!("{err}");
+        err.into()
+    })
+}
+
+fn run_reset_keybindings() -> MainResult<()> {
+    match support::reset_keybindings() {
+        Ok(_) => {
+            println!("Keybindings have been reset to the default ones!");
+            Ok(())
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            Err(err.into())
+        }
+    }
+}
+
+fn run_batch_transfer(transfer: BatchTransfer) -> MainResult<()> {
+    support::batch_transfer(transfer).map_err(|err| {
+        eprintln!("{err}");
+        err.into()
+    })
+}
+
 fn run_activity(
     activity: NextActivity,
     ticks: Duration,
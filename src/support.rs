@@ -2,12 +2,16 @@
 //!
 //! this module exposes some extra run modes for termscp, meant to be used for "support", such as installing themes
 
+mod batch_transfer;
 mod import_ssh_hosts;
+mod keys;
 
 use std::fs;
 use std::path::{Path, PathBuf};
 
+pub use self::batch_transfer::batch_transfer;
 pub use self::import_ssh_hosts::import_ssh_hosts;
+pub use self::keys::{dump_keybindings, print_keybindings, reset_keybindings};
 use crate::system::auto_update::{Update, UpdateStatus};
 use crate::system::bookmarks_client::BookmarksClient;
 use crate::system::config_client::ConfigClient;
@@ -0,0 +1,478 @@
+//! ## Batch transfer
+//!
+//! transfers files from or to a remote from the CLI, without the terminal UI
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use bytesize::ByteSize;
+use remotefs::fs::{Metadata, UnixPex};
+use remotefs::{File, RemoteErrorType, RemoteFs};
+
+use crate::cli::{BatchTransfer, TransferDirection};
+use crate::filetransfer::RemoteFsBuilder;
+use crate::system::config_client::ConfigClient;
+
+/// Connect to the remote of `transfer`, run it reporting the progress to stdout, then disconnect
+pub fn batch_transfer(transfer: BatchTransfer) -> Result<(), String> {
+    let config_client = super::get_config_client().unwrap_or_else(ConfigClient::degraded);
+    let host_name = transfer.remote.params.host_name();
+    let mut client = RemoteFsBuilder::build(
+        transfer.remote.protocol,
+        transfer.remote.params.clone(),
+        &config_client,
+    )?;
+    println!("Connecting to {host_name}...");
+    RemoteFsBuilder::probe(transfer.remote.protocol, &transfer.remote.params)
+        .and_then(|_| client.connect())
+        .map_err(|e| format!("Could not connect to {host_name}: {e}"))?;
+    let result = Transferer::new(client.as_mut(), io::stdout()).run(&transfer);
+    if let Err(err) = client.disconnect() {
+        warn!("Could not disconnect from {host_name}: {err}");
+    }
+    result
+}
+
+/// Copies files between the local host and a remote, writing a line to `progress` for each transferred file
+struct Transferer<'a, W: Write> {
+    client: &'a mut dyn RemoteFs,
+    progress: W,
+    files: usize,
+    bytes: u64,
+}
+
+impl<'a, W: Write> Transferer<'a, W> {
+    fn new(client: &'a mut dyn RemoteFs, progress: W) -> Self {
+        Self {
+            client,
+            progress,
+            files: 0,
+            bytes: 0,
+        }
+    }
+
+    /// Run `transfer`; like `cp`, the source is copied into the destination if it is an existing directory
+    fn run(&mut self, transfer: &BatchTransfer) -> Result<(), String> {
+        let started_at = Instant::now();
+        match transfer.direction {
+            TransferDirection::Download => {
+                let source = self.client.stat(&transfer.remote_path).map_err(|e| {
+                    format!("Could not stat {}: {e}", transfer.remote_path.display())
+                })?;
+                let destination = if transfer.local_path.is_dir() {
+                    transfer.local_path.join(source.name())
+                } else {
+                    transfer.local_path.clone()
+                };
+                self.download(&source, &destination, transfer.recursive)?;
+            }
+            TransferDirection::Upload => {
+                let destination = match self.client.stat(&transfer.remote_path) {
+                    Ok(file) if file.is_dir() => match transfer.local_path.file_name() {
+                        Some(name) => file.path.join(name),
+                        None => file.path,
+                    },
+                    _ => transfer.remote_path.clone(),
+                };
+                self.upload(&transfer.local_path, &destination, transfer.recursive)?;
+            }
+        }
+        writeln!(
+            self.progress,
+            "Transferred {} file(s) ({}) in {:.2}s",
+            self.files,
+            ByteSize(self.bytes),
+            started_at.elapsed().as_secs_f64()
+        )
+        .map_err(|e| format!("Could not report progress: {e}"))
+    }
+
+    /// Download the remote `source` to the local `destination`
+    fn download(
+        &mut self,
+        source: &File,
+        destination: &Path,
+        recursive: bool,
+    ) -> Result<(), String> {
+        if !source.is_dir() {
+            return self.download_file(source, destination);
+        }
+        if !recursive {
+            return Err(format!(
+                "{} is a directory; use --recursive to download it",
+                source.path.display()
+            ));
+        }
+        fs::create_dir_all(destination)
+            .map_err(|e| format!("Could not create directory {}: {e}", destination.display()))?;
+        let entries = self
+            .client
+            .list_dir(&source.path)
+            .map_err(|e| format!("Could not list directory {}: {e}", source.path.display()))?;
+        for entry in entries {
+            self.download(&entry, &destination.join(entry.name()), recursive)?;
+        }
+        Ok(())
+    }
+
+    fn download_file(&mut self, source: &File, destination: &Path) -> Result<(), String> {
+        let mut writer = fs::File::create(destination)
+            .map_err(|e| format!("Could not create file {}: {e}", destination.display()))?;
+        let size = match self.client.open(&source.path) {
+            Ok(mut reader) => {
+                let size = io::copy(&mut reader, &mut writer)
+                    .map_err(|e| format!("Could not download {}: {e}", source.path.display()))?;
+                self.client
+                    .on_read(reader)
+                    .map_err(|e| format!("Could not download {}: {e}", source.path.display()))?;
+                size
+            }
+            Err(err) if err.kind == RemoteErrorType::UnsupportedFeature => self
+                .client
+                .open_file(&source.path, Box::new(writer))
+                .map_err(|e| format!("Could not download {}: {e}", source.path.display()))?,
+            Err(err) => {
+                return Err(format!("Could not open {}: {err}", source.path.display()));
+            }
+        };
+        self.report(&source.path, destination, size)
+    }
+
+    /// Upload the local `source` to the remote `destination`
+    fn upload(&mut self, source: &Path, destination: &Path, recursive: bool) -> Result<(), String> {
+        let metadata = fs::metadata(source)
+            .map_err(|e| format!("Could not stat {}: {e}", source.display()))?;
+        if !metadata.is_dir() {
+            return self.upload_file(source, destination, Metadata::from(metadata));
+        }
+        if !recursive {
+            return Err(format!(
+                "{} is a directory; use --recursive to upload it",
+                source.display()
+            ));
+        }
+        match self.client.create_dir(destination, UnixPex::from(0o755)) {
+            Ok(()) => {}
+            Err(err) if err.kind == RemoteErrorType::DirectoryAlreadyExists => {}
+            Err(err) => {
+                return Err(format!(
+                    "Could not create directory {}: {err}",
+                    destination.display()
+                ));
+            }
+        }
+        let mut entries: Vec<PathBuf> = fs::read_dir(source)
+            .and_then(|entries| entries.map(|x| x.map(|x| x.path())).collect())
+            .map_err(|e| format!("Could not list directory {}: {e}", source.display()))?;
+        entries.sort();
+        for entry in entries {
+            let name = entry.file_name().unwrap_or_default().to_os_string();
+            self.upload(&entry, &destination.join(name), recursive)?;
+        }
+        Ok(())
+    }
+
+    fn upload_file(
+        &mut self,
+        source: &Path,
+        destination: &Path,
+        metadata: Metadata,
+    ) -> Result<(), String> {
+        let mut reader = fs::File::open(source)
+            .map_err(|e| format!("Could not open file {}: {e}", source.display()))?;
+        let size = match self.client.create(destination, &metadata) {
+            Ok(mut writer) => {
+                let size = io::copy(&mut reader, &mut writer)
+                    .map_err(|e| format!("Could not upload {}: {e}", source.display()))?;
+                self.client
+                    .on_written(writer)
+                    .map_err(|e| format!("Could not upload {}: {e}", source.display()))?;
+                size
+            }
+            Err(err) if err.kind == RemoteErrorType::UnsupportedFeature => self
+                .client
+                .create_file(destination, &metadata, Box::new(reader))
+                .map_err(|e| format!("Could not upload {}: {e}", source.display()))?,
+            Err(err) => {
+                return Err(format!(
+                    "Could not create file {}: {err}",
+                    destination.display()
+                ));
+            }
+        };
+        self.report(source, destination, size)
+    }
+
+    fn report(&mut self, source: &Path, destination: &Path, size: u64) -> Result<(), String> {
+        self.files += 1;
+        self.bytes += size;
+        writeln!(
+            self.progress,
+            "{} -> {} ({})",
+            source.display(),
+            destination.display(),
+            ByteSize(size)
+        )
+        .map_err(|e| format!("Could not report progress: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use pretty_assertions::assert_eq;
+    use remotefs::fs::{ReadStream, Welcome, WriteStream};
+    use remotefs::{RemoteError, RemoteResult};
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::filetransfer::params::GenericProtocolParams;
+    use crate::filetransfer::{FileTransferParams, FileTransferProtocol, ProtocolParams};
+
+    /// Remote file system mocked with a local directory, which is the root of the remote
+    struct LocalRemoteFs {
+        root: PathBuf,
+    }
+
+    impl LocalRemoteFs {
+        fn local_path(&self, path: &Path) -> PathBuf {
+            self.root.join(path.strip_prefix("/").unwrap_or(path))
+        }
+
+        fn error(err: io::Error) -> RemoteError {
+            let kind = match err.kind() {
+                io::ErrorKind::NotFound => RemoteErrorType::NoSuchFileOrDirectory,
+                io::ErrorKind::AlreadyExists => RemoteErrorType::DirectoryAlreadyExists,
+                _ => RemoteErrorType::IoError,
+            };
+            RemoteError::new_ex(kind, err)
+        }
+
+        fn unsupported<T>() -> RemoteResult<T> {
+            Err(RemoteError::new(RemoteErrorType::UnsupportedFeature))
+        }
+    }
+
+    impl RemoteFs for LocalRemoteFs {
+        fn connect(&mut self) -> RemoteResult<Welcome> {
+            Ok(Welcome::default())
+        }
+
+        fn disconnect(&mut self) -> RemoteResult<()> {
+            Ok(())
+        }
+
+        fn is_connected(&mut self) -> bool {
+            true
+        }
+
+        fn pwd(&mut self) -> RemoteResult<PathBuf> {
+            Ok(PathBuf::from("/"))
+        }
+
+        fn change_dir(&mut self, dir: &Path) -> RemoteResult<PathBuf> {
+            Ok(dir.to_path_buf())
+        }
+
+        fn list_dir(&mut self, path: &Path) -> RemoteResult<Vec<File>> {
+            let entries = fs::read_dir(self.local_path(path)).map_err(Self::error)?;
+            let mut files = vec![];
+            for entry in entries {
+                let entry = entry.map_err(Self::error)?;
+                files.push(self.stat(&path.join(entry.file_name()))?);
+            }
+            Ok(files)
+        }
+
+        fn stat(&mut self, path: &Path) -> RemoteResult<File> {
+            let metadata = fs::metadata(self.local_path(path)).map_err(Self::error)?;
+            Ok(File {
+                path: path.to_path_buf(),
+                metadata: Metadata::from(metadata),
+            })
+        }
+
+        fn setstat(&mut self, _path: &Path, _metadata: Metadata) -> RemoteResult<()> {
+            Self::unsupported()
+        }
+
+        fn exists(&mut self, path: &Path) -> RemoteResult<bool> {
+            Ok(self.local_path(path).exists())
+        }
+
+        fn remove_file(&mut self, path: &Path) -> RemoteResult<()> {
+            fs::remove_file(self.local_path(path)).map_err(Self::error)
+        }
+
+        fn remove_dir(&mut self, path: &Path) -> RemoteResult<()> {
+            fs::remove_dir(self.local_path(path)).map_err(Self::error)
+        }
+
+        fn create_dir(&mut self, path: &Path, _mode: UnixPex) -> RemoteResult<()> {
+            fs::create_dir(self.local_path(path)).map_err(Self::error)
+        }
+
+        fn symlink(&mut self, _path: &Path, _target: &Path) -> RemoteResult<()> {
+            Self::unsupported()
+        }
+
+        fn copy(&mut self, _src: &Path, _dest: &Path) -> RemoteResult<()> {
+            Self::unsupported()
+        }
+
+        fn mov(&mut self, _src: &Path, _dest: &Path) -> RemoteResult<()> {
+            Self::unsupported()
+        }
+
+        fn exec(&mut self, _cmd: &str) -> RemoteResult<(u32, String)> {
+            Self::unsupported()
+        }
+
+        fn append(&mut self, _path: &Path, _metadata: &Metadata) -> RemoteResult<WriteStream> {
+            Self::unsupported()
+        }
+
+        fn create(&mut self, path: &Path, _metadata: &Metadata) -> RemoteResult<WriteStream> {
+            let file = fs::File::create(self.local_path(path)).map_err(Self::error)?;
+            Ok(WriteStream::from(Box::new(file) as Box<dyn Write + Send>))
+        }
+
+        fn open(&mut self, path: &Path) -> RemoteResult<ReadStream> {
+            let file = fs::File::open(self.local_path(path)).map_err(Self::error)?;
+            Ok(ReadStream::from(Box::new(file) as Box<dyn io::Read + Send>))
+        }
+    }
+
+    #[test]
+    fn should_download_file() {
+        let (remote_dir, local_dir) = (TempDir::new().unwrap(), TempDir::new().unwrap());
+        fs::write(remote_dir.path().join("file.txt"), "hello").unwrap();
+        let transfer = sample_transfer(TransferDirection::Download, "/file.txt", local_dir.path());
+
+        let progress = run_transfer(&remote_dir, &transfer).unwrap();
+        assert_eq!(
+            fs::read_to_string(local_dir.path().join("file.txt")).unwrap(),
+            "hello"
+        );
+        assert!(progress.starts_with("/file.txt -> "));
+        assert!(progress.contains("Transferred 1 file(s)"));
+    }
+
+    #[test]
+    fn should_download_file_to_new_name() {
+        let (remote_dir, local_dir) = (TempDir::new().unwrap(), TempDir::new().unwrap());
+        fs::write(remote_dir.path().join("file.txt"), "hello").unwrap();
+        let destination = local_dir.path().join("renamed.txt");
+        let transfer = sample_transfer(TransferDirection::Download, "/file.txt", &destination);
+
+        assert!(run_transfer(&remote_dir, &transfer).is_ok());
+        assert_eq!(fs::read_to_string(destination).unwrap(), "hello");
+    }
+
+    #[test]
+    fn should_download_directory_recursively() {
+        let (remote_dir, local_dir) = (TempDir::new().unwrap(), TempDir::new().unwrap());
+        fs::create_dir_all(remote_dir.path().join("logs/old")).unwrap();
+        fs::write(remote_dir.path().join("logs/today.log"), "today").unwrap();
+        fs::write(
+            remote_dir.path().join("logs/old/yesterday.log"),
+            "yesterday",
+        )
+        .unwrap();
+        let mut transfer = sample_transfer(TransferDirection::Download, "/logs", local_dir.path());
+        // directories are transferred only with --recursive
+        assert!(run_transfer(&remote_dir, &transfer).is_err());
+        transfer.recursive = true;
+
+        let progress = run_transfer(&remote_dir, &transfer).unwrap();
+        assert_eq!(
+            fs::read_to_string(local_dir.path().join("logs/today.log")).unwrap(),
+            "today"
+        );
+        assert_eq!(
+            fs::read_to_string(local_dir.path().join("logs/old/yesterday.log")).unwrap(),
+            "yesterday"
+        );
+        assert!(progress.contains("Transferred 2 file(s)"));
+    }
+
+    #[test]
+    fn should_upload_file() {
+        let (remote_dir, local_dir) = (TempDir::new().unwrap(), TempDir::new().unwrap());
+        fs::create_dir(remote_dir.path().join("upload")).unwrap();
+        let source = local_dir.path().join("file.txt");
+        fs::write(&source, "hello").unwrap();
+        let transfer = sample_transfer(TransferDirection::Upload, "/upload", &source);
+
+        let progress = run_transfer(&remote_dir, &transfer).unwrap();
+        assert_eq!(
+            fs::read_to_string(remote_dir.path().join("upload/file.txt")).unwrap(),
+            "hello"
+        );
+        assert!(progress.contains(" -> /upload/file.txt (5 B)"));
+    }
+
+    #[test]
+    fn should_upload_directory_recursively() {
+        let (remote_dir, local_dir) = (TempDir::new().unwrap(), TempDir::new().unwrap());
+        let source = local_dir.path().join("logs");
+        fs::create_dir_all(source.join("old")).unwrap();
+        fs::write(source.join("today.log"), "today").unwrap();
+        fs::write(source.join("old/yesterday.log"), "yesterday").unwrap();
+        let mut transfer = sample_transfer(TransferDirection::Upload, "/", &source);
+        transfer.recursive = true;
+
+        let progress = run_transfer(&remote_dir, &transfer).unwrap();
+        assert_eq!(
+            fs::read_to_string(remote_dir.path().join("logs/today.log")).unwrap(),
+            "today"
+        );
+        assert_eq!(
+            fs::read_to_string(remote_dir.path().join("logs/old/yesterday.log")).unwrap(),
+            "yesterday"
+        );
+        assert!(progress.contains("Transferred 2 file(s)"));
+    }
+
+    #[test]
+    fn should_fail_transferring_missing_file() {
+        let (remote_dir, local_dir) = (TempDir::new().unwrap(), TempDir::new().unwrap());
+        let transfer = sample_transfer(TransferDirection::Download, "/missing", local_dir.path());
+        assert!(run_transfer(&remote_dir, &transfer).is_err());
+        let transfer = sample_transfer(
+            TransferDirection::Upload,
+            "/",
+            &local_dir.path().join("missing"),
+        );
+        assert!(run_transfer(&remote_dir, &transfer).is_err());
+    }
+
+    fn sample_transfer(
+        direction: TransferDirection,
+        remote_path: &str,
+        local_path: &Path,
+    ) -> BatchTransfer {
+        BatchTransfer {
+            direction,
+            remote: FileTransferParams::new(
+                FileTransferProtocol::Sftp,
+                ProtocolParams::Generic(GenericProtocolParams::default()),
+            ),
+            remote_path: PathBuf::from(remote_path),
+            local_path: local_path.to_path_buf(),
+            recursive: false,
+        }
+    }
+
+    /// Run `transfer` against the remote mocked with `remote_dir`, returning the reported progress
+    fn run_transfer(remote_dir: &TempDir, transfer: &BatchTransfer) -> Result<String, String> {
+        let mut client = LocalRemoteFs {
+            root: remote_dir.path().to_path_buf(),
+        };
+        let mut progress = vec![];
+        Transferer::new(&mut client, &mut progress).run(transfer)?;
+        Ok(String::from_utf8(progress).unwrap())
+    }
+}
@@ -0,0 +1,143 @@
+use std::str::FromStr;
+
+use crate::config::keybindings::{KeyBinding, KeyBindings};
+use crate::system::environment;
+use crate::system::keybindings_provider::KeyBindingsProvider;
+
+/// Print the active keybindings, optionally filtered by action name or key
+pub fn print_keybindings(filter: Option<&str>) -> Result<(), String> {
+    let provider = keybindings_provider()?;
+    let rows = keybindings_reference(provider.keybindings(), filter);
+    if rows.is_empty() {
+        println!("No keybinding matches \"{}\"", filter.unwrap_or_default());
+    }
+    for row in rows {
+        println!("{row}");
+    }
+    Ok(())
+}
+
+/// Print the active keybindings as JSON
+pub fn dump_keybindings() -> Result<(), String> {
+    let provider = keybindings_provider()?;
+    let json = provider
+        .keybindings()
+        .to_json()
+        .map_err(|e| format!("Could not serialize keybindings: {e}"))?;
+    println!("{json}");
+    Ok(())
+}
+
+/// Replace the keybindings file with the default keybindings.
+///
+/// A file which can't be loaded is replaced as well
+pub fn reset_keybindings() -> Result<(), String> {
+    let mut provider = match keybindings_provider() {
+        Ok(provider) => provider,
+        Err(err) => {
+            warn!("Keybindings couldn't be loaded, so they are replaced: {err}");
+            let config_dir = super::get_config_dir()?;
+            let keybindings_path = environment::get_keybindings_path(config_dir.as_path());
+            std::fs::remove_file(keybindings_path.as_path()).map_err(|e| {
+                format!(
+                    "Could not remove keybindings file \"{}\": {e}",
+                    keybindings_path.display()
+                )
+            })?;
+            keybindings_provider()?
+        }
+    };
+    provider
+        .reset_to_defaults()
+        .map_err(|e| format!("Could not reset keybindings: {e}"))
+}
+
+/// Get a row for each keybinding, made of the action name and of its key.
+///
+/// If `filter` is set, only the actions whose name contains it, or which are bound to it, are returned
+fn keybindings_reference(keybindings: &KeyBindings, filter: Option<&str>) -> Vec<String> {
+    let actions = keybindings.actions();
+    let filter = filter.map(|x| (x.to_lowercase(), KeyBinding::from_str(x).ok()));
+    let rows: Vec<(String, &KeyBinding)> = actions
+        .into_iter()
+        .filter(|(action, binding)| match &filter {
+            None => true,
+            Some((name, key)) => action.contains(name.as_str()) || key.as_ref() == Some(*binding),
+        })
+        .collect();
+    let width = rows
+        .iter()
+        .map(|(action, _)| action.len())
+        .max()
+        .unwrap_or(0);
+    rows.into_iter()
+        .map(|(action, binding)| format!("{action:<width$}  {binding}"))
+        .collect()
+}
+
+/// Load the keybindings from the configuration directory
+fn keybindings_provider() -> Result<KeyBindingsProvider, String> {
+    let config_dir = super::get_config_dir()?;
+    let keybindings_path = environment::get_keybindings_path(config_dir.as_path());
+    KeyBindingsProvider::new(keybindings_path.as_path()).map_err(|e| {
+        format!(
+            "Could not load keybindings from \"{}\": {e}",
+            keybindings_path.display()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_list_all_keybindings() {
+        let keybindings = KeyBindings::default();
+        let rows = keybindings_reference(&keybindings, None);
+        assert_eq!(rows.len(), keybindings.actions().len());
+        assert!(rows.iter().any(|x| row_is(x, "explorer.mkdir", "d")));
+        assert!(
+            rows.iter()
+                .any(|x| row_is(x, "explorer.save_bookmark", "alt+b"))
+        );
+        assert!(rows.iter().any(|x| row_is(x, "global.quit", "q")));
+        assert!(rows.iter().any(|x| row_is(x, "setup.save", "ctrl+s")));
+    }
+
+    #[test]
+    fn should_filter_keybindings_by_action() {
+        let rows = keybindings_reference(&KeyBindings::default(), Some("bookmark"));
+        assert_eq!(
+            rows,
+            vec![
+                String::from("explorer.save_bookmark      alt+b"),
+                String::from("explorer.save_dir_bookmark  alt+t"),
+                String::from("explorer.dir_bookmarks      ctrl+o"),
+                String::from("auth.save_bookmark          ctrl+s"),
+            ]
+        );
+        assert!(keybindings_reference(&KeyBindings::default(), Some("omar")).is_empty());
+    }
+
+    #[test]
+    fn should_filter_keybindings_by_key() {
+        let rows = keybindings_reference(&KeyBindings::default(), Some("CTRL+S"));
+        assert_eq!(
+            rows,
+            vec![
+                String::from("explorer.file_size  ctrl+s"),
+                String::from("auth.save_bookmark  ctrl+s"),
+                String::from("setup.save          ctrl+s"),
+            ]
+        );
+    }
+
+    fn row_is(row: &str, action: &str, key: &str) -> bool {
+        let mut parts = row.split_whitespace();
+        parts.next() == Some(action) && parts.next() == Some(key) && parts.next().is_none()
+    }
+}
@@ -14,7 +14,7 @@ use super::keys::keyringstorage::KeyringStorage;
 use super::keys::{KeyStorage, KeyStorageError};
 // Local
 use crate::config::{
-    bookmarks::{Bookmark, UserHosts},
+    bookmarks::{Bookmark, DirectoryBookmark, ExplorerPrefs, UserHosts},
     serialization::{SerializerError, SerializerErrorKind, deserialize, serialize},
 };
 use crate::filetransfer::FileTransferParams;
@@ -139,51 +139,8 @@ impl BookmarksClient {
     /// Get bookmark associated to key
     pub fn get_bookmark(&self, key: &str) -> Option<FileTransferParams> {
         debug!("Getting bookmark {}", key);
-        let mut entry: Bookmark = self.hosts.bookmarks.get(key).cloned()?;
-        // Decrypt password first
-        if let Some(pwd) = entry.password.as_mut() {
-            match self.decrypt_str(pwd.as_str()) {
-                Ok(decrypted_pwd) => {
-                    *pwd = decrypted_pwd;
-                }
-                Err(err) => {
-                    error!("Failed to decrypt `password` for bookmark {}: {}", key, err);
-                }
-            }
-        }
-        // Decrypt AWS-S3 params
-        if let Some(s3) = entry.s3.as_mut() {
-            // Access key
-            if let Some(access_key) = s3.access_key.as_mut() {
-                match self.decrypt_str(access_key.as_str()) {
-                    Ok(plain) => {
-                        *access_key = plain;
-                    }
-                    Err(err) => {
-                        error!(
-                            "Failed to decrypt `access_key` for bookmark {}: {}",
-                            key, err
-                        );
-                    }
-                }
-            }
-            // Secret access key
-            if let Some(secret_access_key) = s3.secret_access_key.as_mut() {
-                match self.decrypt_str(secret_access_key.as_str()) {
-                    Ok(plain) => {
-                        *secret_access_key = plain;
-                    }
-                    Err(err) => {
-                        error!(
-                            "Failed to decrypt `secret_access_key` for bookmark {}: {}",
-                            key, err
-                        );
-                    }
-                }
-            }
-        }
-        // Then convert into
-        Some(FileTransferParams::from(entry))
+        let entry: Bookmark = self.hosts.bookmarks.get(key).cloned()?;
+        Some(FileTransferParams::from(self.decrypt_bookmark(key, entry)))
     }
 
     /// Add a new recent to bookmarks
@@ -203,20 +160,139 @@ impl BookmarksClient {
         let mut host: Bookmark = self.make_bookmark(params);
         // If not save_password, set secrets to `None`
         if !save_password {
-            host.password = None;
-            if let Some(s3) = host.s3.as_mut() {
-                s3.access_key = None;
-                s3.secret_access_key = None;
-            }
+            Self::forget_secrets(&mut host);
         }
+        // Keep the group of the bookmark being replaced
+        host.group = self
+            .hosts
+            .bookmarks
+            .get(&name)
+            .and_then(|entry| entry.group.clone());
         self.hosts.bookmarks.insert(name, host);
     }
 
+    /// Get the group of bookmark `key`, if any
+    pub fn get_bookmark_group(&self, key: &str) -> Option<&str> {
+        self.hosts.bookmarks.get(key)?.group.as_deref()
+    }
+
+    /// Get the remote and local directories which were open when the last session of bookmark `key` was closed
+    pub fn get_bookmark_last_dirs(&self, key: &str) -> (Option<PathBuf>, Option<PathBuf>) {
+        match self.hosts.bookmarks.get(key) {
+            Some(entry) => (
+                entry.last_remote_path.clone(),
+                entry.last_local_path.clone(),
+            ),
+            None => (None, None),
+        }
+    }
+
+    /// Set the remote and local directories which were open when the session of bookmark `key` was closed.
+    /// Returns whether the bookmark exists
+    pub fn set_bookmark_last_dirs(
+        &mut self,
+        key: &str,
+        remote_path: Option<&Path>,
+        local_path: Option<&Path>,
+    ) -> bool {
+        match self.hosts.bookmarks.get_mut(key) {
+            Some(entry) => {
+                debug!("Saving last directories of bookmark {}", key);
+                entry.last_remote_path = remote_path.map(Path::to_path_buf);
+                entry.last_local_path = local_path.map(Path::to_path_buf);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get the sorting and the filter of the remote and local explorers when the last session of bookmark `key` was closed
+    pub fn get_bookmark_last_explorers(
+        &self,
+        key: &str,
+    ) -> (Option<ExplorerPrefs>, Option<ExplorerPrefs>) {
+        match self.hosts.bookmarks.get(key) {
+            Some(entry) => (
+                entry.last_remote_explorer.clone(),
+                entry.last_local_explorer.clone(),
+            ),
+            None => (None, None),
+        }
+    }
+
+    /// Set the sorting and the filter of the remote and local explorers when the session of bookmark `key` was closed.
+    /// Returns whether the bookmark exists
+    pub fn set_bookmark_last_explorers(
+        &mut self,
+        key: &str,
+        remote: ExplorerPrefs,
+        local: ExplorerPrefs,
+    ) -> bool {
+        match self.hosts.bookmarks.get_mut(key) {
+            Some(entry) => {
+                debug!("Saving last explorers of bookmark {}", key);
+                entry.last_remote_explorer = Some(remote);
+                entry.last_local_explorer = Some(local);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Delete entry from bookmarks
     pub fn del_bookmark(&mut self, name: &str) {
         let _ = self.hosts.bookmarks.remove(name);
         info!("Removed bookmark {}", name);
     }
+    /// Iterate over directory bookmarks keys
+    pub fn iter_directory_bookmarks(&self) -> impl Iterator<Item = &String> + '_ {
+        Box::new(self.hosts.directories.keys())
+    }
+
+    /// Get the params to connect to the directory bookmark associated to key, with the directory as remote path
+    pub fn get_directory_bookmark(&self, key: &str) -> Option<FileTransferParams> {
+        debug!("Getting directory bookmark {}", key);
+        let entry: DirectoryBookmark = self.hosts.directories.get(key).cloned()?;
+        let connection = self.decrypt_bookmark(key, entry.connection);
+        Some(FileTransferParams::from(connection).remote_path(Some(entry.directory)))
+    }
+
+    /// Add a new directory bookmark, to reach `directory` on the remote of `params`
+    pub fn add_directory_bookmark<S: AsRef<str>>(
+        &mut self,
+        name: S,
+        params: FileTransferParams,
+        directory: &Path,
+        save_password: bool,
+    ) {
+        let name: String = name.as_ref().to_string();
+        if name.is_empty() {
+            error!("Fatal error; directory bookmark name is empty");
+            panic!("Bookmark name can't be empty");
+        }
+        info!("Added directory bookmark {}", name);
+        let mut connection: Bookmark = self.make_bookmark(params);
+        // The directory is stored in the directory bookmark
+        connection.remote_path = None;
+        if !save_password {
+            Self::forget_secrets(&mut connection);
+        }
+        let directory = directory.to_path_buf();
+        self.hosts.directories.insert(
+            name,
+            DirectoryBookmark {
+                directory,
+                connection,
+            },
+        );
+    }
+
+    /// Delete entry from directory bookmarks
+    pub fn del_directory_bookmark(&mut self, name: &str) {
+        let _ = self.hosts.directories.remove(name);
+        info!("Removed directory bookmark {}", name);
+    }
+
     /// Iterate over recents keys
     pub fn iter_recents(&self) -> impl Iterator<Item = &String> + '_ {
         Box::new(self.hosts.recents.keys())
@@ -352,6 +428,62 @@ impl BookmarksClient {
         bookmark
     }
 
+    /// Remove the secrets from the bookmark
+    fn forget_secrets(bookmark: &mut Bookmark) {
+        bookmark.password = None;
+        if let Some(s3) = bookmark.s3.as_mut() {
+            s3.access_key = None;
+            s3.secret_access_key = None;
+        }
+    }
+
+    /// Decrypt the secrets of the bookmark `key`
+    fn decrypt_bookmark(&self, key: &str, mut entry: Bookmark) -> Bookmark {
+        // Decrypt password first
+        if let Some(pwd) = entry.password.as_mut() {
+            match self.decrypt_str(pwd.as_str()) {
+                Ok(decrypted_pwd) => {
+                    *pwd = decrypted_pwd;
+                }
+                Err(err) => {
+                    error!("Failed to decrypt `password` for bookmark {}: {}", key, err);
+                }
+            }
+        }
+        // Decrypt AWS-S3 params
+        if let Some(s3) = entry.s3.as_mut() {
+            // Access key
+            if let Some(access_key) = s3.access_key.as_mut() {
+                match self.decrypt_str(access_key.as_str()) {
+                    Ok(plain) => {
+                        *access_key = plain;
+                    }
+                    Err(err) => {
+                        error!(
+                            "Failed to decrypt `access_key` for bookmark {}: {}",
+                            key, err
+                        );
+                    }
+                }
+            }
+            // Secret access key
+            if let Some(secret_access_key) = s3.secret_access_key.as_mut() {
+                match self.decrypt_str(secret_access_key.as_str()) {
+                    Ok(plain) => {
+                        *secret_access_key = plain;
+                    }
+                    Err(err) => {
+                        error!(
+                            "Failed to decrypt `secret_access_key` for bookmark {}: {}",
+                            key, err
+                        );
+                    }
+                }
+            }
+        }
+        entry
+    }
+
     /// Encrypt provided string using AES-128. Encrypted buffer is then converted to BASE64
     fn encrypt_str(&self, txt: &str) -> String {
         crypto::aes128_b64_crypt(self.key.as_str(), txt)
@@ -611,6 +743,171 @@ mod tests {
         assert_eq!(bookmark.4, None);
     }
 
+    #[test]
+    fn should_save_and_restore_bookmark_last_dirs() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: BookmarksClient =
+            BookmarksClient::new(cfg_path.as_path(), key_path.as_path(), 16, false).unwrap();
+        client.add_bookmark(
+            "raspberry",
+            make_generic_ftparams(FileTransferProtocol::Sftp, "192.168.1.31", 22, "pi", None),
+            false,
+        );
+        assert_eq!(client.get_bookmark_last_dirs("raspberry"), (None, None));
+        assert!(client.set_bookmark_last_dirs(
+            "raspberry",
+            Some(Path::new("/home/pi/projects")),
+            Some(Path::new("/tmp")),
+        ));
+        assert!(client.write_bookmarks().is_ok());
+        // Reload bookmarks from file
+        let mut client: BookmarksClient =
+            BookmarksClient::new(cfg_path.as_path(), key_path.as_path(), 16, false).unwrap();
+        assert_eq!(
+            client.get_bookmark_last_dirs("raspberry"),
+            (
+                Some(PathBuf::from("/home/pi/projects")),
+                Some(PathBuf::from("/tmp"))
+            )
+        );
+        // The start directories of the bookmark are kept
+        assert_eq!(client.get_bookmark("raspberry").unwrap().remote_path, None);
+        // Without local directory
+        assert!(client.set_bookmark_last_dirs("raspberry", Some(Path::new("/home/pi")), None));
+        assert_eq!(
+            client.get_bookmark_last_dirs("raspberry"),
+            (Some(PathBuf::from("/home/pi")), None)
+        );
+    }
+
+    #[test]
+    fn should_save_and_restore_bookmark_last_explorers() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: BookmarksClient =
+            BookmarksClient::new(cfg_path.as_path(), key_path.as_path(), 16, false).unwrap();
+        client.add_bookmark(
+            "raspberry",
+            make_generic_ftparams(FileTransferProtocol::Sftp, "192.168.1.31", 22, "pi", None),
+            false,
+        );
+        assert_eq!(
+            client.get_bookmark_last_explorers("raspberry"),
+            (None, None)
+        );
+        let remote = ExplorerPrefs {
+            sorting: Some(String::from("by_size")),
+            filter: Some(String::from("!*.log")),
+        };
+        let local = ExplorerPrefs {
+            sorting: Some(String::from("by_name")),
+            filter: None,
+        };
+        assert!(client.set_bookmark_last_explorers("raspberry", remote.clone(), local.clone()));
+        assert!(client.write_bookmarks().is_ok());
+        // Reload bookmarks from file
+        let client: BookmarksClient =
+            BookmarksClient::new(cfg_path.as_path(), key_path.as_path(), 16, false).unwrap();
+        assert_eq!(
+            client.get_bookmark_last_explorers("raspberry"),
+            (Some(remote), Some(local))
+        );
+        assert_eq!(client.get_bookmark_last_explorers("omar"), (None, None));
+    }
+
+    #[test]
+    fn should_keep_bookmark_group_when_overwritten() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: BookmarksClient =
+            BookmarksClient::new(cfg_path.as_path(), key_path.as_path(), 16, false).unwrap();
+        let params =
+            make_generic_ftparams(FileTransferProtocol::Sftp, "192.168.1.31", 22, "pi", None);
+        client.add_bookmark("raspberry", params.clone(), false);
+        assert_eq!(client.get_bookmark_group("raspberry"), None);
+        client.hosts.bookmarks.get_mut("raspberry").unwrap().group = Some(String::from("home"));
+        client.add_bookmark("raspberry", params, false);
+        assert_eq!(client.get_bookmark_group("raspberry"), Some("home"));
+        assert_eq!(client.get_bookmark_group("omar"), None);
+    }
+
+    #[test]
+    fn should_manipulate_directory_bookmarks() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: BookmarksClient =
+            BookmarksClient::new(cfg_path.as_path(), key_path.as_path(), 16, false).unwrap();
+        client.add_directory_bookmark(
+            "www",
+            make_generic_ftparams(
+                FileTransferProtocol::Sftp,
+                "192.168.1.31",
+                22,
+                "pi",
+                Some("mypassword"),
+            )
+            .remote_path(Some("/home/pi")),
+            Path::new("/var/www/html"),
+            true,
+        );
+        assert_eq!(client.iter_directory_bookmarks().count(), 1);
+        // Secrets are encrypted
+        let entry = client.hosts.directories.get("www").unwrap();
+        assert_ne!(entry.connection.password.as_deref(), Some("mypassword"));
+        assert_eq!(entry.connection.remote_path, None);
+        assert!(client.write_bookmarks().is_ok());
+        // Reload bookmarks from file
+        let mut client: BookmarksClient =
+            BookmarksClient::new(cfg_path.as_path(), key_path.as_path(), 16, false).unwrap();
+        let params = client.get_directory_bookmark("www").unwrap();
+        assert_eq!(params.remote_path, Some(PathBuf::from("/var/www/html")));
+        let bookmark = ftparams_to_tup(params);
+        assert_eq!(bookmark.0, String::from("192.168.1.31"));
+        assert_eq!(bookmark.2, FileTransferProtocol::Sftp);
+        assert_eq!(bookmark.3, String::from("pi"));
+        assert_eq!(bookmark.4.as_deref(), Some("mypassword"));
+        // Directory bookmarks are not connection bookmarks
+        assert_eq!(client.iter_bookmarks().count(), 0);
+        client.del_directory_bookmark("www");
+        assert!(client.get_directory_bookmark("www").is_none());
+        assert_eq!(client.iter_directory_bookmarks().count(), 0);
+    }
+
+    #[test]
+    fn should_save_directory_bookmark_without_password() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: BookmarksClient =
+            BookmarksClient::new(cfg_path.as_path(), key_path.as_path(), 16, false).unwrap();
+        client.add_directory_bookmark(
+            "www",
+            make_generic_ftparams(
+                FileTransferProtocol::Sftp,
+                "192.168.1.31",
+                22,
+                "pi",
+                Some("mypassword"),
+            ),
+            Path::new("/var/www/html"),
+            false,
+        );
+        let bookmark = ftparams_to_tup(client.get_directory_bookmark("www").unwrap());
+        assert_eq!(bookmark.3, String::from("pi"));
+        assert_eq!(bookmark.4, None);
+    }
+
+    #[test]
+    fn should_not_save_last_dirs_of_unknown_bookmark() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: BookmarksClient =
+            BookmarksClient::new(cfg_path.as_path(), key_path.as_path(), 16, false).unwrap();
+        assert!(!client.set_bookmark_last_dirs("raspberry", Some(Path::new("/home")), None));
+        assert_eq!(client.get_bookmark_last_dirs("raspberry"), (None, None));
+        assert_eq!(client.iter_bookmarks().count(), 0);
+    }
+
     #[test]
 
     fn test_system_bookmarks_manipulate_recents() {
@@ -0,0 +1,60 @@
+//! ## Clipboard
+//!
+//! `clipboard` copies text to the system clipboard through the clipboard utility of the guest OS
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Clipboard utilities with their arguments, tried in order
+#[cfg(target_os = "macos")]
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[("pbcopy", &[])];
+#[cfg(target_family = "windows")]
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[("clip", &[])];
+#[cfg(all(unix, not(target_os = "macos")))]
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+];
+
+/// Copy `text` to the system clipboard.
+///
+/// Returns `false` if none of the clipboard utilities could copy the text
+pub fn copy(text: &str) -> bool {
+    CLIPBOARD_COMMANDS
+        .iter()
+        .any(|(program, args)| copy_with(program, args, text))
+}
+
+/// Pipe `text` into `program`
+fn copy_with(program: &str, args: &[&str], text: &str) -> bool {
+    let mut child = match Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(err) => {
+            debug!("could not run {program}: {err}");
+            return false;
+        }
+    };
+    // stdin must be dropped before waiting, so the utility gets EOF
+    let written = child
+        .stdin
+        .take()
+        .is_some_and(|mut stdin| stdin.write_all(text.as_bytes()).is_ok());
+    match child.wait() {
+        Ok(status) if status.success() => written,
+        Ok(status) => {
+            debug!("{program} exited with {status}");
+            false
+        }
+        Err(err) => {
+            debug!("could not wait for {program}: {err}");
+            false
+        }
+    }
+}
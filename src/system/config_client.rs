@@ -4,16 +4,22 @@
 
 // Locals
 // Ext
+use std::collections::HashMap;
 use std::fs::{File, OpenOptions, create_dir, remove_file};
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::string::ToString;
 
-use crate::config::params::{DEFAULT_NOTIFICATION_TRANSFER_THRESHOLD, UserConfig};
+use crate::config::params::{
+    DEFAULT_NOTIFICATION_TRANSFER_THRESHOLD, DEFAULT_PANEL_SPLIT_RATIO, UserConfig,
+};
 use crate::config::serialization::{SerializerError, SerializerErrorKind, deserialize, serialize};
-use crate::explorer::GroupDirs;
+use crate::explorer::{FileSubmitAction, FocusedPanel, GroupDirs};
 use crate::filetransfer::FileTransferProtocol;
+use crate::utils::fmt::{SizeUnits, is_valid_date_fmt};
+use crate::utils::locale::Locale;
+use crate::utils::ui::HighlightStyle;
 
 // Types
 pub type SshHost = (String, String, PathBuf); // 0: host, 1: username, 2: RSA key path
@@ -212,6 +218,260 @@ impl ConfigClient {
         self.config.user_interface.notification_threshold = Some(value);
     }
 
+    /// Get date format for file listings
+    pub fn get_date_fmt(&self) -> Option<String> {
+        self.config.user_interface.date_fmt.clone()
+    }
+
+    /// Set date format for file listings
+    pub fn set_date_fmt(&mut self, s: String) {
+        self.config.user_interface.date_fmt = match s.is_empty() {
+            true => None,
+            false => Some(s),
+        };
+    }
+
+    /// Get locale for number grouping and dates in listings
+    pub fn get_locale(&self) -> Option<String> {
+        self.config.user_interface.locale.clone()
+    }
+
+    /// Set locale for number grouping and dates in listings
+    pub fn set_locale(&mut self, s: String) {
+        self.config.user_interface.locale = match s.is_empty() {
+            true => None,
+            false => Some(s),
+        };
+    }
+
+    /// Get value of `panel_split_ratio`
+    pub fn get_panel_split_ratio(&self) -> u16 {
+        self.config
+            .user_interface
+            .panel_split_ratio
+            .unwrap_or(DEFAULT_PANEL_SPLIT_RATIO)
+    }
+
+    /// Set new value for `panel_split_ratio`
+    pub fn set_panel_split_ratio(&mut self, value: u16) {
+        self.config.user_interface.panel_split_ratio = Some(value);
+    }
+
+    /// Get seconds after which confirmation popups select their safe option.
+    /// Returns `None` if popups wait indefinitely
+    pub fn get_confirmation_timeout(&self) -> Option<u64> {
+        self.config
+            .user_interface
+            .confirmation_timeout
+            .filter(|secs| *secs > 0)
+    }
+
+    /// Set new value for `confirmation_timeout`; `None` or `0` disable the timeout
+    pub fn set_confirmation_timeout(&mut self, value: Option<u64>) {
+        self.config.user_interface.confirmation_timeout = value.filter(|secs| *secs > 0);
+    }
+
+    /// Get value of `connection_summary`
+    pub fn get_connection_summary(&self) -> bool {
+        self.config
+            .user_interface
+            .connection_summary
+            .unwrap_or(false)
+    }
+
+    /// Set new value for `connection_summary`
+    pub fn set_connection_summary(&mut self, value: bool) {
+        self.config.user_interface.connection_summary = Some(value);
+    }
+
+    /// Get value of `quit_immediately`
+    pub fn get_quit_immediately(&self) -> bool {
+        self.config.user_interface.quit_immediately.unwrap_or(false)
+    }
+
+    /// Set new value for `quit_immediately`
+    pub fn set_quit_immediately(&mut self, value: bool) {
+        self.config.user_interface.quit_immediately = Some(value);
+    }
+
+    /// Get value of `hide_footer`
+    pub fn get_hide_footer(&self) -> bool {
+        self.config.user_interface.hide_footer.unwrap_or(false)
+    }
+
+    /// Set new value for `hide_footer`
+    pub fn set_hide_footer(&mut self, value: bool) {
+        self.config.user_interface.hide_footer = Some(value);
+    }
+
+    /// Get value of `title_path_tail`
+    pub fn get_title_path_tail(&self) -> bool {
+        self.config.user_interface.title_path_tail.unwrap_or(false)
+    }
+
+    /// Set new value for `title_path_tail`
+    pub fn set_title_path_tail(&mut self, value: bool) {
+        self.config.user_interface.title_path_tail = Some(value);
+    }
+
+    /// Get value of `prompt_on_save_as_replace`
+    pub fn get_prompt_on_save_as_replace(&self) -> bool {
+        self.config
+            .user_interface
+            .prompt_on_save_as_replace
+            .unwrap_or(true)
+    }
+
+    /// Set new value for `prompt_on_save_as_replace`
+    pub fn set_prompt_on_save_as_replace(&mut self, value: bool) {
+        self.config.user_interface.prompt_on_save_as_replace = Some(value);
+    }
+
+    /// Get value of `hidden_patterns`
+    pub fn get_hidden_patterns(&self) -> Vec<String> {
+        self.config
+            .user_interface
+            .hidden_patterns
+            .clone()
+            .unwrap_or_default()
+    }
+
+    /// Get value of `footer_hints`; `None` if the default hints should be shown
+    pub fn get_footer_hints(&self) -> Option<Vec<String>> {
+        self.config.user_interface.footer_hints.clone()
+    }
+
+    /// Get value of `raw_file_sizes`
+    pub fn get_raw_file_sizes(&self) -> bool {
+        self.config.user_interface.raw_file_sizes.unwrap_or(false)
+    }
+
+    /// Get the units of human readable sizes, from `si_size_units`
+    pub fn get_size_units(&self) -> SizeUnits {
+        match self.config.user_interface.si_size_units.unwrap_or(false) {
+            true => SizeUnits::Si,
+            false => SizeUnits::Binary,
+        }
+    }
+
+    /// Get value of `bookmark_sort_and_filter`
+    pub fn get_bookmark_sort_and_filter(&self) -> bool {
+        self.config
+            .user_interface
+            .bookmark_sort_and_filter
+            .unwrap_or(false)
+    }
+
+    /// Get the association between file extension and command to open the files with
+    pub fn get_open_with(&self) -> HashMap<String, String> {
+        self.config
+            .user_interface
+            .open_with
+            .clone()
+            .unwrap_or_default()
+    }
+
+    /// Set the association between file extension and command to open the files with
+    pub fn set_open_with(&mut self, open_with: HashMap<String, String>) {
+        self.config.user_interface.open_with = match open_with.is_empty() {
+            true => None,
+            false => Some(open_with),
+        };
+    }
+
+    /// Get the command to open the file at `path` with, if its extension is associated to one.
+    /// Extensions are case insensitive and the longest matching one wins (e.g. `tar.gz` over `gz`);
+    /// `None` if the file should be opened with the default program of the system
+    pub fn get_open_with_command(&self, path: &Path) -> Option<String> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+        self.config
+            .user_interface
+            .open_with
+            .as_ref()?
+            .iter()
+            .filter(|(ext, _)| {
+                let ext = ext.trim_start_matches('.').to_lowercase();
+                !ext.is_empty()
+                    && name
+                        .strip_suffix(ext.as_str())
+                        .is_some_and(|stem| stem.len() > 1 && stem.ends_with('.'))
+            })
+            .max_by_key(|(ext, _)| ext.trim_start_matches('.').len())
+            .map(|(_, command)| command.clone())
+    }
+
+    /// Get the action performed when a file is submitted in the explorer
+    pub fn get_file_submit_action(&self) -> FileSubmitAction {
+        self.config
+            .user_interface
+            .file_submit_action
+            .as_deref()
+            .and_then(|x| FileSubmitAction::from_str(x).ok())
+            .unwrap_or_default()
+    }
+
+    /// Get value of `restore_focused_panel`
+    pub fn get_restore_focused_panel(&self) -> bool {
+        self.config
+            .user_interface
+            .restore_focused_panel
+            .unwrap_or(false)
+    }
+
+    /// Get the panel which was focused at the last disconnection, if any
+    pub fn get_last_focused_panel(&self) -> Option<FocusedPanel> {
+        self.config
+            .user_interface
+            .last_focused_panel
+            .as_deref()
+            .and_then(|x| FocusedPanel::from_str(x).ok())
+    }
+
+    /// Set the panel focused at disconnection
+    pub fn set_last_focused_panel(&mut self, panel: FocusedPanel) {
+        self.config.user_interface.last_focused_panel = Some(panel.to_string());
+    }
+
+    /// Get the panel to focus on startup.
+    /// It's the last focused panel if `restore_focused_panel` is enabled, otherwise the local one
+    pub fn get_initial_focused_panel(&self) -> FocusedPanel {
+        match self.get_restore_focused_panel() {
+            true => self.get_last_focused_panel().unwrap_or_default(),
+            false => FocusedPanel::default(),
+        }
+    }
+
+    /// Get value of `case_sensitive_sorting`
+    pub fn get_case_sensitive_sorting(&self) -> bool {
+        self.config
+            .user_interface
+            .case_sensitive_sorting
+            .unwrap_or(false)
+    }
+
+    /// Get value of `confirm_disconnect`
+    pub fn get_confirm_disconnect(&self) -> bool {
+        self.config
+            .user_interface
+            .confirm_disconnect
+            .unwrap_or(true)
+    }
+
+    /// Get value of `advance_on_mark`
+    pub fn get_advance_on_mark(&self) -> bool {
+        self.config.user_interface.advance_on_mark.unwrap_or(true)
+    }
+
+    /// Get the style of the cursor row in the explorers
+    pub fn get_highlight_style(&self) -> HighlightStyle {
+        self.config
+            .user_interface
+            .highlight_style
+            .as_deref()
+            .and_then(|x| HighlightStyle::from_str(x).ok())
+            .unwrap_or_default()
+    }
+
     // Remote params
 
     /// Get ssh config path
@@ -224,6 +484,16 @@ impl ConfigClient {
         self.config.remote.ssh_config = p;
     }
 
+    /// Get value of `rsync`
+    pub fn get_rsync(&self) -> bool {
+        self.config.remote.rsync.unwrap_or(false)
+    }
+
+    /// Set new value for `rsync`
+    pub fn set_rsync(&mut self, value: bool) {
+        self.config.remote.rsync = Some(value);
+    }
+
     // SSH Keys
 
     /// Save a SSH key into configuration.
@@ -365,7 +635,8 @@ impl ConfigClient {
             Ok(reader) => {
                 // Deserialize
                 match deserialize(Box::new(reader)) {
-                    Ok(config) => {
+                    Ok(mut config) => {
+                        Self::validate_config(&mut config);
                         self.config = config;
                         Ok(())
                     }
@@ -382,6 +653,22 @@ impl ConfigClient {
         }
     }
 
+    /// Reset the parameters with an invalid value to their default
+    fn validate_config(config: &mut UserConfig) {
+        if let Some(fmt) = config.user_interface.date_fmt.as_deref()
+            && !is_valid_date_fmt(fmt)
+        {
+            error!("Invalid date format \"{}\"; falling back to default", fmt);
+            config.user_interface.date_fmt = None;
+        }
+        if let Some(locale) = config.user_interface.locale.as_deref()
+            && Locale::from_name(locale).is_none()
+        {
+            error!("Unsupported locale \"{}\"; falling back to default", locale);
+            config.user_interface.locale = None;
+        }
+    }
+
     /// Hosts are saved as `username@host` into configuration.
     /// This method creates the key name, starting from host and username
     fn make_ssh_host_key(host: &str, username: &str) -> String {
@@ -611,6 +898,388 @@ mod tests {
         assert_eq!(client.get_remote_file_fmt(), None);
     }
 
+    #[test]
+    fn test_system_config_date_fmt() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_date_fmt(), None);
+        client.set_date_fmt(String::from("%d/%m/%Y"));
+        assert_eq!(client.get_date_fmt().unwrap(), String::from("%d/%m/%Y"));
+        // Delete
+        client.set_date_fmt(String::from(""));
+        assert_eq!(client.get_date_fmt(), None);
+    }
+
+    #[test]
+    fn should_reset_invalid_date_fmt_at_load() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        client.set_date_fmt(String::from("%Y-%m-%d %Q"));
+        assert!(client.write_config().is_ok());
+        let client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_date_fmt(), None);
+    }
+
+    #[test]
+    fn test_system_config_confirmation_timeout() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_confirmation_timeout(), None);
+        client.set_confirmation_timeout(Some(30));
+        assert_eq!(client.get_confirmation_timeout(), Some(30));
+        client.set_confirmation_timeout(Some(0));
+        assert_eq!(client.get_confirmation_timeout(), None);
+        client.set_confirmation_timeout(Some(10));
+        client.set_confirmation_timeout(None);
+        assert_eq!(client.get_confirmation_timeout(), None);
+    }
+
+    #[test]
+    fn test_system_config_connection_summary() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_connection_summary(), false);
+        client.set_connection_summary(true);
+        assert_eq!(client.get_connection_summary(), true);
+        client.set_connection_summary(false);
+        assert_eq!(client.get_connection_summary(), false);
+    }
+
+    #[test]
+    fn test_system_config_quit_immediately() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_quit_immediately(), false);
+        client.set_quit_immediately(true);
+        assert_eq!(client.get_quit_immediately(), true);
+        client.set_quit_immediately(false);
+        assert_eq!(client.get_quit_immediately(), false);
+    }
+
+    #[test]
+    fn test_system_config_hide_footer() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_hide_footer(), false);
+        client.set_hide_footer(true);
+        assert_eq!(client.get_hide_footer(), true);
+        client.set_hide_footer(false);
+        assert_eq!(client.get_hide_footer(), false);
+    }
+
+    #[test]
+    fn test_system_config_title_path_tail() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_title_path_tail(), false);
+        client.set_title_path_tail(true);
+        assert_eq!(client.get_title_path_tail(), true);
+        client.set_title_path_tail(false);
+        assert_eq!(client.get_title_path_tail(), false);
+    }
+
+    #[test]
+    fn test_system_config_prompt_on_save_as_replace() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_prompt_on_save_as_replace(), true);
+        client.set_prompt_on_save_as_replace(false);
+        assert_eq!(client.get_prompt_on_save_as_replace(), false);
+        client.config.user_interface.prompt_on_save_as_replace = None;
+        assert_eq!(client.get_prompt_on_save_as_replace(), true);
+    }
+
+    #[test]
+    fn test_system_config_file_submit_action() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_file_submit_action(), FileSubmitAction::Nothing);
+        for action in [
+            FileSubmitAction::Open,
+            FileSubmitAction::Transfer,
+            FileSubmitAction::Edit,
+        ] {
+            client.config.user_interface.file_submit_action = Some(action.to_string());
+            assert_eq!(client.get_file_submit_action(), action);
+        }
+        // Invalid value
+        client.config.user_interface.file_submit_action = Some(String::from("omar"));
+        assert_eq!(client.get_file_submit_action(), FileSubmitAction::Nothing);
+    }
+
+    #[test]
+    fn test_system_config_restore_focused_panel() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_restore_focused_panel(), false);
+        assert_eq!(client.get_last_focused_panel(), None);
+        // Disabled: always start from local panel
+        client.set_last_focused_panel(FocusedPanel::Remote);
+        assert_eq!(client.get_initial_focused_panel(), FocusedPanel::Local);
+        // Enabled
+        client.config.user_interface.restore_focused_panel = Some(true);
+        assert_eq!(client.get_initial_focused_panel(), FocusedPanel::Remote);
+        client.set_last_focused_panel(FocusedPanel::Local);
+        assert_eq!(client.get_initial_focused_panel(), FocusedPanel::Local);
+        // Stored value is persisted
+        client.set_last_focused_panel(FocusedPanel::Remote);
+        assert!(client.write_config().is_ok());
+        let client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_last_focused_panel(), Some(FocusedPanel::Remote));
+        assert_eq!(client.get_initial_focused_panel(), FocusedPanel::Remote);
+    }
+
+    #[test]
+    fn test_system_config_restore_focused_panel_defaults() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        client.config.user_interface.restore_focused_panel = Some(true);
+        // Nothing stored
+        assert_eq!(client.get_initial_focused_panel(), FocusedPanel::Local);
+        // Invalid value
+        client.config.user_interface.last_focused_panel = Some(String::from("omar"));
+        assert_eq!(client.get_last_focused_panel(), None);
+        assert_eq!(client.get_initial_focused_panel(), FocusedPanel::Local);
+        // Option missing
+        client.config.user_interface.restore_focused_panel = None;
+        client.set_last_focused_panel(FocusedPanel::Remote);
+        assert_eq!(client.get_initial_focused_panel(), FocusedPanel::Local);
+    }
+
+    #[test]
+    fn test_system_config_case_sensitive_sorting() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_case_sensitive_sorting(), false);
+        client.config.user_interface.case_sensitive_sorting = Some(true);
+        assert_eq!(client.get_case_sensitive_sorting(), true);
+        client.config.user_interface.case_sensitive_sorting = None;
+        assert_eq!(client.get_case_sensitive_sorting(), false);
+    }
+
+    #[test]
+    fn test_system_config_confirm_disconnect() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_confirm_disconnect(), true);
+        client.config.user_interface.confirm_disconnect = Some(false);
+        assert_eq!(client.get_confirm_disconnect(), false);
+        client.config.user_interface.confirm_disconnect = None;
+        assert_eq!(client.get_confirm_disconnect(), true);
+    }
+
+    #[test]
+    fn test_system_config_advance_on_mark() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_advance_on_mark(), true);
+        client.config.user_interface.advance_on_mark = Some(false);
+        assert_eq!(client.get_advance_on_mark(), false);
+        client.config.user_interface.advance_on_mark = None;
+        assert_eq!(client.get_advance_on_mark(), true);
+    }
+
+    #[test]
+    fn test_system_config_highlight_style() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_highlight_style(), HighlightStyle::Color);
+        client.config.user_interface.highlight_style = Some(String::from("reverse"));
+        assert_eq!(client.get_highlight_style(), HighlightStyle::Reverse);
+        // Invalid value
+        client.config.user_interface.highlight_style = Some(String::from("blink"));
+        assert_eq!(client.get_highlight_style(), HighlightStyle::Color);
+    }
+
+    #[test]
+    fn test_system_config_hidden_patterns() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert!(client.get_hidden_patterns().is_empty());
+        client.config.user_interface.hidden_patterns =
+            Some(vec![String::from("target/"), String::from("*.o")]);
+        assert_eq!(
+            client.get_hidden_patterns(),
+            vec![String::from("target/"), String::from("*.o")]
+        );
+    }
+
+    #[test]
+    fn test_system_config_footer_hints() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_footer_hints(), None);
+        client.config.user_interface.footer_hints =
+            Some(vec![String::from("help"), String::from("mkdir")]);
+        assert_eq!(
+            client.get_footer_hints(),
+            Some(vec![String::from("help"), String::from("mkdir")])
+        );
+    }
+
+    #[test]
+    fn test_system_config_raw_file_sizes() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_raw_file_sizes(), false);
+        client.config.user_interface.raw_file_sizes = Some(true);
+        assert_eq!(client.get_raw_file_sizes(), true);
+        client.config.user_interface.raw_file_sizes = None;
+        assert_eq!(client.get_raw_file_sizes(), false);
+    }
+
+    #[test]
+    fn test_system_config_size_units() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_size_units(), SizeUnits::Binary);
+        client.config.user_interface.si_size_units = Some(true);
+        assert_eq!(client.get_size_units(), SizeUnits::Si);
+        client.config.user_interface.si_size_units = None;
+        assert_eq!(client.get_size_units(), SizeUnits::Binary);
+    }
+
+    #[test]
+    fn test_system_config_bookmark_sort_and_filter() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_bookmark_sort_and_filter(), false);
+        client.config.user_interface.bookmark_sort_and_filter = Some(true);
+        assert_eq!(client.get_bookmark_sort_and_filter(), true);
+        client.config.user_interface.bookmark_sort_and_filter = None;
+        assert_eq!(client.get_bookmark_sort_and_filter(), false);
+    }
+
+    #[test]
+    fn test_system_config_open_with() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert!(client.get_open_with().is_empty());
+        assert_eq!(client.get_open_with_command(Path::new("/tmp/a.md")), None);
+        let open_with = HashMap::from([
+            (String::from("md"), String::from("glow {}")),
+            (String::from(".PNG"), String::from("feh")),
+            (String::from("gz"), String::from("zcat {} | less")),
+            (String::from("tar.gz"), String::from("tar tzf {}")),
+        ]);
+        client.set_open_with(open_with.clone());
+        assert_eq!(client.get_open_with(), open_with);
+        assert_eq!(
+            client.get_open_with_command(Path::new("/tmp/README.md")),
+            Some(String::from("glow {}"))
+        );
+        // extensions are case insensitive and may start with a dot
+        assert_eq!(
+            client.get_open_with_command(Path::new("/tmp/photo.png")),
+            Some(String::from("feh"))
+        );
+        // the longest extension wins
+        assert_eq!(
+            client.get_open_with_command(Path::new("/tmp/release.tar.gz")),
+            Some(String::from("tar tzf {}"))
+        );
+        assert_eq!(
+            client.get_open_with_command(Path::new("/tmp/access.log.gz")),
+            Some(String::from("zcat {} | less"))
+        );
+        // no association: falls back to the default program
+        assert_eq!(client.get_open_with_command(Path::new("/tmp/a.txt")), None);
+        assert_eq!(client.get_open_with_command(Path::new("/tmp/md")), None);
+        assert_eq!(client.get_open_with_command(Path::new("/tmp/.md")), None);
+        client.set_open_with(HashMap::new());
+        assert_eq!(client.config.user_interface.open_with, None);
+    }
+
+    #[test]
+    fn test_system_config_locale() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_locale(), None);
+        client.set_locale(String::from("de_DE"));
+        assert_eq!(client.get_locale().unwrap(), String::from("de_DE"));
+        // Unsupported locale is reset at load
+        client.set_locale(String::from("xx_XX"));
+        assert!(client.write_config().is_ok());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_locale(), None);
+        // Delete
+        client.set_locale(String::from("system"));
+        client.set_locale(String::from(""));
+        assert_eq!(client.get_locale(), None);
+    }
+
     #[test]
     fn test_system_config_notifications() {
         let tmp_dir: TempDir = TempDir::new().ok().unwrap();
@@ -642,6 +1311,24 @@ mod tests {
         assert_eq!(client.get_notification_threshold(), 64);
     }
 
+    #[test]
+    fn test_system_config_panel_split_ratio() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_panel_split_ratio(), DEFAULT_PANEL_SPLIT_RATIO); // Null ?
+        client.set_panel_split_ratio(65);
+        assert_eq!(client.get_panel_split_ratio(), 65);
+        // Persist
+        assert!(client.write_config().is_ok());
+        let client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_panel_split_ratio(), 65);
+    }
+
     #[test]
     fn should_get_and_set_ssh_config_dir() {
         let tmp_dir: TempDir = TempDir::new().ok().unwrap();
@@ -667,6 +1354,20 @@ mod tests {
         assert_eq!(client.get_ssh_config(), None);
     }
 
+    #[test]
+    fn test_system_config_rsync() {
+        let tmp_dir: TempDir = TempDir::new().ok().unwrap();
+        let (cfg_path, key_path): (PathBuf, PathBuf) = get_paths(tmp_dir.path());
+        let mut client: ConfigClient = ConfigClient::new(cfg_path.as_path(), key_path.as_path())
+            .ok()
+            .unwrap();
+        assert_eq!(client.get_rsync(), false); // Null ?
+        client.set_rsync(true);
+        assert_eq!(client.get_rsync(), true);
+        client.set_rsync(false);
+        assert_eq!(client.get_rsync(), false);
+    }
+
     #[test]
     fn test_system_config_ssh_keys() {
         let tmp_dir: TempDir = TempDir::new().ok().unwrap();
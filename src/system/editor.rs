@@ -0,0 +1,194 @@
+//! ## Editor
+//!
+//! `editor` is the module which resolves the text editor to edit files with
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Editors looked for in `PATH`, in order, when no other editor is available
+#[cfg(not(windows))]
+const DEFAULT_EDITORS: &[&str] = &["nano", "vim", "vi", "emacs"];
+#[cfg(windows)]
+const DEFAULT_EDITORS: &[&str] = &["notepad.exe"];
+
+/// A text editor command, with its arguments (e.g. `code --wait`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Editor {
+    pub program: PathBuf,
+    pub args: Vec<String>,
+}
+
+impl Editor {
+    /// Get the command to edit `file` with
+    pub fn command(&self, file: &Path) -> Command {
+        let mut command = Command::new(&self.program);
+        command.args(&self.args).arg(file);
+        command
+    }
+
+    /// Parse an editor from the value of `$VISUAL` or `$EDITOR`, whose arguments are separated by whitespaces
+    fn from_env_value(value: &str) -> Option<Self> {
+        let mut words = value.split_whitespace();
+        let program = PathBuf::from(words.next()?);
+        Some(Self {
+            program,
+            args: words.map(String::from).collect(),
+        })
+    }
+}
+
+/// Resolve the editor to edit files with, which is the first available among:
+///
+/// 1. the configured `text_editor`
+/// 2. `$VISUAL`
+/// 3. `$EDITOR`
+/// 4. the default editors of the platform (e.g. `nano`, `vim`, `vi`)
+pub fn resolve_editor(configured: &Path) -> Result<Editor, String> {
+    resolve(
+        configured,
+        env::var("VISUAL").ok().as_deref(),
+        env::var("EDITOR").ok().as_deref(),
+        |program| find_program(program).is_some(),
+    )
+}
+
+fn resolve(
+    configured: &Path,
+    visual: Option<&str>,
+    editor: Option<&str>,
+    is_available: impl Fn(&Path) -> bool,
+) -> Result<Editor, String> {
+    let configured = (!configured.as_os_str().is_empty()).then(|| Editor {
+        program: configured.to_path_buf(),
+        args: Vec::new(),
+    });
+    let defaults = DEFAULT_EDITORS.iter().map(|program| Editor {
+        program: PathBuf::from(program),
+        args: Vec::new(),
+    });
+    configured
+        .into_iter()
+        .chain(visual.and_then(Editor::from_env_value))
+        .chain(editor.and_then(Editor::from_env_value))
+        .chain(defaults)
+        .find(|editor| is_available(&editor.program))
+        .ok_or_else(|| {
+            format!(
+                "No text editor found: set one in the configuration, or set $VISUAL or $EDITOR (tried {})",
+                DEFAULT_EDITORS.join(", ")
+            )
+        })
+}
+
+/// Find `program` in `PATH`; if it's a path, it's returned if it exists.
+/// On Windows, the `.exe` extension may be omitted
+fn find_program(program: &Path) -> Option<PathBuf> {
+    if program.components().count() > 1 {
+        return program.is_file().then(|| program.to_path_buf());
+    }
+    env::split_paths(&env::var_os("PATH")?).find_map(|dir| {
+        let path = dir.join(program);
+        #[cfg(windows)]
+        if !path.is_file() {
+            return Some(path.with_extension("exe")).filter(|path| path.is_file());
+        }
+        path.is_file().then_some(path)
+    })
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn editor(program: &str, args: &[&str]) -> Editor {
+        Editor {
+            program: PathBuf::from(program),
+            args: args.iter().map(|x| x.to_string()).collect(),
+        }
+    }
+
+    fn all_available(_: &Path) -> bool {
+        true
+    }
+
+    #[test]
+    fn should_prefer_configured_editor() {
+        assert_eq!(
+            resolve(
+                Path::new("mcedit"),
+                Some("code --wait"),
+                Some("vim"),
+                all_available
+            )
+            .unwrap(),
+            editor("mcedit", &[])
+        );
+    }
+
+    #[test]
+    fn should_fall_back_to_visual() {
+        assert_eq!(
+            resolve(
+                Path::new(""),
+                Some("code --wait"),
+                Some("vim"),
+                all_available
+            )
+            .unwrap(),
+            editor("code", &["--wait"])
+        );
+    }
+
+    #[test]
+    fn should_fall_back_to_editor() {
+        assert_eq!(
+            resolve(Path::new(""), None, Some("vim"), all_available).unwrap(),
+            editor("vim", &[])
+        );
+        // empty variables are ignored
+        assert_eq!(
+            resolve(Path::new(""), Some("  "), Some("vim"), all_available).unwrap(),
+            editor("vim", &[])
+        );
+    }
+
+    #[test]
+    fn should_fall_back_to_platform_default() {
+        assert_eq!(
+            resolve(Path::new(""), None, None, all_available).unwrap(),
+            editor(DEFAULT_EDITORS[0], &[])
+        );
+    }
+
+    #[test]
+    fn should_skip_unavailable_editors() {
+        let available = |program: &Path| program == Path::new("vi");
+        assert_eq!(
+            resolve(Path::new("mcedit"), Some("code"), Some("vi"), available).unwrap(),
+            editor("vi", &[])
+        );
+    }
+
+    #[test]
+    fn should_fail_if_no_editor_is_available() {
+        assert!(
+            resolve(Path::new("mcedit"), Some("code"), Some("vim"), |_| false)
+                .unwrap_err()
+                .starts_with("No text editor found")
+        );
+    }
+
+    #[test]
+    fn should_make_editor_command() {
+        let command = editor("code", &["--wait"]).command(Path::new("/tmp/a.txt"));
+        assert_eq!(command.get_program(), "code");
+        assert_eq!(
+            command.get_args().collect::<Vec<_>>(),
+            vec!["--wait", "/tmp/a.txt"]
+        );
+    }
+}
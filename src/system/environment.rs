@@ -89,6 +89,13 @@ pub fn get_log_paths(cache_dir: &Path) -> PathBuf {
     log_file
 }
 
+/// Returns the path for the sync baselines file
+pub fn get_sync_baselines_path(cache_dir: &Path) -> PathBuf {
+    let mut baselines_file: PathBuf = PathBuf::from(cache_dir);
+    baselines_file.push("sync.toml");
+    baselines_file
+}
+
 /// Get paths for theme provider
 /// Returns: path of theme.toml
 pub fn get_theme_path(config_dir: &Path) -> PathBuf {
@@ -202,6 +209,15 @@ mod tests {
         );
     }
 
+    #[test]
+    #[serial]
+    fn test_system_environment_get_sync_baselines_path() {
+        assert_eq!(
+            get_sync_baselines_path(Path::new("/home/omar/.cache/termscp/")),
+            PathBuf::from("/home/omar/.cache/termscp/sync.toml"),
+        );
+    }
+
     #[test]
     #[serial]
     fn test_system_environment_get_theme_path() {
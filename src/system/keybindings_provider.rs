@@ -2,10 +2,11 @@
 //!
 //! `keybindings_provider` is the module which provides an API between the keybindings configuration and the system
 
-use std::fs::OpenOptions;
+use std::ffi::OsString;
+use std::fs::{self, OpenOptions};
 use std::path::{Path, PathBuf};
 
-use crate::config::keybindings::KeyBindings;
+use crate::config::keybindings::{KEYBINDINGS_VERSION, KeyBindings};
 use crate::config::serialization::{SerializerError, SerializerErrorKind, deserialize, serialize};
 
 /// KeyBindingsProvider provides a high level API to communicate with the termscp keybindings
@@ -65,7 +66,6 @@ impl KeyBindingsProvider {
     }
 
     /// Returns a mutable reference to the keybindings
-    #[allow(dead_code)]
     pub fn keybindings_mut(&mut self) -> &mut KeyBindings {
         &mut self.keybindings
     }
@@ -82,22 +82,112 @@ impl KeyBindingsProvider {
                 String::from("Can't access keybindings file"),
             ));
         }
-        // Open keybindings file for read
         debug!("Loading keybindings from file...");
-        match OpenOptions::new()
-            .read(true)
-            .open(self.keybindings_path.as_path())
+        self.keybindings = match Self::read(self.keybindings_path.as_path()) {
+            Ok(keybindings) => keybindings,
+            Err(err) => self.restore_backup().ok_or(err)?,
+        };
+        for key in Self::unknown_keys(self.keybindings_path.as_path()) {
+            warn!("Unknown key in keybindings file: {}; it is ignored", key);
+        }
+        if let Err(conflicts) = self.keybindings.validate() {
+            for conflict in conflicts {
+                warn!("Keybindings conflict: {}", conflict);
+            }
+        }
+        if self.keybindings.version < KEYBINDINGS_VERSION
+            && let Err(err) = self.migrate()
         {
-            Ok(reader) => {
-                // Deserialize
-                match deserialize(Box::new(reader)) {
-                    Ok(keybindings) => {
-                        self.keybindings = keybindings;
-                        Ok(())
-                    }
-                    Err(err) => Err(err),
+            error!("Failed to migrate keybindings: {}", err);
+        }
+        Ok(())
+    }
+
+    /// Migrate keybindings written with an older version of the file format.
+    ///
+    /// The actions missing from the file have already taken their default value; then the migration steps of the
+    /// versions following the one of the file are applied. What changed is logged, then the file is rewritten with the
+    /// current version
+    fn migrate(&mut self) -> Result<(), SerializerError> {
+        let added = Self::missing_actions(self.keybindings_path.as_path());
+        let added_bindings = self.keybindings.migrate(self.keybindings.version);
+        let list = |items: Vec<String>| match items.is_empty() {
+            true => String::from("none"),
+            false => items.join(", "),
+        };
+        info!(
+            "Migrating keybindings from version {} to {}; added actions: {}; added bindings: {}",
+            self.keybindings.version,
+            KEYBINDINGS_VERSION,
+            list(added),
+            list(added_bindings)
+        );
+        self.keybindings.version = KEYBINDINGS_VERSION;
+        self.save()
+    }
+
+    /// Get the name of the actions (e.g. `explorer.mkdir`) which aren't set in the keybindings file at `path`
+    fn missing_actions(path: &Path) -> Vec<String> {
+        let table = Self::read_table(path);
+        let mut actions = vec![];
+        for (section, known_actions) in Self::known_keys() {
+            let Some(known_actions) = known_actions.as_table() else {
+                continue;
+            };
+            for name in known_actions.keys() {
+                if table
+                    .get(&section)
+                    .and_then(|actions| actions.get(name))
+                    .is_none()
+                {
+                    actions.push(format!("{section}.{name}"));
                 }
             }
+        }
+        actions
+    }
+
+    /// Get the keys of the keybindings file at `path` (e.g. `explorer.move_upp`) which termscp doesn't know, and so
+    /// are ignored
+    fn unknown_keys(path: &Path) -> Vec<String> {
+        let known = Self::known_keys();
+        let mut keys = vec![];
+        for (section, value) in Self::read_table(path) {
+            match (known.get(&section), value.as_table()) {
+                (None, _) => keys.push(section),
+                (Some(known_actions), Some(actions)) => keys.extend(
+                    actions
+                        .keys()
+                        .filter(|name| known_actions.get(name.as_str()).is_none())
+                        .map(|name| format!("{section}.{name}")),
+                ),
+                // Not a section, like `version`
+                (Some(_), None) => {}
+            }
+        }
+        keys
+    }
+
+    /// Get the keys of the keybindings file, with the sections of actions
+    fn known_keys() -> toml::Table {
+        match toml::Value::try_from(KeyBindings::default()) {
+            Ok(toml::Value::Table(table)) => table,
+            _ => toml::Table::default(),
+        }
+    }
+
+    /// Read the keybindings file at `path` as a TOML table; an unreadable file is read as an empty table
+    fn read_table(path: &Path) -> toml::Table {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| toml::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Read keybindings from the file at `path`
+    fn read(path: &Path) -> Result<KeyBindings, SerializerError> {
+        match OpenOptions::new().read(true).open(path) {
+            Ok(reader) => deserialize(Box::new(reader)),
             Err(err) => {
                 error!("Failed to read keybindings: {}", err);
                 Err(SerializerError::new_ex(
@@ -108,7 +198,43 @@ impl KeyBindingsProvider {
         }
     }
 
-    /// Save keybindings to file
+    /// Replace the keybindings file with its backup, if it can be read.
+    ///
+    /// Returns the keybindings of the backup
+    fn restore_backup(&self) -> Option<KeyBindings> {
+        let backup_path = self.backup_path();
+        if !backup_path.exists() {
+            return None;
+        }
+        let keybindings = Self::read(backup_path.as_path()).ok()?;
+        warn!(
+            "Keybindings file {} couldn't be loaded; restoring it from backup {}",
+            self.keybindings_path.display(),
+            backup_path.display()
+        );
+        if let Err(err) = fs::copy(backup_path.as_path(), self.keybindings_path.as_path()) {
+            error!("Failed to restore keybindings from backup: {}", err);
+        }
+        Some(keybindings)
+    }
+
+    /// Replace the keybindings with the default ones and save them to file
+    pub fn reset_to_defaults(&mut self) -> Result<(), SerializerError> {
+        if self.degraded {
+            warn!("Keybindings won't be reset, since in degraded mode");
+            return Err(SerializerError::new_ex(
+                SerializerErrorKind::Generic,
+                String::from("Can't access keybindings file"),
+            ));
+        }
+        self.keybindings = KeyBindings::default();
+        self.save()
+    }
+
+    /// Save keybindings to file.
+    ///
+    /// Keybindings are written to a temporary file, which is then renamed into place, so a failed write never leaves a
+    /// partial file. The previous keybindings are kept in a `.bak` file
     pub fn save(&self) -> Result<(), SerializerError> {
         if self.degraded {
             warn!("Configuration won't be saved, since in degraded mode");
@@ -119,21 +245,51 @@ impl KeyBindingsProvider {
         }
         // Open file
         debug!("Writing keybindings");
+        let tmp_path = self.sibling_path("tmp");
         match OpenOptions::new()
             .create(true)
             .write(true)
             .truncate(true)
-            .open(self.keybindings_path.as_path())
+            .open(tmp_path.as_path())
         {
-            Ok(writer) => serialize(self.keybindings(), Box::new(writer)),
+            Ok(writer) => {
+                if let Err(err) = serialize(self.keybindings(), Box::new(writer)) {
+                    let _ = fs::remove_file(tmp_path.as_path());
+                    return Err(err);
+                }
+            }
             Err(err) => {
                 error!("Failed to write keybindings: {}", err);
-                Err(SerializerError::new_ex(
+                return Err(SerializerError::new_ex(
                     SerializerErrorKind::Io,
                     err.to_string(),
-                ))
+                ));
             }
         }
+        // Back up the previous keybindings, then replace them
+        if self.keybindings_path.exists()
+            && let Err(err) = fs::copy(self.keybindings_path.as_path(), self.backup_path())
+        {
+            warn!("Failed to back up keybindings: {}", err);
+        }
+        fs::rename(tmp_path.as_path(), self.keybindings_path.as_path()).map_err(|err| {
+            error!("Failed to write keybindings: {}", err);
+            let _ = fs::remove_file(tmp_path.as_path());
+            SerializerError::new_ex(SerializerErrorKind::Io, err.to_string())
+        })
+    }
+
+    /// Path of the backup of the keybindings file
+    fn backup_path(&self) -> PathBuf {
+        self.sibling_path("bak")
+    }
+
+    /// Path of the keybindings file, with `extension` appended (e.g. `keybindings.toml.bak`)
+    fn sibling_path(&self, extension: &str) -> PathBuf {
+        let mut path = OsString::from(self.keybindings_path.as_os_str());
+        path.push(".");
+        path.push(extension);
+        PathBuf::from(path)
     }
 }
 
@@ -156,15 +312,15 @@ mod test {
         // Verify client
         assert_eq!(
             provider.keybindings().explorer.move_up,
-            KeyBinding::simple(Key::Up)
+            vec![KeyBinding::simple(Key::Up)]
         );
         assert_eq!(provider.keybindings_path, keybindings_path);
         assert_eq!(provider.degraded, false);
         // Mutation
-        provider.keybindings_mut().explorer.move_up = KeyBinding::simple(Key::Char('k'));
+        provider.keybindings_mut().explorer.move_up = vec![KeyBinding::simple(Key::Char('k'))];
         assert_eq!(
             provider.keybindings().explorer.move_up,
-            KeyBinding::simple(Key::Char('k'))
+            vec![KeyBinding::simple(Key::Char('k'))]
         );
     }
 
@@ -176,40 +332,327 @@ mod test {
         let mut provider: KeyBindingsProvider =
             KeyBindingsProvider::new(keybindings_path.as_path()).unwrap();
         // Write
-        provider.keybindings_mut().explorer.move_up = KeyBinding::simple(Key::Char('k'));
+        provider.keybindings_mut().explorer.move_up = vec![KeyBinding::simple(Key::Char('k'))];
         assert!(provider.save().is_ok());
-        provider.keybindings_mut().explorer.move_up = KeyBinding::simple(Key::Char('j'));
+        provider.keybindings_mut().explorer.move_up = vec![KeyBinding::simple(Key::Char('j'))];
         // Reload
         assert!(provider.load().is_ok());
         // Unchanged
         assert_eq!(
             provider.keybindings().explorer.move_up,
-            KeyBinding::simple(Key::Char('k'))
+            vec![KeyBinding::simple(Key::Char('k'))]
         );
         // Instantiate a new provider
         let provider: KeyBindingsProvider =
             KeyBindingsProvider::new(keybindings_path.as_path()).unwrap();
         assert_eq!(
             provider.keybindings().explorer.move_up,
-            KeyBinding::simple(Key::Char('k'))
+            vec![KeyBinding::simple(Key::Char('k'))]
         ); // Unchanged
     }
 
+    #[test]
+    fn test_system_keybindings_provider_multiple_bindings() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let keybindings_path: PathBuf = get_keybindings_path(tmp_dir.path());
+        let mut provider: KeyBindingsProvider =
+            KeyBindingsProvider::new(keybindings_path.as_path()).unwrap();
+        provider.keybindings_mut().explorer.move_up = vec![
+            KeyBinding::simple(Key::Char('k')),
+            KeyBinding::simple(Key::Up),
+        ];
+        assert!(provider.save().is_ok());
+        // Actions with a single binding are still written as a string
+        let content = std::fs::read_to_string(keybindings_path.as_path()).unwrap();
+        assert!(content.contains(r#"move_up = ["k", "up"]"#));
+        assert!(content.contains(r#"move_down = "down""#));
+        // Reload
+        let provider: KeyBindingsProvider =
+            KeyBindingsProvider::new(keybindings_path.as_path()).unwrap();
+        assert_eq!(
+            provider.keybindings().explorer.move_up,
+            vec![
+                KeyBinding::simple(Key::Char('k')),
+                KeyBinding::simple(Key::Up)
+            ]
+        );
+        assert_eq!(
+            provider.keybindings().explorer.move_down,
+            vec![KeyBinding::simple(Key::Down)]
+        );
+    }
+
+    #[test]
+    fn test_system_keybindings_provider_load_with_conflicts() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let keybindings_path: PathBuf = get_keybindings_path(tmp_dir.path());
+        let mut provider: KeyBindingsProvider =
+            KeyBindingsProvider::new(keybindings_path.as_path()).unwrap();
+        provider.keybindings_mut().explorer.copy_file = vec![KeyBinding::simple(Key::Char('c'))];
+        provider.keybindings_mut().explorer.mkdir = vec![KeyBinding::simple(Key::Char('c'))];
+        assert!(provider.save().is_ok());
+        // Conflicts are only reported
+        assert!(provider.load().is_ok());
+        assert_eq!(
+            provider.keybindings().explorer.mkdir,
+            vec![KeyBinding::simple(Key::Char('c'))]
+        );
+    }
+
     #[test]
     fn test_system_keybindings_provider_degraded() {
         let mut provider: KeyBindingsProvider = KeyBindingsProvider::degraded();
         assert_eq!(
             provider.keybindings().explorer.move_up,
-            KeyBinding::simple(Key::Up)
+            vec![KeyBinding::simple(Key::Up)]
         );
         assert_eq!(provider.degraded, true);
-        provider.keybindings_mut().explorer.move_up = KeyBinding::simple(Key::Char('k'));
+        provider.keybindings_mut().explorer.move_up = vec![KeyBinding::simple(Key::Char('k'))];
         assert!(provider.load().is_err());
         assert_eq!(
             provider.keybindings().explorer.move_up,
-            KeyBinding::simple(Key::Up)
+            vec![KeyBinding::simple(Key::Up)]
         );
         assert!(provider.save().is_err());
+        assert!(provider.reset_to_defaults().is_err());
+    }
+
+    #[test]
+    fn test_system_keybindings_provider_reset_to_defaults() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let keybindings_path: PathBuf = get_keybindings_path(tmp_dir.path());
+        let default_path: PathBuf = tmp_dir.path().join("default.toml");
+        let mut provider: KeyBindingsProvider =
+            KeyBindingsProvider::new(keybindings_path.as_path()).unwrap();
+        provider.keybindings_mut().explorer.move_up = vec![KeyBinding::simple(Key::Char('k'))];
+        assert!(provider.save().is_ok());
+        assert!(provider.reset_to_defaults().is_ok());
+        assert_eq!(provider.keybindings(), &KeyBindings::default());
+        // A new provider writes the default keybindings
+        assert!(KeyBindingsProvider::new(default_path.as_path()).is_ok());
+        assert_eq!(
+            std::fs::read_to_string(keybindings_path.as_path()).unwrap(),
+            std::fs::read_to_string(default_path.as_path()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_system_keybindings_provider_save_backup() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let keybindings_path: PathBuf = get_keybindings_path(tmp_dir.path());
+        let mut provider: KeyBindingsProvider =
+            KeyBindingsProvider::new(keybindings_path.as_path()).unwrap();
+        // Nothing to back up when creating the file
+        assert!(!provider.backup_path().exists());
+        let previous = std::fs::read_to_string(keybindings_path.as_path()).unwrap();
+        provider.keybindings_mut().explorer.move_up = vec![KeyBinding::simple(Key::Char('k'))];
+        assert!(provider.save().is_ok());
+        // The backup has the previous keybindings
+        assert_eq!(
+            std::fs::read_to_string(provider.backup_path()).unwrap(),
+            previous
+        );
+        // The temporary file has been renamed into place
+        assert!(!provider.sibling_path("tmp").exists());
+        assert_eq!(
+            std::fs::read_dir(tmp_dir.path()).unwrap().count(),
+            2 // keybindings and backup
+        );
+        let content = std::fs::read_to_string(keybindings_path.as_path()).unwrap();
+        assert!(content.contains(r#"move_up = "k""#));
+    }
+
+    #[test]
+    fn test_system_keybindings_provider_restore_backup() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let keybindings_path: PathBuf = get_keybindings_path(tmp_dir.path());
+        let mut provider: KeyBindingsProvider =
+            KeyBindingsProvider::new(keybindings_path.as_path()).unwrap();
+        provider.keybindings_mut().explorer.move_up = vec![KeyBinding::simple(Key::Char('k'))];
+        assert!(provider.save().is_ok());
+        provider.keybindings_mut().explorer.move_up = vec![KeyBinding::simple(Key::Char('j'))];
+        assert!(provider.save().is_ok());
+        // Break the keybindings file
+        std::fs::write(keybindings_path.as_path(), "explorer = 4").unwrap();
+        let provider: KeyBindingsProvider =
+            KeyBindingsProvider::new(keybindings_path.as_path()).unwrap();
+        assert_eq!(
+            provider.keybindings().explorer.move_up,
+            vec![KeyBinding::simple(Key::Char('k'))]
+        );
+        // The keybindings file has been restored
+        let content = std::fs::read_to_string(keybindings_path.as_path()).unwrap();
+        assert!(content.contains(r#"move_up = "k""#));
+        // Without backup, the error is returned
+        std::fs::remove_file(provider.backup_path()).unwrap();
+        std::fs::write(keybindings_path.as_path(), "explorer = 4").unwrap();
+        assert!(KeyBindingsProvider::new(keybindings_path.as_path()).is_err());
+    }
+
+    #[test]
+    fn test_system_keybindings_provider_migrate() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let keybindings_path: PathBuf = get_keybindings_path(tmp_dir.path());
+        // Unversioned file, written before `enter_dir_alt` existed
+        std::fs::write(
+            keybindings_path.as_path(),
+            r#"
+[global]
+quit = "q"
+
+[explorer]
+move_up = "k"
+enter_dir = "l"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            KeyBindingsProvider::missing_actions(keybindings_path.as_path())
+                .iter()
+                .filter(|x| x.as_str() == "explorer.enter_dir_alt")
+                .count(),
+            1
+        );
+        let provider: KeyBindingsProvider =
+            KeyBindingsProvider::new(keybindings_path.as_path()).unwrap();
+        // Customizations are kept, missing actions take the default value
+        assert_eq!(provider.keybindings().version, KEYBINDINGS_VERSION);
+        assert_eq!(
+            provider.keybindings().explorer.move_up,
+            vec![
+                KeyBinding::simple(Key::Char('k')),
+                KeyBinding::simple(Key::Up)
+            ]
+        );
+        assert_eq!(
+            provider.keybindings().explorer.enter_dir,
+            vec![KeyBinding::simple(Key::Char('l'))]
+        );
+        assert_eq!(
+            provider.keybindings().explorer.enter_dir_alt,
+            KeyBindings::default().explorer.enter_dir_alt
+        );
+        // The file has been rewritten with the current version
+        let content = std::fs::read_to_string(keybindings_path.as_path()).unwrap();
+        assert!(content.contains(&format!("version = {KEYBINDINGS_VERSION}")));
+        assert!(content.contains("enter_dir_alt = "));
+        assert!(content.contains(r#"move_up = ["k", "up"]"#));
+        assert!(KeyBindingsProvider::missing_actions(keybindings_path.as_path()).is_empty());
+    }
+
+    #[test]
+    fn test_system_keybindings_provider_migrate_move_keys() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let keybindings_path: PathBuf = get_keybindings_path(tmp_dir.path());
+        // Written before the explorer stopped moving with the arrow, page, home and end keys implicitly
+        std::fs::write(
+            keybindings_path.as_path(),
+            r#"
+version = 9
+
+[explorer]
+move_up = "k"
+move_down = ["j", "down"]
+move_to_bottom = "shift+g"
+delete_file = "end"
+"#,
+        )
+        .unwrap();
+        let provider: KeyBindingsProvider =
+            KeyBindingsProvider::new(keybindings_path.as_path()).unwrap();
+        let explorer = &provider.keybindings().explorer;
+        assert_eq!(
+            explorer.move_up,
+            vec![
+                KeyBinding::simple(Key::Char('k')),
+                KeyBinding::simple(Key::Up)
+            ]
+        );
+        // Keys already bound aren't added again, nor to another action
+        assert_eq!(
+            explorer.move_down,
+            vec![
+                KeyBinding::simple(Key::Char('j')),
+                KeyBinding::simple(Key::Down)
+            ]
+        );
+        assert_eq!(
+            explorer.move_to_bottom,
+            vec![KeyBinding::shift(Key::Char('g'))]
+        );
+        assert_eq!(
+            explorer.delete_file,
+            vec![
+                KeyBinding::simple(Key::End),
+                KeyBinding::simple(Key::Delete),
+                KeyBinding::simple(Key::Function(8))
+            ]
+        );
+        // Actions missing from the file keep their default value
+        assert_eq!(explorer.move_up_page, vec![KeyBinding::simple(Key::PageUp)]);
+        let content = std::fs::read_to_string(keybindings_path.as_path()).unwrap();
+        assert!(content.contains(r#"move_up = ["k", "up"]"#));
+
+        // Files written since then are left as they are
+        std::fs::write(
+            keybindings_path.as_path(),
+            format!("version = {KEYBINDINGS_VERSION}\n\n[explorer]\nmove_up = \"k\"\n"),
+        )
+        .unwrap();
+        let provider: KeyBindingsProvider =
+            KeyBindingsProvider::new(keybindings_path.as_path()).unwrap();
+        assert_eq!(
+            provider.keybindings().explorer.move_up,
+            vec![KeyBinding::simple(Key::Char('k'))]
+        );
+    }
+
+    #[test]
+    fn test_system_keybindings_provider_unknown_keys() {
+        let tmp_dir: tempfile::TempDir = TempDir::new().ok().unwrap();
+        let keybindings_path: PathBuf = get_keybindings_path(tmp_dir.path());
+        std::fs::write(
+            keybindings_path.as_path(),
+            r#"
+version = 1
+leaders = "g"
+
+[explorer]
+move_upp = "k"
+move_down = "j"
+leader = "g"
+
+[explorers]
+move_up = "k"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            KeyBindingsProvider::unknown_keys(keybindings_path.as_path()),
+            vec![
+                String::from("explorer.move_upp"),
+                String::from("explorers"),
+                String::from("leaders"),
+            ]
+        );
+        // Unknown keys are ignored
+        let provider: KeyBindingsProvider =
+            KeyBindingsProvider::new(keybindings_path.as_path()).unwrap();
+        assert_eq!(
+            provider.keybindings().explorer.move_up,
+            KeyBindings::default().explorer.move_up
+        );
+        // The down key is added back by the migration from version 1
+        assert_eq!(
+            provider.keybindings().explorer.move_down,
+            vec![
+                KeyBinding::simple(Key::Char('j')),
+                KeyBinding::simple(Key::Down)
+            ]
+        );
+        // A file written by termscp has no unknown keys
+        assert!(provider.save().is_ok());
+        assert!(KeyBindingsProvider::unknown_keys(keybindings_path.as_path()).is_empty());
     }
 
     #[test]
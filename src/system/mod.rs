@@ -5,12 +5,15 @@
 // modules
 pub mod auto_update;
 pub mod bookmarks_client;
+pub mod clipboard;
 pub mod config_client;
+pub mod editor;
 pub mod environment;
 pub mod keybindings_provider;
 mod keys;
 pub mod logging;
 pub mod notifications;
 pub mod sshkey_storage;
+pub mod sync_baseline_storage;
 pub mod theme_provider;
 pub mod watcher;
@@ -19,6 +19,8 @@ pub struct SshKeyStorage {
     hosts: HashMap<String, PathBuf>,
     /// Ssh2 configuration
     ssh_config: Option<SshConfig>,
+    /// Key given for the connection, which takes precedence over any other key
+    identity_file: Option<PathBuf>,
 }
 
 impl SshKeyStorage {
@@ -27,6 +29,12 @@ impl SshKeyStorage {
         format!("{username}@{host}")
     }
 
+    /// Use `identity_file` for any host, before looking for a key in the storage
+    pub fn with_identity_file(mut self, identity_file: Option<PathBuf>) -> Self {
+        self.identity_file = identity_file;
+        self
+    }
+
     #[cfg(test)]
     /// Add a key to storage
     /// NOTE: available only for tests
@@ -71,6 +79,10 @@ impl SshKeyStorage {
 
 impl SshKeyStorageTrait for SshKeyStorage {
     fn resolve(&self, host: &str, username: &str) -> Option<PathBuf> {
+        // use the key given for the connection
+        if let Some(path) = self.identity_file.as_ref() {
+            return Some(path.clone());
+        }
         // search in termscp keys
         if let Some(path) = self.resolve_host_in_termscp_storage(host, username) {
             return Some(path.to_path_buf());
@@ -112,7 +124,11 @@ impl From<&ConfigClient> for SshKeyStorage {
             info!("Got SSH key for {}", key);
         }
         // Return storage
-        SshKeyStorage { hosts, ssh_config }
+        SshKeyStorage {
+            hosts,
+            ssh_config,
+            identity_file: None,
+        }
     }
 }
 
@@ -206,6 +222,17 @@ Host test
         );
     }
 
+    #[test]
+    fn should_resolve_identity_file_first() {
+        let mut storage =
+            SshKeyStorage::default().with_identity_file(Some(PathBuf::from("/tmp/id")));
+        storage.add_key("deskichup", "veeso", PathBuf::from("/tmp/omar"));
+        assert_eq!(
+            storage.resolve("deskichup", "veeso").unwrap(),
+            PathBuf::from("/tmp/id")
+        );
+    }
+
     /// Get paths for configuration and keys directory
     fn get_paths(dir: &Path) -> (PathBuf, PathBuf) {
         let mut k: PathBuf = PathBuf::from(dir);
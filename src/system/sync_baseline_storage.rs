@@ -0,0 +1,121 @@
+//! ## SyncBaselineStorage
+//!
+//! `sync_baseline_storage` is the module which provides an API between the sync baselines and the system
+
+use std::fs::OpenOptions;
+use std::path::{Path, PathBuf};
+
+use crate::config::serialization::{SerializerError, SerializerErrorKind, deserialize, serialize};
+use crate::config::sync::{SyncBaseline, SyncBaselines};
+
+/// SyncBaselineStorage stores the baselines of the bidirectional sync pairs
+pub struct SyncBaselineStorage {
+    baselines: SyncBaselines,
+    baselines_path: PathBuf,
+}
+
+impl SyncBaselineStorage {
+    /// Instantiates a new `SyncBaselineStorage`; the baselines file is loaded if it exists
+    pub fn new(baselines_path: &Path) -> Result<Self, SerializerError> {
+        let mut storage = Self {
+            baselines: SyncBaselines::default(),
+            baselines_path: baselines_path.to_path_buf(),
+        };
+        if baselines_path.exists() {
+            storage.load()?;
+            debug!("Read sync baselines file");
+        }
+        Ok(storage)
+    }
+
+    /// Get the baseline for the sync pair
+    pub fn get(&self, pair: &str) -> Option<&SyncBaseline> {
+        self.baselines.pairs.get(pair)
+    }
+
+    /// Set the baseline for the sync pair
+    pub fn set(&mut self, pair: &str, baseline: SyncBaseline) {
+        self.baselines.pairs.insert(pair.to_string(), baseline);
+    }
+
+    /// Load baselines from file
+    fn load(&mut self) -> Result<(), SerializerError> {
+        match OpenOptions::new()
+            .read(true)
+            .open(self.baselines_path.as_path())
+        {
+            Ok(reader) => {
+                self.baselines = deserialize(Box::new(reader))?;
+                Ok(())
+            }
+            Err(err) => {
+                error!("Failed to read sync baselines: {}", err);
+                Err(SerializerError::new_ex(
+                    SerializerErrorKind::Io,
+                    err.to_string(),
+                ))
+            }
+        }
+    }
+
+    /// Write baselines to file
+    pub fn write(&self) -> Result<(), SerializerError> {
+        match OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(self.baselines_path.as_path())
+        {
+            Ok(writer) => serialize(&self.baselines, Box::new(writer)),
+            Err(err) => {
+                error!("Failed to write sync baselines: {}", err);
+                Err(SerializerError::new_ex(
+                    SerializerErrorKind::Io,
+                    err.to_string(),
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::config::sync::SyncBaselineEntry;
+
+    #[test]
+    fn should_persist_sync_baselines() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path = tmp_dir.path().join("sync.toml");
+        let mut storage = SyncBaselineStorage::new(path.as_path()).unwrap();
+        assert!(
+            storage
+                .get("localhost:/tmp|omar@192.168.1.31:/home/omar")
+                .is_none()
+        );
+        let mut baseline = SyncBaseline::default();
+        baseline.entries.insert(
+            "docs/README.md".to_string(),
+            SyncBaselineEntry {
+                is_dir: false,
+                size: 1024,
+                mtime: Some(1700000000),
+            },
+        );
+        storage.set(
+            "localhost:/tmp|omar@192.168.1.31:/home/omar",
+            baseline.clone(),
+        );
+        assert!(storage.write().is_ok());
+        // Reload
+        let storage = SyncBaselineStorage::new(path.as_path()).unwrap();
+        assert_eq!(
+            storage.get("localhost:/tmp|omar@192.168.1.31:/home/omar"),
+            Some(&baseline)
+        );
+    }
+}
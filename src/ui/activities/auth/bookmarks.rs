@@ -10,6 +10,13 @@ use crate::filetransfer::params::{
     WebDAVProtocolParams,
 };
 
+/// Returns whether the bookmark or recent `name`, connecting to `host`, matches `filter`.
+/// The filter is a case-insensitive substring of either the name or the host
+pub(super) fn matches_filter(filter: &str, name: &str, host: &str) -> bool {
+    let filter = filter.to_lowercase();
+    name.to_lowercase().contains(&filter) || host.to_lowercase().contains(&filter)
+}
+
 impl AuthActivity {
     /// Delete bookmark
     pub(super) fn del_bookmark(&mut self, idx: usize) {
@@ -35,13 +42,25 @@ impl AuthActivity {
             {
                 // Load parameters into components
                 match form_tab {
-                    FormTab::Remote => self.load_remote_bookmark_into_gui(bookmark),
+                    FormTab::Remote => {
+                        self.loaded_bookmark = Some(key.clone());
+                        self.load_remote_bookmark_into_gui(bookmark);
+                    }
                     FormTab::HostBridge => self.load_host_bridge_bookmark_into_gui(bookmark),
                 }
             }
         }
     }
 
+    /// Get the name of the bookmark loaded into the remote form, if `params` still connect to its host
+    pub(super) fn connected_bookmark(&self, params: &FileTransferParams) -> Option<String> {
+        let name = self.loaded_bookmark.as_ref()?;
+        let bookmark = self.bookmarks_client()?.get_bookmark(name)?;
+        (bookmark.protocol == params.protocol
+            && bookmark.params.host_name() == params.params.host_name())
+        .then(|| name.clone())
+    }
+
     /// Save current input fields as a bookmark
     pub(super) fn save_bookmark(&mut self, form_tab: FormTab, name: String, save_password: bool) {
         let params = match form_tab {
@@ -104,7 +123,10 @@ impl AuthActivity {
             {
                 // Load parameters
                 match form_tab {
-                    FormTab::Remote => self.load_remote_bookmark_into_gui(bookmark),
+                    FormTab::Remote => {
+                        self.loaded_bookmark = None;
+                        self.load_remote_bookmark_into_gui(bookmark);
+                    }
                     FormTab::HostBridge => self.load_host_bridge_bookmark_into_gui(bookmark),
                 }
             }
@@ -212,7 +234,7 @@ impl AuthActivity {
     }
 
     /// Load bookmark data into the gui components
-    fn load_remote_bookmark_into_gui(&mut self, bookmark: FileTransferParams) {
+    pub(super) fn load_remote_bookmark_into_gui(&mut self, bookmark: FileTransferParams) {
         // Load parameters into components
         self.remote_protocol = bookmark.protocol;
         self.mount_remote_protocol(bookmark.protocol);
@@ -295,3 +317,27 @@ impl AuthActivity {
         self.mount_password(form_tab, &params.password);
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn should_match_any_entry_with_empty_filter() {
+        assert!(matches_filter("", "raspberry", "192.168.1.31"));
+    }
+
+    #[test]
+    fn should_match_filter_on_name_or_host() {
+        assert!(matches_filter("rasp", "raspberry", "192.168.1.31"));
+        assert!(matches_filter("168.1", "raspberry", "192.168.1.31"));
+        assert!(!matches_filter("omar", "raspberry", "192.168.1.31"));
+    }
+
+    #[test]
+    fn should_match_filter_ignoring_case() {
+        assert!(matches_filter("RASP", "Raspberry", "192.168.1.31"));
+        assert!(matches_filter("example", "web", "WWW.EXAMPLE.COM"));
+    }
+}
@@ -2,38 +2,276 @@
 //!
 //! auth activity bookmarks components
 
+use std::collections::HashSet;
+
 use tui_realm_stdlib::{Input, List, Radio};
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::event::{Key, KeyEvent, KeyModifiers};
-use tuirealm::props::{Alignment, BorderSides, BorderType, Borders, Color, InputType, TextSpan};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, BorderSides, BorderType, Borders, Color, InputType,
+    PropPayload, PropValue, TextSpan,
+};
 use tuirealm::{Component, Event, MockComponent, NoUserEvent, State, StateValue};
 
 use super::{FormMsg, Msg, UiMsg};
 use crate::ui::activities::auth::FormTab;
 
+/// Get the title of the bookmarks or recents list, showing the filter it's narrowed with
+fn list_title(title: &str, filter: &str) -> String {
+    match filter.is_empty() {
+        true => title.to_string(),
+        false => format!("{title} (filter: \"{filter}\")"),
+    }
+}
+
 // -- bookmark list
 
-#[derive(MockComponent)]
+/// Name of the group the bookmarks without a group are listed under
+const UNGROUPED: &str = "Ungrouped";
+
+/// A row of the bookmarks list
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BookmarkRow {
+    /// Header of a group, with the amount of bookmarks in it
+    Group {
+        name: String,
+        collapsed: bool,
+        len: usize,
+    },
+    /// Bookmark at the index in the bookmarks list
+    Bookmark(usize),
+}
+
+impl BookmarkRow {
+    /// Whether the cursor can stop on the row; headers of expanded groups are skipped
+    fn is_selectable(&self) -> bool {
+        match self {
+            Self::Group { collapsed, .. } => *collapsed,
+            Self::Bookmark(_) => true,
+        }
+    }
+}
+
+/// Arranges the bookmarks under collapsible group headers
+#[derive(Debug)]
+struct BookmarkGroups {
+    /// Groups sorted by name, with the indexes of their bookmarks; ungrouped bookmarks come last
+    groups: Vec<(String, Vec<usize>)>,
+    /// Names of the collapsed groups
+    collapsed: HashSet<String>,
+    /// Whether no bookmark has a group, so the list is shown without headers
+    flat: bool,
+}
+
+impl BookmarkGroups {
+    /// Arrange the bookmarks, given the group of each one of them and whether it's visible with the current filter
+    fn new(groups: &[Option<String>], visible: &[bool]) -> Self {
+        let mut by_group: Vec<(String, Vec<usize>)> = Vec::new();
+        let mut ungrouped = Vec::new();
+        for (idx, group) in groups.iter().enumerate() {
+            if !visible.get(idx).copied().unwrap_or(true) {
+                continue;
+            }
+            match group.as_deref() {
+                None => ungrouped.push(idx),
+                Some(group) => match by_group.iter_mut().find(|(name, _)| name == group) {
+                    Some((_, bookmarks)) => bookmarks.push(idx),
+                    None => by_group.push((group.to_string(), vec![idx])),
+                },
+            }
+        }
+        by_group.sort_by_key(|(name, _)| name.to_lowercase());
+        if !ungrouped.is_empty() {
+            by_group.push((UNGROUPED.to_string(), ungrouped));
+        }
+        Self {
+            groups: by_group,
+            collapsed: HashSet::new(),
+            flat: groups.iter().all(Option::is_none),
+        }
+    }
+
+    /// Get the rows to display
+    fn rows(&self) -> Vec<BookmarkRow> {
+        let mut rows = Vec::new();
+        for (name, bookmarks) in self.groups.iter() {
+            let collapsed = self.collapsed.contains(name);
+            if !self.flat {
+                rows.push(BookmarkRow::Group {
+                    name: name.clone(),
+                    collapsed,
+                    len: bookmarks.len(),
+                });
+            }
+            if !collapsed {
+                rows.extend(bookmarks.iter().copied().map(BookmarkRow::Bookmark));
+            }
+        }
+        rows
+    }
+
+    /// Get the name of the group `row` belongs to
+    fn group_of<'a>(&'a self, row: &'a BookmarkRow) -> Option<&'a str> {
+        match row {
+            BookmarkRow::Group { name, .. } => Some(name),
+            BookmarkRow::Bookmark(idx) => self
+                .groups
+                .iter()
+                .find(|(_, bookmarks)| bookmarks.contains(idx))
+                .map(|(name, _)| name.as_str()),
+        }
+    }
+
+    /// Collapse or expand `group`
+    fn toggle(&mut self, group: &str) {
+        if !self.collapsed.remove(group) {
+            self.collapsed.insert(group.to_string());
+        }
+    }
+}
+
 pub struct BookmarksList {
     component: List,
+    bookmarks: Vec<String>,
+    groups: BookmarkGroups,
+    rows: Vec<BookmarkRow>,
 }
 
 impl BookmarksList {
-    pub fn new(bookmarks: &[String], color: Color) -> Self {
-        Self {
+    pub fn new(
+        bookmarks: &[String],
+        groups: &[Option<String>],
+        visible: &[bool],
+        filter: &str,
+        color: Color,
+    ) -> Self {
+        let groups = BookmarkGroups::new(groups, visible);
+        let rows = groups.rows();
+        let mut list = Self {
             component: List::default()
                 .borders(Borders::default().color(color).modifiers(BorderType::Plain))
                 .highlighted_color(color)
                 .rewind(true)
                 .scroll(true)
                 .step(4)
-                .title("Bookmarks", Alignment::Left)
-                .rows(
-                    bookmarks
-                        .iter()
-                        .map(|x| vec![TextSpan::from(x.as_str())])
-                        .collect(),
-                ),
+                .title(list_title("Bookmarks", filter), Alignment::Left)
+                .rows(Self::make_rows(bookmarks, &rows, groups.flat)),
+            bookmarks: bookmarks.to_vec(),
+            groups,
+            rows,
+        };
+        list.skip_headers(Direction::Down);
+        list
+    }
+
+    fn make_rows(bookmarks: &[String], rows: &[BookmarkRow], flat: bool) -> Vec<Vec<TextSpan>> {
+        rows.iter()
+            .map(|row| match row {
+                BookmarkRow::Group {
+                    name,
+                    collapsed,
+                    len,
+                } => {
+                    let arrow = if *collapsed { "▶" } else { "▼" };
+                    vec![TextSpan::from(format!("{arrow} {name} ({len})")).bold()]
+                }
+                BookmarkRow::Bookmark(idx) if flat => {
+                    vec![TextSpan::from(bookmarks[*idx].as_str())]
+                }
+                BookmarkRow::Bookmark(idx) => {
+                    vec![TextSpan::from(format!("  {}", bookmarks[*idx]))]
+                }
+            })
+            .collect()
+    }
+
+    /// Get the highlighted row
+    fn selected_row(&self) -> Option<&BookmarkRow> {
+        match self.component.state() {
+            State::One(StateValue::Usize(idx)) => self.rows.get(idx),
+            _ => None,
+        }
+    }
+
+    /// Move the cursor towards `direction` until it's on a selectable row
+    fn skip_headers(&mut self, direction: Direction) {
+        for _ in 0..self.rows.len() {
+            if self.selected_row().is_none_or(BookmarkRow::is_selectable) {
+                break;
+            }
+            self.component.perform(Cmd::Move(direction));
+        }
+    }
+
+    /// Collapse or expand the group of the highlighted row, keeping the cursor on its header
+    fn toggle(&mut self) {
+        if self.groups.flat {
+            return;
+        }
+        let Some(group) = self
+            .selected_row()
+            .and_then(|row| self.groups.group_of(row))
+            .map(str::to_string)
+        else {
+            return;
+        };
+        self.groups.toggle(&group);
+        self.rows = self.groups.rows();
+        let header = self
+            .rows
+            .iter()
+            .position(|row| matches!(row, BookmarkRow::Group { name, .. } if *name == group))
+            .unwrap_or_default();
+        self.component.attr(
+            Attribute::Content,
+            AttrValue::Table(Self::make_rows(&self.bookmarks, &self.rows, false)),
+        );
+        self.component.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(header))),
+        );
+        self.skip_headers(Direction::Down);
+    }
+}
+
+impl MockComponent for BookmarksList {
+    fn view(&mut self, frame: &mut tuirealm::Frame, area: tuirealm::ratatui::layout::Rect) {
+        self.component.view(frame, area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.component.query(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.component.attr(attr, value);
+    }
+
+    /// Returns the index of the highlighted bookmark; `State::None` if a group header is highlighted
+    fn state(&self) -> State {
+        match self.selected_row() {
+            Some(BookmarkRow::Bookmark(idx)) => State::One(StateValue::Usize(*idx)),
+            _ => State::None,
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        match cmd {
+            Cmd::Toggle => {
+                self.toggle();
+                CmdResult::Changed(self.state())
+            }
+            Cmd::Move(Direction::Up) => {
+                self.component.perform(cmd);
+                self.skip_headers(Direction::Up);
+                CmdResult::Changed(self.state())
+            }
+            Cmd::Move(_) | Cmd::Scroll(_) | Cmd::GoTo(_) => {
+                self.component.perform(cmd);
+                self.skip_headers(Direction::Down);
+                CmdResult::Changed(self.state())
+            }
+            cmd => self.component.perform(cmd),
         }
     }
 }
@@ -111,12 +349,29 @@ impl Component<Msg, NoUserEvent> for BookmarksList {
             | Event::Keyboard(KeyEvent {
                 code: Key::Char('l'),
                 ..
-            }) => match self.state() {
-                State::One(StateValue::Usize(choice)) => {
-                    Some(Msg::Form(FormMsg::LoadBookmark(choice)))
+            }) => match self.selected_row() {
+                Some(BookmarkRow::Bookmark(choice)) => {
+                    Some(Msg::Form(FormMsg::LoadBookmark(*choice)))
                 }
-                _ => Some(Msg::None),
+                Some(BookmarkRow::Group { .. }) => {
+                    self.perform(Cmd::Toggle);
+                    Some(Msg::None)
+                }
+                None => Some(Msg::None),
             },
+            // Slash - filter bookmarks
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('/'),
+                ..
+            }) => Some(Msg::Ui(UiMsg::ShowBookmarksFilter)),
+            // Space - collapse / expand group
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(' '),
+                ..
+            }) => {
+                self.perform(Cmd::Toggle);
+                Some(Msg::None)
+            }
             // Right - blur to recents
             Event::Keyboard(KeyEvent {
                 code: Key::Right, ..
@@ -132,7 +387,10 @@ impl Component<Msg, NoUserEvent> for BookmarksList {
             | Event::Keyboard(KeyEvent {
                 code: Key::Char('d'),
                 modifiers: KeyModifiers::NONE,
-            }) => Some(Msg::Ui(UiMsg::ShowDeleteBookmarkPopup)),
+            }) => match self.selected_row() {
+                Some(BookmarkRow::Bookmark(_)) => Some(Msg::Ui(UiMsg::ShowDeleteBookmarkPopup)),
+                _ => Some(Msg::None),
+            },
             _ => None,
         }
     }
@@ -140,13 +398,17 @@ impl Component<Msg, NoUserEvent> for BookmarksList {
 
 // -- recents list
 
-#[derive(MockComponent)]
 pub struct RecentsList {
     component: List,
+    /// Indexes in the recents list of the visible entries
+    indexes: Vec<usize>,
 }
 
 impl RecentsList {
-    pub fn new(bookmarks: &[String], color: Color) -> Self {
+    pub fn new(recents: &[String], visible: &[bool], filter: &str, color: Color) -> Self {
+        let indexes: Vec<usize> = (0..recents.len())
+            .filter(|idx| visible.get(*idx).copied().unwrap_or(true))
+            .collect();
         Self {
             component: List::default()
                 .borders(Borders::default().color(color).modifiers(BorderType::Plain))
@@ -154,17 +416,47 @@ impl RecentsList {
                 .rewind(true)
                 .scroll(true)
                 .step(4)
-                .title("Recent connections", Alignment::Left)
+                .title(list_title("Recent connections", filter), Alignment::Left)
                 .rows(
-                    bookmarks
+                    indexes
                         .iter()
-                        .map(|x| vec![TextSpan::from(x.as_str())])
+                        .map(|x| vec![TextSpan::from(recents[*x].as_str())])
                         .collect(),
                 ),
+            indexes,
         }
     }
 }
 
+impl MockComponent for RecentsList {
+    fn view(&mut self, frame: &mut tuirealm::Frame, area: tuirealm::ratatui::layout::Rect) {
+        self.component.view(frame, area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.component.query(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.component.attr(attr, value);
+    }
+
+    /// Returns the index in the recents list of the highlighted entry
+    fn state(&self) -> State {
+        match self.component.state() {
+            State::One(StateValue::Usize(idx)) => match self.indexes.get(idx) {
+                Some(idx) => State::One(StateValue::Usize(*idx)),
+                None => State::None,
+            },
+            state => state,
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        self.component.perform(cmd)
+    }
+}
+
 impl Component<Msg, NoUserEvent> for RecentsList {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
@@ -263,7 +555,15 @@ impl Component<Msg, NoUserEvent> for RecentsList {
             | Event::Keyboard(KeyEvent {
                 code: Key::Char('d'),
                 modifiers: KeyModifiers::NONE,
-            }) => Some(Msg::Ui(UiMsg::ShowDeleteRecentPopup)),
+            }) => match self.state() {
+                State::One(_) => Some(Msg::Ui(UiMsg::ShowDeleteRecentPopup)),
+                _ => Some(Msg::None),
+            },
+            // Slash - filter recents
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('/'),
+                ..
+            }) => Some(Msg::Ui(UiMsg::ShowBookmarksFilter)),
             _ => None,
         }
     }
@@ -466,6 +766,95 @@ impl Component<Msg, NoUserEvent> for BookmarkSavePassword {
 
 // -- new bookmark name
 
+#[derive(MockComponent)]
+pub struct BookmarksFilter {
+    component: Input,
+}
+
+impl BookmarksFilter {
+    pub fn new(filter: &str, color: Color) -> Self {
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .title(
+                    "Filter bookmarks (<ENTER> done, <ESC> clear)",
+                    Alignment::Left,
+                )
+                .input_type(InputType::Text)
+                .value(filter),
+        }
+    }
+
+    /// Get the message to filter the bookmarks with the current input
+    fn filter_msg(&self) -> Msg {
+        match self.state() {
+            State::One(StateValue::String(filter)) => Msg::Ui(UiMsg::FilterBookmarks(filter)),
+            _ => Msg::None,
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for BookmarksFilter {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::ClearBookmarksFilter))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => Some(Msg::Ui(UiMsg::CloseBookmarksFilter)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Delete, ..
+            }) => {
+                self.perform(Cmd::Cancel);
+                Some(self.filter_msg())
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Backspace,
+                ..
+            }) => {
+                self.perform(Cmd::Delete);
+                Some(self.filter_msg())
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch),
+                ..
+            }) => {
+                self.perform(Cmd::Type(ch));
+                Some(self.filter_msg())
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(MockComponent)]
 pub struct BookmarkName {
     component: Input,
@@ -548,3 +937,165 @@ impl Component<Msg, NoUserEvent> for BookmarkName {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn group(name: &str) -> Option<String> {
+        Some(name.to_string())
+    }
+
+    #[test]
+    fn should_list_bookmarks_without_headers_if_there_are_no_groups() {
+        let groups = BookmarkGroups::new(&[None, None], &[true; 2]);
+        assert_eq!(
+            groups.rows(),
+            vec![BookmarkRow::Bookmark(0), BookmarkRow::Bookmark(1)]
+        );
+    }
+
+    #[test]
+    fn should_list_bookmarks_under_group_headers() {
+        let groups = BookmarkGroups::new(
+            &[group("work"), None, group("Home"), group("work")],
+            &[true; 4],
+        );
+        assert_eq!(
+            groups.rows(),
+            vec![
+                BookmarkRow::Group {
+                    name: String::from("Home"),
+                    collapsed: false,
+                    len: 1,
+                },
+                BookmarkRow::Bookmark(2),
+                BookmarkRow::Group {
+                    name: String::from("work"),
+                    collapsed: false,
+                    len: 2,
+                },
+                BookmarkRow::Bookmark(0),
+                BookmarkRow::Bookmark(3),
+                BookmarkRow::Group {
+                    name: String::from(UNGROUPED),
+                    collapsed: false,
+                    len: 1,
+                },
+                BookmarkRow::Bookmark(1),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_list_only_visible_bookmarks() {
+        let groups =
+            BookmarkGroups::new(&[group("work"), None, group("home")], &[true, true, false]);
+        assert_eq!(
+            groups.rows(),
+            vec![
+                BookmarkRow::Group {
+                    name: String::from("work"),
+                    collapsed: false,
+                    len: 1,
+                },
+                BookmarkRow::Bookmark(0),
+                BookmarkRow::Group {
+                    name: String::from(UNGROUPED),
+                    collapsed: false,
+                    len: 1,
+                },
+                BookmarkRow::Bookmark(1),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_collapse_and_expand_groups() {
+        let mut groups = BookmarkGroups::new(&[group("work"), None, group("work")], &[true; 3]);
+        groups.toggle("work");
+        assert_eq!(
+            groups.rows(),
+            vec![
+                BookmarkRow::Group {
+                    name: String::from("work"),
+                    collapsed: true,
+                    len: 2,
+                },
+                BookmarkRow::Group {
+                    name: String::from(UNGROUPED),
+                    collapsed: false,
+                    len: 1,
+                },
+                BookmarkRow::Bookmark(1),
+            ]
+        );
+        groups.toggle("work");
+        assert_eq!(groups.rows().len(), 5);
+    }
+
+    #[test]
+    fn should_tell_group_of_rows() {
+        let groups = BookmarkGroups::new(&[group("work"), None], &[true; 2]);
+        assert_eq!(groups.group_of(&BookmarkRow::Bookmark(0)), Some("work"));
+        assert_eq!(groups.group_of(&BookmarkRow::Bookmark(1)), Some(UNGROUPED));
+        assert_eq!(groups.group_of(&BookmarkRow::Bookmark(2)), None);
+    }
+
+    #[test]
+    fn should_skip_headers_of_expanded_groups() {
+        let expanded = BookmarkRow::Group {
+            name: String::from("work"),
+            collapsed: false,
+            len: 1,
+        };
+        let collapsed = BookmarkRow::Group {
+            name: String::from("work"),
+            collapsed: true,
+            len: 1,
+        };
+        assert!(!expanded.is_selectable());
+        assert!(collapsed.is_selectable());
+        assert!(BookmarkRow::Bookmark(0).is_selectable());
+    }
+
+    #[test]
+    fn should_move_cursor_over_headers() {
+        let mut list = BookmarksList::new(
+            &[String::from("a"), String::from("b"), String::from("c")],
+            &[group("work"), None, group("work")],
+            &[true; 3],
+            "",
+            Color::Reset,
+        );
+        // Starts on the first bookmark, not on the header
+        assert_eq!(list.state(), State::One(StateValue::Usize(0)));
+        list.perform(Cmd::Move(Direction::Down));
+        assert_eq!(list.state(), State::One(StateValue::Usize(2)));
+        // Skips the "Ungrouped" header
+        list.perform(Cmd::Move(Direction::Down));
+        assert_eq!(list.state(), State::One(StateValue::Usize(1)));
+        list.perform(Cmd::Move(Direction::Up));
+        assert_eq!(list.state(), State::One(StateValue::Usize(2)));
+        // Collapse "work": the cursor stays on its header
+        list.perform(Cmd::Toggle);
+        assert_eq!(list.state(), State::None);
+        assert_eq!(
+            list.selected_row(),
+            Some(&BookmarkRow::Group {
+                name: String::from("work"),
+                collapsed: true,
+                len: 2,
+            })
+        );
+        list.perform(Cmd::Move(Direction::Down));
+        assert_eq!(list.state(), State::One(StateValue::Usize(1)));
+        // Expand it back
+        list.perform(Cmd::Move(Direction::Up));
+        list.perform(Cmd::Toggle);
+        assert_eq!(list.state(), State::One(StateValue::Usize(0)));
+    }
+}
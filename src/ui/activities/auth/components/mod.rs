@@ -11,8 +11,8 @@ mod popup;
 mod text;
 
 pub use bookmarks::{
-    BookmarkName, BookmarkSavePassword, BookmarksList, DeleteBookmarkPopup, DeleteRecentPopup,
-    RecentsList,
+    BookmarkName, BookmarkSavePassword, BookmarksFilter, BookmarksList, DeleteBookmarkPopup,
+    DeleteRecentPopup, RecentsList,
 };
 #[cfg(posix)]
 pub use form::InputSmbWorkgroup;
@@ -93,6 +93,10 @@ impl Component<Msg, NoUserEvent> for GlobalListener {
                 if binding_matches(key_ev, &self.auth_keys.save_bookmark) {
                     return Some(Msg::Ui(UiMsg::ShowSaveBookmarkPopup));
                 }
+                // Reconnect
+                if binding_matches(key_ev, &self.auth_keys.reconnect) {
+                    return Some(Msg::Form(FormMsg::Reconnect));
+                }
                 None
             }
             Event::WindowResize(_, _) => Some(Msg::Ui(UiMsg::WindowResized)),
@@ -5,10 +5,12 @@
 use tui_realm_stdlib::{List, Paragraph, Radio, Textarea};
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::event::{Key, KeyEvent, KeyModifiers};
-use tuirealm::props::{Alignment, BorderType, Borders, Color, TableBuilder, TextSpan};
+use tuirealm::props::{Alignment, BorderType, Borders, Color, TextSpan};
 use tuirealm::{Component, Event, MockComponent, NoUserEvent, State, StateValue};
 
 use super::{FormMsg, Msg, UiMsg};
+use crate::config::keybindings::KeyBindings;
+use crate::utils::ui::keybindings_help_table;
 
 // -- error popup
 
@@ -354,7 +356,7 @@ pub struct Keybindings {
 }
 
 impl Keybindings {
-    pub fn new(color: Color) -> Self {
+    pub fn new(color: Color, keybindings: &KeyBindings) -> Self {
         Self {
             component: List::default()
                 .borders(
@@ -366,33 +368,7 @@ impl Keybindings {
                 .title("Keybindings", Alignment::Center)
                 .scroll(true)
                 .step(4)
-                .rows(
-                    TableBuilder::default()
-                        .add_col(TextSpan::new("<ESC>").bold().fg(color))
-                        .add_col(TextSpan::from("           Quit termscp"))
-                        .add_row()
-                        .add_col(TextSpan::new("<TAB>").bold().fg(color))
-                        .add_col(TextSpan::from("           Switch from form and bookmarks"))
-                        .add_row()
-                        .add_col(TextSpan::new("<RIGHT/LEFT>").bold().fg(color))
-                        .add_col(TextSpan::from("    Switch bookmark tab"))
-                        .add_row()
-                        .add_col(TextSpan::new("<UP/DOWN>").bold().fg(color))
-                        .add_col(TextSpan::from("       Move up/down in current tab"))
-                        .add_row()
-                        .add_col(TextSpan::new("<ENTER>").bold().fg(color))
-                        .add_col(TextSpan::from("         Connect/Load bookmark"))
-                        .add_row()
-                        .add_col(TextSpan::new("<DEL|E>").bold().fg(color))
-                        .add_col(TextSpan::from("         Delete selected bookmark"))
-                        .add_row()
-                        .add_col(TextSpan::new("<CTRL+C>").bold().fg(color))
-                        .add_col(TextSpan::from("        Enter setup"))
-                        .add_row()
-                        .add_col(TextSpan::new("<CTRL+S>").bold().fg(color))
-                        .add_col(TextSpan::from("        Save bookmark"))
-                        .build(),
-                ),
+                .rows(keybindings_help_table(&keybindings.auth_help(), color)),
         }
     }
 }
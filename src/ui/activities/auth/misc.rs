@@ -4,7 +4,10 @@
 
 use std::env;
 
-use super::{AuthActivity, FileTransferParams, FileTransferProtocol, FormTab, HostBridgeProtocol};
+use super::{
+    AuthActivity, AuthFormId, ExitReason, FileTransferParams, FileTransferProtocol, FormTab,
+    HostBridgeProtocol, Id,
+};
 use crate::filetransfer::HostBridgeParams;
 use crate::filetransfer::params::ProtocolParams;
 use crate::system::auto_update::{Release, Update, UpdateStatus};
@@ -261,4 +264,27 @@ impl AuthActivity {
             }
         }
     }
+
+    /// Connect again with the params of the last session.
+    /// If the password of the session is not known, the params are loaded into the form to type it in
+    pub(super) fn reconnect(&mut self) {
+        let Some(last) = self.context().last_connection().cloned() else {
+            self.mount_error("There is no previous session to reconnect to");
+            return;
+        };
+        if last.remote_params.params.password_missing() {
+            self.loaded_bookmark = last.bookmark_name;
+            self.load_remote_bookmark_into_gui(last.remote_params);
+            self.last_form_tab = FormTab::Remote;
+            assert!(self.app.active(&Id::Remote(AuthFormId::Password)).is_ok());
+            self.mount_info("Type in the password and press <ENTER> to reconnect");
+            return;
+        }
+        info!("Reconnecting to {}", last.remote_params.params.host_name());
+        let ctx = self.context_mut();
+        ctx.set_bookmark_name(last.bookmark_name);
+        ctx.set_remote_params(last.remote_params);
+        ctx.set_host_bridge_params(last.host_bridge_params);
+        self.exit_reason = Some(ExitReason::Connect);
+    }
 }
@@ -50,6 +50,7 @@ const REMOTE_RADIO_PROTOCOL_SMB: usize = 7; // Keep as last
 pub enum Id {
     BookmarkName,
     BookmarkSavePassword,
+    BookmarksFilter,
     BookmarksList,
     DeleteBookmarkPopup,
     DeleteRecentPopup,
@@ -119,6 +120,7 @@ enum FormMsg {
     HostBridgeProtocolChanged(HostBridgeProtocol),
     RemoteProtocolChanged(FileTransferProtocol),
     Quit,
+    Reconnect,
     SaveBookmark(FormTab),
 }
 
@@ -126,6 +128,8 @@ enum FormMsg {
 pub enum UiMsg {
     BookmarksListBlur,
     BookmarksTabBlur,
+    ClearBookmarksFilter,
+    CloseBookmarksFilter,
     CloseDeleteBookmark,
     CloseDeleteRecent,
     CloseErrorPopup,
@@ -134,11 +138,13 @@ pub enum UiMsg {
     CloseKeybindingsPopup,
     CloseQuitPopup,
     CloseSaveBookmark,
+    FilterBookmarks(String),
     HostBridge(UiAuthFormMsg),
     RececentsListBlur,
     Remote(UiAuthFormMsg),
     BookmarkNameBlur,
     SaveBookmarkPasswordBlur,
+    ShowBookmarksFilter,
     ShowDeleteBookmarkPopup,
     ShowDeleteRecentPopup,
     ShowKeybindingsPopup,
@@ -236,6 +242,10 @@ pub struct AuthActivity {
     app: Application<Id, Msg, NoUserEvent>,
     /// List of bookmarks
     bookmarks_list: Vec<String>,
+    /// Filter the bookmarks and the recents lists are narrowed with
+    bookmarks_filter: String,
+    /// Name of the bookmark loaded into the remote form, if any
+    loaded_bookmark: Option<String>,
     /// List of recent hosts
     recents_list: Vec<String>,
     /// Exit reason
@@ -261,8 +271,10 @@ impl AuthActivity {
             ),
             context: None,
             bookmarks_list: Vec::new(),
+            bookmarks_filter: String::new(),
             exit_reason: None,
             last_form_tab: FormTab::Remote,
+            loaded_bookmark: None,
             recents_list: Vec::new(),
             redraw: true,
             host_bridge_protocol: HostBridgeProtocol::Localhost,
@@ -360,6 +372,11 @@ impl Activity for AuthActivity {
             self.view_bookmarks();
             self.view_recent_connections();
         }
+        // Prefill form after a failed authentication
+        if let Some(params) = self.context_mut().retry_params() {
+            self.loaded_bookmark = self.context().bookmark_name().map(String::from);
+            self.load_remote_bookmark_into_gui(params);
+        }
         // Verify error state from context
         if let Some(err) = self.context_mut().error() {
             self.mount_error(err.as_str());
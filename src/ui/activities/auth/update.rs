@@ -40,6 +40,9 @@ impl AuthActivity {
                 debug!("Host bridge params: {:?}", host_bridge_params);
 
                 self.save_recent();
+                // Remember the bookmark to save the last directories to on disconnect
+                let bookmark_name = self.connected_bookmark(&remote_params);
+                self.context_mut().set_bookmark_name(bookmark_name);
                 // Set file transfer params to context
                 self.context_mut().set_remote_params(remote_params);
                 // set host bridge params
@@ -149,6 +152,7 @@ impl AuthActivity {
             FormMsg::Quit => {
                 self.exit_reason = Some(ExitReason::Quit);
             }
+            FormMsg::Reconnect => self.reconnect(),
             FormMsg::SaveBookmark(form_tab) => {
                 // get bookmark name
                 let (name, save_password) = self.get_new_bookmark();
@@ -218,6 +222,15 @@ impl AuthActivity {
                         .is_ok()
                 );
             }
+            UiMsg::ClearBookmarksFilter => {
+                self.bookmarks_filter.clear();
+                self.view_bookmarks();
+                self.view_recent_connections();
+                self.umount_bookmarks_filter();
+            }
+            UiMsg::CloseBookmarksFilter => {
+                self.umount_bookmarks_filter();
+            }
             UiMsg::CloseDeleteBookmark => {
                 assert!(self.app.umount(&Id::DeleteBookmarkPopup).is_ok());
             }
@@ -242,6 +255,11 @@ impl AuthActivity {
                 assert!(self.app.umount(&Id::BookmarkName).is_ok());
                 assert!(self.app.umount(&Id::BookmarkSavePassword).is_ok());
             }
+            UiMsg::FilterBookmarks(filter) => {
+                self.bookmarks_filter = filter;
+                self.view_bookmarks();
+                self.view_recent_connections();
+            }
             UiMsg::HostBridge(UiAuthFormMsg::LocalDirectoryBlurDown) => {
                 assert!(
                     self.app
@@ -869,6 +887,9 @@ impl AuthActivity {
             UiMsg::SaveBookmarkPasswordBlur => {
                 assert!(self.app.active(&Id::BookmarkName).is_ok());
             }
+            UiMsg::ShowBookmarksFilter => {
+                self.mount_bookmarks_filter();
+            }
             UiMsg::ShowDeleteBookmarkPopup => {
                 self.mount_bookmark_del_dialog();
             }
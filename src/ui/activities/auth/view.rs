@@ -12,6 +12,7 @@ use tuirealm::ratatui::widgets::Clear;
 use tuirealm::event::NoUserEvent;
 use tuirealm::{State, StateValue, Sub, SubClause, SubEventClause};
 
+use super::bookmarks::matches_filter;
 use super::{
     AuthActivity, AuthFormId, Context, FileTransferProtocol, FormTab, HostBridgeProtocol, Id,
     InputMask, components,
@@ -274,6 +275,11 @@ impl AuthActivity {
                 let popup = Popup(Size::Percentage(50), Size::Percentage(70)).draw_in(f.area());
                 f.render_widget(Clear, popup);
                 self.app.view(&Id::Keybindings, f, popup);
+            } else if self.app.mounted(&Id::BookmarksFilter) {
+                // make popup
+                let popup = Popup(Size::Percentage(40), Size::Unit(3)).draw_in(f.area());
+                f.render_widget(Clear, popup);
+                self.app.view(&Id::BookmarksFilter, f, popup);
             } else if self.app.mounted(&Id::BookmarkSavePassword) {
                 // make popup
                 let popup = Popup(Size::Percentage(20), Size::Percentage(20)).draw_in(f.area());
@@ -459,12 +465,36 @@ impl AuthActivity {
                 Self::fmt_bookmark(x, self.bookmarks_client().unwrap().get_bookmark(x).unwrap())
             })
             .collect();
+        let visible: Vec<bool> = self
+            .bookmarks_list
+            .iter()
+            .map(|x| {
+                let params = self.bookmarks_client().unwrap().get_bookmark(x).unwrap();
+                matches_filter(&self.bookmarks_filter, x, &params.params.host_name())
+            })
+            .collect();
+        let groups: Vec<Option<String>> = self
+            .bookmarks_list
+            .iter()
+            .map(|x| {
+                self.bookmarks_client()
+                    .unwrap()
+                    .get_bookmark_group(x)
+                    .map(str::to_string)
+            })
+            .collect();
         let bookmarks_color = self.theme().auth_bookmarks;
         assert!(
             self.app
                 .remount(
                     Id::BookmarksList,
-                    Box::new(components::BookmarksList::new(&bookmarks, bookmarks_color)),
+                    Box::new(components::BookmarksList::new(
+                        &bookmarks,
+                        &groups,
+                        &visible,
+                        &self.bookmarks_filter,
+                        bookmarks_color
+                    )),
                     vec![]
                 )
                 .is_ok()
@@ -478,12 +508,25 @@ impl AuthActivity {
             .iter()
             .map(|x| Self::fmt_recent(self.bookmarks_client().unwrap().get_recent(x).unwrap()))
             .collect();
+        let visible: Vec<bool> = self
+            .recents_list
+            .iter()
+            .map(|x| {
+                let params = self.bookmarks_client().unwrap().get_recent(x).unwrap();
+                matches_filter(&self.bookmarks_filter, x, &params.params.host_name())
+            })
+            .collect();
         let recents_color = self.theme().auth_recents;
         assert!(
             self.app
                 .remount(
                     Id::RecentsList,
-                    Box::new(components::RecentsList::new(&bookmarks, recents_color)),
+                    Box::new(components::RecentsList::new(
+                        &bookmarks,
+                        &visible,
+                        &self.bookmarks_filter,
+                        recents_color
+                    )),
                     vec![]
                 )
                 .is_ok()
@@ -665,14 +708,38 @@ impl AuthActivity {
         let _ = self.app.umount(&Id::BookmarkSavePassword);
     }
 
+    /// Mount the input to filter the bookmarks and the recents with
+    pub(super) fn mount_bookmarks_filter(&mut self) {
+        let color = self.theme().auth_bookmarks;
+        assert!(
+            self.app
+                .remount(
+                    Id::BookmarksFilter,
+                    Box::new(components::BookmarksFilter::new(
+                        &self.bookmarks_filter,
+                        color
+                    )),
+                    vec![]
+                )
+                .is_ok()
+        );
+        assert!(self.app.active(&Id::BookmarksFilter).is_ok());
+    }
+
+    /// Umount the bookmarks filter input
+    pub(super) fn umount_bookmarks_filter(&mut self) {
+        let _ = self.app.umount(&Id::BookmarksFilter);
+    }
+
     /// Mount keybindings
     pub(super) fn mount_keybindings(&mut self) {
         let key_color = self.theme().misc_keys;
+        let keybindings = self.keybindings().clone();
         assert!(
             self.app
                 .remount(
                     Id::Keybindings,
-                    Box::new(components::Keybindings::new(key_color)),
+                    Box::new(components::Keybindings::new(key_color, &keybindings)),
                     vec![]
                 )
                 .is_ok()
@@ -2007,6 +2074,7 @@ impl AuthActivity {
             &auth_keys.help_alt,
             &auth_keys.release_notes,
             &auth_keys.save_bookmark,
+            &auth_keys.reconnect,
         ];
         
         let mut seen = HashSet::new();
@@ -2055,6 +2123,7 @@ impl AuthActivity {
             Id::DeleteRecentPopup,
             Id::InstallUpdatePopup,
             Id::BookmarkSavePassword,
+            Id::BookmarksFilter,
             Id::WaitPopup
         )
     }
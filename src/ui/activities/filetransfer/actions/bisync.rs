@@ -0,0 +1,223 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+// locals
+use std::path::{Path, PathBuf};
+
+use super::walkdir::{WalkdirError, WalkdirTree};
+use super::{File, FileTransferActivity, LogLevel, Msg, PendingActionMsg, TransferPayload};
+use crate::system::environment;
+use crate::system::sync_baseline_storage::SyncBaselineStorage;
+use crate::ui::activities::filetransfer::lib::bisync::{
+    BisyncPlan, baseline_entries, make_baseline,
+};
+use crate::ui::activities::filetransfer::lib::mirror::MirrorEntry;
+
+impl FileTransferActivity {
+    /// Synchronize the working directories of the two panels in both directions,
+    /// reconciling the changes made on each side since the last sync.
+    ///
+    /// Entries changed on both sides are reported as conflicts, which the user resolves one by one
+    pub(crate) fn action_bisync(&mut self) {
+        let local_root = self.host_bridge().wrkdir.clone();
+        let remote_root = self.remote().wrkdir.clone();
+        let pair = format!(
+            "{}:{}|{}:{}",
+            self.get_hostbridge_hostname(),
+            local_root.display(),
+            self.get_remote_hostname(),
+            remote_root.display()
+        );
+        let mut storage = match Self::init_sync_baseline_storage() {
+            Ok(storage) => storage,
+            Err(err) => {
+                self.log_and_alert(
+                    LogLevel::Error,
+                    format!("Could not load sync baselines: {err}"),
+                );
+                return;
+            }
+        };
+        // Scan both trees
+        let (local, remote) = match self.bisync_scan(&local_root, &remote_root) {
+            Some(trees) => trees,
+            None => return,
+        };
+        let baseline = storage.get(&pair).map(baseline_entries).unwrap_or_default();
+        let mut plan = BisyncPlan::new(
+            &Self::bisync_entries(&local),
+            &Self::bisync_entries(&remote),
+            &baseline,
+        );
+        if plan.is_empty() {
+            self.mount_info("Directories are already in sync");
+            return;
+        }
+        // Resolve conflicts
+        for path in std::mem::take(&mut plan.conflicts) {
+            self.mount_sync_conflict_popup(path.as_path());
+            let msg = self.wait_for_pending_msg(&[
+                Msg::PendingAction(PendingActionMsg::SyncKeepLocal),
+                Msg::PendingAction(PendingActionMsg::SyncKeepRemote),
+                Msg::PendingAction(PendingActionMsg::SyncSkip),
+                Msg::PendingAction(PendingActionMsg::SyncCancel),
+            ]);
+            self.umount_sync_conflict_popup();
+            match msg {
+                Msg::PendingAction(PendingActionMsg::SyncKeepLocal) => {
+                    if local.contains_key(&path) {
+                        plan.upload.push(path);
+                    } else {
+                        plan.delete_remote.push(path);
+                    }
+                }
+                Msg::PendingAction(PendingActionMsg::SyncKeepRemote) => {
+                    if remote.contains_key(&path) {
+                        plan.download.push(path);
+                    } else {
+                        plan.delete_local.push(path);
+                    }
+                }
+                Msg::PendingAction(PendingActionMsg::SyncSkip) => {}
+                _ => return,
+            }
+        }
+        // Ask for confirmation
+        self.mount_sync_popup(&plan);
+        let confirmed = self.wait_for_pending_msg(&[
+            Msg::PendingAction(PendingActionMsg::Sync),
+            Msg::PendingAction(PendingActionMsg::SyncCancel),
+        ]) == Msg::PendingAction(PendingActionMsg::Sync);
+        self.umount_sync_popup();
+        if !confirmed {
+            return;
+        }
+        // Apply
+        if let Err(err) = self.bisync_transfer(&plan.upload, &local, &remote, &remote_root, true) {
+            self.log_and_alert(LogLevel::Error, format!("Could not sync: {err}"));
+            return;
+        }
+        if let Err(err) = self.bisync_transfer(&plan.download, &remote, &local, &local_root, false)
+        {
+            self.log_and_alert(LogLevel::Error, format!("Could not sync: {err}"));
+            return;
+        }
+        for (_, file) in plan.delete_local.iter().filter_map(|p| local.get(p)) {
+            self.local_remove_file(file);
+        }
+        for (_, file) in plan.delete_remote.iter().filter_map(|p| remote.get(p)) {
+            self.remote_remove_file(file);
+        }
+        // Store the new baseline
+        let (local, remote) = match self.bisync_scan(&local_root, &remote_root) {
+            Some(trees) => trees,
+            None => return,
+        };
+        storage.set(
+            &pair,
+            make_baseline(
+                &Self::bisync_entries(&local),
+                &Self::bisync_entries(&remote),
+            ),
+        );
+        if let Err(err) = storage.write() {
+            self.log_and_alert(
+                LogLevel::Error,
+                format!("Could not save sync baseline: {err}"),
+            );
+            return;
+        }
+        self.log(
+            LogLevel::Info,
+            format!(
+                "Synchronized \"{}\" with \"{}\"",
+                local_root.display(),
+                remote_root.display()
+            ),
+        );
+    }
+
+    /// Walk both trees; returns `None` if the scan failed or was aborted
+    fn bisync_scan(
+        &mut self,
+        local_root: &Path,
+        remote_root: &Path,
+    ) -> Option<(WalkdirTree, WalkdirTree)> {
+        self.mount_walkdir_wait();
+        let trees = self
+            .action_walkdir_local()
+            .and_then(|local| self.action_walkdir_remote().map(|remote| (local, remote)));
+        self.umount_wait();
+        match trees {
+            Ok((local, remote)) => Some((
+                Self::walkdir_tree(local_root, local),
+                Self::walkdir_tree(remote_root, remote),
+            )),
+            Err(WalkdirError::Error(err)) => {
+                self.mount_error(err.as_str());
+                None
+            }
+            Err(WalkdirError::Aborted) => {
+                self.mount_info("Sync aborted");
+                None
+            }
+        }
+    }
+
+    /// Transfer `paths` from `src` to the `dest_root` tree
+    fn bisync_transfer(
+        &mut self,
+        paths: &[PathBuf],
+        src: &WalkdirTree,
+        dest: &WalkdirTree,
+        dest_root: &Path,
+        upload: bool,
+    ) -> Result<(), String> {
+        // Remove destination entries replaced by an entry of a different kind
+        for p in paths.iter() {
+            if let (Some((src, _)), Some((dest, file))) = (src.get(p), dest.get(p))
+                && src.is_dir != dest.is_dir
+            {
+                if upload {
+                    self.remote_remove_file(file);
+                } else {
+                    self.local_remove_file(file);
+                }
+            }
+        }
+        let queue: Vec<(File, PathBuf)> = paths
+            .iter()
+            .filter_map(|p| src.get(p))
+            .map(|(entry, file)| {
+                let dest_dir = match entry.path.parent() {
+                    Some(parent) => dest_root.join(parent),
+                    None => dest_root.to_path_buf(),
+                };
+                (file.clone(), dest_dir)
+            })
+            .collect();
+        if queue.is_empty() {
+            return Ok(());
+        }
+        if upload {
+            self.filetransfer_send(TransferPayload::TransferQueue(queue), dest_root, None)
+        } else {
+            self.filetransfer_recv(TransferPayload::TransferQueue(queue), dest_root, None)
+        }
+    }
+
+    fn bisync_entries(tree: &WalkdirTree) -> Vec<MirrorEntry> {
+        tree.values().map(|(entry, _)| entry.clone()).collect()
+    }
+
+    /// Initialize the sync baseline storage in the cache directory
+    fn init_sync_baseline_storage() -> Result<SyncBaselineStorage, String> {
+        let cache_dir = environment::init_cache_dir()?
+            .ok_or_else(|| String::from("cache directory not found"))?;
+        SyncBaselineStorage::new(
+            environment::get_sync_baselines_path(cache_dir.as_path()).as_path(),
+        )
+        .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,209 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+// locals
+use std::path::PathBuf;
+
+use tuirealm::{State, StateValue};
+
+use super::{FileTransferActivity, Id, LogLevel};
+use crate::filetransfer::FileTransferParams;
+use crate::ui::activities::filetransfer::components::BookmarkKind;
+use crate::ui::activities::filetransfer::lib::session_bookmark::{
+    is_same_remote, save_directory_bookmark, save_session_bookmark, session_bookmark_name,
+    session_bookmark_params,
+};
+
+impl FileTransferActivity {
+    /// Show the popup to save the current session as a bookmark, prefilled with the name of the remote
+    pub(crate) fn action_show_save_bookmark(&mut self) {
+        if self.context().bookmarks_client().is_none() {
+            self.mount_error("Bookmarks are not available");
+            return;
+        }
+        if let Some(name) = self.context().remote_params().map(session_bookmark_name) {
+            self.mount_save_bookmark(&name, BookmarkKind::Session);
+        }
+    }
+
+    /// Save the current session as a bookmark, starting from the current working directories
+    pub(crate) fn action_save_bookmark(&mut self) {
+        let (name, save_password) = self.get_save_bookmark_input();
+        let local_wrkdir: Option<PathBuf> = self
+            .host_bridge
+            .is_localhost()
+            .then(|| self.host_bridge().wrkdir.clone());
+        let Some(params) = self.context().remote_params().map(|params| {
+            session_bookmark_params(params, &self.remote().wrkdir, local_wrkdir.as_deref())
+        }) else {
+            return;
+        };
+        let result = match self.context_mut().bookmarks_client_mut() {
+            Some(client) => save_session_bookmark(client, &name, params, save_password),
+            None => Err(String::from("Bookmarks are not available")),
+        };
+        match result {
+            Ok(()) => {
+                self.umount_save_bookmark();
+                self.log(
+                    LogLevel::Info,
+                    format!("Saved current session as bookmark \"{name}\""),
+                );
+            }
+            Err(err) => self.log_and_alert(LogLevel::Error, err),
+        }
+    }
+
+    /// Show the popup to bookmark the remote working directory, prefilled with the name of the directory
+    pub(crate) fn action_show_save_dir_bookmark(&mut self) {
+        if self.context().bookmarks_client().is_none() {
+            self.mount_error("Bookmarks are not available");
+            return;
+        }
+        let name = match self.remote().wrkdir.file_name() {
+            Some(name) => name.to_string_lossy().to_string(),
+            None => match self.context().remote_params() {
+                Some(params) => session_bookmark_name(params),
+                None => return,
+            },
+        };
+        self.mount_save_bookmark(&name, BookmarkKind::Directory);
+    }
+
+    /// Bookmark the remote working directory, along with the connection of the current session
+    pub(crate) fn action_save_dir_bookmark(&mut self) {
+        let (name, save_password) = self.get_save_bookmark_input();
+        let directory = self.remote().wrkdir.clone();
+        let Some(params) = self.context().remote_params().cloned() else {
+            return;
+        };
+        let result = match self.context_mut().bookmarks_client_mut() {
+            Some(client) => {
+                save_directory_bookmark(client, &name, params, &directory, save_password)
+            }
+            None => Err(String::from("Bookmarks are not available")),
+        };
+        match result {
+            Ok(()) => {
+                self.umount_save_bookmark();
+                self.log(
+                    LogLevel::Info,
+                    format!(
+                        "Saved directory bookmark \"{name}\" to {}",
+                        directory.display()
+                    ),
+                );
+            }
+            Err(err) => self.log_and_alert(LogLevel::Error, err),
+        }
+    }
+
+    /// Show the list of the directory bookmarks
+    pub(crate) fn action_show_dir_bookmarks(&mut self) {
+        let rows: Vec<String> = self
+            .dir_bookmarks()
+            .into_iter()
+            .map(|(name, params)| {
+                format!(
+                    "{name}  ({}:{})",
+                    params.params.host_name(),
+                    params
+                        .remote_path
+                        .as_deref()
+                        .map(|p| p.display().to_string())
+                        .unwrap_or_default()
+                )
+            })
+            .collect();
+        if rows.is_empty() {
+            self.mount_error("There are no directory bookmarks");
+            return;
+        }
+        self.mount_dir_bookmarks_list(&rows);
+    }
+
+    /// Go to the directory bookmark at `idx` of the list.
+    ///
+    /// If it's on the remote of the current session, the remote explorer just changes directory;
+    /// otherwise the session is closed and termscp connects to its remote
+    pub(crate) fn action_go_to_dir_bookmark(&mut self, idx: usize) {
+        self.umount_dir_bookmarks_list();
+        let Some((name, params)) = self.dir_bookmarks().into_iter().nth(idx) else {
+            return;
+        };
+        let Some(directory) = params.remote_path.clone() else {
+            return;
+        };
+        let same_remote = self
+            .context()
+            .remote_params()
+            .is_some_and(|current| is_same_remote(current, &params));
+        if same_remote {
+            self.remote_changedir(&directory, true);
+            self.update_remote_filelist();
+        } else {
+            self.log(
+                LogLevel::Info,
+                format!("Connecting to directory bookmark \"{name}\""),
+            );
+            self.disconnect_and_connect(params);
+        }
+    }
+
+    /// Delete the directory bookmark at `idx` of the list
+    pub(crate) fn action_delete_dir_bookmark(&mut self, idx: usize) {
+        let Some((name, _)) = self.dir_bookmarks().into_iter().nth(idx) else {
+            return;
+        };
+        let result = match self.context_mut().bookmarks_client_mut() {
+            Some(client) => {
+                client.del_directory_bookmark(&name);
+                client
+                    .write_bookmarks()
+                    .map_err(|err| format!("Could not write bookmarks: {err}"))
+            }
+            None => return,
+        };
+        match result {
+            Ok(()) => {
+                self.log(
+                    LogLevel::Info,
+                    format!("Deleted directory bookmark \"{name}\""),
+                );
+                self.action_show_dir_bookmarks();
+            }
+            Err(err) => self.log_and_alert(LogLevel::Error, err),
+        }
+    }
+
+    /// Get the name and the secrets choice from the save bookmark popup
+    fn get_save_bookmark_input(&self) -> (String, bool) {
+        let name = match self.app.state(&Id::BookmarkName) {
+            Ok(State::One(StateValue::String(name))) => name.trim().to_string(),
+            _ => String::default(),
+        };
+        let save_password = matches!(
+            self.app.state(&Id::BookmarkSavePassword),
+            Ok(State::One(StateValue::Usize(0)))
+        );
+        (name, save_password)
+    }
+
+    /// Get the directory bookmarks, sorted by name, with the params to connect to them
+    fn dir_bookmarks(&self) -> Vec<(String, FileTransferParams)> {
+        let Some(client) = self.context().bookmarks_client() else {
+            return Vec::new();
+        };
+        let mut names: Vec<&String> = client.iter_directory_bookmarks().collect();
+        names.sort();
+        names
+            .into_iter()
+            .filter_map(|name| {
+                client
+                    .get_directory_bookmark(name)
+                    .map(|params| (name.clone(), params))
+            })
+            .collect()
+    }
+}
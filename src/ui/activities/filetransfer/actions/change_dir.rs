@@ -8,12 +8,17 @@ use std::path::PathBuf;
 use remotefs::File;
 
 use super::{FileExplorerTab, FileTransferActivity, LogLevel, Msg, PendingActionMsg};
+use crate::ui::activities::filetransfer::lib::sibling::{SiblingDirection, sibling_dir};
 
 /// Describes destination for sync browsing
 enum SyncBrowsingDestination {
     Path(String),
     ParentDir,
     PreviousDir,
+    /// Directory to go forward to in the history
+    NextDir,
+    /// Sibling directory of the working directory
+    Sibling(PathBuf),
 }
 
 impl FileTransferActivity {
@@ -76,6 +81,28 @@ impl FileTransferActivity {
         }
     }
 
+    /// Go forward to the next directory in the history of localhost
+    pub(crate) fn action_go_forward_local_dir(&mut self) {
+        if let Some(d) = self.host_bridge_mut().forwardd() {
+            self.host_bridge_changedir(d.as_path(), false);
+            // Check whether to sync
+            if self.browser.sync_browsing && self.browser.found().is_none() {
+                self.synchronize_browsing(SyncBrowsingDestination::NextDir);
+            }
+        }
+    }
+
+    /// Go forward to the next directory in the history of remote host
+    pub(crate) fn action_go_forward_remote_dir(&mut self) {
+        if let Some(d) = self.remote_mut().forwardd() {
+            self.remote_changedir(d.as_path(), false);
+            // Check whether to sync
+            if self.browser.sync_browsing && self.browser.found().is_none() {
+                self.synchronize_browsing(SyncBrowsingDestination::NextDir);
+            }
+        }
+    }
+
     /// Go to upper directory on local host
     pub(crate) fn action_go_to_local_upper_dir(&mut self) {
         // Get pwd
@@ -106,6 +133,129 @@ impl FileTransferActivity {
         }
     }
 
+    /// Go to the sibling directory of the working directory on local host
+    pub(crate) fn action_go_to_local_sibling_dir(&mut self, direction: SiblingDirection) {
+        let wrkdir: PathBuf = self.host_bridge().wrkdir.clone();
+        let Some(parent) = wrkdir.parent() else {
+            return;
+        };
+        let show_hidden = self.host_bridge().hidden_files_visible();
+        let siblings = match self.host_bridge.list_dir(parent) {
+            Ok(files) => Self::sibling_dirs(files, show_hidden),
+            Err(err) => {
+                self.log_and_alert(
+                    LogLevel::Error,
+                    format!("Could not scan directory \"{}\": {err}", parent.display()),
+                );
+                return;
+            }
+        };
+        if let Some(dir) = sibling_dir(wrkdir.as_path(), &siblings, direction) {
+            self.host_bridge_changedir(dir.as_path(), true);
+            if self.browser.sync_browsing && self.browser.found().is_none() {
+                self.synchronize_browsing(SyncBrowsingDestination::Sibling(dir));
+            }
+        }
+    }
+
+    /// Go to the sibling directory of the working directory on remote host
+    pub(crate) fn action_go_to_remote_sibling_dir(&mut self, direction: SiblingDirection) {
+        let wrkdir: PathBuf = self.remote().wrkdir.clone();
+        let Some(parent) = wrkdir.parent() else {
+            return;
+        };
+        let show_hidden = self.remote().hidden_files_visible();
+        let siblings = match self.client.list_dir(parent) {
+            Ok(files) => Self::sibling_dirs(files, show_hidden),
+            Err(err) => {
+                self.log_and_alert(
+                    LogLevel::Error,
+                    format!("Could not scan directory \"{}\": {err}", parent.display()),
+                );
+                return;
+            }
+        };
+        if let Some(dir) = sibling_dir(wrkdir.as_path(), &siblings, direction) {
+            self.remote_changedir(dir.as_path(), true);
+            if self.browser.sync_browsing && self.browser.found().is_none() {
+                self.synchronize_browsing(SyncBrowsingDestination::Sibling(dir));
+            }
+        }
+    }
+
+    /// Go to the home directory of the user on local host
+    pub(crate) fn action_go_to_local_home_dir(&mut self) {
+        match self.host_bridge_home.clone() {
+            Some(home) => self.host_bridge_changedir(home.as_path(), true),
+            None => self.log_and_alert(
+                LogLevel::Error,
+                String::from("Could not resolve the home directory of the host bridge"),
+            ),
+        }
+    }
+
+    /// Go to the home directory of the user on remote host
+    pub(crate) fn action_go_to_remote_home_dir(&mut self) {
+        match self.remote_home.clone() {
+            Some(home) => self.remote_changedir(home.as_path(), true),
+            None => self.log_and_alert(
+                LogLevel::Error,
+                String::from("Could not resolve the home directory of the remote"),
+            ),
+        }
+    }
+
+    /// Go to the working directory of the other panel, if different
+    pub(crate) fn action_go_to_other_panel_dir(&mut self) {
+        let Some(dir) = self.browser.other_panel_dir().map(PathBuf::from) else {
+            return;
+        };
+        // No sync browsing: the other panel is already there
+        match self.browser.tab() {
+            FileExplorerTab::HostBridge => self.host_bridge_changedir(dir.as_path(), true),
+            FileExplorerTab::Remote => self.remote_changedir(dir.as_path(), true),
+            FileExplorerTab::FindHostBridge | FileExplorerTab::FindRemote => {}
+        }
+    }
+
+    /// Mark the working directory of the current explorer with `mark`
+    pub(crate) fn action_set_dir_mark(&mut self, mark: char) {
+        let wrkdir = self.browser.explorer().wrkdir.clone();
+        self.browser
+            .explorer_mut()
+            .set_dir_mark(mark, wrkdir.as_path());
+        self.log(
+            LogLevel::Info,
+            format!("Marked {} as '{mark}'", wrkdir.display()),
+        );
+    }
+
+    /// Go to the directory marked with `mark` in the current explorer
+    pub(crate) fn action_goto_dir_mark(&mut self, mark: char) {
+        let Some(dir) = self.browser.explorer().dir_mark(mark).map(PathBuf::from) else {
+            self.log_and_alert(
+                LogLevel::Warn,
+                format!("No directory is marked as '{mark}'"),
+            );
+            return;
+        };
+        // Directories which don't exist anymore are reported by changedir
+        match self.browser.tab() {
+            FileExplorerTab::HostBridge => self.host_bridge_changedir(dir.as_path(), true),
+            FileExplorerTab::Remote => self.remote_changedir(dir.as_path(), true),
+            FileExplorerTab::FindHostBridge | FileExplorerTab::FindRemote => {}
+        }
+    }
+
+    /// Get the paths of the directories among `files`
+    fn sibling_dirs(files: Vec<File>, show_hidden: bool) -> Vec<PathBuf> {
+        files
+            .into_iter()
+            .filter(|x| x.is_dir() && (show_hidden || !x.is_hidden()))
+            .map(|x| x.path().to_path_buf())
+            .collect()
+    }
+
     // -- sync browsing
 
     /// Synchronize browsing on the target browser.
@@ -195,11 +345,18 @@ impl FileTransferActivity {
                 FileExplorerTab::Remote => self.host_bridge_changedir(path.as_path(), true),
                 _ => {}
             },
-            SyncBrowsingDestination::PreviousDir => match self.browser.tab() {
-                FileExplorerTab::HostBridge => self.remote_changedir(path.as_path(), false),
-                FileExplorerTab::Remote => self.host_bridge_changedir(path.as_path(), false),
+            SyncBrowsingDestination::Sibling(_) => match self.browser.tab() {
+                FileExplorerTab::HostBridge => self.remote_changedir(path.as_path(), true),
+                FileExplorerTab::Remote => self.host_bridge_changedir(path.as_path(), true),
                 _ => {}
             },
+            SyncBrowsingDestination::PreviousDir | SyncBrowsingDestination::NextDir => {
+                match self.browser.tab() {
+                    FileExplorerTab::HostBridge => self.remote_changedir(path.as_path(), false),
+                    FileExplorerTab::Remote => self.host_bridge_changedir(path.as_path(), false),
+                    _ => {}
+                }
+            }
         }
     }
 
@@ -232,7 +389,36 @@ impl FileTransferActivity {
                     None
                 }
             }
+            (SyncBrowsingDestination::NextDir, FileExplorerTab::HostBridge) => {
+                if let Some(p) = self.remote_mut().forwardd() {
+                    Some(p)
+                } else {
+                    warn!("Cannot synchronize browsing: remote has no next directory in history");
+                    None
+                }
+            }
+            (SyncBrowsingDestination::NextDir, FileExplorerTab::Remote) => {
+                if let Some(p) = self.host_bridge_mut().forwardd() {
+                    Some(p)
+                } else {
+                    warn!("Cannot synchronize browsing: local has no next directory in history");
+                    None
+                }
+            }
             (SyncBrowsingDestination::Path(p), _) => Some(PathBuf::from(p.as_str())),
+            // NOTE: tab and methods are switched on purpose
+            (SyncBrowsingDestination::Sibling(dir), FileExplorerTab::HostBridge) => self
+                .remote()
+                .wrkdir
+                .parent()
+                .zip(dir.file_name())
+                .map(|(parent, name)| parent.join(name)),
+            (SyncBrowsingDestination::Sibling(dir), FileExplorerTab::Remote) => self
+                .host_bridge()
+                .wrkdir
+                .parent()
+                .zip(dir.file_name())
+                .map(|(parent, name)| parent.join(name)),
             _ => {
                 warn!("Cannot synchronize browsing for current explorer");
                 None
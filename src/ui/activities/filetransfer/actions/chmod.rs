@@ -1,6 +1,13 @@
+use std::path::Path;
+use std::time::Instant;
+
+use remotefs::File;
 use remotefs::fs::UnixPex;
 
 use super::{FileTransferActivity, LogLevel};
+use crate::ui::activities::filetransfer::browser::FileExplorerTab;
+use crate::ui::activities::filetransfer::lib::chmod::{ExecuteMode, entry_mode, should_recurse};
+use crate::ui::activities::filetransfer::lib::walkdir::{self, PROGRESS_INTERVAL, WalkdirError};
 
 impl FileTransferActivity {
     pub fn action_local_chmod(&mut self, mode: UnixPex) {
@@ -96,4 +103,128 @@ impl FileTransferActivity {
             );
         }
     }
+
+    /// Change the mode of the selected entries and of all the descendants of the selected directories.
+    /// The progress is shown in an abortable wait popup, which must be mounted
+    pub fn action_chmod_recursive(&mut self, mode: UnixPex, execute: ExecuteMode) {
+        let (files, local) = match self.browser.tab() {
+            FileExplorerTab::HostBridge => (self.get_local_selected_entries().get_files(), true),
+            FileExplorerTab::Remote => (self.get_remote_selected_entries().get_files(), false),
+            FileExplorerTab::FindHostBridge => {
+                (self.get_found_selected_entries().get_files(), true)
+            }
+            FileExplorerTab::FindRemote => (self.get_found_selected_entries().get_files(), false),
+        };
+        self.init_walkdir();
+        // collect the entries to change, directories come before their contents
+        let mut entries = Vec::with_capacity(files.len());
+        for file in files {
+            let descendants = match should_recurse(&file) {
+                true => self.chmod_walkdir(file.path(), local),
+                false => Ok(Vec::new()),
+            };
+            match descendants {
+                Ok(descendants) => {
+                    entries.push(file);
+                    entries.extend(descendants);
+                }
+                Err(WalkdirError::Aborted) => {
+                    self.log(LogLevel::Warn, String::from("chmod aborted"));
+                    return;
+                }
+                Err(WalkdirError::Error(err)) => {
+                    self.log_and_alert(
+                        LogLevel::Error,
+                        format!(
+                            "could not scan directory {}: {}",
+                            file.path().display(),
+                            err
+                        ),
+                    );
+                    return;
+                }
+            }
+        }
+        // apply mode
+        let total = entries.len();
+        let mut last_report: Option<Instant> = None;
+        for (i, entry) in entries.iter().enumerate() {
+            if last_report.is_none_or(|t| t.elapsed() >= PROGRESS_INTERVAL) {
+                last_report = Some(Instant::now());
+                self.update_abortable_wait(format!("Changing mode… ({i}/{total})"), entry.path());
+                // read events
+                self.tick();
+            }
+            if self.walkdir.aborted {
+                self.log(
+                    LogLevel::Warn,
+                    format!("chmod aborted after changing mode for {i} entries"),
+                );
+                return;
+            }
+            let entry_mode = entry_mode(mode, entry, execute);
+            if let Err(err) = self.chmod_entry(entry, entry_mode, local) {
+                self.log_and_alert(
+                    LogLevel::Error,
+                    format!(
+                        "could not change mode for {}: {}",
+                        entry.path().display(),
+                        err
+                    ),
+                );
+                return;
+            }
+        }
+        self.log(
+            LogLevel::Info,
+            format!(
+                "changed mode to {:#o} for {} entries",
+                u32::from(mode),
+                total
+            ),
+        );
+    }
+
+    /// Walk the directory at `path`, on the host bridge if `local`, otherwise on the remote
+    fn chmod_walkdir(&mut self, path: &Path, local: bool) -> Result<Vec<File>, WalkdirError> {
+        walkdir::walkdir(
+            self,
+            path,
+            PROGRESS_INTERVAL,
+            |activity, path| match local {
+                true => activity
+                    .host_bridge
+                    .list_dir(path)
+                    .map_err(|e| e.to_string()),
+                false => activity.client.list_dir(path).map_err(|e| e.to_string()),
+            },
+            |activity, progress| {
+                activity.update_abortable_wait(
+                    format!("Scanning directory… ({} items found)", progress.entries),
+                    progress.path.as_path(),
+                );
+                // read events
+                activity.tick();
+                Ok(())
+            },
+            |activity| activity.walkdir.aborted,
+        )
+    }
+
+    /// Set `mode` on `entry`, on the host bridge if `local`, otherwise on the remote
+    fn chmod_entry(&mut self, entry: &File, mode: UnixPex, local: bool) -> Result<(), String> {
+        match local {
+            true => self
+                .host_bridge
+                .chmod(entry.path(), mode)
+                .map_err(|e| e.to_string()),
+            false => {
+                let mut metadata = entry.metadata.clone();
+                metadata.mode = Some(mode);
+                self.client
+                    .setstat(entry.path(), metadata)
+                    .map_err(|e| e.to_string())
+            }
+        }
+    }
 }
@@ -0,0 +1,131 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+// locals
+use std::path::{Path, PathBuf};
+
+use super::save::{CheckFileExists, TransferFilesWithOverwritesResult};
+use super::{File, FileTransferActivity, LogLevel, TransferPayload};
+use crate::ui::activities::filetransfer::lib::clipboard::{Clipboard, ClipboardSide};
+
+impl FileTransferActivity {
+    /// Cut the selected files of the host bridge, to move them once pasted
+    pub(crate) fn action_local_cut(&mut self) {
+        let files = self.get_local_selected_entries().get_files();
+        self.cut_files(ClipboardSide::HostBridge, files);
+        // clear selection
+        self.host_bridge_mut().clear_queue();
+        self.reload_host_bridge_filelist();
+    }
+
+    /// Cut the selected files of the remote, to move them once pasted
+    pub(crate) fn action_remote_cut(&mut self) {
+        let files = self.get_remote_selected_entries().get_files();
+        self.cut_files(ClipboardSide::Remote, files);
+        // clear selection
+        self.remote_mut().clear_queue();
+        self.reload_remote_filelist();
+    }
+
+    /// Move the cut files into the working directory of the host bridge
+    pub(crate) fn action_local_paste(&mut self) {
+        let wrkdir: PathBuf = self.host_bridge().wrkdir.clone();
+        self.paste_files(ClipboardSide::HostBridge, wrkdir.as_path());
+    }
+
+    /// Move the cut files into the working directory of the remote
+    pub(crate) fn action_remote_paste(&mut self) {
+        let wrkdir: PathBuf = self.remote().wrkdir.clone();
+        self.paste_files(ClipboardSide::Remote, wrkdir.as_path());
+    }
+
+    fn cut_files(&mut self, side: ClipboardSide, files: Vec<File>) {
+        if files.is_empty() {
+            return;
+        }
+        self.log(LogLevel::Info, format!("Cut {} file(s)", files.len()));
+        self.clipboard = Some(Clipboard::new(side, files));
+    }
+
+    /// Move the cut files into `dest` on the `side` explorer.
+    ///
+    /// Files are renamed when they're pasted on the side they have been cut from;
+    /// otherwise they're transferred, then removed from the source
+    fn paste_files(&mut self, side: ClipboardSide, dest: &Path) {
+        let Some(clipboard) = self.clipboard.take() else {
+            self.log_and_alert(
+                LogLevel::Warn,
+                String::from("There are no cut files to paste"),
+            );
+            return;
+        };
+        let files = clipboard.files_to_paste(side, dest);
+        match (clipboard.side(), side) {
+            (ClipboardSide::HostBridge, ClipboardSide::HostBridge) => {
+                self.mount_blocking_wait("Moving file(s)…");
+                for (entry, mut dest_path) in files.into_iter() {
+                    dest_path.push(entry.name());
+                    self.local_rename_file(&entry, dest_path.as_path());
+                }
+                self.umount_wait();
+            }
+            (ClipboardSide::Remote, ClipboardSide::Remote) => {
+                self.mount_blocking_wait("Moving file(s)…");
+                for (entry, mut dest_path) in files.into_iter() {
+                    dest_path.push(entry.name());
+                    self.remote_rename_file(&entry, dest_path.as_path());
+                }
+                self.umount_wait();
+            }
+            (ClipboardSide::HostBridge, ClipboardSide::Remote) => {
+                let TransferFilesWithOverwritesResult::FilesToTransfer(files) =
+                    self.get_files_to_transfer_with_overwrites(files, CheckFileExists::Remote)
+                else {
+                    debug!("User cancelled move due to overwrites");
+                    return;
+                };
+                let result = self.filetransfer_send(
+                    TransferPayload::TransferQueue(files.clone()),
+                    dest,
+                    None,
+                );
+                if self.moved_files_transferred(result) {
+                    for (entry, _) in files.iter() {
+                        self.local_remove_file(entry);
+                    }
+                }
+            }
+            (ClipboardSide::Remote, ClipboardSide::HostBridge) => {
+                let TransferFilesWithOverwritesResult::FilesToTransfer(files) =
+                    self.get_files_to_transfer_with_overwrites(files, CheckFileExists::HostBridge)
+                else {
+                    debug!("User cancelled move due to overwrites");
+                    return;
+                };
+                let result = self.filetransfer_recv(
+                    TransferPayload::TransferQueue(files.clone()),
+                    dest,
+                    None,
+                );
+                if self.moved_files_transferred(result) {
+                    for (entry, _) in files.iter() {
+                        self.remote_remove_file(entry);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns whether the files moved to the other side have all been transferred,
+    /// so the sources can be removed
+    fn moved_files_transferred(&mut self, result: Result<(), String>) -> bool {
+        match result {
+            Ok(()) => !self.transfer.aborted(),
+            Err(err) => {
+                self.log_and_alert(LogLevel::Error, format!("Could not move files: {err}"));
+                false
+            }
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+// locals
+use std::fs::File as StdFile;
+use std::io::{self, Seek};
+use std::path::PathBuf;
+
+use remotefs::File;
+use remotefs::fs::Metadata;
+use tuirealm::{State, StateValue};
+
+use super::{FileTransferActivity, Id, LogLevel};
+use crate::ui::activities::filetransfer::lib::archive::{
+    ArchiveEntry, ArchiveFormat, ArchiveWriter, archive_entries, archive_path,
+};
+use crate::ui::activities::filetransfer::lib::browser::FileExplorerTab;
+
+impl FileTransferActivity {
+    /// Show the popup to compress the selected files, prefilled with the name of the selected file or of the working directory
+    pub(crate) fn action_show_compress(&mut self) {
+        let (files, wrkdir) = match self.browser.tab() {
+            FileExplorerTab::HostBridge => (
+                self.get_local_selected_entries().get_files(),
+                self.host_bridge().wrkdir.clone(),
+            ),
+            FileExplorerTab::Remote => (
+                self.get_remote_selected_entries().get_files(),
+                self.remote().wrkdir.clone(),
+            ),
+            FileExplorerTab::FindHostBridge | FileExplorerTab::FindRemote => return,
+        };
+        let name = match files.as_slice() {
+            [] => {
+                self.mount_error("No file selected to compress");
+                return;
+            }
+            [file] => file.name(),
+            _ => wrkdir
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| String::from("archive")),
+        };
+        self.mount_compress(&name);
+    }
+
+    /// Compress the selected files into an archive, written in the working directory of the current panel
+    pub(crate) fn action_compress_selected(&mut self) {
+        let name = match self.app.state(&Id::CompressName) {
+            Ok(State::One(StateValue::String(name))) => name.trim().to_string(),
+            _ => String::default(),
+        };
+        let format = match self.app.state(&Id::CompressFormat) {
+            Ok(State::One(StateValue::Usize(1))) => ArchiveFormat::Zip,
+            _ => ArchiveFormat::TarGz,
+        };
+        self.umount_compress();
+        if name.is_empty() {
+            self.mount_error("The archive name can't be empty");
+            return;
+        }
+        let files = match self.browser.tab() {
+            FileExplorerTab::HostBridge => self.get_local_selected_entries().get_files(),
+            FileExplorerTab::Remote => self.get_remote_selected_entries().get_files(),
+            FileExplorerTab::FindHostBridge | FileExplorerTab::FindRemote => return,
+        };
+        if files.is_empty() {
+            self.mount_error("No file selected to compress");
+            return;
+        }
+        self.mount_blocking_wait("Compressing files…");
+        let result = match self.browser.tab() {
+            FileExplorerTab::Remote => self.remote_compress(&files, &name, format),
+            _ => self.local_compress(&files, &name, format),
+        };
+        self.umount_wait();
+        match result {
+            Ok(path) => {
+                self.log(
+                    LogLevel::Info,
+                    format!(
+                        "Compressed {} file(s) into \"{}\"",
+                        files.len(),
+                        path.display()
+                    ),
+                );
+                match self.browser.tab() {
+                    FileExplorerTab::Remote => {
+                        self.remote_mut().clear_queue();
+                        self.reload_remote_filelist();
+                    }
+                    _ => {
+                        self.host_bridge_mut().clear_queue();
+                        self.reload_host_bridge_filelist();
+                    }
+                }
+            }
+            Err(err) => {
+                self.log_and_alert(LogLevel::Error, format!("Could not compress files: {err}"))
+            }
+        }
+    }
+
+    fn local_compress(
+        &mut self,
+        files: &[File],
+        name: &str,
+        format: ArchiveFormat,
+    ) -> Result<PathBuf, String> {
+        let wrkdir = self.host_bridge().wrkdir.clone();
+        let entries = archive_entries(&wrkdir, files, |p| {
+            self.host_bridge.list_dir(p).map_err(|err| err.to_string())
+        })?;
+        let mut writer = ArchiveWriter::new(format, Self::archive_tempfile()?);
+        for entry in entries.iter() {
+            let mut reader: Box<dyn io::Read> = match entry.is_dir() {
+                true => Box::new(io::empty()),
+                false => self
+                    .host_bridge
+                    .open_file(&entry.path)
+                    .map_err(|err| format!("could not read \"{}\": {err}", entry.path.display()))?,
+            };
+            Self::append_to_archive(&mut writer, entry, &mut reader)?;
+        }
+        let (mut archive, metadata) = Self::finish_archive(writer)?;
+        let dest = archive_path(&wrkdir, name, format, |p| {
+            self.host_bridge.exists(p).unwrap_or(false)
+        });
+        let mut writer = self
+            .host_bridge
+            .create_file(&dest, &metadata)
+            .map_err(|err| err.to_string())?;
+        io::copy(&mut archive, &mut writer).map_err(|err| err.to_string())?;
+        self.host_bridge
+            .finalize_write(writer)
+            .map_err(|err| err.to_string())?;
+        Ok(dest)
+    }
+
+    fn remote_compress(
+        &mut self,
+        files: &[File],
+        name: &str,
+        format: ArchiveFormat,
+    ) -> Result<PathBuf, String> {
+        let wrkdir = self.remote().wrkdir.clone();
+        let entries = archive_entries(&wrkdir, files, |p| {
+            self.client.list_dir(p).map_err(|err| err.to_string())
+        })?;
+        let mut writer = ArchiveWriter::new(format, Self::archive_tempfile()?);
+        for entry in entries.iter() {
+            // Download the file first, since remote readers can't be held while writing the archive
+            let mut reader = Self::archive_tempfile()?;
+            if !entry.is_dir() {
+                let dest = reader.try_clone().map_err(|err| err.to_string())?;
+                self.client
+                    .open_file(&entry.path, Box::new(dest))
+                    .map_err(|err| format!("could not read \"{}\": {err}", entry.path.display()))?;
+                reader.rewind().map_err(|err| err.to_string())?;
+            }
+            let mut entry = entry.clone();
+            entry.metadata.size = reader.metadata().map_err(|err| err.to_string())?.len();
+            Self::append_to_archive(&mut writer, &entry, &mut reader)?;
+        }
+        let (archive, metadata) = Self::finish_archive(writer)?;
+        let dest = archive_path(&wrkdir, name, format, |p| {
+            self.client.exists(p).unwrap_or(false)
+        });
+        self.client
+            .create_file(&dest, &metadata, Box::new(archive))
+            .map_err(|err| err.to_string())?;
+        Ok(dest)
+    }
+
+    /// Create the temporary file to buffer archives and their entries in
+    pub(super) fn archive_tempfile() -> Result<StdFile, String> {
+        tempfile::tempfile().map_err(|err| format!("could not create temporary file: {err}"))
+    }
+
+    fn append_to_archive(
+        writer: &mut ArchiveWriter<StdFile>,
+        entry: &ArchiveEntry,
+        reader: &mut dyn io::Read,
+    ) -> Result<(), String> {
+        writer
+            .append(entry, reader)
+            .map_err(|err| format!("could not add \"{}\" to archive: {err}", entry.name))
+    }
+
+    /// Complete the archive and rewind it, returning it with its metadata
+    fn finish_archive(writer: ArchiveWriter<StdFile>) -> Result<(StdFile, Metadata), String> {
+        let mut archive = writer
+            .finish()
+            .map_err(|err| format!("could not write archive: {err}"))?;
+        archive.rewind().map_err(|err| err.to_string())?;
+        let size = archive.metadata().map_err(|err| err.to_string())?.len();
+        Ok((archive, Metadata::default().size(size)))
+    }
+}
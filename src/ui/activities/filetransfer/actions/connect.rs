@@ -0,0 +1,32 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+// locals
+use super::{FileTransferActivity, Msg, PendingActionMsg};
+use crate::ui::activities::filetransfer::ExitReason;
+
+impl FileTransferActivity {
+    /// Show the summary of the connection parameters and wait for the user to confirm them.
+    ///
+    /// Returns whether the user confirmed; if not, the activity is terminated
+    pub(crate) fn action_confirm_connection(&mut self) -> bool {
+        let (Some(host_bridge), Some(remote)) = (
+            self.context().host_bridge_params().cloned(),
+            self.context().remote_params().cloned(),
+        ) else {
+            return true;
+        };
+        self.mount_connection_summary_popup(&host_bridge, &remote);
+        let confirmed = self.wait_for_pending_msg(&[
+            Msg::PendingAction(PendingActionMsg::Connect),
+            Msg::PendingAction(PendingActionMsg::ConnectCancel),
+        ]) == Msg::PendingAction(PendingActionMsg::Connect);
+        self.umount_connection_summary_popup();
+        self.connection_confirmed = confirmed;
+        if !confirmed {
+            self.exit_reason = Some(ExitReason::Disconnect);
+        }
+        confirmed
+    }
+}
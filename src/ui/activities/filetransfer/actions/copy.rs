@@ -54,7 +54,7 @@ impl FileTransferActivity {
         }
     }
 
-    fn local_copy_file(&mut self, entry: &File, dest: &Path) {
+    pub(super) fn local_copy_file(&mut self, entry: &File, dest: &Path) {
         match self.host_bridge.copy(entry, dest) {
             Ok(_) => {
                 self.log(
@@ -78,7 +78,7 @@ impl FileTransferActivity {
         }
     }
 
-    fn remote_copy_file(&mut self, entry: File, dest: &Path) {
+    pub(super) fn remote_copy_file(&mut self, entry: File, dest: &Path) {
         match self.client.as_mut().copy(entry.path(), dest) {
             Ok(_) => {
                 self.log(
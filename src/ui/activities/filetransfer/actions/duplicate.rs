@@ -0,0 +1,73 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+// locals
+use std::path::Path;
+
+use super::FileTransferActivity;
+use crate::ui::activities::filetransfer::lib::browser::FileExplorerTab;
+use crate::ui::activities::filetransfer::lib::duplicate::{
+    available_duplicate_name, duplicate_path,
+};
+
+impl FileTransferActivity {
+    /// Show the popup to duplicate the selected file, prefilled with the first available `name (copy).ext` name
+    pub(crate) fn action_show_duplicate(&mut self) {
+        let file = match self.browser.tab() {
+            FileExplorerTab::HostBridge => self.get_local_selected_file(),
+            FileExplorerTab::Remote => self.get_remote_selected_file(),
+            FileExplorerTab::FindHostBridge | FileExplorerTab::FindRemote => return,
+        };
+        let Some(file) = file else {
+            self.mount_error("No file selected to duplicate");
+            return;
+        };
+        let dir = file.path().parent().unwrap_or(Path::new("/")).to_path_buf();
+        let name = match self.browser.tab() {
+            FileExplorerTab::Remote => {
+                available_duplicate_name(&dir, &file.name(), file.is_dir(), |p| {
+                    self.client.exists(p).unwrap_or(false)
+                })
+            }
+            _ => available_duplicate_name(&dir, &file.name(), file.is_dir(), |p| {
+                self.host_bridge.exists(p).unwrap_or(false)
+            }),
+        };
+        self.mount_duplicate(&name);
+    }
+
+    /// Copy the selected file in its own directory, with the name `name`
+    pub(crate) fn action_duplicate(&mut self, name: String) {
+        let name = name.trim();
+        if name.is_empty() || name.contains('/') {
+            self.mount_error(format!("Invalid file name \"{name}\""));
+            return;
+        }
+        match self.browser.tab() {
+            FileExplorerTab::HostBridge => {
+                let Some(file) = self.get_local_selected_file() else {
+                    return;
+                };
+                let dest = duplicate_path(file.path(), name);
+                if self.host_bridge.exists(&dest).unwrap_or(false) {
+                    self.mount_error(format!("\"{}\" already exists", dest.display()));
+                    return;
+                }
+                self.local_copy_file(&file, &dest);
+            }
+            FileExplorerTab::Remote => {
+                let Some(file) = self.get_remote_selected_file() else {
+                    return;
+                };
+                let dest = duplicate_path(file.path(), name);
+                if self.client.exists(&dest).unwrap_or(false) {
+                    self.mount_error(format!("\"{}\" already exists", dest.display()));
+                    return;
+                }
+                self.remote_copy_file(file, &dest);
+            }
+            FileExplorerTab::FindHostBridge | FileExplorerTab::FindRemote => {}
+        }
+    }
+}
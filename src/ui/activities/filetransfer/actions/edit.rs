@@ -11,6 +11,7 @@ use remotefs::File;
 use remotefs::fs::Metadata;
 
 use super::{FileTransferActivity, LogLevel, SelectedFile, TransferPayload};
+use crate::system::editor::resolve_editor;
 
 impl FileTransferActivity {
     pub(crate) fn action_edit_local_file(&mut self) {
@@ -168,6 +169,7 @@ impl FileTransferActivity {
                 return Err(format!("Could not read file: {err}"));
             }
         }
+        let editor = resolve_editor(&self.config().get_text_editor())?;
         // Put input mode back to normal
         if let Err(err) = self.context_mut().terminal().disable_raw_mode() {
             error!("Failed to disable raw mode: {}", err);
@@ -181,15 +183,21 @@ impl FileTransferActivity {
         // Get current file modification time
         let prev_mtime = self.get_localhost_mtime(path)?;
         // Open editor
-        match edit::edit_file(path) {
-            Ok(_) => self.log(
+        match editor.command(path).status() {
+            Ok(status) if status.success() => self.log(
                 LogLevel::Info,
                 format!(
                     "Changes performed through editor saved to \"{}\"!",
                     path.display()
                 ),
             ),
-            Err(err) => return Err(format!("Could not open editor: {err}")),
+            Ok(status) => return Err(format!("Editor exited with {status}")),
+            Err(err) => {
+                return Err(format!(
+                    "Could not open editor \"{}\": {err}",
+                    editor.program.display()
+                ));
+            }
         }
         if let Some(ctx) = self.context.as_mut() {
             // Enter alternate mode
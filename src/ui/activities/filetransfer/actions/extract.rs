@@ -0,0 +1,164 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+// locals
+use std::collections::HashSet;
+use std::io::{self, Seek};
+use std::path::{Path, PathBuf};
+
+use remotefs::fs::{Metadata, UnixPex};
+use remotefs::{File, RemoteErrorType};
+
+use super::{FileTransferActivity, LogLevel};
+use crate::ui::activities::filetransfer::lib::archive::{
+    ArchiveFormat, ExtractedEntry, extract_path, read_archive,
+};
+use crate::ui::activities::filetransfer::lib::browser::FileExplorerTab;
+
+impl FileTransferActivity {
+    /// Extract the highlighted archive into the working directory of the current panel
+    pub(crate) fn action_extract_archive(&mut self) {
+        let (file, remote) = match self.browser.tab() {
+            FileExplorerTab::HostBridge => (self.get_local_selected_file(), false),
+            FileExplorerTab::Remote => (self.get_remote_selected_file(), true),
+            FileExplorerTab::FindHostBridge | FileExplorerTab::FindRemote => return,
+        };
+        let Some(file) = file else {
+            self.mount_error("No archive selected to extract");
+            return;
+        };
+        let format = match ArchiveFormat::from_path(file.path()) {
+            Some(format) if file.is_file() => format,
+            _ => {
+                self.mount_error(format!(
+                    "\"{}\" is not a supported archive (.zip, .tar.gz, .tgz)",
+                    file.name()
+                ));
+                return;
+            }
+        };
+        self.mount_blocking_wait("Extracting archive…");
+        let result = match remote {
+            true => self.remote_extract(&file, format),
+            false => self.local_extract(&file, format),
+        };
+        self.umount_wait();
+        match result {
+            Ok(count) => {
+                self.log(
+                    LogLevel::Info,
+                    format!(
+                        "Extracted {count} entries from \"{}\"",
+                        file.path().display()
+                    ),
+                );
+                match remote {
+                    true => self.reload_remote_filelist(),
+                    false => self.reload_host_bridge_filelist(),
+                }
+            }
+            Err(err) => self.log_and_alert(
+                LogLevel::Error,
+                format!("Could not extract \"{}\": {err}", file.path().display()),
+            ),
+        }
+    }
+
+    fn local_extract(&mut self, file: &File, format: ArchiveFormat) -> Result<usize, String> {
+        let wrkdir = self.host_bridge().wrkdir.clone();
+        let mut archive = Self::archive_tempfile()?;
+        let mut reader = self
+            .host_bridge
+            .open_file(file.path())
+            .map_err(|err| err.to_string())?;
+        io::copy(&mut reader, &mut archive).map_err(|err| err.to_string())?;
+        archive.rewind().map_err(|err| err.to_string())?;
+        let mut dirs = HashSet::new();
+        read_archive(format, archive, |entry, reader| {
+            let path = extract_path(&wrkdir, &entry.name)?;
+            for dir in Self::dirs_to_create(&wrkdir, &path, entry, &mut dirs) {
+                self.host_bridge
+                    .mkdir_ex(&dir, true)
+                    .map_err(|err| format!("could not create \"{}\": {err}", dir.display()))?;
+            }
+            if entry.dir {
+                return Ok(());
+            }
+            let mut writer = self
+                .host_bridge
+                .create_file(&path, &Self::extracted_metadata(entry))
+                .map_err(|err| format!("could not write \"{}\": {err}", path.display()))?;
+            io::copy(reader, &mut writer)
+                .map_err(|err| format!("could not write \"{}\": {err}", path.display()))?;
+            self.host_bridge
+                .finalize_write(writer)
+                .map_err(|err| format!("could not write \"{}\": {err}", path.display()))
+        })
+    }
+
+    fn remote_extract(&mut self, file: &File, format: ArchiveFormat) -> Result<usize, String> {
+        let wrkdir = self.remote().wrkdir.clone();
+        let mut archive = Self::archive_tempfile()?;
+        let dest = archive.try_clone().map_err(|err| err.to_string())?;
+        self.client
+            .open_file(file.path(), Box::new(dest))
+            .map_err(|err| err.to_string())?;
+        archive.rewind().map_err(|err| err.to_string())?;
+        let mut dirs = HashSet::new();
+        read_archive(format, archive, |entry, reader| {
+            let path = extract_path(&wrkdir, &entry.name)?;
+            for dir in Self::dirs_to_create(&wrkdir, &path, entry, &mut dirs) {
+                match self.client.create_dir(&dir, UnixPex::from(0o755)) {
+                    Ok(()) => {}
+                    Err(err) if err.kind == RemoteErrorType::DirectoryAlreadyExists => {}
+                    Err(err) => {
+                        return Err(format!("could not create \"{}\": {err}", dir.display()));
+                    }
+                }
+            }
+            if entry.dir {
+                return Ok(());
+            }
+            // Remote writers take ownership of the reader, so the entry is buffered first
+            let mut content = Self::archive_tempfile()?;
+            io::copy(reader, &mut content)
+                .and_then(|_| content.rewind())
+                .map_err(|err| format!("could not read \"{}\": {err}", entry.name))?;
+            self.client
+                .create_file(&path, &Self::extracted_metadata(entry), Box::new(content))
+                .map(|_| ())
+                .map_err(|err| format!("could not write \"{}\": {err}", path.display()))
+        })
+    }
+
+    /// Get the directories to create before extracting `entry` at `path`, which haven't been created yet
+    fn dirs_to_create(
+        wrkdir: &Path,
+        path: &Path,
+        entry: &ExtractedEntry,
+        created: &mut HashSet<PathBuf>,
+    ) -> Vec<PathBuf> {
+        let dir = match entry.dir {
+            true => Some(path),
+            false => path.parent(),
+        };
+        let mut dirs: Vec<PathBuf> = dir
+            .into_iter()
+            .flat_map(Path::ancestors)
+            .take_while(|dir| *dir != wrkdir)
+            .filter(|dir| created.insert(dir.to_path_buf()))
+            .map(Path::to_path_buf)
+            .collect();
+        dirs.reverse();
+        dirs
+    }
+
+    fn extracted_metadata(entry: &ExtractedEntry) -> Metadata {
+        let metadata = Metadata::default().size(entry.size);
+        match entry.mode {
+            Some(mode) => metadata.mode(UnixPex::from(mode & 0o777)),
+            None => metadata,
+        }
+    }
+}
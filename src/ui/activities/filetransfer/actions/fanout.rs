@@ -0,0 +1,118 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+// locals
+use remotefs::RemoteFs;
+use tuirealm::{State, StateValue};
+
+use super::{File, FileTransferActivity, Id, LogLevel, Msg, PendingActionMsg, TransferPayload};
+use crate::filetransfer::RemoteFsBuilder;
+use crate::ui::activities::filetransfer::lib::fanout::FanoutReport;
+
+impl FileTransferActivity {
+    /// Upload the selected local files to several bookmarked remotes, one after another.
+    ///
+    /// Files are uploaded into the remote path of the bookmark, or into the working directory
+    /// of the remote if the bookmark has none
+    pub(crate) fn action_fanout(&mut self) {
+        let files = self.get_local_selected_entries().get_files();
+        if files.is_empty() {
+            return;
+        }
+        let mut bookmarks: Vec<String> = self
+            .context()
+            .bookmarks_client()
+            .map(|cli| cli.iter_bookmarks().cloned().collect())
+            .unwrap_or_default();
+        if bookmarks.is_empty() {
+            self.mount_error("There are no bookmarks to transfer files to");
+            return;
+        }
+        bookmarks.sort();
+        // Choose destinations
+        self.mount_fanout_popup(&bookmarks);
+        let confirmed = self.wait_for_pending_msg(&[
+            Msg::PendingAction(PendingActionMsg::Fanout),
+            Msg::PendingAction(PendingActionMsg::FanoutCancel),
+        ]) == Msg::PendingAction(PendingActionMsg::Fanout);
+        let destinations: Vec<String> = match self.app.state(&Id::FanoutPopup) {
+            Ok(State::Vec(selected)) => selected
+                .into_iter()
+                .filter_map(|x| match x {
+                    StateValue::Usize(idx) => bookmarks.get(idx).cloned(),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        self.umount_fanout_popup();
+        if !confirmed || destinations.is_empty() {
+            return;
+        }
+        // Transfer
+        let report = FanoutReport::run(&destinations, |bookmark| {
+            self.fanout_transfer(bookmark, &files)
+        });
+        // Restore the remote explorer, which has been listing the destinations
+        self.reload_remote_dir();
+        for result in report.results.iter() {
+            match &result.result {
+                Ok(()) => self.log(
+                    LogLevel::Info,
+                    format!("Transferred files to \"{}\"", result.destination),
+                ),
+                Err(err) => self.log(
+                    LogLevel::Error,
+                    format!(
+                        "Could not transfer files to \"{}\": {err}",
+                        result.destination
+                    ),
+                ),
+            }
+        }
+        if report.failed().count() > 0 {
+            self.mount_error(report.summary());
+        } else {
+            self.mount_info(report.summary());
+        }
+    }
+
+    /// Connect to the remote of `bookmark` and upload `files` there
+    fn fanout_transfer(&mut self, bookmark: &str, files: &[File]) -> Result<(), String> {
+        let params = self
+            .context()
+            .bookmarks_client()
+            .and_then(|cli| cli.get_bookmark(bookmark))
+            .ok_or_else(|| String::from("bookmark not found"))?;
+        let mut client: Box<dyn RemoteFs> =
+            RemoteFsBuilder::build(params.protocol, params.params.clone(), self.config())?;
+        self.mount_blocking_wait(format!("Connecting to \"{bookmark}\"…"));
+        let connected = RemoteFsBuilder::probe(params.protocol, &params.params)
+            .and_then(|_| client.connect())
+            .map_err(|err| err.to_string());
+        self.umount_wait();
+        connected?;
+        let dest = match params.remote_path {
+            Some(path) => Ok(path),
+            None => client.pwd().map_err(|err| err.to_string()),
+        };
+        let result = dest.and_then(|dest| {
+            let payload = match files {
+                [file] => TransferPayload::Any(file.clone()),
+                files => TransferPayload::TransferQueue(
+                    files.iter().map(|f| (f.clone(), dest.clone())).collect(),
+                ),
+            };
+            // Transfer through the client of the destination
+            std::mem::swap(&mut self.client, &mut client);
+            let result = self.filetransfer_send(payload, dest.as_path(), None);
+            std::mem::swap(&mut self.client, &mut client);
+            result
+        });
+        if let Err(err) = client.disconnect() {
+            warn!("Failed to disconnect from \"{}\": {}", bookmark, err);
+        }
+        result
+    }
+}
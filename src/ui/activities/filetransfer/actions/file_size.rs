@@ -1,7 +1,9 @@
 use remotefs::File;
 
 use super::{FileTransferActivity, LogLevel};
+use crate::ui::activities::filetransfer::MarkQueue;
 use crate::ui::activities::filetransfer::lib::browser::FileExplorerTab;
+use crate::ui::activities::filetransfer::lib::transfer::QueueSummary;
 
 #[derive(Debug, Copy, Clone)]
 enum Host {
@@ -40,6 +42,44 @@ impl FileTransferActivity {
         ));
     }
 
+    /// Show the total size of each transfer queue, and the time to transfer it at the speed of the last transfer
+    pub(crate) fn action_summarize_transfer_queues(&mut self) {
+        self.mount_blocking_wait("Getting total path size...");
+        for queue in [MarkQueue::Local, MarkQueue::Remote] {
+            let summary = self.get_queue_summary(queue);
+            self.remount_transfer_queue(queue, Some(&summary));
+        }
+        self.umount_wait();
+    }
+
+    fn get_queue_summary(&mut self, queue: MarkQueue) -> QueueSummary {
+        let (host, paths) = match queue {
+            MarkQueue::Local => (Host::HostBridge, self.host_bridge().enqueued()),
+            MarkQueue::Remote => (Host::Remote, self.remote().enqueued()),
+        };
+        let paths = paths.keys().cloned().collect::<Vec<_>>();
+        let sizes = paths
+            .iter()
+            .map(|path| {
+                let stat_res = match host {
+                    Host::HostBridge => self.host_bridge.stat(path).map_err(|e| e.to_string()),
+                    Host::Remote => self.client.stat(path).map_err(|e| e.to_string()),
+                };
+                match stat_res {
+                    Ok(file) => Some(self.get_file_size(file, host)),
+                    Err(err_msg) => {
+                        self.log(
+                            LogLevel::Error,
+                            format!("Failed to stat {}: {err_msg}", path.display()),
+                        );
+                        None
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        QueueSummary::new(sizes, self.transfer.full.calc_bytes_per_second())
+    }
+
     fn get_files_size(&mut self, files: Vec<File>, host: Host) -> u64 {
         files.into_iter().map(|f| self.get_file_size(f, host)).sum()
     }
@@ -1,51 +1,29 @@
 use std::str::FromStr;
 
-use regex::Regex;
-use remotefs::File;
-use wildmatch::WildMatch;
-
+use super::LogLevel;
+use crate::explorer::filter::FileFilter;
 use crate::ui::activities::filetransfer::FileTransferActivity;
 use crate::ui::activities::filetransfer::lib::browser::FileExplorerTab;
 
-#[derive(Clone, Debug)]
-pub enum Filter {
-    Regex(Regex),
-    Wildcard(WildMatch),
-}
-
-impl FromStr for Filter {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // try as regex
-        if let Ok(regex) = Regex::new(s) {
-            Ok(Self::Regex(regex))
-        } else {
-            Ok(Self::Wildcard(WildMatch::new(s)))
-        }
-    }
-}
-
-impl Filter {
-    fn matches(&self, s: &str) -> bool {
-        debug!("matching '{s}' with {:?}", self);
-        match self {
-            Self::Regex(re) => re.is_match(s),
-            Self::Wildcard(wm) => wm.matches(s),
-        }
-    }
-}
-
 impl FileTransferActivity {
-    pub fn filter(&self, filter: &str) -> Vec<File> {
-        let filter = Filter::from_str(filter).unwrap();
-
+    /// Filter the files listed in the current explorer by `expression`; an empty expression lists all the files.
+    /// The filter is kept when the working directory changes
+    pub(crate) fn action_filter(&mut self, expression: &str) {
+        let filter = match expression.is_empty() {
+            true => None,
+            false => FileFilter::from_str(expression).ok(),
+        };
         match self.browser.tab() {
-            FileExplorerTab::HostBridge => self.browser.host_bridge().iter_files(),
-            FileExplorerTab::Remote => self.browser.remote().iter_files(),
-            _ => return vec![],
+            FileExplorerTab::HostBridge => self.host_bridge_mut().set_filter(filter),
+            FileExplorerTab::Remote => self.remote_mut().set_filter(filter),
+            FileExplorerTab::FindHostBridge | FileExplorerTab::FindRemote => return,
+        }
+        match expression.is_empty() {
+            true => self.log(LogLevel::Info, String::from("Removed file filter")),
+            false => self.log(
+                LogLevel::Info,
+                format!("Filtering files by \"{expression}\""),
+            ),
         }
-        .filter(|f| filter.matches(&f.name()))
-        .cloned()
-        .collect()
     }
 }
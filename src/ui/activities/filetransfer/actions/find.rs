@@ -35,6 +35,7 @@ impl FileTransferActivity {
     }
 
     pub(crate) fn action_find_transfer(&mut self, opts: TransferOpts) {
+        let rsync = opts.rsync.unwrap_or_else(|| self.config().get_rsync());
         let wrkdir: PathBuf = match self.browser.tab() {
             FileExplorerTab::FindHostBridge | FileExplorerTab::HostBridge => {
                 self.remote().wrkdir.clone()
@@ -47,7 +48,7 @@ impl FileTransferActivity {
             SelectedFile::One(entry) => match self.browser.tab() {
                 FileExplorerTab::FindHostBridge | FileExplorerTab::HostBridge => {
                     let file_to_check = Self::file_to_check(&entry, opts.save_as.as_ref());
-                    if self.config().get_prompt_on_file_replace()
+                    if opts.prompt_on_replace(self.config())
                         && self.remote_file_exists(file_to_check.as_path())
                         && !self.should_replace_file(
                             opts.save_as.clone().unwrap_or_else(|| entry.name()),
@@ -56,10 +57,11 @@ impl FileTransferActivity {
                         // Do not replace
                         return;
                     }
-                    if let Err(err) = self.filetransfer_send(
+                    if let Err(err) = self.filetransfer_send_with_rsync(
                         TransferPayload::Any(entry),
                         wrkdir.as_path(),
                         opts.save_as,
+                        rsync,
                     ) {
                         self.log_and_alert(
                             LogLevel::Error,
@@ -69,7 +71,7 @@ impl FileTransferActivity {
                 }
                 FileExplorerTab::FindRemote | FileExplorerTab::Remote => {
                     let file_to_check = Self::file_to_check(&entry, opts.save_as.as_ref());
-                    if self.config().get_prompt_on_file_replace()
+                    if opts.prompt_on_replace(self.config())
                         && self.host_bridge_file_exists(file_to_check.as_path())
                         && !self.should_replace_file(
                             opts.save_as.clone().unwrap_or_else(|| entry.name()),
@@ -78,10 +80,11 @@ impl FileTransferActivity {
                         // Do not replace
                         return;
                     }
-                    if let Err(err) = self.filetransfer_recv(
+                    if let Err(err) = self.filetransfer_recv_with_rsync(
                         TransferPayload::Any(entry),
                         wrkdir.as_path(),
                         opts.save_as,
+                        rsync,
                     ) {
                         self.log_and_alert(
                             LogLevel::Error,
@@ -109,10 +112,11 @@ impl FileTransferActivity {
                             debug!("User cancelled file transfer due to overwrites");
                             return;
                         };
-                        if let Err(err) = self.filetransfer_send(
+                        if let Err(err) = self.filetransfer_send_with_rsync(
                             TransferPayload::TransferQueue(entries),
                             dest_path.as_path(),
                             None,
+                            rsync,
                         ) {
                             {
                                 self.log_and_alert(
@@ -133,10 +137,11 @@ impl FileTransferActivity {
                             debug!("User cancelled file transfer due to overwrites");
                             return;
                         };
-                        if let Err(err) = self.filetransfer_recv(
+                        if let Err(err) = self.filetransfer_recv_with_rsync(
                             TransferPayload::TransferQueue(entries),
                             dest_path.as_path(),
                             None,
+                            rsync,
                         ) {
                             self.log_and_alert(
                                 LogLevel::Error,
@@ -0,0 +1,40 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+use tuirealm::props::{AttrValue, Attribute, PropPayload, PropValue};
+use tuirealm::{State, StateValue};
+
+use super::{FileTransferActivity, Id};
+use crate::ui::activities::filetransfer::lib::browser::FileExplorerTab;
+
+impl FileTransferActivity {
+    /// Move the cursor of the current explorer to the next entry starting with `ch`, wrapping around
+    pub(crate) fn action_jump_to_entry(&mut self, ch: char) {
+        let id = match self.browser.tab() {
+            FileExplorerTab::HostBridge => Id::ExplorerHostBridge,
+            FileExplorerTab::Remote => Id::ExplorerRemote,
+            FileExplorerTab::FindHostBridge | FileExplorerTab::FindRemote => return,
+        };
+        // on the `..` entry, the lookup starts at the first file
+        let current = match self.app.state(&id) {
+            Ok(State::One(StateValue::Usize(index))) => Some(index),
+            _ => None,
+        };
+        if let Some(index) = self
+            .browser
+            .explorer()
+            .next_index_starting_with(current, ch)
+        {
+            assert!(
+                self.app
+                    .attr(
+                        &id,
+                        Attribute::Value,
+                        AttrValue::Payload(PropPayload::One(PropValue::Usize(index)))
+                    )
+                    .is_ok()
+            );
+        }
+    }
+}
@@ -2,7 +2,11 @@
 //!
 //! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
 
-use super::FileTransferActivity;
+use std::path::PathBuf;
+
+use super::{FileTransferActivity, LogLevel};
+use crate::system::clipboard;
+use crate::ui::activities::filetransfer::lib::browser::FileExplorerTab;
 
 impl FileTransferActivity {
     pub(crate) fn action_mark_file(&mut self, index: usize) {
@@ -16,4 +20,76 @@ impl FileTransferActivity {
     pub(crate) fn action_mark_clear(&mut self) {
         self.clear_queue();
     }
+
+    pub(crate) fn action_invert_selection(&mut self) {
+        self.invert_queue();
+    }
+
+    pub(crate) fn action_select_by_pattern(&mut self, pattern: &str) {
+        match self.enqueue_matching(pattern) {
+            0 => self.log(LogLevel::Info, format!("No file matches \"{pattern}\"")),
+            n => self.log(
+                LogLevel::Info,
+                format!("Selected {n} file(s) matching \"{pattern}\""),
+            ),
+        }
+    }
+
+    /// Copy the paths of the marked files, one per line, to the clipboard.
+    /// If the clipboard is not available, the paths are written to the log instead
+    pub(crate) fn action_copy_marked_paths(&mut self) {
+        let paths = self.browser.explorer().enqueued_paths();
+        let count = paths.len();
+        let text = paths
+            .iter()
+            .map(|x| x.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("\n");
+        if count == 0 {
+            self.log(LogLevel::Warn, "no file is marked".to_string());
+        } else if clipboard::copy(&text) {
+            self.log(
+                LogLevel::Info,
+                format!("copied {count} marked paths to clipboard"),
+            );
+        } else {
+            self.log(
+                LogLevel::Warn,
+                format!("clipboard is not available; marked paths:\n{text}"),
+            );
+        }
+    }
+
+    /// Copy the absolute path of the selected file to the clipboard.
+    /// On the `..` entry, the path of the current directory is copied
+    pub(crate) fn action_copy_path(&mut self) {
+        let path: Option<PathBuf> = match self.browser.tab() {
+            FileExplorerTab::HostBridge => Some(
+                self.get_local_selected_file()
+                    .map(|x| x.path)
+                    .unwrap_or_else(|| self.host_bridge().wrkdir.clone()),
+            ),
+            FileExplorerTab::Remote => Some(
+                self.get_remote_selected_file()
+                    .map(|x| x.path)
+                    .unwrap_or_else(|| self.remote().wrkdir.clone()),
+            ),
+            FileExplorerTab::FindHostBridge | FileExplorerTab::FindRemote => {
+                self.get_found_selected_file().map(|x| x.path)
+            }
+        };
+        let Some(path) = path else {
+            self.log(LogLevel::Warn, "no file is selected".to_string());
+            return;
+        };
+        let text = path.to_string_lossy();
+        if clipboard::copy(&text) {
+            self.log(LogLevel::Info, format!("copied \"{text}\" to clipboard"));
+        } else {
+            self.log(
+                LogLevel::Warn,
+                format!("clipboard is not available; selected path: {text}"),
+            );
+        }
+    }
 }
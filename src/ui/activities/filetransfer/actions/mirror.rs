@@ -0,0 +1,152 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+// locals
+use std::path::PathBuf;
+
+use super::walkdir::WalkdirError;
+use super::{
+    File, FileExplorerTab, FileTransferActivity, LogLevel, Msg, PendingActionMsg, TransferPayload,
+};
+use crate::ui::activities::filetransfer::lib::mirror::MirrorPlan;
+
+impl FileTransferActivity {
+    /// Mirror the working directory of the current tab onto the working directory of the other tab.
+    ///
+    /// Only new and changed entries are transferred; extraneous entries on the destination
+    /// are removed only if the user asks so
+    pub(crate) fn action_mirror(&mut self) {
+        let upload = match self.browser.tab() {
+            FileExplorerTab::HostBridge => true,
+            FileExplorerTab::Remote => false,
+            _ => return,
+        };
+        let src_root = self.host_bridge().wrkdir.clone();
+        let dest_root = self.remote().wrkdir.clone();
+        let (src_root, dest_root) = if upload {
+            (src_root, dest_root)
+        } else {
+            (dest_root, src_root)
+        };
+        // Scan both trees
+        self.mount_walkdir_wait();
+        let trees = if upload {
+            self.action_walkdir_local()
+                .and_then(|src| self.action_walkdir_remote().map(|dest| (src, dest)))
+        } else {
+            self.action_walkdir_remote()
+                .and_then(|src| self.action_walkdir_local().map(|dest| (src, dest)))
+        };
+        self.umount_wait();
+        let (src, dest) = match trees {
+            Ok(trees) => trees,
+            Err(WalkdirError::Error(err)) => {
+                self.mount_error(err.as_str());
+                return;
+            }
+            Err(WalkdirError::Aborted) => {
+                self.mount_info("Mirror aborted");
+                return;
+            }
+        };
+        let src = Self::walkdir_tree(&src_root, src);
+        let dest = Self::walkdir_tree(&dest_root, dest);
+        let plan = MirrorPlan::new(
+            &src.values()
+                .map(|(entry, _)| entry.clone())
+                .collect::<Vec<_>>(),
+            &dest
+                .values()
+                .map(|(entry, _)| entry.clone())
+                .collect::<Vec<_>>(),
+        );
+        if plan.is_empty() {
+            self.mount_info("Directories are already in sync");
+            return;
+        }
+        // Ask for confirmation
+        self.mount_mirror_popup(&plan.transfer, &plan.delete);
+        let delete_extraneous = match self.wait_for_pending_msg(&[
+            Msg::PendingAction(PendingActionMsg::Mirror),
+            Msg::PendingAction(PendingActionMsg::MirrorAndDelete),
+            Msg::PendingAction(PendingActionMsg::MirrorCancel),
+        ]) {
+            Msg::PendingAction(PendingActionMsg::Mirror) => false,
+            Msg::PendingAction(PendingActionMsg::MirrorAndDelete) => true,
+            _ => {
+                self.umount_mirror_popup();
+                return;
+            }
+        };
+        self.umount_mirror_popup();
+        // Remove destination entries replaced by an entry of a different kind
+        for p in plan.transfer.iter() {
+            if let (Some((src, _)), Some((dest, file))) = (src.get(p), dest.get(p))
+                && src.is_dir != dest.is_dir
+            {
+                self.mirror_remove(upload, file);
+            }
+        }
+        // Transfer
+        let queue: Vec<(File, PathBuf)> = plan
+            .transfer
+            .iter()
+            .filter_map(|p| src.get(p))
+            .map(|(entry, file)| {
+                let dest_dir = match entry.path.parent() {
+                    Some(parent) => dest_root.join(parent),
+                    None => dest_root.clone(),
+                };
+                (file.clone(), dest_dir)
+            })
+            .collect();
+        if !queue.is_empty() {
+            let result = if upload {
+                self.filetransfer_send(
+                    TransferPayload::TransferQueue(queue),
+                    dest_root.as_path(),
+                    None,
+                )
+            } else {
+                self.filetransfer_recv(
+                    TransferPayload::TransferQueue(queue),
+                    dest_root.as_path(),
+                    None,
+                )
+            };
+            if let Err(err) = result {
+                self.log_and_alert(
+                    LogLevel::Error,
+                    format!("Could not mirror \"{}\": {err}", src_root.display()),
+                );
+                return;
+            }
+        }
+        // Delete extraneous entries
+        if delete_extraneous {
+            for p in plan.delete.iter() {
+                if let Some((_, file)) = dest.get(p) {
+                    self.mirror_remove(upload, file);
+                }
+            }
+        }
+        self.log(
+            LogLevel::Info,
+            format!(
+                "Mirrored \"{}\" to \"{}\"",
+                src_root.display(),
+                dest_root.display()
+            ),
+        );
+    }
+
+    /// Remove a destination entry
+    fn mirror_remove(&mut self, upload: bool, file: &File) {
+        if upload {
+            self.remote_remove_file(file);
+        } else {
+            self.local_remove_file(file);
+        }
+    }
+}
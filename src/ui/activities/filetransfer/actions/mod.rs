@@ -17,20 +17,31 @@ use super::{
 use crate::explorer::FileExplorer;
 
 // actions
+pub(crate) mod bisync;
+pub(crate) mod bookmark;
 pub(crate) mod change_dir;
 pub(crate) mod chmod;
+pub(crate) mod clipboard;
+pub(crate) mod compress;
+pub(crate) mod connect;
 pub(crate) mod copy;
 pub(crate) mod delete;
+pub(crate) mod duplicate;
 pub(crate) mod edit;
 pub(crate) mod exec;
+pub(crate) mod extract;
+pub(crate) mod fanout;
 pub(crate) mod file_size;
 pub(crate) mod filter;
 pub(crate) mod find;
+pub(crate) mod jump;
 pub(crate) mod mark;
+pub(crate) mod mirror;
 pub(crate) mod mkdir;
 pub(crate) mod newfile;
 pub(crate) mod open;
 mod pending;
+pub(crate) mod preview;
 pub(crate) mod rename;
 pub(crate) mod save;
 pub(crate) mod scan;
@@ -7,6 +7,9 @@
 use std::path::{Path, PathBuf};
 
 use super::{File, FileTransferActivity, LogLevel, SelectedFile, TransferPayload};
+use crate::ui::activities::filetransfer::lib::open_with::{
+    expand_template, is_template, spawn_shell,
+};
 
 impl FileTransferActivity {
     /// Open local file
@@ -43,6 +46,8 @@ impl FileTransferActivity {
 
     /// Perform open lopcal file
     pub(crate) fn action_open_local_file(&mut self, entry: &File, open_with: Option<&str>) {
+        let associated = self.associated_command(entry, open_with);
+        let open_with = open_with.or(associated.as_deref());
         if self.host_bridge.is_localhost() {
             self.open_path_with(entry.path(), open_with);
         } else {
@@ -52,6 +57,8 @@ impl FileTransferActivity {
 
     /// Open remote file. The file is first downloaded to a temporary directory on localhost
     pub(crate) fn action_open_remote_file(&mut self, entry: &File, open_with: Option<&str>) {
+        let associated = self.associated_command(entry, open_with);
+        let open_with = open_with.or(associated.as_deref());
         // Download file
         let tmpfile: String =
             match self.get_cache_tmp_name(&entry.name(), entry.extension().as_deref()) {
@@ -90,6 +97,17 @@ impl FileTransferActivity {
         }
     }
 
+    /// Open the file at `path` on the host bridge
+    pub(crate) fn open_host_bridge_path(&mut self, path: &Path) {
+        match self.host_bridge.stat(path) {
+            Ok(entry) => self.action_open_local_file(&entry, None),
+            Err(err) => self.log(
+                LogLevel::Error,
+                format!("Could not open file `{}`: {err}", path.display()),
+            ),
+        }
+    }
+
     /// Open selected file with provided application
     pub(crate) fn action_local_open_with(&mut self, with: &str) {
         let entries: Vec<File> = match self.get_local_selected_entries() {
@@ -174,11 +192,22 @@ impl FileTransferActivity {
         }
     }
 
+    /// Get the command associated to the extension of `entry` in the configuration, if no program was specified to open it with.
+    /// The extension of the entry is used, since the temporary files of remote entries are renamed
+    fn associated_command(&self, entry: &File, open_with: Option<&str>) -> Option<String> {
+        match open_with {
+            Some(_) => None,
+            None => self.config().get_open_with_command(entry.path()),
+        }
+    }
+
     /// Common function which opens a path with default or specified program.
+    /// The program may be a command template, where `{}` is the path of the file and `{dir}` its directory
     fn open_path_with(&mut self, p: &Path, with: Option<&str>) {
         // Open file
         let result = match with {
             None => open::that(p),
+            Some(with) if is_template(with) => spawn_shell(&expand_template(with, p)),
             Some(with) => open::with(p, with),
         };
         // Log result
@@ -22,6 +22,8 @@ impl FileTransferActivity {
                 Ok(mut messages) => {
                     if !messages.is_empty() {
                         self.redraw = true;
+                        // Interacting with the confirmation popup cancels its timeout
+                        self.confirm_timeout.cancel();
                     }
                     let found = messages.iter().position(|m| wait_for.contains(m));
                     // Return if found
@@ -41,6 +43,17 @@ impl FileTransferActivity {
                     error!("Application error: {}", err);
                 }
             }
+            // Select the safe option of the confirmation popup once its timeout has elapsed
+            if let Some(msg) = self.confirm_timeout.poll() {
+                if wait_for.contains(&msg) {
+                    return msg;
+                }
+                let mut msg = Some(msg);
+                while msg.is_some() {
+                    msg = self.update(msg);
+                }
+                self.redraw = true;
+            }
             // Redraw
             if self.redraw {
                 self.view();
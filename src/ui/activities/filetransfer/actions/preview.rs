@@ -0,0 +1,121 @@
+//! ## FileTransferActivity
+//!
+//! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
+
+// locals
+use std::io::Read;
+use std::time::UNIX_EPOCH;
+
+use bytesize::ByteSize;
+use remotefs::File;
+use tuirealm::props::{Color, TextSpan};
+
+use super::{FileTransferActivity, Id};
+use crate::ui::activities::filetransfer::lib::browser::FileExplorerTab;
+use crate::ui::activities::filetransfer::lib::preview::{
+    PREVIEW_MAX_BYTES, PREVIEW_MAX_LINES, PreviewContent, is_image, preview_content, read_capped,
+};
+use crate::utils::fmt::fmt_date;
+
+impl FileTransferActivity {
+    /// Reload the preview pane, if visible, once another file has been highlighted
+    pub(crate) fn poll_preview(&mut self) {
+        if !self.browser.preview_visible() {
+            return;
+        }
+        let (file, remote) = match self.browser.tab() {
+            FileExplorerTab::HostBridge => (self.get_local_selected_file(), false),
+            FileExplorerTab::Remote => (self.get_remote_selected_file(), true),
+            FileExplorerTab::FindHostBridge => (self.get_found_selected_file(), false),
+            FileExplorerTab::FindRemote => (self.get_found_selected_file(), true),
+        };
+        let path = file.as_ref().map(|file| file.path().to_path_buf());
+        if self.app.mounted(&Id::Preview) && path == self.previewed {
+            return;
+        }
+        let (title, rows) = match file {
+            Some(file) => (file.name(), self.preview_rows(&file, remote)),
+            None => (String::default(), vec![]),
+        };
+        self.mount_preview(&title, rows);
+        self.previewed = path;
+        self.redraw = true;
+    }
+
+    /// Get the rows to preview the file with: the first lines of text files, or the metadata of any other file
+    fn preview_rows(&mut self, file: &File, remote: bool) -> Vec<TextSpan> {
+        if file.is_file() && !is_image(file.path()) {
+            match self.read_preview(file, remote) {
+                Ok(PreviewContent::Text { lines, truncated }) => {
+                    let mut rows: Vec<TextSpan> = lines.into_iter().map(TextSpan::from).collect();
+                    if truncated {
+                        rows.push(TextSpan::from("…").fg(Color::DarkGray));
+                    }
+                    return rows;
+                }
+                Ok(PreviewContent::Binary) => {}
+                Err(err) => {
+                    debug!("could not preview {}: {}", file.path().display(), err);
+                }
+            }
+        }
+        self.preview_metadata_rows(file)
+    }
+
+    /// Read the beginning of the file, up to the preview byte cap
+    fn read_preview(&mut self, file: &File, remote: bool) -> Result<PreviewContent, String> {
+        let (bytes, capped) = if remote {
+            let mut stream = self
+                .client
+                .open(file.path())
+                .map_err(|err| err.to_string())?;
+            let result = read_capped(&mut stream, PREVIEW_MAX_BYTES);
+            if let Err(err) = self.client.on_read(stream) {
+                debug!(
+                    "could not finalize read of {}: {}",
+                    file.path().display(),
+                    err
+                );
+            }
+            result.map_err(|err| err.to_string())?
+        } else {
+            let mut reader: Box<dyn Read + Send> = self
+                .host_bridge
+                .open_file(file.path())
+                .map_err(|err| err.to_string())?;
+            read_capped(&mut reader, PREVIEW_MAX_BYTES).map_err(|err| err.to_string())?
+        };
+        Ok(preview_content(&bytes, capped, PREVIEW_MAX_LINES))
+    }
+
+    fn preview_metadata_rows(&self, file: &File) -> Vec<TextSpan> {
+        let kind = if file.is_dir() {
+            "Directory"
+        } else if file.is_symlink() {
+            "Symlink"
+        } else if is_image(file.path()) {
+            "Image"
+        } else {
+            "Binary file"
+        };
+        let date_fmt = self
+            .config()
+            .get_date_fmt()
+            .unwrap_or_else(|| String::from("%b %d %Y %H:%M:%S"));
+        let mut rows = vec![
+            TextSpan::from(kind).bold(),
+            TextSpan::from(format!("Size: {}", ByteSize(file.metadata().size))),
+            TextSpan::from(format!(
+                "Modified: {}",
+                fmt_date(
+                    file.metadata().modified.unwrap_or(UNIX_EPOCH),
+                    date_fmt.as_str()
+                )
+            )),
+        ];
+        if let Some(mode) = file.metadata().mode {
+            rows.push(TextSpan::from(format!("Mode: {:o}", u32::from(mode))));
+        }
+        rows
+    }
+}
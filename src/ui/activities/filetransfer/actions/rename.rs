@@ -55,7 +55,7 @@ impl FileTransferActivity {
         }
     }
 
-    fn local_rename_file(&mut self, entry: &File, dest: &Path) {
+    pub(crate) fn local_rename_file(&mut self, entry: &File, dest: &Path) {
         match self.host_bridge.rename(entry, dest) {
             Ok(_) => {
                 self.log(
@@ -58,12 +58,26 @@ impl FileTransferActivity {
         self.remote_recv_file(TransferOpts::default());
     }
 
+    /// Download the selected remote file and open it once the transfer is complete
+    pub(crate) fn action_remote_recv_and_open(&mut self) {
+        self.remote_recv_file(TransferOpts::default().open(true));
+    }
+
+    pub(crate) fn action_local_send_rsync(&mut self) {
+        self.local_send_file(TransferOpts::default().rsync(Some(true)));
+    }
+
+    pub(crate) fn action_remote_recv_rsync(&mut self) {
+        self.remote_recv_file(TransferOpts::default().rsync(Some(true)));
+    }
+
     fn local_send_file(&mut self, opts: TransferOpts) {
         let wrkdir: PathBuf = self.remote().wrkdir.clone();
+        let rsync = opts.rsync.unwrap_or_else(|| self.config().get_rsync());
         match self.get_local_selected_entries() {
             SelectedFile::One(entry) => {
                 let file_to_check = Self::file_to_check(&entry, opts.save_as.as_ref());
-                if self.config().get_prompt_on_file_replace()
+                if opts.prompt_on_replace(self.config())
                     && self.remote_file_exists(file_to_check.as_path())
                     && !self
                         .should_replace_file(opts.save_as.clone().unwrap_or_else(|| entry.name()))
@@ -71,10 +85,11 @@ impl FileTransferActivity {
                     // Do not replace
                     return;
                 }
-                if let Err(err) = self.filetransfer_send(
+                if let Err(err) = self.filetransfer_send_with_rsync(
                     TransferPayload::Any(entry),
                     wrkdir.as_path(),
                     opts.save_as,
+                    rsync,
                 ) {
                     {
                         self.log_and_alert(
@@ -97,10 +112,11 @@ impl FileTransferActivity {
                     debug!("User cancelled file transfer due to overwrites");
                     return;
                 };
-                if let Err(err) = self.filetransfer_send(
+                if let Err(err) = self.filetransfer_send_with_rsync(
                     TransferPayload::TransferQueue(entries),
                     dest_path.as_path(),
                     None,
+                    rsync,
                 ) {
                     {
                         self.log_and_alert(
@@ -120,21 +136,24 @@ impl FileTransferActivity {
 
     fn remote_recv_file(&mut self, opts: TransferOpts) {
         let wrkdir: PathBuf = self.host_bridge().wrkdir.clone();
+        let rsync = opts.rsync.unwrap_or_else(|| self.config().get_rsync());
         match self.get_remote_selected_entries() {
             SelectedFile::One(entry) => {
                 let file_to_check = Self::file_to_check(&entry, opts.save_as.as_ref());
-                if self.config().get_prompt_on_file_replace()
+                if opts.prompt_on_replace(self.config())
                     && self.host_bridge_file_exists(file_to_check.as_path())
                     && !self
                         .should_replace_file(opts.save_as.clone().unwrap_or_else(|| entry.name()))
                 {
                     return;
                 }
-                if let Err(err) = self.filetransfer_recv(
-                    TransferPayload::Any(entry),
+                let result = self.filetransfer_recv_with_rsync(
+                    TransferPayload::Any(entry.clone()),
                     wrkdir.as_path(),
-                    opts.save_as,
-                ) {
+                    opts.save_as.clone(),
+                    rsync,
+                );
+                if let Err(err) = &result {
                     {
                         self.log_and_alert(
                             LogLevel::Error,
@@ -142,6 +161,9 @@ impl FileTransferActivity {
                         );
                     }
                 }
+                if let Some(path) = opts.path_to_open(&entry, wrkdir.as_path(), &result) {
+                    self.open_host_bridge_path(path.as_path());
+                }
             }
             SelectedFile::Many(entries) => {
                 // In case of selection: save multiple files in wrkdir/input
@@ -156,10 +178,11 @@ impl FileTransferActivity {
                     return;
                 };
 
-                if let Err(err) = self.filetransfer_recv(
+                if let Err(err) = self.filetransfer_recv_with_rsync(
                     TransferPayload::TransferQueue(entries),
                     dest_path.as_path(),
                     None,
+                    rsync,
                 ) {
                     {
                         self.log_and_alert(
@@ -326,3 +349,46 @@ impl FileTransferActivity {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+    use remotefs::fs::Metadata;
+
+    use super::*;
+
+    fn file(path: &str) -> File {
+        File {
+            path: PathBuf::from(path),
+            metadata: Metadata::default(),
+        }
+    }
+
+    #[test]
+    fn should_transfer_file_to_other_panel_wrkdir_with_same_name() {
+        let entry = file("/home/omar/Documents/report.pdf");
+        // One file is transferred to the working directory of the other panel, keeping its name
+        assert_eq!(
+            FileTransferActivity::file_to_check(&entry, None),
+            PathBuf::from("report.pdf")
+        );
+        assert_eq!(
+            FileTransferActivity::file_to_check(&entry, Some(&String::from("notes.pdf"))),
+            PathBuf::from("notes.pdf")
+        );
+    }
+
+    #[test]
+    fn should_transfer_many_files_to_other_panel_wrkdir_with_same_names() {
+        let wrkdir = Path::new("/home/pi/uploads");
+        assert_eq!(
+            FileTransferActivity::file_to_check_many(&file("/tmp/a.txt"), wrkdir),
+            PathBuf::from("/home/pi/uploads/a.txt")
+        );
+        assert_eq!(
+            FileTransferActivity::file_to_check_many(&file("/tmp/src"), wrkdir),
+            PathBuf::from("/home/pi/uploads/src")
+        );
+    }
+}
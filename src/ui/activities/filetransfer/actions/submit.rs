@@ -4,23 +4,42 @@
 
 // locals
 use super::{File, FileTransferActivity};
+use crate::explorer::FileSubmitAction;
 
+#[derive(Debug, PartialEq, Eq)]
 enum SubmitAction {
     ChangeDir,
+    Edit,
+    Open,
+    Transfer,
     None,
 }
 
+impl SubmitAction {
+    /// Get the action to perform on submit of a file which is not a directory
+    fn for_file(action: FileSubmitAction) -> Self {
+        match action {
+            FileSubmitAction::Nothing => SubmitAction::None,
+            FileSubmitAction::Open => SubmitAction::Open,
+            FileSubmitAction::Transfer => SubmitAction::Transfer,
+            FileSubmitAction::Edit => SubmitAction::Edit,
+        }
+    }
+}
+
 impl FileTransferActivity {
-    /// Decides which action to perform on submit for local explorer
-    /// Return true whether the directory changed
+    /// Decides which action to perform on submit for local explorer;
+    /// directories are entered, while files get the configured file submit action
     pub(crate) fn action_submit_local(&mut self, entry: File) {
+        let file_action = SubmitAction::for_file(self.config().get_file_submit_action());
         let (action, entry) = if entry.is_dir() {
             (SubmitAction::ChangeDir, entry)
         } else if entry.metadata().symlink.is_some() {
             // Stat file
             let symlink = entry.metadata().symlink.as_ref().unwrap();
-            let stat_file = match self.host_bridge.stat(symlink.as_path()) {
-                Ok(e) => e,
+            match self.host_bridge.stat(symlink.as_path()) {
+                Ok(e) if !e.is_dir() => (file_action, entry),
+                Ok(e) => (SubmitAction::ChangeDir, e),
                 Err(err) => {
                     warn!(
                         "Could not stat file pointed by {} ({}): {}",
@@ -28,28 +47,36 @@ impl FileTransferActivity {
                         symlink.display(),
                         err
                     );
-                    entry
+                    (SubmitAction::ChangeDir, entry)
                 }
-            };
-            (SubmitAction::ChangeDir, stat_file)
+            }
         } else {
-            (SubmitAction::None, entry)
+            (file_action, entry)
         };
-        if let (SubmitAction::ChangeDir, entry) = (action, entry) {
-            self.action_enter_local_dir(entry)
+        match action {
+            SubmitAction::ChangeDir => self.action_enter_local_dir(entry),
+            SubmitAction::Edit => self.action_edit_local_file(),
+            SubmitAction::Open => self.action_open_local(),
+            SubmitAction::Transfer => {
+                self.action_local_send();
+                self.update_browser_file_list_swapped();
+            }
+            SubmitAction::None => {}
         }
     }
 
-    /// Decides which action to perform on submit for remote explorer
-    /// Return true whether the directory changed
+    /// Decides which action to perform on submit for remote explorer;
+    /// directories are entered, while files get the configured file submit action
     pub(crate) fn action_submit_remote(&mut self, entry: File) {
+        let file_action = SubmitAction::for_file(self.config().get_file_submit_action());
         let (action, entry) = if entry.is_dir() {
             (SubmitAction::ChangeDir, entry)
         } else if entry.metadata().symlink.is_some() {
             // Stat file
             let symlink = entry.metadata().symlink.as_ref().unwrap();
-            let stat_file = match self.client.stat(symlink.as_path()) {
-                Ok(e) => e,
+            match self.client.stat(symlink.as_path()) {
+                Ok(e) if !e.is_dir() => (file_action, entry),
+                Ok(e) => (SubmitAction::ChangeDir, e),
                 Err(err) => {
                     warn!(
                         "Could not stat file pointed by {} ({}): {}",
@@ -57,15 +84,49 @@ impl FileTransferActivity {
                         symlink.display(),
                         err
                     );
-                    entry
+                    (SubmitAction::ChangeDir, entry)
                 }
-            };
-            (SubmitAction::ChangeDir, stat_file)
+            }
         } else {
-            (SubmitAction::None, entry)
+            (file_action, entry)
         };
-        if let (SubmitAction::ChangeDir, entry) = (action, entry) {
-            self.action_enter_remote_dir(entry)
+        match action {
+            SubmitAction::ChangeDir => self.action_enter_remote_dir(entry),
+            SubmitAction::Edit => self.action_edit_remote_file(),
+            SubmitAction::Open => self.action_open_remote(),
+            SubmitAction::Transfer => {
+                self.action_remote_recv();
+                self.update_browser_file_list_swapped();
+            }
+            SubmitAction::None => {}
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_get_submit_action_for_file() {
+        assert_eq!(
+            SubmitAction::for_file(FileSubmitAction::Nothing),
+            SubmitAction::None
+        );
+        assert_eq!(
+            SubmitAction::for_file(FileSubmitAction::Open),
+            SubmitAction::Open
+        );
+        assert_eq!(
+            SubmitAction::for_file(FileSubmitAction::Transfer),
+            SubmitAction::Transfer
+        );
+        assert_eq!(
+            SubmitAction::for_file(FileSubmitAction::Edit),
+            SubmitAction::Edit
+        );
+    }
+}
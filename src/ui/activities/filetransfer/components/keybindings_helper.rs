@@ -2,7 +2,7 @@
 //!
 //! Helper module for matching keybindings in components
 
-use tuirealm::event::{Key, KeyEvent};
+use tuirealm::event::KeyEvent;
 
 use crate::config::keybindings::{ExplorerKeyBindings, GlobalKeyBindings, KeyBinding};
 
@@ -11,6 +11,11 @@ pub fn key_matches(event: &KeyEvent, binding: &KeyBinding) -> bool {
     event.code == binding.key && event.modifiers == binding.modifiers
 }
 
+/// Check if a key event matches any of the keybindings of an action
+pub fn key_matches_any(event: &KeyEvent, bindings: &[KeyBinding]) -> bool {
+    bindings.iter().any(|binding| key_matches(event, binding))
+}
+
 
 /// Explorer keybinding matcher for file transfer activity
 pub struct ExplorerKeyMatcher<'a> {
@@ -24,168 +29,314 @@ impl<'a> ExplorerKeyMatcher<'a> {
     }
 
     // Navigation
+    pub fn is_leader(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.leader)
+    }
+
+    pub fn is_jump_to_entry(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.jump_to_entry)
+    }
+
+    pub fn is_set_dir_mark(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.set_dir_mark)
+    }
+
+    pub fn is_goto_dir_mark(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.goto_dir_mark)
+    }
+
     pub fn is_move_up(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.move_up) || ev.code == Key::Up
+        key_matches_any(ev, &self.explorer.move_up)
     }
 
     pub fn is_move_down(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.move_down) || ev.code == Key::Down
+        key_matches_any(ev, &self.explorer.move_down)
     }
 
     pub fn is_move_up_page(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.move_up_page) || ev.code == Key::PageUp
+        key_matches_any(ev, &self.explorer.move_up_page)
     }
 
     pub fn is_move_down_page(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.move_down_page) || ev.code == Key::PageDown
+        key_matches_any(ev, &self.explorer.move_down_page)
     }
 
     pub fn is_move_to_top(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.move_to_top) || ev.code == Key::Home
+        key_matches_any(ev, &self.explorer.move_to_top)
     }
 
     pub fn is_move_to_bottom(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.move_to_bottom) || ev.code == Key::End
+        key_matches_any(ev, &self.explorer.move_to_bottom)
     }
 
+    /// Go to the parent of the working directory (`..`)
     pub fn is_go_to_parent(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.go_to_parent)
+        key_matches_any(ev, &self.explorer.go_to_parent)
     }
 
+    /// Go back to the previous directory in the history, which isn't necessarily the parent
     pub fn is_go_back(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.go_back) || ev.code == Key::Backspace
+        key_matches_any(ev, &self.explorer.go_back)
+    }
+
+    /// Go forward to the directory left with go back, like the forward button of a browser
+    pub fn is_go_forward(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.go_forward)
+    }
+
+    /// Go to the home directory of the user on the host of the panel
+    pub fn is_go_home(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.go_home)
+    }
+
+    pub fn is_next_sibling_dir(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.next_sibling_dir)
+    }
+
+    pub fn is_prev_sibling_dir(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.prev_sibling_dir)
+    }
+
+    pub fn is_go_to_other_panel_dir(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.go_to_other_panel_dir)
     }
 
     pub fn is_enter_dir(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.enter_dir)
-            || key_matches(ev, &self.explorer.enter_dir_alt)
+        key_matches_any(ev, &self.explorer.enter_dir)
+            || key_matches_any(ev, &self.explorer.enter_dir_alt)
     }
 
     pub fn is_change_panel(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.change_panel)
-            || ev.code == Key::Tab
-            || ev.code == Key::BackTab
-            || ev.code == Key::Left
-            || ev.code == Key::Right
+        key_matches_any(ev, &self.explorer.change_panel)
     }
 
     // File operations
     pub fn is_transfer_file(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.transfer_file)
+        key_matches_any(ev, &self.explorer.transfer_file)
+    }
+
+    pub fn is_transfer_file_rsync(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.transfer_file_rsync)
     }
 
     pub fn is_copy_file(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.copy_file) || ev.code == Key::Function(5)
+        key_matches_any(ev, &self.explorer.copy_file)
     }
 
     pub fn is_rename_file(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.rename_file) || ev.code == Key::Function(6)
+        key_matches_any(ev, &self.explorer.rename_file)
     }
 
     pub fn is_delete_file(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.delete_file)
-            || ev.code == Key::Delete
-            || ev.code == Key::Function(8)
+        key_matches_any(ev, &self.explorer.delete_file)
     }
 
     pub fn is_mkdir(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.mkdir) || ev.code == Key::Function(7)
+        key_matches_any(ev, &self.explorer.mkdir)
     }
 
     pub fn is_new_file(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.new_file)
+        key_matches_any(ev, &self.explorer.new_file)
     }
 
     pub fn is_edit_file(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.edit_file) || ev.code == Key::Function(4)
+        key_matches_any(ev, &self.explorer.edit_file)
     }
 
     pub fn is_open_file(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.open_file) || ev.code == Key::Function(3)
+        key_matches_any(ev, &self.explorer.open_file)
     }
 
     pub fn is_open_with(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.open_with)
+        key_matches_any(ev, &self.explorer.open_with)
     }
 
     pub fn is_save_as(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.save_as) || ev.code == Key::Function(2)
+        key_matches_any(ev, &self.explorer.save_as)
     }
 
     pub fn is_chmod(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.chmod)
+        key_matches_any(ev, &self.explorer.chmod)
     }
 
     pub fn is_symlink(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.symlink)
+        key_matches_any(ev, &self.explorer.symlink)
     }
 
     pub fn is_reload_dir(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.reload_dir)
+        key_matches_any(ev, &self.explorer.reload_dir)
+    }
+
+    pub fn is_mirror_dir(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.mirror_dir)
+    }
+
+    pub fn is_sync_dir(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.sync_dir)
+    }
+
+    pub fn is_fanout(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.fanout)
+    }
+
+    pub fn is_compress(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.compress)
+    }
+
+    pub fn is_extract(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.extract)
+    }
+
+    pub fn is_duplicate(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.duplicate)
+    }
+
+    pub fn is_save_bookmark(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.save_bookmark)
+    }
+
+    pub fn is_save_dir_bookmark(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.save_dir_bookmark)
+    }
+
+    pub fn is_dir_bookmarks(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.dir_bookmarks)
+    }
+
+    pub fn is_transfer_and_open(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.transfer_and_open)
+    }
+
+    pub fn is_cut_file(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.cut_file)
+    }
+
+    pub fn is_paste(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.paste)
     }
 
     // Selection
     pub fn is_mark_file(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.mark_file)
+        key_matches_any(ev, &self.explorer.mark_file)
     }
 
     pub fn is_mark_all(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.mark_all)
+        key_matches_any(ev, &self.explorer.mark_all)
     }
 
     pub fn is_unmark_all(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.unmark_all)
+        key_matches_any(ev, &self.explorer.unmark_all)
+    }
+
+    pub fn is_invert_selection(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.invert_selection)
+    }
+
+    pub fn is_select_by_pattern(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.select_by_pattern)
+    }
+
+    pub fn is_copy_marked(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.copy_marked)
+    }
+
+    pub fn is_copy_path(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.copy_path)
     }
 
     // View
     pub fn is_toggle_hidden(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.toggle_hidden)
+        key_matches_any(ev, &self.explorer.toggle_hidden)
+    }
+
+    pub fn is_toggle_hidden_patterns(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.toggle_hidden_patterns)
+    }
+
+    pub fn is_toggle_raw_sizes(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.toggle_raw_sizes)
     }
 
     pub fn is_file_info(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.file_info)
+        key_matches_any(ev, &self.explorer.file_info)
     }
 
     pub fn is_file_size(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.file_size)
+        key_matches_any(ev, &self.explorer.file_size)
     }
 
     pub fn is_sorting(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.sorting)
+        key_matches_any(ev, &self.explorer.sorting)
     }
 
     pub fn is_filter(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.filter)
+        key_matches_any(ev, &self.explorer.filter)
+    }
+
+    pub fn is_grow_panel(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.grow_panel)
+    }
+
+    pub fn is_shrink_panel(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.shrink_panel)
+    }
+
+    pub fn is_maximize_panel(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.maximize_panel)
     }
 
     // Search
     pub fn is_fuzzy_search(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.fuzzy_search)
+        key_matches_any(ev, &self.explorer.fuzzy_search)
     }
 
     pub fn is_goto_path(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.goto_path)
+        key_matches_any(ev, &self.explorer.goto_path)
+    }
+
+    pub fn is_toggle_search_regex(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.toggle_search_regex)
+    }
+
+    pub fn is_toggle_search_case(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.toggle_search_case)
+    }
+
+    pub fn is_close_find(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.close_find)
     }
 
     // Misc
     pub fn is_terminal(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.terminal)
+        key_matches_any(ev, &self.explorer.terminal)
     }
 
     pub fn is_sync_browsing(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.sync_browsing)
+        key_matches_any(ev, &self.explorer.sync_browsing)
     }
 
     pub fn is_watcher(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.watcher)
+        key_matches_any(ev, &self.explorer.watcher)
     }
 
     pub fn is_watched_paths(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.watched_paths)
+        key_matches_any(ev, &self.explorer.watched_paths)
     }
 
     pub fn is_pending_queue(&self, ev: &KeyEvent) -> bool {
-        key_matches(ev, &self.explorer.pending_queue)
+        key_matches_any(ev, &self.explorer.pending_queue)
+    }
+
+    pub fn is_toggle_log(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.toggle_log)
+    }
+
+    pub fn is_toggle_footer(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.toggle_footer)
+    }
+
+    pub fn is_preview(&self, ev: &KeyEvent) -> bool {
+        key_matches_any(ev, &self.explorer.preview)
     }
 
     // Global
@@ -194,3 +345,211 @@ impl<'a> ExplorerKeyMatcher<'a> {
     }
 }
 
+#[cfg(test)]
+mod test {
+
+    use tempfile::TempDir;
+    use tuirealm::event::{Key, KeyModifiers};
+
+    use super::*;
+    use crate::config::keybindings::KeyBindings;
+    use crate::system::keybindings_provider::KeyBindingsProvider;
+
+    #[test]
+    fn should_match_toggle_log_rebind() {
+        let mut keybindings = KeyBindings::default();
+        let matcher = ExplorerKeyMatcher::new(&keybindings.explorer, &keybindings.global);
+        assert!(matcher.is_toggle_log(&KeyEvent::new(Key::Char('l'), KeyModifiers::ALT)));
+        keybindings.explorer.toggle_log = vec![KeyBinding::ctrl(Key::Char('o'))];
+        let matcher = ExplorerKeyMatcher::new(&keybindings.explorer, &keybindings.global);
+        assert!(matcher.is_toggle_log(&KeyEvent::new(Key::Char('o'), KeyModifiers::CONTROL)));
+        assert!(!matcher.is_toggle_log(&KeyEvent::new(Key::Char('l'), KeyModifiers::ALT)));
+    }
+
+    #[test]
+    fn should_match_move_rebind_only() {
+        let mut keybindings = KeyBindings::default();
+        let matcher = ExplorerKeyMatcher::new(&keybindings.explorer, &keybindings.global);
+        assert!(matcher.is_move_up(&KeyEvent::new(Key::Up, KeyModifiers::NONE)));
+        assert!(matcher.is_move_down_page(&KeyEvent::new(Key::PageDown, KeyModifiers::NONE)));
+        keybindings.explorer.move_up = vec![KeyBinding::simple(Key::Char('k'))];
+        keybindings.explorer.move_down_page = vec![KeyBinding::ctrl(Key::Char('d'))];
+        let matcher = ExplorerKeyMatcher::new(&keybindings.explorer, &keybindings.global);
+        assert!(matcher.is_move_up(&KeyEvent::new(Key::Char('k'), KeyModifiers::NONE)));
+        assert!(!matcher.is_move_up(&KeyEvent::new(Key::Up, KeyModifiers::NONE)));
+        assert!(matcher.is_move_down_page(&KeyEvent::new(Key::Char('d'), KeyModifiers::CONTROL)));
+        assert!(!matcher.is_move_down_page(&KeyEvent::new(Key::PageDown, KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn should_match_go_home() {
+        let mut keybindings = KeyBindings::default();
+        let matcher = ExplorerKeyMatcher::new(&keybindings.explorer, &keybindings.global);
+        assert!(matcher.is_go_home(&KeyEvent::new(Key::Char('~'), KeyModifiers::NONE)));
+        assert!(!matcher.is_go_home(&KeyEvent::new(Key::Char('u'), KeyModifiers::NONE)));
+        keybindings.explorer.go_home = vec![KeyBinding::alt(Key::Char('h'))];
+        let matcher = ExplorerKeyMatcher::new(&keybindings.explorer, &keybindings.global);
+        assert!(matcher.is_go_home(&KeyEvent::new(Key::Char('h'), KeyModifiers::ALT)));
+        assert!(!matcher.is_go_home(&KeyEvent::new(Key::Char('~'), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn should_match_copy_path() {
+        let mut keybindings = KeyBindings::default();
+        let matcher = ExplorerKeyMatcher::new(&keybindings.explorer, &keybindings.global);
+        assert!(matcher.is_copy_path(&KeyEvent::new(Key::Char('y'), KeyModifiers::CONTROL)));
+        assert!(!matcher.is_copy_path(&KeyEvent::new(Key::Char('y'), KeyModifiers::NONE)));
+        keybindings.explorer.copy_path = vec![KeyBinding::alt(Key::Char('p'))];
+        let matcher = ExplorerKeyMatcher::new(&keybindings.explorer, &keybindings.global);
+        assert!(matcher.is_copy_path(&KeyEvent::new(Key::Char('p'), KeyModifiers::ALT)));
+        assert!(!matcher.is_copy_path(&KeyEvent::new(Key::Char('y'), KeyModifiers::CONTROL)));
+    }
+
+    #[test]
+    fn should_match_invert_selection() {
+        let keybindings = KeyBindings::default();
+        let matcher = ExplorerKeyMatcher::new(&keybindings.explorer, &keybindings.global);
+        assert!(matcher.is_invert_selection(&KeyEvent::new(Key::Char('i'), KeyModifiers::ALT)));
+        assert!(!matcher.is_invert_selection(&KeyEvent::new(Key::Char('i'), KeyModifiers::NONE)));
+        let keybindings = KeyBindings::emacs_style();
+        let matcher = ExplorerKeyMatcher::new(&keybindings.explorer, &keybindings.global);
+        assert!(matcher.is_invert_selection(&KeyEvent::new(Key::Char('t'), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn should_match_cut_and_paste() {
+        let keybindings = KeyBindings::default();
+        let matcher = ExplorerKeyMatcher::new(&keybindings.explorer, &keybindings.global);
+        assert!(matcher.is_cut_file(&KeyEvent::new(Key::Char('x'), KeyModifiers::CONTROL)));
+        assert!(!matcher.is_cut_file(&KeyEvent::new(Key::Char('x'), KeyModifiers::NONE)));
+        assert!(matcher.is_paste(&KeyEvent::new(Key::Char('v'), KeyModifiers::CONTROL)));
+        assert!(!matcher.is_paste(&KeyEvent::new(Key::Char('v'), KeyModifiers::NONE)));
+        let keybindings = KeyBindings::vim_style();
+        let matcher = ExplorerKeyMatcher::new(&keybindings.explorer, &keybindings.global);
+        assert!(matcher.is_cut_file(&KeyEvent::new(Key::Char('x'), KeyModifiers::NONE)));
+        assert!(matcher.is_paste(&KeyEvent::new(Key::Char('X'), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn should_match_any_binding_of_action() {
+        let mut keybindings = KeyBindings::default();
+        keybindings.explorer.mkdir = vec![
+            KeyBinding::simple(Key::Char('d')),
+            KeyBinding::simple(Key::Function(7)),
+        ];
+        let matcher = ExplorerKeyMatcher::new(&keybindings.explorer, &keybindings.global);
+        assert!(matcher.is_mkdir(&KeyEvent::new(Key::Char('d'), KeyModifiers::NONE)));
+        assert!(matcher.is_mkdir(&KeyEvent::new(Key::Function(7), KeyModifiers::NONE)));
+        assert!(!matcher.is_mkdir(&KeyEvent::new(Key::Char('x'), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn should_match_enter_dir_and_enter_dir_alt() {
+        let keybindings = KeyBindings::vim_style();
+        let matcher = ExplorerKeyMatcher::new(&keybindings.explorer, &keybindings.global);
+        // enter_dir
+        assert!(matcher.is_enter_dir(&KeyEvent::new(Key::Char('l'), KeyModifiers::NONE)));
+        // enter_dir_alt
+        assert!(matcher.is_enter_dir(&KeyEvent::new(Key::Enter, KeyModifiers::NONE)));
+        assert!(!matcher.is_enter_dir(&KeyEvent::new(Key::Char('h'), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn should_match_enter_dir_alt_rebind() {
+        let mut keybindings = KeyBindings::vim_style();
+        keybindings.explorer.enter_dir_alt = vec![KeyBinding::ctrl(Key::Char('o'))];
+        let matcher = ExplorerKeyMatcher::new(&keybindings.explorer, &keybindings.global);
+        assert!(matcher.is_enter_dir(&KeyEvent::new(Key::Char('o'), KeyModifiers::CONTROL)));
+        assert!(matcher.is_enter_dir(&KeyEvent::new(Key::Char('l'), KeyModifiers::NONE)));
+        assert!(!matcher.is_enter_dir(&KeyEvent::new(Key::Enter, KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn should_match_implicit_keys_of_older_files() {
+        let tmp_dir = TempDir::new().unwrap();
+        let keybindings_path = tmp_dir.path().join("keybindings.toml");
+        // The vim preset as shipped before the file format was versioned
+        std::fs::write(
+            keybindings_path.as_path(),
+            r#"[global]
+quit = "q"
+quit_alt = "Q"
+disconnect = "esc"
+help = "?"
+help_alt = "f1"
+
+[explorer]
+move_up = "k"
+move_down = "j"
+move_up_page = "ctrl+u"
+move_down_page = "ctrl+d"
+move_to_top = "g"
+move_to_bottom = "G"
+go_to_parent = "h"
+enter_dir = "l"
+enter_dir_alt = "enter"
+go_back = "-"
+change_panel = "tab"
+transfer_file = "p"
+copy_file = "c"
+rename_file = "r"
+delete_file = "d"
+mkdir = "a"
+new_file = "A"
+edit_file = "e"
+open_file = "o"
+open_with = "O"
+save_as = "S"
+chmod = "z"
+symlink = "K"
+reload_dir = "ctrl+r"
+mark_file = "space"
+mark_all = "V"
+unmark_all = "u"
+toggle_hidden = "."
+file_info = "i"
+file_size = "I"
+sorting = "s"
+filter = "F"
+fuzzy_search = "/"
+goto_path = ":"
+terminal = "!"
+sync_browsing = "Y"
+watcher = "w"
+watched_paths = "W"
+pending_queue = "P"
+
+[auth]
+quit = "esc"
+quit_alt = "ctrl+q"
+setup = "ctrl+c"
+help = "?"
+help_alt = "f1"
+release_notes = "ctrl+n"
+save_bookmark = "ctrl+s"
+
+[setup]
+quit = "esc"
+quit_alt = "ctrl+q"
+change_tab = "tab"
+help = "?"
+help_alt = "f1"
+revert = "ctrl+r"
+save = "ctrl+s"
+save_alt = "ctrl+w"
+"#,
+        )
+        .unwrap();
+        let provider = KeyBindingsProvider::new(keybindings_path.as_path()).unwrap();
+        let keybindings = provider.keybindings();
+        let matcher = ExplorerKeyMatcher::new(&keybindings.explorer, &keybindings.global);
+        assert!(matcher.is_move_up(&KeyEvent::new(Key::Char('k'), KeyModifiers::NONE)));
+        assert!(matcher.is_move_up(&KeyEvent::new(Key::Up, KeyModifiers::NONE)));
+        assert!(matcher.is_move_down(&KeyEvent::new(Key::Down, KeyModifiers::NONE)));
+        assert!(matcher.is_move_to_bottom(&KeyEvent::new(Key::End, KeyModifiers::NONE)));
+        assert!(matcher.is_go_back(&KeyEvent::new(Key::Backspace, KeyModifiers::NONE)));
+        assert!(matcher.is_change_panel(&KeyEvent::new(Key::BackTab, KeyModifiers::SHIFT)));
+        assert!(matcher.is_copy_file(&KeyEvent::new(Key::Function(5), KeyModifiers::NONE)));
+        assert!(matcher.is_delete_file(&KeyEvent::new(Key::Delete, KeyModifiers::NONE)));
+    }
+}
@@ -2,92 +2,368 @@
 //!
 //! file transfer activity components
 
+use std::ops::Range;
+
 use tui_realm_stdlib::Span;
-use tuirealm::props::{Color, TextSpan};
-use tuirealm::{Component, Event, MockComponent, NoUserEvent};
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{AttrValue, Attribute, Color, TextSpan};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::{Component, Event, Frame, MockComponent, NoUserEvent, State};
+use unicode_width::UnicodeWidthStr;
+
+use super::keybindings_helper::key_matches_any;
+use super::{Msg, UiMsg};
+use crate::config::keybindings::{KeyBinding, KeyBindings, fmt_bindings};
+
+/// Indicator shown at the end of the footer when the hints don't fit in a single page
+const MORE_HINTS: &str = "…";
+
+/// Actions shown in the footer if `footer_hints` isn't configured
+const DEFAULT_FOOTER_HINTS: [&str; 12] = [
+    "help",
+    "change_panel",
+    "transfer_file",
+    "enter_dir",
+    "save_as",
+    "open_file",
+    "edit_file",
+    "copy_file",
+    "rename_file",
+    "mkdir",
+    "delete_file",
+    "quit",
+];
+
+/// Format the keybindings of an action for display
+fn format_keys(bindings: &[KeyBinding]) -> String {
+    fmt_bindings(bindings).to_uppercase()
+}
+
+/// Get the label of an action in the footer
+fn footer_label(action: &str) -> String {
+    match action {
+        "help" => String::from("Help"),
+        "change_panel" => String::from("Tab"),
+        "transfer_file" => String::from("Transfer"),
+        "enter_dir" => String::from("Enter"),
+        "save_as" => String::from("Save"),
+        "open_file" => String::from("Open"),
+        "edit_file" => String::from("Edit"),
+        "copy_file" => String::from("Copy"),
+        "rename_file" => String::from("Rename"),
+        "mkdir" => String::from("Mkdir"),
+        "delete_file" => String::from("Del"),
+        "quit" => String::from("Quit"),
+        action => {
+            let label = action.replace('_', " ");
+            let mut chars = label.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => label,
+            }
+        }
+    }
+}
+
+/// Hint of the footer: the keys of an action and its label
+struct FooterHint {
+    keys: String,
+    label: String,
+}
 
-use super::Msg;
-use crate::config::keybindings::{KeyBinding, KeyBindings};
+impl FooterHint {
+    fn new(keys: impl ToString, label: &str) -> Self {
+        Self {
+            keys: format!("<{}>", keys.to_string()),
+            label: format!(" {label} "),
+        }
+    }
 
-/// Format a keybinding for display
-fn format_key(binding: &KeyBinding) -> String {
-    binding.to_string().to_uppercase()
+    /// Width of the hint once rendered
+    fn width(&self) -> usize {
+        self.keys.width() + self.label.width()
+    }
 }
 
-#[derive(MockComponent)]
+/// Footer with the keys of the main actions, or of the actions configured in `footer_hints`.
+///
+/// If the hints don't fit in the footer width, they are split into pages, which are cycled with the
+/// `next_footer_page` keys; the first page is shown again when the terminal is resized
 pub struct FooterBar {
     component: Span,
+    hints: Vec<FooterHint>,
+    key_color: Color,
+    next_page_keys: Vec<KeyBinding>,
+    pages: Vec<Range<usize>>,
+    page: usize,
+    /// Width the pages have been computed for; `None` until the footer is rendered
+    width: Option<u16>,
 }
 
 impl FooterBar {
-    pub fn new(key_color: Color, keybindings: Option<&KeyBindings>) -> Self {
-        let spans = if let Some(kb) = keybindings {
-            let global = &kb.global;
-            let explorer = &kb.explorer;
-            vec![
-                TextSpan::from(format!("<{}>", format_key(&global.help))).bold().fg(key_color),
-                TextSpan::from(" Help "),
-                TextSpan::from(format!("<{}>", format_key(&explorer.change_panel))).bold().fg(key_color),
-                TextSpan::from(" Tab "),
-                TextSpan::from(format!("<{}>", format_key(&explorer.transfer_file))).bold().fg(key_color),
-                TextSpan::from(" Transfer "),
-                TextSpan::from(format!("<{}>", format_key(&explorer.enter_dir))).bold().fg(key_color),
-                TextSpan::from(" Enter "),
-                TextSpan::from(format!("<{}>", format_key(&explorer.save_as))).bold().fg(key_color),
-                TextSpan::from(" Save "),
-                TextSpan::from(format!("<{}>", format_key(&explorer.open_file))).bold().fg(key_color),
-                TextSpan::from(" Open "),
-                TextSpan::from(format!("<{}>", format_key(&explorer.edit_file))).bold().fg(key_color),
-                TextSpan::from(" Edit "),
-                TextSpan::from(format!("<{}>", format_key(&explorer.copy_file))).bold().fg(key_color),
-                TextSpan::from(" Copy "),
-                TextSpan::from(format!("<{}>", format_key(&explorer.rename_file))).bold().fg(key_color),
-                TextSpan::from(" Rename "),
-                TextSpan::from(format!("<{}>", format_key(&explorer.mkdir))).bold().fg(key_color),
-                TextSpan::from(" Mkdir "),
-                TextSpan::from(format!("<{}>", format_key(&explorer.delete_file))).bold().fg(key_color),
-                TextSpan::from(" Del "),
-                TextSpan::from(format!("<{}>", format_key(&global.quit))).bold().fg(key_color),
-                TextSpan::from(" Quit "),
-            ]
-        } else {
+    pub fn new(
+        key_color: Color,
+        keybindings: Option<&KeyBindings>,
+        actions: Option<&[String]>,
+    ) -> Self {
+        let hints = match (keybindings, actions) {
+            (Some(kb), Some(actions)) => Self::action_hints(kb, actions),
+            (Some(kb), None) => Self::action_hints(kb, &DEFAULT_FOOTER_HINTS),
             // Default fallback
-            vec![
-                TextSpan::from("<F1|H>").bold().fg(key_color),
-                TextSpan::from(" Help "),
-                TextSpan::from("<TAB>").bold().fg(key_color),
-                TextSpan::from(" Change tab "),
-                TextSpan::from("<SPACE>").bold().fg(key_color),
-                TextSpan::from(" Transfer "),
-                TextSpan::from("<ENTER>").bold().fg(key_color),
-                TextSpan::from(" Enter dir "),
-                TextSpan::from("<F2|S>").bold().fg(key_color),
-                TextSpan::from(" Save as "),
-                TextSpan::from("<F3|V>").bold().fg(key_color),
-                TextSpan::from(" View "),
-                TextSpan::from("<F4|O>").bold().fg(key_color),
-                TextSpan::from(" Edit "),
-                TextSpan::from("<F5|C>").bold().fg(key_color),
-                TextSpan::from(" Copy "),
-                TextSpan::from("<F6|R>").bold().fg(key_color),
-                TextSpan::from(" Rename "),
-                TextSpan::from("<F7|D>").bold().fg(key_color),
-                TextSpan::from(" Make dir "),
-                TextSpan::from("<F8|DEL>").bold().fg(key_color),
-                TextSpan::from(" Delete "),
-                TextSpan::from("<F10|Q>").bold().fg(key_color),
-                TextSpan::from(" Quit "),
-            ]
+            (None, _) => vec![
+                FooterHint::new("F1|H", "Help"),
+                FooterHint::new("TAB", "Change tab"),
+                FooterHint::new("SPACE", "Transfer"),
+                FooterHint::new("ENTER", "Enter dir"),
+                FooterHint::new("F2|S", "Save as"),
+                FooterHint::new("F3|V", "View"),
+                FooterHint::new("F4|O", "Edit"),
+                FooterHint::new("F5|C", "Copy"),
+                FooterHint::new("F6|R", "Rename"),
+                FooterHint::new("F7|D", "Make dir"),
+                FooterHint::new("F8|DEL", "Delete"),
+                FooterHint::new("F10|Q", "Quit"),
+            ],
         };
+        let next_page_keys = keybindings
+            .map(|kb| kb.explorer.next_footer_page.clone())
+            .unwrap_or_else(|| KeyBindings::default().explorer.next_footer_page);
 
-        Self {
-            component: Span::default().spans(spans),
+        // Until the footer is rendered, all the hints are on a single page
+        let widths: Vec<usize> = hints.iter().map(FooterHint::width).collect();
+        let mut footer = Self {
+            component: Span::default(),
+            pages: paginate(&widths, usize::MAX),
+            hints,
+            key_color,
+            next_page_keys,
+            page: 0,
+            width: None,
+        };
+        footer.update_spans();
+        footer
+    }
+
+    /// Build the hints of `actions`, named as in the keybindings file.
+    ///
+    /// Unknown actions are skipped with a warning, unbound ones are skipped
+    fn action_hints<S: AsRef<str>>(keybindings: &KeyBindings, actions: &[S]) -> Vec<FooterHint> {
+        actions
+            .iter()
+            .map(AsRef::as_ref)
+            .filter_map(|action| match keybindings.explorer_action(action) {
+                Some(bindings) if bindings.is_empty() => None,
+                Some(bindings) => Some(FooterHint::new(
+                    format_keys(&bindings),
+                    &footer_label(action),
+                )),
+                None => {
+                    warn!("Unknown action in footer hints: {action}");
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Split the hints into pages fitting in `width`
+    fn set_width(&mut self, width: u16) {
+        let widths: Vec<usize> = self.hints.iter().map(FooterHint::width).collect();
+        self.pages = paginate(&widths, width as usize);
+        self.page = self.page.min(self.pages.len() - 1);
+        self.width = Some(width);
+        self.update_spans();
+    }
+
+    /// Show the next page of hints, going back to the first one after the last page.
+    ///
+    /// Returns whether the page has changed
+    fn next_page(&mut self) -> bool {
+        if self.pages.len() < 2 {
+            return false;
         }
+        self.page = (self.page + 1) % self.pages.len();
+        self.update_spans();
+        true
+    }
+
+    /// Set the spans of the current page
+    fn update_spans(&mut self) {
+        let mut spans: Vec<TextSpan> = self.hints[self.pages[self.page].clone()]
+            .iter()
+            .flat_map(|hint| {
+                [
+                    TextSpan::from(hint.keys.as_str()).bold().fg(self.key_color),
+                    TextSpan::from(hint.label.as_str()),
+                ]
+            })
+            .collect();
+        if self.pages.len() > 1 {
+            spans.push(TextSpan::from(MORE_HINTS).bold().fg(self.key_color));
+        }
+        self.component = Span::default().spans(spans);
+    }
+}
+
+/// Split hints of the given widths into pages fitting in `width` columns.
+///
+/// If the hints don't fit in a single page, each page keeps room for the [`MORE_HINTS`] indicator.
+/// A hint wider than `width` gets a page on its own
+fn paginate(widths: &[usize], width: usize) -> Vec<Range<usize>> {
+    let available = if widths.iter().sum::<usize>() <= width {
+        width
+    } else {
+        width.saturating_sub(MORE_HINTS.width())
+    };
+    let mut pages = Vec::new();
+    let mut start = 0;
+    let mut used = 0;
+    for (i, hint_width) in widths.iter().enumerate() {
+        if i > start && used + hint_width > available {
+            pages.push(start..i);
+            start = i;
+            used = 0;
+        }
+        used += hint_width;
+    }
+    pages.push(start..widths.len());
+    pages
+}
+
+impl MockComponent for FooterBar {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        if self.width != Some(area.width) {
+            self.set_width(area.width);
+        }
+        self.component.view(frame, area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.component.query(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.component.attr(attr, value);
+    }
+
+    fn state(&self) -> State {
+        self.component.state()
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        self.component.perform(cmd)
     }
 }
 
 impl Component<Msg, NoUserEvent> for FooterBar {
-    fn on(&mut self, _: Event<NoUserEvent>) -> Option<Msg> {
-        None
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(ref key_ev) if key_matches_any(key_ev, &self.next_page_keys) => {
+                let changed = self.next_page();
+                changed.then_some(Msg::Ui(UiMsg::FooterPageChanged))
+            }
+            Event::WindowResize(width, _) => {
+                // The footer takes the whole terminal width
+                self.page = 0;
+                self.set_width(width);
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+    use tuirealm::event::{Key, KeyEvent, KeyModifiers};
+
+    use super::*;
+
+    #[test]
+    fn should_paginate_footer_hints() {
+        let widths = [8, 6, 10, 4];
+        // everything fits
+        assert_eq!(paginate(&widths, 28), vec![0..4]);
+        assert_eq!(paginate(&widths, 200), vec![0..4]);
+        // room is kept for the indicator
+        assert_eq!(paginate(&widths, 27), vec![0..3, 3..4]);
+        assert_eq!(paginate(&widths, 15), vec![0..2, 2..4]);
+        assert_eq!(paginate(&widths, 11), vec![0..1, 1..2, 2..3, 3..4]);
+        // hints wider than the footer get their own page
+        assert_eq!(paginate(&widths, 5), vec![0..1, 1..2, 2..3, 3..4]);
+        assert_eq!(paginate(&widths, 0), vec![0..1, 1..2, 2..3, 3..4]);
+        assert_eq!(paginate(&[], 0), vec![0..0]);
+    }
+
+    #[test]
+    fn should_build_footer_hints_from_actions() {
+        let hints = |footer: FooterBar| -> Vec<(String, String)> {
+            footer
+                .hints
+                .into_iter()
+                .map(|hint| (hint.keys, hint.label))
+                .collect()
+        };
+        let keybindings = KeyBindings::default();
+        let default = hints(FooterBar::new(Color::Reset, Some(&keybindings), None));
+        assert_eq!(default.len(), DEFAULT_FOOTER_HINTS.len());
+        assert_eq!(default[0], (String::from("<H>"), String::from(" Help ")));
+        assert_eq!(
+            default[2],
+            (String::from("<SPACE>"), String::from(" Transfer "))
+        );
+        // configured actions, in order; unknown and unbound actions are skipped
+        let actions = [
+            String::from("mkdir"),
+            String::from("mkdirr"),
+            String::from("leader"),
+            String::from("go_home"),
+            String::from("quit"),
+        ];
+        assert_eq!(
+            hints(FooterBar::new(
+                Color::Reset,
+                Some(&keybindings),
+                Some(&actions)
+            )),
+            vec![
+                (String::from("<D|F7>"), String::from(" Mkdir ")),
+                (String::from("<~>"), String::from(" Go home ")),
+                (String::from("<Q>"), String::from(" Quit ")),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_cycle_footer_pages() {
+        let mut footer = FooterBar::new(Color::Reset, None, None);
+        let next_page = Event::Keyboard(KeyEvent::new(Key::Char('.'), KeyModifiers::ALT));
+        // before being rendered, all the hints are on one page
+        assert_eq!(footer.on(next_page.clone()), None);
+        footer.set_width(200);
+        assert_eq!(footer.pages.len(), 1);
+        assert_eq!(footer.on(next_page.clone()), None);
+        footer.set_width(40);
+        let pages = footer.pages.len();
+        assert!(pages > 1);
+        for page in 1..=pages {
+            assert_eq!(
+                footer.on(next_page.clone()),
+                Some(Msg::Ui(UiMsg::FooterPageChanged))
+            );
+            assert_eq!(footer.page, page % pages);
+        }
+        // resizing goes back to the first page
+        footer.on(next_page.clone());
+        assert_eq!(footer.page, 1);
+        footer.on(Event::WindowResize(30, 10));
+        assert_eq!(footer.page, 0);
+        assert_eq!(footer.width, Some(30));
+        // each page fits in the footer, with the indicator
+        for page in footer.pages.clone() {
+            let width: usize = footer.hints[page].iter().map(FooterHint::width).sum();
+            assert!(width < 30);
+        }
     }
 }
@@ -4,7 +4,7 @@
 
 use tui_realm_stdlib::Phantom;
 use tuirealm::event::Event;
-use tuirealm::{Component, MockComponent, NoUserEvent};
+use tuirealm::{AttrValue, Attribute, Component, MockComponent, NoUserEvent};
 
 use super::{Msg, PendingActionMsg, TransferMsg, UiMsg};
 use crate::config::keybindings::{GlobalKeyBindings, KeyBindings};
@@ -14,28 +14,37 @@ pub mod keybindings_helper;
 mod log;
 mod misc;
 mod popups;
+mod preview;
 mod selected_files;
 mod terminal;
 mod transfer;
 
 pub use misc::FooterBar;
 pub use popups::{
-    ATTR_FILES, ChmodPopup, CopyPopup, DeletePopup, DisconnectPopup, ErrorPopup, FatalPopup,
-    FileInfoPopup, FilterPopup, GotoPopup, KeybindingsPopup, MkdirPopup, NewfilePopup,
-    OpenWithPopup, ProgressBarFull, ProgressBarPartial, QuitPopup, RenamePopup, ReplacePopup,
-    SaveAsPopup, SortingPopup, StatusBarLocal, StatusBarRemote, SymlinkPopup,
-    SyncBrowsingMkdirPopup, WaitPopup, WalkdirWaitPopup, WatchedPathsList, WatcherPopup,
+    ATTR_FILES, BookmarkKind, BookmarkName, BookmarkSavePassword, ChmodPopup, CompressFormat,
+    CompressName, ConnectionSummaryPopup, CopyPopup, DeletePopup, DirBookmarksList,
+    DisconnectPopup, DuplicatePopup, ErrorPopup, FanoutPopup, FatalPopup, FileInfoPopup,
+    FilterPopup, GotoPopup, KeybindingsPopup, MirrorPopup, MkdirPopup, NewfilePopup, OpenWithPopup,
+    ProgressBarFull, ProgressBarPartial, QuitPopup, RenamePopup, ReplacePopup, SaveAsPopup,
+    SelectPatternPopup, SortingPopup, StatusBarLocal, StatusBarRemote, SymlinkPopup,
+    SyncBrowsingMkdirPopup, SyncConflictPopup, SyncPopup, WaitPopup, WalkdirWaitPopup,
+    WatchedPathsList, WatcherPopup,
 };
 pub use transfer::{ExplorerFind, ExplorerFuzzy, ExplorerLocal, ExplorerRemote};
 
 pub use self::log::Log;
+pub use self::preview::Preview;
 pub use self::selected_files::SelectedFilesList;
 pub use self::terminal::Terminal;
 
+/// Custom attribute set on the [`GlobalListener`] while a transfer is in progress
+pub const ATTR_TRANSFER_ACTIVE: &str = "transfer-active";
+
 #[derive(MockComponent)]
 pub struct GlobalListener {
     component: Phantom,
     global_keys: GlobalKeyBindings,
+    quit_immediately: bool,
 }
 
 impl Default for GlobalListener {
@@ -43,19 +52,29 @@ impl Default for GlobalListener {
         Self {
             component: Phantom::default(),
             global_keys: GlobalKeyBindings::default(),
+            quit_immediately: false,
         }
     }
 }
 
 impl GlobalListener {
-    pub fn new(keybindings: Option<&KeyBindings>) -> Self {
+    pub fn new(keybindings: Option<&KeyBindings>, quit_immediately: bool) -> Self {
         Self {
             component: Phantom::default(),
             global_keys: keybindings
                 .map(|k| k.global.clone())
                 .unwrap_or_default(),
+            quit_immediately,
         }
     }
+
+    /// Returns whether a transfer is in progress
+    fn transfer_active(&self) -> bool {
+        matches!(
+            self.query(Attribute::Custom(ATTR_TRANSFER_ACTIVE)),
+            Some(AttrValue::Flag(true))
+        )
+    }
 }
 
 impl Component<Msg, NoUserEvent> for GlobalListener {
@@ -70,6 +89,10 @@ impl Component<Msg, NoUserEvent> for GlobalListener {
                 if keybindings_helper::key_matches(key_ev, &self.global_keys.quit)
                     || keybindings_helper::key_matches(key_ev, &self.global_keys.quit_alt)
                 {
+                    // Always ask for confirmation if a transfer would be interrupted
+                    if self.quit_immediately && !self.transfer_active() {
+                        return Some(Msg::Ui(UiMsg::Quit));
+                    }
                     return Some(Msg::Ui(UiMsg::ShowQuitPopup));
                 }
                 // Check help
@@ -85,3 +108,43 @@ impl Component<Msg, NoUserEvent> for GlobalListener {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+    use tuirealm::event::{Key, KeyEvent};
+
+    use super::*;
+
+    fn quit_key() -> Event<NoUserEvent> {
+        Event::Keyboard(KeyEvent::from(Key::Char('q')))
+    }
+
+    #[test]
+    fn should_quit_immediately_without_active_transfer() {
+        let mut listener = GlobalListener::new(None, true);
+        assert_eq!(listener.on(quit_key()), Some(Msg::Ui(UiMsg::Quit)));
+    }
+
+    #[test]
+    fn should_prompt_quit_with_active_transfer() {
+        let mut listener = GlobalListener::new(None, true);
+        listener.attr(
+            Attribute::Custom(ATTR_TRANSFER_ACTIVE),
+            AttrValue::Flag(true),
+        );
+        assert_eq!(listener.on(quit_key()), Some(Msg::Ui(UiMsg::ShowQuitPopup)));
+        listener.attr(
+            Attribute::Custom(ATTR_TRANSFER_ACTIVE),
+            AttrValue::Flag(false),
+        );
+        assert_eq!(listener.on(quit_key()), Some(Msg::Ui(UiMsg::Quit)));
+    }
+
+    #[test]
+    fn should_prompt_quit_by_default() {
+        let mut listener = GlobalListener::new(None, false);
+        assert_eq!(listener.on(quit_key()), Some(Msg::Ui(UiMsg::ShowQuitPopup)));
+    }
+}
@@ -2,30 +2,105 @@
 //!
 //! popups components
 
+mod bookmark;
 mod chmod;
+mod compress;
+mod fanout;
 mod goto;
 
 use std::time::UNIX_EPOCH;
 
-use bytesize::ByteSize;
 use remotefs::File;
 use tui_realm_stdlib::{Input, List, Paragraph, ProgressBar, Radio, Span};
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::event::{Key, KeyEvent, KeyModifiers};
 use tuirealm::props::{
-    Alignment, BorderSides, BorderType, Borders, Color, InputType, Style, TableBuilder, TextSpan,
+    Alignment, AttrValue, Attribute, BorderSides, BorderType, Borders, Color, InputType, Style,
+    TableBuilder, TextSpan,
 };
 use tuirealm::{Component, Event, MockComponent, NoUserEvent, State, StateValue};
 #[cfg(posix)]
 use uzers::{get_group_by_gid, get_user_by_uid};
 
+pub use self::bookmark::{BookmarkKind, BookmarkName, BookmarkSavePassword};
 pub use self::chmod::ChmodPopup;
+pub use self::compress::{CompressFormat, CompressName};
+pub use self::fanout::FanoutPopup;
 pub use self::goto::{ATTR_FILES, GotoPopup};
 use super::super::Browser;
+use super::super::lib::bisync::BisyncPlan;
 use super::{Msg, PendingActionMsg, TransferMsg, UiMsg};
 use crate::config::keybindings::KeyBindings;
-use crate::explorer::FileSorting;
-use crate::utils::fmt::fmt_time;
+use crate::explorer::{FileExplorer, FileSorting};
+use crate::filetransfer::{FileTransferParams, HostBridgeParams};
+use crate::utils::fmt::{SizeUnits, fmt_date, fmt_size};
+use crate::utils::locale::Locale;
+use crate::utils::ui::keybindings_help_table;
+
+#[derive(MockComponent)]
+pub struct ConnectionSummaryPopup {
+    component: List,
+}
+
+impl ConnectionSummaryPopup {
+    pub fn new(host_bridge: &HostBridgeParams, remote: &FileTransferParams, color: Color) -> Self {
+        let section = |title: &'static str, summary: Vec<(&'static str, String)>| {
+            std::iter::once(vec![TextSpan::from(title).bold().fg(color)]).chain(
+                summary
+                    .into_iter()
+                    .map(|(key, value)| vec![TextSpan::from(format!("  {key:<20}{value}"))]),
+            )
+        };
+        let rows = section("Host bridge", host_bridge.summary())
+            .chain(section("Remote", remote.summary()))
+            .collect();
+        Self {
+            component: List::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .rewind(true)
+                .scroll(true)
+                .step(4)
+                .highlighted_color(color)
+                .highlighted_str("➤ ")
+                .title("Connect? <ENTER> connect, <ESC> cancel", Alignment::Center)
+                .rows(rows),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for ConnectionSummaryPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. })
+            | Event::Keyboard(KeyEvent {
+                code: Key::Char('n'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::PendingAction(PendingActionMsg::ConnectCancel)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            })
+            | Event::Keyboard(KeyEvent {
+                code: Key::Char('y'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::PendingAction(PendingActionMsg::Connect)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up));
+                Some(Msg::None)
+            }
+            _ => None,
+        }
+    }
+}
 
 #[derive(MockComponent)]
 pub struct CopyPopup {
@@ -113,13 +188,96 @@ impl Component<Msg, NoUserEvent> for CopyPopup {
     }
 }
 
+#[derive(MockComponent)]
+pub struct DuplicatePopup {
+    component: Input,
+}
+
+impl DuplicatePopup {
+    pub fn new(name: &str, color: Color) -> Self {
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .input_type(InputType::Text)
+                .value(name)
+                .title("Duplicate file as…", Alignment::Center),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for DuplicatePopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Delete, ..
+            }) => {
+                self.perform(Cmd::Cancel);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Backspace,
+                ..
+            }) => {
+                self.perform(Cmd::Delete);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch),
+                ..
+            }) => {
+                self.perform(Cmd::Type(ch));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => match self.state() {
+                State::One(StateValue::String(i)) => {
+                    Some(Msg::Transfer(TransferMsg::DuplicateFile(i)))
+                }
+                _ => Some(Msg::None),
+            },
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseDuplicatePopup))
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(MockComponent)]
 pub struct FilterPopup {
     component: Input,
 }
 
 impl FilterPopup {
-    pub fn new(color: Color) -> Self {
+    pub fn new(filter: &str, color: Color) -> Self {
         Self {
             component: Input::default()
                 .borders(
@@ -129,14 +287,12 @@ impl FilterPopup {
                 )
                 .foreground(color)
                 .input_type(InputType::Text)
+                .value(filter)
                 .placeholder(
-                    "regex or wildmatch",
+                    "regex, glob or !glob to exclude; empty to show all",
                     Style::default().fg(Color::Rgb(128, 128, 128)),
                 )
-                .title(
-                    "Filter files by regex or wildmatch in the current directory",
-                    Alignment::Center,
-                ),
+                .title("Filter files by regex or glob", Alignment::Center),
         }
     }
 }
@@ -200,6 +356,89 @@ impl Component<Msg, NoUserEvent> for FilterPopup {
     }
 }
 
+#[derive(MockComponent)]
+pub struct SelectPatternPopup {
+    component: Input,
+}
+
+impl SelectPatternPopup {
+    pub fn new(color: Color) -> Self {
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .input_type(InputType::Text)
+                .placeholder("*.log", Style::default().fg(Color::Rgb(128, 128, 128)))
+                .title("Select files matching pattern", Alignment::Center),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for SelectPatternPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Delete, ..
+            }) => {
+                self.perform(Cmd::Cancel);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Backspace,
+                ..
+            }) => {
+                self.perform(Cmd::Delete);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch),
+                ..
+            }) => {
+                self.perform(Cmd::Type(ch));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => match self.state() {
+                State::One(StateValue::String(pattern)) => {
+                    Some(Msg::Ui(UiMsg::SelectByPattern(pattern)))
+                }
+                _ => Some(Msg::None),
+            },
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseSelectPatternPopup))
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(MockComponent)]
 pub struct DeletePopup {
     component: Radio,
@@ -403,7 +642,13 @@ pub struct FileInfoPopup {
 }
 
 impl FileInfoPopup {
-    pub fn new(file: &File) -> Self {
+    pub fn new(
+        file: &File,
+        date_fmt: Option<&str>,
+        locale: Option<Locale>,
+        raw_sizes: bool,
+        size_units: SizeUnits,
+    ) -> Self {
         let mut texts: TableBuilder = TableBuilder::default();
         // Abs path
         let real_path = file.metadata().symlink.as_deref();
@@ -425,22 +670,36 @@ impl FileInfoPopup {
                 .add_col(TextSpan::from("File type: "))
                 .add_col(TextSpan::new(filetype).fg(Color::LightGreen));
         }
-        let (bsize, size): (ByteSize, u64) = (ByteSize(file.metadata().size), file.metadata().size);
+        // Exact sizes are shown alone, human readable sizes along with the exact one
+        let size = fmt_size(file.metadata().size, true, size_units, locale);
+        let size = match raw_sizes {
+            true => size,
+            false => format!(
+                "{} ({size})",
+                fmt_size(file.metadata().size, false, size_units, locale)
+            ),
+        };
         texts
             .add_row()
             .add_col(TextSpan::from("Size: "))
-            .add_col(TextSpan::new(format!("{bsize} ({size})").as_str()).fg(Color::Cyan));
-        let atime: String = fmt_time(
+            .add_col(TextSpan::new(size.as_str()).fg(Color::Cyan));
+        // Date format: configured one, then the one of the locale (with seconds), then default
+        let date_fmt: String = match (date_fmt, locale) {
+            (Some(fmt), _) => fmt.to_string(),
+            (None, Some(locale)) => format!("{}:%S", locale.date_fmt()),
+            (None, None) => String::from("%b %d %Y %H:%M:%S"),
+        };
+        let atime: String = fmt_date(
             file.metadata().accessed.unwrap_or(UNIX_EPOCH),
-            "%b %d %Y %H:%M:%S",
+            date_fmt.as_str(),
         );
-        let ctime: String = fmt_time(
+        let ctime: String = fmt_date(
             file.metadata().created.unwrap_or(UNIX_EPOCH),
-            "%b %d %Y %H:%M:%S",
+            date_fmt.as_str(),
         );
-        let mtime: String = fmt_time(
+        let mtime: String = fmt_date(
             file.metadata().modified.unwrap_or(UNIX_EPOCH),
-            "%b %d %Y %H:%M:%S",
+            date_fmt.as_str(),
         );
         texts
             .add_row()
@@ -513,10 +772,9 @@ pub struct KeybindingsPopup {
 
 impl KeybindingsPopup {
     pub fn new(key_color: Color, keybindings: Option<&KeyBindings>) -> Self {
-        let table = if let Some(kb) = keybindings {
-            Self::build_table_from_config(key_color, kb)
-        } else {
-            Self::build_default_table(key_color)
+        let rows = match keybindings {
+            Some(keybindings) => keybindings.explorer_help(),
+            None => KeyBindings::default().explorer_help(),
         };
 
         Self {
@@ -527,107 +785,120 @@ impl KeybindingsPopup {
                 .highlighted_str("? ")
                 .title("Keybindings", Alignment::Center)
                 .rewind(true)
-                .rows(table),
-        }
-    }
-
-    /// Format a keybinding for display
-    fn fmt_key(binding: &crate::config::keybindings::KeyBinding) -> String {
-        format!("<{}>", binding.to_string().to_uppercase())
-    }
-
-    /// Build keybindings table from configuration
-    fn build_table_from_config(key_color: Color, kb: &KeyBindings) -> Vec<Vec<TextSpan>> {
-        let global = &kb.global;
-        let explorer = &kb.explorer;
-
-        // Helper to add a row
-        fn row(key: String, desc: &str, key_color: Color) -> Vec<TextSpan> {
-            vec![
-                TextSpan::new(format!("{:<16}", key)).bold().fg(key_color),
-                TextSpan::from(desc.to_string()),
-            ]
-        }
-
-        vec![
-            row(Self::fmt_key(&global.disconnect), "Disconnect", key_color),
-            row(Self::fmt_key(&explorer.go_back), "Go to previous directory", key_color),
-            row(Self::fmt_key(&explorer.change_panel), "Change explorer tab", key_color),
-            row(format!("<{}/{}>", Self::fmt_key(&explorer.move_up).trim_matches(|c| c == '<' || c == '>'), 
-                Self::fmt_key(&explorer.move_down).trim_matches(|c| c == '<' || c == '>')), "Move up/down in list", key_color),
-            row(Self::fmt_key(&explorer.enter_dir), "Enter directory", key_color),
-            row(Self::fmt_key(&explorer.transfer_file), "Upload/Download file", key_color),
-            row(Self::fmt_key(&explorer.go_to_parent), "Go to parent directory", key_color),
-            row(Self::fmt_key(&explorer.toggle_hidden), "Toggle hidden files", key_color),
-            row(Self::fmt_key(&explorer.sorting), "Change file sorting mode", key_color),
-            row(Self::fmt_key(&explorer.copy_file), "Copy", key_color),
-            row(Self::fmt_key(&explorer.mkdir), "Make directory", key_color),
-            row(Self::fmt_key(&explorer.fuzzy_search), "Search files", key_color),
-            row(Self::fmt_key(&explorer.goto_path), "Go to path", key_color),
-            row(Self::fmt_key(&global.help), "Show help", key_color),
-            row(Self::fmt_key(&explorer.file_info), "Show info about selected file", key_color),
-            row(Self::fmt_key(&explorer.symlink), "Create symlink", key_color),
-            row(Self::fmt_key(&explorer.reload_dir), "Reload directory content", key_color),
-            row(Self::fmt_key(&explorer.mark_file), "Select/mark file", key_color),
-            row(Self::fmt_key(&explorer.new_file), "Create new file", key_color),
-            row(Self::fmt_key(&explorer.edit_file), "Open text file with editor", key_color),
-            row(Self::fmt_key(&explorer.pending_queue), "Toggle bottom panel", key_color),
-            row(Self::fmt_key(&global.quit), "Quit termscp", key_color),
-            row(Self::fmt_key(&explorer.rename_file), "Rename file", key_color),
-            row(Self::fmt_key(&explorer.save_as), "Save file as", key_color),
-            row(Self::fmt_key(&explorer.watcher), "Watch/unwatch file changes", key_color),
-            row(Self::fmt_key(&explorer.open_file), "Open file with default app", key_color),
-            row(Self::fmt_key(&explorer.open_with), "Open file with specified app", key_color),
-            row(Self::fmt_key(&explorer.terminal), "Execute shell command", key_color),
-            row(Self::fmt_key(&explorer.sync_browsing), "Toggle synchronized browsing", key_color),
-            row(Self::fmt_key(&explorer.chmod), "Change file permissions", key_color),
-            row(Self::fmt_key(&explorer.filter), "Filter files", key_color),
-            row(Self::fmt_key(&explorer.delete_file), "Delete selected file", key_color),
-            row(Self::fmt_key(&explorer.mark_all), "Select all files", key_color),
-            row(Self::fmt_key(&explorer.unmark_all), "Deselect all files", key_color),
-            row(Self::fmt_key(&explorer.file_size), "Get total path size", key_color),
-            row(Self::fmt_key(&explorer.watched_paths), "Show watched paths", key_color),
-            row(String::from("<CTRL+C>"), "Interrupt file transfer", key_color),
-        ]
-    }
-
-    /// Build default keybindings table (fallback)
-    fn build_default_table(key_color: Color) -> Vec<Vec<TextSpan>> {
-        TableBuilder::default()
-            .add_col(TextSpan::new("<ESC>").bold().fg(key_color))
-            .add_col(TextSpan::from("             Disconnect"))
-            .add_row()
-            .add_col(TextSpan::new("<BACKSPACE>").bold().fg(key_color))
-            .add_col(TextSpan::from("       Go to previous directory"))
-            .add_row()
-            .add_col(TextSpan::new("<TAB>").bold().fg(key_color))
-            .add_col(TextSpan::from("             Change explorer tab"))
-            .add_row()
-            .add_col(TextSpan::new("<UP/DOWN>").bold().fg(key_color))
-            .add_col(TextSpan::from("         Move up/down in list"))
-            .add_row()
-            .add_col(TextSpan::new("<ENTER>").bold().fg(key_color))
-            .add_col(TextSpan::from("           Enter directory"))
-            .add_row()
-            .add_col(TextSpan::new("<SPACE>").bold().fg(key_color))
-            .add_col(TextSpan::from("           Upload/Download file"))
-            .add_row()
-            .add_col(TextSpan::new("<H|F1>").bold().fg(key_color))
-            .add_col(TextSpan::from("            Show help"))
-            .add_row()
-            .add_col(TextSpan::new("<Q|F10>").bold().fg(key_color))
-            .add_col(TextSpan::from("           Quit termscp"))
-            .build()
+                .rows(keybindings_help_table(&rows, key_color)),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for KeybindingsPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Esc | Key::Enter,
+                ..
+            }) => Some(Msg::Ui(UiMsg::CloseKeybindingsPopup)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageDown,
+                ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageUp, ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct MirrorPopup {
+    component: List,
+}
+
+impl MirrorPopup {
+    pub fn new(
+        transfer: &[std::path::PathBuf],
+        delete: &[std::path::PathBuf],
+        color: Color,
+    ) -> Self {
+        let rows = transfer
+            .iter()
+            .map(|x| vec![TextSpan::from(format!("+ {}", x.display()))])
+            .chain(
+                delete
+                    .iter()
+                    .map(|x| vec![TextSpan::from(format!("- {}", x.display())).fg(Color::Red)]),
+            )
+            .collect();
+        Self {
+            component: List::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .rewind(true)
+                .scroll(true)
+                .step(4)
+                .highlighted_color(color)
+                .highlighted_str("➤ ")
+                .title(
+                    format!(
+                        "{} to transfer, {} extraneous. <ENTER> mirror, <D> mirror and delete extraneous, <ESC> cancel",
+                        transfer.len(),
+                        delete.len()
+                    ),
+                    Alignment::Center,
+                )
+                .rows(rows),
+        }
     }
 }
 
-impl Component<Msg, NoUserEvent> for KeybindingsPopup {
+impl Component<Msg, NoUserEvent> for MirrorPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         match ev {
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. })
+            | Event::Keyboard(KeyEvent {
+                code: Key::Char('n'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::PendingAction(PendingActionMsg::MirrorCancel)),
             Event::Keyboard(KeyEvent {
-                code: Key::Esc | Key::Enter,
-                ..
-            }) => Some(Msg::Ui(UiMsg::CloseKeybindingsPopup)),
+                code: Key::Enter, ..
+            })
+            | Event::Keyboard(KeyEvent {
+                code: Key::Char('y'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::PendingAction(PendingActionMsg::Mirror)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('d'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::PendingAction(PendingActionMsg::MirrorAndDelete)),
             Event::Keyboard(KeyEvent {
                 code: Key::Down, ..
             }) => {
@@ -837,10 +1108,14 @@ impl Component<Msg, NoUserEvent> for NewfilePopup {
 #[derive(MockComponent)]
 pub struct OpenWithPopup {
     component: Input,
+    /// Commands recently used to open files with, from the most recent one
+    recents: Vec<String>,
+    /// Index of the recent command in the input, if any
+    recent: Option<usize>,
 }
 
 impl OpenWithPopup {
-    pub fn new(color: Color) -> Self {
+    pub fn new(recents: &[String], color: Color) -> Self {
         Self {
             component: Input::default()
                 .borders(
@@ -851,12 +1126,27 @@ impl OpenWithPopup {
                 .foreground(color)
                 .input_type(InputType::Text)
                 .placeholder(
-                    "Open file with…",
+                    "Program, or command with {} for the file and {dir} for its directory",
                     Style::default().fg(Color::Rgb(128, 128, 128)),
                 )
-                .title("Type the program to open the file with", Alignment::Center),
+                .title(
+                    "Type the program to open the file with (↑↓ for recent ones)",
+                    Alignment::Center,
+                ),
+            recents: recents.to_vec(),
+            recent: None,
         }
     }
+
+    /// Put the recent command at `index` in the input; `None` clears it
+    fn select_recent(&mut self, index: Option<usize>) {
+        self.recent = index;
+        let value = index
+            .and_then(|i| self.recents.get(i))
+            .cloned()
+            .unwrap_or_default();
+        self.attr(Attribute::Value, AttrValue::String(value));
+    }
 }
 
 impl Component<Msg, NoUserEvent> for OpenWithPopup {
@@ -912,6 +1202,19 @@ impl Component<Msg, NoUserEvent> for OpenWithPopup {
                 }
                 _ => Some(Msg::None),
             },
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                let index = self.recent.map(|i| i + 1).unwrap_or(0);
+                if index < self.recents.len() {
+                    self.select_recent(Some(index));
+                }
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => {
+                self.select_recent(self.recent.and_then(|i| i.checked_sub(1)));
+                Some(Msg::None)
+            }
             Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
                 Some(Msg::Ui(UiMsg::CloseOpenWithPopup))
             }
@@ -1222,6 +1525,168 @@ impl Component<Msg, NoUserEvent> for ReplacePopup {
     }
 }
 
+#[derive(MockComponent)]
+pub struct SyncConflictPopup {
+    component: Radio,
+}
+
+impl SyncConflictPopup {
+    pub fn new(path: &std::path::Path, color: Color) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .choices(["Keep local", "Keep remote", "Skip", "Cancel"])
+                .title(
+                    format!(r#""{}" changed on both sides"#, path.display()),
+                    Alignment::Center,
+                ),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for SyncConflictPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::PendingAction(PendingActionMsg::SyncCancel))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => match self.perform(Cmd::Submit) {
+                CmdResult::Submit(State::One(StateValue::Usize(0))) => {
+                    Some(Msg::PendingAction(PendingActionMsg::SyncKeepLocal))
+                }
+                CmdResult::Submit(State::One(StateValue::Usize(1))) => {
+                    Some(Msg::PendingAction(PendingActionMsg::SyncKeepRemote))
+                }
+                CmdResult::Submit(State::One(StateValue::Usize(2))) => {
+                    Some(Msg::PendingAction(PendingActionMsg::SyncSkip))
+                }
+                CmdResult::Submit(State::One(StateValue::Usize(3))) => {
+                    Some(Msg::PendingAction(PendingActionMsg::SyncCancel))
+                }
+                _ => Some(Msg::None),
+            },
+            _ => None,
+        }
+    }
+}
+
+#[derive(MockComponent)]
+pub struct SyncPopup {
+    component: List,
+}
+
+impl SyncPopup {
+    pub fn new(plan: &BisyncPlan, color: Color) -> Self {
+        let fmt_rows = |paths: &[std::path::PathBuf], prefix: &'static str, fg: Color| {
+            paths
+                .iter()
+                .map(move |x| vec![TextSpan::from(format!("{prefix} {}", x.display())).fg(fg)])
+                .collect::<Vec<Vec<TextSpan>>>()
+        };
+        let rows = fmt_rows(&plan.upload, "→", Color::Reset)
+            .into_iter()
+            .chain(fmt_rows(&plan.download, "←", Color::Reset))
+            .chain(fmt_rows(&plan.delete_remote, "- remote:", Color::Red))
+            .chain(fmt_rows(&plan.delete_local, "- local:", Color::Red))
+            .collect();
+        Self {
+            component: List::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .rewind(true)
+                .scroll(true)
+                .step(4)
+                .highlighted_color(color)
+                .highlighted_str("➤ ")
+                .title(
+                    format!(
+                        "{} to upload, {} to download, {} to delete. <ENTER> sync, <ESC> cancel",
+                        plan.upload.len(),
+                        plan.download.len(),
+                        plan.delete_local.len() + plan.delete_remote.len()
+                    ),
+                    Alignment::Center,
+                )
+                .rows(rows),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for SyncPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. })
+            | Event::Keyboard(KeyEvent {
+                code: Key::Char('n'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::PendingAction(PendingActionMsg::SyncCancel)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            })
+            | Event::Keyboard(KeyEvent {
+                code: Key::Char('y'),
+                modifiers: KeyModifiers::NONE,
+            }) => Some(Msg::PendingAction(PendingActionMsg::Sync)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageDown,
+                ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageUp, ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(MockComponent)]
 pub struct SaveAsPopup {
     component: Input,
@@ -1323,13 +1788,22 @@ impl SortingPopup {
                         .modifiers(BorderType::Rounded),
                 )
                 .foreground(color)
-                .choices(["Name", "Modify time", "Creation time", "Size"])
+                .choices([
+                    "Name",
+                    "Modify time",
+                    "Creation time",
+                    "Size",
+                    "Extension",
+                    "Natural",
+                ])
                 .title("Sort files by…", Alignment::Center)
                 .value(match value {
                     FileSorting::CreationTime => 2,
                     FileSorting::ModifyTime => 1,
                     FileSorting::Name => 0,
                     FileSorting::Size => 3,
+                    FileSorting::Extension => 4,
+                    FileSorting::Natural => 5,
                     FileSorting::None => 0,
                 }),
         }
@@ -1357,6 +1831,8 @@ impl Component<Msg, NoUserEvent> for SortingPopup {
                 1 => FileSorting::ModifyTime,
                 2 => FileSorting::CreationTime,
                 3 => FileSorting::Size,
+                4 => FileSorting::Extension,
+                5 => FileSorting::Natural,
                 _ => FileSorting::Name,
             })))
         } else {
@@ -1374,13 +1850,16 @@ impl StatusBarLocal {
     pub fn new(browser: &Browser, sorting_color: Color, hidden_color: Color) -> Self {
         let file_sorting = file_sorting_label(browser.host_bridge().file_sorting);
         let hidden_files = hidden_files_label(browser.host_bridge().hidden_files_visible());
+        let mut spans = vec![
+            TextSpan::new("File sorting: ").fg(sorting_color),
+            TextSpan::new(file_sorting).fg(sorting_color).reversed(),
+            TextSpan::new(" Hidden files: ").fg(hidden_color),
+            TextSpan::new(hidden_files).fg(hidden_color).reversed(),
+        ];
+        spans.extend(hidden_patterns_spans(browser.host_bridge(), hidden_color));
+        spans.extend(filter_spans(browser.host_bridge(), sorting_color));
         Self {
-            component: Span::default().spans([
-                TextSpan::new("File sorting: ").fg(sorting_color),
-                TextSpan::new(file_sorting).fg(sorting_color).reversed(),
-                TextSpan::new(" Hidden files: ").fg(hidden_color),
-                TextSpan::new(hidden_files).fg(hidden_color).reversed(),
-            ]),
+            component: Span::default().spans(spans),
         }
     }
 }
@@ -1409,15 +1888,20 @@ impl StatusBarRemote {
             true => "ON ",
             false => "OFF",
         };
+        let mut spans = vec![
+            TextSpan::new("File sorting: ").fg(sorting_color),
+            TextSpan::new(file_sorting).fg(sorting_color).reversed(),
+            TextSpan::new(" Hidden files: ").fg(hidden_color),
+            TextSpan::new(hidden_files).fg(hidden_color).reversed(),
+        ];
+        spans.extend(hidden_patterns_spans(browser.remote(), hidden_color));
+        spans.extend(filter_spans(browser.remote(), sorting_color));
+        spans.extend([
+            TextSpan::new(" Sync browsing: ").fg(sync_color),
+            TextSpan::new(sync_browsing).fg(sync_color).reversed(),
+        ]);
         Self {
-            component: Span::default().spans([
-                TextSpan::new("File sorting: ").fg(sorting_color),
-                TextSpan::new(file_sorting).fg(sorting_color).reversed(),
-                TextSpan::new(" Hidden files: ").fg(hidden_color),
-                TextSpan::new(hidden_files).fg(hidden_color).reversed(),
-                TextSpan::new(" Sync browsing: ").fg(sync_color),
-                TextSpan::new(sync_browsing).fg(sync_color).reversed(),
-            ]),
+            component: Span::default().spans(spans),
         }
     }
 }
@@ -1434,6 +1918,8 @@ fn file_sorting_label(sorting: FileSorting) -> &'static str {
         FileSorting::ModifyTime => "By modify time",
         FileSorting::Name => "By name",
         FileSorting::Size => "By size",
+        FileSorting::Extension => "By extension",
+        FileSorting::Natural => "By name (natural)",
         FileSorting::None => "",
     }
 }
@@ -1445,6 +1931,29 @@ fn hidden_files_label(visible: bool) -> &'static str {
     }
 }
 
+/// Status of the files hidden by patterns; empty if the explorer has no hidden patterns
+fn hidden_patterns_spans(explorer: &FileExplorer, color: Color) -> Vec<TextSpan> {
+    if !explorer.has_hidden_patterns() {
+        return Vec::new();
+    }
+    let label = hidden_files_label(explorer.pattern_hidden_files_visible());
+    vec![
+        TextSpan::new(" Hidden patterns: ").fg(color),
+        TextSpan::new(label).fg(color).reversed(),
+    ]
+}
+
+/// Status of the filter on the file names; empty if the explorer isn't filtered
+fn filter_spans(explorer: &FileExplorer, color: Color) -> Vec<TextSpan> {
+    match explorer.filter() {
+        Some(filter) => vec![
+            TextSpan::new(" Filter: ").fg(color),
+            TextSpan::new(filter.expression()).fg(color).reversed(),
+        ],
+        None => Vec::new(),
+    }
+}
+
 #[derive(MockComponent)]
 pub struct SymlinkPopup {
     component: Input,
@@ -1653,7 +2162,7 @@ impl WalkdirWaitPopup {
                 .foreground(color)
                 .text([
                     TextSpan::from(text.as_ref()),
-                    TextSpan::from("Press 'CTRL+C' to abort"),
+                    TextSpan::from("Press 'ESC' or 'CTRL+C' to abort"),
                 ])
                 .wrap(true),
         }
@@ -1664,10 +2173,11 @@ impl Component<Msg, NoUserEvent> for WalkdirWaitPopup {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         if matches!(
             ev,
-            Event::Keyboard(KeyEvent {
-                code: Key::Char('c'),
-                modifiers: KeyModifiers::CONTROL
-            })
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. })
+                | Event::Keyboard(KeyEvent {
+                    code: Key::Char('c'),
+                    modifiers: KeyModifiers::CONTROL
+                })
         ) {
             Some(Msg::Transfer(TransferMsg::AbortWalkdir))
         } else {
@@ -1763,6 +2273,100 @@ impl Component<Msg, NoUserEvent> for WatchedPathsList {
     }
 }
 
+/// List of the directory bookmarks, to connect to one of them
+#[derive(MockComponent)]
+pub struct DirBookmarksList {
+    component: List,
+}
+
+impl DirBookmarksList {
+    pub fn new(bookmarks: &[String], color: Color) -> Self {
+        Self {
+            component: List::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .rewind(true)
+                .scroll(true)
+                .step(4)
+                .highlighted_color(color)
+                .highlighted_str("➤ ")
+                .title(
+                    "Go to directory (<ENTER> go, <DEL> delete)",
+                    Alignment::Center,
+                )
+                .rows(
+                    bookmarks
+                        .iter()
+                        .map(|x| vec![TextSpan::from(x.as_str())])
+                        .collect(),
+                ),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for DirBookmarksList {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseDirBookmarksList))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageDown,
+                ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageUp, ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => match self.component.state() {
+                State::One(StateValue::Usize(idx)) => {
+                    Some(Msg::Transfer(TransferMsg::GoToDirBookmark(idx)))
+                }
+                _ => Some(Msg::None),
+            },
+            Event::Keyboard(KeyEvent {
+                code: Key::Delete, ..
+            }) => match self.component.state() {
+                State::One(StateValue::Usize(idx)) => {
+                    Some(Msg::Transfer(TransferMsg::DeleteDirBookmark(idx)))
+                }
+                _ => Some(Msg::None),
+            },
+            _ => None,
+        }
+    }
+}
+
 #[derive(MockComponent)]
 pub struct WatcherPopup {
     component: Radio,
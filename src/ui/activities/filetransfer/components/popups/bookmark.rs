@@ -0,0 +1,170 @@
+use tui_realm_stdlib::{Input, Radio};
+use tuirealm::command::{Cmd, Direction, Position};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::props::{Alignment, BorderSides, BorderType, Borders, Color, InputType};
+use tuirealm::{Component, Event, MockComponent, NoUserEvent};
+
+use super::{Msg, TransferMsg, UiMsg};
+
+/// What the save bookmark popup saves
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BookmarkKind {
+    /// The connection of the current session
+    Session,
+    /// The remote working directory, along with the connection to reach it
+    Directory,
+}
+
+impl BookmarkKind {
+    fn save_msg(self) -> Msg {
+        match self {
+            Self::Session => Msg::Transfer(TransferMsg::SaveBookmark),
+            Self::Directory => Msg::Transfer(TransferMsg::SaveDirBookmark),
+        }
+    }
+}
+
+/// Name of the bookmark to save the current session as
+#[derive(MockComponent)]
+pub struct BookmarkName {
+    component: Input,
+    kind: BookmarkKind,
+}
+
+impl BookmarkName {
+    pub fn new(name: &str, kind: BookmarkKind, color: Color) -> Self {
+        let title = match kind {
+            BookmarkKind::Session => "Bookmark name",
+            BookmarkKind::Directory => "Directory bookmark name",
+        };
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::Reset)
+                        .sides(BorderSides::TOP | BorderSides::LEFT | BorderSides::RIGHT)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .title(title, Alignment::Left)
+                .input_type(InputType::Text)
+                .value(name),
+            kind,
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for BookmarkName {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseSaveBookmarkPopup))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Delete, ..
+            }) => {
+                self.perform(Cmd::Cancel);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Backspace,
+                ..
+            }) => {
+                self.perform(Cmd::Delete);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch),
+                ..
+            }) => {
+                self.perform(Cmd::Type(ch));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => Some(self.kind.save_msg()),
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => Some(Msg::Ui(UiMsg::BookmarkNameBlur)),
+            _ => None,
+        }
+    }
+}
+
+/// Whether to save the secrets of the current session in the bookmark
+#[derive(MockComponent)]
+pub struct BookmarkSavePassword {
+    component: Radio,
+    kind: BookmarkKind,
+}
+
+impl BookmarkSavePassword {
+    pub fn new(kind: BookmarkKind, color: Color) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::Reset)
+                        .sides(BorderSides::BOTTOM | BorderSides::LEFT | BorderSides::RIGHT)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(["Yes", "No"])
+                .value(0)
+                .rewind(true)
+                .foreground(color)
+                .title("Save secrets?", Alignment::Center),
+            kind,
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for BookmarkSavePassword {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseSaveBookmarkPopup))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => Some(self.kind.save_msg()),
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                Some(Msg::Ui(UiMsg::SaveBookmarkPasswordBlur))
+            }
+            _ => None,
+        }
+    }
+}
@@ -7,6 +7,7 @@ use tuirealm::ratatui::layout::{Constraint, Direction as LayoutDirection, Layout
 use tuirealm::{Component, Event, MockComponent, NoUserEvent, Props, State, StateValue};
 
 use super::{Msg, TransferMsg, UiMsg};
+use crate::ui::activities::filetransfer::lib::chmod::ExecuteMode;
 
 #[derive(Default, Clone, Copy, PartialEq, Eq)]
 pub enum Item {
@@ -14,6 +15,7 @@ pub enum Item {
     User,
     Group,
     Others,
+    Recursive,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -30,6 +32,8 @@ pub struct ChmodPopup {
     user: Checkbox,
     group: Checkbox,
     others: Checkbox,
+    /// Whether to apply the mode to the contents of directories and how to set the execute permissions
+    recursive: Checkbox,
 }
 
 /// Make checkbox values from unix pex class
@@ -78,6 +82,12 @@ impl ChmodPopup {
                 .borders(Borders::default().sides(BorderSides::NONE))
                 .values(&make_pex_values(pex.others()))
                 .rewind(true),
+            recursive: Checkbox::default()
+                .foreground(color)
+                .choices(["Recursive", "Execute only dirs and executables (X)"])
+                .title("Directories", Alignment::Left)
+                .borders(Borders::default().sides(BorderSides::NONE))
+                .rewind(true),
         }
     }
 
@@ -85,6 +95,7 @@ impl ChmodPopup {
         match self.states.focus {
             Item::Group => &mut self.group,
             Item::Others => &mut self.others,
+            Item::Recursive => &mut self.recursive,
             Item::User => &mut self.user,
         }
     }
@@ -94,15 +105,19 @@ impl ChmodPopup {
             Item::User => self.user.attr(Attribute::Focus, AttrValue::Flag(value)),
             Item::Group => self.group.attr(Attribute::Focus, AttrValue::Flag(value)),
             Item::Others => self.others.attr(Attribute::Focus, AttrValue::Flag(value)),
+            Item::Recursive => self
+                .recursive
+                .attr(Attribute::Focus, AttrValue::Flag(value)),
         }
     }
 
     fn active_checkbox_up(&mut self) {
         self.toggle_checkbox_focus(false);
         let next = match self.states.focus {
-            Item::User => Item::Others,
+            Item::User => Item::Recursive,
             Item::Group => Item::User,
             Item::Others => Item::Group,
+            Item::Recursive => Item::Others,
         };
 
         self.states.focus = next;
@@ -115,7 +130,8 @@ impl ChmodPopup {
         let next = match self.states.focus {
             Item::User => Item::Group,
             Item::Group => Item::Others,
-            Item::Others => Item::User,
+            Item::Others => Item::Recursive,
+            Item::Recursive => Item::User,
         };
 
         self.states.focus = next;
@@ -144,6 +160,25 @@ impl ChmodPopup {
             Self::checkbox_state_to_pex_class(self.others.state()),
         )
     }
+
+    /// Get the message to submit; the change of mode is recursive if the option is checked
+    fn get_msg(&self) -> TransferMsg {
+        let options: Vec<usize> = self
+            .recursive
+            .state()
+            .unwrap_vec()
+            .into_iter()
+            .map(|x| x.unwrap_usize())
+            .collect();
+        let execute = match options.contains(&1) {
+            true => ExecuteMode::DirsAndExecutables,
+            false => ExecuteMode::All,
+        };
+        match options.contains(&0) {
+            true => TransferMsg::ChmodRecursive(self.get_mode(), execute),
+            false => TransferMsg::Chmod(self.get_mode()),
+        }
+    }
 }
 
 impl MockComponent for ChmodPopup {
@@ -155,7 +190,8 @@ impl MockComponent for ChmodPopup {
         } else {
             self.user.attr(attr, value.clone());
             self.group.attr(attr, value.clone());
-            self.others.attr(attr, value);
+            self.others.attr(attr, value.clone());
+            self.recursive.attr(attr, value);
         }
     }
 
@@ -198,6 +234,7 @@ impl MockComponent for ChmodPopup {
                     Constraint::Length(3),
                     Constraint::Length(3),
                     Constraint::Length(3),
+                    Constraint::Length(3),
                 ]
                 .as_ref(),
             )
@@ -222,6 +259,7 @@ impl MockComponent for ChmodPopup {
         self.user.view(frame, chunks[0]);
         self.group.view(frame, chunks[1]);
         self.others.view(frame, chunks[2]);
+        self.recursive.view(frame, chunks[3]);
     }
 }
 
@@ -262,7 +300,7 @@ impl Component<Msg, NoUserEvent> for ChmodPopup {
             }
             Event::Keyboard(KeyEvent {
                 code: Key::Enter, ..
-            }) => Some(Msg::Transfer(TransferMsg::Chmod(self.get_mode()))),
+            }) => Some(Msg::Transfer(self.get_msg())),
             _ => None,
         }
     }
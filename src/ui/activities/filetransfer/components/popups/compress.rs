@@ -0,0 +1,144 @@
+use tui_realm_stdlib::{Input, Radio};
+use tuirealm::command::{Cmd, Direction, Position};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::props::{Alignment, BorderSides, BorderType, Borders, Color, InputType};
+use tuirealm::{Component, Event, MockComponent, NoUserEvent};
+
+use super::{Msg, TransferMsg, UiMsg};
+
+/// Name of the archive to compress the selected files into
+#[derive(MockComponent)]
+pub struct CompressName {
+    component: Input,
+}
+
+impl CompressName {
+    pub fn new(name: &str, color: Color) -> Self {
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::Reset)
+                        .sides(BorderSides::TOP | BorderSides::LEFT | BorderSides::RIGHT)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .title("Archive name", Alignment::Left)
+                .input_type(InputType::Text)
+                .value(name),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for CompressName {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseCompressPopup))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Delete, ..
+            }) => {
+                self.perform(Cmd::Cancel);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Backspace,
+                ..
+            }) => {
+                self.perform(Cmd::Delete);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch),
+                ..
+            }) => {
+                self.perform(Cmd::Type(ch));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => Some(Msg::Transfer(TransferMsg::CompressSelected)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => Some(Msg::Ui(UiMsg::CompressNameBlur)),
+            _ => None,
+        }
+    }
+}
+
+/// Format of the archive to compress the selected files into
+#[derive(MockComponent)]
+pub struct CompressFormat {
+    component: Radio,
+}
+
+impl CompressFormat {
+    pub fn new(color: Color) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::Reset)
+                        .sides(BorderSides::BOTTOM | BorderSides::LEFT | BorderSides::RIGHT)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(["tar.gz", "zip"])
+                .value(0)
+                .rewind(true)
+                .foreground(color)
+                .title("Format", Alignment::Center),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for CompressFormat {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::Ui(UiMsg::CloseCompressPopup))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Left));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Right));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => Some(Msg::Transfer(TransferMsg::CompressSelected)),
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                Some(Msg::Ui(UiMsg::CompressFormatBlur))
+            }
+            _ => None,
+        }
+    }
+}
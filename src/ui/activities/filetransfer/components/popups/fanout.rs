@@ -0,0 +1,153 @@
+use tui_realm_stdlib::List;
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::props::{Alignment, AttrValue, Attribute, BorderType, Borders, Color, TextSpan};
+use tuirealm::{Component, Event, MockComponent, NoUserEvent, State, StateValue};
+
+use super::{Msg, PendingActionMsg};
+
+/// Popup to choose the bookmarks to transfer the selected files to
+pub struct FanoutPopup {
+    component: List,
+    bookmarks: Vec<String>,
+    selected: Vec<bool>,
+}
+
+impl FanoutPopup {
+    pub fn new(bookmarks: &[String], color: Color) -> Self {
+        let selected = vec![false; bookmarks.len()];
+        Self {
+            component: List::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .rewind(true)
+                .scroll(true)
+                .step(4)
+                .highlighted_color(color)
+                .highlighted_str("➤ ")
+                .title(
+                    "Transfer to bookmarks: <SPACE> select, <ENTER> transfer, <ESC> cancel",
+                    Alignment::Center,
+                )
+                .rows(Self::make_rows(bookmarks, &selected)),
+            bookmarks: bookmarks.to_vec(),
+            selected,
+        }
+    }
+
+    fn make_rows(bookmarks: &[String], selected: &[bool]) -> Vec<Vec<TextSpan>> {
+        bookmarks
+            .iter()
+            .zip(selected.iter())
+            .map(|(name, selected)| {
+                let check = if *selected { "[x]" } else { "[ ]" };
+                vec![TextSpan::from(format!("{check} {name}"))]
+            })
+            .collect()
+    }
+
+    /// Toggle selection of the highlighted bookmark
+    fn toggle(&mut self) {
+        if let State::One(StateValue::Usize(idx)) = self.component.state()
+            && let Some(selected) = self.selected.get_mut(idx)
+        {
+            *selected = !*selected;
+            let rows = Self::make_rows(&self.bookmarks, &self.selected);
+            self.component
+                .attr(Attribute::Content, AttrValue::Table(rows));
+        }
+    }
+}
+
+impl MockComponent for FanoutPopup {
+    fn view(&mut self, frame: &mut tuirealm::Frame, area: tuirealm::ratatui::layout::Rect) {
+        self.component.view(frame, area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.component.query(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.component.attr(attr, value);
+    }
+
+    /// Returns the indexes of the selected bookmarks
+    fn state(&self) -> State {
+        State::Vec(
+            self.selected
+                .iter()
+                .enumerate()
+                .filter(|(_, selected)| **selected)
+                .map(|(idx, _)| StateValue::Usize(idx))
+                .collect(),
+        )
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        match cmd {
+            Cmd::Toggle => {
+                self.toggle();
+                CmdResult::Changed(self.state())
+            }
+            cmd => self.component.perform(cmd),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for FanoutPopup {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        match ev {
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
+                Some(Msg::PendingAction(PendingActionMsg::FanoutCancel))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => Some(Msg::PendingAction(PendingActionMsg::Fanout)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(' '),
+                ..
+            }) => {
+                self.perform(Cmd::Toggle);
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => {
+                self.perform(Cmd::Move(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageDown,
+                ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Down));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::PageUp, ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Up));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End));
+                Some(Msg::None)
+            }
+            _ => None,
+        }
+    }
+}
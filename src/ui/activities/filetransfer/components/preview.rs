@@ -0,0 +1,44 @@
+//! ## Preview
+//!
+//! preview pane component
+
+use tui_realm_stdlib::List;
+use tuirealm::props::{Alignment, BorderType, Borders, Color, TableBuilder, TextSpan};
+use tuirealm::{Component, Event, MockComponent, NoUserEvent};
+
+use super::Msg;
+
+/// Shows the preview of the highlighted file
+#[derive(MockComponent)]
+pub struct Preview {
+    component: List,
+}
+
+impl Preview {
+    pub fn new(title: &str, rows: Vec<TextSpan>, color: Color) -> Self {
+        let mut table = TableBuilder::default();
+        for (i, row) in rows.into_iter().enumerate() {
+            if i > 0 {
+                table.add_row();
+            }
+            table.add_col(row);
+        }
+        Self {
+            component: List::default()
+                .borders(
+                    Borders::default()
+                        .color(color)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(color)
+                .rows(table.build())
+                .title(title, Alignment::Left),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for Preview {
+    fn on(&mut self, _ev: Event<NoUserEvent>) -> Option<Msg> {
+        None
+    }
+}
@@ -6,6 +6,7 @@ use tuirealm::event::{Key, KeyEvent};
 use tuirealm::props::{Alignment, BorderType, Borders, Color, TextSpan};
 use tuirealm::{Component, Event, MockComponent, NoUserEvent, State, StateValue};
 
+use crate::ui::activities::filetransfer::lib::transfer::QueueSummary;
 use crate::ui::activities::filetransfer::{MarkQueue, Msg, UiMsg};
 
 #[derive(MockComponent)]
@@ -21,12 +22,18 @@ impl SelectedFilesList {
         queue: MarkQueue,
         color: Color,
         title: &'static str,
+        summary: Option<&QueueSummary>,
     ) -> Self {
         let enqueued_paths = paths
             .iter()
             .map(|(src, _)| src.clone())
             .collect::<Vec<PathBuf>>();
 
+        let title = match summary {
+            Some(summary) => format!("{title} ({summary})"),
+            None => title.to_string(),
+        };
+
         Self {
             queue,
             paths: enqueued_paths,
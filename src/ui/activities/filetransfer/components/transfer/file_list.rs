@@ -4,15 +4,21 @@
 
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::props::{
-    Alignment, AttrValue, Attribute, Borders, Color, Style, Table, TextModifiers, TextSpan,
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Style, Table,
+    TextModifiers, TextSpan,
 };
 use tuirealm::ratatui::text::{Line, Span};
 use tuirealm::ratatui::widgets::{List as TuiList, ListDirection, ListItem, ListState};
 use tuirealm::{MockComponent, Props, State, StateValue};
 
+use crate::utils::ui::HighlightStyle;
+
 pub const FILE_LIST_CMD_SELECT_ALL: &str = "A";
 pub const FILE_LIST_CMD_DESELECT_ALL: &str = "D";
+pub const FILE_LIST_CMD_INVERT_SELECTION: &str = "I";
 const PROP_DOT_DOT: &str = "dot_dot";
+const PROP_HIGHLIGHT_STYLE: &str = "highlight_style";
+const PROP_ADVANCE_ON_TOGGLE: &str = "advance_on_toggle";
 
 /// OwnStates contains states for this component
 #[derive(Clone, Default)]
@@ -121,6 +127,15 @@ impl FileList {
         self
     }
 
+    /// Set the style of the cursor row
+    pub fn highlight_style(mut self, style: HighlightStyle) -> Self {
+        self.attr(
+            Attribute::Custom(PROP_HIGHLIGHT_STYLE),
+            AttrValue::String(style.to_string()),
+        );
+        self
+    }
+
     pub fn rows(mut self, rows: Table) -> Self {
         self.attr(Attribute::Content, AttrValue::Table(rows));
         self
@@ -132,6 +147,15 @@ impl FileList {
         self
     }
 
+    /// Set whether the cursor moves to the next row after toggling the selection of a row
+    pub fn advance_on_toggle(mut self, advance: bool) -> Self {
+        self.attr(
+            Attribute::Custom(PROP_ADVANCE_ON_TOGGLE),
+            AttrValue::Flag(advance),
+        );
+        self
+    }
+
     /// Returns the value of the `dot_dot` property
     fn has_dot_dot(&self) -> bool {
         self.props
@@ -139,6 +163,41 @@ impl FileList {
             .map(|x| x.unwrap_flag())
             .unwrap_or(false)
     }
+
+    /// Returns the value of the `advance_on_toggle` property
+    fn advances_on_toggle(&self) -> bool {
+        self.props
+            .get(Attribute::Custom(PROP_ADVANCE_ON_TOGGLE))
+            .map(|x| x.unwrap_flag())
+            .unwrap_or(true)
+    }
+
+    /// Returns the value of the `highlight_style` property
+    fn get_highlight_style(&self) -> HighlightStyle {
+        self.props
+            .get(Attribute::Custom(PROP_HIGHLIGHT_STYLE))
+            .and_then(|x| x.unwrap_string().parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+/// Get the style of the cursor row.
+///
+/// When the list is not focused, the row is just drawn with the highlighted color
+fn cursor_style(style: HighlightStyle, color: Color, focus: bool) -> Style {
+    if !focus {
+        return Style::default().fg(color);
+    }
+    match style {
+        HighlightStyle::Color => Style::default()
+            .fg(color)
+            .add_modifier(TextModifiers::REVERSED),
+        HighlightStyle::Reverse => Style::default().add_modifier(TextModifiers::REVERSED),
+        HighlightStyle::Bold => Style::default().fg(color).add_modifier(TextModifiers::BOLD),
+        HighlightStyle::Underline => Style::default()
+            .fg(color)
+            .add_modifier(TextModifiers::UNDERLINED),
+    }
 }
 
 impl MockComponent for FileList {
@@ -196,20 +255,16 @@ impl MockComponent for FileList {
             .props
             .get(Attribute::HighlightedColor)
             .map(|x| x.unwrap_color());
-        let modifiers = match focus {
-            true => TextModifiers::REVERSED,
-            false => TextModifiers::empty(),
-        };
         // Make list
         let mut list = TuiList::new(list_items)
             .block(div)
             .direction(ListDirection::TopToBottom);
         if let Some(highlighted_color) = highlighted_color {
-            list = list.highlight_style(
-                Style::default()
-                    .fg(highlighted_color)
-                    .add_modifier(modifiers),
-            );
+            list = list.highlight_style(cursor_style(
+                self.get_highlight_style(),
+                highlighted_color,
+                focus,
+            ));
         }
         let mut state: ListState = ListState::default();
         state.select(Some(self.states.list_index));
@@ -217,6 +272,14 @@ impl MockComponent for FileList {
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        // Setting the value moves the cursor to the file at the provided index
+        if let (Attribute::Value, AttrValue::Payload(PropPayload::One(PropValue::Usize(index)))) =
+            (attr, &value)
+        {
+            self.states.list_index = index + usize::from(self.has_dot_dot());
+            self.states.fix_list_index();
+            return;
+        }
         self.props.set(attr, value);
         if matches!(attr, Attribute::Content) {
             self.states.init_list_states(
@@ -308,14 +371,107 @@ impl MockComponent for FileList {
                 }
 
                 let index = self.states.real_index();
-                self.states.list_index = self
-                    .states
-                    .list_index
-                    .saturating_add(1)
-                    .min(self.states.list_len.saturating_sub(1));
+                if self.advances_on_toggle() {
+                    self.states.list_index = self
+                        .states
+                        .list_index
+                        .saturating_add(1)
+                        .min(self.states.list_len.saturating_sub(1));
+                }
                 CmdResult::Changed(State::One(StateValue::Usize(index)))
             }
             _ => CmdResult::None,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_make_cursor_style() {
+        assert_eq!(
+            cursor_style(HighlightStyle::Color, Color::Yellow, true),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(TextModifiers::REVERSED)
+        );
+        assert_eq!(
+            cursor_style(HighlightStyle::Reverse, Color::Yellow, true),
+            Style::default().add_modifier(TextModifiers::REVERSED)
+        );
+        assert_eq!(
+            cursor_style(HighlightStyle::Bold, Color::Yellow, true),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(TextModifiers::BOLD)
+        );
+        assert_eq!(
+            cursor_style(HighlightStyle::Underline, Color::Yellow, true),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(TextModifiers::UNDERLINED)
+        );
+    }
+
+    #[test]
+    fn should_make_cursor_style_without_focus() {
+        for style in [
+            HighlightStyle::Color,
+            HighlightStyle::Reverse,
+            HighlightStyle::Bold,
+            HighlightStyle::Underline,
+        ] {
+            assert_eq!(
+                cursor_style(style, Color::Yellow, false),
+                Style::default().fg(Color::Yellow)
+            );
+        }
+    }
+
+    #[test]
+    fn should_move_cursor_to_value() {
+        let rows = vec![
+            vec![TextSpan::from("a")],
+            vec![TextSpan::from("b")],
+            vec![TextSpan::from("c")],
+        ];
+        let mut list = FileList::default().rows(rows.clone());
+        list.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(2))),
+        );
+        assert_eq!(list.state(), State::One(StateValue::Usize(2)));
+        // The index is kept within the list
+        list.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(10))),
+        );
+        assert_eq!(list.state(), State::One(StateValue::Usize(2)));
+        // The index doesn't count the `..` entry
+        let mut list = FileList::default().dot_dot(true).rows(rows);
+        list.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(0))),
+        );
+        assert_eq!(list.state(), State::One(StateValue::Usize(0)));
+    }
+
+    #[test]
+    fn should_store_highlight_style() {
+        assert_eq!(
+            FileList::default().get_highlight_style(),
+            HighlightStyle::Color
+        );
+        assert_eq!(
+            FileList::default()
+                .highlight_style(HighlightStyle::Underline)
+                .get_highlight_style(),
+            HighlightStyle::Underline
+        );
+    }
+}
@@ -5,6 +5,7 @@ use tuirealm::ratatui::layout::{Constraint, Direction, Layout};
 use tuirealm::{MockComponent, State};
 
 use super::file_list::FileList;
+use crate::utils::ui::HighlightStyle;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum Focus {
@@ -74,12 +75,30 @@ impl FileListWithSearch {
         self
     }
 
+    /// Set the title of the search input
+    pub fn search_title<S: AsRef<str>>(&mut self, t: S, a: Alignment) {
+        self.search.attr(
+            Attribute::Title,
+            AttrValue::Title((t.as_ref().to_string(), a)),
+        );
+    }
+
     pub fn highlighted_color(mut self, c: Color) -> Self {
         self.file_list
             .attr(Attribute::HighlightedColor, AttrValue::Color(c));
         self
     }
 
+    pub fn highlight_style(mut self, style: HighlightStyle) -> Self {
+        self.file_list = self.file_list.highlight_style(style);
+        self
+    }
+
+    pub fn advance_on_toggle(mut self, advance: bool) -> Self {
+        self.file_list = self.file_list.advance_on_toggle(advance);
+        self
+    }
+
     pub fn rows(mut self, rows: Table) -> Self {
         self.file_list
             .attr(Attribute::Content, AttrValue::Table(rows));
@@ -4,23 +4,42 @@
 
 mod file_list;
 mod file_list_with_search;
+mod pending_key;
 
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
-use tuirealm::event::{Key, KeyEvent};
+use tuirealm::event::{Key, KeyEvent, KeyModifiers};
 use tuirealm::props::{Alignment, Borders, Color, TextSpan};
 use tuirealm::{Component, Event, MockComponent, NoUserEvent, State, StateValue};
 
 use self::file_list::FileList;
 use self::file_list_with_search::FileListWithSearch;
+use self::pending_key::{CharAction, KeySequence, PendingKey, push_count_digit};
 use super::keybindings_helper::ExplorerKeyMatcher;
 use super::{Msg, TransferMsg, UiMsg};
 use crate::config::keybindings::{ExplorerKeyBindings, GlobalKeyBindings, KeyBindings};
+use crate::ui::activities::filetransfer::lib::sibling::SiblingDirection;
+use crate::utils::ui::HighlightStyle;
+
+/// Handle the keys leaving the find results: `close_find` is checked before `disconnect`,
+/// so that either one can be rebound without breaking the other
+fn on_find_exit(matcher: &ExplorerKeyMatcher<'_>, ev: &KeyEvent) -> Option<Msg> {
+    if matcher.is_close_find(ev) {
+        Some(Msg::Ui(UiMsg::CloseFindExplorer))
+    } else if matcher.is_disconnect(ev) {
+        Some(Msg::Ui(UiMsg::ShowDisconnectPopup))
+    } else {
+        None
+    }
+}
 
 #[derive(MockComponent)]
 pub struct ExplorerFuzzy {
     component: FileListWithSearch,
     explorer_keys: ExplorerKeyBindings,
     global_keys: GlobalKeyBindings,
+    /// Search with a regex, submitted with `Enter`, instead of fuzzy searching while typing
+    regex: bool,
+    case_sensitive: bool,
 }
 
 impl ExplorerFuzzy {
@@ -30,6 +49,7 @@ impl ExplorerFuzzy {
         bg: Color,
         fg: Color,
         hg: Color,
+        highlight_style: HighlightStyle,
         keybindings: Option<&KeyBindings>,
     ) -> Self {
         let (explorer_keys, global_keys) = keybindings
@@ -47,18 +67,75 @@ impl ExplorerFuzzy {
                 .borders(Borders::default().color(hg))
                 .foreground(fg)
                 .highlighted_color(hg)
+                .highlight_style(highlight_style)
                 .title(title, Alignment::Left)
                 .rows(files.iter().map(|x| vec![TextSpan::from(*x)]).collect()),
             explorer_keys,
             global_keys,
+            regex: false,
+            case_sensitive: false,
         }
     }
 
+    /// Set whether marking a file moves the cursor to the next one
+    pub fn advance_on_mark(mut self, advance: bool) -> Self {
+        self.component = self.component.advance_on_toggle(advance);
+        self
+    }
+
     fn matcher(&self) -> ExplorerKeyMatcher<'_> {
         ExplorerKeyMatcher::new(&self.explorer_keys, &self.global_keys)
     }
 
+    fn search(&self) -> String {
+        match self.component.state() {
+            State::One(StateValue::String(search)) => search,
+            _ => String::new(),
+        }
+    }
+
+    fn update_search_title(&mut self) {
+        let title = match (self.regex, self.case_sensitive) {
+            (false, _) => "Fuzzy search",
+            (true, false) => "Regex search (ignore case)",
+            (true, true) => "Regex search (match case)",
+        };
+        self.component.search_title(title, Alignment::Left);
+    }
+
+    /// Switch between fuzzy and regex search, searching the current input with the new mode
+    fn toggle_regex(&mut self) -> Msg {
+        self.regex = !self.regex;
+        self.update_search_title();
+        match self.regex {
+            true => Msg::Ui(UiMsg::FindWithRegex(self.search())),
+            false => Msg::Ui(UiMsg::FuzzySearch(self.search())),
+        }
+    }
+
     fn on_search(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        // Toggle regex search, then the case sensitivity of the regex search
+        if let Event::Keyboard(key_ev) = &ev {
+            let matcher = self.matcher();
+            if matcher.is_toggle_search_regex(key_ev) {
+                return Some(self.toggle_regex());
+            }
+            if self.regex && matcher.is_toggle_search_case(key_ev) {
+                self.case_sensitive = !self.case_sensitive;
+                self.update_search_title();
+                return Some(Msg::Ui(UiMsg::ToggleFindCaseSensitive));
+            }
+        }
+        // Plain characters are typed into the search input
+        if let Event::Keyboard(key_ev) = &ev
+            && (!matches!(key_ev.code, Key::Char(_))
+                || key_ev
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT))
+            && let Some(msg) = on_find_exit(&self.matcher(), key_ev)
+        {
+            return Some(msg);
+        }
         match ev {
             Event::Keyboard(KeyEvent {
                 code: Key::Left, ..
@@ -96,24 +173,24 @@ impl ExplorerFuzzy {
                 Some(Msg::None)
             }
             Event::Keyboard(KeyEvent {
-                code: Key::Tab | Key::Up | Key::Down,
+                code: Key::Tab | Key::BackTab | Key::Up | Key::Down,
                 ..
             }) => {
                 self.perform(Cmd::Change);
                 Some(Msg::None)
             }
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) if self.regex => Some(Msg::Ui(UiMsg::FindWithRegex(self.search()))),
             Event::Keyboard(KeyEvent {
                 code: Key::Char(ch),
                 ..
             }) => match self.perform(Cmd::Type(ch)) {
-                CmdResult::Changed(State::One(StateValue::String(search))) => {
+                CmdResult::Changed(State::One(StateValue::String(search))) if !self.regex => {
                     Some(Msg::Ui(UiMsg::FuzzySearch(search)))
                 }
                 _ => Some(Msg::None),
             },
-            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => {
-                Some(Msg::Ui(UiMsg::CloseFindExplorer))
-            }
             _ => None,
         }
     }
@@ -160,6 +237,16 @@ impl ExplorerFuzzy {
                 let _ = self.perform(Cmd::Custom(file_list::FILE_LIST_CMD_DESELECT_ALL));
             return Some(Msg::Ui(UiMsg::MarkClear));
             }
+        if matcher.is_invert_selection(key_ev) {
+            let _ = self.perform(Cmd::Custom(file_list::FILE_LIST_CMD_INVERT_SELECTION));
+            return Some(Msg::Ui(UiMsg::InvertSelection));
+        }
+        if matcher.is_copy_marked(key_ev) {
+            return Some(Msg::Ui(UiMsg::CopyMarkedPaths));
+        }
+        if matcher.is_copy_path(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::CopyPathToClipboard));
+        }
         if matcher.is_mark_file(key_ev) {
                 let CmdResult::Changed(State::One(StateValue::Usize(index))) =
                     self.perform(Cmd::Toggle)
@@ -169,19 +256,19 @@ impl ExplorerFuzzy {
             return Some(Msg::Ui(UiMsg::MarkFile(index)));
             }
 
-        // Tab to switch focus
-        if key_ev.code == Key::Tab {
+        // Tab / BackTab to switch focus to the search input
+        if matches!(key_ev.code, Key::Tab | Key::BackTab) {
                 self.perform(Cmd::Change);
             return Some(Msg::None);
         }
 
-        // ESC to close
-        if key_ev.code == Key::Esc {
-            return Some(Msg::Ui(UiMsg::CloseFindExplorer));
+        // Close or disconnect
+        if let Some(msg) = on_find_exit(&matcher, key_ev) {
+            return Some(msg);
         }
 
         // Change panel
-        if key_ev.code == Key::Left || key_ev.code == Key::Right {
+        if matcher.is_change_panel(key_ev) {
             return Some(Msg::Ui(UiMsg::ChangeTransferWindow));
         }
 
@@ -194,6 +281,9 @@ impl ExplorerFuzzy {
         if matcher.is_transfer_file(key_ev) {
             return Some(Msg::Transfer(TransferMsg::TransferFile));
         }
+        if matcher.is_transfer_file_rsync(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::TransferFileRsync));
+        }
 
         // Go back
         if matcher.is_go_back(key_ev) {
@@ -204,6 +294,12 @@ impl ExplorerFuzzy {
         if matcher.is_toggle_hidden(key_ev) {
             return Some(Msg::Ui(UiMsg::ToggleHiddenFiles));
         }
+        if matcher.is_toggle_hidden_patterns(key_ev) {
+            return Some(Msg::Ui(UiMsg::ToggleHiddenPatterns));
+        }
+        if matcher.is_toggle_raw_sizes(key_ev) {
+            return Some(Msg::Ui(UiMsg::ToggleRawSizes));
+        }
         if matcher.is_sorting(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowFileSortingPopup));
         }
@@ -256,6 +352,7 @@ impl ExplorerFind {
         bg: Color,
         fg: Color,
         hg: Color,
+        highlight_style: HighlightStyle,
         keybindings: Option<&KeyBindings>,
     ) -> Self {
         let (explorer_keys, global_keys) = keybindings
@@ -273,6 +370,7 @@ impl ExplorerFind {
                 .borders(Borders::default().color(hg))
                 .foreground(fg)
                 .highlighted_color(hg)
+                .highlight_style(highlight_style)
                 .title(title, Alignment::Left)
                 .rows(files.iter().map(|x| vec![TextSpan::from(*x)]).collect()),
             explorer_keys,
@@ -280,6 +378,12 @@ impl ExplorerFind {
         }
     }
 
+    /// Set whether marking a file moves the cursor to the next one
+    pub fn advance_on_mark(mut self, advance: bool) -> Self {
+        self.component = self.component.advance_on_toggle(advance);
+        self
+    }
+
     fn matcher(&self) -> ExplorerKeyMatcher<'_> {
         ExplorerKeyMatcher::new(&self.explorer_keys, &self.global_keys)
     }
@@ -328,6 +432,16 @@ impl Component<Msg, NoUserEvent> for ExplorerFind {
                 let _ = self.perform(Cmd::Custom(file_list::FILE_LIST_CMD_DESELECT_ALL));
             return Some(Msg::Ui(UiMsg::MarkClear));
             }
+        if matcher.is_invert_selection(key_ev) {
+            let _ = self.perform(Cmd::Custom(file_list::FILE_LIST_CMD_INVERT_SELECTION));
+            return Some(Msg::Ui(UiMsg::InvertSelection));
+        }
+        if matcher.is_copy_marked(key_ev) {
+            return Some(Msg::Ui(UiMsg::CopyMarkedPaths));
+        }
+        if matcher.is_copy_path(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::CopyPathToClipboard));
+        }
         if matcher.is_mark_file(key_ev) {
                 let CmdResult::Changed(State::One(StateValue::Usize(index))) =
                     self.perform(Cmd::Toggle)
@@ -337,9 +451,9 @@ impl Component<Msg, NoUserEvent> for ExplorerFind {
             return Some(Msg::Ui(UiMsg::MarkFile(index)));
         }
 
-        // ESC to close
-        if key_ev.code == Key::Esc {
-            return Some(Msg::Ui(UiMsg::CloseFindExplorer));
+        // Close or disconnect
+        if let Some(msg) = on_find_exit(&matcher, key_ev) {
+            return Some(msg);
         }
 
         // Change panel
@@ -356,6 +470,9 @@ impl Component<Msg, NoUserEvent> for ExplorerFind {
         if matcher.is_transfer_file(key_ev) {
             return Some(Msg::Transfer(TransferMsg::TransferFile));
         }
+        if matcher.is_transfer_file_rsync(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::TransferFileRsync));
+        }
 
         // Go back
         if matcher.is_go_back(key_ev) {
@@ -366,6 +483,12 @@ impl Component<Msg, NoUserEvent> for ExplorerFind {
         if matcher.is_toggle_hidden(key_ev) {
             return Some(Msg::Ui(UiMsg::ToggleHiddenFiles));
         }
+        if matcher.is_toggle_hidden_patterns(key_ev) {
+            return Some(Msg::Ui(UiMsg::ToggleHiddenPatterns));
+        }
+        if matcher.is_toggle_raw_sizes(key_ev) {
+            return Some(Msg::Ui(UiMsg::ToggleRawSizes));
+        }
         if matcher.is_sorting(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowFileSortingPopup));
         }
@@ -400,6 +523,11 @@ pub struct ExplorerLocal {
     component: FileList,
     explorer_keys: ExplorerKeyBindings,
     global_keys: GlobalKeyBindings,
+    /// Whether disconnecting from this panel must be confirmed
+    confirm_disconnect: bool,
+    pending_key: PendingKey,
+    /// Count prefix of the next movement (e.g. `5` in `5j`)
+    count_buffer: Option<usize>,
 }
 
 impl ExplorerLocal {
@@ -409,6 +537,7 @@ impl ExplorerLocal {
         bg: Color,
         fg: Color,
         hg: Color,
+        highlight_style: HighlightStyle,
         keybindings: Option<&KeyBindings>,
     ) -> Self {
         let (explorer_keys, global_keys) = keybindings
@@ -426,17 +555,52 @@ impl ExplorerLocal {
                 .borders(Borders::default().color(hg))
                 .foreground(fg)
                 .highlighted_color(hg)
+                .highlight_style(highlight_style)
                 .title(title, Alignment::Left)
                 .rows(files.iter().map(|x| vec![TextSpan::from(*x)]).collect())
                 .dot_dot(true),
             explorer_keys,
             global_keys,
+            confirm_disconnect: true,
+            pending_key: PendingKey::default(),
+            count_buffer: None,
         }
     }
 
+    /// Set whether disconnecting from this panel must be confirmed
+    pub fn confirm_disconnect(mut self, confirm: bool) -> Self {
+        self.confirm_disconnect = confirm;
+        self
+    }
+
+    /// Set whether marking a file moves the cursor to the next one
+    pub fn advance_on_mark(mut self, advance: bool) -> Self {
+        self.component = self.component.advance_on_toggle(advance);
+        self
+    }
+
     fn matcher(&self) -> ExplorerKeyMatcher<'_> {
         ExplorerKeyMatcher::new(&self.explorer_keys, &self.global_keys)
     }
+
+    /// Handle the key sequences (e.g. `gg`); returns `None` if `ev` must be handled as usual
+    fn on_key_sequence(&mut self, ev: &KeyEvent) -> Option<Msg> {
+        let matcher = ExplorerKeyMatcher::new(&self.explorer_keys, &self.global_keys);
+        match self.pending_key.on_key(&matcher, ev) {
+            KeySequence::None => None,
+            KeySequence::Consumed => Some(Msg::None),
+            KeySequence::MoveToTop => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            KeySequence::GotoPath => Some(Msg::Ui(UiMsg::ShowGotoPopup)),
+            KeySequence::Char(action, ch) => Some(Msg::Transfer(match action {
+                CharAction::JumpToEntry => TransferMsg::JumpToEntryStartingWith(ch),
+                CharAction::SetDirMark => TransferMsg::SetDirMark(ch),
+                CharAction::GotoDirMark => TransferMsg::GotoDirMark(ch),
+            })),
+        }
+    }
 }
 
 impl Component<Msg, NoUserEvent> for ExplorerLocal {
@@ -445,25 +609,46 @@ impl Component<Msg, NoUserEvent> for ExplorerLocal {
             Event::Keyboard(k) => k,
             _ => return None,
         };
+        // Key sequences come first, so that their keys aren't handled as single keys
+        if let Some(msg) = self.on_key_sequence(key_ev) {
+            return Some(msg);
+        }
+        // Count prefix; any other key clears it, and Esc only clears it
+        if push_count_digit(&mut self.count_buffer, key_ev) {
+            return Some(Msg::None);
+        }
+        let count = self.count_buffer.take();
+        if count.is_some() && key_ev.code == Key::Esc {
+            return Some(Msg::None);
+        }
+        let count = count.unwrap_or(1);
         let matcher = self.matcher();
 
         // Navigation
         if matcher.is_move_down(key_ev) {
+            (0..count).for_each(|_| {
                 self.perform(Cmd::Move(Direction::Down));
+            });
             return Some(Msg::None);
-            }
+        }
         if matcher.is_move_up(key_ev) {
+            (0..count).for_each(|_| {
                 self.perform(Cmd::Move(Direction::Up));
+            });
             return Some(Msg::None);
-            }
+        }
         if matcher.is_move_down_page(key_ev) {
+            (0..count).for_each(|_| {
                 self.perform(Cmd::Scroll(Direction::Down));
+            });
             return Some(Msg::None);
-            }
+        }
         if matcher.is_move_up_page(key_ev) {
+            (0..count).for_each(|_| {
                 self.perform(Cmd::Scroll(Direction::Up));
+            });
             return Some(Msg::None);
-            }
+        }
         if matcher.is_move_to_top(key_ev) {
                 self.perform(Cmd::GoTo(Position::Begin));
             return Some(Msg::None);
@@ -482,6 +667,16 @@ impl Component<Msg, NoUserEvent> for ExplorerLocal {
                 let _ = self.perform(Cmd::Custom(file_list::FILE_LIST_CMD_DESELECT_ALL));
             return Some(Msg::Ui(UiMsg::MarkClear));
             }
+        if matcher.is_invert_selection(key_ev) {
+            let _ = self.perform(Cmd::Custom(file_list::FILE_LIST_CMD_INVERT_SELECTION));
+            return Some(Msg::Ui(UiMsg::InvertSelection));
+        }
+        if matcher.is_copy_marked(key_ev) {
+            return Some(Msg::Ui(UiMsg::CopyMarkedPaths));
+        }
+        if matcher.is_copy_path(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::CopyPathToClipboard));
+        }
         if matcher.is_mark_file(key_ev) {
                 let CmdResult::Changed(State::One(StateValue::Usize(index))) =
                     self.perform(Cmd::Toggle)
@@ -493,6 +688,9 @@ impl Component<Msg, NoUserEvent> for ExplorerLocal {
 
         // ESC to disconnect
         if matcher.is_disconnect(key_ev) {
+            if !self.confirm_disconnect {
+                return Some(Msg::Ui(UiMsg::Disconnect));
+            }
             return Some(Msg::Ui(UiMsg::ShowDisconnectPopup));
         }
 
@@ -501,10 +699,20 @@ impl Component<Msg, NoUserEvent> for ExplorerLocal {
             return Some(Msg::Ui(UiMsg::ChangeTransferWindow));
         }
 
-        // Go back
+        // Go back and forward (directories in history) and go to parent directory;
+        // checked before the other actions, so they can't be shadowed
         if matcher.is_go_back(key_ev) {
             return Some(Msg::Transfer(TransferMsg::GoToPreviousDirectory));
         }
+        if matcher.is_go_forward(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::GoForwardDirectory));
+        }
+        if matcher.is_go_to_parent(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::GoToParentDirectory));
+        }
+        if matcher.is_go_home(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::GoToHomeDirectory));
+        }
 
         // Enter directory or go to parent
         if matcher.is_enter_dir(key_ev) {
@@ -523,11 +731,24 @@ impl Component<Msg, NoUserEvent> for ExplorerLocal {
                 return Some(Msg::Transfer(TransferMsg::TransferFile));
             }
         }
+        if matcher.is_transfer_file_rsync(key_ev) {
+            if matches!(self.component.state(), State::One(StateValue::String(_))) {
+                return Some(Msg::None);
+            } else {
+                return Some(Msg::Transfer(TransferMsg::TransferFileRsync));
+            }
+        }
 
         // View operations
         if matcher.is_toggle_hidden(key_ev) {
             return Some(Msg::Ui(UiMsg::ToggleHiddenFiles));
         }
+        if matcher.is_toggle_hidden_patterns(key_ev) {
+            return Some(Msg::Ui(UiMsg::ToggleHiddenPatterns));
+        }
+        if matcher.is_toggle_raw_sizes(key_ev) {
+            return Some(Msg::Ui(UiMsg::ToggleRawSizes));
+        }
         if matcher.is_sorting(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowFileSortingPopup));
         }
@@ -536,6 +757,12 @@ impl Component<Msg, NoUserEvent> for ExplorerLocal {
         if matcher.is_copy_file(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowCopyPopup));
         }
+        if matcher.is_cut_file(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::CutFile));
+        }
+        if matcher.is_paste(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::PasteFiles));
+        }
         if matcher.is_mkdir(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowMkdirPopup));
         }
@@ -557,6 +784,33 @@ impl Component<Msg, NoUserEvent> for ExplorerLocal {
         if matcher.is_reload_dir(key_ev) {
             return Some(Msg::Transfer(TransferMsg::ReloadDir));
         }
+        if matcher.is_mirror_dir(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::Mirror));
+        }
+        if matcher.is_sync_dir(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::Bisync));
+        }
+        if matcher.is_save_bookmark(key_ev) {
+            return Some(Msg::Ui(UiMsg::ShowSaveBookmarkPopup));
+        }
+        if matcher.is_save_dir_bookmark(key_ev) {
+            return Some(Msg::Ui(UiMsg::ShowSaveDirBookmarkPopup));
+        }
+        if matcher.is_dir_bookmarks(key_ev) {
+            return Some(Msg::Ui(UiMsg::ShowDirBookmarksList));
+        }
+        if matcher.is_compress(key_ev) {
+            return Some(Msg::Ui(UiMsg::ShowCompressPopup));
+        }
+        if matcher.is_extract(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::ExtractArchive));
+        }
+        if matcher.is_duplicate(key_ev) {
+            return Some(Msg::Ui(UiMsg::ShowDuplicatePopup));
+        }
+        if matcher.is_fanout(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::Fanout));
+        }
         if matcher.is_new_file(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowNewFilePopup));
         }
@@ -566,6 +820,24 @@ impl Component<Msg, NoUserEvent> for ExplorerLocal {
         if matcher.is_pending_queue(key_ev) {
             return Some(Msg::Ui(UiMsg::GoToTransferQueue));
         }
+        if matcher.is_toggle_log(key_ev) {
+            return Some(Msg::Ui(UiMsg::ToggleLog));
+        }
+        if matcher.is_toggle_footer(key_ev) {
+            return Some(Msg::Ui(UiMsg::ToggleFooter));
+        }
+        if matcher.is_preview(key_ev) {
+            return Some(Msg::Ui(UiMsg::TogglePreviewPane));
+        }
+        if matcher.is_grow_panel(key_ev) {
+            return Some(Msg::Ui(UiMsg::GrowPanel));
+        }
+        if matcher.is_shrink_panel(key_ev) {
+            return Some(Msg::Ui(UiMsg::ShrinkPanel));
+        }
+        if matcher.is_maximize_panel(key_ev) {
+            return Some(Msg::Ui(UiMsg::ToggleMaximizePanel));
+        }
         if matcher.is_rename_file(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowRenamePopup));
         }
@@ -581,8 +853,18 @@ impl Component<Msg, NoUserEvent> for ExplorerLocal {
         if matcher.is_watched_paths(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowWatchedPathsList));
         }
-        if matcher.is_go_to_parent(key_ev) {
-            return Some(Msg::Transfer(TransferMsg::GoToParentDirectory));
+        if matcher.is_next_sibling_dir(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::GoToSiblingDirectory(
+                SiblingDirection::Next,
+            )));
+        }
+        if matcher.is_prev_sibling_dir(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::GoToSiblingDirectory(
+                SiblingDirection::Previous,
+            )));
+        }
+        if matcher.is_go_to_other_panel_dir(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::GoToOtherPanelDirectory));
         }
         if matcher.is_terminal(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowTerminal));
@@ -602,6 +884,9 @@ impl Component<Msg, NoUserEvent> for ExplorerLocal {
         if matcher.is_filter(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowFilterPopup));
         }
+        if matcher.is_select_by_pattern(key_ev) {
+            return Some(Msg::Ui(UiMsg::ShowSelectPatternPopup));
+        }
 
         None
     }
@@ -612,6 +897,9 @@ pub struct ExplorerRemote {
     component: FileList,
     explorer_keys: ExplorerKeyBindings,
     global_keys: GlobalKeyBindings,
+    pending_key: PendingKey,
+    /// Count prefix of the next movement (e.g. `5` in `5j`)
+    count_buffer: Option<usize>,
 }
 
 impl ExplorerRemote {
@@ -621,6 +909,7 @@ impl ExplorerRemote {
         bg: Color,
         fg: Color,
         hg: Color,
+        highlight_style: HighlightStyle,
         keybindings: Option<&KeyBindings>,
     ) -> Self {
         let (explorer_keys, global_keys) = keybindings
@@ -638,17 +927,45 @@ impl ExplorerRemote {
                 .borders(Borders::default().color(hg))
                 .foreground(fg)
                 .highlighted_color(hg)
+                .highlight_style(highlight_style)
                 .title(title, Alignment::Left)
                 .rows(files.iter().map(|x| vec![TextSpan::from(*x)]).collect())
                 .dot_dot(true),
             explorer_keys,
             global_keys,
+            pending_key: PendingKey::default(),
+            count_buffer: None,
         }
     }
 
+    /// Set whether marking a file moves the cursor to the next one
+    pub fn advance_on_mark(mut self, advance: bool) -> Self {
+        self.component = self.component.advance_on_toggle(advance);
+        self
+    }
+
     fn matcher(&self) -> ExplorerKeyMatcher<'_> {
         ExplorerKeyMatcher::new(&self.explorer_keys, &self.global_keys)
     }
+
+    /// Handle the key sequences (e.g. `gg`); returns `None` if `ev` must be handled as usual
+    fn on_key_sequence(&mut self, ev: &KeyEvent) -> Option<Msg> {
+        let matcher = ExplorerKeyMatcher::new(&self.explorer_keys, &self.global_keys);
+        match self.pending_key.on_key(&matcher, ev) {
+            KeySequence::None => None,
+            KeySequence::Consumed => Some(Msg::None),
+            KeySequence::MoveToTop => {
+                self.perform(Cmd::GoTo(Position::Begin));
+                Some(Msg::None)
+            }
+            KeySequence::GotoPath => Some(Msg::Ui(UiMsg::ShowGotoPopup)),
+            KeySequence::Char(action, ch) => Some(Msg::Transfer(match action {
+                CharAction::JumpToEntry => TransferMsg::JumpToEntryStartingWith(ch),
+                CharAction::SetDirMark => TransferMsg::SetDirMark(ch),
+                CharAction::GotoDirMark => TransferMsg::GotoDirMark(ch),
+            })),
+        }
+    }
 }
 
 impl Component<Msg, NoUserEvent> for ExplorerRemote {
@@ -657,25 +974,46 @@ impl Component<Msg, NoUserEvent> for ExplorerRemote {
             Event::Keyboard(k) => k,
             _ => return None,
         };
+        // Key sequences come first, so that their keys aren't handled as single keys
+        if let Some(msg) = self.on_key_sequence(key_ev) {
+            return Some(msg);
+        }
+        // Count prefix; any other key clears it, and Esc only clears it
+        if push_count_digit(&mut self.count_buffer, key_ev) {
+            return Some(Msg::None);
+        }
+        let count = self.count_buffer.take();
+        if count.is_some() && key_ev.code == Key::Esc {
+            return Some(Msg::None);
+        }
+        let count = count.unwrap_or(1);
         let matcher = self.matcher();
 
         // Navigation
         if matcher.is_move_down(key_ev) {
+            (0..count).for_each(|_| {
                 self.perform(Cmd::Move(Direction::Down));
+            });
             return Some(Msg::None);
-            }
+        }
         if matcher.is_move_up(key_ev) {
+            (0..count).for_each(|_| {
                 self.perform(Cmd::Move(Direction::Up));
+            });
             return Some(Msg::None);
-            }
+        }
         if matcher.is_move_down_page(key_ev) {
+            (0..count).for_each(|_| {
                 self.perform(Cmd::Scroll(Direction::Down));
+            });
             return Some(Msg::None);
-            }
+        }
         if matcher.is_move_up_page(key_ev) {
+            (0..count).for_each(|_| {
                 self.perform(Cmd::Scroll(Direction::Up));
+            });
             return Some(Msg::None);
-            }
+        }
         if matcher.is_move_to_top(key_ev) {
                 self.perform(Cmd::GoTo(Position::Begin));
             return Some(Msg::None);
@@ -694,6 +1032,16 @@ impl Component<Msg, NoUserEvent> for ExplorerRemote {
                 let _ = self.perform(Cmd::Custom(file_list::FILE_LIST_CMD_DESELECT_ALL));
             return Some(Msg::Ui(UiMsg::MarkClear));
             }
+        if matcher.is_invert_selection(key_ev) {
+            let _ = self.perform(Cmd::Custom(file_list::FILE_LIST_CMD_INVERT_SELECTION));
+            return Some(Msg::Ui(UiMsg::InvertSelection));
+        }
+        if matcher.is_copy_marked(key_ev) {
+            return Some(Msg::Ui(UiMsg::CopyMarkedPaths));
+        }
+        if matcher.is_copy_path(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::CopyPathToClipboard));
+        }
         if matcher.is_mark_file(key_ev) {
                 let CmdResult::Changed(State::One(StateValue::Usize(index))) =
                     self.perform(Cmd::Toggle)
@@ -713,10 +1061,20 @@ impl Component<Msg, NoUserEvent> for ExplorerRemote {
             return Some(Msg::Ui(UiMsg::ChangeTransferWindow));
         }
 
-        // Go back
+        // Go back and forward (directories in history) and go to parent directory;
+        // checked before the other actions, so they can't be shadowed
         if matcher.is_go_back(key_ev) {
             return Some(Msg::Transfer(TransferMsg::GoToPreviousDirectory));
         }
+        if matcher.is_go_forward(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::GoForwardDirectory));
+        }
+        if matcher.is_go_to_parent(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::GoToParentDirectory));
+        }
+        if matcher.is_go_home(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::GoToHomeDirectory));
+        }
 
         // Enter directory or go to parent
         if matcher.is_enter_dir(key_ev) {
@@ -735,11 +1093,24 @@ impl Component<Msg, NoUserEvent> for ExplorerRemote {
                 return Some(Msg::Transfer(TransferMsg::TransferFile));
             }
         }
+        if matcher.is_transfer_file_rsync(key_ev) {
+            if matches!(self.component.state(), State::One(StateValue::String(_))) {
+                return Some(Msg::None);
+            } else {
+                return Some(Msg::Transfer(TransferMsg::TransferFileRsync));
+            }
+        }
 
         // View operations
         if matcher.is_toggle_hidden(key_ev) {
             return Some(Msg::Ui(UiMsg::ToggleHiddenFiles));
         }
+        if matcher.is_toggle_hidden_patterns(key_ev) {
+            return Some(Msg::Ui(UiMsg::ToggleHiddenPatterns));
+        }
+        if matcher.is_toggle_raw_sizes(key_ev) {
+            return Some(Msg::Ui(UiMsg::ToggleRawSizes));
+        }
         if matcher.is_sorting(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowFileSortingPopup));
         }
@@ -748,6 +1119,12 @@ impl Component<Msg, NoUserEvent> for ExplorerRemote {
         if matcher.is_copy_file(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowCopyPopup));
         }
+        if matcher.is_cut_file(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::CutFile));
+        }
+        if matcher.is_paste(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::PasteFiles));
+        }
         if matcher.is_mkdir(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowMkdirPopup));
         }
@@ -769,6 +1146,33 @@ impl Component<Msg, NoUserEvent> for ExplorerRemote {
         if matcher.is_reload_dir(key_ev) {
             return Some(Msg::Transfer(TransferMsg::ReloadDir));
         }
+        if matcher.is_mirror_dir(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::Mirror));
+        }
+        if matcher.is_sync_dir(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::Bisync));
+        }
+        if matcher.is_save_bookmark(key_ev) {
+            return Some(Msg::Ui(UiMsg::ShowSaveBookmarkPopup));
+        }
+        if matcher.is_save_dir_bookmark(key_ev) {
+            return Some(Msg::Ui(UiMsg::ShowSaveDirBookmarkPopup));
+        }
+        if matcher.is_dir_bookmarks(key_ev) {
+            return Some(Msg::Ui(UiMsg::ShowDirBookmarksList));
+        }
+        if matcher.is_compress(key_ev) {
+            return Some(Msg::Ui(UiMsg::ShowCompressPopup));
+        }
+        if matcher.is_extract(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::ExtractArchive));
+        }
+        if matcher.is_duplicate(key_ev) {
+            return Some(Msg::Ui(UiMsg::ShowDuplicatePopup));
+        }
+        if matcher.is_transfer_and_open(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::TransferAndOpenFile));
+        }
         if matcher.is_new_file(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowNewFilePopup));
         }
@@ -778,6 +1182,24 @@ impl Component<Msg, NoUserEvent> for ExplorerRemote {
         if matcher.is_pending_queue(key_ev) {
             return Some(Msg::Ui(UiMsg::GoToTransferQueue));
         }
+        if matcher.is_toggle_log(key_ev) {
+            return Some(Msg::Ui(UiMsg::ToggleLog));
+        }
+        if matcher.is_toggle_footer(key_ev) {
+            return Some(Msg::Ui(UiMsg::ToggleFooter));
+        }
+        if matcher.is_preview(key_ev) {
+            return Some(Msg::Ui(UiMsg::TogglePreviewPane));
+        }
+        if matcher.is_grow_panel(key_ev) {
+            return Some(Msg::Ui(UiMsg::GrowPanel));
+        }
+        if matcher.is_shrink_panel(key_ev) {
+            return Some(Msg::Ui(UiMsg::ShrinkPanel));
+        }
+        if matcher.is_maximize_panel(key_ev) {
+            return Some(Msg::Ui(UiMsg::ToggleMaximizePanel));
+        }
         if matcher.is_rename_file(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowRenamePopup));
         }
@@ -793,8 +1215,18 @@ impl Component<Msg, NoUserEvent> for ExplorerRemote {
         if matcher.is_watched_paths(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowWatchedPathsList));
         }
-        if matcher.is_go_to_parent(key_ev) {
-            return Some(Msg::Transfer(TransferMsg::GoToParentDirectory));
+        if matcher.is_next_sibling_dir(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::GoToSiblingDirectory(
+                SiblingDirection::Next,
+            )));
+        }
+        if matcher.is_prev_sibling_dir(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::GoToSiblingDirectory(
+                SiblingDirection::Previous,
+            )));
+        }
+        if matcher.is_go_to_other_panel_dir(key_ev) {
+            return Some(Msg::Transfer(TransferMsg::GoToOtherPanelDirectory));
         }
         if matcher.is_terminal(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowTerminal));
@@ -814,7 +1246,641 @@ impl Component<Msg, NoUserEvent> for ExplorerRemote {
         if matcher.is_filter(key_ev) {
             return Some(Msg::Ui(UiMsg::ShowFilterPopup));
         }
+        if matcher.is_select_by_pattern(key_ev) {
+            return Some(Msg::Ui(UiMsg::ShowSelectPatternPopup));
+        }
 
         None
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::config::keybindings::KeyBinding;
+
+    fn key(code: Key) -> Event<NoUserEvent> {
+        Event::Keyboard(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn explorer_local() -> ExplorerLocal {
+        ExplorerLocal::new(
+            "local",
+            &["a", "b"],
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            None,
+        )
+    }
+
+    fn explorer_fuzzy() -> ExplorerFuzzy {
+        ExplorerFuzzy::new(
+            "fuzzy",
+            &["a", "b"],
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            None,
+        )
+    }
+
+    fn explorer_remote() -> ExplorerRemote {
+        ExplorerRemote::new(
+            "remote",
+            &["a", "b"],
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            None,
+        )
+    }
+
+    #[test]
+    fn should_go_to_parent_and_go_back_distinctly() {
+        let mut local = explorer_local();
+        assert_eq!(
+            local.on(key(Key::Char('u'))),
+            Some(Msg::Transfer(TransferMsg::GoToParentDirectory))
+        );
+        assert_eq!(
+            local.on(key(Key::Backspace)),
+            Some(Msg::Transfer(TransferMsg::GoToPreviousDirectory))
+        );
+        let mut remote = explorer_remote();
+        assert_eq!(
+            remote.on(key(Key::Char('u'))),
+            Some(Msg::Transfer(TransferMsg::GoToParentDirectory))
+        );
+        assert_eq!(
+            remote.on(key(Key::Backspace)),
+            Some(Msg::Transfer(TransferMsg::GoToPreviousDirectory))
+        );
+    }
+
+    #[test]
+    fn should_go_back_and_forward() {
+        let mut local = explorer_local();
+        assert_eq!(
+            local.on(Event::Keyboard(KeyEvent::new(
+                Key::Backspace,
+                KeyModifiers::SHIFT
+            ))),
+            Some(Msg::Transfer(TransferMsg::GoForwardDirectory))
+        );
+        let keybindings = KeyBindings::vim_style();
+        let mut remote = ExplorerRemote::new(
+            "remote",
+            &["a", "b"],
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            Some(&keybindings),
+        );
+        assert_eq!(
+            remote.on(key(Key::Char('-'))),
+            Some(Msg::Transfer(TransferMsg::GoToPreviousDirectory))
+        );
+        assert_eq!(
+            remote.on(key(Key::Char('+'))),
+            Some(Msg::Transfer(TransferMsg::GoForwardDirectory))
+        );
+    }
+
+    #[test]
+    fn should_go_to_home_directory() {
+        assert_eq!(
+            explorer_local().on(key(Key::Char('~'))),
+            Some(Msg::Transfer(TransferMsg::GoToHomeDirectory))
+        );
+        assert_eq!(
+            explorer_remote().on(key(Key::Char('~'))),
+            Some(Msg::Transfer(TransferMsg::GoToHomeDirectory))
+        );
+    }
+
+    #[test]
+    fn should_jump_to_entry_starting_with_char() {
+        let mut local = explorer_local();
+        assert_eq!(local.on(key(Key::Char('\''))), Some(Msg::None));
+        // `m` marks files, unless it follows the jump key
+        assert_eq!(
+            local.on(key(Key::Char('m'))),
+            Some(Msg::Transfer(TransferMsg::JumpToEntryStartingWith('m')))
+        );
+        assert_ne!(
+            local.on(key(Key::Char('m'))),
+            Some(Msg::Transfer(TransferMsg::JumpToEntryStartingWith('m')))
+        );
+        let mut remote = explorer_remote();
+        remote.on(key(Key::Char('\'')));
+        assert_eq!(
+            remote.on(key(Key::Char('b'))),
+            Some(Msg::Transfer(TransferMsg::JumpToEntryStartingWith('b')))
+        );
+    }
+
+    #[test]
+    fn should_set_then_go_to_dir_mark() {
+        let keybindings = KeyBindings::vim_style();
+        let mut local = ExplorerLocal::new(
+            "local",
+            &["a", "b"],
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            Some(&keybindings),
+        );
+        assert_eq!(local.on(key(Key::Char('m'))), Some(Msg::None));
+        assert_eq!(
+            local.on(key(Key::Char('a'))),
+            Some(Msg::Transfer(TransferMsg::SetDirMark('a')))
+        );
+        assert_eq!(local.on(key(Key::Char('\''))), Some(Msg::None));
+        assert_eq!(
+            local.on(key(Key::Char('a'))),
+            Some(Msg::Transfer(TransferMsg::GotoDirMark('a')))
+        );
+        // The remote explorer has marks too
+        let mut remote = explorer_remote();
+        remote.on(key(Key::Char('@')));
+        assert_eq!(
+            remote.on(key(Key::Char('z'))),
+            Some(Msg::Transfer(TransferMsg::SetDirMark('z')))
+        );
+        remote.on(key(Key::Char('`')));
+        assert_eq!(
+            remote.on(key(Key::Char('z'))),
+            Some(Msg::Transfer(TransferMsg::GotoDirMark('z')))
+        );
+    }
+
+    #[test]
+    fn should_move_to_top_with_leader_sequence() {
+        let keybindings = KeyBindings::vim_style();
+        let mut local = ExplorerLocal::new(
+            "local",
+            &["a", "b"],
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            Some(&keybindings),
+        );
+        local.on(key(Key::Char('j')));
+        assert_eq!(local.state(), State::One(StateValue::Usize(0)));
+        // A single `g` waits for the next key
+        assert_eq!(local.on(key(Key::Char('g'))), Some(Msg::None));
+        assert_eq!(local.state(), State::One(StateValue::Usize(0)));
+        assert_eq!(local.on(key(Key::Char('g'))), Some(Msg::None));
+        assert_eq!(
+            local.state(),
+            State::One(StateValue::String("..".to_string()))
+        );
+        // Another key cancels the sequence and is handled as usual
+        local.on(key(Key::Char('g')));
+        local.on(key(Key::Char('j')));
+        assert_eq!(local.state(), State::One(StateValue::Usize(0)));
+        // The leader followed by the go to path key opens the go to popup
+        local.on(key(Key::Char('g')));
+        assert_eq!(
+            local.on(key(Key::Char(':'))),
+            Some(Msg::Ui(UiMsg::ShowGotoPopup))
+        );
+        // Esc cancels the sequence without disconnecting
+        local.on(key(Key::Char('g')));
+        assert_eq!(local.on(key(Key::Esc)), Some(Msg::None));
+        assert_eq!(
+            local.on(key(Key::Esc)),
+            Some(Msg::Ui(UiMsg::ShowDisconnectPopup))
+        );
+
+        let mut remote = ExplorerRemote::new(
+            "remote",
+            &["a", "b"],
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            Some(&keybindings),
+        );
+        remote.on(key(Key::Char('j')));
+        remote.on(key(Key::Char('g')));
+        remote.on(key(Key::Char('g')));
+        assert_eq!(
+            remote.state(),
+            State::One(StateValue::String("..".to_string()))
+        );
+    }
+
+    #[test]
+    fn should_repeat_movement_with_count_prefix() {
+        let files: Vec<String> = (0..20).map(|x| x.to_string()).collect();
+        let files: Vec<&str> = files.iter().map(String::as_str).collect();
+        let keybindings = KeyBindings::vim_style();
+        let mut local = ExplorerLocal::new(
+            "local",
+            &files,
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            Some(&keybindings),
+        );
+        assert_eq!(local.on(key(Key::Char('1'))), Some(Msg::None));
+        assert_eq!(local.on(key(Key::Char('0'))), Some(Msg::None));
+        assert_eq!(local.on(key(Key::Char('j'))), Some(Msg::None));
+        // `..` comes first
+        assert_eq!(local.state(), State::One(StateValue::Usize(9)));
+        // The count has been reset
+        local.on(key(Key::Char('k')));
+        assert_eq!(local.state(), State::One(StateValue::Usize(8)));
+        // Other keys clear the count
+        local.on(key(Key::Char('3')));
+        local.on(key(Key::Char('.')));
+        local.on(key(Key::Char('j')));
+        assert_eq!(local.state(), State::One(StateValue::Usize(9)));
+        // Esc only clears the count
+        local.on(key(Key::Char('3')));
+        assert_eq!(local.on(key(Key::Esc)), Some(Msg::None));
+        local.on(key(Key::Char('j')));
+        assert_eq!(local.state(), State::One(StateValue::Usize(10)));
+
+        let mut remote = ExplorerRemote::new(
+            "remote",
+            &files,
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            Some(&keybindings),
+        );
+        remote.on(key(Key::Char('5')));
+        remote.on(key(Key::Char('j')));
+        assert_eq!(remote.state(), State::One(StateValue::Usize(4)));
+    }
+
+    #[test]
+    fn should_copy_path_to_clipboard() {
+        let copy_path = Event::Keyboard(KeyEvent::new(Key::Char('y'), KeyModifiers::CONTROL));
+        let mut local = explorer_local();
+        // The `..` entry is selected at first
+        assert_eq!(
+            local.on(copy_path.clone()),
+            Some(Msg::Transfer(TransferMsg::CopyPathToClipboard))
+        );
+        local.on(key(Key::Down));
+        assert_eq!(
+            local.on(copy_path.clone()),
+            Some(Msg::Transfer(TransferMsg::CopyPathToClipboard))
+        );
+        assert_eq!(
+            explorer_remote().on(copy_path),
+            Some(Msg::Transfer(TransferMsg::CopyPathToClipboard))
+        );
+    }
+
+    #[test]
+    fn should_invert_selection() {
+        let invert = Event::Keyboard(KeyEvent::new(Key::Char('i'), KeyModifiers::ALT));
+        assert_eq!(
+            explorer_local().on(invert.clone()),
+            Some(Msg::Ui(UiMsg::InvertSelection))
+        );
+        assert_eq!(
+            explorer_remote().on(invert),
+            Some(Msg::Ui(UiMsg::InvertSelection))
+        );
+    }
+
+    #[test]
+    fn should_search_with_regex() {
+        let ctrl = |ch| Event::Keyboard(KeyEvent::new(Key::Char(ch), KeyModifiers::CONTROL));
+        let mut fuzzy = explorer_fuzzy();
+        assert_eq!(
+            fuzzy.on(key(Key::Char('a'))),
+            Some(Msg::Ui(UiMsg::FuzzySearch(String::from("a"))))
+        );
+        assert_eq!(
+            fuzzy.on(ctrl('r')),
+            Some(Msg::Ui(UiMsg::FindWithRegex(String::from("a"))))
+        );
+        // The regex is searched on enter
+        assert_eq!(fuzzy.on(key(Key::Char('$'))), Some(Msg::None));
+        assert_eq!(
+            fuzzy.on(key(Key::Enter)),
+            Some(Msg::Ui(UiMsg::FindWithRegex(String::from("a$"))))
+        );
+        assert_eq!(
+            fuzzy.on(ctrl('u')),
+            Some(Msg::Ui(UiMsg::ToggleFindCaseSensitive))
+        );
+        assert_eq!(
+            fuzzy.on(ctrl('r')),
+            Some(Msg::Ui(UiMsg::FuzzySearch(String::from("a$"))))
+        );
+    }
+
+    #[test]
+    fn should_search_with_regex_rebind() {
+        let alt = |ch| Event::Keyboard(KeyEvent::new(Key::Char(ch), KeyModifiers::ALT));
+        let mut keybindings = KeyBindings::default();
+        keybindings.explorer.toggle_search_regex = vec![KeyBinding::alt(Key::Char('x'))];
+        keybindings.explorer.toggle_search_case = vec![KeyBinding::alt(Key::Char('y'))];
+        let mut fuzzy = ExplorerFuzzy::new(
+            "fuzzy",
+            &["a", "b"],
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            Some(&keybindings),
+        );
+        // The default key no longer toggles the regex search
+        assert_ne!(
+            fuzzy.on(Event::Keyboard(KeyEvent::new(
+                Key::Char('r'),
+                KeyModifiers::CONTROL
+            ))),
+            Some(Msg::Ui(UiMsg::FindWithRegex(String::new())))
+        );
+        assert!(matches!(
+            fuzzy.on(alt('x')),
+            Some(Msg::Ui(UiMsg::FindWithRegex(_)))
+        ));
+        assert_eq!(
+            fuzzy.on(alt('y')),
+            Some(Msg::Ui(UiMsg::ToggleFindCaseSensitive))
+        );
+    }
+
+    #[test]
+    fn should_show_select_pattern_popup() {
+        let select = key(Key::Char('\\'));
+        assert_eq!(
+            explorer_local().on(select.clone()),
+            Some(Msg::Ui(UiMsg::ShowSelectPatternPopup))
+        );
+        assert_eq!(
+            explorer_remote().on(select),
+            Some(Msg::Ui(UiMsg::ShowSelectPatternPopup))
+        );
+    }
+
+    #[test]
+    fn should_cut_and_paste_files() {
+        let cut = Event::Keyboard(KeyEvent::new(Key::Char('x'), KeyModifiers::CONTROL));
+        let paste = Event::Keyboard(KeyEvent::new(Key::Char('v'), KeyModifiers::CONTROL));
+        let mut local = explorer_local();
+        assert_eq!(
+            local.on(cut.clone()),
+            Some(Msg::Transfer(TransferMsg::CutFile))
+        );
+        assert_eq!(
+            local.on(paste.clone()),
+            Some(Msg::Transfer(TransferMsg::PasteFiles))
+        );
+        let mut remote = explorer_remote();
+        assert_eq!(remote.on(cut), Some(Msg::Transfer(TransferMsg::CutFile)));
+        assert_eq!(
+            remote.on(paste),
+            Some(Msg::Transfer(TransferMsg::PasteFiles))
+        );
+    }
+
+    #[test]
+    fn should_confirm_disconnect_from_local_panel() {
+        assert_eq!(
+            explorer_local().on(key(Key::Esc)),
+            Some(Msg::Ui(UiMsg::ShowDisconnectPopup))
+        );
+    }
+
+    #[test]
+    fn should_disconnect_from_local_panel_without_confirmation() {
+        let mut local = ExplorerLocal::new(
+            "local",
+            &["a", "b"],
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            None,
+        )
+        .confirm_disconnect(false);
+        assert_eq!(local.on(key(Key::Esc)), Some(Msg::Ui(UiMsg::Disconnect)));
+        // The remote panel always asks
+        assert_eq!(
+            explorer_remote().on(key(Key::Esc)),
+            Some(Msg::Ui(UiMsg::ShowDisconnectPopup))
+        );
+    }
+
+    #[test]
+    fn should_advance_on_mark() {
+        let mut fuzzy = explorer_fuzzy();
+        fuzzy.on(key(Key::Tab));
+        assert_eq!(
+            fuzzy.on(key(Key::Char('m'))),
+            Some(Msg::Ui(UiMsg::MarkFile(0)))
+        );
+        assert_eq!(
+            fuzzy.on(key(Key::Char('m'))),
+            Some(Msg::Ui(UiMsg::MarkFile(1)))
+        );
+        // Stay on last file
+        assert_eq!(
+            fuzzy.on(key(Key::Char('m'))),
+            Some(Msg::Ui(UiMsg::MarkFile(1)))
+        );
+    }
+
+    #[test]
+    fn should_stay_on_mark() {
+        let mut fuzzy = explorer_fuzzy().advance_on_mark(false);
+        fuzzy.on(key(Key::Tab));
+        assert_eq!(
+            fuzzy.on(key(Key::Char('m'))),
+            Some(Msg::Ui(UiMsg::MarkFile(0)))
+        );
+        assert_eq!(
+            fuzzy.on(key(Key::Char('m'))),
+            Some(Msg::Ui(UiMsg::MarkFile(0)))
+        );
+    }
+
+    #[test]
+    fn should_not_shadow_go_to_parent() {
+        // Bind go_to_parent to a key used by another action
+        let mut keybindings = KeyBindings::default();
+        keybindings.explorer.go_to_parent = keybindings.explorer.reload_dir.clone();
+        let mut local = ExplorerLocal::new(
+            "local",
+            &["a", "b"],
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            Some(&keybindings),
+        );
+        assert_eq!(
+            local.on(key(Key::Char('l'))),
+            Some(Msg::Transfer(TransferMsg::GoToParentDirectory))
+        );
+    }
+
+    #[test]
+    fn should_switch_fuzzy_focus_with_backtab() {
+        let mut fuzzy = explorer_fuzzy();
+        assert_eq!(
+            fuzzy.component.focus(),
+            file_list_with_search::Focus::Search
+        );
+        assert_eq!(fuzzy.on(key(Key::BackTab)), Some(Msg::None));
+        assert_eq!(fuzzy.component.focus(), file_list_with_search::Focus::List);
+        assert_eq!(fuzzy.on(key(Key::BackTab)), Some(Msg::None));
+        assert_eq!(
+            fuzzy.component.focus(),
+            file_list_with_search::Focus::Search
+        );
+    }
+
+    #[test]
+    fn should_change_panel_from_fuzzy_list() {
+        let mut fuzzy = explorer_fuzzy();
+        fuzzy.on(key(Key::Tab));
+        assert_eq!(fuzzy.component.focus(), file_list_with_search::Focus::List);
+        assert_eq!(
+            fuzzy.on(key(Key::Left)),
+            Some(Msg::Ui(UiMsg::ChangeTransferWindow))
+        );
+        assert_eq!(
+            fuzzy.on(key(Key::Right)),
+            Some(Msg::Ui(UiMsg::ChangeTransferWindow))
+        );
+    }
+
+    #[test]
+    fn should_change_panel_from_find_with_backtab() {
+        let mut find = ExplorerFind::new(
+            "find",
+            &["a", "b"],
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            None,
+        );
+        assert_eq!(
+            find.on(Event::Keyboard(KeyEvent::new(
+                Key::BackTab,
+                KeyModifiers::SHIFT
+            ))),
+            Some(Msg::Ui(UiMsg::ChangeTransferWindow))
+        );
+        assert_eq!(
+            find.on(key(Key::Tab)),
+            Some(Msg::Ui(UiMsg::ChangeTransferWindow))
+        );
+    }
+
+    #[test]
+    fn should_close_find_with_esc_by_default() {
+        let mut find = ExplorerFind::new(
+            "find",
+            &["a", "b"],
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            None,
+        );
+        assert_eq!(
+            find.on(key(Key::Esc)),
+            Some(Msg::Ui(UiMsg::CloseFindExplorer))
+        );
+        let mut fuzzy = explorer_fuzzy();
+        assert_eq!(
+            fuzzy.on(key(Key::Esc)),
+            Some(Msg::Ui(UiMsg::CloseFindExplorer))
+        );
+    }
+
+    #[test]
+    fn should_close_find_and_disconnect_with_distinct_keys() {
+        let mut keybindings = KeyBindings::default();
+        keybindings.explorer.close_find = vec![KeyBinding::ctrl(Key::Char('c'))];
+        let ctrl_c = Event::Keyboard(KeyEvent::new(Key::Char('c'), KeyModifiers::CONTROL));
+        let mut find = ExplorerFind::new(
+            "find",
+            &["a", "b"],
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            Some(&keybindings),
+        );
+        assert_eq!(
+            find.on(ctrl_c.clone()),
+            Some(Msg::Ui(UiMsg::CloseFindExplorer))
+        );
+        assert_eq!(
+            find.on(key(Key::Esc)),
+            Some(Msg::Ui(UiMsg::ShowDisconnectPopup))
+        );
+        // both in the search input and in the result list of the fuzzy finder
+        let mut fuzzy = ExplorerFuzzy::new(
+            "fuzzy",
+            &["a", "b"],
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            Some(&keybindings),
+        );
+        for _ in 0..2 {
+            assert_eq!(
+                fuzzy.on(ctrl_c.clone()),
+                Some(Msg::Ui(UiMsg::CloseFindExplorer))
+            );
+            assert_eq!(
+                fuzzy.on(key(Key::Esc)),
+                Some(Msg::Ui(UiMsg::ShowDisconnectPopup))
+            );
+            fuzzy.on(key(Key::Tab));
+        }
+        assert_eq!(
+            fuzzy.component.focus(),
+            file_list_with_search::Focus::Search
+        );
+    }
+
+    #[test]
+    fn should_disconnect_from_find_with_rebound_disconnect() {
+        let mut keybindings = KeyBindings::default();
+        keybindings.global.disconnect = KeyBinding::ctrl(Key::Char('d'));
+        let ctrl_d = Event::Keyboard(KeyEvent::new(Key::Char('d'), KeyModifiers::CONTROL));
+        let mut fuzzy = ExplorerFuzzy::new(
+            "fuzzy",
+            &["a", "b"],
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+            HighlightStyle::default(),
+            Some(&keybindings),
+        );
+        assert_eq!(fuzzy.on(ctrl_d), Some(Msg::Ui(UiMsg::ShowDisconnectPopup)));
+        assert_eq!(
+            fuzzy.on(key(Key::Esc)),
+            Some(Msg::Ui(UiMsg::CloseFindExplorer))
+        );
+    }
+}
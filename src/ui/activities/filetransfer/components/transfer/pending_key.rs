@@ -0,0 +1,304 @@
+//! ## Pending key
+//!
+//! key sequences and count prefixes of the explorers (e.g. `gg`, `'a`, `ma`, `5j`)
+
+use std::time::{Duration, Instant};
+
+use tuirealm::event::{Key, KeyEvent, KeyModifiers};
+
+use super::ExplorerKeyMatcher;
+
+/// Time to press the next key of a sequence, before the pending key is discarded
+const PENDING_KEY_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Maximum count prefix
+const MAX_COUNT: usize = 9999;
+
+/// First key of a sequence, waiting for the next one
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PendingKey {
+    #[default]
+    None,
+    /// The leader key has been pressed at this instant
+    Leader(Instant),
+    /// A key taking a char has been pressed at this instant
+    Char(CharAction, Instant),
+}
+
+/// Action of a key taking the char typed next
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharAction {
+    /// Move to the next entry starting with the char
+    JumpToEntry,
+    /// Mark the working directory with the char
+    SetDirMark,
+    /// Go to the directory marked with the char
+    GotoDirMark,
+}
+
+/// What to do with a key, once checked against the key sequences
+#[derive(Debug, PartialEq, Eq)]
+pub enum KeySequence {
+    /// The key isn't part of a sequence and must be handled as usual
+    None,
+    /// The key started or cancelled a sequence
+    Consumed,
+    /// The leader has been pressed twice: move to the top
+    MoveToTop,
+    /// The leader has been followed by the go to path key
+    GotoPath,
+    /// A char has been typed after a key taking it
+    Char(CharAction, char),
+}
+
+impl PendingKey {
+    /// Check `ev` against the key sequences, updating the pending key.
+    ///
+    /// A pending key is cleared by any key, by `Esc` without further action, and it expires after
+    /// [`PENDING_KEY_TIMEOUT`]; then the key is handled as usual
+    pub fn on_key(&mut self, matcher: &ExplorerKeyMatcher<'_>, ev: &KeyEvent) -> KeySequence {
+        let pending = std::mem::take(self);
+        match pending {
+            Self::Leader(at) if at.elapsed() < PENDING_KEY_TIMEOUT => {
+                if matcher.is_leader(ev) {
+                    KeySequence::MoveToTop
+                } else if matcher.is_goto_path(ev) {
+                    KeySequence::GotoPath
+                } else if ev.code == Key::Esc {
+                    KeySequence::Consumed
+                } else {
+                    KeySequence::None
+                }
+            }
+            Self::Char(action, at) if at.elapsed() < PENDING_KEY_TIMEOUT => match ev.code {
+                Key::Char(ch)
+                    if !ev
+                        .modifiers
+                        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+                {
+                    KeySequence::Char(action, ch)
+                }
+                Key::Esc => KeySequence::Consumed,
+                _ => KeySequence::None,
+            },
+            _ if matcher.is_leader(ev) => {
+                *self = Self::Leader(Instant::now());
+                KeySequence::Consumed
+            }
+            _ => match Self::char_action(matcher, ev) {
+                Some(action) => {
+                    *self = Self::Char(action, Instant::now());
+                    KeySequence::Consumed
+                }
+                None => KeySequence::None,
+            },
+        }
+    }
+
+    /// Get the action of `ev`, if it's a key taking a char
+    fn char_action(matcher: &ExplorerKeyMatcher<'_>, ev: &KeyEvent) -> Option<CharAction> {
+        if matcher.is_jump_to_entry(ev) {
+            Some(CharAction::JumpToEntry)
+        } else if matcher.is_set_dir_mark(ev) {
+            Some(CharAction::SetDirMark)
+        } else if matcher.is_goto_dir_mark(ev) {
+            Some(CharAction::GotoDirMark)
+        } else {
+            None
+        }
+    }
+}
+
+/// Add the digit typed with `ev`, if any, to the count prefix `count`.
+///
+/// Returns whether `ev` is part of the count. `0` can't start a count, it only extends one
+pub fn push_count_digit(count: &mut Option<usize>, ev: &KeyEvent) -> bool {
+    if ev.modifiers != KeyModifiers::NONE {
+        return false;
+    }
+    let Some(digit) = (match ev.code {
+        Key::Char(ch) => ch.to_digit(10),
+        _ => None,
+    }) else {
+        return false;
+    };
+    *count = match *count {
+        None if digit == 0 => return false,
+        None => Some(digit as usize),
+        Some(count) => Some((count * 10 + digit as usize).min(MAX_COUNT)),
+    };
+    true
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::config::keybindings::KeyBindings;
+
+    fn key(code: Key) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn should_move_to_top_on_leader_pressed_twice() {
+        let kb = KeyBindings::vim_style();
+        let matcher = ExplorerKeyMatcher::new(&kb.explorer, &kb.global);
+        let mut pending = PendingKey::default();
+        assert_eq!(
+            pending.on_key(&matcher, &key(Key::Char('g'))),
+            KeySequence::Consumed
+        );
+        assert!(matches!(pending, PendingKey::Leader(_)));
+        assert_eq!(
+            pending.on_key(&matcher, &key(Key::Char('g'))),
+            KeySequence::MoveToTop
+        );
+        assert_eq!(pending, PendingKey::None);
+    }
+
+    #[test]
+    fn should_go_to_path_on_leader_followed_by_goto_path() {
+        let kb = KeyBindings::vim_style();
+        let matcher = ExplorerKeyMatcher::new(&kb.explorer, &kb.global);
+        let mut pending = PendingKey::default();
+        pending.on_key(&matcher, &key(Key::Char('g')));
+        assert_eq!(
+            pending.on_key(&matcher, &key(Key::Char(':'))),
+            KeySequence::GotoPath
+        );
+        assert_eq!(pending, PendingKey::None);
+    }
+
+    #[test]
+    fn should_cancel_sequence() {
+        let kb = KeyBindings::vim_style();
+        let matcher = ExplorerKeyMatcher::new(&kb.explorer, &kb.global);
+        let mut pending = PendingKey::default();
+        pending.on_key(&matcher, &key(Key::Char('g')));
+        assert_eq!(
+            pending.on_key(&matcher, &key(Key::Esc)),
+            KeySequence::Consumed
+        );
+        assert_eq!(pending, PendingKey::None);
+        // Another key cancels the sequence and is handled as usual
+        pending.on_key(&matcher, &key(Key::Char('g')));
+        assert_eq!(
+            pending.on_key(&matcher, &key(Key::Char('j'))),
+            KeySequence::None
+        );
+        assert_eq!(pending, PendingKey::None);
+    }
+
+    #[test]
+    fn should_expire_pending_key() {
+        let kb = KeyBindings::vim_style();
+        let matcher = ExplorerKeyMatcher::new(&kb.explorer, &kb.global);
+        let mut pending =
+            PendingKey::Leader(Instant::now().checked_sub(PENDING_KEY_TIMEOUT).unwrap());
+        // The leader starts a new sequence
+        assert_eq!(
+            pending.on_key(&matcher, &key(Key::Char('g'))),
+            KeySequence::Consumed
+        );
+        let mut pending =
+            PendingKey::Leader(Instant::now().checked_sub(PENDING_KEY_TIMEOUT).unwrap());
+        assert_eq!(pending.on_key(&matcher, &key(Key::Esc)), KeySequence::None);
+    }
+
+    #[test]
+    fn should_ignore_sequences_without_leader() {
+        let kb = KeyBindings::default();
+        let matcher = ExplorerKeyMatcher::new(&kb.explorer, &kb.global);
+        let mut pending = PendingKey::default();
+        assert_eq!(
+            pending.on_key(&matcher, &key(Key::Char('g'))),
+            KeySequence::None
+        );
+        assert_eq!(pending, PendingKey::None);
+    }
+
+    #[test]
+    fn should_jump_to_char_typed_after_jump_key() {
+        let kb = KeyBindings::default();
+        let matcher = ExplorerKeyMatcher::new(&kb.explorer, &kb.global);
+        let mut pending = PendingKey::default();
+        assert_eq!(
+            pending.on_key(&matcher, &key(Key::Char('\''))),
+            KeySequence::Consumed
+        );
+        assert!(matches!(
+            pending,
+            PendingKey::Char(CharAction::JumpToEntry, _)
+        ));
+        // Any char is a target, even if it's bound to an action
+        assert_eq!(
+            pending.on_key(&matcher, &key(Key::Char('m'))),
+            KeySequence::Char(CharAction::JumpToEntry, 'm')
+        );
+        assert_eq!(pending, PendingKey::None);
+        // Jumps are one-shot
+        assert_eq!(
+            pending.on_key(&matcher, &key(Key::Char('m'))),
+            KeySequence::None
+        );
+        pending.on_key(&matcher, &key(Key::Char('\'')));
+        assert_eq!(
+            pending.on_key(&matcher, &key(Key::Esc)),
+            KeySequence::Consumed
+        );
+        assert_eq!(pending, PendingKey::None);
+    }
+
+    #[test]
+    fn should_take_dir_mark_char() {
+        let kb = KeyBindings::vim_style();
+        let matcher = ExplorerKeyMatcher::new(&kb.explorer, &kb.global);
+        let mut pending = PendingKey::default();
+        pending.on_key(&matcher, &key(Key::Char('m')));
+        assert_eq!(
+            pending.on_key(&matcher, &key(Key::Char('a'))),
+            KeySequence::Char(CharAction::SetDirMark, 'a')
+        );
+        pending.on_key(&matcher, &key(Key::Char('\'')));
+        assert_eq!(
+            pending.on_key(&matcher, &key(Key::Char('a'))),
+            KeySequence::Char(CharAction::GotoDirMark, 'a')
+        );
+        // Chars typed with modifiers cancel the sequence
+        pending.on_key(&matcher, &key(Key::Char('m')));
+        assert_eq!(
+            pending.on_key(&matcher, &KeyEvent::new(Key::Char('a'), KeyModifiers::ALT)),
+            KeySequence::None
+        );
+    }
+
+    #[test]
+    fn should_push_count_digits() {
+        let mut count = None;
+        assert!(push_count_digit(&mut count, &key(Key::Char('1'))));
+        assert!(push_count_digit(&mut count, &key(Key::Char('0'))));
+        assert_eq!(count, Some(10));
+        assert!(!push_count_digit(&mut count, &key(Key::Char('j'))));
+        assert_eq!(count, Some(10));
+        // Digits typed with modifiers aren't part of the count
+        assert!(!push_count_digit(
+            &mut count,
+            &KeyEvent::new(Key::Char('1'), KeyModifiers::ALT)
+        ));
+        // Counts are capped
+        (0..8).for_each(|_| {
+            push_count_digit(&mut count, &key(Key::Char('9')));
+        });
+        assert_eq!(count, Some(MAX_COUNT));
+    }
+
+    #[test]
+    fn should_not_start_count_with_zero() {
+        let mut count = None;
+        assert!(!push_count_digit(&mut count, &key(Key::Char('0'))));
+        assert_eq!(count, None);
+    }
+}
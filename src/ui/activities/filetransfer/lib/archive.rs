@@ -0,0 +1,488 @@
+//! ## Archive
+//!
+//! `archive` lists the files to archive out of the selected entries and writes them into a tar.gz or zip archive,
+//! and reads the entries to extract out of an archive
+
+use std::io::{self, Read, Seek, Write};
+use std::path::{Component, Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use remotefs::File;
+use remotefs::fs::Metadata;
+use zip::write::SimpleFileOptions;
+
+/// Supported archive formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    TarGz,
+    Zip,
+}
+
+impl ArchiveFormat {
+    /// File extension of the archive, without the leading dot
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::TarGz => "tar.gz",
+            Self::Zip => "zip",
+        }
+    }
+
+    /// Detect the format of the archive at `path` from its extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+        if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Self::TarGz)
+        } else if name.ends_with(".zip") {
+            Some(Self::Zip)
+        } else {
+            None
+        }
+    }
+}
+
+/// An entry to put into the archive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchiveEntry {
+    /// Path of the file on its host
+    pub path: PathBuf,
+    /// Name of the entry inside of the archive, with `/` as separator
+    pub name: String,
+    pub metadata: Metadata,
+}
+
+impl ArchiveEntry {
+    fn new(wrkdir: &Path, file: &File) -> Self {
+        // Entries outside of the working directory are archived by their name
+        let name = match file.path().strip_prefix(wrkdir) {
+            Ok(relative) if !relative.as_os_str().is_empty() => relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+            _ => file.name(),
+        };
+        Self {
+            path: file.path().to_path_buf(),
+            name,
+            metadata: file.metadata().clone(),
+        }
+    }
+
+    pub fn is_dir(&self) -> bool {
+        self.metadata.is_dir()
+    }
+}
+
+/// List the entries to archive out of the selected files, named after their path relative to `wrkdir`.
+/// Directories are archived with their whole content, which is listed with `list_dir`; symlinks are skipped.
+/// Entries are sorted by name, and entries selected twice (e.g. a directory and a file inside of it) are archived once
+pub fn archive_entries<F>(
+    wrkdir: &Path,
+    selected: &[File],
+    mut list_dir: F,
+) -> Result<Vec<ArchiveEntry>, String>
+where
+    F: FnMut(&Path) -> Result<Vec<File>, String>,
+{
+    let mut entries = Vec::new();
+    let mut stack: Vec<File> = selected.to_vec();
+    while let Some(file) = stack.pop() {
+        if file.is_symlink() {
+            continue;
+        }
+        if file.is_dir() {
+            stack.extend(list_dir(file.path())?);
+        }
+        entries.push(ArchiveEntry::new(wrkdir, &file));
+    }
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    entries.dedup_by(|a, b| a.name == b.name);
+    Ok(entries)
+}
+
+/// Get the path to write the archive named `name` at in `wrkdir`, adding the format extension if missing.
+/// If the path is already taken, according to `exists`, a `_{n}` suffix is added to the name
+pub fn archive_path<F>(wrkdir: &Path, name: &str, format: ArchiveFormat, mut exists: F) -> PathBuf
+where
+    F: FnMut(&Path) -> bool,
+{
+    let extension = format!(".{}", format.extension());
+    let stem = name.strip_suffix(extension.as_str()).unwrap_or(name);
+    let mut path = wrkdir.join(format!("{stem}{extension}"));
+    let mut n = 1;
+    while exists(&path) {
+        path = wrkdir.join(format!("{stem}_{n}{extension}"));
+        n += 1;
+    }
+    path
+}
+
+/// Get the path to extract the archive entry named `name` to, in `dest`.
+/// Names which would be extracted outside of `dest`, such as absolute paths or paths containing `..`, are rejected
+pub fn extract_path(dest: &Path, name: &str) -> Result<PathBuf, String> {
+    let mut path = dest.to_path_buf();
+    for component in Path::new(name).components() {
+        match component {
+            Component::Normal(component) => path.push(component),
+            Component::CurDir => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => {
+                return Err(format!(
+                    "refusing to extract \"{name}\" outside of \"{}\"",
+                    dest.display()
+                ));
+            }
+        }
+    }
+    if path == dest {
+        return Err(format!("invalid entry name \"{name}\""));
+    }
+    Ok(path)
+}
+
+/// An entry read out of an archive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtractedEntry {
+    /// Name of the entry inside of the archive
+    pub name: String,
+    pub dir: bool,
+    pub size: u64,
+    pub mode: Option<u32>,
+}
+
+/// Read the entries of the archive, calling `extract` with each directory and file, along with its content.
+/// Other entries, such as symlinks, are skipped. Returns the amount of extracted entries
+pub fn read_archive<R, F>(format: ArchiveFormat, reader: R, mut extract: F) -> Result<usize, String>
+where
+    R: Read + Seek,
+    F: FnMut(&ExtractedEntry, &mut dyn Read) -> Result<(), String>,
+{
+    let invalid = |err: &dyn std::fmt::Display| format!("invalid archive: {err}");
+    let mut extracted = 0;
+    match format {
+        ArchiveFormat::TarGz => {
+            let mut archive = tar::Archive::new(GzDecoder::new(reader));
+            for entry in archive.entries().map_err(|err| invalid(&err))? {
+                let mut entry = entry.map_err(|err| invalid(&err))?;
+                let entry_type = entry.header().entry_type();
+                if !entry_type.is_dir() && !entry_type.is_file() {
+                    continue;
+                }
+                let extracted_entry = ExtractedEntry {
+                    name: entry
+                        .path()
+                        .map_err(|err| invalid(&err))?
+                        .to_string_lossy()
+                        .to_string(),
+                    dir: entry_type.is_dir(),
+                    size: entry.size(),
+                    mode: entry.header().mode().ok(),
+                };
+                extract(&extracted_entry, &mut entry)?;
+                extracted += 1;
+            }
+        }
+        ArchiveFormat::Zip => {
+            let mut archive = zip::ZipArchive::new(reader).map_err(|err| invalid(&err))?;
+            for i in 0..archive.len() {
+                let mut file = archive.by_index(i).map_err(|err| invalid(&err))?;
+                if file.is_symlink() {
+                    continue;
+                }
+                let extracted_entry = ExtractedEntry {
+                    name: file.name().to_string(),
+                    dir: file.is_dir(),
+                    size: file.size(),
+                    mode: file.unix_mode(),
+                };
+                extract(&extracted_entry, &mut file)?;
+                extracted += 1;
+            }
+        }
+    }
+    Ok(extracted)
+}
+
+/// Writes the entries into an archive
+pub enum ArchiveWriter<W: Write + Seek> {
+    TarGz(tar::Builder<GzEncoder<W>>),
+    Zip(zip::ZipWriter<W>),
+}
+
+impl<W: Write + Seek> ArchiveWriter<W> {
+    pub fn new(format: ArchiveFormat, writer: W) -> Self {
+        match format {
+            ArchiveFormat::TarGz => Self::TarGz(tar::Builder::new(GzEncoder::new(
+                writer,
+                Compression::default(),
+            ))),
+            ArchiveFormat::Zip => Self::Zip(zip::ZipWriter::new(writer)),
+        }
+    }
+
+    /// Add an entry to the archive; the content of files is read from `reader`
+    pub fn append(&mut self, entry: &ArchiveEntry, reader: &mut dyn Read) -> io::Result<()> {
+        let mode = entry.metadata.mode.map(u32::from);
+        match self {
+            Self::TarGz(builder) => {
+                let mut header = tar::Header::new_gnu();
+                if entry.is_dir() {
+                    header.set_entry_type(tar::EntryType::Directory);
+                    header.set_size(0);
+                    header.set_mode(mode.unwrap_or(0o755));
+                } else {
+                    header.set_entry_type(tar::EntryType::Regular);
+                    header.set_size(entry.metadata.size);
+                    header.set_mode(mode.unwrap_or(0o644));
+                }
+                if let Some(mtime) = entry
+                    .metadata
+                    .modified
+                    .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                {
+                    header.set_mtime(mtime.as_secs());
+                }
+                if entry.is_dir() {
+                    builder.append_data(&mut header, &entry.name, io::empty())
+                } else {
+                    builder.append_data(&mut header, &entry.name, reader)
+                }
+            }
+            Self::Zip(writer) => {
+                let mut options = SimpleFileOptions::default()
+                    .compression_method(zip::CompressionMethod::Deflated);
+                if let Some(mode) = mode {
+                    options = options.unix_permissions(mode);
+                }
+                if entry.is_dir() {
+                    writer
+                        .add_directory(entry.name.as_str(), options)
+                        .map_err(io::Error::other)
+                } else {
+                    writer
+                        .start_file(entry.name.as_str(), options)
+                        .map_err(io::Error::other)?;
+                    io::copy(reader, writer).map(|_| ())
+                }
+            }
+        }
+    }
+
+    /// Complete the archive and return the underlying writer
+    pub fn finish(self) -> io::Result<W> {
+        match self {
+            Self::TarGz(builder) => builder.into_inner()?.finish(),
+            Self::Zip(writer) => writer.finish().map_err(io::Error::other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::collections::HashMap;
+    use std::io::{Cursor, SeekFrom};
+
+    use pretty_assertions::assert_eq;
+    use remotefs::fs::FileType;
+
+    use super::*;
+
+    fn file(path: &str) -> File {
+        File {
+            path: PathBuf::from(path),
+            metadata: Metadata::default().size(4),
+        }
+    }
+
+    fn dir(path: &str) -> File {
+        File {
+            path: PathBuf::from(path),
+            metadata: Metadata::default().file_type(FileType::Directory),
+        }
+    }
+
+    fn names(entries: &[ArchiveEntry]) -> Vec<&str> {
+        entries.iter().map(|e| e.name.as_str()).collect()
+    }
+
+    #[test]
+    fn should_list_selected_files() {
+        let selected = [file("/home/omar/b.txt"), file("/home/omar/a.txt")];
+        let entries = archive_entries(Path::new("/home/omar"), &selected, |_| {
+            panic!("no directory to list")
+        })
+        .unwrap();
+        assert_eq!(names(&entries), vec!["a.txt", "b.txt"]);
+        assert_eq!(entries[0].path, PathBuf::from("/home/omar/a.txt"));
+    }
+
+    #[test]
+    fn should_list_selected_directories_recursively() {
+        let tree = HashMap::from([
+            (
+                PathBuf::from("/home/omar/docs"),
+                vec![
+                    file("/home/omar/docs/README.md"),
+                    dir("/home/omar/docs/img"),
+                ],
+            ),
+            (
+                PathBuf::from("/home/omar/docs/img"),
+                vec![file("/home/omar/docs/img/logo.png")],
+            ),
+        ]);
+        let selected = [dir("/home/omar/docs"), file("/home/omar/docs/README.md")];
+        let entries = archive_entries(Path::new("/home/omar"), &selected, |p| {
+            tree.get(p)
+                .cloned()
+                .ok_or_else(|| String::from("not found"))
+        })
+        .unwrap();
+        assert_eq!(
+            names(&entries),
+            vec!["docs", "docs/README.md", "docs/img", "docs/img/logo.png"]
+        );
+        assert!(entries[0].is_dir());
+    }
+
+    #[test]
+    fn should_skip_symlinks_and_fail_on_list_error() {
+        let link = File {
+            path: PathBuf::from("/home/omar/link"),
+            metadata: Metadata::default()
+                .file_type(FileType::Symlink)
+                .symlink("/etc/passwd"),
+        };
+        let entries = archive_entries(Path::new("/home/omar"), &[link], |_| {
+            panic!("no directory to list")
+        })
+        .unwrap();
+        assert!(entries.is_empty());
+        assert!(
+            archive_entries(Path::new("/home/omar"), &[dir("/home/omar/docs")], |_| Err(
+                String::from("permission denied")
+            ))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn should_name_entries_outside_of_wrkdir_by_file_name() {
+        let entries = archive_entries(Path::new("/home/omar"), &[file("/tmp/a.txt")], |_| {
+            Ok(vec![])
+        })
+        .unwrap();
+        assert_eq!(names(&entries), vec!["a.txt"]);
+    }
+
+    #[test]
+    fn should_get_archive_path() {
+        let wrkdir = Path::new("/home/omar");
+        assert_eq!(
+            archive_path(wrkdir, "backup", ArchiveFormat::TarGz, |_| false),
+            PathBuf::from("/home/omar/backup.tar.gz")
+        );
+        assert_eq!(
+            archive_path(wrkdir, "backup.zip", ArchiveFormat::Zip, |_| false),
+            PathBuf::from("/home/omar/backup.zip")
+        );
+    }
+
+    #[test]
+    fn should_get_archive_path_on_collision() {
+        let wrkdir = Path::new("/home/omar");
+        let taken = [
+            PathBuf::from("/home/omar/backup.zip"),
+            PathBuf::from("/home/omar/backup_1.zip"),
+        ];
+        assert_eq!(
+            archive_path(wrkdir, "backup.zip", ArchiveFormat::Zip, |p| taken
+                .iter()
+                .any(|t| t == p)),
+            PathBuf::from("/home/omar/backup_2.zip")
+        );
+    }
+
+    #[test]
+    fn should_detect_archive_format() {
+        assert_eq!(
+            ArchiveFormat::from_path(Path::new("/tmp/backup.tar.gz")),
+            Some(ArchiveFormat::TarGz)
+        );
+        assert_eq!(
+            ArchiveFormat::from_path(Path::new("/tmp/backup.TGZ")),
+            Some(ArchiveFormat::TarGz)
+        );
+        assert_eq!(
+            ArchiveFormat::from_path(Path::new("/tmp/backup.zip")),
+            Some(ArchiveFormat::Zip)
+        );
+        assert_eq!(ArchiveFormat::from_path(Path::new("/tmp/backup.tar")), None);
+        assert_eq!(ArchiveFormat::from_path(Path::new("/tmp/zip")), None);
+        assert_eq!(ArchiveFormat::from_path(Path::new("/")), None);
+    }
+
+    #[test]
+    fn should_get_extract_path() {
+        let dest = Path::new("/home/omar");
+        assert_eq!(
+            extract_path(dest, "docs/a.txt").unwrap(),
+            PathBuf::from("/home/omar/docs/a.txt")
+        );
+        assert_eq!(
+            extract_path(dest, "./docs/").unwrap(),
+            PathBuf::from("/home/omar/docs")
+        );
+        assert!(extract_path(dest, "../.bashrc").is_err());
+        assert!(extract_path(dest, "docs/../../.bashrc").is_err());
+        assert!(extract_path(dest, "/etc/passwd").is_err());
+        assert!(extract_path(dest, "./").is_err());
+    }
+
+    #[test]
+    fn should_write_archives() {
+        let entries = [
+            ArchiveEntry::new(Path::new("/home/omar"), &dir("/home/omar/docs")),
+            ArchiveEntry::new(Path::new("/home/omar"), &file("/home/omar/docs/a.txt")),
+        ];
+        for format in [ArchiveFormat::TarGz, ArchiveFormat::Zip] {
+            let mut writer = ArchiveWriter::new(format, Cursor::new(Vec::new()));
+            for entry in entries.iter() {
+                writer.append(entry, &mut "test".as_bytes()).unwrap();
+            }
+            let mut archive = writer.finish().unwrap();
+            archive.seek(SeekFrom::Start(0)).unwrap();
+            let mut extracted = Vec::new();
+            let count = read_archive(format, archive, |entry, reader| {
+                let mut content = String::new();
+                reader.read_to_string(&mut content).unwrap();
+                extracted.push((
+                    entry.name.trim_end_matches('/').to_string(),
+                    entry.dir,
+                    content,
+                ));
+                Ok(())
+            })
+            .unwrap();
+            assert_eq!(count, 2);
+            assert_eq!(
+                extracted,
+                vec![
+                    (String::from("docs"), true, String::new()),
+                    (String::from("docs/a.txt"), false, String::from("test")),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn should_fail_reading_invalid_archive() {
+        for format in [ArchiveFormat::TarGz, ArchiveFormat::Zip] {
+            let archive = Cursor::new(b"not an archive".to_vec());
+            assert!(read_archive(format, archive, |_, _| Ok(())).is_err());
+        }
+    }
+}
@@ -0,0 +1,302 @@
+//! ## Bisync
+//!
+//! `bisync` reconciles two directory trees against the baseline stored after the last sync (two-way sync)
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::mirror::MirrorEntry;
+use crate::config::sync::{SyncBaseline, SyncBaselineEntry};
+
+/// Describes the operations to perform to reconcile the local and the remote trees.
+/// All the paths are relative to the sync roots
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BisyncPlan {
+    /// Entries to copy from local to remote
+    pub upload: Vec<PathBuf>,
+    /// Entries to copy from remote to local
+    pub download: Vec<PathBuf>,
+    /// Entries removed from remote since the baseline, to remove from local
+    pub delete_local: Vec<PathBuf>,
+    /// Entries removed from local since the baseline, to remove from remote
+    pub delete_remote: Vec<PathBuf>,
+    /// Entries changed on both sides since the baseline
+    pub conflicts: Vec<PathBuf>,
+}
+
+impl BisyncPlan {
+    /// Reconcile `local` and `remote` against `baseline`.
+    ///
+    /// Entries contained in a directory which is already copied (or removed) as a whole are not reported
+    pub fn new(local: &[MirrorEntry], remote: &[MirrorEntry], baseline: &[MirrorEntry]) -> Self {
+        let local: HashMap<&Path, &MirrorEntry> =
+            local.iter().map(|x| (x.path.as_path(), x)).collect();
+        let remote: HashMap<&Path, &MirrorEntry> =
+            remote.iter().map(|x| (x.path.as_path(), x)).collect();
+        let baseline: HashMap<&Path, &MirrorEntry> =
+            baseline.iter().map(|x| (x.path.as_path(), x)).collect();
+        let paths: BTreeSet<&Path> = local.keys().chain(remote.keys()).copied().collect();
+
+        let mut plan = Self::default();
+        let mut whole_dirs: Vec<PathBuf> = Vec::new();
+        for path in paths {
+            if whole_dirs
+                .iter()
+                .any(|dir| path != dir && path.starts_with(dir))
+            {
+                continue;
+            }
+            let base = baseline.get(path).copied();
+            let list = match (local.get(path).copied(), remote.get(path).copied()) {
+                (Some(l), Some(r)) if !l.differs_from(r) => continue,
+                (Some(l), Some(r)) => match (Self::changed(l, base), Self::changed(r, base)) {
+                    (true, false) => &mut plan.upload,
+                    (false, true) => &mut plan.download,
+                    _ => &mut plan.conflicts,
+                },
+                (Some(_), None) => match base {
+                    None => &mut plan.upload,
+                    Some(_) if Self::subtree_changed(&local, &baseline, path) => {
+                        &mut plan.conflicts
+                    }
+                    Some(_) => &mut plan.delete_local,
+                },
+                (None, Some(_)) => match base {
+                    None => &mut plan.download,
+                    Some(_) if Self::subtree_changed(&remote, &baseline, path) => {
+                        &mut plan.conflicts
+                    }
+                    Some(_) => &mut plan.delete_remote,
+                },
+                (None, None) => continue,
+            };
+            list.push(path.to_path_buf());
+            let is_dir = local
+                .get(path)
+                .or(remote.get(path))
+                .is_some_and(|x| x.is_dir);
+            if is_dir {
+                whole_dirs.push(path.to_path_buf());
+            }
+        }
+        plan
+    }
+
+    /// Returns whether there's nothing to reconcile
+    pub fn is_empty(&self) -> bool {
+        self.upload.is_empty()
+            && self.download.is_empty()
+            && self.delete_local.is_empty()
+            && self.delete_remote.is_empty()
+            && self.conflicts.is_empty()
+    }
+
+    /// Returns whether the entry at `path` or any of its children in `tree` has changed since the baseline
+    fn subtree_changed(
+        tree: &HashMap<&Path, &MirrorEntry>,
+        baseline: &HashMap<&Path, &MirrorEntry>,
+        path: &Path,
+    ) -> bool {
+        tree.iter()
+            .filter(|(p, _)| p.starts_with(path))
+            .any(|(p, entry)| Self::changed(entry, baseline.get(p).copied()))
+    }
+
+    /// Returns whether `entry` has changed since the baseline
+    fn changed(entry: &MirrorEntry, baseline: Option<&MirrorEntry>) -> bool {
+        match baseline {
+            Some(base) => entry.differs_from(base),
+            None => true,
+        }
+    }
+}
+
+/// Get the baseline entries from the stored baseline
+pub fn baseline_entries(baseline: &SyncBaseline) -> Vec<MirrorEntry> {
+    baseline
+        .entries
+        .iter()
+        .map(|(path, entry)| MirrorEntry {
+            path: PathBuf::from(path),
+            is_dir: entry.is_dir,
+            size: entry.size,
+            mtime: entry
+                .mtime
+                .map(|secs| UNIX_EPOCH + Duration::from_secs(secs)),
+        })
+        .collect()
+}
+
+/// Make the baseline to store after a sync: it contains the entries which are in sync on both sides
+pub fn make_baseline(local: &[MirrorEntry], remote: &[MirrorEntry]) -> SyncBaseline {
+    let remote: HashMap<&Path, &MirrorEntry> =
+        remote.iter().map(|x| (x.path.as_path(), x)).collect();
+    let entries = local
+        .iter()
+        .filter(|l| {
+            remote
+                .get(l.path.as_path())
+                .is_some_and(|r| !l.differs_from(r))
+        })
+        .map(|l| {
+            (
+                l.path.to_string_lossy().to_string(),
+                SyncBaselineEntry {
+                    is_dir: l.is_dir,
+                    size: l.size,
+                    mtime: l
+                        .mtime
+                        .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+                        .map(|d| d.as_secs()),
+                },
+            )
+        })
+        .collect();
+    SyncBaseline { entries }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn file(path: &str, size: u64, mtime: u64) -> MirrorEntry {
+        MirrorEntry {
+            path: PathBuf::from(path),
+            is_dir: false,
+            size,
+            mtime: Some(UNIX_EPOCH + Duration::from_secs(mtime)),
+        }
+    }
+
+    fn dir(path: &str) -> MirrorEntry {
+        MirrorEntry {
+            path: PathBuf::from(path),
+            is_dir: true,
+            size: 4096,
+            mtime: None,
+        }
+    }
+
+    fn paths(p: &[&str]) -> Vec<PathBuf> {
+        p.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn should_copy_entries_changed_on_one_side() {
+        let baseline = vec![
+            dir("docs"),
+            file("docs/README.md", 1024, 100),
+            file("main.rs", 256, 100),
+        ];
+        let local = vec![
+            dir("docs"),
+            file("docs/README.md", 1024, 100),
+            file("main.rs", 300, 200),
+            file("lib.rs", 64, 200),
+        ];
+        let remote = vec![
+            dir("docs"),
+            file("docs/README.md", 2048, 300),
+            file("main.rs", 256, 100),
+            dir("assets"),
+            file("assets/logo.svg", 10, 300),
+        ];
+        let plan = BisyncPlan::new(&local, &remote, &baseline);
+        assert_eq!(plan.upload, paths(&["lib.rs", "main.rs"]));
+        assert_eq!(plan.download, paths(&["assets", "docs/README.md"]));
+        assert!(plan.delete_local.is_empty());
+        assert!(plan.delete_remote.is_empty());
+        assert!(plan.conflicts.is_empty());
+    }
+
+    #[test]
+    fn should_propagate_deletions() {
+        let baseline = vec![
+            file("main.rs", 256, 100),
+            file("lib.rs", 64, 100),
+            dir("target"),
+            file("target/termscp", 10, 100),
+        ];
+        let local = vec![file("lib.rs", 64, 100)];
+        let remote = vec![
+            file("main.rs", 256, 100),
+            dir("target"),
+            file("target/termscp", 10, 100),
+        ];
+        let plan = BisyncPlan::new(&local, &remote, &baseline);
+        assert!(plan.upload.is_empty());
+        assert!(plan.download.is_empty());
+        assert_eq!(plan.delete_local, paths(&["lib.rs"]));
+        assert_eq!(plan.delete_remote, paths(&["main.rs", "target"]));
+        assert!(plan.conflicts.is_empty());
+    }
+
+    #[test]
+    fn should_detect_conflicts() {
+        let baseline = vec![file("main.rs", 256, 100), file("lib.rs", 64, 100)];
+        // main.rs changed on both sides; lib.rs changed locally, removed on remote
+        let local = vec![file("main.rs", 300, 200), file("lib.rs", 65, 200)];
+        let remote = vec![file("main.rs", 310, 300)];
+        let plan = BisyncPlan::new(&local, &remote, &baseline);
+        assert!(plan.upload.is_empty());
+        assert!(plan.download.is_empty());
+        assert!(plan.delete_local.is_empty());
+        assert!(plan.delete_remote.is_empty());
+        assert_eq!(plan.conflicts, paths(&["lib.rs", "main.rs"]));
+    }
+
+    #[test]
+    fn should_not_delete_directories_with_changes() {
+        let baseline = vec![dir("docs"), file("docs/README.md", 1024, 100)];
+        let local = vec![];
+        let remote = vec![
+            dir("docs"),
+            file("docs/README.md", 1024, 100),
+            file("docs/CHANGELOG.md", 512, 200),
+        ];
+        let plan = BisyncPlan::new(&local, &remote, &baseline);
+        assert!(plan.delete_remote.is_empty());
+        assert_eq!(plan.conflicts, paths(&["docs"]));
+    }
+
+    #[test]
+    fn should_treat_different_new_entries_as_conflict_without_baseline() {
+        let local = vec![file("main.rs", 300, 200), file("lib.rs", 64, 100)];
+        let remote = vec![file("main.rs", 310, 300), file("lib.rs", 64, 100)];
+        let plan = BisyncPlan::new(&local, &remote, &[]);
+        assert_eq!(plan.conflicts, paths(&["main.rs"]));
+        assert_eq!(plan.is_empty(), false);
+        assert!(BisyncPlan::new(&remote[1..], &remote[1..], &[]).is_empty());
+    }
+
+    #[test]
+    fn should_convert_baseline() {
+        let local = vec![
+            dir("docs"),
+            file("docs/README.md", 1024, 100),
+            file("main.rs", 10, 100),
+        ];
+        let remote = vec![
+            dir("docs"),
+            file("docs/README.md", 1024, 100),
+            file("main.rs", 11, 100),
+        ];
+        let baseline = make_baseline(&local, &remote);
+        assert_eq!(baseline.entries.len(), 2);
+        assert_eq!(
+            baseline.entries.get("docs/README.md"),
+            Some(&SyncBaselineEntry {
+                is_dir: false,
+                size: 1024,
+                mtime: Some(100)
+            })
+        );
+        let mut entries = baseline_entries(&baseline);
+        entries.sort_by(|a, b| a.path.cmp(&b.path));
+        assert_eq!(entries, local[0..2].to_vec());
+    }
+}
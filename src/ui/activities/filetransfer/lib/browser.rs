@@ -2,16 +2,22 @@
 //!
 //! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use nucleo::Utf32String;
+use nucleo::{Utf32Str, Utf32String};
+use regex::RegexBuilder;
 use remotefs::File;
 
 use crate::explorer::builder::FileExplorerBuilder;
 use crate::explorer::{FileExplorer, FileSorting};
 use crate::system::config_client::ConfigClient;
+use crate::utils::locale::Locale;
 
 const FUZZY_SEARCH_THRESHOLD: u16 = 50;
+/// Bounds and step for the width of the host bridge panel, as a percentage
+const PANEL_SPLIT_RATIO_MIN: u16 = 20;
+const PANEL_SPLIT_RATIO_MAX: u16 = 80;
+const PANEL_SPLIT_RATIO_STEP: u16 = 5;
 
 /// File explorer tab
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -36,6 +42,11 @@ pub struct Browser {
     found: Option<Found>,      // File explorer for find result
     tab: FileExplorerTab,      // Current selected tab
     pub sync_browsing: bool,
+    log_visible: bool,     // Whether the log panel is displayed
+    footer_visible: bool,  // Whether the footer bar is displayed
+    preview_visible: bool, // Whether the preview pane is displayed
+    split_ratio: u16,      // Width of the host bridge panel as a percentage
+    maximized: bool,       // Whether the panel of the current tab takes the whole width
 }
 
 impl Browser {
@@ -47,6 +58,13 @@ impl Browser {
             found: None,
             tab: FileExplorerTab::HostBridge,
             sync_browsing: false,
+            log_visible: true,
+            footer_visible: !cli.get_hide_footer(),
+            preview_visible: false,
+            split_ratio: cli
+                .get_panel_split_ratio()
+                .clamp(PANEL_SPLIT_RATIO_MIN, PANEL_SPLIT_RATIO_MAX),
+            maximized: false,
         }
     }
 
@@ -108,6 +126,27 @@ impl Browser {
         }
     }
 
+    /// Returns the query of the last fuzzy search on found tab, if the results come from one
+    pub fn fuzzy_search_needle(&self) -> Option<&str> {
+        self.found.as_ref().and_then(|x| x.needle.as_deref())
+    }
+
+    /// Perform regex search on found tab
+    pub fn regex_search(&mut self, pattern: &str) -> Result<(), regex::Error> {
+        match self.found.as_mut() {
+            Some(x) => x.regex_search(pattern),
+            None => Ok(()),
+        }
+    }
+
+    /// Toggle the case sensitivity of the regex search on found tab, then repeat the last search
+    pub fn toggle_find_case_sensitive(&mut self) -> Result<(), regex::Error> {
+        match self.found.as_mut() {
+            Some(x) => x.toggle_case_sensitive(),
+            None => Ok(()),
+        }
+    }
+
     /// Initialize fuzzy search
     pub fn init_fuzzy_search(&mut self) {
         if let Some(explorer) = self.found_mut() {
@@ -122,6 +161,10 @@ impl Browser {
             tab,
             explorer,
             search_results: files,
+            wrkdir: wrkdir.to_path_buf(),
+            needle: None,
+            regex: None,
+            case_sensitive: false,
         });
     }
 
@@ -148,6 +191,113 @@ impl Browser {
         self.sync_browsing = !self.sync_browsing;
     }
 
+    /// Returns whether the log panel is visible
+    pub fn log_visible(&self) -> bool {
+        self.log_visible
+    }
+
+    /// Show or hide the log panel
+    pub fn toggle_log(&mut self) {
+        self.log_visible = !self.log_visible;
+    }
+
+    /// Returns whether sizes are displayed as the exact count of bytes
+    pub fn raw_sizes(&self) -> bool {
+        self.host_bridge.raw_sizes()
+    }
+
+    /// Switch sizes of both explorers between human readable and the exact count of bytes
+    pub fn toggle_raw_sizes(&mut self) {
+        self.host_bridge.toggle_raw_sizes();
+        self.remote.toggle_raw_sizes();
+    }
+
+    /// Returns whether the footer bar is visible
+    pub fn footer_visible(&self) -> bool {
+        self.footer_visible
+    }
+
+    /// Show or hide the footer bar
+    pub fn toggle_footer(&mut self) {
+        self.footer_visible = !self.footer_visible;
+    }
+
+    /// Returns whether the preview pane is visible
+    pub fn preview_visible(&self) -> bool {
+        self.preview_visible
+    }
+
+    /// Show or hide the preview pane
+    pub fn toggle_preview(&mut self) {
+        self.preview_visible = !self.preview_visible;
+    }
+
+    /// Returns the height of the footer bar; when hidden the row is given to the explorers
+    pub fn footer_height(&self) -> u16 {
+        u16::from(self.footer_visible)
+    }
+
+    /// Returns the width of the host bridge panel as a percentage
+    pub fn split_ratio(&self) -> u16 {
+        self.split_ratio
+    }
+
+    /// Grow the panel of the current tab
+    pub fn grow_panel(&mut self) {
+        match self.tab {
+            FileExplorerTab::HostBridge | FileExplorerTab::FindHostBridge => {
+                self.move_split(PANEL_SPLIT_RATIO_STEP as i16)
+            }
+            FileExplorerTab::Remote | FileExplorerTab::FindRemote => {
+                self.move_split(-(PANEL_SPLIT_RATIO_STEP as i16))
+            }
+        }
+    }
+
+    /// Shrink the panel of the current tab
+    pub fn shrink_panel(&mut self) {
+        match self.tab {
+            FileExplorerTab::HostBridge | FileExplorerTab::FindHostBridge => {
+                self.move_split(-(PANEL_SPLIT_RATIO_STEP as i16))
+            }
+            FileExplorerTab::Remote | FileExplorerTab::FindRemote => {
+                self.move_split(PANEL_SPLIT_RATIO_STEP as i16)
+            }
+        }
+    }
+
+    /// Maximize the panel of the current tab or restore the split layout
+    pub fn toggle_maximize(&mut self) {
+        self.maximized = !self.maximized;
+    }
+
+    /// Returns the width, as a percentage, of the host bridge and of the remote panels.
+    /// When maximized, the panel of the current tab takes the whole width
+    pub fn panel_widths(&self) -> (u16, u16) {
+        match (self.maximized, self.tab) {
+            (true, FileExplorerTab::HostBridge | FileExplorerTab::FindHostBridge) => (100, 0),
+            (true, FileExplorerTab::Remote | FileExplorerTab::FindRemote) => (0, 100),
+            (false, _) => (self.split_ratio, 100 - self.split_ratio),
+        }
+    }
+
+    fn move_split(&mut self, delta: i16) {
+        self.split_ratio = (self.split_ratio as i16 + delta)
+            .clamp(PANEL_SPLIT_RATIO_MIN as i16, PANEL_SPLIT_RATIO_MAX as i16)
+            as u16;
+    }
+
+    /// Returns the working directory of the other panel, which the panel of the current tab can go to.
+    /// Returns `None` if both panels are already in the same directory or if the current tab is a find result
+    pub fn other_panel_dir(&self) -> Option<&Path> {
+        let (current, other) = match self.tab {
+            FileExplorerTab::HostBridge => (&self.host_bridge, &self.remote),
+            FileExplorerTab::Remote => (&self.remote, &self.host_bridge),
+            FileExplorerTab::FindHostBridge | FileExplorerTab::FindRemote => return None,
+        };
+        (current.wrkdir != other.wrkdir).then_some(other.wrkdir.as_path())
+    }
+
     /// Toggle terminal for the current tab
     pub fn toggle_terminal(&mut self, terminal: bool) {
         if self.tab == FileExplorerTab::HostBridge {
@@ -170,14 +320,24 @@ impl Browser {
     /// Build a file explorer with local host setup
     pub fn build_local_explorer(cli: &ConfigClient) -> FileExplorer {
         let mut builder = Self::build_explorer(cli);
-        builder.with_formatter(cli.get_local_file_fmt().as_deref());
+        builder
+            .with_formatter(cli.get_local_file_fmt().as_deref())
+            .with_locale(cli.get_locale().and_then(|l| Locale::from_name(&l)))
+            .with_date_fmt(cli.get_date_fmt().as_deref())
+            .with_raw_sizes(cli.get_raw_file_sizes())
+            .with_size_units(cli.get_size_units());
         builder.build()
     }
 
     /// Build a file explorer with remote host setup
     pub fn build_remote_explorer(cli: &ConfigClient) -> FileExplorer {
         let mut builder = Self::build_explorer(cli);
-        builder.with_formatter(cli.get_remote_file_fmt().as_deref());
+        builder
+            .with_formatter(cli.get_remote_file_fmt().as_deref())
+            .with_locale(cli.get_locale().and_then(|l| Locale::from_name(&l)))
+            .with_date_fmt(cli.get_date_fmt().as_deref())
+            .with_raw_sizes(cli.get_raw_file_sizes())
+            .with_size_units(cli.get_size_units());
         builder.build()
     }
 
@@ -186,10 +346,12 @@ impl Browser {
         let mut builder: FileExplorerBuilder = FileExplorerBuilder::new();
         // Set common keys
         builder
+            .with_case_sensitive_sorting(cli.get_case_sensitive_sorting())
             .with_file_sorting(FileSorting::Name)
             .with_stack_size(16)
             .with_group_dirs(cli.get_group_dirs())
-            .with_hidden_files(cli.get_show_hidden_files());
+            .with_hidden_files(cli.get_show_hidden_files())
+            .with_hidden_patterns(&cli.get_hidden_patterns());
         builder
     }
 
@@ -207,12 +369,51 @@ impl Browser {
     }
 }
 
+/// Highlights the chars matching a fuzzy search
+pub struct FuzzyHighlighter {
+    matcher: nucleo::Matcher,
+    needle: Utf32String,
+}
+
+impl FuzzyHighlighter {
+    pub fn new(needle: &str) -> Self {
+        Self {
+            matcher: nucleo::Matcher::new(nucleo::Config::DEFAULT.match_paths()),
+            needle: Utf32String::from(needle),
+        }
+    }
+
+    /// Get the indexes of the chars of `haystack` matching the search, in ascending order.
+    ///
+    /// Indexes count chars rather than bytes, so they can be used with multibyte names
+    pub fn match_indices(&mut self, haystack: &str) -> Vec<usize> {
+        // `Utf32String` would merge grapheme clusters; keep one item per char
+        let chars: Vec<char> = haystack.chars().collect();
+        let mut indices = Vec::new();
+        self.matcher.fuzzy_indices(
+            Utf32Str::Unicode(&chars),
+            self.needle.slice(..),
+            &mut indices,
+        );
+        indices.sort_unstable();
+        indices.dedup();
+        indices.into_iter().map(|x| x as usize).collect()
+    }
+}
+
 /// Found state
 struct Found {
     explorer: FileExplorer,
     /// Search results; original copy of files
     search_results: Vec<File>,
     tab: FoundExplorerTab,
+    /// Directory the search has been performed at
+    wrkdir: PathBuf,
+    /// Last fuzzy search
+    needle: Option<String>,
+    /// Last regex searched
+    regex: Option<String>,
+    case_sensitive: bool,
 }
 
 impl Found {
@@ -220,6 +421,8 @@ impl Found {
     pub fn fuzzy_search(&mut self, needle: &str) {
         let search = Utf32String::from(needle);
         let mut nucleo = nucleo::Matcher::new(nucleo::Config::DEFAULT.match_paths());
+        self.needle = Some(needle.to_string());
+        self.regex = None;
 
         // get scores
         let mut fuzzy_results_with_score = self
@@ -250,4 +453,248 @@ impl Found {
                 .collect(),
         );
     }
+
+    /// Regex search from `search_results` and update `explorer.files` with the files whose path,
+    /// relative to the search directory, matches `pattern`. Results keep the original order.
+    ///
+    /// If `pattern` is not a valid regex, the results are left untouched
+    pub fn regex_search(&mut self, pattern: &str) -> Result<(), regex::Error> {
+        let regex = RegexBuilder::new(pattern)
+            .case_insensitive(!self.case_sensitive)
+            .build()?;
+        self.regex = Some(pattern.to_string());
+        self.needle = None;
+        let files = self
+            .search_results
+            .iter()
+            .filter(|f| {
+                let path = f.path().strip_prefix(&self.wrkdir).unwrap_or(f.path());
+                regex.is_match(&path.to_string_lossy())
+            })
+            .cloned()
+            .collect();
+        self.explorer.set_files(files);
+        Ok(())
+    }
+
+    /// Toggle the case sensitivity of the regex search, then repeat the last search if any
+    pub fn toggle_case_sensitive(&mut self) -> Result<(), regex::Error> {
+        self.case_sensitive = !self.case_sensitive;
+        match self.regex.clone() {
+            Some(pattern) => self.regex_search(&pattern),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::path::PathBuf;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_toggle_log_visibility() {
+        let mut browser = Browser::new(&ConfigClient::degraded());
+        assert_eq!(browser.log_visible(), true);
+        browser.toggle_log();
+        assert_eq!(browser.log_visible(), false);
+        browser.toggle_log();
+        assert_eq!(browser.log_visible(), true);
+    }
+
+    #[test]
+    fn should_toggle_footer_visibility() {
+        let mut browser = Browser::new(&ConfigClient::degraded());
+        assert_eq!(browser.footer_visible(), true);
+        assert_eq!(browser.footer_height(), 1);
+        browser.toggle_footer();
+        assert_eq!(browser.footer_visible(), false);
+        assert_eq!(browser.footer_height(), 0);
+        browser.toggle_footer();
+        assert_eq!(browser.footer_visible(), true);
+        assert_eq!(browser.footer_height(), 1);
+    }
+
+    #[test]
+    fn should_toggle_preview_visibility() {
+        let mut browser = Browser::new(&ConfigClient::degraded());
+        assert_eq!(browser.preview_visible(), false);
+        browser.toggle_preview();
+        assert_eq!(browser.preview_visible(), true);
+        browser.toggle_preview();
+        assert_eq!(browser.preview_visible(), false);
+    }
+
+    #[test]
+    fn should_hide_footer_from_config() {
+        let mut config = ConfigClient::degraded();
+        config.set_hide_footer(true);
+        let browser = Browser::new(&config);
+        assert_eq!(browser.footer_visible(), false);
+        assert_eq!(browser.footer_height(), 0);
+    }
+
+    #[test]
+    fn should_adjust_split_ratio_within_bounds() {
+        let mut browser = Browser::new(&ConfigClient::degraded());
+        assert_eq!(browser.split_ratio(), 50);
+        browser.grow_panel();
+        assert_eq!(browser.split_ratio(), 55);
+        browser.change_tab(FileExplorerTab::Remote);
+        browser.grow_panel();
+        browser.grow_panel();
+        assert_eq!(browser.split_ratio(), 45);
+        for _ in 0..20 {
+            browser.grow_panel();
+        }
+        assert_eq!(browser.split_ratio(), PANEL_SPLIT_RATIO_MIN);
+        for _ in 0..20 {
+            browser.shrink_panel();
+        }
+        assert_eq!(browser.split_ratio(), PANEL_SPLIT_RATIO_MAX);
+    }
+
+    #[test]
+    fn should_toggle_maximized_panel() {
+        let mut browser = Browser::new(&ConfigClient::degraded());
+        assert_eq!(browser.panel_widths(), (50, 50));
+        browser.toggle_maximize();
+        assert_eq!(browser.panel_widths(), (100, 0));
+        browser.change_tab(FileExplorerTab::Remote);
+        assert_eq!(browser.panel_widths(), (0, 100));
+        browser.toggle_maximize();
+        assert_eq!(browser.panel_widths(), (50, 50));
+    }
+
+    #[test]
+    fn should_keep_hidden_panel_path_when_maximized() {
+        let mut browser = Browser::new(&ConfigClient::degraded());
+        browser.remote_mut().wrkdir = PathBuf::from("/home/omar/docs");
+        browser.toggle_maximize();
+        assert_eq!(browser.panel_widths(), (100, 0));
+        // Transfers from the maximized panel still target the hidden panel's directory
+        assert_eq!(
+            browser.other_explorer_no_found().wrkdir.as_path(),
+            Path::new("/home/omar/docs")
+        );
+    }
+
+    fn found_browser() -> Browser {
+        let mut browser = Browser::new(&ConfigClient::degraded());
+        let files = [
+            "/home/omar/src/main.rs",
+            "/home/omar/src/Lib.rs",
+            "/home/omar/README.md",
+        ]
+        .iter()
+        .map(|path| File {
+            path: PathBuf::from(path),
+            metadata: Default::default(),
+        })
+        .collect();
+        browser.set_found(FoundExplorerTab::Local, files, Path::new("/home/omar"));
+        browser
+    }
+
+    fn found_paths(browser: &Browser) -> Vec<&Path> {
+        browser
+            .found()
+            .unwrap()
+            .iter_files()
+            .map(|x| x.path())
+            .collect()
+    }
+
+    #[test]
+    fn should_get_fuzzy_match_indices() {
+        let mut highlighter = FuzzyHighlighter::new("mrs");
+        assert_eq!(highlighter.match_indices("src/main.rs"), vec![4, 9, 10]);
+        assert!(highlighter.match_indices("README.md").is_empty());
+        // Indexes count chars
+        let mut highlighter = FuzzyHighlighter::new("ax");
+        assert_eq!(highlighter.match_indices("café/ñandú.txt"), vec![6, 12]);
+    }
+
+    #[test]
+    fn should_keep_fuzzy_search_needle() {
+        let mut browser = found_browser();
+        assert_eq!(browser.fuzzy_search_needle(), None);
+        browser.fuzzy_search("main");
+        assert_eq!(browser.fuzzy_search_needle(), Some("main"));
+        assert!(browser.regex_search("main").is_ok());
+        assert_eq!(browser.fuzzy_search_needle(), None);
+    }
+
+    #[test]
+    fn should_find_with_regex() {
+        let mut browser = found_browser();
+        assert!(browser.regex_search(r"^src/.+\.rs$").is_ok());
+        assert_eq!(
+            found_paths(&browser),
+            vec![
+                Path::new("/home/omar/src/main.rs"),
+                Path::new("/home/omar/src/Lib.rs")
+            ]
+        );
+        // Case insensitive by default
+        assert!(browser.regex_search("lib|readme").is_ok());
+        assert_eq!(
+            found_paths(&browser),
+            vec![
+                Path::new("/home/omar/src/Lib.rs"),
+                Path::new("/home/omar/README.md")
+            ]
+        );
+        // The last search is repeated
+        assert!(browser.toggle_find_case_sensitive().is_ok());
+        assert!(found_paths(&browser).is_empty());
+        assert!(browser.toggle_find_case_sensitive().is_ok());
+        assert_eq!(found_paths(&browser).len(), 2);
+    }
+
+    #[test]
+    fn should_not_find_with_invalid_regex() {
+        let mut browser = found_browser();
+        assert!(browser.regex_search("main").is_ok());
+        assert!(browser.regex_search("src/(main").is_err());
+        // Results are left untouched
+        assert_eq!(
+            found_paths(&browser),
+            vec![Path::new("/home/omar/src/main.rs")]
+        );
+        assert!(browser.toggle_find_case_sensitive().is_ok());
+    }
+
+    #[test]
+    fn should_get_other_panel_dir() {
+        let mut browser = Browser::new(&ConfigClient::degraded());
+        browser.host_bridge_mut().wrkdir = PathBuf::from("/home/omar");
+        browser.remote_mut().wrkdir = PathBuf::from("/srv/www");
+        assert_eq!(browser.other_panel_dir(), Some(Path::new("/srv/www")));
+        browser.change_tab(FileExplorerTab::Remote);
+        assert_eq!(browser.other_panel_dir(), Some(Path::new("/home/omar")));
+        browser.change_tab(FileExplorerTab::FindRemote);
+        assert_eq!(browser.other_panel_dir(), None);
+    }
+
+    #[test]
+    fn should_not_get_other_panel_dir_if_same() {
+        let mut browser = Browser::new(&ConfigClient::degraded());
+        browser.host_bridge_mut().wrkdir = PathBuf::from("/srv/www");
+        browser.remote_mut().wrkdir = PathBuf::from("/srv/www");
+        assert_eq!(browser.other_panel_dir(), None);
+        browser.change_tab(FileExplorerTab::Remote);
+        assert_eq!(browser.other_panel_dir(), None);
+    }
+
+    #[test]
+    fn should_clamp_configured_split_ratio() {
+        let mut config = ConfigClient::degraded();
+        config.set_panel_split_ratio(95);
+        assert_eq!(Browser::new(&config).split_ratio(), PANEL_SPLIT_RATIO_MAX);
+    }
 }
@@ -0,0 +1,123 @@
+//! ## Chmod
+//!
+//! `chmod` decides which entries a recursive change of mode applies to, and the mode set on each of them
+
+use remotefs::File;
+use remotefs::fs::UnixPex;
+
+/// Execute permissions of all the classes
+const EXECUTE_BITS: u32 = 0o111;
+
+/// How the execute permissions of the mode are applied by a recursive chmod
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecuteMode {
+    /// Like `chmod -R u+x`: the execute permissions are set on all the entries
+    All,
+    /// Like `chmod -R u+X`: the execute permissions are only set on directories and on files
+    /// which are already executable by someone
+    DirsAndExecutables,
+}
+
+/// Returns whether a recursive chmod on `entry` must be applied to its descendants too.
+/// Only directories are walked; symlinks are never followed
+pub fn should_recurse(entry: &File) -> bool {
+    entry.is_dir() && !entry.is_symlink()
+}
+
+/// Get the mode to set on `entry` for the requested `mode`, according to `execute`
+pub fn entry_mode(mode: UnixPex, entry: &File, execute: ExecuteMode) -> UnixPex {
+    let is_executable = entry
+        .metadata()
+        .mode
+        .is_some_and(|current| u32::from(current) & EXECUTE_BITS != 0);
+    match execute {
+        ExecuteMode::DirsAndExecutables if !entry.is_dir() && !is_executable => {
+            UnixPex::from(u32::from(mode) & !EXECUTE_BITS)
+        }
+        ExecuteMode::All | ExecuteMode::DirsAndExecutables => mode,
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::path::PathBuf;
+
+    use pretty_assertions::assert_eq;
+    use remotefs::fs::{FileType, Metadata};
+
+    use super::*;
+
+    fn file(name: &str, file_type: FileType, mode: Option<u32>) -> File {
+        File {
+            path: PathBuf::from(name),
+            metadata: Metadata {
+                file_type,
+                mode: mode.map(UnixPex::from),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn should_only_recurse_into_directories() {
+        let dir = file("src", FileType::Directory, Some(0o755));
+        let regular = file("main.rs", FileType::File, Some(0o644));
+        let symlink = file("link", FileType::Symlink, Some(0o777));
+        assert!(should_recurse(&dir));
+        assert!(!should_recurse(&regular));
+        assert!(!should_recurse(&symlink));
+    }
+
+    #[test]
+    fn should_set_execute_on_all_entries() {
+        let mode = UnixPex::from(0o755);
+        for entry in [
+            file("src", FileType::Directory, Some(0o700)),
+            file("main.rs", FileType::File, Some(0o644)),
+            file("run.sh", FileType::File, Some(0o744)),
+        ] {
+            assert_eq!(u32::from(entry_mode(mode, &entry, ExecuteMode::All)), 0o755);
+        }
+    }
+
+    #[test]
+    fn should_set_execute_on_dirs_and_executables() {
+        let mode = UnixPex::from(0o755);
+        let exec = ExecuteMode::DirsAndExecutables;
+        assert_eq!(
+            u32::from(entry_mode(
+                mode,
+                &file("src", FileType::Directory, Some(0o700)),
+                exec
+            )),
+            0o755
+        );
+        // executable by anyone
+        assert_eq!(
+            u32::from(entry_mode(
+                mode,
+                &file("run.sh", FileType::File, Some(0o641)),
+                exec
+            )),
+            0o755
+        );
+        // not executable
+        assert_eq!(
+            u32::from(entry_mode(
+                mode,
+                &file("main.rs", FileType::File, Some(0o644)),
+                exec
+            )),
+            0o644
+        );
+        assert_eq!(
+            u32::from(entry_mode(
+                mode,
+                &file("main.rs", FileType::File, None),
+                exec
+            )),
+            0o644
+        );
+    }
+}
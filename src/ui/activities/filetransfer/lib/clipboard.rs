@@ -0,0 +1,127 @@
+//! ## Clipboard
+//!
+//! `clipboard` holds the files cut from an explorer, until they're pasted into another directory
+
+use std::path::{Path, PathBuf};
+
+use remotefs::fs::File;
+
+/// Explorer the files have been cut from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipboardSide {
+    HostBridge,
+    Remote,
+}
+
+/// Files cut from an explorer, waiting to be moved
+#[derive(Debug, Clone)]
+pub struct Clipboard {
+    side: ClipboardSide,
+    files: Vec<File>,
+}
+
+impl Clipboard {
+    pub fn new(side: ClipboardSide, files: Vec<File>) -> Self {
+        Self { side, files }
+    }
+
+    /// Explorer the files have been cut from
+    pub fn side(&self) -> ClipboardSide {
+        self.side
+    }
+
+    /// Get the files to move into `dest`, on the `side` explorer, with the directory to move them into.
+    ///
+    /// When pasting on the side the files have been cut from, files which are already in `dest`
+    /// and directories which would be moved into themselves are skipped
+    pub fn files_to_paste(&self, side: ClipboardSide, dest: &Path) -> Vec<(File, PathBuf)> {
+        let same_side = side == self.side;
+        self.files
+            .iter()
+            .filter(|x| {
+                !same_side
+                    || (x.path().parent() != Some(dest)
+                        && !(x.is_dir() && dest.starts_with(x.path())))
+            })
+            .map(|x| (x.clone(), dest.to_path_buf()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+    use remotefs::fs::{FileType, Metadata};
+
+    use super::*;
+
+    fn file(path: &str, is_dir: bool) -> File {
+        File {
+            path: PathBuf::from(path),
+            metadata: Metadata {
+                file_type: if is_dir {
+                    FileType::Directory
+                } else {
+                    FileType::File
+                },
+                ..Default::default()
+            },
+        }
+    }
+
+    fn clipboard() -> Clipboard {
+        Clipboard::new(
+            ClipboardSide::Remote,
+            vec![
+                file("/home/omar/a.txt", false),
+                file("/home/omar/docs", true),
+            ],
+        )
+    }
+
+    #[test]
+    fn should_get_files_to_paste() {
+        assert_eq!(clipboard().side(), ClipboardSide::Remote);
+        let files = clipboard().files_to_paste(ClipboardSide::Remote, Path::new("/tmp"));
+        assert_eq!(
+            files
+                .iter()
+                .map(|(file, dest)| (file.path(), dest.as_path()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Path::new("/home/omar/a.txt"), Path::new("/tmp")),
+                (Path::new("/home/omar/docs"), Path::new("/tmp")),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_skip_files_already_in_destination() {
+        assert!(
+            clipboard()
+                .files_to_paste(ClipboardSide::Remote, Path::new("/home/omar"))
+                .is_empty()
+        );
+        // On the other side, these are different directories
+        assert_eq!(
+            clipboard()
+                .files_to_paste(ClipboardSide::HostBridge, Path::new("/home/omar"))
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn should_not_paste_directory_into_itself() {
+        let files =
+            clipboard().files_to_paste(ClipboardSide::Remote, Path::new("/home/omar/docs/drafts"));
+        assert_eq!(
+            files
+                .iter()
+                .map(|(file, _)| file.path())
+                .collect::<Vec<_>>(),
+            vec![Path::new("/home/omar/a.txt")]
+        );
+    }
+}
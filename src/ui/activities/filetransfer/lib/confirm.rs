@@ -0,0 +1,83 @@
+//! ## Confirm
+//!
+//! `confirm` provides the timeout which auto-selects the safe option of a confirmation popup
+
+use std::time::{Duration, Instant};
+
+/// Timeout for confirmation popups: once elapsed, the safe (non-destructive) message is fired
+pub struct ConfirmTimeout<T> {
+    /// Timeout configured by the user; if `None`, popups wait indefinitely
+    timeout: Option<Duration>,
+    /// Deadline and safe message of the mounted popup
+    pending: Option<(Instant, T)>,
+}
+
+impl<T> ConfirmTimeout<T> {
+    pub fn new(timeout: Option<Duration>) -> Self {
+        Self {
+            timeout,
+            pending: None,
+        }
+    }
+
+    /// Start the timeout for a popup; `safe` is fired once it elapses.
+    /// Does nothing if the timeout is not configured
+    pub fn start(&mut self, safe: T) {
+        self.pending = self.timeout.map(|timeout| (Instant::now() + timeout, safe));
+    }
+
+    /// Cancel the pending timeout (e.g. the user interacted with the popup)
+    pub fn cancel(&mut self) {
+        self.pending = None;
+    }
+
+    /// Returns the safe message if the timeout has elapsed; the timeout is then cleared
+    pub fn poll(&mut self) -> Option<T> {
+        match self.pending.as_ref() {
+            Some((deadline, _)) if Instant::now() >= *deadline => {
+                self.pending.take().map(|(_, safe)| safe)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_fire_safe_option_when_timeout_elapses() {
+        let mut timeout = ConfirmTimeout::new(Some(Duration::ZERO));
+        assert_eq!(timeout.poll(), None);
+        timeout.start("No");
+        assert_eq!(timeout.poll(), Some("No"));
+        // Fired only once
+        assert_eq!(timeout.poll(), None);
+    }
+
+    #[test]
+    fn should_not_fire_before_timeout() {
+        let mut timeout = ConfirmTimeout::new(Some(Duration::from_secs(60)));
+        timeout.start("No");
+        assert_eq!(timeout.poll(), None);
+    }
+
+    #[test]
+    fn should_cancel_timeout_on_interaction() {
+        let mut timeout = ConfirmTimeout::new(Some(Duration::ZERO));
+        timeout.start("No");
+        timeout.cancel();
+        assert_eq!(timeout.poll(), None);
+    }
+
+    #[test]
+    fn should_not_start_timeout_if_disabled() {
+        let mut timeout = ConfirmTimeout::new(None);
+        timeout.start("No");
+        assert_eq!(timeout.poll(), None);
+    }
+}
@@ -0,0 +1,108 @@
+//! ## Connect error
+//!
+//! `connect_error` decides what to do when the connection to the remote fails
+
+use remotefs::{RemoteError, RemoteErrorType};
+
+use crate::filetransfer::FileTransferParams;
+use crate::filetransfer::params::ProtocolParams;
+
+/// Where to go after the connection to the remote has failed
+#[derive(Debug)]
+pub enum ConnectErrorRoute {
+    /// Go back to the authentication form, prefilled with these params, to enter the credentials again
+    RetryAuth(Box<FileTransferParams>),
+    /// Show the fatal error popup with this message
+    Fatal(String),
+}
+
+/// Get the route for the connection error `err` of the connection made with `params`.
+///
+/// Only authentication failures lead back to the authentication form; the password is never prefilled
+pub fn connect_error_route(err: &RemoteError, params: &FileTransferParams) -> ConnectErrorRoute {
+    match err.kind {
+        RemoteErrorType::AuthenticationFailed => {
+            ConnectErrorRoute::RetryAuth(Box::new(params_without_password(params)))
+        }
+        _ => ConnectErrorRoute::Fatal(err.to_string()),
+    }
+}
+
+/// Get a copy of `params` with the secret cleared
+fn params_without_password(params: &FileTransferParams) -> FileTransferParams {
+    let mut params = params.clone();
+    match &mut params.params {
+        ProtocolParams::AwsS3(params) => params.secret_access_key = None,
+        ProtocolParams::Generic(params) => params.password = None,
+        ProtocolParams::Kube(_) => {}
+        ProtocolParams::Smb(params) => params.password = None,
+        ProtocolParams::WebDAV(params) => params.password = String::new(),
+    }
+    params
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::path::PathBuf;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::filetransfer::FileTransferProtocol;
+    use crate::filetransfer::params::{GenericProtocolParams, WebDAVProtocolParams};
+
+    fn sftp_params() -> FileTransferParams {
+        FileTransferParams::new(
+            FileTransferProtocol::Sftp,
+            ProtocolParams::Generic(
+                GenericProtocolParams::default()
+                    .address("192.168.1.31")
+                    .port(2222)
+                    .username(Some("pi"))
+                    .password(Some("secret")),
+            ),
+        )
+        .remote_path(Some("/home/pi"))
+    }
+
+    #[test]
+    fn should_route_auth_error_to_prefilled_form() {
+        let err = RemoteError::new_ex(RemoteErrorType::AuthenticationFailed, "bad password");
+        let ConnectErrorRoute::RetryAuth(params) = connect_error_route(&err, &sftp_params()) else {
+            panic!("expected retry auth route");
+        };
+        assert_eq!(params.protocol, FileTransferProtocol::Sftp);
+        assert_eq!(params.remote_path, Some(PathBuf::from("/home/pi")));
+        let generic = params.params.generic_params().unwrap();
+        assert_eq!(generic.address, "192.168.1.31");
+        assert_eq!(generic.port, 2222);
+        assert_eq!(generic.username.as_deref(), Some("pi"));
+        assert_eq!(generic.password, None);
+    }
+
+    #[test]
+    fn should_route_network_error_to_fatal_popup() {
+        let err = RemoteError::new_ex(RemoteErrorType::ConnectionError, "connection refused");
+        match connect_error_route(&err, &sftp_params()) {
+            ConnectErrorRoute::Fatal(msg) => assert_eq!(msg, err.to_string()),
+            route => panic!("expected fatal route; got {route:?}"),
+        }
+    }
+
+    #[test]
+    fn should_clear_webdav_password() {
+        let params = FileTransferParams::new(
+            FileTransferProtocol::WebDAV,
+            ProtocolParams::WebDAV(WebDAVProtocolParams {
+                uri: "http://localhost".to_string(),
+                username: "user".to_string(),
+                password: "pass".to_string(),
+            }),
+        );
+        let params = params_without_password(&params);
+        let webdav = params.params.webdav_params().unwrap();
+        assert_eq!(webdav.username, "user");
+        assert!(webdav.password.is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+//! ## Duplicate
+//!
+//! `duplicate` proposes the name to duplicate a file with, in its own directory
+
+use std::path::{Path, PathBuf};
+
+/// Split the extension out of the file name; hidden files without any other dot have no extension
+fn split_extension(name: &str) -> (&str, Option<&str>) {
+    match name.rfind('.') {
+        Some(idx) if idx > 0 => (&name[..idx], Some(&name[idx + 1..])),
+        _ => (name, None),
+    }
+}
+
+/// Get the name to duplicate the file named `name` with, as `name (copy).ext`.
+/// `n` is the index of the copy: after the first one, it's added to the suffix, as `name (copy n).ext`
+pub fn duplicate_name(name: &str, is_dir: bool, n: usize) -> String {
+    let suffix = match n {
+        0 | 1 => String::from("copy"),
+        n => format!("copy {n}"),
+    };
+    match split_extension(name) {
+        (stem, Some(extension)) if !is_dir => format!("{stem} ({suffix}).{extension}"),
+        _ => format!("{name} ({suffix})"),
+    }
+}
+
+/// Get the first name to duplicate the file named `name` in `dir` with, which doesn't exist yet according to `exists`
+pub fn available_duplicate_name<F>(dir: &Path, name: &str, is_dir: bool, mut exists: F) -> String
+where
+    F: FnMut(&Path) -> bool,
+{
+    (1..)
+        .map(|n| duplicate_name(name, is_dir, n))
+        .find(|name| !exists(dir.join(name).as_path()))
+        .unwrap_or_else(|| duplicate_name(name, is_dir, 1))
+}
+
+/// Get the path to duplicate the file at `path` to, with the name `name`, in the same directory
+pub fn duplicate_path(path: &Path, name: &str) -> PathBuf {
+    path.parent()
+        .map(|dir| dir.join(name))
+        .unwrap_or_else(|| PathBuf::from(name))
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_get_duplicate_name() {
+        assert_eq!(duplicate_name("README.md", false, 1), "README (copy).md");
+        assert_eq!(duplicate_name("README.md", false, 3), "README (copy 3).md");
+        assert_eq!(
+            duplicate_name("backup.tar.gz", false, 1),
+            "backup.tar (copy).gz"
+        );
+        assert_eq!(duplicate_name("Makefile", false, 1), "Makefile (copy)");
+        assert_eq!(duplicate_name(".bashrc", false, 1), ".bashrc (copy)");
+        assert_eq!(duplicate_name("docs.old", true, 2), "docs.old (copy 2)");
+    }
+
+    #[test]
+    fn should_get_available_duplicate_name() {
+        let dir = Path::new("/home/omar");
+        assert_eq!(
+            available_duplicate_name(dir, "a.txt", false, |_| false),
+            "a (copy).txt"
+        );
+        let taken = [
+            PathBuf::from("/home/omar/a (copy).txt"),
+            PathBuf::from("/home/omar/a (copy 2).txt"),
+        ];
+        assert_eq!(
+            available_duplicate_name(dir, "a.txt", false, |p| taken.iter().any(|t| t == p)),
+            "a (copy 3).txt"
+        );
+    }
+
+    #[test]
+    fn should_get_duplicate_path() {
+        assert_eq!(
+            duplicate_path(Path::new("/home/omar/a.txt"), "a (copy).txt"),
+            PathBuf::from("/home/omar/a (copy).txt")
+        );
+    }
+}
@@ -0,0 +1,117 @@
+//! ## Fanout
+//!
+//! `fanout` transfers the same files to several destinations, one after another, collecting the outcome of each transfer
+
+/// Outcome of the transfer to one destination
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FanoutResult {
+    /// Name of the destination (bookmark)
+    pub destination: String,
+    pub result: Result<(), String>,
+}
+
+/// Outcome of the transfers to all the destinations, in the order they were run
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FanoutReport {
+    pub results: Vec<FanoutResult>,
+}
+
+impl FanoutReport {
+    /// Run `transfer` once for each destination, sequentially.
+    /// A failed transfer doesn't prevent the transfers to the next destinations
+    pub fn run<F>(destinations: &[String], mut transfer: F) -> Self
+    where
+        F: FnMut(&str) -> Result<(), String>,
+    {
+        let results = destinations
+            .iter()
+            .map(|destination| FanoutResult {
+                destination: destination.clone(),
+                result: transfer(destination),
+            })
+            .collect();
+        Self { results }
+    }
+
+    /// Returns the amount of successful transfers
+    pub fn succeeded(&self) -> usize {
+        self.results.iter().filter(|x| x.result.is_ok()).count()
+    }
+
+    /// Iterate over failed transfers
+    pub fn failed(&self) -> impl Iterator<Item = &FanoutResult> {
+        self.results.iter().filter(|x| x.result.is_err())
+    }
+
+    /// Returns a summary of the report
+    pub fn summary(&self) -> String {
+        let failed: Vec<&str> = self.failed().map(|x| x.destination.as_str()).collect();
+        let summary = format!(
+            "Transferred to {} of {} destinations",
+            self.succeeded(),
+            self.results.len()
+        );
+        match failed.is_empty() {
+            true => summary,
+            false => format!("{summary}; failed: {}", failed.join(", ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn destinations(names: &[&str]) -> Vec<String> {
+        names.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn should_transfer_once_per_destination() {
+        let mut transferred: Vec<String> = Vec::new();
+        let report = FanoutReport::run(&destinations(&["prod", "staging", "backup"]), |dest| {
+            transferred.push(dest.to_string());
+            Ok(())
+        });
+        assert_eq!(transferred, destinations(&["prod", "staging", "backup"]));
+        assert_eq!(report.results.len(), 3);
+        assert_eq!(report.succeeded(), 3);
+        assert_eq!(report.failed().count(), 0);
+        assert_eq!(report.summary(), "Transferred to 3 of 3 destinations");
+    }
+
+    #[test]
+    fn should_aggregate_failures_and_keep_going() {
+        let report =
+            FanoutReport::run(
+                &destinations(&["prod", "staging", "backup"]),
+                |dest| match dest {
+                    "staging" => Err(String::from("connection refused")),
+                    _ => Ok(()),
+                },
+            );
+        assert_eq!(report.succeeded(), 2);
+        assert_eq!(
+            report.failed().collect::<Vec<_>>(),
+            vec![&FanoutResult {
+                destination: String::from("staging"),
+                result: Err(String::from("connection refused")),
+            }]
+        );
+        assert_eq!(report.results[2].destination, "backup");
+        assert_eq!(
+            report.summary(),
+            "Transferred to 2 of 3 destinations; failed: staging"
+        );
+    }
+
+    #[test]
+    fn should_report_nothing_without_destinations() {
+        let report = FanoutReport::run(&[], |_| panic!("no transfer expected"));
+        assert!(report.results.is_empty());
+        assert_eq!(report.summary(), "Transferred to 0 of 0 destinations");
+    }
+}
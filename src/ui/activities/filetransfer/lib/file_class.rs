@@ -0,0 +1,152 @@
+//! ## FileClass
+//!
+//! `file_class` classifies the files in the explorers by type and extension, to color them like `ls --color`
+
+use remotefs::File;
+use tuirealm::ratatui::style::Color;
+
+use crate::config::themes::Theme;
+
+/// Extensions of archives and compressed files
+const ARCHIVE_EXTENSIONS: &[&str] = &[
+    "7z", "bz2", "gz", "jar", "lz", "lz4", "lzma", "rar", "tar", "tbz", "tbz2", "tgz", "txz", "xz",
+    "z", "zip", "zst",
+];
+
+/// Class of a file in the explorers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileClass {
+    Directory,
+    Symlink,
+    Executable,
+    Archive,
+    Regular,
+}
+
+impl FileClass {
+    /// Classify `file` by its type, its permissions and its extension
+    pub fn of(file: &File) -> Self {
+        if file.is_symlink() {
+            Self::Symlink
+        } else if file.is_dir() {
+            Self::Directory
+        } else if file.metadata().mode.is_some_and(|mode| {
+            mode.user().execute() || mode.group().execute() || mode.others().execute()
+        }) {
+            Self::Executable
+        } else if file
+            .extension()
+            .is_some_and(|ext| ARCHIVE_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+        {
+            Self::Archive
+        } else {
+            Self::Regular
+        }
+    }
+
+    /// Get the color of the class in `theme`; regular files use the foreground of the explorer
+    pub fn color(self, theme: &Theme) -> Color {
+        match self {
+            Self::Directory => theme.transfer_explorer_directory,
+            Self::Symlink => theme.transfer_explorer_symlink,
+            Self::Executable => theme.transfer_explorer_executable,
+            Self::Archive => theme.transfer_explorer_archive,
+            Self::Regular => Color::Reset,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::path::PathBuf;
+
+    use pretty_assertions::assert_eq;
+    use remotefs::fs::{FileType, Metadata, UnixPex};
+
+    use super::*;
+
+    fn file(name: &str, file_type: FileType, mode: u32) -> File {
+        File {
+            path: PathBuf::from(name),
+            metadata: Metadata::default()
+                .file_type(file_type)
+                .mode(UnixPex::from(mode)),
+        }
+    }
+
+    #[test]
+    fn should_classify_files() {
+        assert_eq!(
+            FileClass::of(&file("src", FileType::Directory, 0o755)),
+            FileClass::Directory
+        );
+        assert_eq!(
+            FileClass::of(&file("link", FileType::Symlink, 0o777)),
+            FileClass::Symlink
+        );
+        assert_eq!(
+            FileClass::of(&file("termscp", FileType::File, 0o755)),
+            FileClass::Executable
+        );
+        assert_eq!(
+            FileClass::of(&file("run.sh", FileType::File, 0o701)),
+            FileClass::Executable
+        );
+        assert_eq!(
+            FileClass::of(&file("release.tar.gz", FileType::File, 0o644)),
+            FileClass::Archive
+        );
+        assert_eq!(
+            FileClass::of(&file("BACKUP.ZIP", FileType::File, 0o644)),
+            FileClass::Archive
+        );
+        assert_eq!(
+            FileClass::of(&file("README.md", FileType::File, 0o644)),
+            FileClass::Regular
+        );
+        assert_eq!(
+            FileClass::of(&file("Makefile", FileType::File, 0o644)),
+            FileClass::Regular
+        );
+    }
+
+    #[test]
+    fn should_classify_directories_named_as_archives() {
+        assert_eq!(
+            FileClass::of(&file("backup.zip", FileType::Directory, 0o755)),
+            FileClass::Directory
+        );
+    }
+
+    #[test]
+    fn should_classify_files_without_mode() {
+        let file = File {
+            path: PathBuf::from("termscp"),
+            metadata: Metadata::default(),
+        };
+        assert_eq!(FileClass::of(&file), FileClass::Regular);
+    }
+
+    #[test]
+    fn should_get_class_color_from_theme() {
+        let theme = Theme::default();
+        assert_eq!(
+            FileClass::Directory.color(&theme),
+            theme.transfer_explorer_directory
+        );
+        assert_eq!(
+            FileClass::Symlink.color(&theme),
+            theme.transfer_explorer_symlink
+        );
+        assert_eq!(
+            FileClass::Executable.color(&theme),
+            theme.transfer_explorer_executable
+        );
+        assert_eq!(
+            FileClass::Archive.color(&theme),
+            theme.transfer_explorer_archive
+        );
+        assert_eq!(FileClass::Regular.color(&theme), Color::Reset);
+    }
+}
@@ -0,0 +1,57 @@
+//! ## Keepalive
+//!
+//! `keepalive` provides the interval to send keepalives to the remote with, so the server doesn't drop idle sessions
+
+use std::time::{Duration, Instant};
+
+/// Schedules the keepalives to send to the remote
+pub struct Keepalive {
+    /// Interval between keepalives; if `None`, keepalives are disabled
+    interval: Option<Duration>,
+    /// When the next keepalive is due
+    deadline: Instant,
+}
+
+impl Keepalive {
+    /// Instantiates a new `Keepalive`; an interval of 0 seconds disables keepalives
+    pub fn new(secs: u64) -> Self {
+        let interval = (secs > 0).then(|| Duration::from_secs(secs));
+        Self {
+            interval,
+            deadline: Instant::now() + interval.unwrap_or_default(),
+        }
+    }
+
+    /// Returns whether a keepalive is due; the next one is then scheduled after the interval
+    pub fn poll(&mut self) -> bool {
+        match self.interval {
+            Some(interval) if Instant::now() >= self.deadline => {
+                self.deadline = Instant::now() + interval;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn should_not_send_keepalives_if_disabled() {
+        let mut keepalive = Keepalive::new(0);
+        assert!(!keepalive.poll());
+    }
+
+    #[test]
+    fn should_send_keepalive_once_interval_elapses() {
+        let mut keepalive = Keepalive::new(60);
+        assert!(!keepalive.poll());
+        keepalive.deadline = Instant::now();
+        assert!(keepalive.poll());
+        // Rescheduled after the interval
+        assert!(!keepalive.poll());
+    }
+}
@@ -0,0 +1,241 @@
+//! ## Mirror
+//!
+//! `mirror` computes the operations required to mirror a directory tree onto another one (one-way sync)
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use remotefs::File;
+
+/// Modification times closer than this are considered equal, since not every protocol
+/// preserves sub-second precision
+const MTIME_TOLERANCE: Duration = Duration::from_secs(1);
+
+/// An entry of a directory tree, with its path relative to the tree root
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MirrorEntry {
+    pub path: PathBuf,
+    pub is_dir: bool,
+    pub size: u64,
+    pub mtime: Option<SystemTime>,
+}
+
+impl MirrorEntry {
+    /// Make a mirror entry from a file contained in `root`.
+    /// Returns `None` if the file is not contained in `root`
+    pub fn from_file(root: &Path, file: &File) -> Option<Self> {
+        let path = file.path().strip_prefix(root).ok()?.to_path_buf();
+        if path.as_os_str().is_empty() {
+            return None;
+        }
+        Some(Self {
+            path,
+            is_dir: file.is_dir(),
+            size: file.metadata().size,
+            mtime: file.metadata().modified,
+        })
+    }
+
+    /// Returns whether `dest` differs from this entry and must be replaced
+    pub fn differs_from(&self, dest: &Self) -> bool {
+        if self.is_dir || dest.is_dir {
+            return self.is_dir != dest.is_dir;
+        }
+        if self.size != dest.size {
+            return true;
+        }
+        match (self.mtime, dest.mtime) {
+            (Some(src), Some(dest)) => {
+                let delta = src.duration_since(dest).unwrap_or_else(|e| e.duration());
+                delta > MTIME_TOLERANCE
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Describes the operations to perform to mirror a source tree onto a destination tree.
+/// All the paths are relative to the tree roots
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MirrorPlan {
+    /// Entries to transfer from source to destination. Directories are transferred recursively
+    pub transfer: Vec<PathBuf>,
+    /// Extraneous entries to remove from destination. Directories are removed recursively
+    pub delete: Vec<PathBuf>,
+}
+
+impl MirrorPlan {
+    /// Compute the mirror plan comparing `src` and `dest` by size and modification time.
+    ///
+    /// Entries contained in a directory which is already transferred (or deleted) as a whole
+    /// are not reported
+    pub fn new(src: &[MirrorEntry], dest: &[MirrorEntry]) -> Self {
+        let dest_entries: HashMap<&Path, &MirrorEntry> =
+            dest.iter().map(|x| (x.path.as_path(), x)).collect();
+        let src_paths: HashMap<&Path, &MirrorEntry> =
+            src.iter().map(|x| (x.path.as_path(), x)).collect();
+
+        let mut src = src.iter().collect::<Vec<&MirrorEntry>>();
+        src.sort_by(|a, b| a.path.cmp(&b.path));
+        let mut transfer: Vec<PathBuf> = Vec::new();
+        for entry in src {
+            if Self::is_contained(&transfer, &entry.path) {
+                continue;
+            }
+            match dest_entries.get(entry.path.as_path()) {
+                Some(dest) if !entry.differs_from(dest) => {}
+                _ => transfer.push(entry.path.clone()),
+            }
+        }
+
+        let mut dest = dest.iter().collect::<Vec<&MirrorEntry>>();
+        dest.sort_by(|a, b| a.path.cmp(&b.path));
+        let mut delete: Vec<PathBuf> = Vec::new();
+        for entry in dest {
+            if Self::is_contained(&delete, &entry.path)
+                || Self::is_contained(&transfer, &entry.path)
+            {
+                continue;
+            }
+            if !src_paths.contains_key(entry.path.as_path()) {
+                delete.push(entry.path.clone());
+            }
+        }
+
+        Self { transfer, delete }
+    }
+
+    /// Returns whether the plan has nothing to do
+    pub fn is_empty(&self) -> bool {
+        self.transfer.is_empty() && self.delete.is_empty()
+    }
+
+    /// Returns whether `p` is contained in one of the directories in `dirs`
+    fn is_contained(dirs: &[PathBuf], p: &Path) -> bool {
+        dirs.iter().any(|dir| p != dir && p.starts_with(dir))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn file(path: &str, size: u64, mtime: u64) -> MirrorEntry {
+        MirrorEntry {
+            path: PathBuf::from(path),
+            is_dir: false,
+            size,
+            mtime: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(mtime)),
+        }
+    }
+
+    fn dir(path: &str) -> MirrorEntry {
+        MirrorEntry {
+            path: PathBuf::from(path),
+            is_dir: true,
+            size: 4096,
+            mtime: None,
+        }
+    }
+
+    fn paths(p: &[&str]) -> Vec<PathBuf> {
+        p.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn should_make_mirror_entry_from_file() {
+        use remotefs::fs::Metadata;
+
+        let file = File {
+            path: PathBuf::from("/home/omar/docs/README.md"),
+            metadata: Metadata::default().size(8192),
+        };
+        let entry = MirrorEntry::from_file(Path::new("/home/omar"), &file).unwrap();
+        assert_eq!(entry.path, PathBuf::from("docs/README.md"));
+        assert_eq!(entry.size, 8192);
+        assert_eq!(entry.is_dir, false);
+        assert!(MirrorEntry::from_file(Path::new("/tmp"), &file).is_none());
+    }
+
+    #[test]
+    fn should_compute_empty_plan_for_equal_trees() {
+        let tree = vec![
+            dir("docs"),
+            file("docs/README.md", 1024, 100),
+            file("main.rs", 256, 200),
+        ];
+        let plan = MirrorPlan::new(&tree, &tree);
+        assert!(plan.is_empty());
+    }
+
+    #[test]
+    fn should_transfer_new_and_changed_files() {
+        let src = vec![
+            dir("docs"),
+            file("docs/README.md", 1024, 100),
+            file("docs/CHANGELOG.md", 512, 100),
+            file("main.rs", 256, 300),
+            file("lib.rs", 128, 100),
+            file("build.rs", 64, 100),
+        ];
+        let dest = vec![
+            dir("docs"),
+            file("docs/README.md", 1024, 100),
+            // changed mtime
+            file("main.rs", 256, 200),
+            // changed size
+            file("lib.rs", 127, 100),
+            // mtime within tolerance
+            file("build.rs", 64, 101),
+        ];
+        let plan = MirrorPlan::new(&src, &dest);
+        assert_eq!(
+            plan.transfer,
+            paths(&["docs/CHANGELOG.md", "lib.rs", "main.rs"])
+        );
+        assert!(plan.delete.is_empty());
+    }
+
+    #[test]
+    fn should_transfer_missing_directories_as_a_whole() {
+        let src = vec![
+            dir("src"),
+            dir("src/ui"),
+            file("src/ui/mod.rs", 100, 100),
+            file("src/main.rs", 100, 100),
+        ];
+        let dest = vec![];
+        let plan = MirrorPlan::new(&src, &dest);
+        assert_eq!(plan.transfer, paths(&["src"]));
+        assert!(plan.delete.is_empty());
+    }
+
+    #[test]
+    fn should_delete_extraneous_entries() {
+        let src = vec![dir("docs"), file("docs/README.md", 1024, 100)];
+        let dest = vec![
+            dir("docs"),
+            file("docs/README.md", 1024, 100),
+            file("docs/old.md", 10, 100),
+            dir("target"),
+            dir("target/debug"),
+            file("target/debug/termscp", 100, 100),
+        ];
+        let plan = MirrorPlan::new(&src, &dest);
+        assert!(plan.transfer.is_empty());
+        assert_eq!(plan.delete, paths(&["docs/old.md", "target"]));
+    }
+
+    #[test]
+    fn should_replace_entries_with_different_kind() {
+        let src = vec![file("data", 10, 100)];
+        let dest = vec![dir("data"), file("data/log.txt", 10, 100)];
+        let plan = MirrorPlan::new(&src, &dest);
+        assert_eq!(plan.transfer, paths(&["data"]));
+        assert!(plan.delete.is_empty());
+    }
+}
@@ -2,6 +2,22 @@
 //!
 //! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
 
+pub(crate) mod archive;
+pub(crate) mod bisync;
 pub(crate) mod browser;
+pub(crate) mod chmod;
+pub(crate) mod clipboard;
+pub(crate) mod confirm;
+pub(crate) mod connect_error;
+pub(crate) mod duplicate;
+pub(crate) mod fanout;
+pub(crate) mod file_class;
+pub(crate) mod keepalive;
+pub(crate) mod mirror;
+pub(crate) mod open_with;
+pub(crate) mod preview;
+pub(crate) mod rsync;
+pub(crate) mod session_bookmark;
+pub(crate) mod sibling;
 pub(crate) mod transfer;
 pub(crate) mod walkdir;
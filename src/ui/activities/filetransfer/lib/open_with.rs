@@ -0,0 +1,187 @@
+//! ## OpenWith
+//!
+//! `open_with` provides the command templates to open files with (e.g. `vlc --fullscreen {}`) and the recently used ones
+
+use std::io;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Placeholder replaced by the path of the file
+const FILE_PLACEHOLDER: &str = "{}";
+/// Placeholder replaced by the path of the directory of the file
+const DIR_PLACEHOLDER: &str = "{dir}";
+/// Maximum number of recently used commands to remember
+const MAX_RECENTS: usize = 16;
+
+/// Returns whether `with` is a command template, rather than the name of a program
+pub fn is_template(with: &str) -> bool {
+    with.contains(FILE_PLACEHOLDER) || with.contains(DIR_PLACEHOLDER)
+}
+
+/// Get the shell command to open `path` with, replacing the placeholders of `template` with the quoted paths
+pub fn expand_template(template: &str, path: &Path) -> String {
+    let file = shell_quote(&path.to_string_lossy());
+    let dir = shell_quote(&path.parent().unwrap_or(Path::new("")).to_string_lossy());
+    // placeholders are replaced in a single pass, so that braces in the paths are kept
+    let mut command = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(i) = rest.find('{') {
+        command.push_str(&rest[..i]);
+        rest = &rest[i..];
+        if let Some(tail) = rest.strip_prefix(FILE_PLACEHOLDER) {
+            command.push_str(&file);
+            rest = tail;
+        } else if let Some(tail) = rest.strip_prefix(DIR_PLACEHOLDER) {
+            command.push_str(&dir);
+            rest = tail;
+        } else {
+            command.push('{');
+            rest = &rest[1..];
+        }
+    }
+    command.push_str(rest);
+    command
+}
+
+/// Quote `s` so that the shell passes it verbatim as a single argument
+#[cfg(not(windows))]
+pub fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
+
+/// Quote `s` so that the shell passes it verbatim as a single argument
+#[cfg(windows)]
+pub fn shell_quote(s: &str) -> String {
+    // quotes aren't allowed in Windows paths
+    format!("\"{}\"", s.replace('"', ""))
+}
+
+/// Run the shell `command` in background, detached from the terminal
+pub fn spawn_shell(command: &str) -> io::Result<()> {
+    #[cfg(not(windows))]
+    let mut shell = {
+        let mut shell = Command::new("sh");
+        shell.arg("-c");
+        shell
+    };
+    #[cfg(windows)]
+    let mut shell = {
+        let mut shell = Command::new("cmd");
+        shell.arg("/C");
+        shell
+    };
+    shell
+        .arg(command)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+}
+
+/// Push `with` on top of the recently used commands `recents`, which are sorted from the most recent one
+pub fn push_recent(recents: &mut Vec<String>, with: &str) {
+    recents.retain(|x| x != with);
+    recents.insert(0, with.to_string());
+    recents.truncate(MAX_RECENTS);
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_tell_templates() {
+        assert!(is_template("vlc --fullscreen {}"));
+        assert!(is_template("cd {dir} && make"));
+        assert!(!is_template("vlc"));
+        assert!(!is_template("code --wait"));
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn should_expand_template() {
+        assert_eq!(
+            expand_template("vlc --fullscreen {}", Path::new("/home/omar/movie.mkv")),
+            "vlc --fullscreen '/home/omar/movie.mkv'"
+        );
+        assert_eq!(
+            expand_template("cd {dir} && ls {}", Path::new("/tmp/a.txt")),
+            "cd '/tmp' && ls '/tmp/a.txt'"
+        );
+        // every placeholder is replaced
+        assert_eq!(
+            expand_template("diff {} {}.orig", Path::new("/tmp/a.txt")),
+            "diff '/tmp/a.txt' '/tmp/a.txt'.orig"
+        );
+        // braces in the template and in the paths are kept
+        assert_eq!(
+            expand_template("awk '{print}' {}", Path::new("/tmp/{}/{dir}.txt")),
+            "awk '{print}' '/tmp/{}/{dir}.txt'"
+        );
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn should_quote_paths_for_the_shell() {
+        assert_eq!(shell_quote("/tmp/a.txt"), "'/tmp/a.txt'");
+        assert_eq!(shell_quote("/tmp/my file.txt"), "'/tmp/my file.txt'");
+        assert_eq!(shell_quote("/tmp/it's.txt"), r"'/tmp/it'\''s.txt'");
+        assert_eq!(
+            shell_quote("/tmp/$(rm -rf ~);.txt"),
+            "'/tmp/$(rm -rf ~);.txt'"
+        );
+        assert_eq!(
+            expand_template("cat {}", Path::new("/tmp/`whoami`.txt")),
+            "cat '/tmp/`whoami`.txt'"
+        );
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn should_pass_quoted_paths_verbatim_to_the_shell() {
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg(expand_template(
+                "printf %s {}",
+                Path::new("/tmp/it's $HOME.txt"),
+            ))
+            .output()
+            .unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "/tmp/it's $HOME.txt"
+        );
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn should_expand_template() {
+        assert_eq!(
+            expand_template(
+                "vlc --fullscreen {}",
+                Path::new(r"C:\Users\omar\my movie.mkv")
+            ),
+            r#"vlc --fullscreen "C:\Users\omar\my movie.mkv""#
+        );
+    }
+
+    #[test]
+    fn should_push_recently_used_commands() {
+        let mut recents = Vec::new();
+        push_recent(&mut recents, "vlc {}");
+        push_recent(&mut recents, "code");
+        assert_eq!(recents, vec!["code", "vlc {}"]);
+        // used again: moved on top
+        push_recent(&mut recents, "vlc {}");
+        assert_eq!(recents, vec!["vlc {}", "code"]);
+        for i in 0..MAX_RECENTS {
+            push_recent(&mut recents, &format!("cmd{i}"));
+        }
+        assert_eq!(recents.len(), MAX_RECENTS);
+        assert_eq!(recents[0], format!("cmd{}", MAX_RECENTS - 1));
+    }
+}
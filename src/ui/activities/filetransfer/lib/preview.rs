@@ -0,0 +1,153 @@
+//! ## Preview
+//!
+//! `preview` reads the beginning of the highlighted file, to show it in the preview pane
+
+use std::io::{self, Read};
+use std::path::Path;
+
+/// Maximum amount of bytes read out of a file to preview it, so large files aren't read as a whole
+pub const PREVIEW_MAX_BYTES: u64 = 64 * 1024;
+/// Maximum amount of lines shown in the preview
+pub const PREVIEW_MAX_LINES: usize = 200;
+/// Amount of spaces tabs are expanded to
+const TAB_WIDTH: usize = 4;
+
+/// Extensions of the files previewed as images
+const IMAGE_EXTENSIONS: [&str; 9] = [
+    "bmp", "gif", "ico", "jpeg", "jpg", "png", "svg", "tiff", "webp",
+];
+
+/// Content of the preview of a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreviewContent {
+    /// Lines of a text file; `truncated` if the file has more content than the lines
+    Text { lines: Vec<String>, truncated: bool },
+    /// The file isn't text, so only its metadata is shown
+    Binary,
+}
+
+/// Read up to `max_bytes` out of `reader`.
+/// Returns the bytes read and whether the reader had more to read
+pub fn read_capped(reader: &mut dyn Read, max_bytes: u64) -> io::Result<(Vec<u8>, bool)> {
+    let mut bytes = Vec::new();
+    reader.take(max_bytes + 1).read_to_end(&mut bytes)?;
+    let capped = bytes.len() as u64 > max_bytes;
+    bytes.truncate(max_bytes as usize);
+    Ok((bytes, capped))
+}
+
+/// Make the preview of the beginning of a file, showing up to `max_lines` lines.
+/// `capped` tells whether `bytes` is only the beginning of the file, in which case a multi-byte char cut at the end is ignored.
+/// Content with NUL bytes or which isn't UTF-8 is considered binary
+pub fn preview_content(bytes: &[u8], capped: bool, max_lines: usize) -> PreviewContent {
+    if bytes.contains(&0) {
+        return PreviewContent::Binary;
+    }
+    let text = match std::str::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(err) if capped && err.error_len().is_none() => {
+            // The char has been cut by the cap
+            std::str::from_utf8(&bytes[..err.valid_up_to()]).unwrap_or_default()
+        }
+        Err(_) => return PreviewContent::Binary,
+    };
+    let mut lines = text.lines();
+    let preview: Vec<String> = lines
+        .by_ref()
+        .take(max_lines)
+        .map(|line| line.replace('\t', &" ".repeat(TAB_WIDTH)))
+        .collect();
+    PreviewContent::Text {
+        truncated: capped || lines.next().is_some(),
+        lines: preview,
+    }
+}
+
+/// Returns whether the file at `path` is an image, according to its extension
+pub fn is_image(path: &Path) -> bool {
+    path.extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.as_str()))
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn text(lines: &[&str], truncated: bool) -> PreviewContent {
+        PreviewContent::Text {
+            lines: lines.iter().map(|l| l.to_string()).collect(),
+            truncated,
+        }
+    }
+
+    #[test]
+    fn should_read_capped() {
+        let (bytes, capped) = read_capped(&mut "hello world".as_bytes(), 5).unwrap();
+        assert_eq!(bytes, b"hello");
+        assert_eq!(capped, true);
+        let (bytes, capped) = read_capped(&mut "hello".as_bytes(), 5).unwrap();
+        assert_eq!(bytes, b"hello");
+        assert_eq!(capped, false);
+    }
+
+    #[test]
+    fn should_preview_text() {
+        assert_eq!(
+            preview_content(b"fn main() {\r\n\tprintln!();\n}\n", false, 10),
+            text(&["fn main() {", "    println!();", "}"], false)
+        );
+        assert_eq!(preview_content(b"", false, 10), text(&[], false));
+    }
+
+    #[test]
+    fn should_truncate_text_to_max_lines() {
+        assert_eq!(
+            preview_content(b"a\nb\nc\nd", false, 2),
+            text(&["a", "b"], true)
+        );
+        assert_eq!(
+            preview_content(b"a\nb\n", false, 2),
+            text(&["a", "b"], false)
+        );
+    }
+
+    #[test]
+    fn should_truncate_capped_text() {
+        assert_eq!(preview_content(b"a\nb", true, 10), text(&["a", "b"], true));
+        // A multi-byte char cut by the cap is dropped
+        let bytes = "ab\u{e8}".as_bytes();
+        assert_eq!(
+            preview_content(&bytes[..bytes.len() - 1], true, 10),
+            text(&["ab"], true)
+        );
+    }
+
+    #[test]
+    fn should_detect_binary_content() {
+        assert_eq!(
+            preview_content(b"\x7fELF\x02\x01\x01\0", false, 10),
+            PreviewContent::Binary
+        );
+        assert_eq!(
+            preview_content(&[0xff, 0xfe, b'a'], false, 10),
+            PreviewContent::Binary
+        );
+        // Without the cap, an incomplete char at the end isn't text
+        assert_eq!(
+            preview_content(&[b'a', 0xc3], false, 10),
+            PreviewContent::Binary
+        );
+    }
+
+    #[test]
+    fn should_tell_images() {
+        assert!(is_image(Path::new("/home/omar/logo.PNG")));
+        assert!(is_image(Path::new("photo.jpeg")));
+        assert!(!is_image(Path::new("README.md")));
+        assert!(!is_image(Path::new("png")));
+    }
+}
@@ -0,0 +1,348 @@
+//! ## Rsync
+//!
+//! `rsync` provides the rsync-over-ssh transfer mode, which performs delta transfers
+//! when both the local and the remote host support it
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::filetransfer::{FileTransferProtocol, ProtocolParams};
+
+/// Default rsync executable
+pub const RSYNC_BIN: &str = "rsync";
+
+/// Command used to check whether rsync is available on the remote host
+pub const RSYNC_REMOTE_CHECK_CMD: &str = "command -v rsync";
+
+/// Direction of a rsync transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RsyncDirection {
+    /// From the local host to the remote host
+    Upload,
+    /// From the remote host to the local host
+    Download,
+}
+
+/// Describes the ssh endpoint rsync connects to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RsyncTarget {
+    pub address: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub ssh_key: Option<PathBuf>,
+}
+
+impl RsyncTarget {
+    /// Get the rsync target from the remote params.
+    /// Returns `None` if the protocol doesn't run over ssh
+    pub fn from_params(protocol: FileTransferProtocol, params: &ProtocolParams) -> Option<Self> {
+        if !matches!(
+            protocol,
+            FileTransferProtocol::Sftp | FileTransferProtocol::Scp
+        ) {
+            return None;
+        }
+        params.generic_params().map(|params| Self {
+            address: params.address.clone(),
+            port: params.port,
+            username: params.username.clone(),
+            ssh_key: None,
+        })
+    }
+
+    /// Set the private key to use to authenticate
+    pub fn ssh_key(mut self, key: Option<PathBuf>) -> Self {
+        self.ssh_key = key;
+        self
+    }
+
+    /// Get the remote shell command used by rsync
+    fn ssh_command(&self) -> String {
+        let mut cmd = format!("ssh -p {} -o BatchMode=yes", self.port);
+        if let Some(key) = self.ssh_key.as_ref() {
+            cmd.push_str(&format!(" -i '{}'", key.display()));
+        }
+        cmd
+    }
+
+    /// Get the rsync remote path spec (`[user@]host:path`).
+    /// IPv6 addresses are put in brackets, otherwise rsync would split the spec at their first `:`
+    fn remote_spec(&self, p: &str) -> String {
+        let host = match self.address.contains(':') && !self.address.starts_with('[') {
+            true => format!("[{}]", self.address),
+            false => self.address.clone(),
+        };
+        match self.username.as_deref() {
+            Some(username) => format!("{username}@{host}:{p}"),
+            None => format!("{host}:{p}"),
+        }
+    }
+}
+
+/// Describes whether the rsync transfer mode can be used for the current session
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RsyncSupport {
+    /// Both endpoints support rsync
+    Available,
+    /// The protocol doesn't run over ssh or the host bridge is not the local host
+    Unsupported,
+    /// rsync is not installed on the local host
+    MissingLocal,
+    /// rsync is not installed on the remote host
+    MissingRemote,
+}
+
+impl RsyncSupport {
+    /// Detect rsync support for the session.
+    ///
+    /// `remote_check` is called only if the local checks succeed and must return whether
+    /// rsync is available on the remote host
+    pub fn detect<F>(
+        target: Option<&RsyncTarget>,
+        localhost: bool,
+        program: &str,
+        remote_check: F,
+    ) -> Self
+    where
+        F: FnOnce() -> bool,
+    {
+        if target.is_none() || !localhost {
+            Self::Unsupported
+        } else if !local_rsync_available(program) {
+            Self::MissingLocal
+        } else if !remote_check() {
+            Self::MissingRemote
+        } else {
+            Self::Available
+        }
+    }
+
+    /// Returns whether rsync can be used
+    pub fn is_available(&self) -> bool {
+        matches!(self, Self::Available)
+    }
+}
+
+/// Returns whether `program` is a working rsync executable on the local host
+pub fn local_rsync_available(program: &str) -> bool {
+    Command::new(program)
+        .arg("--version")
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+/// A rsync command ready to be executed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RsyncCommand {
+    program: String,
+    args: Vec<String>,
+}
+
+impl RsyncCommand {
+    /// Build a rsync command transferring `local` to `remote` (or the other way round for downloads).
+    ///
+    /// `dest` is always the full destination path; when transferring a directory, its content
+    /// is synchronized into `dest`
+    pub fn new(
+        program: &str,
+        target: &RsyncTarget,
+        direction: RsyncDirection,
+        src: &Path,
+        dest: &Path,
+        is_dir: bool,
+    ) -> Self {
+        let mut src = src.to_string_lossy().to_string();
+        if is_dir && !src.ends_with('/') {
+            src.push('/');
+        }
+        let dest = dest.to_string_lossy().to_string();
+        let (src, dest) = match direction {
+            RsyncDirection::Upload => (src, target.remote_spec(&dest)),
+            RsyncDirection::Download => (target.remote_spec(&src), dest),
+        };
+        Self {
+            program: program.to_string(),
+            args: vec![
+                "-az".to_string(),
+                "--partial".to_string(),
+                "--protect-args".to_string(),
+                "-e".to_string(),
+                target.ssh_command(),
+                src,
+                dest,
+            ],
+        }
+    }
+
+    /// Run the command, waiting for it to terminate
+    pub fn run(&self) -> Result<(), String> {
+        debug!("Running {} {:?}", self.program, self.args);
+        let output = Command::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|err| format!("could not start {}: {err}", self.program))?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} exited with {}: {}",
+                self.program,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::filetransfer::params::GenericProtocolParams;
+
+    fn generic_params() -> ProtocolParams {
+        ProtocolParams::Generic(
+            GenericProtocolParams::default()
+                .address("192.168.1.31")
+                .port(2222)
+                .username(Some("omar")),
+        )
+    }
+
+    fn target() -> RsyncTarget {
+        RsyncTarget::from_params(FileTransferProtocol::Sftp, &generic_params()).unwrap()
+    }
+
+    #[test]
+    fn should_get_target_for_ssh_protocols() {
+        let target = target();
+        assert_eq!(target.address.as_str(), "192.168.1.31");
+        assert_eq!(target.port, 2222);
+        assert_eq!(target.username.as_deref(), Some("omar"));
+        assert!(RsyncTarget::from_params(FileTransferProtocol::Scp, &generic_params()).is_some());
+        assert!(
+            RsyncTarget::from_params(FileTransferProtocol::Ftp(false), &generic_params()).is_none()
+        );
+    }
+
+    #[test]
+    fn should_detect_unsupported_rsync() {
+        let target = target();
+        assert_eq!(
+            RsyncSupport::detect(None, true, RSYNC_BIN, || true),
+            RsyncSupport::Unsupported
+        );
+        assert_eq!(
+            RsyncSupport::detect(Some(&target), false, RSYNC_BIN, || true),
+            RsyncSupport::Unsupported
+        );
+        assert_eq!(
+            RsyncSupport::detect(Some(&target), true, "/this/rsync/does/not/exist", || true),
+            RsyncSupport::MissingLocal
+        );
+        assert!(!RsyncSupport::MissingLocal.is_available());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn should_detect_missing_remote_rsync() {
+        // `true` always succeeds, so it is a valid stand-in for the local rsync
+        let target = target();
+        assert_eq!(
+            RsyncSupport::detect(Some(&target), true, "true", || false),
+            RsyncSupport::MissingRemote
+        );
+        let support = RsyncSupport::detect(Some(&target), true, "true", || true);
+        assert_eq!(support, RsyncSupport::Available);
+        assert!(support.is_available());
+    }
+
+    #[test]
+    fn should_build_upload_command() {
+        let target = target().ssh_key(Some(PathBuf::from("/home/omar/.ssh/id_rsa")));
+        let cmd = RsyncCommand::new(
+            RSYNC_BIN,
+            &target,
+            RsyncDirection::Upload,
+            Path::new("/tmp/data"),
+            Path::new("/home/omar/data"),
+            true,
+        );
+        assert_eq!(
+            cmd.args.as_slice(),
+            &[
+                "-az",
+                "--partial",
+                "--protect-args",
+                "-e",
+                "ssh -p 2222 -o BatchMode=yes -i '/home/omar/.ssh/id_rsa'",
+                "/tmp/data/",
+                "omar@192.168.1.31:/home/omar/data",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_build_download_command() {
+        let mut target = target();
+        target.username = None;
+        let cmd = RsyncCommand::new(
+            RSYNC_BIN,
+            &target,
+            RsyncDirection::Download,
+            Path::new("/home/omar/README.md"),
+            Path::new("/tmp/README.md"),
+            false,
+        );
+        assert_eq!(
+            cmd.args.as_slice(),
+            &[
+                "-az",
+                "--partial",
+                "--protect-args",
+                "-e",
+                "ssh -p 2222 -o BatchMode=yes",
+                "192.168.1.31:/home/omar/README.md",
+                "/tmp/README.md",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_put_ipv6_addresses_in_brackets() {
+        let mut target = target();
+        target.address = String::from("fe80::1");
+        assert_eq!(
+            target.remote_spec("/home/omar"),
+            "omar@[fe80::1]:/home/omar"
+        );
+        target.username = None;
+        target.address = String::from("::1");
+        assert_eq!(target.remote_spec("/tmp"), "[::1]:/tmp");
+        // already in brackets
+        target.address = String::from("[2001:db8::1]");
+        assert_eq!(target.remote_spec("/tmp"), "[2001:db8::1]:/tmp");
+        target.address = String::from("192.168.1.31");
+        assert_eq!(target.remote_spec("/tmp"), "192.168.1.31:/tmp");
+    }
+
+    #[test]
+    fn should_fail_running_missing_rsync() {
+        let cmd = RsyncCommand::new(
+            "/this/rsync/does/not/exist",
+            &target(),
+            RsyncDirection::Upload,
+            Path::new("/tmp/data"),
+            Path::new("/home/omar/data"),
+            false,
+        );
+        assert!(cmd.run().is_err());
+    }
+}
@@ -0,0 +1,387 @@
+//! ## Session bookmark
+//!
+//! `session_bookmark` makes a bookmark out of the parameters of the active connection
+
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::config::bookmarks::ExplorerPrefs;
+use crate::explorer::filter::FileFilter;
+use crate::explorer::{FileExplorer, FileSorting};
+use crate::filetransfer::FileTransferParams;
+use crate::system::bookmarks_client::BookmarksClient;
+
+/// Get the bookmark parameters of the active connection, with the current working directories as start paths.
+///
+/// `local_wrkdir` is only set when the host bridge is the localhost
+pub fn session_bookmark_params(
+    params: &FileTransferParams,
+    remote_wrkdir: &Path,
+    local_wrkdir: Option<&Path>,
+) -> FileTransferParams {
+    let mut params = params.clone();
+    params.remote_path = Some(remote_wrkdir.to_path_buf());
+    params.local_path = local_wrkdir.map(Path::to_path_buf);
+    params
+}
+
+/// Get the default name of the bookmark of the active connection (e.g. `pi@192.168.1.31`)
+pub fn session_bookmark_name(params: &FileTransferParams) -> String {
+    let host = params.params.host_name();
+    match params
+        .params
+        .generic_params()
+        .and_then(|x| x.username.as_deref())
+    {
+        Some(username) => format!("{username}@{host}"),
+        None => host,
+    }
+}
+
+/// Add the bookmark `name` with `params` and write the bookmarks file
+pub fn save_session_bookmark(
+    client: &mut BookmarksClient,
+    name: &str,
+    params: FileTransferParams,
+    save_password: bool,
+) -> Result<(), String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(String::from("Bookmark name can't be empty"));
+    }
+    client.add_bookmark(name, params, save_password);
+    client
+        .write_bookmarks()
+        .map_err(|err| format!("Could not write bookmarks: {err}"))
+}
+
+/// Add the directory bookmark `name`, reaching `directory` on the remote of `params`, and write the bookmarks file
+pub fn save_directory_bookmark(
+    client: &mut BookmarksClient,
+    name: &str,
+    params: FileTransferParams,
+    directory: &Path,
+    save_password: bool,
+) -> Result<(), String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(String::from("Bookmark name can't be empty"));
+    }
+    client.add_directory_bookmark(name, params, directory, save_password);
+    client
+        .write_bookmarks()
+        .map_err(|err| format!("Could not write bookmarks: {err}"))
+}
+
+/// Returns whether `a` and `b` connect to the same remote, with the same user
+pub fn is_same_remote(a: &FileTransferParams, b: &FileTransferParams) -> bool {
+    let endpoint = |params: &FileTransferParams| {
+        params
+            .params
+            .generic_params()
+            .map(|x| (x.port, x.username.clone()))
+    };
+    a.protocol == b.protocol
+        && a.params.host_name() == b.params.host_name()
+        && endpoint(a) == endpoint(b)
+}
+
+/// Store the working directories of the session against the bookmark `name` and write the bookmarks file.
+///
+/// `local_wrkdir` is only set when the host bridge is the localhost
+pub fn save_session_last_dirs(
+    client: &mut BookmarksClient,
+    name: &str,
+    remote_wrkdir: &Path,
+    local_wrkdir: Option<&Path>,
+) -> Result<(), String> {
+    if !client.set_bookmark_last_dirs(name, Some(remote_wrkdir), local_wrkdir) {
+        return Err(format!("No such bookmark \"{name}\""));
+    }
+    client
+        .write_bookmarks()
+        .map_err(|err| format!("Could not write bookmarks: {err}"))
+}
+
+/// Get the sorting and the filter of `explorer`, to save in the bookmark of the session
+pub fn explorer_prefs(explorer: &FileExplorer) -> ExplorerPrefs {
+    ExplorerPrefs {
+        sorting: Some(explorer.get_file_sorting().to_string()),
+        filter: explorer.filter().map(|x| x.expression().to_string()),
+    }
+}
+
+/// Restore the sorting and the filter saved in the bookmark of the session on `explorer`;
+/// an invalid sorting is ignored
+pub fn restore_explorer_prefs(explorer: &mut FileExplorer, prefs: &ExplorerPrefs) {
+    if let Some(sorting) = prefs
+        .sorting
+        .as_deref()
+        .and_then(|x| FileSorting::from_str(x).ok())
+    {
+        explorer.sort_by(sorting);
+    }
+    explorer.set_filter(
+        prefs
+            .filter
+            .as_deref()
+            .and_then(|x| FileFilter::from_str(x).ok()),
+    );
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::path::PathBuf;
+
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::filetransfer::params::GenericProtocolParams;
+    use crate::filetransfer::{FileTransferProtocol, ProtocolParams};
+
+    fn session_params() -> FileTransferParams {
+        FileTransferParams::new(
+            FileTransferProtocol::Sftp,
+            ProtocolParams::Generic(
+                GenericProtocolParams::default()
+                    .address("192.168.1.31")
+                    .port(2222)
+                    .username(Some("pi"))
+                    .password(Some("secret")),
+            ),
+        )
+        .remote_path(Some("/home/pi"))
+    }
+
+    fn assert_session_params(params: &FileTransferParams, password: Option<&str>) {
+        assert_eq!(params.protocol, FileTransferProtocol::Sftp);
+        let generic = params.params.generic_params().unwrap();
+        assert_eq!(generic.address, "192.168.1.31");
+        assert_eq!(generic.port, 2222);
+        assert_eq!(generic.username.as_deref(), Some("pi"));
+        assert_eq!(generic.password.as_deref(), password);
+    }
+
+    #[test]
+    fn should_prefill_bookmark_with_active_connection() {
+        let params = session_bookmark_params(
+            &session_params(),
+            Path::new("/home/pi/projects"),
+            Some(Path::new("/tmp")),
+        );
+        assert_session_params(&params, Some("secret"));
+        assert_eq!(params.remote_path, Some(PathBuf::from("/home/pi/projects")));
+        assert_eq!(params.local_path, Some(PathBuf::from("/tmp")));
+        // without local working directory
+        let params =
+            session_bookmark_params(&session_params(), Path::new("/home/pi/projects"), None);
+        assert_eq!(params.local_path, None);
+    }
+
+    #[test]
+    fn should_make_session_bookmark_name() {
+        assert_eq!(session_bookmark_name(&session_params()), "pi@192.168.1.31");
+        let params = FileTransferParams::new(
+            FileTransferProtocol::Ftp(false),
+            ProtocolParams::Generic(GenericProtocolParams::default().address("ftp.example.com")),
+        );
+        assert_eq!(session_bookmark_name(&params), "ftp.example.com");
+    }
+
+    #[test]
+    fn should_save_session_bookmark() {
+        let tmp_dir = TempDir::new().unwrap();
+        let bookmarks_file = tmp_dir.path().join("bookmarks.toml");
+        let mut client =
+            BookmarksClient::new(bookmarks_file.as_path(), tmp_dir.path(), 16, false).unwrap();
+        let params = session_bookmark_params(
+            &session_params(),
+            Path::new("/home/pi/projects"),
+            Some(Path::new("/tmp")),
+        );
+        assert!(save_session_bookmark(&mut client, "raspberry", params, true).is_ok());
+        // Reload bookmarks from file
+        let client =
+            BookmarksClient::new(bookmarks_file.as_path(), tmp_dir.path(), 16, false).unwrap();
+        let params = client.get_bookmark("raspberry").unwrap();
+        assert_session_params(&params, Some("secret"));
+        assert_eq!(params.remote_path, Some(PathBuf::from("/home/pi/projects")));
+        assert_eq!(params.local_path, Some(PathBuf::from("/tmp")));
+    }
+
+    #[test]
+    fn should_save_session_bookmark_without_password() {
+        let tmp_dir = TempDir::new().unwrap();
+        let bookmarks_file = tmp_dir.path().join("bookmarks.toml");
+        let mut client =
+            BookmarksClient::new(bookmarks_file.as_path(), tmp_dir.path(), 16, false).unwrap();
+        assert!(save_session_bookmark(&mut client, "raspberry", session_params(), false).is_ok());
+        let client =
+            BookmarksClient::new(bookmarks_file.as_path(), tmp_dir.path(), 16, false).unwrap();
+        assert_session_params(&client.get_bookmark("raspberry").unwrap(), None);
+    }
+
+    #[test]
+    fn should_save_session_last_dirs() {
+        let tmp_dir = TempDir::new().unwrap();
+        let bookmarks_file = tmp_dir.path().join("bookmarks.toml");
+        let mut client =
+            BookmarksClient::new(bookmarks_file.as_path(), tmp_dir.path(), 16, false).unwrap();
+        assert!(save_session_bookmark(&mut client, "raspberry", session_params(), true).is_ok());
+        assert!(
+            save_session_last_dirs(
+                &mut client,
+                "raspberry",
+                Path::new("/home/pi/projects"),
+                Some(Path::new("/tmp")),
+            )
+            .is_ok()
+        );
+        // Reload bookmarks from file
+        let client =
+            BookmarksClient::new(bookmarks_file.as_path(), tmp_dir.path(), 16, false).unwrap();
+        assert_eq!(
+            client.get_bookmark_last_dirs("raspberry"),
+            (
+                Some(PathBuf::from("/home/pi/projects")),
+                Some(PathBuf::from("/tmp"))
+            )
+        );
+        // The start directory of the bookmark is unchanged
+        let params = client.get_bookmark("raspberry").unwrap();
+        assert_eq!(params.remote_path, Some(PathBuf::from("/home/pi")));
+    }
+
+    #[test]
+    fn should_save_and_restore_explorer_prefs() {
+        let mut explorer = FileExplorer::default();
+        assert_eq!(
+            explorer_prefs(&explorer),
+            ExplorerPrefs {
+                sorting: Some(String::from("by_name")),
+                filter: None,
+            }
+        );
+        explorer.sort_by(FileSorting::ModifyTime);
+        explorer.set_filter(Some(FileFilter::from_str("*.rs").unwrap()));
+        let prefs = explorer_prefs(&explorer);
+        assert_eq!(
+            prefs,
+            ExplorerPrefs {
+                sorting: Some(String::from("by_mtime")),
+                filter: Some(String::from("*.rs")),
+            }
+        );
+        let mut explorer = FileExplorer::default();
+        restore_explorer_prefs(&mut explorer, &prefs);
+        assert_eq!(explorer.get_file_sorting(), FileSorting::ModifyTime);
+        assert_eq!(explorer.filter().unwrap().expression(), "*.rs");
+    }
+
+    #[test]
+    fn should_ignore_invalid_sorting_when_restoring_explorer_prefs() {
+        let mut explorer = FileExplorer::default();
+        explorer.set_filter(Some(FileFilter::from_str("*.rs").unwrap()));
+        restore_explorer_prefs(
+            &mut explorer,
+            &ExplorerPrefs {
+                sorting: Some(String::from("by_color")),
+                filter: None,
+            },
+        );
+        assert_eq!(explorer.get_file_sorting(), FileSorting::Name);
+        assert!(explorer.filter().is_none());
+    }
+
+    #[test]
+    fn should_not_save_last_dirs_of_unknown_bookmark() {
+        let tmp_dir = TempDir::new().unwrap();
+        let bookmarks_file = tmp_dir.path().join("bookmarks.toml");
+        let mut client =
+            BookmarksClient::new(bookmarks_file.as_path(), tmp_dir.path(), 16, false).unwrap();
+        assert!(
+            save_session_last_dirs(&mut client, "raspberry", Path::new("/home"), None).is_err()
+        );
+        assert_eq!(client.iter_bookmarks().count(), 0);
+    }
+
+    #[test]
+    fn should_save_directory_bookmark() {
+        let tmp_dir = TempDir::new().unwrap();
+        let bookmarks_file = tmp_dir.path().join("bookmarks.toml");
+        let mut client =
+            BookmarksClient::new(bookmarks_file.as_path(), tmp_dir.path(), 16, false).unwrap();
+        assert!(
+            save_directory_bookmark(
+                &mut client,
+                " www ",
+                session_params(),
+                Path::new("/var/www"),
+                true
+            )
+            .is_ok()
+        );
+        let client =
+            BookmarksClient::new(bookmarks_file.as_path(), tmp_dir.path(), 16, false).unwrap();
+        let params = client.get_directory_bookmark("www").unwrap();
+        assert_session_params(&params, Some("secret"));
+        assert_eq!(params.remote_path, Some(PathBuf::from("/var/www")));
+        // Empty name
+        let mut client = client;
+        assert!(
+            save_directory_bookmark(
+                &mut client,
+                "",
+                session_params(),
+                Path::new("/var/www"),
+                true
+            )
+            .is_err()
+        );
+        assert_eq!(client.iter_directory_bookmarks().count(), 1);
+    }
+
+    #[test]
+    fn should_tell_whether_params_connect_to_same_remote() {
+        let params = session_params();
+        assert!(is_same_remote(
+            &params,
+            &session_params().remote_path(Some("/tmp"))
+        ));
+        let other_user = FileTransferParams::new(
+            FileTransferProtocol::Sftp,
+            ProtocolParams::Generic(
+                GenericProtocolParams::default()
+                    .address("192.168.1.31")
+                    .port(2222)
+                    .username(Some("root")),
+            ),
+        );
+        assert!(!is_same_remote(&params, &other_user));
+        let other_protocol =
+            FileTransferParams::new(FileTransferProtocol::Scp, session_params().params.clone());
+        assert!(!is_same_remote(&params, &other_protocol));
+        let other_host = FileTransferParams::new(
+            FileTransferProtocol::Sftp,
+            ProtocolParams::Generic(
+                GenericProtocolParams::default()
+                    .address("192.168.1.32")
+                    .port(2222)
+                    .username(Some("pi")),
+            ),
+        );
+        assert!(!is_same_remote(&params, &other_host));
+    }
+
+    #[test]
+    fn should_not_save_session_bookmark_without_name() {
+        let tmp_dir = TempDir::new().unwrap();
+        let bookmarks_file = tmp_dir.path().join("bookmarks.toml");
+        let mut client =
+            BookmarksClient::new(bookmarks_file.as_path(), tmp_dir.path(), 16, false).unwrap();
+        assert!(save_session_bookmark(&mut client, "  ", session_params(), true).is_err());
+        assert_eq!(client.iter_bookmarks().count(), 0);
+    }
+}
@@ -0,0 +1,126 @@
+//! ## Sibling
+//!
+//! `sibling` resolves the directory next to the working directory among the subdirectories of its parent
+
+use std::path::{Path, PathBuf};
+
+/// Direction to move to among sibling directories
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SiblingDirection {
+    Next,
+    Previous,
+}
+
+/// Get the sibling directory of `current` in `direction` among `siblings`, the subdirectories of its parent.
+///
+/// Siblings are ordered by name and the navigation cycles, so the next sibling of the last directory is the first one.
+/// Returns `None` if there is no other directory to move to
+pub fn sibling_dir(
+    current: &Path,
+    siblings: &[PathBuf],
+    direction: SiblingDirection,
+) -> Option<PathBuf> {
+    let mut siblings: Vec<&PathBuf> = siblings.iter().filter(|x| *x != current).collect();
+    if siblings.is_empty() {
+        return None;
+    }
+    siblings.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
+    // position of the first sibling coming after the current directory
+    let pos = siblings.partition_point(|x| x.file_name() < current.file_name());
+    let idx = match direction {
+        SiblingDirection::Next => pos % siblings.len(),
+        SiblingDirection::Previous => (pos + siblings.len() - 1) % siblings.len(),
+    };
+    Some(siblings[idx].clone())
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn dirs(names: &[&str]) -> Vec<PathBuf> {
+        names
+            .iter()
+            .map(|x| Path::new("/var/log").join(x))
+            .collect()
+    }
+
+    #[test]
+    fn should_cycle_through_siblings() {
+        let siblings = dirs(&["app3", "app1", "app2"]);
+        let mut current = PathBuf::from("/var/log/app1");
+        let mut visited = Vec::new();
+        for _ in 0..3 {
+            current = sibling_dir(&current, &siblings, SiblingDirection::Next).unwrap();
+            visited.push(current.clone());
+        }
+        assert_eq!(visited, dirs(&["app2", "app3", "app1"]));
+        for _ in 0..3 {
+            current = sibling_dir(&current, &siblings, SiblingDirection::Previous).unwrap();
+            visited.push(current.clone());
+        }
+        assert_eq!(&visited[3..], dirs(&["app3", "app2", "app1"]).as_slice());
+    }
+
+    #[test]
+    fn should_wrap_around_first_and_last_sibling() {
+        let siblings = dirs(&["app1", "app2", "app3"]);
+        assert_eq!(
+            sibling_dir(
+                Path::new("/var/log/app3"),
+                &siblings,
+                SiblingDirection::Next
+            ),
+            Some(PathBuf::from("/var/log/app1"))
+        );
+        assert_eq!(
+            sibling_dir(
+                Path::new("/var/log/app1"),
+                &siblings,
+                SiblingDirection::Previous
+            ),
+            Some(PathBuf::from("/var/log/app3"))
+        );
+    }
+
+    #[test]
+    fn should_not_move_without_siblings() {
+        assert_eq!(
+            sibling_dir(
+                Path::new("/var/log/app1"),
+                &dirs(&["app1"]),
+                SiblingDirection::Next
+            ),
+            None
+        );
+        assert_eq!(
+            sibling_dir(Path::new("/var/log/app1"), &[], SiblingDirection::Previous),
+            None
+        );
+    }
+
+    #[test]
+    fn should_find_siblings_of_unlisted_directory() {
+        // e.g. the current directory is hidden
+        let siblings = dirs(&["app1", "app3"]);
+        assert_eq!(
+            sibling_dir(
+                Path::new("/var/log/app2"),
+                &siblings,
+                SiblingDirection::Next
+            ),
+            Some(PathBuf::from("/var/log/app3"))
+        );
+        assert_eq!(
+            sibling_dir(
+                Path::new("/var/log/app2"),
+                &siblings,
+                SiblingDirection::Previous
+            ),
+            Some(PathBuf::from("/var/log/app1"))
+        );
+    }
+}
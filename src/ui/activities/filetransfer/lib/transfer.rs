@@ -3,9 +3,13 @@
 //! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
 
 use std::fmt;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 use bytesize::ByteSize;
+use remotefs::File;
+
+use crate::system::config_client::ConfigClient;
 
 // -- States and progress
 
@@ -19,10 +23,25 @@ pub struct TransferStates {
 /// Progress states describes the states for the progress of a single transfer part
 pub struct ProgressStates {
     started: Instant,
+    /// When the whole size was written; the speed is then frozen
+    completed: Option<Instant>,
     total: usize,
     written: usize,
 }
 
+/// Aggregate size of the entries in a transfer queue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueueSummary {
+    /// Amount of queued entries
+    entries: usize,
+    /// Total size of the entries whose size is known
+    size: u64,
+    /// Whether the size of some entries is unknown
+    unknown: bool,
+    /// Speed of the last transfer, to estimate the time to transfer the queue with
+    bytes_per_second: u64,
+}
+
 impl Default for TransferStates {
     fn default() -> Self {
         Self::new()
@@ -64,28 +83,33 @@ impl Default for ProgressStates {
     fn default() -> Self {
         ProgressStates {
             started: Instant::now(),
+            completed: None,
             written: 0,
             total: 0,
         }
     }
 }
 
+/// Format an ETA in seconds as `mm:ss`; `--:--` if unknown (0)
+fn fmt_eta(seconds: u64) -> String {
+    match seconds {
+        0 => String::from("--:--"),
+        seconds => format!(
+            "{:0width$}:{:0width$}",
+            (seconds / 60),
+            (seconds % 60),
+            width = 2
+        ),
+    }
+}
+
 impl fmt::Display for ProgressStates {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let eta: String = match self.calc_eta() {
-            0 => String::from("--:--"),
-            seconds => format!(
-                "{:0width$}:{:0width$}",
-                (seconds / 60),
-                (seconds % 60),
-                width = 2
-            ),
-        };
         write!(
             f,
             "{:.2}% - ETA {} ({}/s)",
             self.calc_progress_percentage(),
-            eta,
+            fmt_eta(self.calc_eta()),
             ByteSize(self.calc_bytes_per_second())
         )
     }
@@ -95,6 +119,7 @@ impl ProgressStates {
     /// Initialize a new Progress State
     pub fn init(&mut self, sz: usize) {
         self.started = Instant::now();
+        self.completed = None;
         self.total = sz;
         self.written = 0;
     }
@@ -102,6 +127,9 @@ impl ProgressStates {
     /// Update progress state
     pub fn update_progress(&mut self, delta: usize) -> f64 {
         self.written += delta;
+        if self.written >= self.total && self.completed.is_none() {
+            self.completed = Some(Instant::now());
+        }
         self.calc_progress_percentage()
     }
 
@@ -132,7 +160,10 @@ impl ProgressStates {
     /// and the total amount of bytes to write
     pub fn calc_bytes_per_second(&self) -> u64 {
         // bytes_written : elapsed_secs = x : 1
-        let elapsed_secs: u64 = self.started.elapsed().as_secs();
+        let elapsed_secs: u64 = match self.completed {
+            Some(completed) => completed.saturating_duration_since(self.started).as_secs(),
+            None => self.started.elapsed().as_secs(),
+        };
         match elapsed_secs {
             0 => match self.written == self.total {
                 // NOTE: would divide by 0 :D
@@ -154,6 +185,60 @@ impl ProgressStates {
     }
 }
 
+impl QueueSummary {
+    /// Summarize the queued entries from their sizes; `None` is an entry whose size is unknown
+    pub fn new<I>(sizes: I, bytes_per_second: u64) -> Self
+    where
+        I: IntoIterator<Item = Option<u64>>,
+    {
+        let mut summary = Self {
+            entries: 0,
+            size: 0,
+            unknown: false,
+            bytes_per_second,
+        };
+        for size in sizes {
+            summary.entries += 1;
+            match size {
+                Some(size) => summary.size += size,
+                None => summary.unknown = true,
+            }
+        }
+        summary
+    }
+
+    /// Estimate the seconds to transfer the queue in at the speed of the last transfer.
+    /// Returns 0 (unknown) if the size of some entries is unknown or nothing was transferred yet
+    fn calc_eta(&self) -> u64 {
+        match (self.unknown, self.bytes_per_second) {
+            (true, _) | (_, 0) => 0,
+            (false, speed) => self.size.div_ceil(speed),
+        }
+    }
+}
+
+impl fmt::Display for QueueSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let size = match (self.unknown, self.size) {
+            (true, 0) => String::from("?"),
+            (true, size) => format!(">= {}", ByteSize(size)),
+            (false, size) => ByteSize(size).to_string(),
+        };
+        let entries = match self.entries {
+            1 => "entry",
+            _ => "entries",
+        };
+        write!(
+            f,
+            "{} {}, {} - ETA {}",
+            self.entries,
+            entries,
+            size,
+            fmt_eta(self.calc_eta())
+        )
+    }
+}
+
 // -- Options
 
 /// Defines the transfer options for transfer actions
@@ -161,6 +246,10 @@ impl ProgressStates {
 pub struct TransferOpts {
     /// Save file as
     pub save_as: Option<String>,
+    /// Whether to transfer using rsync. If `None`, the configuration value is used
+    pub rsync: Option<bool>,
+    /// Open the file once it has been transferred
+    pub open: bool,
 }
 
 impl TransferOpts {
@@ -169,6 +258,39 @@ impl TransferOpts {
         self.save_as = n.map(|x| x.as_ref().to_string());
         self
     }
+
+    /// Define whether to transfer using rsync
+    pub fn rsync(mut self, rsync: Option<bool>) -> Self {
+        self.rsync = rsync;
+        self
+    }
+
+    /// Define whether to open the file once it has been transferred
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Returns the path of the file to open once the transfer of `entry` into `wrkdir` has completed with `result`.
+    /// Nothing is opened if the transfer failed or if `entry` is a directory
+    pub fn path_to_open<E>(
+        &self,
+        entry: &File,
+        wrkdir: &Path,
+        result: &Result<(), E>,
+    ) -> Option<PathBuf> {
+        if !self.open || result.is_err() || entry.is_dir() {
+            return None;
+        }
+        Some(wrkdir.join(self.save_as.clone().unwrap_or_else(|| entry.name())))
+    }
+
+    /// Returns whether the user must confirm the transfer if the target file already exists.
+    /// When saving under a new name, `prompt_on_save_as_replace` applies as well
+    pub fn prompt_on_replace(&self, config: &ConfigClient) -> bool {
+        config.get_prompt_on_file_replace()
+            || (self.save_as.is_some() && config.get_prompt_on_save_as_replace())
+    }
 }
 
 #[cfg(test)]
@@ -177,6 +299,7 @@ mod test {
     use std::time::Duration;
 
     use pretty_assertions::assert_eq;
+    use remotefs::fs::{FileType, Metadata};
 
     use super::*;
 
@@ -226,6 +349,56 @@ mod test {
         assert_eq!(states.calc_progress(), 0.0);
     }
 
+    #[test]
+    fn should_freeze_speed_once_transfer_completed() {
+        let mut states = ProgressStates::default();
+        states.init(1024);
+        states.started = states.started.checked_sub(Duration::from_secs(4)).unwrap();
+        states.update_progress(1024);
+        // Time passing after the transfer doesn't slow it down
+        states.started = states.started.checked_sub(Duration::from_secs(60)).unwrap();
+        states.completed = states
+            .completed
+            .unwrap()
+            .checked_sub(Duration::from_secs(60));
+        assert_eq!(states.calc_bytes_per_second(), 256);
+        // A new transfer measures its own speed
+        states.init(2048);
+        assert!(states.completed.is_none());
+    }
+
+    #[test]
+    fn should_summarize_queue() {
+        let summary = QueueSummary::new([Some(1024), Some(3072), Some(0)], 64);
+        assert_eq!(summary.entries, 3);
+        assert_eq!(summary.size, 4096);
+        assert_eq!(summary.unknown, false);
+        assert_eq!(summary.calc_eta(), 64);
+        assert_eq!(summary.to_string(), "3 entries, 4.0 KiB - ETA 01:04");
+        // Rounds up a partial second
+        assert_eq!(QueueSummary::new([Some(65)], 64).calc_eta(), 2);
+        // Nothing was transferred yet
+        let summary = QueueSummary::new([Some(1024)], 0);
+        assert_eq!(summary.calc_eta(), 0);
+        assert_eq!(summary.to_string(), "1 entry, 1.0 KiB - ETA --:--");
+        // Empty queue
+        let summary = QueueSummary::new([], 64);
+        assert_eq!(summary.to_string(), "0 entries, 0 B - ETA --:--");
+    }
+
+    #[test]
+    fn should_summarize_queue_with_unknown_sizes() {
+        let summary = QueueSummary::new([Some(1024), None], 64);
+        assert_eq!(summary.entries, 2);
+        assert_eq!(summary.size, 1024);
+        assert_eq!(summary.unknown, true);
+        assert_eq!(summary.calc_eta(), 0);
+        assert_eq!(summary.to_string(), "2 entries, >= 1.0 KiB - ETA --:--");
+        // No size is known
+        let summary = QueueSummary::new([None, None], 64);
+        assert_eq!(summary.to_string(), "2 entries, ? - ETA --:--");
+    }
+
     #[test]
     fn test_ui_activities_filetransfer_lib_transfer_states() {
         let mut states: TransferStates = TransferStates::default();
@@ -251,5 +424,92 @@ mod test {
         assert!(opts.save_as.is_none());
         let opts = TransferOpts::default().save_as(Some("omar.txt"));
         assert_eq!(opts.save_as.as_deref().unwrap(), "omar.txt");
+        assert!(opts.rsync.is_none());
+        let opts = TransferOpts::default().rsync(Some(true));
+        assert_eq!(opts.rsync, Some(true));
+    }
+
+    #[test]
+    fn should_prompt_on_replace_with_save_as() {
+        let mut config = ConfigClient::degraded();
+        config.set_prompt_on_file_replace(false);
+        config.set_prompt_on_save_as_replace(true);
+        // An existing target is confirmed when saving as, a plain transfer is not
+        assert!(
+            TransferOpts::default()
+                .save_as(Some("omar.txt"))
+                .prompt_on_replace(&config)
+        );
+        assert!(!TransferOpts::default().prompt_on_replace(&config));
+        config.set_prompt_on_save_as_replace(false);
+        assert!(
+            !TransferOpts::default()
+                .save_as(Some("omar.txt"))
+                .prompt_on_replace(&config)
+        );
+    }
+
+    #[test]
+    fn should_prompt_on_replace_with_prompt_on_file_replace() {
+        let mut config = ConfigClient::degraded();
+        config.set_prompt_on_file_replace(true);
+        config.set_prompt_on_save_as_replace(false);
+        assert!(TransferOpts::default().prompt_on_replace(&config));
+        assert!(
+            TransferOpts::default()
+                .save_as(Some("omar.txt"))
+                .prompt_on_replace(&config)
+        );
+    }
+
+    fn entry(path: &str, file_type: FileType) -> File {
+        File {
+            path: PathBuf::from(path),
+            metadata: Metadata {
+                file_type,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn should_open_file_after_successful_transfer() {
+        let opts = TransferOpts::default().open(true);
+        let file = entry("/home/pi/notes.txt", FileType::File);
+        assert_eq!(
+            opts.path_to_open(&file, Path::new("/tmp"), &Ok::<(), String>(())),
+            Some(PathBuf::from("/tmp/notes.txt"))
+        );
+        // with save as
+        let opts = TransferOpts::default().open(true).save_as(Some("todo.txt"));
+        assert_eq!(
+            opts.path_to_open(&file, Path::new("/tmp"), &Ok::<(), String>(())),
+            Some(PathBuf::from("/tmp/todo.txt"))
+        );
+    }
+
+    #[test]
+    fn should_not_open_file_after_failed_transfer() {
+        let opts = TransferOpts::default().open(true);
+        let file = entry("/home/pi/notes.txt", FileType::File);
+        assert_eq!(
+            opts.path_to_open(&file, Path::new("/tmp"), &Err(String::from("aborted"))),
+            None
+        );
+    }
+
+    #[test]
+    fn should_not_open_transferred_directory_or_without_option() {
+        let opts = TransferOpts::default().open(true);
+        let dir = entry("/home/pi/docs", FileType::Directory);
+        assert_eq!(
+            opts.path_to_open(&dir, Path::new("/tmp"), &Ok::<(), String>(())),
+            None
+        );
+        let file = entry("/home/pi/notes.txt", FileType::File);
+        assert_eq!(
+            TransferOpts::default().path_to_open(&file, Path::new("/tmp"), &Ok::<(), String>(())),
+            None
+        );
     }
 }
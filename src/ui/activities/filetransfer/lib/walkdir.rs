@@ -1,4 +1,284 @@
+//! ## Walkdir
+//!
+//! `walkdir` collects the entries of a directory tree, reporting the progress of the scan
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use remotefs::fs::File;
+
+/// Minimum interval between two progress reports of a walk
+pub const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
 #[derive(Debug, Default)]
 pub struct WalkdirStates {
     pub aborted: bool,
 }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WalkdirError {
+    Aborted,
+    Error(String),
+}
+
+/// Progress of a walk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalkdirProgress {
+    /// Entries found so far
+    pub entries: usize,
+    /// Last scanned directory
+    pub path: PathBuf,
+}
+
+/// Walk the tree at `root` with `list_dir`, returning all of its entries but `root`.
+///
+/// Entries of a directory come before the entries of its subdirectories, which are scanned in listing order.
+/// `on_progress` is called after a directory has been scanned, if `interval` has elapsed since the last report,
+/// and once more when the walk is complete, with the final count. If it returns an error, the walk stops.
+/// `is_cancelled` is checked before scanning each directory and before returning; once it returns `true`,
+/// the walk fails with [`WalkdirError::Aborted`] and the entries collected so far are discarded.
+pub fn walkdir<C, L, P, X>(
+    ctx: &mut C,
+    root: &Path,
+    interval: Duration,
+    list_dir: L,
+    mut on_progress: P,
+    is_cancelled: X,
+) -> Result<Vec<File>, WalkdirError>
+where
+    L: Fn(&mut C, &Path) -> Result<Vec<File>, String>,
+    P: FnMut(&mut C, &WalkdirProgress) -> Result<(), WalkdirError>,
+    X: Fn(&C) -> bool,
+{
+    let mut acc = Vec::with_capacity(32_768);
+    let mut last_report: Option<Instant> = None;
+    // directories to scan; the next one is on top
+    let mut stack = vec![root.to_path_buf()];
+    let mut progress = WalkdirProgress {
+        entries: 0,
+        path: root.to_path_buf(),
+    };
+    while let Some(path) = stack.pop() {
+        if is_cancelled(ctx) {
+            return Err(WalkdirError::Aborted);
+        }
+        let entries = list_dir(ctx, &path).map_err(WalkdirError::Error)?;
+        stack.extend(
+            entries
+                .iter()
+                .rev()
+                .filter(|entry| entry.is_dir())
+                .map(|entry| entry.path.clone()),
+        );
+        acc.extend(entries);
+        progress = WalkdirProgress {
+            entries: acc.len(),
+            path,
+        };
+        if last_report.is_none_or(|t| t.elapsed() >= interval) {
+            last_report = Some(Instant::now());
+            on_progress(ctx, &progress)?;
+        }
+    }
+    on_progress(ctx, &progress)?;
+    if is_cancelled(ctx) {
+        return Err(WalkdirError::Aborted);
+    }
+
+    Ok(acc)
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+    use remotefs::fs::{FileType, Metadata};
+
+    use super::*;
+
+    /// Directory tree, as a map between directories and their entries
+    struct Tree {
+        dirs: HashMap<PathBuf, Vec<File>>,
+        reports: Vec<WalkdirProgress>,
+        /// Listed directories
+        listed: usize,
+        /// Cancel the walk once this number of directories has been listed
+        cancel_after: Option<usize>,
+    }
+
+    impl Tree {
+        fn new(entries: &[&str]) -> Self {
+            let mut dirs: HashMap<PathBuf, Vec<File>> = HashMap::new();
+            dirs.insert(PathBuf::from("/"), Vec::new());
+            for entry in entries {
+                let is_dir = entry.ends_with('/');
+                let path = PathBuf::from(entry.trim_end_matches('/'));
+                if is_dir {
+                    dirs.insert(path.clone(), Vec::new());
+                }
+                let file = File {
+                    path: path.clone(),
+                    metadata: Metadata {
+                        file_type: if is_dir {
+                            FileType::Directory
+                        } else {
+                            FileType::File
+                        },
+                        ..Default::default()
+                    },
+                };
+                dirs.get_mut(path.parent().unwrap()).unwrap().push(file);
+            }
+            Self {
+                dirs,
+                reports: Vec::new(),
+                listed: 0,
+                cancel_after: None,
+            }
+        }
+
+        fn list_dir(&mut self, path: &Path) -> Result<Vec<File>, String> {
+            self.listed += 1;
+            self.dirs
+                .get(path)
+                .cloned()
+                .ok_or_else(|| format!("no such directory: {}", path.display()))
+        }
+
+        fn walk(&mut self, interval: Duration) -> Result<Vec<File>, WalkdirError> {
+            walkdir(
+                self,
+                Path::new("/"),
+                interval,
+                Tree::list_dir,
+                |tree, progress| {
+                    tree.reports.push(progress.clone());
+                    Ok(())
+                },
+                Tree::is_cancelled,
+            )
+        }
+
+        fn is_cancelled(&self) -> bool {
+            self.cancel_after.is_some_and(|n| self.listed >= n)
+        }
+    }
+
+    const TREE: &[&str] = &[
+        "/a/",
+        "/a/b/",
+        "/a/b/1.txt",
+        "/a/2.txt",
+        "/c/",
+        "/c/3.txt",
+        "/4.txt",
+    ];
+
+    #[test]
+    fn should_walk_tree() {
+        let mut tree = Tree::new(TREE);
+        let files = tree.walk(Duration::ZERO).unwrap();
+        assert_eq!(
+            files.iter().map(|x| x.path.clone()).collect::<Vec<_>>(),
+            [
+                "/a",
+                "/c",
+                "/4.txt",
+                "/a/b",
+                "/a/2.txt",
+                "/a/b/1.txt",
+                "/c/3.txt"
+            ]
+            .iter()
+            .map(PathBuf::from)
+            .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_report_progress_and_final_count() {
+        let mut tree = Tree::new(TREE);
+        let files = tree.walk(Duration::ZERO).unwrap();
+        // one report per directory, then the final one
+        assert_eq!(
+            tree.reports
+                .iter()
+                .map(|x| (x.entries, x.path.to_string_lossy().to_string()))
+                .collect::<Vec<_>>(),
+            vec![
+                (3, String::from("/")),
+                (5, String::from("/a")),
+                (6, String::from("/a/b")),
+                (7, String::from("/c")),
+                (7, String::from("/c")),
+            ]
+        );
+        assert_eq!(tree.reports.last().unwrap().entries, files.len());
+        assert_eq!(files.len(), TREE.len());
+    }
+
+    #[test]
+    fn should_throttle_progress() {
+        let mut tree = Tree::new(TREE);
+        let files = tree.walk(Duration::from_secs(3600)).unwrap();
+        // first and final report only
+        assert_eq!(tree.reports.len(), 2);
+        assert_eq!(tree.reports[0].entries, 3);
+        assert_eq!(tree.reports[1].entries, files.len());
+    }
+
+    #[test]
+    fn should_stop_walk_on_progress_error() {
+        let mut tree = Tree::new(TREE);
+        let mut reports = 0;
+        let result = walkdir(
+            &mut tree,
+            Path::new("/"),
+            Duration::ZERO,
+            Tree::list_dir,
+            |_, _| {
+                reports += 1;
+                Err(WalkdirError::Aborted)
+            },
+            |_| false,
+        );
+        assert_eq!(result.unwrap_err(), WalkdirError::Aborted);
+        assert_eq!(reports, 1);
+    }
+
+    #[test]
+    fn should_stop_walk_when_cancelled() {
+        let mut tree = Tree::new(TREE);
+        tree.cancel_after = Some(2);
+        assert_eq!(
+            tree.walk(Duration::ZERO).unwrap_err(),
+            WalkdirError::Aborted
+        );
+        // "/" and "/a" have been listed; "/a/b" and "/c" haven't
+        assert_eq!(tree.listed, 2);
+        assert_eq!(tree.reports.len(), 2);
+    }
+
+    #[test]
+    fn should_discard_walk_cancelled_on_last_directory() {
+        let mut tree = Tree::new(TREE);
+        tree.cancel_after = Some(4);
+        assert_eq!(
+            tree.walk(Duration::ZERO).unwrap_err(),
+            WalkdirError::Aborted
+        );
+        assert_eq!(tree.listed, 4);
+    }
+
+    #[test]
+    fn should_fail_walk_on_list_error() {
+        let mut tree = Tree::new(TREE);
+        tree.dirs.remove(Path::new("/a/b"));
+        assert_eq!(
+            tree.walk(Duration::ZERO).unwrap_err(),
+            WalkdirError::Error(String::from("no such directory: /a/b"))
+        );
+    }
+}
@@ -1,4 +1,3 @@
-use std::env;
 use std::path::{Path, PathBuf};
 
 use bytesize::ByteSize;
@@ -7,13 +6,17 @@ use tuirealm::props::{
     TextSpan,
 };
 use tuirealm::{PollStrategy, Update};
+use unicode_width::UnicodeWidthStr;
 
-use super::browser::FileExplorerTab;
+use super::browser::{FileExplorerTab, FoundExplorerTab, FuzzyHighlighter};
+use super::lib::file_class::FileClass;
+use super::lib::session_bookmark::{explorer_prefs, save_session_last_dirs};
 use super::{ConfigClient, FileTransferActivity, Id, LogLevel, LogRecord, TransferPayload};
+use crate::explorer::FocusedPanel;
 use crate::filetransfer::{HostBridgeParams, ProtocolParams};
 use crate::system::environment;
 use crate::system::notifications::Notification;
-use crate::utils::fmt::{fmt_millis, fmt_path_elide_ex};
+use crate::utils::fmt::{fmt_millis, fmt_path_elide_ex, fmt_path_tail};
 use crate::utils::path;
 
 const LOG_CAPACITY: usize = 256;
@@ -25,6 +28,8 @@ impl FileTransferActivity {
             Ok(messages) => {
                 if !messages.is_empty() {
                     self.redraw = true;
+                    // Interacting with the confirmation popup cancels its timeout
+                    self.confirm_timeout.cancel();
                 }
                 for msg in messages.into_iter() {
                     let mut msg = Some(msg);
@@ -37,6 +42,12 @@ impl FileTransferActivity {
                 self.mount_error(format!("Application error: {err}"));
             }
         }
+        // Select the safe option of the confirmation popup once its timeout has elapsed
+        let mut msg = self.confirm_timeout.poll();
+        while msg.is_some() {
+            self.redraw = true;
+            msg = self.update(msg);
+        }
     }
 
     /// Add message to log events
@@ -89,10 +100,67 @@ impl FileTransferActivity {
         }
     }
 
-    /// Set text editor to use
-    pub(super) fn setup_text_editor(&self) {
-        unsafe {
-            env::set_var("EDITOR", self.config().get_text_editor());
+    /// Store the current panel split ratio in the configuration
+    pub(super) fn save_panel_split_ratio(&mut self) {
+        let ratio = self.browser.split_ratio();
+        let config = self.context_mut().config_mut();
+        config.set_panel_split_ratio(ratio);
+        if let Err(err) = config.write_config() {
+            error!("Could not save panel split ratio: {}", err);
+        }
+        self.redraw = true;
+    }
+
+    /// Store the working directories against the bookmark the session was opened from, if any,
+    /// so that they're restored on the next connection to it.
+    /// If enabled, the sorting and the filter of the explorers are stored too
+    pub(super) fn save_bookmark_last_dirs(&mut self) {
+        if !self.remote_connected {
+            return;
+        }
+        let Some(name) = self.context().bookmark_name().map(String::from) else {
+            return;
+        };
+        let remote_wrkdir = self.remote().wrkdir.clone();
+        let local_wrkdir: Option<PathBuf> = self
+            .host_bridge
+            .is_localhost()
+            .then(|| self.host_bridge().wrkdir.clone());
+        let explorers = self.config().get_bookmark_sort_and_filter().then(|| {
+            (
+                explorer_prefs(self.remote()),
+                explorer_prefs(self.host_bridge()),
+            )
+        });
+        let Some(client) = self.context_mut().bookmarks_client_mut() else {
+            return;
+        };
+        if let Some((remote, local)) = explorers {
+            client.set_bookmark_last_explorers(&name, remote, local);
+        }
+        if let Err(err) =
+            save_session_last_dirs(client, &name, &remote_wrkdir, local_wrkdir.as_deref())
+        {
+            error!(
+                "Could not save last directories of bookmark {}: {}",
+                name, err
+            );
+        }
+    }
+
+    /// Store the focused panel in the configuration, if it must be restored on the next connection
+    pub(super) fn save_last_focused_panel(&mut self) {
+        if !self.config().get_restore_focused_panel() {
+            return;
+        }
+        let panel = match self.browser.tab() {
+            FileExplorerTab::HostBridge | FileExplorerTab::FindHostBridge => FocusedPanel::Local,
+            FileExplorerTab::Remote | FileExplorerTab::FindRemote => FocusedPanel::Remote,
+        };
+        let config = self.context_mut().config_mut();
+        config.set_last_focused_panel(panel);
+        if let Err(err) = config.write_config() {
+            error!("Could not save last focused panel: {}", err);
         }
     }
 
@@ -258,21 +326,15 @@ impl FileTransferActivity {
 
         let hostname: String = format!(
             "{hostname}:{} ",
-            fmt_path_elide_ex(
-                self.host_bridge().wrkdir.as_path(),
-                width,
-                hostname.len() + 3
-            ) // 3 because of '/…/'
+            self.fmt_title_path(self.host_bridge().wrkdir.as_path(), width, &hostname)
         );
         let files: Vec<Vec<TextSpan>> = self
             .host_bridge()
             .iter_files()
             .map(|x| {
                 let mut span = TextSpan::from(self.host_bridge().fmt_file(x));
-                // Use different color for directories
-                if x.is_dir() {
-                    span.fg = Color::LightCyan;
-                }
+                // Color by file type, like `ls --color`
+                span.fg = FileClass::of(x).color(self.theme());
                 if self.host_bridge().enqueued().contains_key(x.path()) {
                     span.modifiers |=
                         TextModifiers::REVERSED | TextModifiers::UNDERLINED | TextModifiers::ITALIC;
@@ -302,6 +364,16 @@ impl FileTransferActivity {
         );
     }
 
+    /// Format the working directory for the title of an explorer `width` columns wide, after `hostname:`
+    fn fmt_title_path(&self, wrkdir: &Path, width: usize, hostname: &str) -> String {
+        if self.config().get_title_path_tail() {
+            // 2 because of ':' and the trailing space
+            fmt_path_tail(wrkdir, width.saturating_sub(hostname.width() + 2))
+        } else {
+            fmt_path_elide_ex(wrkdir, width, hostname.len() + 3) // 3 because of '/…/'
+        }
+    }
+
     /// Update remote file list
     pub(super) fn update_remote_filelist(&mut self) {
         self.reload_remote_dir();
@@ -384,21 +456,15 @@ impl FileTransferActivity {
         let hostname: String = format!(
             "{}:{} ",
             hostname,
-            fmt_path_elide_ex(
-                self.remote().wrkdir.as_path(),
-                width,
-                hostname.len() + 3 // 3 because of '/…/'
-            )
+            self.fmt_title_path(self.remote().wrkdir.as_path(), width, &hostname)
         );
         let files: Vec<Vec<TextSpan>> = self
             .remote()
             .iter_files()
             .map(|x| {
                 let mut span = TextSpan::from(self.remote().fmt_file(x));
-                // Use different color for directories
-                if x.is_dir() {
-                    span.fg = Color::LightCyan;
-                }
+                // Color by file type, like `ls --color`
+                span.fg = FileClass::of(x).color(self.theme());
                 if self.remote().enqueued().contains_key(x.path()) {
                     span.modifiers |=
                         TextModifiers::REVERSED | TextModifiers::UNDERLINED | TextModifiers::ITALIC;
@@ -553,21 +619,40 @@ impl FileTransferActivity {
     }
 
     pub(super) fn update_find_list(&mut self) {
+        // Highlight the chars matching the fuzzy search
+        let mut highlighter = self
+            .browser
+            .fuzzy_search_needle()
+            .filter(|needle| !needle.is_empty())
+            .map(FuzzyHighlighter::new);
+        let highlight_color = match self.browser.found_tab() {
+            Some(FoundExplorerTab::Remote) => self.theme().transfer_remote_explorer_highlighted,
+            _ => self.theme().transfer_local_explorer_highlighted,
+        };
         let files: Vec<Vec<TextSpan>> = self
             .found()
             .unwrap()
             .iter_files()
             .map(|x| {
-                let mut span = TextSpan::from(self.found().unwrap().fmt_file(x));
-                // Use different color for directories
-                if x.is_dir() {
-                    span.fg = Color::LightCyan;
-                }
-                if self.found().unwrap().enqueued().contains_key(x.path()) {
-                    span.modifiers |=
-                        TextModifiers::REVERSED | TextModifiers::UNDERLINED | TextModifiers::ITALIC;
+                let text = self.found().unwrap().fmt_file(x);
+                let indices = highlighter
+                    .as_mut()
+                    .map(|h| h.match_indices(&text))
+                    .unwrap_or_default();
+                let mut row = Self::highlight_chars(&text, &indices, highlight_color);
+                let class_color = FileClass::of(x).color(self.theme());
+                for span in row.iter_mut() {
+                    // Color by file type, like `ls --color`, but keep the fuzzy search highlight
+                    if span.fg == Color::Reset {
+                        span.fg = class_color;
+                    }
+                    if self.found().unwrap().enqueued().contains_key(x.path()) {
+                        span.modifiers |= TextModifiers::REVERSED
+                            | TextModifiers::UNDERLINED
+                            | TextModifiers::ITALIC;
+                    }
                 }
-                vec![span]
+                row
             })
             .collect();
         assert!(
@@ -581,6 +666,36 @@ impl FileTransferActivity {
         );
     }
 
+    /// Split `text` into spans, rendering the chars at `indices` in bold with `color`
+    fn highlight_chars(text: &str, indices: &[usize], color: Color) -> Vec<TextSpan> {
+        let mut spans: Vec<TextSpan> = Vec::new();
+        let mut chunk = String::new();
+        let mut chunk_matched = false;
+        for (i, ch) in text.chars().enumerate() {
+            let matched = indices.binary_search(&i).is_ok();
+            if matched != chunk_matched && !chunk.is_empty() {
+                spans.push(Self::highlight_span(
+                    std::mem::take(&mut chunk),
+                    chunk_matched,
+                    color,
+                ));
+            }
+            chunk_matched = matched;
+            chunk.push(ch);
+        }
+        if !chunk.is_empty() || spans.is_empty() {
+            spans.push(Self::highlight_span(chunk, chunk_matched, color));
+        }
+        spans
+    }
+
+    fn highlight_span(text: String, matched: bool, color: Color) -> TextSpan {
+        match matched {
+            true => TextSpan::from(text).fg(color).bold(),
+            false => TextSpan::from(text),
+        }
+    }
+
     pub(super) fn update_browser_file_list(&mut self) {
         match self.browser.tab() {
             FileExplorerTab::HostBridge | FileExplorerTab::FindHostBridge => {
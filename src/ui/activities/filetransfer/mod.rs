@@ -21,6 +21,12 @@ use std::time::Duration;
 use chrono::{DateTime, Local};
 use lib::browser;
 use lib::browser::Browser;
+use lib::chmod::ExecuteMode;
+use lib::clipboard::Clipboard;
+use lib::confirm::ConfirmTimeout;
+use lib::keepalive::Keepalive;
+use lib::rsync::RsyncSupport;
+use lib::sibling::SiblingDirection;
 use lib::transfer::{TransferOpts, TransferStates};
 use lib::walkdir::WalkdirStates;
 use remotefs::RemoteFs;
@@ -33,7 +39,8 @@ use crate::config::keybindings::KeyBindings;
 use crate::config::themes::Theme;
 use crate::explorer::{FileExplorer, FileSorting};
 use crate::filetransfer::{
-    FileTransferParams, HostBridgeBuilder, HostBridgeParams, RemoteFsBuilder,
+    FileTransferParams, FileTransferProtocol, HostBridgeBuilder, HostBridgeParams, ProtocolParams,
+    RemoteFsBuilder,
 };
 use crate::host::HostBridge;
 use crate::system::config_client::ConfigClient;
@@ -49,14 +56,22 @@ pub enum MarkQueue {
 
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
 enum Id {
+    BookmarkName,
+    BookmarkSavePassword,
     ChmodPopup,
+    CompressFormat,
+    CompressName,
+    ConnectionSummaryPopup,
     CopyPopup,
     DeletePopup,
+    DirBookmarksList,
     DisconnectPopup,
+    DuplicatePopup,
     ErrorPopup,
     ExplorerFind,
     ExplorerHostBridge,
     ExplorerRemote,
+    FanoutPopup,
     FatalPopup,
     FileInfoPopup,
     FilterPopup,
@@ -65,20 +80,25 @@ enum Id {
     GotoPopup,
     KeybindingsPopup,
     Log,
+    MirrorPopup,
     MkdirPopup,
     NewfilePopup,
     OpenWithPopup,
+    Preview,
     ProgressBarFull,
     ProgressBarPartial,
     QuitPopup,
     RenamePopup,
     ReplacePopup,
     SaveAsPopup,
+    SelectPatternPopup,
     SortingPopup,
     StatusBarHostBridge,
     StatusBarRemote,
     SymlinkPopup,
     SyncBrowsingMkdirPopup,
+    SyncConflictPopup,
+    SyncPopup,
     TerminalHostBridge,
     TerminalRemote,
     TransferQueueHostBridge,
@@ -99,54 +119,117 @@ enum Msg {
 #[derive(Debug, PartialEq)]
 enum PendingActionMsg {
     CloseSyncBrowsingMkdirPopup,
+    /// Connection summary popup
+    Connect,
+    ConnectCancel,
+    /// Fanout popup
+    Fanout,
+    FanoutCancel,
     MakePendingDirectory,
+    /// Mirror popup
+    Mirror,
+    MirrorAndDelete,
+    MirrorCancel,
     /// Replace file popup
     ReplaceCancel,
     ReplaceOverwrite,
     ReplaceOverwriteAll,
     ReplaceSkip,
     ReplaceSkipAll,
+    /// Sync popups
+    Sync,
+    SyncCancel,
+    SyncKeepLocal,
+    SyncKeepRemote,
+    SyncSkip,
 }
 
 #[derive(Debug, PartialEq)]
 enum TransferMsg {
     AbortWalkdir,
     AbortTransfer,
+    Bisync,
     Chmod(remotefs::fs::UnixPex),
+    /// Change the mode of the selected files and of the contents of the selected directories
+    ChmodRecursive(remotefs::fs::UnixPex, ExecuteMode),
+    /// Compress the selected files into an archive
+    CompressSelected,
     CopyFileTo(String),
+    /// Copy the path of the selected file to the clipboard
+    CopyPathToClipboard,
     CreateSymlink(String),
+    /// Cut the selected files, to move them once pasted
+    CutFile,
     DeleteFile,
+    /// Delete the directory bookmark at the index of the list
+    DeleteDirBookmark(usize),
+    /// Copy the selected file in its own directory, with the provided name
+    DuplicateFile(String),
     EnterDirectory,
     ExecuteCmd(String),
+    /// Extract the selected archive into the working directory
+    ExtractArchive,
+    Fanout,
     GetFileSize,
+    /// Go forward to the directory left with go back
+    GoForwardDirectory,
     GoTo(String),
+    /// Connect to the directory bookmark at the index of the list and go to its directory
+    GoToDirBookmark(usize),
+    /// Go to the working directory of the other panel
+    GoToOtherPanelDirectory,
+    /// Go to the home directory of the user on the host of the current panel
+    GoToHomeDirectory,
     GoToParentDirectory,
     GoToPreviousDirectory,
+    GoToSiblingDirectory(SiblingDirection),
+    /// Go to the directory marked with the char
+    GotoDirMark(char),
     InitFuzzySearch,
+    /// Move the cursor to the next entry starting with the char, wrapping around
+    JumpToEntryStartingWith(char),
+    Mirror,
     Mkdir(String),
     NewFile(String),
     OpenFile,
     OpenFileWith(String),
     OpenTextFile,
+    /// Move the cut files into the working directory of the current panel
+    PasteFiles,
     ReloadDir,
     RenameFile(String),
     RescanGotoFiles(PathBuf),
+    /// Save the current session as a bookmark
+    SaveBookmark,
+    /// Bookmark the remote working directory
+    SaveDirBookmark,
     SaveFileAs(String),
+    /// Mark the working directory with the char
+    SetDirMark(char),
     ToggleWatch,
     ToggleWatchFor(usize),
+    /// Download the selected file and open it
+    TransferAndOpenFile,
     TransferFile,
+    TransferFileRsync,
 }
 
 #[derive(Debug, PartialEq)]
 enum UiMsg {
+    BookmarkNameBlur,
     BottomPanelLeft,
     BottomPanelRight,
     ChangeFileSorting(FileSorting),
     ChangeTransferWindow,
+    CompressFormatBlur,
+    CompressNameBlur,
     CloseChmodPopup,
+    CloseCompressPopup,
     CloseCopyPopup,
     CloseDeletePopup,
+    CloseDirBookmarksList,
     CloseDisconnectPopup,
+    CloseDuplicatePopup,
     CloseErrorPopup,
     CloseExecPopup,
     CloseFatalPopup,
@@ -162,12 +245,21 @@ enum UiMsg {
     CloseQuitPopup,
     CloseRenamePopup,
     CloseSaveAsPopup,
+    CloseSaveBookmarkPopup,
+    CloseSelectPatternPopup,
     CloseSymlinkPopup,
     CloseWatchedPathsList,
     CloseWatcherPopup,
+    /// Copy the paths of the marked files to the clipboard
+    CopyMarkedPaths,
     Disconnect,
     FilterFiles(String),
+    /// The footer shows another page of hints
+    FooterPageChanged,
+    /// Search the find results with a regex
+    FindWithRegex(String),
     FuzzySearch(String),
+    GrowPanel,
     LogBackTabbed,
     /// Mark file on the list; usize is the index of the file
     MarkFile(usize),
@@ -176,12 +268,20 @@ enum UiMsg {
     MarkAll,
     /// Clear all marks
     MarkClear,
+    /// Invert the marks of the files at tab
+    InvertSelection,
+    /// Mark the files at tab matching the glob pattern
+    SelectByPattern(String),
 
     Quit,
+    SaveBookmarkPasswordBlur,
     ShowChmodPopup,
+    ShowCompressPopup,
     ShowCopyPopup,
     ShowDeletePopup,
+    ShowDirBookmarksList,
     ShowDisconnectPopup,
+    ShowDuplicatePopup,
     ShowTerminal,
     ShowFileInfoPopup,
     ShowFileSortingPopup,
@@ -195,10 +295,23 @@ enum UiMsg {
     ShowQuitPopup,
     ShowRenamePopup,
     ShowSaveAsPopup,
+    ShowSaveBookmarkPopup,
+    ShowSaveDirBookmarkPopup,
+    ShowSelectPatternPopup,
     ShowSymlinkPopup,
     ShowWatchedPathsList,
     ShowWatcherPopup,
+    ShrinkPanel,
+    /// Toggle the case sensitivity of the regex search in the find results
+    ToggleFindCaseSensitive,
+    ToggleFooter,
     ToggleHiddenFiles,
+    ToggleHiddenPatterns,
+    ToggleRawSizes,
+    ToggleLog,
+    ToggleMaximizePanel,
+    /// Show or hide the preview of the highlighted file
+    TogglePreviewPane,
     ToggleSyncBrowsing,
     WindowResized,
 }
@@ -258,6 +371,24 @@ pub struct FileTransferActivity {
     host_bridge_connected: bool,
     /// remote connected once
     remote_connected: bool,
+    /// whether the connection summary has already been confirmed (or is disabled)
+    connection_confirmed: bool,
+    /// rsync support for the current session; detected on first rsync transfer
+    rsync: Option<RsyncSupport>,
+    /// Home directory of the user on the host bridge
+    host_bridge_home: Option<PathBuf>,
+    /// Home directory of the user on the remote, which is the working directory once connected
+    remote_home: Option<PathBuf>,
+    /// Files cut from an explorer, waiting to be pasted
+    clipboard: Option<Clipboard>,
+    /// Timeout of the mounted confirmation popup
+    confirm_timeout: ConfirmTimeout<Msg>,
+    /// Keepalives to send to the remote while idle
+    keepalive: Keepalive,
+    /// Path of the file shown in the preview pane
+    previewed: Option<PathBuf>,
+    /// Commands recently used to open files with, from the most recent one
+    open_with_recents: Vec<String>,
 }
 
 impl FileTransferActivity {
@@ -273,6 +404,12 @@ impl FileTransferActivity {
         let host_bridge = HostBridgeBuilder::build(host_bridge_params, &config_client)?;
         let host_bridge_connected = host_bridge.is_localhost();
         let enable_fs_watcher = host_bridge.is_localhost();
+        // A remote host bridge's home is resolved once connected
+        let host_bridge_home = if host_bridge.is_localhost() {
+            dirs::home_dir()
+        } else {
+            None
+        };
         Ok(Self {
             exit_reason: None,
             context: None,
@@ -300,9 +437,33 @@ impl FileTransferActivity {
             },
             host_bridge_connected,
             remote_connected: false,
+            connection_confirmed: !config_client.get_connection_summary(),
+            rsync: None,
+            host_bridge_home,
+            remote_home: None,
+            clipboard: None,
+            confirm_timeout: ConfirmTimeout::new(
+                config_client
+                    .get_confirmation_timeout()
+                    .map(Duration::from_secs),
+            ),
+            keepalive: Keepalive::new(Self::keepalive_secs(remote_params)),
+            previewed: None,
+            open_with_recents: Vec::new(),
         })
     }
 
+    /// Get the interval to send keepalives to the remote with; only SSH protocols send keepalives
+    fn keepalive_secs(params: &FileTransferParams) -> u64 {
+        match (params.protocol, &params.params) {
+            (
+                FileTransferProtocol::Scp | FileTransferProtocol::Sftp,
+                ProtocolParams::Generic(params),
+            ) => params.keepalive_secs,
+            _ => 0,
+        }
+    }
+
     fn host_bridge(&self) -> &FileExplorer {
         self.browser.host_bridge()
     }
@@ -359,6 +520,24 @@ impl FileTransferActivity {
         self.refresh_remote_transfer_queue();
     }
 
+    fn invert_queue(&mut self) {
+        let dest = self.browser.other_explorer_no_found().wrkdir.clone();
+        self.browser.explorer_mut().invert_queue(&dest);
+        self.reload_browser_file_list();
+        self.refresh_host_bridge_transfer_queue();
+        self.refresh_remote_transfer_queue();
+    }
+
+    /// Mark the files at tab matching `pattern`; returns the amount of matching files
+    fn enqueue_matching(&mut self, pattern: &str) -> usize {
+        let dest = self.browser.other_explorer_no_found().wrkdir.clone();
+        let matching = self.browser.explorer_mut().enqueue_matching(pattern, &dest);
+        self.reload_browser_file_list();
+        self.refresh_host_bridge_transfer_queue();
+        self.refresh_remote_transfer_queue();
+        matching
+    }
+
     fn clear_queue(&mut self) {
         self.browser.explorer_mut().clear_queue();
         self.reload_browser_file_list();
@@ -444,9 +623,6 @@ impl Activity for FileTransferActivity {
             self.reload_host_bridge_dir();
         }
         debug!("Read working directory");
-        // Configure text editor
-        self.setup_text_editor();
-        debug!("Setup text editor");
         // init view
         self.init();
         debug!("Initialized view");
@@ -465,6 +641,10 @@ impl Activity for FileTransferActivity {
         if self.context.is_none() {
             return;
         }
+        // Confirm the connection parameters before the first connection
+        if !self.connection_confirmed && !self.action_confirm_connection() {
+            return;
+        }
         // Check if connected to host bridge (popup must be None, otherwise would try reconnecting in loop in case of error)
         if (!self.host_bridge.is_connected() || !self.host_bridge_connected)
             && !self.app.mounted(&Id::FatalPopup)
@@ -499,6 +679,8 @@ impl Activity for FileTransferActivity {
         self.tick();
         // poll
         self.poll_watcher();
+        self.poll_keepalive();
+        self.poll_preview();
         // View
         if self.redraw {
             self.view();
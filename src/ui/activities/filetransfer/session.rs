@@ -11,7 +11,13 @@ use remotefs::fs::{File, Metadata, ReadStream, UnixPex, Welcome, WriteStream};
 use remotefs::{RemoteError, RemoteErrorType, RemoteResult};
 use thiserror::Error;
 
+use super::lib::connect_error::{ConnectErrorRoute, connect_error_route};
+use super::lib::rsync::{
+    RSYNC_BIN, RSYNC_REMOTE_CHECK_CMD, RsyncCommand, RsyncDirection, RsyncSupport, RsyncTarget,
+};
+use super::lib::session_bookmark::restore_explorer_prefs;
 use super::{FileTransferActivity, LogLevel};
+use crate::filetransfer::{FileTransferParams, RemoteFsBuilder};
 use crate::host::HostError;
 use crate::utils::fmt::fmt_millis;
 
@@ -37,7 +43,7 @@ enum TransferErrorReason {
 /// - File: describes an individual `File` to send
 /// - Any: Can be any kind of `File`, but just one
 /// - Many: a list of `File`
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(super) enum TransferPayload {
     File(File),
     Any(File),
@@ -66,13 +72,22 @@ impl FileTransferActivity {
                     ),
                 );
 
-                // Try to change directory to entry directory
-                let mut remote_chdir: Option<PathBuf> = None;
-                if let Some(remote_path) = &entry_dir {
-                    remote_chdir = Some(remote_path.clone());
-                }
-                if let Some(remote_path) = remote_chdir {
-                    self.local_changedir(remote_path.as_path(), false);
+                // The working directory on login is the home directory
+                self.host_bridge_home = self.host_bridge.pwd().ok();
+                // Restore the directory the bookmark was left at, otherwise go to the entry directory
+                let last_dir = match self.host_bridge.is_localhost() {
+                    true => self.bookmark_last_dirs().1,
+                    false => None,
+                };
+                if !last_dir.is_some_and(|dir| self.restore_host_bridge_last_dir(&dir)) {
+                    // Try to change directory to entry directory
+                    let mut remote_chdir: Option<PathBuf> = None;
+                    if let Some(remote_path) = &entry_dir {
+                        remote_chdir = Some(remote_path.clone());
+                    }
+                    if let Some(remote_path) = remote_chdir {
+                        self.local_changedir(remote_path.as_path(), false);
+                    }
                 }
                 // Set state to explorer
                 self.umount_wait();
@@ -88,14 +103,31 @@ impl FileTransferActivity {
         }
     }
 
+    /// Send a keepalive to the remote, if due, by querying its working directory
+    pub(super) fn poll_keepalive(&mut self) {
+        if !self.keepalive.poll() || !self.remote_connected || !self.client.is_connected() {
+            return;
+        }
+        debug!("sending keepalive to remote");
+        let wrkdir = self.remote().wrkdir.clone();
+        if let Err(err) = self.client.stat(&wrkdir) {
+            self.log(LogLevel::Warn, format!("Keepalive failed: {err}"));
+        }
+    }
+
     /// Connect to remote
     pub(super) fn connect_to_remote(&mut self) {
         let ft_params = self.context().remote_params().unwrap().clone();
-        let entry_dir: Option<PathBuf> = ft_params.remote_path;
+        let entry_dir: Option<PathBuf> = ft_params.remote_path.clone();
         // Connect to remote
-        match self.client.connect() {
+        match RemoteFsBuilder::probe(ft_params.protocol, &ft_params.params)
+            .and_then(|_| self.client.connect())
+        {
             Ok(Welcome { banner, .. }) => {
                 self.remote_connected = self.client.is_connected();
+                // Remember the params to reconnect with from the authentication form
+                let connected = self.remote_connected;
+                self.context_mut().capture_last_connection(connected);
                 if !self.remote_connected {
                     return;
                 }
@@ -120,13 +152,20 @@ impl FileTransferActivity {
                         ),
                     );
                 }
-                // Try to change directory to entry directory
-                let mut remote_chdir: Option<PathBuf> = None;
-                if let Some(remote_path) = &entry_dir {
-                    remote_chdir = Some(remote_path.clone());
-                }
-                if let Some(remote_path) = remote_chdir {
-                    self.remote_changedir(remote_path.as_path(), false);
+                // The working directory on login is the home directory
+                self.remote_home = self.client.pwd().ok();
+                // Restore the directory the bookmark was left at, otherwise go to the entry directory
+                let last_dir = self.bookmark_last_dirs().0;
+                self.restore_bookmark_last_explorers();
+                if !last_dir.is_some_and(|dir| self.restore_remote_last_dir(&dir)) {
+                    // Try to change directory to entry directory
+                    let mut remote_chdir: Option<PathBuf> = None;
+                    if let Some(remote_path) = &entry_dir {
+                        remote_chdir = Some(remote_path.clone());
+                    }
+                    if let Some(remote_path) = remote_chdir {
+                        self.remote_changedir(remote_path.as_path(), false);
+                    }
                 }
                 // Set state to explorer
                 self.umount_wait();
@@ -135,10 +174,101 @@ impl FileTransferActivity {
                 self.update_host_bridge_filelist();
                 self.update_remote_filelist();
             }
+            Err(err) => match connect_error_route(&err, &ft_params) {
+                ConnectErrorRoute::RetryAuth(params) => {
+                    // Go back to authentication form to enter credentials again
+                    self.umount_wait();
+                    let ctx = self.context_mut();
+                    ctx.set_retry_params(*params);
+                    ctx.set_error(format!("Authentication failed: {err}"));
+                    self.exit_reason = Some(super::ExitReason::Disconnect);
+                }
+                ConnectErrorRoute::Fatal(msg) => {
+                    // Set popup fatal error
+                    self.umount_wait();
+                    self.mount_fatal(msg);
+                }
+            },
+        }
+    }
+
+    /// Get the remote and local directories the bookmark of the session was left at, if any
+    fn bookmark_last_dirs(&self) -> (Option<PathBuf>, Option<PathBuf>) {
+        match (
+            self.context().bookmark_name(),
+            self.context().bookmarks_client(),
+        ) {
+            (Some(name), Some(client)) => client.get_bookmark_last_dirs(name),
+            _ => (None, None),
+        }
+    }
+
+    /// Restore the sorting and the filter the explorers of the bookmark of the session were left with, if enabled
+    fn restore_bookmark_last_explorers(&mut self) {
+        if !self.config().get_bookmark_sort_and_filter() {
+            return;
+        }
+        let (remote, local) = match (
+            self.context().bookmark_name(),
+            self.context().bookmarks_client(),
+        ) {
+            (Some(name), Some(client)) => client.get_bookmark_last_explorers(name),
+            _ => return,
+        };
+        if let Some(prefs) = remote {
+            restore_explorer_prefs(self.remote_mut(), &prefs);
+        }
+        if let Some(prefs) = local {
+            restore_explorer_prefs(self.host_bridge_mut(), &prefs);
+        }
+        self.refresh_local_status_bar();
+        self.refresh_remote_status_bar();
+    }
+
+    /// Change the host bridge working directory to the one the bookmark was left at.
+    /// Returns whether it succeeded; if the directory doesn't exist anymore, it fails with a warning
+    fn restore_host_bridge_last_dir(&mut self, dir: &Path) -> bool {
+        match self.host_bridge.change_wrkdir(dir) {
+            Ok(_) => {
+                self.log(
+                    LogLevel::Info,
+                    format!("Restored last directory on host bridge: {}", dir.display()),
+                );
+                true
+            }
             Err(err) => {
-                // Set popup fatal error
-                self.umount_wait();
-                self.mount_fatal(err.to_string());
+                self.log(
+                    LogLevel::Warn,
+                    format!(
+                        "Could not restore last directory on host bridge {}: {err}",
+                        dir.display()
+                    ),
+                );
+                false
+            }
+        }
+    }
+
+    /// Change the remote working directory to the one the bookmark was left at.
+    /// Returns whether it succeeded; if the directory doesn't exist anymore, it fails with a warning
+    fn restore_remote_last_dir(&mut self, dir: &Path) -> bool {
+        match self.client.change_dir(dir) {
+            Ok(_) => {
+                self.log(
+                    LogLevel::Info,
+                    format!("Restored last directory on remote: {}", dir.display()),
+                );
+                true
+            }
+            Err(err) => {
+                self.log(
+                    LogLevel::Warn,
+                    format!(
+                        "Could not restore last directory on remote {}: {err}",
+                        dir.display()
+                    ),
+                );
+                false
             }
         }
     }
@@ -150,10 +280,21 @@ impl FileTransferActivity {
         self.mount_wait(msg.as_str());
         // Disconnect
         let _ = self.client.disconnect();
+        self.save_bookmark_last_dirs();
+        self.save_last_focused_panel();
         // Quit
         self.exit_reason = Some(super::ExitReason::Disconnect);
     }
 
+    /// disconnect from remote and then connect to the remote of `params`
+    pub(super) fn disconnect_and_connect(&mut self, params: FileTransferParams) {
+        self.disconnect();
+        let ctx = self.context_mut();
+        ctx.set_bookmark_name(None);
+        ctx.set_remote_params(params);
+        self.exit_reason = Some(super::ExitReason::Connect);
+    }
+
     /// disconnect from remote and then quit
     pub(super) fn disconnect_and_quit(&mut self) {
         self.disconnect();
@@ -720,6 +861,162 @@ impl FileTransferActivity {
         result
     }
 
+    /// Send payload to remote using rsync if `rsync` is set and both endpoints support it.
+    /// Falls back to [`Self::filetransfer_send`] otherwise, or for the entries rsync fails to transfer.
+    pub(super) fn filetransfer_send_with_rsync(
+        &mut self,
+        payload: TransferPayload,
+        curr_remote_path: &Path,
+        dst_name: Option<String>,
+        rsync: bool,
+    ) -> Result<(), String> {
+        if !rsync {
+            return self.filetransfer_send(payload, curr_remote_path, dst_name);
+        }
+        match self.rsync_transfer(
+            RsyncDirection::Upload,
+            &payload,
+            curr_remote_path,
+            dst_name.as_deref(),
+        ) {
+            None => {
+                self.notify_transfer_completed(&payload);
+                Ok(())
+            }
+            Some(fallback) => self.filetransfer_send(fallback, curr_remote_path, dst_name),
+        }
+    }
+
+    /// Receive payload from remote using rsync if `rsync` is set and both endpoints support it.
+    /// Falls back to [`Self::filetransfer_recv`] otherwise, or for the entries rsync fails to transfer.
+    pub(super) fn filetransfer_recv_with_rsync(
+        &mut self,
+        payload: TransferPayload,
+        host_bridge_path: &Path,
+        dst_name: Option<String>,
+        rsync: bool,
+    ) -> Result<(), String> {
+        if !rsync {
+            return self.filetransfer_recv(payload, host_bridge_path, dst_name);
+        }
+        match self.rsync_transfer(
+            RsyncDirection::Download,
+            &payload,
+            host_bridge_path,
+            dst_name.as_deref(),
+        ) {
+            None => {
+                self.notify_transfer_completed(&payload);
+                Ok(())
+            }
+            Some(fallback) => self.filetransfer_recv(fallback, host_bridge_path, dst_name),
+        }
+    }
+
+    /// Get rsync support for the current session, detecting it on first call
+    fn rsync_support(&mut self) -> RsyncSupport {
+        if let Some(support) = self.rsync {
+            return support;
+        }
+        let ft_params = self.context().remote_params().unwrap();
+        let target = RsyncTarget::from_params(ft_params.protocol, &ft_params.params);
+        let localhost = self.host_bridge.is_localhost();
+        let client = &mut self.client;
+        let support = RsyncSupport::detect(target.as_ref(), localhost, RSYNC_BIN, || {
+            matches!(client.exec(RSYNC_REMOTE_CHECK_CMD), Ok((0, _)))
+        });
+        debug!("rsync support for session: {:?}", support);
+        self.rsync = Some(support);
+        support
+    }
+
+    /// Transfer payload with rsync.
+    /// Returns the payload of the entries which must be transferred with the plain transfer, since rsync isn't
+    /// available or failed to transfer them; `None` if all the entries have been transferred
+    fn rsync_transfer(
+        &mut self,
+        direction: RsyncDirection,
+        payload: &TransferPayload,
+        dest_dir: &Path,
+        dst_name: Option<&str>,
+    ) -> Option<TransferPayload> {
+        let entries: Vec<(&File, PathBuf)> = match payload {
+            TransferPayload::Any(entry) => {
+                let mut dest = dest_dir.to_path_buf();
+                dest.push(dst_name.map(String::from).unwrap_or_else(|| entry.name()));
+                vec![(entry, dest)]
+            }
+            TransferPayload::TransferQueue(entries) => entries
+                .iter()
+                .map(|(entry, dest_dir)| {
+                    let mut dest = dest_dir.clone();
+                    dest.push(entry.name());
+                    (entry, dest)
+                })
+                .collect(),
+            TransferPayload::File(_) => return Some(payload.clone()),
+        };
+        let support = self.rsync_support();
+        if !support.is_available() {
+            self.log(
+                LogLevel::Warn,
+                format!("rsync is not available ({support:?}); falling back to plain transfer"),
+            );
+            return Some(payload.clone());
+        }
+        let ft_params = self.context().remote_params().unwrap();
+        let Some(target) = RsyncTarget::from_params(ft_params.protocol, &ft_params.params) else {
+            return Some(payload.clone());
+        };
+        let ssh_key = target.username.as_ref().and_then(|username| {
+            self.config()
+                .get_ssh_key(&format!("{username}@{}", target.address))
+                .map(|(_, _, key)| key)
+        });
+        let target = target.ssh_key(ssh_key);
+        self.mount_blocking_wait("Transferring file(s) with rsync…");
+        // entries rsync failed to transfer, with their destination directory
+        let mut failed: Vec<(File, PathBuf)> = Vec::new();
+        for (entry, dest) in entries {
+            let result = RsyncCommand::new(
+                RSYNC_BIN,
+                &target,
+                direction,
+                entry.path(),
+                dest.as_path(),
+                entry.is_dir(),
+            )
+            .run();
+            match result {
+                Ok(()) => self.log(
+                    LogLevel::Info,
+                    format!(
+                        "Synchronized \"{}\" with \"{}\" using rsync",
+                        entry.path().display(),
+                        dest.display()
+                    ),
+                ),
+                Err(err) => {
+                    self.log(
+                        LogLevel::Warn,
+                        format!(
+                            "rsync transfer of \"{}\" failed ({err}); falling back to plain transfer",
+                            entry.path().display()
+                        ),
+                    );
+                    let dest_dir = dest.parent().map(Path::to_path_buf).unwrap_or_default();
+                    failed.push((entry.clone(), dest_dir));
+                }
+            }
+        }
+        self.umount_wait();
+        match payload {
+            _ if failed.is_empty() => None,
+            TransferPayload::Any(entry) => Some(TransferPayload::Any(entry.clone())),
+            _ => Some(TransferPayload::TransferQueue(failed)),
+        }
+    }
+
     /// Recv fs entry from remote.
     /// If dst_name is Some, entry will be saved with a different name.
     /// If entry is a directory, this applies to directory only
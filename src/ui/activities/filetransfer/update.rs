@@ -10,6 +10,7 @@ use tuirealm::{State, StateValue, Update};
 use super::actions::SelectedFile;
 use super::actions::walkdir::WalkdirError;
 use super::browser::{FileExplorerTab, FoundExplorerTab};
+use super::lib::open_with::push_recent;
 use super::{
     ExitReason, FileTransferActivity, Id, MarkQueue, Msg, TransferMsg, TransferOpts, UiMsg,
 };
@@ -51,6 +52,21 @@ impl FileTransferActivity {
                 self.umount_wait();
                 self.update_browser_file_list();
             }
+            TransferMsg::ChmodRecursive(mode, execute) => {
+                self.umount_chmod();
+                let is_windows_host_bridge = matches!(
+                    self.browser.tab(),
+                    FileExplorerTab::HostBridge | FileExplorerTab::FindHostBridge
+                ) && self.host_bridge.is_localhost()
+                    && cfg!(windows);
+                if !is_windows_host_bridge {
+                    self.mount_abortable_wait("Changing mode…");
+                    self.action_chmod_recursive(mode, execute);
+                    self.umount_wait();
+                }
+                self.update_browser_file_list();
+            }
+            TransferMsg::CompressSelected => self.action_compress_selected(),
             TransferMsg::CopyFileTo(dest) => {
                 self.umount_copy();
                 self.mount_blocking_wait("Copying file(s)…");
@@ -63,6 +79,7 @@ impl FileTransferActivity {
                 // Reload files
                 self.update_browser_file_list()
             }
+            TransferMsg::CopyPathToClipboard => self.action_copy_path(),
             TransferMsg::CreateSymlink(name) => {
                 self.umount_symlink();
                 self.mount_blocking_wait("Creating symlink…");
@@ -75,6 +92,11 @@ impl FileTransferActivity {
                 // Reload files
                 self.update_browser_file_list()
             }
+            TransferMsg::CutFile => match self.browser.tab() {
+                FileExplorerTab::HostBridge => self.action_local_cut(),
+                FileExplorerTab::Remote => self.action_remote_cut(),
+                _ => {}
+            },
             TransferMsg::DeleteFile => {
                 self.umount_radio_delete();
                 self.mount_blocking_wait("Removing file(s)…");
@@ -113,6 +135,15 @@ impl FileTransferActivity {
                     FileExplorerTab::FindRemote => self.update_remote_filelist(),
                 }
             }
+            TransferMsg::DeleteDirBookmark(idx) => self.action_delete_dir_bookmark(idx),
+            TransferMsg::DuplicateFile(name) => {
+                self.umount_duplicate();
+                self.mount_blocking_wait("Duplicating file…");
+                self.action_duplicate(name);
+                self.umount_wait();
+                // Reload files
+                self.update_browser_file_list()
+            }
             TransferMsg::EnterDirectory if self.browser.tab() == FileExplorerTab::HostBridge => {
                 if let Some(entry) = self.get_local_selected_file() {
                     self.action_submit_local(entry);
@@ -155,6 +186,7 @@ impl FileTransferActivity {
             TransferMsg::GetFileSize => {
                 self.action_get_file_size();
             }
+            TransferMsg::GoToDirBookmark(idx) => self.action_go_to_dir_bookmark(idx),
             TransferMsg::GoTo(dir) => {
                 match self.browser.tab() {
                     FileExplorerTab::HostBridge => self.action_change_local_dir(dir),
@@ -212,6 +244,105 @@ impl FileTransferActivity {
                     _ => {}
                 }
             }
+            TransferMsg::GoForwardDirectory => {
+                match self.browser.tab() {
+                    FileExplorerTab::HostBridge => {
+                        self.action_go_forward_local_dir();
+                        if self.browser.sync_browsing && self.browser.found().is_none() {
+                            self.update_remote_filelist();
+                        }
+                        // Reload file list component
+                        self.update_host_bridge_filelist()
+                    }
+                    FileExplorerTab::Remote => {
+                        self.action_go_forward_remote_dir();
+                        if self.browser.sync_browsing && self.browser.found().is_none() {
+                            self.update_host_bridge_filelist();
+                        }
+                        // Reload file list component
+                        self.update_remote_filelist()
+                    }
+                    _ => {}
+                }
+            }
+            TransferMsg::GoToHomeDirectory => {
+                // No sync browsing: homes differ between hosts
+                match self.browser.tab() {
+                    FileExplorerTab::HostBridge => {
+                        self.action_go_to_local_home_dir();
+                        self.update_host_bridge_filelist()
+                    }
+                    FileExplorerTab::Remote => {
+                        self.action_go_to_remote_home_dir();
+                        self.update_remote_filelist()
+                    }
+                    _ => {}
+                }
+            }
+            TransferMsg::GotoDirMark(mark) => {
+                // No sync browsing: marks are set per panel
+                match self.browser.tab() {
+                    FileExplorerTab::HostBridge => {
+                        self.action_goto_dir_mark(mark);
+                        self.update_host_bridge_filelist()
+                    }
+                    FileExplorerTab::Remote => {
+                        self.action_goto_dir_mark(mark);
+                        self.update_remote_filelist()
+                    }
+                    _ => {}
+                }
+            }
+            TransferMsg::SetDirMark(mark) => {
+                self.action_set_dir_mark(mark);
+            }
+            TransferMsg::GoToOtherPanelDirectory => {
+                self.action_go_to_other_panel_dir();
+                match self.browser.tab() {
+                    FileExplorerTab::HostBridge => self.update_host_bridge_filelist(),
+                    FileExplorerTab::Remote => self.update_remote_filelist(),
+                    _ => {}
+                }
+            }
+            TransferMsg::GoToSiblingDirectory(direction) => {
+                match self.browser.tab() {
+                    FileExplorerTab::HostBridge => {
+                        self.action_go_to_local_sibling_dir(direction);
+                        if self.browser.sync_browsing && self.browser.found().is_none() {
+                            self.update_remote_filelist();
+                        }
+                        // Reload file list component
+                        self.update_host_bridge_filelist()
+                    }
+                    FileExplorerTab::Remote => {
+                        self.action_go_to_remote_sibling_dir(direction);
+                        if self.browser.sync_browsing && self.browser.found().is_none() {
+                            self.update_host_bridge_filelist();
+                        }
+                        // Reload file list component
+                        self.update_remote_filelist()
+                    }
+                    _ => {}
+                }
+            }
+            TransferMsg::Bisync => {
+                self.action_bisync();
+                self.reload_host_bridge_filelist();
+                self.reload_remote_filelist();
+            }
+            TransferMsg::ExtractArchive => self.action_extract_archive(),
+            TransferMsg::Fanout => {
+                self.action_fanout();
+                self.reload_remote_filelist();
+            }
+            TransferMsg::Mirror => {
+                self.action_mirror();
+                self.reload_host_bridge_filelist();
+                self.reload_remote_filelist();
+            }
+            TransferMsg::JumpToEntryStartingWith(ch) => {
+                self.action_jump_to_entry(ch);
+            }
             TransferMsg::InitFuzzySearch => {
                 // Mount wait
                 self.mount_walkdir_wait();
@@ -293,6 +424,7 @@ impl FileTransferActivity {
                 }
             },
             TransferMsg::OpenFileWith(prog) => {
+                push_recent(&mut self.open_with_recents, &prog);
                 match self.browser.tab() {
                     FileExplorerTab::HostBridge => self.action_local_open_with(&prog),
                     FileExplorerTab::Remote => self.action_remote_open_with(&prog),
@@ -310,6 +442,16 @@ impl FileTransferActivity {
                 }
                 self.update_browser_file_list()
             }
+            TransferMsg::PasteFiles => {
+                match self.browser.tab() {
+                    FileExplorerTab::HostBridge => self.action_local_paste(),
+                    FileExplorerTab::Remote => self.action_remote_paste(),
+                    _ => {}
+                }
+                // Reload both panels, since files may come from the other one
+                self.update_browser_file_list();
+                self.update_browser_file_list_swapped();
+            }
             TransferMsg::ReloadDir => self.update_browser_file_list(),
             TransferMsg::RenameFile(dest) => {
                 self.umount_rename();
@@ -332,6 +474,8 @@ impl FileTransferActivity {
                     .collect();
                 self.update_goto(files);
             }
+            TransferMsg::SaveBookmark => self.action_save_bookmark(),
+            TransferMsg::SaveDirBookmark => self.action_save_dir_bookmark(),
             TransferMsg::SaveFileAs(dest) => {
                 self.umount_saveas();
                 match self.browser.tab() {
@@ -349,6 +493,12 @@ impl FileTransferActivity {
 
             TransferMsg::ToggleWatch => self.action_toggle_watch(),
             TransferMsg::ToggleWatchFor(index) => self.action_toggle_watch_for(index),
+            TransferMsg::TransferAndOpenFile => {
+                if self.browser.tab() == FileExplorerTab::Remote {
+                    self.action_remote_recv_and_open();
+                    self.update_browser_file_list_swapped();
+                }
+            }
             TransferMsg::TransferFile => {
                 match self.browser.tab() {
                     FileExplorerTab::HostBridge => self.action_local_send(),
@@ -359,6 +509,16 @@ impl FileTransferActivity {
                 }
                 self.update_browser_file_list_swapped();
             }
+            TransferMsg::TransferFileRsync => {
+                match self.browser.tab() {
+                    FileExplorerTab::HostBridge => self.action_local_send_rsync(),
+                    FileExplorerTab::Remote => self.action_remote_recv_rsync(),
+                    FileExplorerTab::FindHostBridge | FileExplorerTab::FindRemote => {
+                        self.action_find_transfer(TransferOpts::default().rsync(Some(true)))
+                    }
+                }
+                self.update_browser_file_list_swapped();
+            }
         }
         // Force redraw
         self.redraw = true;
@@ -367,7 +527,11 @@ impl FileTransferActivity {
 
     fn update_ui(&mut self, msg: UiMsg) -> Option<Msg> {
         match msg {
+            UiMsg::BookmarkNameBlur => {
+                assert!(self.app.active(&Id::BookmarkSavePassword).is_ok());
+            }
             UiMsg::CloseChmodPopup => self.umount_chmod(),
+            UiMsg::CloseCompressPopup => self.umount_compress(),
             UiMsg::ChangeFileSorting(sorting) => {
                 match self.browser.tab() {
                     FileExplorerTab::HostBridge | FileExplorerTab::FindHostBridge => {
@@ -410,9 +574,17 @@ impl FileTransferActivity {
                 }
                 self.browser.change_tab(new_tab);
             }
+            UiMsg::CompressFormatBlur => {
+                assert!(self.app.active(&Id::CompressName).is_ok());
+            }
+            UiMsg::CompressNameBlur => {
+                assert!(self.app.active(&Id::CompressFormat).is_ok());
+            }
             UiMsg::CloseCopyPopup => self.umount_copy(),
             UiMsg::CloseDeletePopup => self.umount_radio_delete(),
+            UiMsg::CloseDirBookmarksList => self.umount_dir_bookmarks_list(),
             UiMsg::CloseDisconnectPopup => self.umount_disconnect(),
+            UiMsg::CloseDuplicatePopup => self.umount_duplicate(),
             UiMsg::CloseErrorPopup => self.umount_error(),
             UiMsg::CloseExecPopup => {
                 self.browser.toggle_terminal(false);
@@ -437,6 +609,8 @@ impl FileTransferActivity {
             UiMsg::CloseQuitPopup => self.umount_quit(),
             UiMsg::CloseRenamePopup => self.umount_rename(),
             UiMsg::CloseSaveAsPopup => self.umount_saveas(),
+            UiMsg::CloseSaveBookmarkPopup => self.umount_save_bookmark(),
+            UiMsg::CloseSelectPatternPopup => self.umount_select_pattern(),
             UiMsg::CloseSymlinkPopup => self.umount_symlink(),
             UiMsg::CloseWatchedPathsList => self.umount_watched_paths_list(),
             UiMsg::CloseWatcherPopup => self.umount_radio_watcher(),
@@ -446,37 +620,30 @@ impl FileTransferActivity {
             }
             UiMsg::FilterFiles(filter) => {
                 self.umount_filter();
-                let files = self.filter(&filter);
-                // Get wrkdir
-                let wrkdir = match self.browser.tab() {
-                    FileExplorerTab::HostBridge => self.host_bridge().wrkdir.clone(),
-                    _ => self.remote().wrkdir.clone(),
-                };
-                // Create explorer and load files
-                self.browser.set_found(
-                    match self.browser.tab() {
-                        FileExplorerTab::HostBridge => FoundExplorerTab::Local,
-                        _ => FoundExplorerTab::Remote,
-                    },
-                    files,
-                    wrkdir.as_path(),
-                );
-                // Mount result widget
-                self.mount_find(&filter, false);
-                self.update_find_list();
-                // Initialize tab
-                self.browser.change_tab(match self.browser.tab() {
-                    FileExplorerTab::HostBridge => FileExplorerTab::FindHostBridge,
-                    FileExplorerTab::Remote => FileExplorerTab::FindRemote,
-                    _ => FileExplorerTab::FindHostBridge,
-                });
+                self.action_filter(filter.trim());
+                match self.browser.tab() {
+                    FileExplorerTab::HostBridge => self.refresh_local_status_bar(),
+                    _ => self.refresh_remote_status_bar(),
+                }
+                self.update_browser_file_list();
             }
+            UiMsg::FindWithRegex(pattern) => match self.browser.regex_search(&pattern) {
+                Ok(()) => self.update_find_list(),
+                Err(err) => self.mount_error(format!("Invalid regex: {err}")),
+            },
             UiMsg::FuzzySearch(needle) => {
                 self.browser.fuzzy_search(&needle);
                 self.update_find_list();
             }
             UiMsg::GoToTransferQueue => {
-                assert!(self.app.active(&Id::TransferQueueHostBridge).is_ok());
+                if self.browser.log_visible() {
+                    self.action_summarize_transfer_queues();
+                    assert!(self.app.active(&Id::TransferQueueHostBridge).is_ok());
+                }
+            }
+            UiMsg::GrowPanel => {
+                self.browser.grow_panel();
+                self.save_panel_split_ratio();
             }
             UiMsg::LogBackTabbed => {
                 assert!(self.app.active(&Id::ExplorerHostBridge).is_ok());
@@ -490,6 +657,16 @@ impl FileTransferActivity {
             UiMsg::MarkClear => {
                 self.action_mark_clear();
             }
+            UiMsg::InvertSelection => {
+                self.action_invert_selection();
+            }
+            UiMsg::SelectByPattern(pattern) => {
+                self.umount_select_pattern();
+                self.action_select_by_pattern(&pattern);
+            }
+            UiMsg::CopyMarkedPaths => {
+                self.action_copy_marked_paths();
+            }
             UiMsg::MarkRemove(tab, path) => match tab {
                 MarkQueue::Local => {
                     self.host_bridge_mut().dequeue(&path);
@@ -506,6 +683,10 @@ impl FileTransferActivity {
                 self.disconnect_and_quit();
                 self.umount_quit();
             }
+            UiMsg::SaveBookmarkPasswordBlur => {
+                assert!(self.app.active(&Id::BookmarkName).is_ok());
+            }
+            UiMsg::ShowCompressPopup => self.action_show_compress(),
             UiMsg::ShowChmodPopup => {
                 let selected_file = match self.browser.tab() {
                     #[cfg(posix)]
@@ -536,7 +717,14 @@ impl FileTransferActivity {
             }
             UiMsg::ShowCopyPopup => self.mount_copy(),
             UiMsg::ShowDeletePopup => self.mount_radio_delete(),
-            UiMsg::ShowDisconnectPopup => self.mount_disconnect(),
+            UiMsg::ShowDirBookmarksList => self.action_show_dir_bookmarks(),
+            UiMsg::ShowDuplicatePopup => self.action_show_duplicate(),
+            UiMsg::ShowDisconnectPopup => {
+                // The global listener asks as well, even if the panel has already disconnected
+                if self.exit_reason.is_none() {
+                    self.mount_disconnect();
+                }
+            }
             UiMsg::ShowTerminal => {
                 self.browser.toggle_terminal(true);
                 self.mount_exec()
@@ -566,6 +754,9 @@ impl FileTransferActivity {
             UiMsg::ShowQuitPopup => self.mount_quit(),
             UiMsg::ShowRenamePopup => self.mount_rename(),
             UiMsg::ShowSaveAsPopup => self.mount_saveas(),
+            UiMsg::ShowSaveBookmarkPopup => self.action_show_save_bookmark(),
+            UiMsg::ShowSaveDirBookmarkPopup => self.action_show_save_dir_bookmark(),
+            UiMsg::ShowSelectPatternPopup => self.mount_select_pattern(),
             UiMsg::ShowSymlinkPopup => {
                 if match self.browser.tab() {
                     FileExplorerTab::HostBridge => self.is_local_selected_one(),
@@ -582,6 +773,10 @@ impl FileTransferActivity {
             }
             UiMsg::ShowWatchedPathsList => self.action_show_watched_paths_list(),
             UiMsg::ShowWatcherPopup => self.action_show_radio_watch(),
+            UiMsg::ShrinkPanel => {
+                self.browser.shrink_panel();
+                self.save_panel_split_ratio();
+            }
             UiMsg::ToggleHiddenFiles => match self.browser.tab() {
                 FileExplorerTab::FindHostBridge | FileExplorerTab::HostBridge => {
                     self.browser.host_bridge_mut().toggle_hidden_files();
@@ -594,11 +789,51 @@ impl FileTransferActivity {
                     self.update_browser_file_list();
                 }
             },
+            UiMsg::ToggleHiddenPatterns => match self.browser.tab() {
+                FileExplorerTab::FindHostBridge | FileExplorerTab::HostBridge => {
+                    self.browser.host_bridge_mut().toggle_pattern_hidden_files();
+                    self.refresh_local_status_bar();
+                    self.update_browser_file_list();
+                }
+                FileExplorerTab::FindRemote | FileExplorerTab::Remote => {
+                    self.browser.remote_mut().toggle_pattern_hidden_files();
+                    self.refresh_remote_status_bar();
+                    self.update_browser_file_list();
+                }
+            },
+            UiMsg::ToggleFindCaseSensitive => match self.browser.toggle_find_case_sensitive() {
+                Ok(()) => self.update_find_list(),
+                Err(err) => self.mount_error(format!("Invalid regex: {err}")),
+            },
+            UiMsg::ToggleFooter => {
+                self.browser.toggle_footer();
+                self.redraw = true;
+            }
+            UiMsg::ToggleLog => {
+                self.browser.toggle_log();
+                self.redraw = true;
+            }
+            UiMsg::ToggleMaximizePanel => {
+                self.browser.toggle_maximize();
+                self.redraw = true;
+            }
+            UiMsg::TogglePreviewPane => {
+                self.browser.toggle_preview();
+                if !self.browser.preview_visible() {
+                    self.umount_preview();
+                }
+                self.redraw = true;
+            }
+            UiMsg::ToggleRawSizes => {
+                self.browser.toggle_raw_sizes();
+                self.update_host_bridge_filelist();
+                self.update_remote_filelist();
+            }
             UiMsg::ToggleSyncBrowsing => {
                 self.browser.toggle_sync_browsing();
                 self.refresh_remote_status_bar();
             }
-            UiMsg::WindowResized => {
+            UiMsg::WindowResized | UiMsg::FooterPageChanged => {
                 self.redraw = true;
             }
 
@@ -3,20 +3,28 @@
 //! `filetransfer_activiy` is the module which implements the Filetransfer activity, which is the main activity afterall
 
 // locals
+use std::path::Path;
+
 // Ext
 use remotefs::fs::{File, UnixPex};
 use tuirealm::event::KeyEvent;
 use tuirealm::props::{PropPayload, PropValue, TextSpan};
 use tuirealm::ratatui::layout::{Constraint, Direction, Layout};
 use tuirealm::ratatui::widgets::Clear;
-use tuirealm::{AttrValue, Attribute, Sub, SubClause, SubEventClause};
+use tuirealm::{AttrValue, Attribute, NoUserEvent, Sub, SubClause, SubEventClause};
 use unicode_width::UnicodeWidthStr;
 
 use super::browser::{FileExplorerTab, FoundExplorerTab};
-use super::components::ATTR_FILES;
-use super::{Context, FileTransferActivity, Id, components};
-use crate::explorer::FileSorting;
+use super::components::{ATTR_FILES, ATTR_TRANSFER_ACTIVE, BookmarkKind};
+use super::lib::bisync::BisyncPlan;
+use super::lib::open_with::push_recent;
+use super::lib::transfer::QueueSummary;
+use super::{Context, FileTransferActivity, Id, Msg, PendingActionMsg, UiMsg, components};
+use crate::explorer::{FileSorting, FocusedPanel};
+use crate::filetransfer::{FileTransferParams, HostBridgeParams};
 use crate::ui::activities::filetransfer::MarkQueue;
+use crate::utils::fmt::fmt_path_elide;
+use crate::utils::locale::Locale;
 use crate::utils::ui::{Popup, Size};
 
 impl FileTransferActivity {
@@ -34,13 +42,36 @@ impl FileTransferActivity {
         let key_color = self.theme().misc_keys;
         let log_panel = self.theme().transfer_log_window;
         let log_background = self.theme().transfer_log_background;
+        let highlight_style = self.config().get_highlight_style();
+        let confirm_disconnect = self.config().get_confirm_disconnect();
+        let advance_on_mark = self.config().get_advance_on_mark();
         let keybindings = self.keybindings().clone();
+        let mut footer_subs: Vec<Sub<Id, NoUserEvent>> = keybindings
+            .explorer
+            .next_footer_page
+            .iter()
+            .map(|binding| {
+                Sub::new(
+                    SubEventClause::Keyboard(KeyEvent {
+                        code: binding.key,
+                        modifiers: binding.modifiers,
+                    }),
+                    Self::no_popup_mounted_clause(),
+                )
+            })
+            .collect();
+        footer_subs.push(Sub::new(SubEventClause::WindowResize, SubClause::Always));
+        let footer_hints = self.config().get_footer_hints();
         assert!(
             self.app
                 .mount(
                     Id::FooterBar,
-                    Box::new(components::FooterBar::new(key_color, Some(&keybindings))),
-                    vec![]
+                    Box::new(components::FooterBar::new(
+                        key_color,
+                        Some(&keybindings),
+                        footer_hints.as_deref()
+                    )),
+                    footer_subs
                 )
                 .is_ok()
         );
@@ -48,14 +79,19 @@ impl FileTransferActivity {
             self.app
                 .mount(
                     Id::ExplorerHostBridge,
-                    Box::new(components::ExplorerLocal::new(
-                        "",
-                        &[],
-                        local_explorer_background,
-                        local_explorer_foreground,
-                        local_explorer_highlighted,
-                        Some(&keybindings)
-                    )),
+                    Box::new(
+                        components::ExplorerLocal::new(
+                            "",
+                            &[],
+                            local_explorer_background,
+                            local_explorer_foreground,
+                            local_explorer_highlighted,
+                            highlight_style,
+                            Some(&keybindings)
+                        )
+                        .confirm_disconnect(confirm_disconnect)
+                        .advance_on_mark(advance_on_mark)
+                    ),
                     vec![]
                 )
                 .is_ok()
@@ -64,14 +100,18 @@ impl FileTransferActivity {
             self.app
                 .mount(
                     Id::ExplorerRemote,
-                    Box::new(components::ExplorerRemote::new(
-                        "",
-                        &[],
-                        remote_explorer_background,
-                        remote_explorer_foreground,
-                        remote_explorer_highlighted,
-                        Some(&keybindings)
-                    )),
+                    Box::new(
+                        components::ExplorerRemote::new(
+                            "",
+                            &[],
+                            remote_explorer_background,
+                            remote_explorer_foreground,
+                            remote_explorer_highlighted,
+                            highlight_style,
+                            Some(&keybindings)
+                        )
+                        .advance_on_mark(advance_on_mark)
+                    ),
                     vec![]
                 )
                 .is_ok()
@@ -95,8 +135,16 @@ impl FileTransferActivity {
         // self.update_remote_filelist();
         // Global listener
         self.mount_global_listener();
-        // Give focus to local explorer
-        assert!(self.app.active(&Id::ExplorerHostBridge).is_ok());
+        // Give focus to local explorer, or to the panel focused at the last disconnection
+        match self.config().get_initial_focused_panel() {
+            FocusedPanel::Local => {
+                assert!(self.app.active(&Id::ExplorerHostBridge).is_ok());
+            }
+            FocusedPanel::Remote => {
+                assert!(self.app.active(&Id::ExplorerRemote).is_ok());
+                self.browser.change_tab(FileExplorerTab::Remote);
+            }
+        }
     }
 
     // -- view
@@ -111,28 +159,58 @@ impl FileTransferActivity {
                 .direction(Direction::Vertical)
                 .constraints(
                     [
-                        Constraint::Min(7),    // Body
-                        Constraint::Length(1), // Footer
+                        Constraint::Min(7),                               // Body
+                        Constraint::Length(self.browser.footer_height()), // Footer
                     ]
                     .as_ref(),
                 )
                 .split(f.area());
-            // main chunks
+            // main chunks; if the log panel is hidden, only the status bar is kept below the explorers
+            let main_constraints = if self.browser.log_visible() {
+                [
+                    Constraint::Percentage(70), // Explorer
+                    Constraint::Percentage(30), // Log
+                ]
+            } else {
+                [
+                    Constraint::Min(5),    // Explorer
+                    Constraint::Length(1), // Status bar
+                ]
+            };
             let main_chunks = Layout::default()
                 .direction(Direction::Vertical)
+                .constraints(main_constraints.as_ref())
+                .split(body[0]);
+            // Create explorer chunks; if the preview pane is visible, it's drawn on the right of the explorers
+            let preview_width = if self.browser.preview_visible() {
+                35
+            } else {
+                0
+            };
+            let explorer_chunks = Layout::default()
                 .constraints(
                     [
-                        Constraint::Percentage(70), // Explorer
-                        Constraint::Percentage(30), // Log
+                        Constraint::Percentage(100 - preview_width),
+                        Constraint::Percentage(preview_width), // Preview
                     ]
                     .as_ref(),
                 )
-                .split(body[0]);
-            // Create explorer chunks
-            let tabs_chunks = Layout::default()
-                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
                 .direction(Direction::Horizontal)
                 .split(main_chunks[0]);
+            let (host_bridge_width, remote_width) = self.browser.panel_widths();
+            let tabs_chunks = Layout::default()
+                .constraints(
+                    [
+                        Constraint::Percentage(host_bridge_width),
+                        Constraint::Percentage(remote_width),
+                    ]
+                    .as_ref(),
+                )
+                .direction(Direction::Horizontal)
+                .split(explorer_chunks[0]);
+            if preview_width > 0 {
+                self.app.view(&Id::Preview, f, explorer_chunks[1]);
+            }
             // Create log box chunks
             let bottom_chunks = Layout::default()
                 .constraints([Constraint::Length(1), Constraint::Length(10)].as_ref())
@@ -155,32 +233,40 @@ impl FileTransferActivity {
                 )
                 .direction(Direction::Horizontal)
                 .split(bottom_chunks[1]);
-            // Draw footer
-            self.app.view(&Id::FooterBar, f, body[1]);
+            // Draw footer; if hidden, its row is given to the body
+            if self.browser.footer_visible() {
+                self.app.view(&Id::FooterBar, f, body[1]);
+            }
             // Draw explorers
-            // @! Local explorer (Find or default)
-            if matches!(self.browser.found_tab(), Some(FoundExplorerTab::Local)) {
-                self.app.view(&Id::ExplorerFind, f, tabs_chunks[0]);
-            } else if self.browser.is_terminal_open_host_bridge() {
-                self.app.view(&Id::TerminalHostBridge, f, tabs_chunks[0]);
-            } else {
-                self.app.view(&Id::ExplorerHostBridge, f, tabs_chunks[0]);
+            // @! Local explorer (Find or default); hidden if the remote panel is maximized
+            if host_bridge_width > 0 {
+                if matches!(self.browser.found_tab(), Some(FoundExplorerTab::Local)) {
+                    self.app.view(&Id::ExplorerFind, f, tabs_chunks[0]);
+                } else if self.browser.is_terminal_open_host_bridge() {
+                    self.app.view(&Id::TerminalHostBridge, f, tabs_chunks[0]);
+                } else {
+                    self.app.view(&Id::ExplorerHostBridge, f, tabs_chunks[0]);
+                }
             }
-            // @! Remote explorer (Find or default)
-            if matches!(self.browser.found_tab(), Some(FoundExplorerTab::Remote)) {
-                self.app.view(&Id::ExplorerFind, f, tabs_chunks[1]);
-            } else if self.browser.is_terminal_open_remote() {
-                self.app.view(&Id::TerminalRemote, f, tabs_chunks[1]);
-            } else {
-                self.app.view(&Id::ExplorerRemote, f, tabs_chunks[1]);
+            // @! Remote explorer (Find or default); hidden if the local panel is maximized
+            if remote_width > 0 {
+                if matches!(self.browser.found_tab(), Some(FoundExplorerTab::Remote)) {
+                    self.app.view(&Id::ExplorerFind, f, tabs_chunks[1]);
+                } else if self.browser.is_terminal_open_remote() {
+                    self.app.view(&Id::TerminalRemote, f, tabs_chunks[1]);
+                } else {
+                    self.app.view(&Id::ExplorerRemote, f, tabs_chunks[1]);
+                }
+            }
+            if self.browser.log_visible() {
+                // draw transfer queues
+                self.app
+                    .view(&Id::TransferQueueHostBridge, f, bottom_components[0]);
+                self.app
+                    .view(&Id::TransferQueueRemote, f, bottom_components[1]);
+                // Draw log box
+                self.app.view(&Id::Log, f, bottom_components[2]);
             }
-            // draw transfer queues
-            self.app
-                .view(&Id::TransferQueueHostBridge, f, bottom_components[0]);
-            self.app
-                .view(&Id::TransferQueueRemote, f, bottom_components[1]);
-            // Draw log box
-            self.app.view(&Id::Log, f, bottom_components[2]);
             // Draw status bar
             self.app
                 .view(&Id::StatusBarHostBridge, f, status_bar_chunks[0]);
@@ -201,7 +287,7 @@ impl FileTransferActivity {
                 // make popup
                 self.app.view(&Id::CopyPopup, f, popup);
             } else if self.app.mounted(&Id::ChmodPopup) {
-                let popup = Popup(Size::Percentage(50), Size::Unit(12)).draw_in(f.area());
+                let popup = Popup(Size::Percentage(50), Size::Unit(15)).draw_in(f.area());
                 f.render_widget(Clear, popup);
                 // make popup
                 self.app.view(&Id::ChmodPopup, f, popup);
@@ -210,6 +296,11 @@ impl FileTransferActivity {
                 f.render_widget(Clear, popup);
                 // make popup
                 self.app.view(&Id::FilterPopup, f, popup);
+            } else if self.app.mounted(&Id::SelectPatternPopup) {
+                let popup = Popup(Size::Percentage(40), Size::Unit(3)).draw_in(f.area());
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::SelectPatternPopup, f, popup);
             } else if self.app.mounted(&Id::GotoPopup) {
                 let popup = Popup(Size::Percentage(40), Size::Unit(3)).draw_in(f.area());
                 f.render_widget(Clear, popup);
@@ -230,6 +321,11 @@ impl FileTransferActivity {
                 f.render_widget(Clear, popup);
                 // make popup
                 self.app.view(&Id::OpenWithPopup, f, popup);
+            } else if self.app.mounted(&Id::DuplicatePopup) {
+                let popup = Popup(Size::Percentage(40), Size::Unit(3)).draw_in(f.area());
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::DuplicatePopup, f, popup);
             } else if self.app.mounted(&Id::RenamePopup) {
                 let popup = Popup(Size::Percentage(40), Size::Unit(3)).draw_in(f.area());
                 f.render_widget(Clear, popup);
@@ -286,6 +382,66 @@ impl FileTransferActivity {
                 f.render_widget(Clear, popup);
                 // make popup
                 self.app.view(&Id::QuitPopup, f, popup);
+            } else if self.app.mounted(&Id::MirrorPopup) {
+                let popup = Popup(Size::Percentage(60), Size::Percentage(50)).draw_in(f.area());
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::MirrorPopup, f, popup);
+            } else if self.app.mounted(&Id::ConnectionSummaryPopup) {
+                let popup = Popup(Size::Percentage(60), Size::Percentage(50)).draw_in(f.area());
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::ConnectionSummaryPopup, f, popup);
+            } else if self.app.mounted(&Id::BookmarkSavePassword) {
+                let popup = Popup(Size::Percentage(30), Size::Unit(7)).draw_in(f.area());
+                f.render_widget(Clear, popup);
+                let popup_chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints(
+                        [
+                            Constraint::Length(3), // Input form
+                            Constraint::Length(4), // Yes/No
+                        ]
+                        .as_ref(),
+                    )
+                    .split(popup);
+                self.app.view(&Id::BookmarkName, f, popup_chunks[0]);
+                self.app.view(&Id::BookmarkSavePassword, f, popup_chunks[1]);
+            } else if self.app.mounted(&Id::CompressFormat) {
+                let popup = Popup(Size::Percentage(30), Size::Unit(7)).draw_in(f.area());
+                f.render_widget(Clear, popup);
+                let popup_chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints(
+                        [
+                            Constraint::Length(3), // Input form
+                            Constraint::Length(4), // Format
+                        ]
+                        .as_ref(),
+                    )
+                    .split(popup);
+                self.app.view(&Id::CompressName, f, popup_chunks[0]);
+                self.app.view(&Id::CompressFormat, f, popup_chunks[1]);
+            } else if self.app.mounted(&Id::FanoutPopup) {
+                let popup = Popup(Size::Percentage(60), Size::Percentage(50)).draw_in(f.area());
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::FanoutPopup, f, popup);
+            } else if self.app.mounted(&Id::SyncPopup) {
+                let popup = Popup(Size::Percentage(60), Size::Percentage(50)).draw_in(f.area());
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::SyncPopup, f, popup);
+            } else if self.app.mounted(&Id::SyncConflictPopup) {
+                let popup = Popup(Size::Percentage(50), Size::Unit(3)).draw_in(f.area());
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::SyncConflictPopup, f, popup);
+            } else if self.app.mounted(&Id::DirBookmarksList) {
+                let popup = Popup(Size::Percentage(60), Size::Percentage(50)).draw_in(f.area());
+                f.render_widget(Clear, popup);
+                // make popup
+                self.app.view(&Id::DirBookmarksList, f, popup);
             } else if self.app.mounted(&Id::WatchedPathsList) {
                 let popup = Popup(Size::Percentage(60), Size::Percentage(50)).draw_in(f.area());
                 f.render_widget(Clear, popup);
@@ -297,7 +453,7 @@ impl FileTransferActivity {
                 // make popup
                 self.app.view(&Id::WatcherPopup, f, popup);
             } else if self.app.mounted(&Id::SortingPopup) {
-                let popup = Popup(Size::Percentage(50), Size::Unit(3)).draw_in(f.area());
+                let popup = Popup(Size::Percentage(70), Size::Unit(3)).draw_in(f.area());
                 f.render_widget(Clear, popup);
                 // make popup
                 self.app.view(&Id::SortingPopup, f, popup);
@@ -414,15 +570,17 @@ impl FileTransferActivity {
     }
 
     pub(super) fn mount_walkdir_wait(&mut self) {
+        self.mount_abortable_wait("Scanning current directory…");
+    }
+
+    /// Mount a wait popup which can be aborted with `ESC`, like the one of walkdir
+    pub(super) fn mount_abortable_wait<S: AsRef<str>>(&mut self, text: S) {
         let color = self.theme().misc_info_dialog;
         assert!(
             self.app
                 .remount(
                     Id::WaitPopup,
-                    Box::new(components::WalkdirWaitPopup::new(
-                        "Scanning current directory…",
-                        color
-                    )),
+                    Box::new(components::WalkdirWaitPopup::new(text, color)),
                     vec![],
                 )
                 .is_ok()
@@ -432,14 +590,28 @@ impl FileTransferActivity {
         self.view();
     }
 
-    pub(super) fn update_walkdir_entries(&mut self, entries: usize) {
+    pub(super) fn update_walkdir_entries(&mut self, entries: usize, path: &Path) {
         let text = format!("Scanning current directory… ({entries} items found)",);
+        self.update_abortable_wait(text, path);
+    }
+
+    /// Update the text of the abortable wait popup, showing the path being processed
+    pub(super) fn update_abortable_wait(&mut self, text: String, path: &Path) {
+        // the popup takes half of the terminal; keep room for the borders
+        let width = self
+            .context_mut()
+            .terminal()
+            .raw()
+            .size()
+            .map(|x| (x.width / 2).saturating_sub(2))
+            .unwrap_or(0) as usize;
         let _ = self.app.attr(
             &Id::WaitPopup,
             Attribute::Text,
             AttrValue::Payload(PropPayload::Vec(vec![
                 PropValue::TextSpan(TextSpan::from(text)),
-                PropValue::TextSpan(TextSpan::from("Press 'CTRL+C' to abort")),
+                PropValue::TextSpan(TextSpan::from(fmt_path_elide(path, width))),
+                PropValue::TextSpan(TextSpan::from("Press 'ESC' or 'CTRL+C' to abort")),
             ])),
         );
 
@@ -469,11 +641,13 @@ impl FileTransferActivity {
                 .is_ok()
         );
         assert!(self.app.active(&Id::QuitPopup).is_ok());
+        self.confirm_timeout.start(Msg::Ui(UiMsg::CloseQuitPopup));
     }
 
     /// Umount quit popup
     pub(super) fn umount_quit(&mut self) {
         let _ = self.app.umount(&Id::QuitPopup);
+        self.confirm_timeout.cancel();
     }
 
     /// Mount disconnect popup
@@ -490,11 +664,14 @@ impl FileTransferActivity {
                 .is_ok()
         );
         assert!(self.app.active(&Id::DisconnectPopup).is_ok());
+        self.confirm_timeout
+            .start(Msg::Ui(UiMsg::CloseDisconnectPopup));
     }
 
     /// Umount disconnect popup
     pub(super) fn umount_disconnect(&mut self) {
         let _ = self.app.umount(&Id::DisconnectPopup);
+        self.confirm_timeout.cancel();
     }
 
     pub(super) fn mount_chmod(&mut self, mode: UnixPex, title: String) {
@@ -522,11 +699,17 @@ impl FileTransferActivity {
 
     pub(super) fn mount_filter(&mut self) {
         let input_color = self.theme().misc_input_dialog;
+        let filter = self
+            .browser
+            .explorer()
+            .filter()
+            .map(|filter| filter.expression().to_string())
+            .unwrap_or_default();
         assert!(
             self.app
                 .remount(
                     Id::FilterPopup,
-                    Box::new(components::FilterPopup::new(input_color)),
+                    Box::new(components::FilterPopup::new(&filter, input_color)),
                     vec![],
                 )
                 .is_ok()
@@ -534,6 +717,24 @@ impl FileTransferActivity {
         assert!(self.app.active(&Id::FilterPopup).is_ok());
     }
 
+    pub(super) fn umount_select_pattern(&mut self) {
+        let _ = self.app.umount(&Id::SelectPatternPopup);
+    }
+
+    pub(super) fn mount_select_pattern(&mut self) {
+        let input_color = self.theme().misc_input_dialog;
+        assert!(
+            self.app
+                .remount(
+                    Id::SelectPatternPopup,
+                    Box::new(components::SelectPatternPopup::new(input_color)),
+                    vec![],
+                )
+                .is_ok()
+        );
+        assert!(self.app.active(&Id::SelectPatternPopup).is_ok());
+    }
+
     pub(super) fn mount_copy(&mut self) {
         let input_color = self.theme().misc_input_dialog;
         assert!(
@@ -632,6 +833,8 @@ impl FileTransferActivity {
                 self.theme().transfer_remote_explorer_highlighted,
             ),
         };
+        let highlight_style = self.config().get_highlight_style();
+        let advance_on_mark = self.config().get_advance_on_mark();
         let keybindings = self.keybindings().clone();
 
         // Mount component
@@ -640,23 +843,31 @@ impl FileTransferActivity {
                 .remount(
                     Id::ExplorerFind,
                     if fuzzy_search {
-                        Box::new(components::ExplorerFuzzy::new(
-                            msg.to_string(),
-                            &[],
-                            bg,
-                            fg,
-                            hg,
-                            Some(&keybindings),
-                        ))
+                        Box::new(
+                            components::ExplorerFuzzy::new(
+                                msg.to_string(),
+                                &[],
+                                bg,
+                                fg,
+                                hg,
+                                highlight_style,
+                                Some(&keybindings),
+                            )
+                            .advance_on_mark(advance_on_mark),
+                        )
                     } else {
-                        Box::new(components::ExplorerFind::new(
-                            msg.to_string(),
-                            &[],
-                            bg,
-                            fg,
-                            hg,
-                            Some(&keybindings),
-                        ))
+                        Box::new(
+                            components::ExplorerFind::new(
+                                msg.to_string(),
+                                &[],
+                                bg,
+                                fg,
+                                hg,
+                                highlight_style,
+                                Some(&keybindings),
+                            )
+                            .advance_on_mark(advance_on_mark),
+                        )
                     },
                     vec![],
                 )
@@ -747,11 +958,25 @@ impl FileTransferActivity {
 
     pub(super) fn mount_openwith(&mut self) {
         let input_color = self.theme().misc_input_dialog;
+        // the command associated to the extension of the selected file is the first choice
+        let selected = match self.browser.tab() {
+            FileExplorerTab::HostBridge => self.get_local_selected_file(),
+            FileExplorerTab::Remote => self.get_remote_selected_file(),
+            FileExplorerTab::FindHostBridge | FileExplorerTab::FindRemote => {
+                self.get_found_selected_file()
+            }
+        };
+        let mut choices = self.open_with_recents.clone();
+        if let Some(command) =
+            selected.and_then(|file| self.config().get_open_with_command(file.path()))
+        {
+            push_recent(&mut choices, &command);
+        }
         assert!(
             self.app
                 .remount(
                     Id::OpenWithPopup,
-                    Box::new(components::OpenWithPopup::new(input_color)),
+                    Box::new(components::OpenWithPopup::new(&choices, input_color)),
                     vec![],
                 )
                 .is_ok()
@@ -781,6 +1006,24 @@ impl FileTransferActivity {
         let _ = self.app.umount(&Id::RenamePopup);
     }
 
+    pub(super) fn mount_duplicate(&mut self, name: &str) {
+        let input_color = self.theme().misc_input_dialog;
+        assert!(
+            self.app
+                .remount(
+                    Id::DuplicatePopup,
+                    Box::new(components::DuplicatePopup::new(name, input_color)),
+                    vec![],
+                )
+                .is_ok()
+        );
+        assert!(self.app.active(&Id::DuplicatePopup).is_ok());
+    }
+
+    pub(super) fn umount_duplicate(&mut self) {
+        let _ = self.app.umount(&Id::DuplicatePopup);
+    }
+
     pub(super) fn mount_saveas(&mut self) {
         let input_color = self.theme().misc_input_dialog;
         assert!(
@@ -831,11 +1074,21 @@ impl FileTransferActivity {
                 .is_ok()
         );
         assert!(self.app.active(&Id::ProgressBarPartial).is_ok());
+        let _ = self.app.attr(
+            &Id::GlobalListener,
+            Attribute::Custom(ATTR_TRANSFER_ACTIVE),
+            AttrValue::Flag(true),
+        );
     }
 
     pub(super) fn umount_progress_bar(&mut self) {
         let _ = self.app.umount(&Id::ProgressBarPartial);
         let _ = self.app.umount(&Id::ProgressBarFull);
+        let _ = self.app.attr(
+            &Id::GlobalListener,
+            Attribute::Custom(ATTR_TRANSFER_ACTIVE),
+            AttrValue::Flag(false),
+        );
     }
 
     pub(super) fn mount_file_sorting(&mut self) {
@@ -873,10 +1126,12 @@ impl FileTransferActivity {
                 .is_ok()
         );
         assert!(self.app.active(&Id::DeletePopup).is_ok());
+        self.confirm_timeout.start(Msg::Ui(UiMsg::CloseDeletePopup));
     }
 
     pub(super) fn umount_radio_delete(&mut self) {
         let _ = self.app.umount(&Id::DeletePopup);
+        self.confirm_timeout.cancel();
     }
 
     pub(super) fn mount_radio_watch(&mut self, watch: bool, local: &str, remote: &str) {
@@ -917,6 +1172,192 @@ impl FileTransferActivity {
         let _ = self.app.umount(&Id::WatchedPathsList);
     }
 
+    pub(super) fn mount_mirror_popup(
+        &mut self,
+        transfer: &[std::path::PathBuf],
+        delete: &[std::path::PathBuf],
+    ) {
+        let warn_color = self.theme().misc_warn_dialog;
+        assert!(
+            self.app
+                .remount(
+                    Id::MirrorPopup,
+                    Box::new(components::MirrorPopup::new(transfer, delete, warn_color)),
+                    vec![],
+                )
+                .is_ok()
+        );
+        assert!(self.app.active(&Id::MirrorPopup).is_ok());
+        self.confirm_timeout
+            .start(Msg::PendingAction(PendingActionMsg::MirrorCancel));
+    }
+
+    pub(super) fn umount_mirror_popup(&mut self) {
+        let _ = self.app.umount(&Id::MirrorPopup);
+        self.confirm_timeout.cancel();
+    }
+
+    pub(super) fn mount_connection_summary_popup(
+        &mut self,
+        host_bridge: &HostBridgeParams,
+        remote: &FileTransferParams,
+    ) {
+        let info_color = self.theme().misc_info_dialog;
+        assert!(
+            self.app
+                .remount(
+                    Id::ConnectionSummaryPopup,
+                    Box::new(components::ConnectionSummaryPopup::new(
+                        host_bridge,
+                        remote,
+                        info_color
+                    )),
+                    vec![],
+                )
+                .is_ok()
+        );
+        assert!(self.app.active(&Id::ConnectionSummaryPopup).is_ok());
+    }
+
+    pub(super) fn umount_connection_summary_popup(&mut self) {
+        let _ = self.app.umount(&Id::ConnectionSummaryPopup);
+    }
+
+    pub(super) fn mount_fanout_popup(&mut self, bookmarks: &[String]) {
+        let info_color = self.theme().misc_info_dialog;
+        assert!(
+            self.app
+                .remount(
+                    Id::FanoutPopup,
+                    Box::new(components::FanoutPopup::new(bookmarks, info_color)),
+                    vec![],
+                )
+                .is_ok()
+        );
+        assert!(self.app.active(&Id::FanoutPopup).is_ok());
+    }
+
+    pub(super) fn umount_fanout_popup(&mut self) {
+        let _ = self.app.umount(&Id::FanoutPopup);
+    }
+
+    pub(super) fn mount_save_bookmark(&mut self, name: &str, kind: BookmarkKind) {
+        let save_color = self.theme().misc_save_dialog;
+        let warn_color = self.theme().misc_warn_dialog;
+        assert!(
+            self.app
+                .remount(
+                    Id::BookmarkName,
+                    Box::new(components::BookmarkName::new(name, kind, save_color)),
+                    vec![],
+                )
+                .is_ok()
+        );
+        assert!(
+            self.app
+                .remount(
+                    Id::BookmarkSavePassword,
+                    Box::new(components::BookmarkSavePassword::new(kind, warn_color)),
+                    vec![],
+                )
+                .is_ok()
+        );
+        assert!(self.app.active(&Id::BookmarkName).is_ok());
+    }
+
+    pub(super) fn umount_save_bookmark(&mut self) {
+        let _ = self.app.umount(&Id::BookmarkName);
+        let _ = self.app.umount(&Id::BookmarkSavePassword);
+    }
+
+    pub(super) fn mount_dir_bookmarks_list(&mut self, bookmarks: &[String]) {
+        let info_color = self.theme().misc_info_dialog;
+        assert!(
+            self.app
+                .remount(
+                    Id::DirBookmarksList,
+                    Box::new(components::DirBookmarksList::new(bookmarks, info_color)),
+                    vec![],
+                )
+                .is_ok()
+        );
+        assert!(self.app.active(&Id::DirBookmarksList).is_ok());
+    }
+
+    pub(super) fn umount_dir_bookmarks_list(&mut self) {
+        let _ = self.app.umount(&Id::DirBookmarksList);
+    }
+
+    pub(super) fn mount_compress(&mut self, name: &str) {
+        let input_color = self.theme().misc_input_dialog;
+        assert!(
+            self.app
+                .remount(
+                    Id::CompressName,
+                    Box::new(components::CompressName::new(name, input_color)),
+                    vec![],
+                )
+                .is_ok()
+        );
+        assert!(
+            self.app
+                .remount(
+                    Id::CompressFormat,
+                    Box::new(components::CompressFormat::new(input_color)),
+                    vec![],
+                )
+                .is_ok()
+        );
+        assert!(self.app.active(&Id::CompressName).is_ok());
+    }
+
+    pub(super) fn umount_compress(&mut self) {
+        let _ = self.app.umount(&Id::CompressName);
+        let _ = self.app.umount(&Id::CompressFormat);
+    }
+
+    pub(super) fn mount_sync_popup(&mut self, plan: &BisyncPlan) {
+        let warn_color = self.theme().misc_warn_dialog;
+        assert!(
+            self.app
+                .remount(
+                    Id::SyncPopup,
+                    Box::new(components::SyncPopup::new(plan, warn_color)),
+                    vec![],
+                )
+                .is_ok()
+        );
+        assert!(self.app.active(&Id::SyncPopup).is_ok());
+        self.confirm_timeout
+            .start(Msg::PendingAction(PendingActionMsg::SyncCancel));
+    }
+
+    pub(super) fn umount_sync_popup(&mut self) {
+        let _ = self.app.umount(&Id::SyncPopup);
+        self.confirm_timeout.cancel();
+    }
+
+    pub(super) fn mount_sync_conflict_popup(&mut self, path: &std::path::Path) {
+        let warn_color = self.theme().misc_warn_dialog;
+        assert!(
+            self.app
+                .remount(
+                    Id::SyncConflictPopup,
+                    Box::new(components::SyncConflictPopup::new(path, warn_color)),
+                    vec![],
+                )
+                .is_ok()
+        );
+        assert!(self.app.active(&Id::SyncConflictPopup).is_ok());
+        self.confirm_timeout
+            .start(Msg::PendingAction(PendingActionMsg::SyncSkip));
+    }
+
+    pub(super) fn umount_sync_conflict_popup(&mut self) {
+        let _ = self.app.umount(&Id::SyncConflictPopup);
+        self.confirm_timeout.cancel();
+    }
+
     pub(super) fn mount_radio_replace(&mut self, file_name: &str) {
         let warn_color = self.theme().misc_warn_dialog;
         assert!(
@@ -929,57 +1370,88 @@ impl FileTransferActivity {
                 .is_ok()
         );
         assert!(self.app.active(&Id::ReplacePopup).is_ok());
+        self.confirm_timeout
+            .start(Msg::PendingAction(PendingActionMsg::ReplaceCancel));
     }
 
     pub(super) fn umount_radio_replace(&mut self) {
         let _ = self.app.umount(&Id::ReplacePopup);
+        self.confirm_timeout.cancel();
     }
 
-    pub(super) fn mount_file_info(&mut self, file: &File) {
+    pub(super) fn mount_preview(&mut self, title: &str, rows: Vec<TextSpan>) {
+        let color = self.theme().misc_info_dialog;
         assert!(
             self.app
                 .remount(
-                    Id::FileInfoPopup,
-                    Box::new(components::FileInfoPopup::new(file)),
+                    Id::Preview,
+                    Box::new(components::Preview::new(title, rows, color)),
                     vec![],
                 )
                 .is_ok()
         );
-        assert!(self.app.active(&Id::FileInfoPopup).is_ok());
     }
 
-    pub(super) fn umount_file_info(&mut self) {
-        let _ = self.app.umount(&Id::FileInfoPopup);
+    pub(super) fn umount_preview(&mut self) {
+        let _ = self.app.umount(&Id::Preview);
+        self.previewed = None;
     }
 
-    pub(super) fn refresh_host_bridge_transfer_queue(&mut self) {
-        let enqueued = self
-            .host_bridge()
-            .enqueued()
-            .iter()
-            .map(|(src, dest)| (src.clone(), dest.clone()))
-            .collect::<Vec<_>>();
-        let log_panel = self.theme().transfer_log_window;
-
+    pub(super) fn mount_file_info(&mut self, file: &File) {
+        let date_fmt = self.config().get_date_fmt();
+        let locale = self
+            .config()
+            .get_locale()
+            .and_then(|l| Locale::from_name(&l));
         assert!(
             self.app
                 .remount(
-                    Id::TransferQueueHostBridge,
-                    Box::new(components::SelectedFilesList::new(
-                        &enqueued,
-                        MarkQueue::Local,
-                        log_panel,
-                        "Host Bridge selected files",
+                    Id::FileInfoPopup,
+                    Box::new(components::FileInfoPopup::new(
+                        file,
+                        date_fmt.as_deref(),
+                        locale,
+                        self.browser.raw_sizes(),
+                        self.config().get_size_units(),
                     )),
-                    vec![]
+                    vec![],
                 )
                 .is_ok()
         );
+        assert!(self.app.active(&Id::FileInfoPopup).is_ok());
+    }
+
+    pub(super) fn umount_file_info(&mut self) {
+        let _ = self.app.umount(&Id::FileInfoPopup);
+    }
+
+    pub(super) fn refresh_host_bridge_transfer_queue(&mut self) {
+        self.remount_transfer_queue(MarkQueue::Local, None);
     }
 
     pub(super) fn refresh_remote_transfer_queue(&mut self) {
-        let enqueued = self
-            .remote()
+        self.remount_transfer_queue(MarkQueue::Remote, None);
+    }
+
+    /// Remount the transfer queue list; the summary of the queue, if any, is shown in its title
+    pub(super) fn remount_transfer_queue(
+        &mut self,
+        queue: MarkQueue,
+        summary: Option<&QueueSummary>,
+    ) {
+        let (id, explorer, title) = match queue {
+            MarkQueue::Local => (
+                Id::TransferQueueHostBridge,
+                self.host_bridge(),
+                "Host Bridge selected files",
+            ),
+            MarkQueue::Remote => (
+                Id::TransferQueueRemote,
+                self.remote(),
+                "Remote transfer selected files",
+            ),
+        };
+        let enqueued = explorer
             .enqueued()
             .iter()
             .map(|(src, dest)| (src.clone(), dest.clone()))
@@ -989,12 +1461,9 @@ impl FileTransferActivity {
         assert!(
             self.app
                 .remount(
-                    Id::TransferQueueRemote,
+                    id,
                     Box::new(components::SelectedFilesList::new(
-                        &enqueued,
-                        MarkQueue::Remote,
-                        log_panel,
-                        "Remote transfer selected files",
+                        &enqueued, queue, log_panel, title, summary,
                     )),
                     vec![]
                 )
@@ -1171,7 +1640,10 @@ impl FileTransferActivity {
             self.app
                 .mount(
                     Id::GlobalListener,
-                    Box::new(components::GlobalListener::new(Some(&keybindings))),
+                    Box::new(components::GlobalListener::new(
+                        Some(&keybindings),
+                        self.config().get_quit_immediately()
+                    )),
                     subs
                 )
                 .is_ok()
@@ -1181,16 +1653,25 @@ impl FileTransferActivity {
     /// Returns a sub clause which requires that no popup is mounted in order to be satisfied
     fn no_popup_mounted_clause() -> SubClause<Id> {
         tuirealm::subclause_and_not!(
+            Id::BookmarkName,
+            Id::BookmarkSavePassword,
+            Id::CompressFormat,
+            Id::CompressName,
+            Id::ConnectionSummaryPopup,
             Id::CopyPopup,
             Id::DeletePopup,
+            Id::DirBookmarksList,
             Id::DisconnectPopup,
+            Id::DuplicatePopup,
             Id::ErrorPopup,
+            Id::FanoutPopup,
             Id::TerminalHostBridge,
             Id::TerminalRemote,
             Id::FatalPopup,
             Id::FileInfoPopup,
             Id::GotoPopup,
             Id::KeybindingsPopup,
+            Id::MirrorPopup,
             Id::MkdirPopup,
             Id::NewfilePopup,
             Id::OpenWithPopup,
@@ -1201,8 +1682,11 @@ impl FileTransferActivity {
             Id::RenamePopup,
             Id::ReplacePopup,
             Id::SaveAsPopup,
+            Id::SelectPatternPopup,
             Id::SortingPopup,
             Id::SyncBrowsingMkdirPopup,
+            Id::SyncConflictPopup,
+            Id::SyncPopup,
             Id::SymlinkPopup,
             Id::WatcherPopup,
             Id::WatchedPathsList,
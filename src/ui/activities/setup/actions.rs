@@ -29,6 +29,7 @@ impl SetupActivity {
     pub(super) fn action_save_all(&mut self) -> Result<(), String> {
         self.action_save_config()?;
         self.action_save_theme()?;
+        self.action_save_keybindings_preset()?;
         // Set config changed to false
         self.set_config_changed(false);
         Ok(())
@@ -54,6 +55,18 @@ impl SetupActivity {
         self.save_theme()
     }
 
+    /// Replace the keybindings with the preset chosen in the setup form, if any, and save them
+    fn action_save_keybindings_preset(&mut self) -> Result<(), String> {
+        if let Some(preset) = self.keybindings_preset.take() {
+            self.save_keybindings(preset.keybindings())?;
+            // Reset preset selection
+            if self.layout == ViewLayout::SetupForm {
+                self.load_input_values();
+            }
+        }
+        Ok(())
+    }
+
     /// Change view tab and load input values in order not to lose them
     pub(super) fn action_change_tab(&mut self, new_tab: ViewLayout) -> Result<(), String> {
         // load values for current tab first
@@ -71,6 +84,7 @@ impl SetupActivity {
 
     /// Reset configuration input fields
     pub(super) fn action_reset_config(&mut self) -> Result<(), String> {
+        self.keybindings_preset = None;
         match self.reset_config_changes() {
             Err(err) => Err(err),
             Ok(_) => {
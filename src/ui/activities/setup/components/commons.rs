@@ -5,10 +5,12 @@
 use tui_realm_stdlib::{List, Paragraph, Radio, Span};
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::event::{Key, KeyEvent, KeyModifiers};
-use tuirealm::props::{Alignment, BorderSides, BorderType, Borders, Color, TableBuilder, TextSpan};
+use tuirealm::props::{Alignment, BorderSides, BorderType, Borders, Color, TextSpan};
 use tuirealm::{Component, Event, MockComponent, NoUserEvent, State, StateValue};
 
 use super::{CommonMsg, Msg, ViewLayout};
+use crate::config::keybindings::KeyBindings;
+use crate::utils::ui::keybindings_help_table;
 
 #[derive(MockComponent)]
 pub struct ErrorPopup {
@@ -110,44 +112,18 @@ pub struct Keybindings {
     component: List,
 }
 
-impl Default for Keybindings {
-    fn default() -> Self {
+impl Keybindings {
+    pub fn new(keybindings: &KeyBindings) -> Self {
         Self {
             component: List::default()
                 .borders(Borders::default().modifiers(BorderType::Rounded))
                 .title("Keybindings", Alignment::Center)
                 .scroll(true)
                 .highlighted_str("? ")
-                .rows(
-                    TableBuilder::default()
-                        .add_col(TextSpan::new("<ESC>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("           Exit setup"))
-                        .add_row()
-                        .add_col(TextSpan::new("<TAB>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("           Change setup page"))
-                        .add_row()
-                        .add_col(TextSpan::new("<RIGHT/LEFT>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("    Change cursor"))
-                        .add_row()
-                        .add_col(TextSpan::new("<UP/DOWN>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("       Change input field"))
-                        .add_row()
-                        .add_col(TextSpan::new("<ENTER>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("         Select / Dismiss popup"))
-                        .add_row()
-                        .add_col(TextSpan::new("<DEL|E>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("         Delete SSH key"))
-                        .add_row()
-                        .add_col(TextSpan::new("<CTRL+N>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("        New SSH key"))
-                        .add_row()
-                        .add_col(TextSpan::new("<CTRL+R>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("        Revert changes"))
-                        .add_row()
-                        .add_col(TextSpan::new("<CTRL+S>").bold().fg(Color::Cyan))
-                        .add_col(TextSpan::from("        Save configuration"))
-                        .build(),
-                ),
+                .rows(keybindings_help_table(
+                    &keybindings.setup_help(),
+                    Color::Cyan,
+                )),
         }
     }
 }
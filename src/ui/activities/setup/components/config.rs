@@ -9,12 +9,15 @@ use tuirealm::props::{Alignment, BorderType, Borders, Color, InputType, Style};
 use tuirealm::{Component, Event, MockComponent, NoUserEvent};
 
 use super::{ConfigMsg, Msg};
+use crate::config::keybindings::KeyBindingsPreset as KeyBindingsPresetEnum;
 use crate::explorer::GroupDirs as GroupDirsEnum;
 use crate::filetransfer::FileTransferProtocol;
 use crate::ui::activities::setup::{
     RADIO_PROTOCOL_FTP, RADIO_PROTOCOL_FTPS, RADIO_PROTOCOL_KUBE, RADIO_PROTOCOL_S3,
     RADIO_PROTOCOL_SCP, RADIO_PROTOCOL_SFTP, RADIO_PROTOCOL_SMB, RADIO_PROTOCOL_WEBDAV,
 };
+use crate::utils::fmt::is_valid_date_fmt;
+use crate::utils::locale::Locale as LocaleFmt;
 use crate::utils::parser::parse_bytesize;
 
 // -- components
@@ -236,6 +239,255 @@ impl Component<Msg, NoUserEvent> for PromptOnFileReplace {
     }
 }
 
+#[derive(MockComponent)]
+pub struct PromptOnSaveAsReplace {
+    component: Radio,
+}
+
+impl PromptOnSaveAsReplace {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightBlue)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(["Yes", "No"])
+                .foreground(Color::LightBlue)
+                .rewind(true)
+                .title(
+                    "Prompt when replacing files with \"save as\"?",
+                    Alignment::Left,
+                )
+                .value(usize::from(!enabled)),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for PromptOnSaveAsReplace {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_radio_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::PromptOnSaveAsReplaceBlurDown),
+            Msg::Config(ConfigMsg::PromptOnSaveAsReplaceBlurUp),
+        )
+    }
+}
+
+#[derive(MockComponent)]
+pub struct QuitImmediately {
+    component: Radio,
+}
+
+impl QuitImmediately {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightRed)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(["Yes", "No"])
+                .foreground(Color::LightRed)
+                .rewind(true)
+                .title("Quit without confirmation?", Alignment::Left)
+                .value(usize::from(!enabled)),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for QuitImmediately {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_radio_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::QuitImmediatelyBlurDown),
+            Msg::Config(ConfigMsg::QuitImmediatelyBlurUp),
+        )
+    }
+}
+
+#[derive(MockComponent)]
+pub struct TitlePathTail {
+    component: Radio,
+}
+
+impl TitlePathTail {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightYellow)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(["Keep tail", "Elide middle"])
+                .foreground(Color::LightYellow)
+                .rewind(true)
+                .title("Long paths in explorer titles", Alignment::Left)
+                .value(usize::from(!enabled)),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for TitlePathTail {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_radio_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::TitlePathTailBlurDown),
+            Msg::Config(ConfigMsg::TitlePathTailBlurUp),
+        )
+    }
+}
+
+#[derive(MockComponent)]
+pub struct KeybindingsPreset {
+    component: Radio,
+}
+
+impl KeybindingsPreset {
+    pub fn new(preset: Option<KeyBindingsPresetEnum>) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightBlue)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(["Keep current", "Default", "Vim", "Emacs"])
+                .foreground(Color::LightBlue)
+                .rewind(true)
+                .title("Replace keybindings with preset", Alignment::Left)
+                .value(match preset {
+                    None => 0,
+                    Some(KeyBindingsPresetEnum::Default) => 1,
+                    Some(KeyBindingsPresetEnum::Vim) => 2,
+                    Some(KeyBindingsPresetEnum::Emacs) => 3,
+                }),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for KeybindingsPreset {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_radio_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::KeybindingsPresetBlurDown),
+            Msg::Config(ConfigMsg::KeybindingsPresetBlurUp),
+        )
+    }
+}
+
+#[derive(MockComponent)]
+pub struct Rsync {
+    component: Radio,
+}
+
+impl Rsync {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightYellow)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(["Yes", "No"])
+                .foreground(Color::LightYellow)
+                .rewind(true)
+                .title("Transfer files with rsync when available?", Alignment::Left)
+                .value(usize::from(!enabled)),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for Rsync {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_radio_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::RsyncBlurDown),
+            Msg::Config(ConfigMsg::RsyncBlurUp),
+        )
+    }
+}
+
+#[derive(MockComponent)]
+pub struct ConnectionSummary {
+    component: Radio,
+}
+
+impl ConnectionSummary {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightBlue)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(["Yes", "No"])
+                .foreground(Color::LightBlue)
+                .rewind(true)
+                .title(
+                    "Show connection summary before connecting?",
+                    Alignment::Left,
+                )
+                .value(usize::from(!enabled)),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for ConnectionSummary {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_radio_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::ConnectionSummaryBlurDown),
+            Msg::Config(ConfigMsg::ConnectionSummaryBlurUp),
+        )
+    }
+}
+
+#[derive(MockComponent)]
+pub struct HideFooter {
+    component: Radio,
+}
+
+impl HideFooter {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            component: Radio::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightGreen)
+                        .modifiers(BorderType::Rounded),
+                )
+                .choices(["Yes", "No"])
+                .foreground(Color::LightGreen)
+                .rewind(true)
+                .title("Hide footer in file explorer?", Alignment::Left)
+                .value(usize::from(!enabled)),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for HideFooter {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_radio_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::HideFooterBlurDown),
+            Msg::Config(ConfigMsg::HideFooterBlurUp),
+        )
+    }
+}
+
 #[derive(MockComponent)]
 pub struct LocalFileFmt {
     component: Input,
@@ -315,6 +567,133 @@ impl Component<Msg, NoUserEvent> for NotificationsThreshold {
     }
 }
 
+#[derive(MockComponent)]
+pub struct ConfirmationTimeout {
+    component: Input,
+}
+
+impl ConfirmationTimeout {
+    pub fn new(value: &str) -> Self {
+        // -- validators
+        fn validate(secs: &str) -> bool {
+            secs.is_empty() || secs.parse::<u64>().is_ok()
+        }
+        fn char_valid(_input: &str, incoming: char) -> bool {
+            incoming.is_ascii_digit()
+        }
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightRed)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(Color::LightRed)
+                .invalid_style(Style::default().fg(Color::Red))
+                .input_type(InputType::Custom(validate, char_valid))
+                .placeholder("never", Style::default().fg(Color::Rgb(128, 128, 128)))
+                .title("Confirmation popups timeout (seconds)", Alignment::Left)
+                .value(value),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for ConfirmationTimeout {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_input_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::ConfirmationTimeoutBlurDown),
+            Msg::Config(ConfigMsg::ConfirmationTimeoutBlurUp),
+        )
+    }
+}
+
+#[derive(MockComponent)]
+pub struct DateFmt {
+    component: Input,
+}
+
+impl DateFmt {
+    pub fn new(value: &str) -> Self {
+        fn validate(fmt: &str) -> bool {
+            is_valid_date_fmt(fmt)
+        }
+        fn char_valid(_input: &str, _incoming: char) -> bool {
+            true
+        }
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightGreen)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(Color::LightGreen)
+                .invalid_style(Style::default().fg(Color::Red))
+                .input_type(InputType::Custom(validate, char_valid))
+                .placeholder(
+                    "%b %d %Y %H:%M",
+                    Style::default().fg(Color::Rgb(128, 128, 128)),
+                )
+                .title("Date format (strftime or \"relative\")", Alignment::Left)
+                .value(value),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for DateFmt {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_input_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::DateFmtBlurDown),
+            Msg::Config(ConfigMsg::DateFmtBlurUp),
+        )
+    }
+}
+
+#[derive(MockComponent)]
+pub struct Locale {
+    component: Input,
+}
+
+impl Locale {
+    pub fn new(value: &str) -> Self {
+        fn validate(locale: &str) -> bool {
+            locale.is_empty() || LocaleFmt::from_name(locale).is_some()
+        }
+        fn char_valid(_input: &str, incoming: char) -> bool {
+            incoming.is_ascii_alphanumeric() || matches!(incoming, '_' | '-' | '.' | '@')
+        }
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightBlue)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(Color::LightBlue)
+                .invalid_style(Style::default().fg(Color::Red))
+                .input_type(InputType::Custom(validate, char_valid))
+                .placeholder("en_US", Style::default().fg(Color::Rgb(128, 128, 128)))
+                .title("Locale (e.g. de_DE or \"system\")", Alignment::Left)
+                .value(value),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for Locale {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_input_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::LocaleBlurDown),
+            Msg::Config(ConfigMsg::LocaleBlurUp),
+        )
+    }
+}
+
 #[derive(MockComponent)]
 pub struct RemoteFileFmt {
     component: Input,
@@ -423,6 +802,43 @@ impl Component<Msg, NoUserEvent> for TextEditor {
     }
 }
 
+#[derive(MockComponent)]
+pub struct OpenWith {
+    component: Input,
+}
+
+impl OpenWith {
+    pub fn new(value: &str) -> Self {
+        Self {
+            component: Input::default()
+                .borders(
+                    Borders::default()
+                        .color(Color::LightCyan)
+                        .modifiers(BorderType::Rounded),
+                )
+                .foreground(Color::LightCyan)
+                .input_type(InputType::Text)
+                .placeholder(
+                    "md=glow {}; png=feh",
+                    Style::default().fg(Color::Rgb(128, 128, 128)),
+                )
+                .title("Open files with (ext=command; ...)", Alignment::Left)
+                .value(value),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for OpenWith {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        handle_input_ev(
+            self,
+            ev,
+            Msg::Config(ConfigMsg::OpenWithBlurDown),
+            Msg::Config(ConfigMsg::OpenWithBlurUp),
+        )
+    }
+}
+
 // -- event handler
 
 fn handle_input_ev(
@@ -12,8 +12,10 @@ mod theme;
 
 pub(super) use commons::{ErrorPopup, Footer, Header, Keybindings, QuitPopup, SavePopup};
 pub(super) use config::{
-    CheckUpdates, DefaultProtocol, GroupDirs, HiddenFiles, LocalFileFmt, NotificationsEnabled,
-    NotificationsThreshold, PromptOnFileReplace, RemoteFileFmt, SshConfig, TextEditor,
+    CheckUpdates, ConfirmationTimeout, ConnectionSummary, DateFmt, DefaultProtocol, GroupDirs,
+    HiddenFiles, HideFooter, KeybindingsPreset, LocalFileFmt, Locale, NotificationsEnabled,
+    NotificationsThreshold, OpenWith, PromptOnFileReplace, PromptOnSaveAsReplace, QuitImmediately,
+    RemoteFileFmt, Rsync, SshConfig, TextEditor, TitlePathTail,
 };
 pub(super) use ssh::{DelSshKeyPopup, SshHost, SshKeys, SshUsername};
 pub(super) use theme::*;
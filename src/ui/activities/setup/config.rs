@@ -8,6 +8,7 @@
 use std::env;
 
 use super::SetupActivity;
+use crate::config::keybindings::KeyBindings;
 
 impl SetupActivity {
     /// Save configuration
@@ -43,6 +44,15 @@ impl SetupActivity {
             .map_err(|e| format!("Could not restore theme: {e}"))
     }
 
+    /// Replace the keybindings with `keybindings` and save them to file
+    pub(super) fn save_keybindings(&mut self, keybindings: KeyBindings) -> Result<(), String> {
+        let provider = self.context_mut().keybindings_mut();
+        *provider.keybindings_mut() = keybindings;
+        provider
+            .save()
+            .map_err(|e| format!("Could not save keybindings: {e}"))
+    }
+
     /// Delete ssh key from config cli
     pub(super) fn delete_ssh_key(&mut self, host: &str, username: &str) -> Result<(), String> {
         match self.config_mut().del_ssh_key(host, username) {
@@ -20,7 +20,7 @@ use tuirealm::props::Color;
 use tuirealm::{Application, NoUserEvent, Update};
 
 use super::{Activity, CROSSTERM_MAX_POLL, Context, ExitReason};
-use crate::config::keybindings::KeyBindings;
+use crate::config::keybindings::{KeyBindings, KeyBindingsPreset};
 use crate::config::themes::Theme;
 use crate::system::config_client::ConfigClient;
 use crate::system::theme_provider::ThemeProvider;
@@ -58,16 +58,27 @@ enum IdCommon {
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
 enum IdConfig {
     CheckUpdates,
+    ConfirmationTimeout,
+    ConnectionSummary,
+    DateFmt,
     DefaultProtocol,
     GroupDirs,
     HiddenFiles,
+    HideFooter,
+    KeybindingsPreset,
     LocalFileFmt,
+    Locale,
     NotificationsEnabled,
     NotificationsThreshold,
+    OpenWith,
     PromptOnFileReplace,
+    PromptOnSaveAsReplace,
+    QuitImmediately,
     RemoteFileFmt,
+    Rsync,
     SshConfig,
     TextEditor,
+    TitlePathTail,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
@@ -144,26 +155,48 @@ pub enum ConfigMsg {
     CheckUpdatesBlurDown,
     CheckUpdatesBlurUp,
     ConfigChanged,
+    ConfirmationTimeoutBlurDown,
+    ConfirmationTimeoutBlurUp,
+    ConnectionSummaryBlurDown,
+    ConnectionSummaryBlurUp,
+    DateFmtBlurDown,
+    DateFmtBlurUp,
     DefaultProtocolBlurDown,
     DefaultProtocolBlurUp,
     GroupDirsBlurDown,
     GroupDirsBlurUp,
     HiddenFilesBlurDown,
     HiddenFilesBlurUp,
+    HideFooterBlurDown,
+    HideFooterBlurUp,
+    KeybindingsPresetBlurDown,
+    KeybindingsPresetBlurUp,
     LocalFileFmtBlurDown,
     LocalFileFmtBlurUp,
+    LocaleBlurDown,
+    LocaleBlurUp,
     NotificationsEnabledBlurDown,
     NotificationsEnabledBlurUp,
     NotificationsThresholdBlurDown,
     NotificationsThresholdBlurUp,
+    OpenWithBlurDown,
+    OpenWithBlurUp,
     PromptOnFileReplaceBlurDown,
     PromptOnFileReplaceBlurUp,
+    PromptOnSaveAsReplaceBlurDown,
+    PromptOnSaveAsReplaceBlurUp,
+    QuitImmediatelyBlurDown,
+    QuitImmediatelyBlurUp,
     RemoteFileFmtBlurDown,
     RemoteFileFmtBlurUp,
+    RsyncBlurDown,
+    RsyncBlurUp,
     SshConfigBlurDown,
     SshConfigBlurUp,
     TextEditorBlurDown,
     TextEditorBlurUp,
+    TitlePathTailBlurDown,
+    TitlePathTailBlurUp,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -255,6 +288,8 @@ pub struct SetupActivity {
     exit_reason: Option<ExitReason>,
     context: Option<Context>, // Context holder
     layout: ViewLayout,       // View layout
+    /// Keybindings preset to write to the keybindings file on save
+    keybindings_preset: Option<KeyBindingsPreset>,
     redraw: bool,
 }
 
@@ -269,6 +304,7 @@ impl SetupActivity {
             exit_reason: None,
             context: None,
             layout: ViewLayout::SetupForm,
+            keybindings_preset: None,
             redraw: true, // Draw at first `on_draw`
         }
     }
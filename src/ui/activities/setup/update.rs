@@ -112,6 +112,32 @@ impl SetupActivity {
             ConfigMsg::CheckUpdatesBlurUp => {
                 assert!(self.app.active(&Id::Config(IdConfig::HiddenFiles)).is_ok());
             }
+            ConfigMsg::ConfirmationTimeoutBlurDown => {
+                assert!(
+                    self.app
+                        .active(&Id::Config(IdConfig::ConnectionSummary))
+                        .is_ok()
+                );
+            }
+            ConfigMsg::ConfirmationTimeoutBlurUp => {
+                assert!(self.app.active(&Id::Config(IdConfig::Rsync)).is_ok());
+            }
+            ConfigMsg::ConnectionSummaryBlurDown => {
+                assert!(self.app.active(&Id::Config(IdConfig::HideFooter)).is_ok());
+            }
+            ConfigMsg::ConnectionSummaryBlurUp => {
+                assert!(
+                    self.app
+                        .active(&Id::Config(IdConfig::ConfirmationTimeout))
+                        .is_ok()
+                );
+            }
+            ConfigMsg::DateFmtBlurDown => {
+                assert!(self.app.active(&Id::Config(IdConfig::Locale)).is_ok());
+            }
+            ConfigMsg::DateFmtBlurUp => {
+                assert!(self.app.active(&Id::Config(IdConfig::GroupDirs)).is_ok());
+            }
             ConfigMsg::DefaultProtocolBlurDown => {
                 assert!(self.app.active(&Id::Config(IdConfig::HiddenFiles)).is_ok());
             }
@@ -119,7 +145,7 @@ impl SetupActivity {
                 assert!(self.app.active(&Id::Config(IdConfig::TextEditor)).is_ok());
             }
             ConfigMsg::GroupDirsBlurDown => {
-                assert!(self.app.active(&Id::Config(IdConfig::LocalFileFmt)).is_ok());
+                assert!(self.app.active(&Id::Config(IdConfig::DateFmt)).is_ok());
             }
             ConfigMsg::GroupDirsBlurUp => {
                 assert!(
@@ -138,6 +164,20 @@ impl SetupActivity {
                         .is_ok()
                 );
             }
+            ConfigMsg::HideFooterBlurDown => {
+                assert!(
+                    self.app
+                        .active(&Id::Config(IdConfig::PromptOnSaveAsReplace))
+                        .is_ok()
+                );
+            }
+            ConfigMsg::HideFooterBlurUp => {
+                assert!(
+                    self.app
+                        .active(&Id::Config(IdConfig::ConnectionSummary))
+                        .is_ok()
+                );
+            }
             ConfigMsg::LocalFileFmtBlurDown => {
                 assert!(
                     self.app
@@ -145,8 +185,32 @@ impl SetupActivity {
                         .is_ok()
                 );
             }
+            ConfigMsg::KeybindingsPresetBlurDown => {
+                assert!(self.app.active(&Id::Config(IdConfig::LocalFileFmt)).is_ok());
+            }
+            ConfigMsg::KeybindingsPresetBlurUp => {
+                assert!(
+                    self.app
+                        .active(&Id::Config(IdConfig::TitlePathTail))
+                        .is_ok()
+                );
+            }
             ConfigMsg::LocalFileFmtBlurUp => {
-                assert!(self.app.active(&Id::Config(IdConfig::GroupDirs)).is_ok());
+                assert!(
+                    self.app
+                        .active(&Id::Config(IdConfig::KeybindingsPreset))
+                        .is_ok()
+                );
+            }
+            ConfigMsg::LocaleBlurDown => {
+                assert!(
+                    self.app
+                        .active(&Id::Config(IdConfig::QuitImmediately))
+                        .is_ok()
+                );
+            }
+            ConfigMsg::LocaleBlurUp => {
+                assert!(self.app.active(&Id::Config(IdConfig::DateFmt)).is_ok());
             }
             ConfigMsg::NotificationsEnabledBlurDown => {
                 assert!(
@@ -185,9 +249,45 @@ impl SetupActivity {
                         .is_ok()
                 );
             }
+            ConfigMsg::OpenWithBlurDown => {
+                assert!(self.app.active(&Id::Config(IdConfig::TextEditor)).is_ok());
+            }
+            ConfigMsg::OpenWithBlurUp => {
+                assert!(
+                    self.app
+                        .active(&Id::Config(IdConfig::PromptOnSaveAsReplace))
+                        .is_ok()
+                );
+            }
+            ConfigMsg::PromptOnSaveAsReplaceBlurDown => {
+                assert!(self.app.active(&Id::Config(IdConfig::OpenWith)).is_ok());
+            }
+            ConfigMsg::PromptOnSaveAsReplaceBlurUp => {
+                assert!(self.app.active(&Id::Config(IdConfig::HideFooter)).is_ok());
+            }
+            ConfigMsg::QuitImmediatelyBlurDown => {
+                assert!(
+                    self.app
+                        .active(&Id::Config(IdConfig::TitlePathTail))
+                        .is_ok()
+                );
+            }
+            ConfigMsg::QuitImmediatelyBlurUp => {
+                assert!(self.app.active(&Id::Config(IdConfig::Locale)).is_ok());
+            }
             ConfigMsg::RemoteFileFmtBlurUp => {
                 assert!(self.app.active(&Id::Config(IdConfig::LocalFileFmt)).is_ok());
             }
+            ConfigMsg::RsyncBlurDown => {
+                assert!(
+                    self.app
+                        .active(&Id::Config(IdConfig::ConfirmationTimeout))
+                        .is_ok()
+                );
+            }
+            ConfigMsg::RsyncBlurUp => {
+                assert!(self.app.active(&Id::Config(IdConfig::SshConfig)).is_ok());
+            }
             ConfigMsg::TextEditorBlurDown => {
                 assert!(
                     self.app
@@ -196,10 +296,24 @@ impl SetupActivity {
                 );
             }
             ConfigMsg::TextEditorBlurUp => {
-                assert!(self.app.active(&Id::Config(IdConfig::SshConfig)).is_ok());
+                assert!(self.app.active(&Id::Config(IdConfig::OpenWith)).is_ok());
+            }
+            ConfigMsg::TitlePathTailBlurDown => {
+                assert!(
+                    self.app
+                        .active(&Id::Config(IdConfig::KeybindingsPreset))
+                        .is_ok()
+                );
+            }
+            ConfigMsg::TitlePathTailBlurUp => {
+                assert!(
+                    self.app
+                        .active(&Id::Config(IdConfig::QuitImmediately))
+                        .is_ok()
+                );
             }
             ConfigMsg::SshConfigBlurDown => {
-                assert!(self.app.active(&Id::Config(IdConfig::TextEditor)).is_ok());
+                assert!(self.app.active(&Id::Config(IdConfig::Rsync)).is_ok());
             }
             ConfigMsg::SshConfigBlurUp => {
                 assert!(
@@ -97,11 +97,12 @@ impl SetupActivity {
 
     /// Mount help
     pub(super) fn mount_help(&mut self) {
+        let keybindings = self.keybindings().clone();
         assert!(
             self.app
                 .remount(
                     Id::Common(IdCommon::Keybindings),
-                    Box::<components::Keybindings>::default(),
+                    Box::new(components::Keybindings::new(&keybindings)),
                     vec![],
                 )
                 .is_ok()
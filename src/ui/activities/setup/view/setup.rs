@@ -14,13 +14,15 @@ use super::{
     Context, Id, IdCommon, IdConfig, RADIO_PROTOCOL_KUBE, RADIO_PROTOCOL_WEBDAV, SetupActivity,
     ViewLayout, components,
 };
+use crate::config::keybindings::KeyBindingsPreset;
 use crate::explorer::GroupDirs;
 use crate::filetransfer::FileTransferProtocol;
 use crate::ui::activities::setup::{
     RADIO_PROTOCOL_FTP, RADIO_PROTOCOL_FTPS, RADIO_PROTOCOL_S3, RADIO_PROTOCOL_SCP,
     RADIO_PROTOCOL_SMB,
 };
-use crate::utils::fmt::fmt_bytes;
+use crate::utils::fmt::{fmt_bytes, fmt_open_with};
+use crate::utils::parser::parse_open_with;
 
 impl SetupActivity {
     // -- view
@@ -70,6 +72,11 @@ impl SetupActivity {
                         Constraint::Length(3), // Updates tab
                         Constraint::Length(3), // Prompt file replace
                         Constraint::Length(3), // Group dirs
+                        Constraint::Length(3), // Date format
+                        Constraint::Length(3), // Locale
+                        Constraint::Length(3), // Quit immediately
+                        Constraint::Length(3), // Title path tail
+                        Constraint::Length(3), // Keybindings preset
                         Constraint::Length(1), // Prevent overflow
                     ]
                     .as_ref(),
@@ -96,6 +103,25 @@ impl SetupActivity {
             );
             self.app
                 .view(&Id::Config(IdConfig::GroupDirs), f, ui_cfg_chunks_col1[5]);
+            self.app
+                .view(&Id::Config(IdConfig::DateFmt), f, ui_cfg_chunks_col1[6]);
+            self.app
+                .view(&Id::Config(IdConfig::Locale), f, ui_cfg_chunks_col1[7]);
+            self.app.view(
+                &Id::Config(IdConfig::QuitImmediately),
+                f,
+                ui_cfg_chunks_col1[8],
+            );
+            self.app.view(
+                &Id::Config(IdConfig::TitlePathTail),
+                f,
+                ui_cfg_chunks_col1[9],
+            );
+            self.app.view(
+                &Id::Config(IdConfig::KeybindingsPreset),
+                f,
+                ui_cfg_chunks_col1[10],
+            );
             // Column 2
             let ui_cfg_chunks_col2 = Layout::default()
                 .direction(Direction::Vertical)
@@ -106,6 +132,12 @@ impl SetupActivity {
                         Constraint::Length(3), // Notifications enabled
                         Constraint::Length(3), // Notifications threshold
                         Constraint::Length(3), // Ssh config
+                        Constraint::Length(3), // Rsync
+                        Constraint::Length(3), // Confirmation timeout
+                        Constraint::Length(3), // Connection summary
+                        Constraint::Length(3), // Hide footer
+                        Constraint::Length(3), // Prompt on save as replace
+                        Constraint::Length(3), // Open with
                         Constraint::Length(1), // Prevent overflow
                     ]
                     .as_ref(),
@@ -133,6 +165,27 @@ impl SetupActivity {
             );
             self.app
                 .view(&Id::Config(IdConfig::SshConfig), f, ui_cfg_chunks_col2[4]);
+            self.app
+                .view(&Id::Config(IdConfig::Rsync), f, ui_cfg_chunks_col2[5]);
+            self.app.view(
+                &Id::Config(IdConfig::ConfirmationTimeout),
+                f,
+                ui_cfg_chunks_col2[6],
+            );
+            self.app.view(
+                &Id::Config(IdConfig::ConnectionSummary),
+                f,
+                ui_cfg_chunks_col2[7],
+            );
+            self.app
+                .view(&Id::Config(IdConfig::HideFooter), f, ui_cfg_chunks_col2[8]);
+            self.app.view(
+                &Id::Config(IdConfig::PromptOnSaveAsReplace),
+                f,
+                ui_cfg_chunks_col2[9],
+            );
+            self.app
+                .view(&Id::Config(IdConfig::OpenWith), f, ui_cfg_chunks_col2[10]);
             // Popups
             self.view_popups(f);
         });
@@ -212,6 +265,64 @@ impl SetupActivity {
                 )
                 .is_ok()
         );
+        // Date fmt
+        assert!(
+            self.app
+                .remount(
+                    Id::Config(IdConfig::DateFmt),
+                    Box::new(components::DateFmt::new(
+                        &self.config().get_date_fmt().unwrap_or_default()
+                    )),
+                    vec![]
+                )
+                .is_ok()
+        );
+        // Locale
+        assert!(
+            self.app
+                .remount(
+                    Id::Config(IdConfig::Locale),
+                    Box::new(components::Locale::new(
+                        &self.config().get_locale().unwrap_or_default()
+                    )),
+                    vec![]
+                )
+                .is_ok()
+        );
+        // Quit immediately
+        assert!(
+            self.app
+                .remount(
+                    Id::Config(IdConfig::QuitImmediately),
+                    Box::new(components::QuitImmediately::new(
+                        self.config().get_quit_immediately()
+                    )),
+                    vec![]
+                )
+                .is_ok()
+        );
+        // Title path tail
+        assert!(
+            self.app
+                .remount(
+                    Id::Config(IdConfig::TitlePathTail),
+                    Box::new(components::TitlePathTail::new(
+                        self.config().get_title_path_tail()
+                    )),
+                    vec![]
+                )
+                .is_ok()
+        );
+        // Keybindings preset
+        assert!(
+            self.app
+                .remount(
+                    Id::Config(IdConfig::KeybindingsPreset),
+                    Box::new(components::KeybindingsPreset::new(self.keybindings_preset)),
+                    vec![]
+                )
+                .is_ok()
+        );
         // Local File Fmt
         assert!(
             self.app
@@ -272,6 +383,77 @@ impl SetupActivity {
                 )
                 .is_ok()
         );
+        // Rsync
+        assert!(
+            self.app
+                .remount(
+                    Id::Config(IdConfig::Rsync),
+                    Box::new(components::Rsync::new(self.config().get_rsync())),
+                    vec![]
+                )
+                .is_ok()
+        );
+        // Confirmation timeout
+        assert!(
+            self.app
+                .remount(
+                    Id::Config(IdConfig::ConfirmationTimeout),
+                    Box::new(components::ConfirmationTimeout::new(
+                        &self
+                            .config()
+                            .get_confirmation_timeout()
+                            .map(|secs| secs.to_string())
+                            .unwrap_or_default()
+                    )),
+                    vec![]
+                )
+                .is_ok()
+        );
+        // Connection summary
+        assert!(
+            self.app
+                .remount(
+                    Id::Config(IdConfig::ConnectionSummary),
+                    Box::new(components::ConnectionSummary::new(
+                        self.config().get_connection_summary()
+                    )),
+                    vec![]
+                )
+                .is_ok()
+        );
+        // Hide footer
+        assert!(
+            self.app
+                .remount(
+                    Id::Config(IdConfig::HideFooter),
+                    Box::new(components::HideFooter::new(self.config().get_hide_footer())),
+                    vec![]
+                )
+                .is_ok()
+        );
+        // Prompt on save as replace
+        assert!(
+            self.app
+                .remount(
+                    Id::Config(IdConfig::PromptOnSaveAsReplace),
+                    Box::new(components::PromptOnSaveAsReplace::new(
+                        self.config().get_prompt_on_save_as_replace()
+                    )),
+                    vec![]
+                )
+                .is_ok()
+        );
+        // Open with
+        let open_with = fmt_open_with(&self.config().get_open_with());
+        assert!(
+            self.app
+                .remount(
+                    Id::Config(IdConfig::OpenWith),
+                    Box::new(components::OpenWith::new(open_with.as_str())),
+                    vec![]
+                )
+                .is_ok()
+        );
     }
 
     /// Collect values from input and put them into the configuration
@@ -315,6 +497,36 @@ impl SetupActivity {
             let check: bool = matches!(opt, 0);
             self.config_mut().set_prompt_on_file_replace(check);
         }
+        if let Ok(State::One(StateValue::String(fmt))) =
+            self.app.state(&Id::Config(IdConfig::DateFmt))
+        {
+            self.config_mut().set_date_fmt(fmt);
+        }
+        if let Ok(State::One(StateValue::String(locale))) =
+            self.app.state(&Id::Config(IdConfig::Locale))
+        {
+            self.config_mut().set_locale(locale);
+        }
+        if let Ok(State::One(StateValue::Usize(opt))) =
+            self.app.state(&Id::Config(IdConfig::QuitImmediately))
+        {
+            self.config_mut().set_quit_immediately(opt == 0);
+        }
+        if let Ok(State::One(StateValue::Usize(opt))) =
+            self.app.state(&Id::Config(IdConfig::TitlePathTail))
+        {
+            self.config_mut().set_title_path_tail(opt == 0);
+        }
+        if let Ok(State::One(StateValue::Usize(opt))) =
+            self.app.state(&Id::Config(IdConfig::KeybindingsPreset))
+        {
+            self.keybindings_preset = match opt {
+                1 => Some(KeyBindingsPreset::Default),
+                2 => Some(KeyBindingsPreset::Vim),
+                3 => Some(KeyBindingsPreset::Emacs),
+                _ => None,
+            };
+        }
         if let Ok(State::One(StateValue::String(fmt))) =
             self.app.state(&Id::Config(IdConfig::LocalFileFmt))
         {
@@ -346,6 +558,37 @@ impl SetupActivity {
         {
             self.config_mut().set_notification_threshold(bytes);
         }
+        if let Ok(State::One(StateValue::Usize(opt))) = self.app.state(&Id::Config(IdConfig::Rsync))
+        {
+            self.config_mut().set_rsync(opt == 0);
+        }
+        if let Ok(State::One(StateValue::String(secs))) =
+            self.app.state(&Id::Config(IdConfig::ConfirmationTimeout))
+        {
+            self.config_mut()
+                .set_confirmation_timeout(secs.parse::<u64>().ok());
+        }
+        if let Ok(State::One(StateValue::Usize(opt))) =
+            self.app.state(&Id::Config(IdConfig::ConnectionSummary))
+        {
+            self.config_mut().set_connection_summary(opt == 0);
+        }
+        if let Ok(State::One(StateValue::Usize(opt))) =
+            self.app.state(&Id::Config(IdConfig::HideFooter))
+        {
+            self.config_mut().set_hide_footer(opt == 0);
+        }
+        if let Ok(State::One(StateValue::Usize(opt))) =
+            self.app.state(&Id::Config(IdConfig::PromptOnSaveAsReplace))
+        {
+            self.config_mut().set_prompt_on_save_as_replace(opt == 0);
+        }
+        if let Ok(State::One(StateValue::String(open_with))) =
+            self.app.state(&Id::Config(IdConfig::OpenWith))
+        {
+            self.config_mut()
+                .set_open_with(parse_open_with(open_with.as_str()));
+        }
         if let Ok(State::One(StateValue::String(mut path))) =
             self.app.state(&Id::Config(IdConfig::SshConfig))
         {
@@ -16,6 +16,9 @@ use crate::system::theme_provider::ThemeProvider;
 pub struct Context {
     host_bridge_params: Option<HostBridgeParams>,
     remote_params: Option<FileTransferParams>,
+    retry_params: Option<FileTransferParams>,
+    last_connection: Option<LastConnection>,
+    bookmark_name: Option<String>,
     bookmarks_client: Option<BookmarksClient>,
     config_client: ConfigClient,
     keybindings_provider: KeyBindingsProvider,
@@ -38,11 +41,14 @@ impl Context {
         let _ = terminal.disable_mouse_capture();
 
         Context {
+            bookmark_name: None,
             bookmarks_client,
             config_client,
             host_bridge_params: None,
             keybindings_provider,
+            last_connection: None,
             remote_params: None,
+            retry_params: None,
             store: Store::init(),
             terminal,
             theme_provider,
@@ -60,6 +66,11 @@ impl Context {
         self.host_bridge_params.as_ref()
     }
 
+    /// Get the name of the bookmark the remote params were loaded from, if any
+    pub fn bookmark_name(&self) -> Option<&str> {
+        self.bookmark_name.as_deref()
+    }
+
     pub fn bookmarks_client(&self) -> Option<&BookmarksClient> {
         self.bookmarks_client.as_ref()
     }
@@ -96,7 +107,6 @@ impl Context {
         &self.keybindings_provider
     }
 
-    #[allow(dead_code)]
     pub fn keybindings_mut(&mut self) -> &mut KeyBindingsProvider {
         &mut self.keybindings_provider
     }
@@ -111,6 +121,10 @@ impl Context {
         self.remote_params = Some(params);
     }
 
+    pub fn set_bookmark_name(&mut self, name: Option<String>) {
+        self.bookmark_name = name;
+    }
+
     pub fn set_host_bridge_params(&mut self, params: HostBridgeParams) {
         self.host_bridge_params = Some(params);
     }
@@ -125,6 +139,65 @@ impl Context {
     pub fn set_error(&mut self, error: String) {
         self.error = Some(error);
     }
+
+    // -- retry params
+
+    /// Get the params to prefill the authentication form with and remove them from the context
+    pub fn retry_params(&mut self) -> Option<FileTransferParams> {
+        self.retry_params.take()
+    }
+
+    pub fn set_retry_params(&mut self, params: FileTransferParams) {
+        self.retry_params = Some(params);
+    }
+
+    // -- last connection
+
+    /// Get the last connection which was established, to reconnect with
+    pub fn last_connection(&self) -> Option<&LastConnection> {
+        self.last_connection.as_ref()
+    }
+
+    /// Remember the current params as the last connection, if the connection with them was established
+    pub fn capture_last_connection(&mut self, connected: bool) {
+        capture_last_connection(
+            &mut self.last_connection,
+            connected,
+            self.host_bridge_params.as_ref(),
+            self.remote_params.as_ref(),
+            self.bookmark_name.as_deref(),
+        );
+    }
+}
+
+/// Params of a connection which was established, to connect with again in one keystroke
+#[derive(Debug, Clone)]
+pub struct LastConnection {
+    pub host_bridge_params: HostBridgeParams,
+    /// Remote params, including the password used in the session, if any
+    pub remote_params: FileTransferParams,
+    /// Name of the bookmark the connection was loaded from, if any
+    pub bookmark_name: Option<String>,
+}
+
+/// Replace `last` with the connection made with the given params, only if it was established
+fn capture_last_connection(
+    last: &mut Option<LastConnection>,
+    connected: bool,
+    host_bridge_params: Option<&HostBridgeParams>,
+    remote_params: Option<&FileTransferParams>,
+    bookmark_name: Option<&str>,
+) {
+    if !connected {
+        return;
+    }
+    if let (Some(host_bridge_params), Some(remote_params)) = (host_bridge_params, remote_params) {
+        *last = Some(LastConnection {
+            host_bridge_params: host_bridge_params.clone(),
+            remote_params: remote_params.clone(),
+            bookmark_name: bookmark_name.map(String::from),
+        });
+    }
 }
 
 impl Drop for Context {
@@ -134,3 +207,92 @@ impl Drop for Context {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use std::path::{Path, PathBuf};
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::filetransfer::FileTransferProtocol;
+    use crate::filetransfer::params::{GenericProtocolParams, ProtocolParams};
+
+    fn host_bridge_params() -> HostBridgeParams {
+        HostBridgeParams::Localhost(PathBuf::from("/home/pi"))
+    }
+
+    fn remote_params(address: &str) -> FileTransferParams {
+        FileTransferParams::new(
+            FileTransferProtocol::Sftp,
+            ProtocolParams::Generic(
+                GenericProtocolParams::default()
+                    .address(address)
+                    .port(22)
+                    .username(Some("pi"))
+                    .password(Some("secret")),
+            ),
+        )
+    }
+
+    #[test]
+    fn should_capture_last_connection_once_connected() {
+        let mut last = None;
+        capture_last_connection(
+            &mut last,
+            true,
+            Some(&host_bridge_params()),
+            Some(&remote_params("192.168.1.31")),
+            Some("raspberry"),
+        );
+        let last = last.unwrap();
+        assert_eq!(last.remote_params.params.host_name(), "192.168.1.31");
+        assert_eq!(
+            last.remote_params
+                .params
+                .generic_params()
+                .unwrap()
+                .password
+                .as_deref(),
+            Some("secret")
+        );
+        assert_eq!(last.bookmark_name.as_deref(), Some("raspberry"));
+        assert!(matches!(
+            last.host_bridge_params,
+            HostBridgeParams::Localhost(path) if path == Path::new("/home/pi")
+        ));
+    }
+
+    #[test]
+    fn should_keep_last_connection_if_connection_failed() {
+        let mut last = None;
+        capture_last_connection(
+            &mut last,
+            true,
+            Some(&host_bridge_params()),
+            Some(&remote_params("192.168.1.31")),
+            None,
+        );
+        capture_last_connection(
+            &mut last,
+            false,
+            Some(&host_bridge_params()),
+            Some(&remote_params("10.0.0.1")),
+            None,
+        );
+        assert_eq!(
+            last.unwrap().remote_params.params.host_name(),
+            "192.168.1.31"
+        );
+    }
+
+    #[test]
+    fn should_not_capture_last_connection_without_params() {
+        let mut last = None;
+        capture_last_connection(&mut last, true, None, Some(&remote_params("::1")), None);
+        assert!(last.is_none());
+        capture_last_connection(&mut last, true, Some(&host_bridge_params()), None, None);
+        assert!(last.is_none());
+    }
+}
@@ -2,13 +2,44 @@
 //!
 //! `fmt` is the module which provides utilities for formatting
 
-use std::path::{Path, PathBuf};
+use std::collections::HashMap;
+use std::path::{MAIN_SEPARATOR, Path, PathBuf};
 use std::time::{Duration, SystemTime};
 
+use chrono::format::{Item, StrftimeItems};
 use chrono::prelude::*;
 use remotefs::fs::UnixPexClass;
 use tuirealm::ratatui::style::Color;
-use unicode_width::UnicodeWidthStr;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::utils::locale::Locale;
+
+/// Units of human readable sizes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SizeUnits {
+    /// 1024-based units (`KiB`, `MiB`, ...)
+    #[default]
+    Binary,
+    /// 1000-based units (`kB`, `MB`, ...)
+    Si,
+}
+
+impl SizeUnits {
+    fn base(self) -> u64 {
+        match self {
+            Self::Binary => 1024,
+            Self::Si => 1000,
+        }
+    }
+
+    /// Units after bytes
+    fn suffixes(self) -> [&'static str; 6] {
+        match self {
+            Self::Binary => ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"],
+            Self::Si => ["kB", "MB", "GB", "TB", "PB", "EB"],
+        }
+    }
+}
 
 /// Convert permissions bytes of permissions value into ls notation (e.g. rwx,-wx,--x)
 pub fn fmt_pex(pex: UnixPexClass) -> String {
@@ -35,6 +66,37 @@ pub fn fmt_time(time: SystemTime, fmt: &str) -> String {
     format!("{}", datetime.format(fmt))
 }
 
+/// Date format which renders times relative to now (e.g. `2h ago`)
+pub const DATE_FMT_RELATIVE: &str = "relative";
+
+/// Returns whether `fmt` is either a valid strftime pattern or the relative date format
+pub fn is_valid_date_fmt(fmt: &str) -> bool {
+    fmt == DATE_FMT_RELATIVE || !StrftimeItems::new(fmt).any(|item| matches!(item, Item::Error))
+}
+
+/// Format a date with `fmt`, which is either a strftime pattern or the relative date format
+pub fn fmt_date(time: SystemTime, fmt: &str) -> String {
+    if fmt == DATE_FMT_RELATIVE {
+        fmt_time_relative(time, SystemTime::now())
+    } else {
+        fmt_time(time, fmt)
+    }
+}
+
+/// Format `time` relatively to `now` (e.g. `5m ago`, `2h ago`, `3d ago`)
+pub fn fmt_time_relative(time: SystemTime, now: SystemTime) -> String {
+    // Times in the future (e.g. clock skew between hosts) are reported as now
+    let secs = now.duration_since(time).unwrap_or_default().as_secs();
+    match secs {
+        0..60 => String::from("just now"),
+        60..3600 => format!("{}m ago", secs / 60),
+        3600..86400 => format!("{}h ago", secs / 3600),
+        86400..2592000 => format!("{}d ago", secs / 86400),
+        2592000..31536000 => format!("{}mo ago", secs / 2592000),
+        _ => format!("{}y ago", secs / 31536000),
+    }
+}
+
 /// Format duration as {secs}.{millis}
 pub fn fmt_millis(duration: Duration) -> String {
     let seconds: u128 = duration.as_millis() / 1000;
@@ -80,6 +142,85 @@ pub fn fmt_path_elide_ex(p: &Path, width: usize, extra_len: usize) -> String {
     }
 }
 
+/// Shorten a path if longer than width, keeping its tail visible
+/// In this case, the path is formatted to …/{PARENT[n]}/…/{PARENT[0]}/{BASENAME}, with as many parents as fit in width.
+/// If not even the basename fits, it is formatted to …{BASENAME_TAIL}
+pub fn fmt_path_tail(p: &Path, width: usize) -> String {
+    let fmt_path: String = format!("{}", p.display());
+    if fmt_path.width() <= width {
+        return fmt_path;
+    }
+    // Keep the longest tail of whole components fitting after '…/'
+    let available = width.saturating_sub(2);
+    if let Some(tail) = fmt_path
+        .match_indices(MAIN_SEPARATOR)
+        .map(|(idx, _)| &fmt_path[idx + 1..])
+        .find(|tail| !tail.is_empty() && tail.width() <= available)
+    {
+        return format!("…{MAIN_SEPARATOR}{tail}");
+    }
+    if width == 0 {
+        return String::new();
+    }
+    // Keep the last characters fitting after '…'
+    let mut tail: Vec<char> = Vec::new();
+    let mut tail_width: usize = 0;
+    for ch in fmt_path.chars().rev() {
+        let ch_width = ch.width().unwrap_or(0);
+        if tail_width + ch_width > width - 1 {
+            break;
+        }
+        tail_width += ch_width;
+        tail.push(ch);
+    }
+    format!("…{}", tail.into_iter().rev().collect::<String>())
+}
+
+/// Elide a file name in the middle if wider than width, keeping its extension visible
+/// In this case, the name is formatted to {HEAD}…{TAIL}.{EXTENSION}.
+/// The extension is dropped too if there's no room left for the name around it
+pub fn fmt_name_elide(name: &str, width: usize) -> String {
+    if name.width() <= width {
+        return name.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    // Keep the extension only if at least a character of the stem fits before it
+    let (stem, ext) = match name.rfind('.') {
+        Some(idx) if idx > 0 && name[idx..].width() + 2 <= width => name.split_at(idx),
+        _ => (name, ""),
+    };
+    let available = width - 1 - ext.width();
+    let head = width_prefix(stem, available.div_ceil(2));
+    let tail = width_suffix(stem, available - head.width());
+    format!("{head}…{tail}{ext}")
+}
+
+/// Get the longest prefix of `s` fitting in `width` columns
+fn width_prefix(s: &str, width: usize) -> &str {
+    let mut cur_width: usize = 0;
+    for (idx, ch) in s.char_indices() {
+        cur_width += ch.width().unwrap_or(0);
+        if cur_width > width {
+            return &s[..idx];
+        }
+    }
+    s
+}
+
+/// Get the longest suffix of `s` fitting in `width` columns
+fn width_suffix(s: &str, width: usize) -> &str {
+    let mut cur_width: usize = 0;
+    for (idx, ch) in s.char_indices().rev() {
+        cur_width += ch.width().unwrap_or(0);
+        if cur_width > width {
+            return &s[idx + ch.len_utf8()..];
+        }
+    }
+    s
+}
+
 /// Format color
 pub fn fmt_color(color: &Color) -> String {
     match color {
@@ -268,6 +409,47 @@ pub fn fmt_bytes(v: u64) -> String {
     }
 }
 
+/// Format a size in bytes, either human readable with `units` (e.g. `1.5 KiB`) or as the exact count of bytes (e.g. `1536 B`).
+/// If a locale is provided, it's used for the decimal separator and to group the thousands of the exact count
+pub fn fmt_size(size: u64, raw: bool, units: SizeUnits, locale: Option<Locale>) -> String {
+    if raw {
+        return match locale {
+            Some(locale) => format!("{} B", locale.fmt_number(size)),
+            None => format!("{size} B"),
+        };
+    }
+    let base = units.base();
+    if size < base {
+        return format!("{size} B");
+    }
+    let suffixes = units.suffixes();
+    let base = base as f64;
+    let mut value = size as f64 / base;
+    let mut unit = 0;
+    // Move to the next unit when the value would be rounded up to the base
+    while value >= base - 0.05 && unit < suffixes.len() - 1 {
+        value /= base;
+        unit += 1;
+    }
+    let fmt = format!("{value:.1} {}", suffixes[unit]);
+    match locale {
+        Some(locale) => locale.fmt_size(&fmt),
+        None => fmt,
+    }
+}
+
+/// Format the association between file extension and command to open the files with as `ext=command` entries
+/// separated by `;`, sorted by extension (e.g. `md=glow {}; png=feh`)
+pub fn fmt_open_with(open_with: &HashMap<String, String>) -> String {
+    let mut entries: Vec<(&String, &String)> = open_with.iter().collect();
+    entries.sort();
+    entries
+        .into_iter()
+        .map(|(ext, command)| format!("{ext}={command}"))
+        .collect::<Vec<String>>()
+        .join("; ")
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -291,6 +473,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_fmt_date_with_custom_pattern() {
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(86400 * 365);
+        let expected = fmt_time(time, "%d/%m/%Y");
+        assert_eq!(fmt_date(time, "%d/%m/%Y"), expected);
+        assert_eq!(expected.len(), 10);
+        assert!(is_valid_date_fmt("%d/%m/%Y %H:%M"));
+        assert!(is_valid_date_fmt(DATE_FMT_RELATIVE));
+        assert!(!is_valid_date_fmt("%d/%m/%Y %Q"));
+    }
+
+    #[test]
+    fn should_fmt_time_relative() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+        let ago = |secs: u64| fmt_time_relative(now - Duration::from_secs(secs), now);
+        assert_eq!(ago(0), "just now");
+        assert_eq!(ago(59), "just now");
+        assert_eq!(ago(60 * 5), "5m ago");
+        assert_eq!(ago(3600 * 2 + 59), "2h ago");
+        assert_eq!(ago(86400 * 3), "3d ago");
+        assert_eq!(ago(86400 * 62), "2mo ago");
+        assert_eq!(ago(86400 * 800), "2y ago");
+        // future
+        assert_eq!(
+            fmt_time_relative(now + Duration::from_secs(120), now),
+            "just now"
+        );
+    }
+
     #[test]
     fn test_utils_fmt_millis() {
         assert_eq!(
@@ -319,6 +530,76 @@ mod tests {
         assert_eq!(fmt_path_elide(p, 16), String::from("/develop/…/foo/bar"));
     }
 
+    #[test]
+    fn should_fmt_path_tail() {
+        let p: &Path = Path::new("/home/omar/projects/termscp/src/ui");
+        // Under max size
+        assert_eq!(
+            fmt_path_tail(p, 64),
+            String::from("/home/omar/projects/termscp/src/ui")
+        );
+        assert_eq!(
+            fmt_path_tail(p, 34),
+            String::from("/home/omar/projects/termscp/src/ui")
+        );
+        // Keep as many trailing components as fit
+        assert_eq!(
+            fmt_path_tail(p, 33),
+            String::from("…/omar/projects/termscp/src/ui")
+        );
+        assert_eq!(fmt_path_tail(p, 20), String::from("…/termscp/src/ui"));
+        assert_eq!(fmt_path_tail(p, 8), String::from("…/src/ui"));
+        assert_eq!(fmt_path_tail(p, 7), String::from("…/ui"));
+        assert_eq!(fmt_path_tail(p, 4), String::from("…/ui"));
+        // Not even the basename fits
+        assert_eq!(fmt_path_tail(p, 3), String::from("…ui"));
+        assert_eq!(fmt_path_tail(p, 2), String::from("…i"));
+        assert_eq!(fmt_path_tail(p, 1), String::from("…"));
+        assert_eq!(fmt_path_tail(p, 0), String::new());
+    }
+
+    #[test]
+    fn should_fmt_path_tail_with_wide_chars() {
+        let p: &Path = Path::new("/home/omar/文档/報告");
+        // '文档' and '報告' are 4 columns wide each
+        assert_eq!(fmt_path_tail(p, 12), String::from("…/文档/報告"));
+        assert_eq!(fmt_path_tail(p, 9), String::from("…/報告"));
+        assert_eq!(fmt_path_tail(p, 4), String::from("…告"));
+    }
+
+    #[test]
+    fn should_fmt_name_elide() {
+        let name = "termscp-0.19.1-x86_64-unknown-linux-gnu.tar.gz";
+        // Under max size
+        assert_eq!(fmt_name_elide(name, 64), name);
+        assert_eq!(fmt_name_elide(name, 46), name);
+        // Elide in the middle, keeping extension
+        assert_eq!(
+            fmt_name_elide(name, 45),
+            "termscp-0.19.1-x86_64…nknown-linux-gnu.tar.gz"
+        );
+        assert_eq!(fmt_name_elide(name, 24), "termscp-0.…ux-gnu.tar.gz");
+        assert_eq!(fmt_name_elide(name, 12), "term….tar.gz");
+        assert_eq!(fmt_name_elide(name, 6), "t…r.gz");
+        assert_eq!(fmt_name_elide(name, 5), "t….gz");
+        // Not enough room for extension
+        assert_eq!(fmt_name_elide(name, 4), "te…z");
+        assert_eq!(fmt_name_elide(name, 1), "…");
+        assert_eq!(fmt_name_elide(name, 0), "");
+        // Hidden files have no extension
+        assert_eq!(fmt_name_elide(".bash_history", 8), ".bas…ory");
+        // Without extension
+        assert_eq!(fmt_name_elide("CMakeLists", 7), "CMa…sts");
+    }
+
+    #[test]
+    fn should_fmt_name_elide_with_wide_chars() {
+        // '喵' is 2 columns wide
+        assert_eq!(fmt_name_elide("喵喵喵喵喵喵.txt", 11), "喵…喵喵.txt");
+        assert_eq!(fmt_name_elide("喵喵喵喵喵喵", 8), "喵喵…喵");
+        assert_eq!(fmt_name_elide("喵喵喵喵喵喵", 2), "…");
+    }
+
     #[test]
     fn test_utils_fmt_color() {
         assert_eq!(fmt_color(&Color::Black).as_str(), "Black");
@@ -581,6 +862,73 @@ mod tests {
         assert_eq!(shadow_password("foobar"), String::from("******"));
     }
 
+    #[test]
+    fn should_fmt_size_human_readable() {
+        let fmt = |size| fmt_size(size, false, SizeUnits::Binary, None);
+        assert_eq!(fmt(0).as_str(), "0 B");
+        assert_eq!(fmt(1023).as_str(), "1023 B");
+        assert_eq!(fmt(1024).as_str(), "1.0 KiB");
+        assert_eq!(fmt(1536).as_str(), "1.5 KiB");
+        assert_eq!(fmt(1048575).as_str(), "1.0 MiB");
+        assert_eq!(fmt(1048576).as_str(), "1.0 MiB");
+        assert_eq!(fmt(1073741823).as_str(), "1.0 GiB");
+        assert_eq!(fmt(1073741824).as_str(), "1.0 GiB");
+        assert_eq!(fmt(1099511627775).as_str(), "1.0 TiB");
+        assert_eq!(fmt(1099511627776).as_str(), "1.0 TiB");
+        assert_eq!(fmt(1125899906842624).as_str(), "1.0 PiB");
+        assert_eq!(fmt(1152921504606846976).as_str(), "1.0 EiB");
+        assert_eq!(fmt(u64::MAX).as_str(), "16.0 EiB");
+    }
+
+    #[test]
+    fn should_fmt_size_si() {
+        let fmt = |size| fmt_size(size, false, SizeUnits::Si, None);
+        assert_eq!(fmt(999).as_str(), "999 B");
+        assert_eq!(fmt(1000).as_str(), "1.0 kB");
+        assert_eq!(fmt(1024).as_str(), "1.0 kB");
+        assert_eq!(fmt(1500).as_str(), "1.5 kB");
+        assert_eq!(fmt(999999).as_str(), "1.0 MB");
+        assert_eq!(fmt(1048576).as_str(), "1.0 MB");
+        assert_eq!(fmt(1000000000000).as_str(), "1.0 TB");
+        assert_eq!(fmt(u64::MAX).as_str(), "18.4 EB");
+    }
+
+    #[test]
+    fn should_fmt_size_binary_and_si_at_same_value() {
+        let binary = |size| fmt_size(size, false, SizeUnits::Binary, None);
+        let si = |size| fmt_size(size, false, SizeUnits::Si, None);
+        assert_eq!(binary(1536000).as_str(), "1.5 MiB");
+        assert_eq!(si(1536000).as_str(), "1.5 MB");
+        assert_eq!(binary(5000000000).as_str(), "4.7 GiB");
+        assert_eq!(si(5000000000).as_str(), "5.0 GB");
+        // Exact counts don't depend on units
+        assert_eq!(
+            fmt_size(1536000, true, SizeUnits::Si, None),
+            fmt_size(1536000, true, SizeUnits::Binary, None)
+        );
+    }
+
+    #[test]
+    fn should_fmt_size_raw() {
+        let fmt = |size| fmt_size(size, true, SizeUnits::Binary, None);
+        assert_eq!(fmt(1023).as_str(), "1023 B");
+        assert_eq!(fmt(1048575).as_str(), "1048575 B");
+        assert_eq!(fmt(u64::MAX).as_str(), "18446744073709551615 B");
+    }
+
+    #[test]
+    fn should_fmt_size_with_locale() {
+        let locale = Locale::from_name("de_DE");
+        assert_eq!(
+            fmt_size(1536, false, SizeUnits::Binary, locale).as_str(),
+            "1,5 KiB"
+        );
+        assert_eq!(
+            fmt_size(1048575, true, SizeUnits::Binary, locale).as_str(),
+            "1.048.575 B"
+        );
+    }
+
     #[test]
     fn format_bytes() {
         assert_eq!(fmt_bytes(110).as_str(), "110 B");
@@ -590,4 +938,17 @@ mod tests {
         assert_eq!(fmt_bytes(3298534883328).as_str(), "3 TB");
         assert_eq!(fmt_bytes(3377699720527872).as_str(), "3 PB");
     }
+
+    #[test]
+    fn format_open_with() {
+        assert_eq!(fmt_open_with(&HashMap::new()).as_str(), "");
+        assert_eq!(
+            fmt_open_with(&HashMap::from([
+                (String::from("png"), String::from("feh")),
+                (String::from("md"), String::from("glow {}")),
+            ]))
+            .as_str(),
+            "md=glow {}; png=feh"
+        );
+    }
 }
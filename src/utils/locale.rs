@@ -0,0 +1,147 @@
+//! ## Locale
+//!
+//! `locale` is the module which provides locale-aware number and date formatting
+
+use std::env;
+
+/// Locale name which selects the locale of the system
+pub const LOCALE_SYSTEM: &str = "system";
+
+/// Formatting conventions of a locale
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    /// Separator between groups of thousands
+    thousands_sep: char,
+    /// Separator between the integer and the fractional part
+    decimal_sep: char,
+    /// Date format (strftime) for listings
+    date_fmt: &'static str,
+}
+
+impl Locale {
+    /// Get the locale by name (e.g. `de_DE`, `fr`, `de_DE.UTF-8` or `system`).
+    /// Returns `None` if the locale is not supported
+    pub fn from_name(name: &str) -> Option<Self> {
+        if name == LOCALE_SYSTEM {
+            return Some(Self::system());
+        }
+        // Strip encoding and modifiers (e.g. `de_DE.UTF-8@euro`)
+        let name = name.split(['.', '@']).next().unwrap_or_default();
+        let (lang, region) = match name.split_once(['_', '-']) {
+            Some((lang, region)) => (lang, Some(region)),
+            None => (name, None),
+        };
+        let locale = match (lang.to_ascii_lowercase().as_str(), region) {
+            ("en", Some("GB" | "IE" | "AU" | "NZ")) => Self::new(',', '.', "%d %b %Y %H:%M"),
+            ("en", _) | ("c" | "posix", None) => Self::new(',', '.', "%b %d %Y %H:%M"),
+            ("de", Some("CH")) => Self::new('\'', '.', "%d.%m.%Y %H:%M"),
+            ("de" | "da" | "nl" | "tr" | "id", _) => Self::new('.', ',', "%d.%m.%Y %H:%M"),
+            ("it" | "es" | "pt", _) => Self::new('.', ',', "%d/%m/%Y %H:%M"),
+            ("fr" | "ru" | "pl" | "cs" | "sv" | "fi" | "nb" | "uk", _) => {
+                Self::new('\u{a0}', ',', "%d/%m/%Y %H:%M")
+            }
+            ("ja" | "zh" | "ko", _) => Self::new(',', '.', "%Y/%m/%d %H:%M"),
+            _ => return None,
+        };
+        Some(locale)
+    }
+
+    /// Get the locale of the system from the environment (`LC_ALL`, `LC_NUMERIC`, `LANG`).
+    /// Falls back to `en_US` if the locale is not set or not supported
+    pub fn system() -> Self {
+        ["LC_ALL", "LC_NUMERIC", "LANG"]
+            .iter()
+            .filter_map(|var| env::var(var).ok())
+            .find(|x| !x.is_empty())
+            .and_then(|name| Self::from_name(&name))
+            .unwrap_or(Self::new(',', '.', "%b %d %Y %H:%M"))
+    }
+
+    const fn new(thousands_sep: char, decimal_sep: char, date_fmt: &'static str) -> Self {
+        Self {
+            thousands_sep,
+            decimal_sep,
+            date_fmt,
+        }
+    }
+
+    /// Returns the date format of the locale
+    pub fn date_fmt(&self) -> &'static str {
+        self.date_fmt
+    }
+
+    /// Format a number grouping thousands (e.g. `1,048,576`)
+    pub fn fmt_number(&self, n: u64) -> String {
+        let digits = n.to_string();
+        let mut fmt = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                fmt.push(self.thousands_sep);
+            }
+            fmt.push(digit);
+        }
+        fmt
+    }
+
+    /// Localize the decimal separator of a formatted size (e.g. `1.5 KiB` => `1,5 KiB`)
+    pub fn fmt_size(&self, size: &str) -> String {
+        size.replace('.', &self.decimal_sep.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_get_locale_from_name() {
+        assert_eq!(
+            Locale::from_name("de_DE.UTF-8").unwrap().date_fmt(),
+            "%d.%m.%Y %H:%M"
+        );
+        assert_eq!(
+            Locale::from_name("en_GB").unwrap().date_fmt(),
+            "%d %b %Y %H:%M"
+        );
+        assert_eq!(
+            Locale::from_name("en-US").unwrap().date_fmt(),
+            "%b %d %Y %H:%M"
+        );
+        assert_eq!(
+            Locale::from_name("ja").unwrap().date_fmt(),
+            "%Y/%m/%d %H:%M"
+        );
+        assert!(Locale::from_name("system").is_some());
+        assert!(Locale::from_name("xx_XX").is_none());
+    }
+
+    #[test]
+    fn should_group_thousands() {
+        let en = Locale::from_name("en_US").unwrap();
+        assert_eq!(en.fmt_number(0), "0");
+        assert_eq!(en.fmt_number(999), "999");
+        assert_eq!(en.fmt_number(1000), "1,000");
+        assert_eq!(en.fmt_number(1048576), "1,048,576");
+        let de = Locale::from_name("de_DE").unwrap();
+        assert_eq!(de.fmt_number(1048576), "1.048.576");
+        let fr = Locale::from_name("fr_FR").unwrap();
+        assert_eq!(fr.fmt_number(123456), "123\u{a0}456");
+        let ch = Locale::from_name("de_CH").unwrap();
+        assert_eq!(ch.fmt_number(123456), "123'456");
+    }
+
+    #[test]
+    fn should_localize_size() {
+        assert_eq!(
+            Locale::from_name("en_US").unwrap().fmt_size("8.2 kB"),
+            "8.2 kB"
+        );
+        assert_eq!(
+            Locale::from_name("it_IT").unwrap().fmt_size("8.2 kB"),
+            "8,2 kB"
+        );
+    }
+}
@@ -6,11 +6,11 @@
 pub mod crypto;
 pub mod file;
 pub mod fmt;
+pub mod locale;
 pub mod parser;
 pub mod path;
 pub mod random;
 pub mod ssh;
-pub mod string;
 pub mod tty;
 pub mod ui;
 
@@ -3,6 +3,7 @@
 //! `parser` is the module which provides utilities for parsing different kind of stuff
 
 // Locals
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::str::FromStr;
 
@@ -45,6 +46,16 @@ static REMOTE_GENERIC_OPT_REGEX: Lazy<Regex> = lazy_regex!(
     r"(?:(.+[^@])@)?(?:([^:]+))(?::((?:[0-9]{1,4}|[1-5][0-9]{4}|6[0-4][0-9]{3}|65[0-4][0-9]{2}|655[0-2][0-9]|6553[0-5])(?:[0-9]{1,4}|[1-5][0-9]{4}|6[0-4][0-9]{3}|65[0-4][0-9]{2}|655[0-2][0-9]|6553[0-5])))?(?::([^:]+))?"
 );
 
+/**
+ * Regex matches, for bracketed IPv6 addresses (e.g. `[2001:db8::1]`):
+ *  - group 1: Some(user) | None
+ *  - group 2: Address, with brackets
+ *  - group 3: Some(port) | None
+ *  - group 4: Some(path) | None; the path may follow the port without `:`
+ */
+static REMOTE_GENERIC_IPV6_OPT_REGEX: Lazy<Regex> =
+    lazy_regex!(r"^(?:(.+[^@])@)?(\[[0-9a-fA-F:.]+(?:%[^\]]+)?\])(?::([0-9]{1,5}))?(?::?(.+))?$");
+
 /**
  * Regex matches:
  *  - group 1: Username
@@ -118,7 +129,8 @@ static BYTESIZE_REGEX: Lazy<Regex> = lazy_regex!(r"(:?([0-9])+)( )*(:?[KMGTP])?B
 /// In case of error, message is returned
 /// If port is missing default port will be used for each protocol
 ///     SFTP => 22
-///     FTP => 21
+///     FTP/FTPS => 21
+/// FTPS uses explicit TLS (`AUTH TLS`), since implicit TLS isn't supported by the FTP client
 /// The option string has the following syntax
 /// [protocol://][username@]{address}[:port][:path]
 /// The only argument which is mandatory is address
@@ -223,7 +235,12 @@ fn parse_generic_remote_opt(
     s: &str,
     protocol: FileTransferProtocol,
 ) -> Result<FileTransferParams, String> {
-    match REMOTE_GENERIC_OPT_REGEX.captures(s) {
+    // IPv6 addresses contain `:`, so they must be bracketed and are matched on their own;
+    // brackets are kept in the address, since it's joined with the port to connect
+    let groups = REMOTE_GENERIC_IPV6_OPT_REGEX
+        .captures(s)
+        .or_else(|| REMOTE_GENERIC_OPT_REGEX.captures(s));
+    match groups {
         Some(groups) => {
             // Match user
             let username = groups.get(1).map(|x| x.as_str().to_string());
@@ -487,6 +504,35 @@ pub fn parse_bytesize<S: AsRef<str>>(bytes: S) -> Option<ByteSize> {
     }
 }
 
+/// Parse the association between file extension and command to open the files with,
+/// written as `ext=command` entries separated by `;` (e.g. `md=glow {}; png=feh`).
+/// A `;` not followed by an `ext=` entry is kept in the command (e.g. `c=cd {dir}; make`)
+pub fn parse_open_with(s: &str) -> HashMap<String, String> {
+    let mut entries: Vec<(String, String)> = Vec::new();
+    for part in s.split(';') {
+        let entry = part.split_once('=').and_then(|(ext, command)| {
+            let ext = ext.trim().trim_start_matches('.');
+            let is_ext = !ext.is_empty()
+                && ext
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || matches!(c, '.' | '_' | '-'));
+            is_ext.then(|| (ext.to_string(), command.trim().to_string()))
+        });
+        match (entry, entries.last_mut()) {
+            (Some(entry), _) => entries.push(entry),
+            (None, Some((_, command))) => {
+                command.push(';');
+                command.push_str(part.trim_end());
+            }
+            (None, None) => {}
+        }
+    }
+    entries
+        .into_iter()
+        .filter(|(_, command)| !command.is_empty())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -644,6 +690,61 @@ mod tests {
         assert!(result.remote_path.is_none());
     }
 
+    #[test]
+    fn test_should_parse_ftp_remote_opt() {
+        let result = parse_remote_opt("ftp://ftp.example.com").unwrap();
+        let params = result.params.generic_params().unwrap();
+        assert_eq!(result.protocol, FileTransferProtocol::Ftp(false));
+        assert_eq!(params.address.as_str(), "ftp.example.com");
+        assert_eq!(params.port, 21);
+        assert!(params.username.is_none());
+        assert!(result.remote_path.is_none());
+        // FTPS uses explicit TLS on any port, including the one of implicit TLS
+        let result = parse_remote_opt("ftps://ftp.example.com:990").unwrap();
+        let params = result.params.generic_params().unwrap();
+        assert_eq!(result.protocol, FileTransferProtocol::Ftp(true));
+        assert_eq!(params.address.as_str(), "ftp.example.com");
+        assert_eq!(params.port, 990);
+        assert!(result.remote_path.is_none());
+        let result = parse_remote_opt("ftps://anon@ftp.example.com:/pub").unwrap();
+        let params = result.params.generic_params().unwrap();
+        assert_eq!(result.protocol, FileTransferProtocol::Ftp(true));
+        assert_eq!(params.port, 21);
+        assert_eq!(params.username.as_deref(), Some("anon"));
+        assert_eq!(result.remote_path.unwrap(), PathBuf::from("/pub"));
+    }
+
+    #[test]
+    fn test_should_parse_ipv6_remote_opt() {
+        let result = parse_remote_opt("sftp://[2001:db8::1]:2222/path").unwrap();
+        let params = result.params.generic_params().unwrap();
+        assert_eq!(result.protocol, FileTransferProtocol::Sftp);
+        assert_eq!(params.address.as_str(), "[2001:db8::1]");
+        assert_eq!(params.port, 2222);
+        assert!(params.username.is_none());
+        assert_eq!(result.remote_path.unwrap(), PathBuf::from("/path"));
+        // Without port
+        let result = parse_remote_opt("root@[2001:db8::1]").unwrap();
+        let params = result.params.generic_params().unwrap();
+        assert_eq!(params.address.as_str(), "[2001:db8::1]");
+        assert_eq!(params.port, 22);
+        assert_eq!(params.username.as_deref(), Some("root"));
+        assert!(result.remote_path.is_none());
+        let result = parse_remote_opt("ftp://[::1]:/tmp").unwrap();
+        let params = result.params.generic_params().unwrap();
+        assert_eq!(params.address.as_str(), "[::1]");
+        assert_eq!(params.port, 21);
+        assert_eq!(result.remote_path.unwrap(), PathBuf::from("/tmp"));
+        // Path after port, separated by `:` as for the other addresses
+        let result = parse_remote_opt("[fe80::1%eth0]:8022:/var").unwrap();
+        let params = result.params.generic_params().unwrap();
+        assert_eq!(params.address.as_str(), "[fe80::1%eth0]");
+        assert_eq!(params.port, 8022);
+        assert_eq!(result.remote_path.unwrap(), PathBuf::from("/var"));
+        // Bad port
+        assert!(parse_remote_opt("[::1]:99999").is_err());
+    }
+
     #[test]
     fn test_should_parse_webdav_opt() {
         let result =
@@ -877,4 +978,30 @@ mod tests {
         assert!(parse_bytesize("1 GBaaaaa").is_none());
         assert!(parse_bytesize("1MBaaaaa").is_none());
     }
+
+    #[test]
+    fn parse_str_as_open_with() {
+        assert_eq!(
+            parse_open_with("md=glow {}; .png = feh ;tar.gz=tar tzf {}"),
+            HashMap::from([
+                (String::from("md"), String::from("glow {}")),
+                (String::from("png"), String::from("feh")),
+                (String::from("tar.gz"), String::from("tar tzf {}")),
+            ])
+        );
+        // `;` in commands
+        assert_eq!(
+            parse_open_with("c=cd {dir}; make; md=glow {}"),
+            HashMap::from([
+                (String::from("c"), String::from("cd {dir}; make")),
+                (String::from("md"), String::from("glow {}")),
+            ])
+        );
+        // bad entries are ignored
+        assert_eq!(
+            parse_open_with("glow; =feh; md=; txt=less"),
+            HashMap::from([(String::from("txt"), String::from("less"))])
+        );
+        assert!(parse_open_with("").is_empty());
+    }
 }
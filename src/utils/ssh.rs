@@ -1,4 +1,8 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
 use ssh2_config::{ParseRule, SshConfig};
+use wildmatch::WildMatch;
 
 pub fn parse_ssh2_config(path: &str) -> Result<SshConfig, String> {
     use std::fs::File;
@@ -12,10 +16,98 @@ pub fn parse_ssh2_config(path: &str) -> Result<SshConfig, String> {
         .map_err(|e| format!("Failed to parse ssh2 config: {e}"))
 }
 
+/// Read the ssh2 config at `path`, replacing its `Include` directives with the content of the included files.
+///
+/// Included paths may start with `~`, are relative to the directory of the including file
+/// and may have wildcards in the file name. Each file is included once, which breaks include cycles
+pub fn read_ssh2_config_with_includes(path: &Path) -> Result<String, String> {
+    let mut config = String::new();
+    expand_includes(path, &mut HashSet::new(), &mut config)?;
+    Ok(config)
+}
+
+fn expand_includes(
+    path: &Path,
+    visited: &mut HashSet<PathBuf>,
+    config: &mut String,
+) -> Result<(), String> {
+    let canonical = path
+        .canonicalize()
+        .map_err(|e| format!("failed to open {}: {e}", path.display()))?;
+    if !visited.insert(canonical) {
+        warn!("{} has already been included; skipping it", path.display());
+        return Ok(());
+    }
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    let dir = path.parent().unwrap_or(Path::new(""));
+    for line in content.lines() {
+        match include_patterns(line) {
+            Some(patterns) => {
+                for file in patterns.flat_map(|x| include_files(dir, x)) {
+                    expand_includes(&file, visited, config)?;
+                }
+            }
+            None => {
+                config.push_str(line);
+                config.push('\n');
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Get the paths of the `Include` directive on `line`, if it's one
+fn include_patterns(line: &str) -> Option<impl Iterator<Item = &str>> {
+    let (keyword, args) = line
+        .trim_start()
+        .split_once(|c: char| c.is_whitespace() || c == '=')?;
+    keyword.eq_ignore_ascii_case("include").then(|| {
+        args.trim_start_matches(|c: char| c.is_whitespace() || c == '=')
+            .split_whitespace()
+    })
+}
+
+/// Get the files matching the `Include` path `pattern`, sorted by name; like ssh, missing files are ignored
+fn include_files(dir: &Path, pattern: &str) -> Vec<PathBuf> {
+    let pattern = dir.join(shellexpand::tilde(pattern).as_ref());
+    let (Some(parent), Some(name)) = (pattern.parent(), pattern.file_name()) else {
+        return Vec::new();
+    };
+    let name = name.to_string_lossy();
+    if !name.contains(['*', '?']) {
+        return if pattern.is_file() {
+            vec![pattern]
+        } else {
+            Vec::new()
+        };
+    }
+    let matcher = WildMatch::new(&name);
+    let mut files: Vec<PathBuf> = std::fs::read_dir(parent)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|x| x.path())
+                .filter(|x| {
+                    x.is_file()
+                        && x.file_name()
+                            .is_some_and(|x| matcher.matches(&x.to_string_lossy()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files
+}
+
 #[cfg(test)]
 mod test {
 
-    use crate::utils::ssh::parse_ssh2_config;
+    use std::fs;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::utils::ssh::{parse_ssh2_config, read_ssh2_config_with_includes};
     use crate::utils::test_helpers;
 
     #[test]
@@ -47,4 +139,30 @@ Host test
             .is_ok()
         );
     }
+
+    #[test]
+    fn should_expand_includes() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let dir = tmp_dir.path();
+        fs::create_dir(dir.join("config.d")).unwrap();
+        fs::write(dir.join("config.d").join("a"), "Host a\n").unwrap();
+        // Paths are relative to the directory of the including file; the cycle is broken
+        fs::write(
+            dir.join("config.d").join("b"),
+            "Host b\nInclude ../config\n",
+        )
+        .unwrap();
+        fs::write(dir.join("config.d").join("ignored.bak"), "Host ignored\n").unwrap();
+        fs::write(
+            dir.join("config"),
+            "Host first\n    Include config.d/? missing\nHost last\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            read_ssh2_config_with_includes(&dir.join("config")).unwrap(),
+            "Host first\nHost a\nHost b\nHost last\n"
+        );
+        assert!(read_ssh2_config_with_includes(&dir.join("missing")).is_err());
+    }
 }
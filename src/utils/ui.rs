@@ -2,8 +2,14 @@
 //!
 //! `Utils` implements utilities functions to work with layouts
 
+use std::fmt;
+use std::str::FromStr;
+
+use tuirealm::props::{Color, TextSpan};
 use tuirealm::ratatui::layout::{Constraint, Direction, Layout, Rect};
 
+use crate::config::keybindings::{HelpRow, fmt_bindings};
+
 /// Size type for UI renders
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Size {
@@ -11,6 +17,46 @@ pub enum Size {
     Unit(u16),
 }
 
+/// Style of the cursor row in the file explorers
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum HighlightStyle {
+    /// Highlighted color as background
+    #[default]
+    Color,
+    /// Reverse-video of the row colors
+    Reverse,
+    Bold,
+    Underline,
+}
+
+impl fmt::Display for HighlightStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                HighlightStyle::Color => "color",
+                HighlightStyle::Reverse => "reverse",
+                HighlightStyle::Bold => "bold",
+                HighlightStyle::Underline => "underline",
+            }
+        )
+    }
+}
+
+impl FromStr for HighlightStyle {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "color" => Ok(HighlightStyle::Color),
+            "reverse" => Ok(HighlightStyle::Reverse),
+            "bold" => Ok(HighlightStyle::Bold),
+            "underline" => Ok(HighlightStyle::Underline),
+            _ => Err(()),
+        }
+    }
+}
+
 /// Ui popup dialog (w x h)
 pub struct Popup(pub Size, pub Size);
 
@@ -54,6 +100,29 @@ impl Popup {
     }
 }
 
+/// Build the rows of a keybindings help list; the rows of each category follow the category name
+pub fn keybindings_help_table(rows: &[HelpRow], key_color: Color) -> Vec<Vec<TextSpan>> {
+    let mut table = vec![];
+    let mut category = None;
+    for row in rows {
+        if category != Some(row.category) {
+            if category.is_some() {
+                table.push(vec![TextSpan::from("")]);
+            }
+            category = Some(row.category);
+            table.push(vec![
+                TextSpan::new(row.category.to_string()).bold().underlined(),
+            ]);
+        }
+        let keys = format!("<{}>", fmt_bindings(&row.bindings).to_uppercase());
+        table.push(vec![
+            TextSpan::new(format!("{keys:<16}")).bold().fg(key_color),
+            TextSpan::from(row.label),
+        ]);
+    }
+    table
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -70,4 +139,24 @@ mod tests {
         assert_eq!(child.width, 768);
         assert_eq!(child.height, 154);
     }
+
+    #[test]
+    fn test_utils_ui_highlight_style() {
+        for style in [
+            HighlightStyle::Color,
+            HighlightStyle::Reverse,
+            HighlightStyle::Bold,
+            HighlightStyle::Underline,
+        ] {
+            assert_eq!(
+                HighlightStyle::from_str(&style.to_string()).ok().unwrap(),
+                style
+            );
+        }
+        assert_eq!(
+            HighlightStyle::from_str("BOLD").ok().unwrap(),
+            HighlightStyle::Bold
+        );
+        assert!(HighlightStyle::from_str("blink").is_err());
+    }
 }